@@ -26,3 +26,4 @@ export 'src/utils/fft.dart';
 // Rust FFI (Audio Palette)
 export 'src/rust/api.dart';
 export 'src/rust/lib.dart';
+export 'src/rust/midi.dart';
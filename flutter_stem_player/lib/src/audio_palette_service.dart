@@ -9,6 +9,7 @@
 import 'package:path_provider/path_provider.dart';
 import 'package:flutter_stem_player/src/rust/api.dart' as rust_api;
 import 'package:flutter_stem_player/src/rust/lib.dart' as rust_lib;
+import 'package:flutter_stem_player/src/rust/midi.dart' as rust_midi;
 import 'package:flutter_stem_player/src/rust/frb_generated.dart';
 
 /// Service for audio palette operations (database, fingerprinting, search)
@@ -19,6 +20,9 @@ class AudioPaletteService {
 
   bool _initialized = false;
 
+  /// Handle of the palette this service works on
+  late int _palette;
+
   /// Initialize the audio palette service
   Future<void> initialize() async {
     if (_initialized) return;
@@ -30,8 +34,8 @@ class AudioPaletteService {
     final appDir = await getApplicationDocumentsDirectory();
     final dbPath = '${appDir.path}/audio_palette.db';
 
-    // Initialize the database
-    rust_api.initDatabase(dbPath: dbPath);
+    // Open the palette database
+    _palette = rust_api.openPalette(dbPath: dbPath);
 
     _initialized = true;
   }
@@ -39,26 +43,26 @@ class AudioPaletteService {
   /// Add a sound file to the palette database
   Future<int> addSound(String filepath) async {
     await _ensureInitialized();
-    final id = await rust_api.addSound(filepath: filepath);
+    final id = await rust_api.addSound(palette: _palette, filepath: filepath);
     return id.toInt();
   }
 
   /// Get all sounds in the database
   Future<List<rust_lib.SoundRecord>> getAllSounds() async {
     await _ensureInitialized();
-    return rust_api.getAllSounds();
+    return rust_api.getAllSounds(palette: _palette);
   }
 
   /// Get the total number of sounds in the database
   int getSoundCount() {
     if (!_initialized) return 0;
-    return rust_api.getSoundCount().toInt();
+    return rust_api.getSoundCount(palette: _palette).toInt();
   }
 
   /// Search sounds by filename
   Future<List<rust_lib.SoundRecord>> searchSounds(String query) async {
     await _ensureInitialized();
-    return rust_api.searchSounds(query: query);
+    return rust_api.searchSounds(palette: _palette, query: query);
   }
 
   /// Find similar sounds to a query file
@@ -73,6 +77,7 @@ class AudioPaletteService {
   }) async {
     await _ensureInitialized();
     return rust_api.findSimilar(
+      palette: _palette,
       queryPath: queryPath,
       threshold: threshold,
       maxResults: BigInt.from(maxResults),
@@ -90,6 +95,7 @@ class AudioPaletteService {
   }) async {
     await _ensureInitialized();
     return rust_api.findSimilarWithSegments(
+      palette: _palette,
       queryPath: queryPath,
       threshold: threshold,
       maxResults: BigInt.from(maxResults),
@@ -109,6 +115,7 @@ class AudioPaletteService {
   }) async {
     await _ensureInitialized();
     return rust_api.findSimilarFromSamples(
+      palette: _palette,
       samples: samples,
       sampleRate: sampleRate,
       threshold: threshold,
@@ -119,18 +126,32 @@ class AudioPaletteService {
   /// Export match results to MIDI file
   ///
   /// Creates a MIDI file where each match is a note at the matching timestamp.
+  /// A [quantizeNoteValue] of 4 to 32 snaps notes to that grid, with
+  /// [swing] pushing every second grid line later; 0 keeps the timing.
+  /// [followDetectedTempo] writes the first match's detected beats as the
+  /// tempo map instead of [tempoBpm]. [noteMapping] picks each track's
+  /// channel, program and note from its instrument (and pitch).
   Future<void> exportToMidi(
     List<rust_lib.MatchResult> matches,
     String outputPath, {
     int tempoBpm = 120,
     int baseNote = 60,
+    int quantizeNoteValue = 0,
+    double swing = 0.0,
+    bool followDetectedTempo = false,
+    rust_midi.MidiNoteMapping noteMapping = rust_midi.MidiNoteMapping.sequential,
   }) async {
     await _ensureInitialized();
     await rust_api.exportToMidi(
+      palette: _palette,
       matches: matches,
       outputPath: outputPath,
       tempoBpm: tempoBpm,
       baseNote: baseNote,
+      quantizeNoteValue: quantizeNoteValue,
+      swing: swing,
+      followDetectedTempo: followDetectedTempo,
+      noteMapping: noteMapping,
     );
   }
 
@@ -140,7 +161,8 @@ class AudioPaletteService {
     String outputPath,
   ) async {
     await _ensureInitialized();
-    await rust_api.exportToCsv(matches: matches, outputPath: outputPath);
+    await rust_api.exportToCsv(
+        palette: _palette, matches: matches, outputPath: outputPath);
   }
 
   /// Export match results to markers file
@@ -149,13 +171,14 @@ class AudioPaletteService {
     String outputPath,
   ) async {
     await _ensureInitialized();
-    await rust_api.exportToMarkers(matches: matches, outputPath: outputPath);
+    await rust_api.exportToMarkers(
+        palette: _palette, matches: matches, outputPath: outputPath);
   }
 
   /// Remove a sound from the database
   Future<void> removeSound(int soundId) async {
     await _ensureInitialized();
-    await rust_api.removeSound(soundId: soundId);
+    await rust_api.removeSound(palette: _palette, soundId: soundId);
   }
 
   /// Get fingerprint info for a sound file
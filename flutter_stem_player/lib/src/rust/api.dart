@@ -3,134 +3,833 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'database/archive.dart';
+import 'database/clusters.dart';
+import 'database/collections.dart';
+import 'database/history.dart';
+import 'database/maintenance.dart';
+import 'database/merge.dart';
+import 'database/properties.dart';
+import 'database/query.dart';
+import 'database/regions.dart';
+import 'database/searches.dart';
+import 'database/smart_collections.dart';
+import 'database/sound_map.dart';
+import 'database/usage.dart';
+import 'fingerprint.dart';
+import 'fingerprint/analysis.dart';
 import 'frb_generated.dart';
+import 'indexer.dart';
 import 'lib.dart';
+import 'midi/csv.dart';
+import 'midi/dspreset.dart';
+import 'midi/import.dart';
+import 'midi/mapping.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'render/colormap.dart';
+import 'render/thumbnail.dart';
+import 'render/waveform.dart';
+import 'search/batch.dart';
+import 'search/containment.dart';
+import 'search/folder.dart';
+import 'search/loops.dart';
+import 'search/presets.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_db`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
-/// Initialize the audio palette database
-void initDatabase({required String dbPath}) =>
-    AudioPalette.instance.api.crateApiInitDatabase(dbPath: dbPath);
+            // These functions are ignored because they are not marked as `pub`: `cached_similar`, `collection_matches`, `database`, `detected_beats`, `get_tagger`, `gpu_scorer`, `live_sessions`, `match_fingerprint`, `match_melody`, `palette`, `palettes`, `register_palette`, `sample_rates`, `search_engine`, `send_updates`, `smart_reference`, `to_config`, `track_search`, `track_usage`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LiveSession`, `Palette`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Open (or create) a palette database, returning the handle that every
+/// other call takes to target it
+///
+/// Several palettes can be open at once (e.g. "drums" and "foley").
+PlatformInt64  openPalette({required String dbPath }) => AudioPalette.instance.api.crateApiOpenPalette(dbPath: dbPath);
+
+/// Open (or create) an encrypted palette, returning its handle
+///
+/// The passphrase is needed every time the palette is opened; there is no
+/// way to recover a palette whose passphrase is lost.
+PlatformInt64  openEncryptedPalette({required String dbPath , required String passphrase }) => AudioPalette.instance.api.crateApiOpenEncryptedPalette(dbPath: dbPath, passphrase: passphrase);
+
+/// Close a palette; calls already running on it finish first
+void  closePalette({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiClosePalette(palette: palette);
+
+/// Handles of the open palettes, in the order they were opened
+Int64List  getOpenPalettes() => AudioPalette.instance.api.crateApiGetOpenPalettes();
+
+/// Whether this build can open encrypted palettes
+bool  encryptionSupported() => AudioPalette.instance.api.crateApiEncryptionSupported();
 
 /// Add a sound file to the database
-Future<PlatformInt64> addSound({required String filepath}) =>
-    AudioPalette.instance.api.crateApiAddSound(filepath: filepath);
+///
+/// If the same audio (identical file contents) is already indexed under
+/// another path, returns the existing sound's id without re-indexing.
+Future<PlatformInt64>  addSound({required PlatformInt64 palette , required String filepath }) => AudioPalette.instance.api.crateApiAddSound(palette: palette, filepath: filepath);
+
+/// Add many sound files, analyzing them in parallel and writing them in a
+/// single transaction
+///
+/// Returns one entry per input path: the sound id (the existing one for
+/// exact duplicates), or None if the file could not be loaded.
+Future<List<PlatformInt64?>>  addSoundsBatch({required PlatformInt64 palette , required List<String> filepaths }) => AudioPalette.instance.api.crateApiAddSoundsBatch(palette: palette, filepaths: filepaths);
+
+/// Re-analyze sounds whose files were edited on disk since indexing, or
+/// whose fingerprint is outdated
+Future<RescanSummary>  rescanLibrary({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiRescanLibrary(palette: palette);
+
+/// Check every indexed file against the content hash it was indexed with
+Future<VerifyReport>  verifyLibrary({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiVerifyLibrary(palette: palette);
+
+/// Groups of sounds whose files have identical contents
+Future<List<List<SoundRecord>>>  findDuplicateGroups({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiFindDuplicateGroups(palette: palette);
+
+/// Groups of perceptually near-identical sounds (other encodes, trims or
+/// bitrates of the same sample) scoring at least `threshold` (0-100, e.g.
+/// 98) against another member, largest group first
+Future<List<List<SoundRecord>>>  findDuplicates({required PlatformInt64 palette , required double threshold }) => AudioPalette.instance.api.crateApiFindDuplicates(palette: palette, threshold: threshold);
+
+/// List sounds whose file no longer exists at the indexed path
+Future<List<SoundRecord>>  findMissingSounds({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiFindMissingSounds(palette: palette);
+
+/// Point a sound at its file's new location
+///
+/// The new file must have the same contents or a closely matching
+/// fingerprint; returns the fingerprint similarity (0-100).
+Future<double>  relinkSound({required PlatformInt64 palette , required PlatformInt64 soundId , required String newPath }) => AudioPalette.instance.api.crateApiRelinkSound(palette: palette, soundId: soundId, newPath: newPath);
+
+/// Recursively index a folder
+///
+/// Only files with one of `extensions` (all supported formats when empty)
+/// and at most `max_file_size` bytes are considered; files already in the
+/// library are skipped unless their fingerprint is outdated. Poll
+/// `get_index_progress` while this runs.
+Future<IndexProgress>  indexDirectory({required PlatformInt64 palette , required String path , required List<String> extensions , BigInt? maxFileSize }) => AudioPalette.instance.api.crateApiIndexDirectory(palette: palette, path: path, extensions: extensions, maxFileSize: maxFileSize);
+
+/// Progress of the running (or last finished) `index_directory` call
+IndexProgress?  getIndexProgress({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetIndexProgress(palette: palette);
+
+/// Stop the running `index_directory` call once the files it is
+/// analyzing are stored; it returns with `cancelled` set
+void  cancelIndexing({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiCancelIndexing(palette: palette);
+
+/// Compact the database and rebuild its indexes and statistics
+///
+/// Worth running occasionally on long-lived palettes with many add/remove
+/// cycles. Writes wait until it finishes; poll `get_optimize_progress`.
+Future<OptimizeSummary>  optimizeDatabase({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiOptimizeDatabase(palette: palette);
+
+/// Check the database file and its fingerprints for damage
+Future<IntegrityReport>  checkIntegrity({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiCheckIntegrity(palette: palette);
+
+/// Library root of a portable palette (None if paths are stored absolute)
+String?  getLibraryRoot({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetLibraryRoot(palette: palette);
+
+/// Store paths under `root` relative to it, so the palette keeps working
+/// when the drive holding the library is mounted elsewhere; call again
+/// with the new location after it moves. None stores absolute paths
+/// again. Returns how many paths changed form.
+Future<BigInt>  setLibraryRoot({required PlatformInt64 palette , String? root }) => AudioPalette.instance.api.crateApiSetLibraryRoot(palette: palette, root: root);
+
+/// Progress of the running (or last) `optimize_database` call
+OptimizeProgress?  getOptimizeProgress({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetOptimizeProgress(palette: palette);
+
+/// Group the library into clusters of similar sounds for browsing,
+/// replacing the previous clustering
+///
+/// `clusters` of 0 picks the number that separates the library best
+/// (by silhouette). Each cluster gets a proposed label. Sounds added
+/// afterwards stay unclustered until the next run; poll
+/// `get_cluster_progress` meanwhile.
+Future<ClusteringSummary>  clusterLibrary({required PlatformInt64 palette , required BigInt clusters }) => AudioPalette.instance.api.crateApiClusterLibrary(palette: palette, clusters: clusters);
+
+/// Progress of the running (or last) `cluster_library` call
+ClusterProgress?  getClusterProgress({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetClusterProgress(palette: palette);
+
+/// Clusters from the last `cluster_library` run, largest first
+Future<List<SoundCluster>>  getClusters({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetClusters(palette: palette);
+
+/// Sounds in a cluster ("browse this cluster")
+Future<List<SoundRecord>>  getClusterSounds({required PlatformInt64 palette , required PlatformInt64 clusterId }) => AudioPalette.instance.api.crateApiGetClusterSounds(palette: palette, clusterId: clusterId);
+
+/// Save the last clustering as categories under the top-level category
+/// `parent` (one per cluster, named by its label), replacing the ones a
+/// previous call put there; returns how many were written
+Future<BigInt>  writeClusterCategories({required PlatformInt64 palette , required String parent }) => AudioPalette.instance.api.crateApiWriteClusterCategories(palette: palette, parent: parent);
+
+/// Cluster a sound belongs to, to browse its neighbours (None if unclustered)
+Future<PlatformInt64?>  getSoundCluster({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetSoundCluster(palette: palette, soundId: soundId);
+
+/// Lay the library out on a 2D map where similar sounds sit near each
+/// other, replacing the previous layout; returns the number of sounds placed
+///
+/// Sounds added afterwards have no position until the next run; poll
+/// `get_map_progress` meanwhile.
+Future<BigInt>  computeSoundMap({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiComputeSoundMap(palette: palette);
+
+/// Progress of the running (or last) `compute_sound_map` call
+MapProgress?  getMapProgress({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetMapProgress(palette: palette);
+
+/// Every placed sound's map position (coordinates 0-1)
+Future<List<MapPoint>>  getSoundMap({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetSoundMap(palette: palette);
+
+/// A sound's map position, to centre the map on it (None if not placed)
+Future<MapPoint?>  getMapPosition({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetMapPosition(palette: palette, soundId: soundId);
+
+/// Report an indexed sound with exactly the same contents as a file stored
+/// under a different path
+Future<SoundRecord?>  findDuplicate({required PlatformInt64 palette , required String filepath }) => AudioPalette.instance.api.crateApiFindDuplicate(palette: palette, filepath: filepath);
+
+/// Load an ONNX genre/mood tagging model to run when sounds are added
+/// (requires a build with the `onnx` feature)
+Future<void>  loadTaggingModel({required String modelPath , required String labelsPath , required double threshold }) => AudioPalette.instance.api.crateApiLoadTaggingModel(modelPath: modelPath, labelsPath: labelsPath, threshold: threshold);
+
+/// Stop applying the tagging model to new sounds
+void  unloadTaggingModel() => AudioPalette.instance.api.crateApiUnloadTaggingModel();
+
+/// Predict instrument labels for a file without indexing it
+Future<List<SoundTag>>  classifyInstrument({required String filepath }) => AudioPalette.instance.api.crateApiClassifyInstrument(filepath: filepath);
 
 /// Get all sounds in the database
-Future<List<SoundRecord>> getAllSounds() =>
-    AudioPalette.instance.api.crateApiGetAllSounds();
+Future<List<SoundRecord>>  getAllSounds({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetAllSounds(palette: palette);
+
+/// Get one page of the library in the given order
+///
+/// Prefer this over `get_all_sounds` for large libraries.
+Future<SoundPage>  getSoundsPage({required PlatformInt64 palette , required PlatformInt64 offset , required PlatformInt64 limit , required SoundSort sort , required bool descending }) => AudioPalette.instance.api.crateApiGetSoundsPage(palette: palette, offset: offset, limit: limit, sort: sort, descending: descending);
+
+/// Find sounds by duration, format, sample rate, channels, rating and
+/// text, returning one page of the sorted results
+Future<SoundPage>  querySounds({required PlatformInt64 palette , required SoundQuery query }) => AudioPalette.instance.api.crateApiQuerySounds(palette: palette, query: query);
+
+/// Rate a sound from 1 to 5 stars, or 0 to clear the rating
+Future<void>  setSoundRating({required PlatformInt64 palette , required PlatformInt64 soundId , required int rating }) => AudioPalette.instance.api.crateApiSetSoundRating(palette: palette, soundId: soundId, rating: rating);
+
+/// Count a playback of a sound towards its usage statistics
+Future<void>  recordAudition({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiRecordAudition(palette: palette, soundId: soundId);
+
+/// How often a sound was returned, auditioned and exported
+Future<SoundUsage>  getSoundUsage({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetSoundUsage(palette: palette, soundId: soundId);
+
+/// Sounds auditioned or exported most often
+Future<List<UsedSound>>  getMostUsedSounds({required PlatformInt64 palette , required PlatformInt64 limit }) => AudioPalette.instance.api.crateApiGetMostUsedSounds(palette: palette, limit: limit);
+
+/// Sounds auditioned or exported most recently
+Future<List<UsedSound>>  getRecentlyUsedSounds({required PlatformInt64 palette , required PlatformInt64 limit }) => AudioPalette.instance.api.crateApiGetRecentlyUsedSounds(palette: palette, limit: limit);
+
+/// Waveform overview of a sound with `buckets` min/max pairs
+///
+/// Served from the database cache when the file is unchanged since the
+/// peaks were computed; otherwise decoded once and cached.
+Future<WaveformPeaks>  getWaveform({required PlatformInt64 palette , required PlatformInt64 soundId , required BigInt buckets }) => AudioPalette.instance.api.crateApiGetWaveform(palette: palette, soundId: soundId, buckets: buckets);
+
+/// Thumbnail image of a sound (PNG for waveforms and spectrograms)
+///
+/// Rendered at index time; sounds indexed earlier, or changed since, are
+/// rendered on first request and cached. None if `kind` is `Artwork` and
+/// the file has no (small enough) embedded picture.
+Future<Thumbnail?>  getThumbnail({required PlatformInt64 palette , required PlatformInt64 soundId , required ThumbnailKind kind }) => AudioPalette.instance.api.crateApiGetThumbnail(palette: palette, soundId: soundId, kind: kind);
+
+/// Cached thumbnails for a page of sounds, parallel to `sound_ids`
+///
+/// Never decodes audio, so grid views can call it for every page; fill
+/// gaps with `get_thumbnail`.
+Future<List<Thumbnail?>>  getCachedThumbnails({required PlatformInt64 palette , required Int64List soundIds , required ThumbnailKind kind }) => AudioPalette.instance.api.crateApiGetCachedThumbnails(palette: palette, soundIds: soundIds, kind: kind);
+
+/// Onsets, beat grid or pitch curve of a sound
+///
+/// Computed at index time; sounds indexed earlier, or changed since, are
+/// analyzed on first request and the results stored.
+Future<AnalysisTrack>  getAnalysis({required PlatformInt64 palette , required PlatformInt64 soundId , required AnalysisKind kind }) => AudioPalette.instance.api.crateApiGetAnalysis(palette: palette, soundId: soundId, kind: kind);
 
 /// Get sound count
-PlatformInt64 getSoundCount() =>
-    AudioPalette.instance.api.crateApiGetSoundCount();
+PlatformInt64  getSoundCount({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetSoundCount(palette: palette);
+
+/// Number of sounds similarity search skips because their fingerprint is
+/// missing or outdated; `index_directory` and `rescan_library` re-analyze them
+Future<PlatformInt64>  getOutdatedFingerprintCount({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetOutdatedFingerprintCount(palette: palette);
+
+/// Search sounds by filename, path, metadata and tags (prefix matching, ranked)
+Future<List<SoundRecord>>  searchSounds({required PlatformInt64 palette , required String query }) => AudioPalette.instance.api.crateApiSearchSounds(palette: palette, query: query);
+
+/// Export the whole library (records, tags, fingerprints and optionally the
+/// audio files) to a zip archive
+Future<ArchiveSummary>  exportLibrary({required PlatformInt64 palette , required String archivePath , required bool includeAudio }) => AudioPalette.instance.api.crateApiExportLibrary(palette: palette, archivePath: archivePath, includeAudio: includeAudio);
+
+/// Merge a library archive into the current database, extracting any
+/// bundled audio into `audio_dir`
+Future<ImportSummary>  importLibrary({required PlatformInt64 palette , required String archivePath , String? audioDir }) => AudioPalette.instance.api.crateApiImportLibrary(palette: palette, archivePath: archivePath, audioDir: audioDir);
+
+/// Merge another palette database (e.g. a teammate's) into this one
+///
+/// Sounds already here, by path or content hash, are handled according to
+/// `policy`; tags and collections are combined.
+Future<MergeSummary>  mergeDatabase({required PlatformInt64 palette , required String otherPath , required ConflictPolicy policy }) => AudioPalette.instance.api.crateApiMergeDatabase(palette: palette, otherPath: otherPath, policy: policy);
+
+/// Edit the descriptive metadata of a sound (`None` leaves a field unchanged)
+Future<void>  updateSoundMetadata({required PlatformInt64 palette , required PlatformInt64 soundId , String? title , String? artist , String? notes , String? source }) => AudioPalette.instance.api.crateApiUpdateSoundMetadata(palette: palette, soundId: soundId, title: title, artist: artist, notes: notes, source: source);
+
+/// Add a user tag to a sound
+Future<void>  addTag({required PlatformInt64 palette , required PlatformInt64 soundId , required String tag }) => AudioPalette.instance.api.crateApiAddTag(palette: palette, soundId: soundId, tag: tag);
+
+/// Remove a tag from a sound
+Future<void>  removeTag({required PlatformInt64 palette , required PlatformInt64 soundId , required String tag }) => AudioPalette.instance.api.crateApiRemoveTag(palette: palette, soundId: soundId, tag: tag);
+
+/// Get the tags of a sound (user tags and predicted labels)
+Future<List<SoundTag>>  getTags({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetTags(palette: palette, soundId: soundId);
+
+/// List all tags with usage counts
+Future<List<TagSummary>>  getAllTags({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetAllTags(palette: palette);
+
+/// Rename a tag on every sound
+Future<void>  renameTag({required PlatformInt64 palette , required String oldName , required String newName }) => AudioPalette.instance.api.crateApiRenameTag(palette: palette, oldName: oldName, newName: newName);
+
+/// Delete a tag from the library
+Future<void>  deleteTag({required PlatformInt64 palette , required String name }) => AudioPalette.instance.api.crateApiDeleteTag(palette: palette, name: name);
+
+/// Find sounds with any (or all, if `match_all`) of the given tags
+Future<List<SoundRecord>>  findByTags({required PlatformInt64 palette , required List<String> tags , required bool matchAll , required double minConfidence }) => AudioPalette.instance.api.crateApiFindByTags(palette: palette, tags: tags, matchAll: matchAll, minConfidence: minConfidence);
+
+/// Recent searches, newest first, with the parameters to re-run them
+///
+/// Selection-based searches (`find_similar_from_samples`) have no query
+/// file to re-run and are not recorded.
+Future<List<RecentSearch>>  getRecentSearches({required PlatformInt64 palette , required PlatformInt64 limit }) => AudioPalette.instance.api.crateApiGetRecentSearches(palette: palette, limit: limit);
+
+/// Remove one entry from the recent searches
+Future<void>  deleteRecentSearch({required PlatformInt64 palette , required PlatformInt64 searchId }) => AudioPalette.instance.api.crateApiDeleteRecentSearch(palette: palette, searchId: searchId);
+
+/// Forget all recent searches
+Future<void>  clearSearchHistory({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiClearSearchHistory(palette: palette);
+
+/// Recent changes (adds, removals, tag and metadata edits), newest first
+Future<List<HistoryEntry>>  getHistory({required PlatformInt64 palette , required PlatformInt64 limit }) => AudioPalette.instance.api.crateApiGetHistory(palette: palette, limit: limit);
+
+/// Reverse a change from the history
+Future<HistoryEntry>  undoChange({required PlatformInt64 palette , required PlatformInt64 entryId }) => AudioPalette.instance.api.crateApiUndoChange(palette: palette, entryId: entryId);
+
+/// Reverse the most recent change not yet undone (None if there is none)
+Future<HistoryEntry?>  undoLastChange({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiUndoLastChange(palette: palette);
+
+/// Attach a custom property to a sound, replacing any previous value
+Future<void>  setSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key , required String value }) => AudioPalette.instance.api.crateApiSetSoundProperty(palette: palette, soundId: soundId, key: key, value: value);
+
+/// Get one custom property of a sound
+Future<String?>  getSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key }) => AudioPalette.instance.api.crateApiGetSoundProperty(palette: palette, soundId: soundId, key: key);
+
+/// Get all custom properties of a sound, sorted by key
+Future<List<SoundProperty>>  getSoundProperties({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetSoundProperties(palette: palette, soundId: soundId);
+
+/// Remove a custom property from a sound
+Future<void>  removeSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key }) => AudioPalette.instance.api.crateApiRemoveSoundProperty(palette: palette, soundId: soundId, key: key);
+
+/// Find sounds by a custom property value (e.g. an external id)
+Future<List<SoundRecord>>  findByProperty({required PlatformInt64 palette , required String key , required String value }) => AudioPalette.instance.api.crateApiFindByProperty(palette: palette, key: key, value: value);
+
+/// Create an empty collection, returning its id
+Future<PlatformInt64>  createCollection({required PlatformInt64 palette , required String name , required String description }) => AudioPalette.instance.api.crateApiCreateCollection(palette: palette, name: name, description: description);
+
+/// Rename a collection and change its description
+Future<void>  updateCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required String name , required String description }) => AudioPalette.instance.api.crateApiUpdateCollection(palette: palette, collectionId: collectionId, name: name, description: description);
+
+/// Delete a collection (its sounds stay in the library)
+Future<void>  deleteCollection({required PlatformInt64 palette , required PlatformInt64 collectionId }) => AudioPalette.instance.api.crateApiDeleteCollection(palette: palette, collectionId: collectionId);
+
+/// List all collections with their item counts
+Future<List<Collection>>  getCollections({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetCollections(palette: palette);
+
+/// Append whole sounds to a collection, returning the new item ids
+Future<Int64List>  addSoundsToCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required Int64List soundIds }) => AudioPalette.instance.api.crateApiAddSoundsToCollection(palette: palette, collectionId: collectionId, soundIds: soundIds);
+
+/// Append the matched segments of search results to a collection,
+/// returning the new item ids
+Future<Int64List>  addMatchesToCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required List<MatchResult> matches }) => AudioPalette.instance.api.crateApiAddMatchesToCollection(palette: palette, collectionId: collectionId, matches: matches);
+
+/// Remove an item from its collection
+Future<void>  removeFromCollection({required PlatformInt64 palette , required PlatformInt64 itemId }) => AudioPalette.instance.api.crateApiRemoveFromCollection(palette: palette, itemId: itemId);
+
+/// Move a collection item to a new index
+Future<void>  moveCollectionItem({required PlatformInt64 palette , required PlatformInt64 itemId , required BigInt position }) => AudioPalette.instance.api.crateApiMoveCollectionItem(palette: palette, itemId: itemId, position: position);
 
-/// Search sounds by filename
-Future<List<SoundRecord>> searchSounds({required String query}) =>
-    AudioPalette.instance.api.crateApiSearchSounds(query: query);
+/// Put all items of a collection in the given order
+Future<void>  reorderCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required Int64List itemIds }) => AudioPalette.instance.api.crateApiReorderCollection(palette: palette, collectionId: collectionId, itemIds: itemIds);
+
+/// Get the items of a collection in order
+Future<List<CollectionItem>>  getCollectionItems({required PlatformInt64 palette , required PlatformInt64 collectionId }) => AudioPalette.instance.api.crateApiGetCollectionItems(palette: palette, collectionId: collectionId);
+
+/// Save a smart collection (a query evaluated whenever it is opened),
+/// returning its id
+///
+/// The reference file, if any, is fingerprinted now; it need not exist
+/// later.
+Future<PlatformInt64>  createSmartCollection({required PlatformInt64 palette , required String name , required SmartQuery query }) => AudioPalette.instance.api.crateApiCreateSmartCollection(palette: palette, name: name, query: query);
+
+/// Rename a smart collection or change its query
+///
+/// An unchanged reference path keeps the stored fingerprint.
+Future<void>  updateSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required String name , required SmartQuery query }) => AudioPalette.instance.api.crateApiUpdateSmartCollection(palette: palette, collectionId: collectionId, name: name, query: query);
+
+/// Delete a smart collection
+Future<void>  deleteSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId }) => AudioPalette.instance.api.crateApiDeleteSmartCollection(palette: palette, collectionId: collectionId);
+
+/// List all smart collections
+Future<List<SmartCollection>>  getSmartCollections({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiGetSmartCollections(palette: palette);
+
+/// Evaluate a smart collection against the palette as it is now
+Future<List<MatchResult>>  evaluateSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId }) => AudioPalette.instance.api.crateApiEvaluateSmartCollection(palette: palette, collectionId: collectionId);
+
+/// Mark a region of a sound (times in seconds), returning its id
+Future<PlatformInt64>  addRegion({required PlatformInt64 palette , required PlatformInt64 soundId , required double startTime , required double endTime , required String label , required String color }) => AudioPalette.instance.api.crateApiAddRegion(palette: palette, soundId: soundId, startTime: startTime, endTime: endTime, label: label, color: color);
+
+/// Move, relabel or recolour a region
+Future<void>  updateRegion({required PlatformInt64 palette , required PlatformInt64 regionId , required double startTime , required double endTime , required String label , required String color }) => AudioPalette.instance.api.crateApiUpdateRegion(palette: palette, regionId: regionId, startTime: startTime, endTime: endTime, label: label, color: color);
+
+/// Delete a region
+Future<void>  deleteRegion({required PlatformInt64 palette , required PlatformInt64 regionId }) => AudioPalette.instance.api.crateApiDeleteRegion(palette: palette, regionId: regionId);
+
+/// Get the regions of a sound in time order
+Future<List<Region>>  getRegions({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetRegions(palette: palette, soundId: soundId);
+
+/// Regions as match results, to pass to the MIDI/CSV/marker exports
+Future<List<MatchResult>>  getRegionMatches({required PlatformInt64 palette , required Int64List regionIds }) => AudioPalette.instance.api.crateApiGetRegionMatches(palette: palette, regionIds: regionIds);
+
+/// Abort every similarity search running on the palette; they fail with
+/// "Operation cancelled" (streams end with that error). Searches started
+/// afterwards run normally.
+void  cancelSearches({required PlatformInt64 palette }) => AudioPalette.instance.api.crateApiCancelSearches(palette: palette);
 
 /// Find similar sounds to a query file
-Future<List<MatchResult>> findSimilar(
-        {required String queryPath,
-        required double threshold,
-        required BigInt maxResults}) =>
-    AudioPalette.instance.api.crateApiFindSimilar(
-        queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+///
+/// Repeating a search (or asking for fewer results) while the library is
+/// unchanged is answered from the palette's query cache.
+Future<List<MatchResult>>  findSimilar({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilar(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// One page (`offset`, `limit`) of `find_similar`'s ranking; pages of an
+/// unchanged library come from the query cache where possible
+Future<List<MatchResult>>  findSimilarPage({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt offset , required BigInt limit }) => AudioPalette.instance.api.crateApiFindSimilarPage(palette: palette, queryPath: queryPath, threshold: threshold, offset: offset, limit: limit);
+
+/// Find sounds similar to one already in the palette ("more like this"),
+/// using its stored fingerprint instead of decoding the file again
+Future<List<MatchResult>>  findSimilarById({required PlatformInt64 palette , required PlatformInt64 soundId , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarById(palette: palette, soundId: soundId, threshold: threshold, maxResults: maxResults);
+
+/// Find sounds that literally contain the query file's audio (same
+/// recording, possibly at another level), with the sample-accurate offset
+/// and correlation of each occurrence
+///
+/// Decodes every sound at least as long as the query, so it is much slower
+/// than a similarity search. `min_correlation` of 0.9 suits most uses.
+Future<List<ContainmentMatch>>  findContaining({required PlatformInt64 palette , required String queryPath , required double minCorrelation , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindContaining(palette: palette, queryPath: queryPath, minCorrelation: minCorrelation, maxResults: maxResults);
+
+/// Find shorter sounds (loops) that repeat through a long query file,
+/// e.g. the two-bar loops a phrase was built from, with the share of the
+/// query each one covers
+///
+/// A part of the query matches a loop when it scores at least
+/// `threshold`; loops covering less than `min_coverage` (0-1) are left out.
+Future<List<LoopMatch>>  findLoopSources({required PlatformInt64 palette , required String queryPath , required double threshold , required double minCoverage , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindLoopSources(palette: palette, queryPath: queryPath, threshold: threshold, minCoverage: minCoverage, maxResults: maxResults);
+
+/// Find sounds containing the melody hummed or sung in the query file
+///
+/// Only sounds with a stored pitch curve (computed at indexing) are
+/// searched; match times span the melody in each result.
+Future<List<MatchResult>>  findMelody({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindMelody(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// Find sounds matching both words and a sound, e.g. "vinyl kick" like
+/// this file, in one ranking
+///
+/// `text_weight` (0-1) is the share of the score given to text relevance
+/// (0.3 is a good default); the rest is audio similarity.
+Future<List<MatchResult>>  findSimilarWithText({required PlatformInt64 palette , required String queryPath , required String text , required double textWeight , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarWithText(palette: palette, queryPath: queryPath, text: text, textWeight: textWeight, threshold: threshold, maxResults: maxResults);
+
+/// Rank the files of a folder by similarity to the query without adding
+/// them to the library, e.g. to triage a sample pack before indexing it
+///
+/// Only files with one of `extensions` (all supported formats when empty)
+/// are considered. Fingerprints are kept for the session, so another
+/// query against the same folder only decodes files changed since.
+Future<List<FolderMatch>>  findSimilarInDirectory({required PlatformInt64 palette , required String queryPath , required String directory , required List<String> extensions , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarInDirectory(palette: palette, queryPath: queryPath, directory: directory, extensions: extensions, threshold: threshold, maxResults: maxResults);
 
 /// Find similar sounds with segment matching (returns exact time ranges)
-Future<List<MatchResult>> findSimilarWithSegments(
-        {required String queryPath,
-        required double threshold,
-        required BigInt maxResults}) =>
-    AudioPalette.instance.api.crateApiFindSimilarWithSegments(
-        queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+Future<List<MatchResult>>  findSimilarWithSegments({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarWithSegments(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// Find the marked regions most similar to a query file
+Future<List<MatchResult>>  findSimilarInRegions({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarInRegions(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// Find similar sounds among those matching `filter`, e.g. only loops
+/// at 118-126 BPM in a key compatible with A minor
+Future<List<MatchResult>>  findSimilarMatching({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults , required SoundQuery filter }) => AudioPalette.instance.api.crateApiFindSimilarMatching(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults, filter: filter);
+
+/// Find sounds like every file in `positive_paths` and unlike those in
+/// `negative_paths`, for when one example alone is ambiguous
+///
+/// The example files themselves are left out of the results.
+Future<List<MatchResult>>  findSimilarToExamples({required PlatformInt64 palette , required List<String> positivePaths , required List<String> negativePaths , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarToExamples(palette: palette, positivePaths: positivePaths, negativePaths: negativePaths, threshold: threshold, maxResults: maxResults);
+
+/// Score batch searches on the GPU (for very large libraries), or go
+/// back to the CPU with `enabled` false
+///
+/// Uploads the library's fingerprints once; sounds added later are
+/// searchable straight away and the library is uploaded again once enough
+/// of it changed. Returns whether a GPU is in use: false when none
+/// is usable (or the crate was built without the `gpu` feature), in which
+/// case batches keep scoring on the CPU.
+Future<bool>  enableGpuScoring({required PlatformInt64 palette , required bool enabled }) => AudioPalette.instance.api.crateApiEnableGpuScoring(palette: palette, enabled: enabled);
+
+/// Find similar sounds for many query files (or ranges of them) at once,
+/// e.g. to match a whole folder against the palette
+///
+/// Results line up with `queries`; a file that can't be decoded reports
+/// its error instead of failing the batch. With `enable_gpu_scoring` on,
+/// candidates are shortlisted on the GPU.
+Future<List<BatchMatches>>  findSimilarBatch({required PlatformInt64 palette , required List<BatchQuery> queries , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarBatch(palette: palette, queries: queries, threshold: threshold, maxResults: maxResults);
+
+/// Notes of a MIDI clip as time regions, by start time, e.g. to preview
+/// what `find_similar_from_midi` will search
+Future<List<MidiRegion>>  readMidiRegions({required String midiPath }) => AudioPalette.instance.api.crateApiReadMidiRegions(midiPath: midiPath);
+
+/// Search each region of `audio_path` marked by a note of a MIDI clip
+/// drawn against it in a DAW
+///
+/// Results line up with `read_midi_regions`, as in `find_similar_batch`.
+Future<List<BatchMatches>>  findSimilarFromMidi({required PlatformInt64 palette , required String midiPath , required String audioPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarFromMidi(palette: palette, midiPath: midiPath, audioPath: audioPath, threshold: threshold, maxResults: maxResults);
+
+/// Search options tuned for a kind of material (drum one-shots, melodic
+/// loops, ambience, dialogue), to pass to `find_similar_with_options` as
+/// they are or adjusted
+SearchOptions  searchPresetOptions({required SearchPreset preset , required BigInt maxResults }) => AudioPalette.instance.api.crateApiSearchPresetOptions(preset: preset, maxResults: maxResults);
+
+/// Find similar sounds with segment matching using explicit search options
+Future<List<MatchResult>>  findSimilarWithOptions({required PlatformInt64 palette , required String queryPath , required SearchOptions options }) => AudioPalette.instance.api.crateApiFindSimilarWithOptions(palette: palette, queryPath: queryPath, options: options);
+
+/// `find_similar`, streaming results as they are found
+///
+/// Each event is the best results so far, best first; the last event is
+/// the final list (empty if nothing matched).
+Stream<List<MatchResult>>  findSimilarStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarStream(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// Start matching live audio (e.g. from the microphone) against the
+/// palette; returns the handle `push_live_audio` and `stop_live_query` take
+///
+/// The last `window_seconds` of audio are searched each time another
+/// second has been pushed, and each search's best matches are sent to
+/// the stream. Silence isn't searched, so the last matches stand.
+Stream<List<MatchResult>>  startLiveQuery({required PlatformInt64 palette , required int sampleRate , required double windowSeconds , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiStartLiveQuery(palette: palette, sampleRate: sampleRate, windowSeconds: windowSeconds, threshold: threshold, maxResults: maxResults);
+
+/// Add recorded mono samples to a live query, searching its window when due
+///
+/// If the previous search is still running, the window is skipped rather
+/// than queued, so matches never lag behind the audio.
+Future<void>  pushLiveAudio({required PlatformInt64 session , required List<double> samples }) => AudioPalette.instance.api.crateApiPushLiveAudio(session: session, samples: samples);
+
+/// End a live query; its stream receives no further matches
+void  stopLiveQuery({required PlatformInt64 session }) => AudioPalette.instance.api.crateApiStopLiveQuery(session: session);
+
+/// `find_similar_with_segments`, streaming results as each candidate is
+/// scored (events as in `find_similar_stream`)
+Stream<List<MatchResult>>  findSimilarWithSegmentsStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarWithSegmentsStream(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// `find_similar_in_regions`, streaming results as each region is scored
+/// (events as in `find_similar_stream`)
+Stream<List<MatchResult>>  findSimilarInRegionsStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarInRegionsStream(palette: palette, queryPath: queryPath, threshold: threshold, maxResults: maxResults);
+
+/// `find_similar_with_options`, streaming results as each candidate is
+/// scored (events as in `find_similar_stream`)
+Stream<List<MatchResult>>  findSimilarWithOptionsStream({required PlatformInt64 palette , required String queryPath , required SearchOptions options }) => AudioPalette.instance.api.crateApiFindSimilarWithOptionsStream(palette: palette, queryPath: queryPath, options: options);
 
 /// Find similar sounds from audio samples (for selection-based search)
-Future<List<MatchResult>> findSimilarFromSamples(
-        {required List<double> samples,
-        required int sampleRate,
-        required double threshold,
-        required BigInt maxResults}) =>
-    AudioPalette.instance.api.crateApiFindSimilarFromSamples(
-        samples: samples,
-        sampleRate: sampleRate,
-        threshold: threshold,
-        maxResults: maxResults);
-
-/// Export match results to MIDI file
-Future<void> exportToMidi(
-        {required List<MatchResult> matches,
-        required String outputPath,
-        required int tempoBpm,
-        required int baseNote}) =>
-    AudioPalette.instance.api.crateApiExportToMidi(
-        matches: matches,
-        outputPath: outputPath,
-        tempoBpm: tempoBpm,
-        baseNote: baseNote);
+Future<List<MatchResult>>  findSimilarFromSamples({required PlatformInt64 palette , required List<double> samples , required int sampleRate , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarFromSamples(palette: palette, samples: samples, sampleRate: sampleRate, threshold: threshold, maxResults: maxResults);
+
+/// Find sounds similar to a time selection of a stored sound, decoding
+/// just that range here rather than passing its samples over the bridge
+///
+/// Matches overlapping the selection itself are left out.
+Future<List<MatchResult>>  findSimilarFromRegion({required PlatformInt64 palette , required PlatformInt64 soundId , required double startS , required double endS , required double threshold , required BigInt maxResults }) => AudioPalette.instance.api.crateApiFindSimilarFromRegion(palette: palette, soundId: soundId, startS: startS, endS: endS, threshold: threshold, maxResults: maxResults);
+
+/// Export match results to MIDI file, one track per match named after its
+/// file, with the sound's classified instrument as the instrument name
+///
+/// Note starts and lengths snap to a grid of `quantize_note_value` notes
+/// (4 for quarters through 32 for thirty-seconds; 0 keeps the timing of
+/// the matches), every second grid line pushed later by `swing` of a step.
+/// With `follow_detected_tempo`, the tempo track follows the beats
+/// detected in the first match's sound instead of `tempo_bpm`, for matches
+/// that are regions of one file. `note_mapping` picks each track's channel,
+/// program and note from its instrument (and detected pitch) rather than
+/// `base_note` plus the match's index.
+Future<void>  exportToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm , required int baseNote , required int quantizeNoteValue , required double swing , required bool followDetectedTempo , required MidiNoteMapping noteMapping }) => AudioPalette.instance.api.crateApiExportToMidi(palette: palette, matches: matches, outputPath: outputPath, tempoBpm: tempoBpm, baseNote: baseNote, quantizeNoteValue: quantizeNoteValue, swing: swing, followDetectedTempo: followDetectedTempo, noteMapping: noteMapping);
+
+/// Export the matches found in one long file (that of the first match) as
+/// a MIDI arrangement: each at its original time on a single timeline,
+/// with a track per label (lining up with `matches`, e.g. the palette
+/// sound each was found for); returns how many were placed
+///
+/// With `follow_detected_tempo` the tempo track follows the beats detected
+/// in that file, so the map lines up with the source in a DAW.
+Future<BigInt>  exportArrangementToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required List<String> labels , required String outputPath , required int tempoBpm , required int baseNote , required bool followDetectedTempo }) => AudioPalette.instance.api.crateApiExportArrangementToMidi(palette: palette, matches: matches, labels: labels, outputPath: outputPath, tempoBpm: tempoBpm, baseNote: baseNote, followDetectedTempo: followDetectedTempo);
+
+/// Transcribe the melody of a match (e.g. a found vocal or bass phrase)
+/// to a MIDI file, notes snapped to `quantize_division` steps per beat at
+/// `tempo_bpm` (0 leaves the timing as played), returning the note count
+///
+/// Times are relative to the start of the match. With
+/// `follow_detected_tempo` the tempo track follows the sound's detected
+/// beats instead of `tempo_bpm`, so the grid matches the performance.
+Future<BigInt>  exportMelodyToMidi({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int tempoBpm , required int quantizeDivision , required bool followDetectedTempo }) => AudioPalette.instance.api.crateApiExportMelodyToMidi(palette: palette, result: result, outputPath: outputPath, tempoBpm: tempoBpm, quantizeDivision: quantizeDivision, followDetectedTempo: followDetectedTempo);
+
+/// Transcribe the melody of a match to a MusicXML score for notation
+/// software, returning the note count
+///
+/// A `tempo_bpm` of 0 uses the sound's detected tempo (or 120 BPM when
+/// unknown); the key signature comes from its detected key.
+Future<BigInt>  exportMelodyToMusicxml({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int tempoBpm }) => AudioPalette.instance.api.crateApiExportMelodyToMusicxml(palette: palette, result: result, outputPath: outputPath, tempoBpm: tempoBpm);
+
+/// Export the onsets of each match (or whole sound) as General MIDI drum
+/// hits, one track per match, for a groove template from a sampled break;
+/// returns the number of hits
+///
+/// Hits are classified as kick, snare or hi-hat, snapped to
+/// `quantize_division` steps per beat at `tempo_bpm` (0 leaves the timing
+/// as played) and timed from the start of their match. With
+/// `follow_detected_tempo` the tempo track follows the beats detected in
+/// the first match instead of `tempo_bpm`.
+Future<BigInt>  exportDrumsToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm , required int quantizeDivision , required bool followDetectedTempo }) => AudioPalette.instance.api.crateApiExportDrumsToMidi(palette: palette, matches: matches, outputPath: outputPath, tempoBpm: tempoBpm, quantizeDivision: quantizeDivision, followDetectedTempo: followDetectedTempo);
+
+/// Export the beats detected in a match (or whole sound) as a MIDI click
+/// track, timed from the start of the match, with a tempo track following
+/// them; returns how many beats were written
+///
+/// Every `beats_per_bar`th beat from `first_downbeat` (counted within the
+/// match) is accented.
+Future<BigInt>  exportClickTrackToMidi({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int beatsPerBar , required int firstDownbeat }) => AudioPalette.instance.api.crateApiExportClickTrackToMidi(palette: palette, result: result, outputPath: outputPath, beatsPerBar: beatsPerBar, firstDownbeat: firstDownbeat);
 
 /// Export match results to CSV file
-Future<void> exportToCsv(
-        {required List<MatchResult> matches, required String outputPath}) =>
-    AudioPalette.instance.api
-        .crateApiExportToCsv(matches: matches, outputPath: outputPath);
+Future<void>  exportToCsv({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath }) => AudioPalette.instance.api.crateApiExportToCsv(palette: palette, matches: matches, outputPath: outputPath);
+
+/// Export match results to CSV with a chosen delimiter, columns and
+/// header row
+Future<void>  exportToCsvWithOptions({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required CsvExportOptions options }) => AudioPalette.instance.api.crateApiExportToCsvWithOptions(palette: palette, matches: matches, outputPath: outputPath, options: options);
+
+/// Export match results to a JSON file
+///
+/// With `query_path`, each match also gets per-feature scores against the
+/// query (as in `explain_match`); with `include_fingerprints`, the query's
+/// and each matched range's fingerprints are written too. Either means
+/// fingerprinting every match, which takes a while for long lists.
+Future<void>  exportToJson({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , String? queryPath , required bool includeFingerprints }) => AudioPalette.instance.api.crateApiExportToJson(palette: palette, matches: matches, outputPath: outputPath, queryPath: queryPath, includeFingerprints: includeFingerprints);
 
 /// Export match results to markers file
-Future<void> exportToMarkers(
-        {required List<MatchResult> matches, required String outputPath}) =>
-    AudioPalette.instance.api
-        .crateApiExportToMarkers(matches: matches, outputPath: outputPath);
+Future<void>  exportToMarkers({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath }) => AudioPalette.instance.api.crateApiExportToMarkers(palette: palette, matches: matches, outputPath: outputPath);
+
+/// Export match results as a REAPER region CSV (Region/Marker Manager
+/// import), regions colored by score
+Future<void>  exportToReaperRegions({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath }) => AudioPalette.instance.api.crateApiExportToReaperRegions(palette: palette, matches: matches, outputPath: outputPath);
+
+/// Export match results as an Ableton Live Set at `tempo_bpm`, each match
+/// an audio clip of its source file on a track of its own
+Future<void>  exportToAls({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm }) => AudioPalette.instance.api.crateApiExportToAls(palette: palette, matches: matches, outputPath: outputPath, tempoBpm: tempoBpm);
+
+/// Export match results as a DAWproject file at `tempo_bpm`, each match
+/// an audio clip of its source file on a track of its own
+Future<void>  exportToDawproject({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm }) => AudioPalette.instance.api.crateApiExportToDawproject(palette: palette, matches: matches, outputPath: outputPath, tempoBpm: tempoBpm);
+
+/// Export match results as a cue sheet titled `title`, indexing each
+/// match's position in its source file
+Future<void>  exportToCue({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required String outputPath }) => AudioPalette.instance.api.crateApiExportToCue(palette: palette, matches: matches, title: title, outputPath: outputPath);
+
+/// Export match results as a CMX3600 EDL titled `title` at `fps` frames per
+/// second, for conforming found material into a video edit
+Future<void>  exportToEdl({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required int fps , required String outputPath }) => AudioPalette.instance.api.crateApiExportToEdl(palette: palette, matches: matches, title: title, fps: fps, outputPath: outputPath);
+
+/// Export match results as an M3U playlist, with paths relative to the
+/// playlist's folder if `relative_paths`
+Future<void>  exportToM3U({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required bool relativePaths }) => AudioPalette.instance.api.crateApiExportToM3U(palette: palette, matches: matches, outputPath: outputPath, relativePaths: relativePaths);
+
+/// Export a collection, in order, as an M3U playlist
+Future<void>  exportCollectionToM3U({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required bool relativePaths }) => AudioPalette.instance.api.crateApiExportCollectionToM3U(palette: palette, collectionId: collectionId, outputPath: outputPath, relativePaths: relativePaths);
+
+/// Export match results as an SFZ sampler instrument, each match on
+/// `keys_per_sample` keys from `lowest_note` up, returning how many fit on
+/// the keyboard
+Future<BigInt>  exportToSfz({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int lowestNote , required int keysPerSample }) => AudioPalette.instance.api.crateApiExportToSfz(palette: palette, matches: matches, outputPath: outputPath, lowestNote: lowestNote, keysPerSample: keysPerSample);
+
+/// Export match results as a DecentSampler preset, laid out on the
+/// keyboard as for SFZ, returning how many fit
+Future<BigInt>  exportToDspreset({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int lowestNote , required int keysPerSample , required SamplerEnvelope envelope }) => AudioPalette.instance.api.crateApiExportToDspreset(palette: palette, matches: matches, outputPath: outputPath, lowestNote: lowestNote, keysPerSample: keysPerSample, envelope: envelope);
+
+/// Export up to 16 match results as an Akai MPC drum program named
+/// `name`, one per pad, in the classic pad layout or chromatic; returns
+/// how many were placed
+Future<BigInt>  exportToXpm({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required String name , required bool chromatic }) => AudioPalette.instance.api.crateApiExportToXpm(palette: palette, matches: matches, outputPath: outputPath, name: name, chromatic: chromatic);
+
+/// Export a collection of one-shots (or regions), in order, as an SFZ
+/// sampler instrument
+Future<BigInt>  exportCollectionToSfz({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required int lowestNote , required int keysPerSample }) => AudioPalette.instance.api.crateApiExportCollectionToSfz(palette: palette, collectionId: collectionId, outputPath: outputPath, lowestNote: lowestNote, keysPerSample: keysPerSample);
+
+/// Export a collection of one-shots (or regions), in order, as a
+/// DecentSampler preset
+Future<BigInt>  exportCollectionToDspreset({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required int lowestNote , required int keysPerSample , required SamplerEnvelope envelope }) => AudioPalette.instance.api.crateApiExportCollectionToDspreset(palette: palette, collectionId: collectionId, outputPath: outputPath, lowestNote: lowestNote, keysPerSample: keysPerSample, envelope: envelope);
+
+/// Export a collection of one-shots (or regions), in order, as an Akai
+/// MPC drum program
+Future<BigInt>  exportCollectionToXpm({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required String name , required bool chromatic }) => AudioPalette.instance.api.crateApiExportCollectionToXpm(palette: palette, collectionId: collectionId, outputPath: outputPath, name: name, chromatic: chromatic);
+
+/// Export match results as a zip of WAV clips of their ranges, with JSON
+/// and CSV manifests, returning how many clips were written (matches
+/// whose file can't be decoded are only listed)
+Future<BigInt>  exportToBundle({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath }) => AudioPalette.instance.api.crateApiExportToBundle(palette: palette, matches: matches, outputPath: outputPath);
+
+/// Export match results as a self-contained HTML report titled `title`,
+/// with each match's scores, time range, metadata and tags, and (with
+/// `include_images`) its waveform and spectrogram
+///
+/// Images are drawn from the audio, so a report with them takes a moment
+/// per match; printing the page from a browser gives a PDF version.
+Future<void>  exportReport({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required String outputPath , required bool includeImages }) => AudioPalette.instance.api.crateApiExportReport(palette: palette, matches: matches, title: title, outputPath: outputPath, includeImages: includeImages);
+
+/// Render a spectrogram of a file (or a time range of it) as PNG bytes
+Future<Uint8List>  renderSpectrogramPng({required String filepath , double? startS , double? endS , required int width , required int height , required Colormap colormap , required double minDb , required double maxDb }) => AudioPalette.instance.api.crateApiRenderSpectrogramPng(filepath: filepath, startS: startS, endS: endS, width: width, height: height, colormap: colormap, minDb: minDb, maxDb: maxDb);
 
 /// Remove a sound from the database
-Future<void> removeSound({required PlatformInt64 soundId}) =>
-    AudioPalette.instance.api.crateApiRemoveSound(soundId: soundId);
+Future<void>  removeSound({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiRemoveSound(palette: palette, soundId: soundId);
 
 /// Extract audio fingerprint from file (for debugging/display)
-Future<AudioFingerprintInfo> getFingerprint({required String filepath}) =>
-    AudioPalette.instance.api.crateApiGetFingerprint(filepath: filepath);
+Future<AudioFingerprintInfo>  getFingerprint({required String filepath }) => AudioPalette.instance.api.crateApiGetFingerprint(filepath: filepath);
+
+/// Get the noise floor / SNR estimate for an indexed sound
+/// (None if the sound was indexed before noise estimation existed)
+Future<NoiseInfo?>  getNoiseInfo({required PlatformInt64 palette , required PlatformInt64 soundId }) => AudioPalette.instance.api.crateApiGetNoiseInfo(palette: palette, soundId: soundId);
+
+/// Explain a match: per-feature-group similarity between the query and the
+/// matched time range of the result (e.g. timbre 92%, harmony 40%, energy 85%)
+Future<List<FeatureContribution>>  explainMatch({required String queryPath , required MatchResult result }) => AudioPalette.instance.api.crateApiExplainMatch(queryPath: queryPath, result: result);
 
 /// Compute similarity between two fingerprints (0-100)
-double computeSimilarity({required String fp1Path, required String fp2Path}) =>
-    AudioPalette.instance.api
-        .crateApiComputeSimilarity(fp1Path: fp1Path, fp2Path: fp2Path);
-
-/// Simplified fingerprint info for Flutter
-class AudioFingerprintInfo {
-  final double duration;
-  final double spectralCentroid;
-  final double spectralBandwidth;
-  final double spectralRolloff;
-  final Float64List mfccMean;
-  final Float64List mfccStd;
-
-  const AudioFingerprintInfo({
-    required this.duration,
-    required this.spectralCentroid,
-    required this.spectralBandwidth,
-    required this.spectralRolloff,
-    required this.mfccMean,
-    required this.mfccStd,
-  });
-
-  @override
-  int get hashCode =>
-      duration.hashCode ^
-      spectralCentroid.hashCode ^
-      spectralBandwidth.hashCode ^
-      spectralRolloff.hashCode ^
-      mfccMean.hashCode ^
-      mfccStd.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is AudioFingerprintInfo &&
-          runtimeType == other.runtimeType &&
-          duration == other.duration &&
-          spectralCentroid == other.spectralCentroid &&
-          spectralBandwidth == other.spectralBandwidth &&
-          spectralRolloff == other.spectralRolloff &&
-          mfccMean == other.mfccMean &&
-          mfccStd == other.mfccStd;
-}
+double  computeSimilarity({required String fp1Path , required String fp2Path }) => AudioPalette.instance.api.crateApiComputeSimilarity(fp1Path: fp1Path, fp2Path: fp2Path);
+
+            /// Simplified fingerprint info for Flutter
+class AudioFingerprintInfo  {
+                final double duration;
+final double spectralCentroid;
+final double spectralBandwidth;
+final double spectralRolloff;
+final Float64List mfccMean;
+final Float64List mfccStd;
+
+                const AudioFingerprintInfo({required this.duration ,required this.spectralCentroid ,required this.spectralBandwidth ,required this.spectralRolloff ,required this.mfccMean ,required this.mfccStd ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => duration.hashCode^spectralCentroid.hashCode^spectralBandwidth.hashCode^spectralRolloff.hashCode^mfccMean.hashCode^mfccStd.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AudioFingerprintInfo &&
+                runtimeType == other.runtimeType
+                && duration == other.duration&& spectralCentroid == other.spectralCentroid&& spectralBandwidth == other.spectralBandwidth&& spectralRolloff == other.spectralRolloff&& mfccMean == other.mfccMean&& mfccStd == other.mfccStd;
+        
+            }
+
+/// Noise estimate for a stored sound
+class NoiseInfo  {
+                final double noiseFloorDb;
+final double snrDb;
+/// True if the recording is likely too noisy to be useful
+final bool isNoisy;
+
+                const NoiseInfo({required this.noiseFloorDb ,required this.snrDb ,required this.isNoisy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => noiseFloorDb.hashCode^snrDb.hashCode^isNoisy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoiseInfo &&
+                runtimeType == other.runtimeType
+                && noiseFloorDb == other.noiseFloorDb&& snrDb == other.snrDb&& isNoisy == other.isNoisy;
+        
+            }
+
+/// Search options for Flutter
+class SearchOptions  {
+                final double threshold;
+final BigInt maxResults;
+/// Rerank segment matches with DTW over frame-level MFCCs
+final bool dtwRerank;
+/// Blend weight of the DTW score (0-1)
+final double dtwWeight;
+/// Warp-align segment matches to the query so slower or faster
+/// performances still match, reporting the warped boundaries
+final bool dtwAlign;
+/// Refine match offsets to sample accuracy via cross-correlation
+final bool refineOffsets;
+/// Return every non-overlapping match above the threshold in each file
+/// (e.g. all three hits in a stem containing the query three times)
+/// instead of only the best one
+final bool allSegments;
+/// Spread results over different-sounding options rather than near
+/// copies of the best match (0 disables, up to 1)
+final double diversity;
+/// Feature groups to ignore, e.g. everything but timbre to match
+/// regardless of pitch
+final List<FeatureGroup> ignoredFeatures;
+/// Score points subtracted per dB of SNR below the noisy threshold (0 disables)
+final double snrPenalty;
+/// Scores (and the threshold) as the percentage of the library the
+/// query is less similar to, so 80 means "better than 80% of it"
+final bool calibrateScores;
+/// Library sounds whose family to leave out ("not like these"), e.g.
+/// ones the user already rejected
+final Int64List excludedSounds;
+/// Similarity (0-100) to an excluded sound at which results are left
+/// out (85 is a good default)
+final double exclusionThreshold;
+/// Only score the sounds a quick hash comparison says are close:
+/// much faster on large libraries, occasionally misses a match
+final bool lshPrefilter;
+/// Most decoded audio a segment search holds at once, in MB (0 for
+/// the default of 256); lower it on devices short of memory
+final BigInt memoryBudgetMb;
+/// Sliding-window length in seconds when scanning files without
+/// stored segments (0 uses the query's length)
+final double segmentWindowS;
+/// Overlap of consecutive windows, 0 to 0.95 (0.75 is the usual):
+/// more gives finer match positions but takes longer
+final double segmentOverlap;
+/// Most windows per file before the hop widens (50 is the usual, 0
+/// for no cap); results report the resolution each match got
+final BigInt maxSegmentWindows;
+
+                const SearchOptions({required this.threshold ,required this.maxResults ,required this.dtwRerank ,required this.dtwWeight ,required this.dtwAlign ,required this.refineOffsets ,required this.allSegments ,required this.diversity ,required this.ignoredFeatures ,required this.snrPenalty ,required this.calibrateScores ,required this.excludedSounds ,required this.exclusionThreshold ,required this.lshPrefilter ,required this.memoryBudgetMb ,required this.segmentWindowS ,required this.segmentOverlap ,required this.maxSegmentWindows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => threshold.hashCode^maxResults.hashCode^dtwRerank.hashCode^dtwWeight.hashCode^dtwAlign.hashCode^refineOffsets.hashCode^allSegments.hashCode^diversity.hashCode^ignoredFeatures.hashCode^snrPenalty.hashCode^calibrateScores.hashCode^excludedSounds.hashCode^exclusionThreshold.hashCode^lshPrefilter.hashCode^memoryBudgetMb.hashCode^segmentWindowS.hashCode^segmentOverlap.hashCode^maxSegmentWindows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchOptions &&
+                runtimeType == other.runtimeType
+                && threshold == other.threshold&& maxResults == other.maxResults&& dtwRerank == other.dtwRerank&& dtwWeight == other.dtwWeight&& dtwAlign == other.dtwAlign&& refineOffsets == other.refineOffsets&& allSegments == other.allSegments&& diversity == other.diversity&& ignoredFeatures == other.ignoredFeatures&& snrPenalty == other.snrPenalty&& calibrateScores == other.calibrateScores&& excludedSounds == other.excludedSounds&& exclusionThreshold == other.exclusionThreshold&& lshPrefilter == other.lshPrefilter&& memoryBudgetMb == other.memoryBudgetMb&& segmentWindowS == other.segmentWindowS&& segmentOverlap == other.segmentOverlap&& maxSegmentWindows == other.maxSegmentWindows;
+        
+            }
+            
\ No newline at end of file
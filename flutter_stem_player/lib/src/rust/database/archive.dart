@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Result of an export
+class ArchiveSummary  {
+                final BigInt sounds;
+final BigInt audioFiles;
+
+                const ArchiveSummary({required this.sounds ,required this.audioFiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sounds.hashCode^audioFiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ArchiveSummary &&
+                runtimeType == other.runtimeType
+                && sounds == other.sounds&& audioFiles == other.audioFiles;
+        
+            }
+
+/// Result of an import
+class ImportSummary  {
+                /// Sounds new to this library
+final BigInt added;
+/// Sounds already indexed under the same path or content hash
+final BigInt merged;
+/// Audio files extracted from the archive
+final BigInt audioFiles;
+
+                const ImportSummary({required this.added ,required this.merged ,required this.audioFiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => added.hashCode^merged.hashCode^audioFiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportSummary &&
+                runtimeType == other.runtimeType
+                && added == other.added&& merged == other.merged&& audioFiles == other.audioFiles;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,98 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Progress of a `cluster_library` run
+class ClusterProgress  {
+                /// Assignment pass running (the last one once finished)
+final BigInt iteration;
+final BigInt maxIterations;
+final bool finished;
+
+                const ClusterProgress({required this.iteration ,required this.maxIterations ,required this.finished ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => iteration.hashCode^maxIterations.hashCode^finished.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClusterProgress &&
+                runtimeType == other.runtimeType
+                && iteration == other.iteration&& maxIterations == other.maxIterations&& finished == other.finished;
+        
+            }
+
+/// Outcome of `cluster_library`
+class ClusteringSummary  {
+                final BigInt clusters;
+/// Sounds assigned a cluster (those with a fingerprint)
+final BigInt sounds;
+/// Assignment passes run; below the maximum means it converged
+final BigInt iterations;
+
+                const ClusteringSummary({required this.clusters ,required this.sounds ,required this.iterations ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => clusters.hashCode^sounds.hashCode^iterations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClusteringSummary &&
+                runtimeType == other.runtimeType
+                && clusters == other.clusters&& sounds == other.sounds&& iterations == other.iterations;
+        
+            }
+
+/// A cluster as listed for browsing
+class SoundCluster  {
+                final PlatformInt64 id;
+final PlatformInt64 size;
+/// Member nearest the cluster centre when it was computed
+final SoundRecord? representative;
+/// Proposed name: the tag or filename word most members share
+final String? label;
+
+                const SoundCluster({required this.id ,required this.size ,this.representative ,this.label ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^size.hashCode^representative.hashCode^label.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundCluster &&
+                runtimeType == other.runtimeType
+                && id == other.id&& size == other.size&& representative == other.representative&& label == other.label;
+        
+            }
+            
\ No newline at end of file
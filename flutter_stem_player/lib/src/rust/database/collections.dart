@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A collection with its item count
+class Collection  {
+                final PlatformInt64 id;
+final String name;
+final String description;
+final String dateCreated;
+final PlatformInt64 itemCount;
+
+                const Collection({required this.id ,required this.name ,required this.description ,required this.dateCreated ,required this.itemCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^description.hashCode^dateCreated.hashCode^itemCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Collection &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& description == other.description&& dateCreated == other.dateCreated&& itemCount == other.itemCount;
+        
+            }
+
+/// An entry of a collection, in order
+class CollectionItem  {
+                final PlatformInt64 id;
+final PlatformInt64 position;
+final double? startTime;
+final double? endTime;
+final SoundRecord sound;
+
+                const CollectionItem({required this.id ,required this.position ,this.startTime ,this.endTime ,required this.sound ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^position.hashCode^startTime.hashCode^endTime.hashCode^sound.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CollectionItem &&
+                runtimeType == other.runtimeType
+                && id == other.id&& position == other.position&& startTime == other.startTime&& endTime == other.endTime&& sound == other.sound;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A recorded change, newest first in `get_history`
+class HistoryEntry  {
+                final PlatformInt64 id;
+final HistoryOperation operation;
+final PlatformInt64 soundId;
+/// File name of the sound at the time (kept after it is removed)
+final String filename;
+final String timestamp;
+final bool undone;
+
+                const HistoryEntry({required this.id ,required this.operation ,required this.soundId ,required this.filename ,required this.timestamp ,required this.undone ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^operation.hashCode^soundId.hashCode^filename.hashCode^timestamp.hashCode^undone.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryEntry &&
+                runtimeType == other.runtimeType
+                && id == other.id&& operation == other.operation&& soundId == other.soundId&& filename == other.filename&& timestamp == other.timestamp&& undone == other.undone;
+        
+            }
+
+/// Kind of change an entry records
+enum HistoryOperation {
+                    add,
+remove,
+retag,
+editMetadata,
+                    ;
+                    
+                }
+            
\ No newline at end of file
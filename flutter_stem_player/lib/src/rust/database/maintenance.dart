@@ -0,0 +1,112 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Result of `check_integrity`
+class IntegrityReport  {
+                /// Problems reported by SQLite's own check (empty when the file is sound)
+final List<String> sqliteErrors;
+/// Sounds that have no fingerprint, so similarity search can't find them
+final Int64List missingFingerprints;
+/// Sounds whose stored fingerprint can't be decoded
+final Int64List unreadableFingerprints;
+/// Fingerprint rows whose sound no longer exists
+final Int64List orphanedFingerprints;
+
+                const IntegrityReport({required this.sqliteErrors ,required this.missingFingerprints ,required this.unreadableFingerprints ,required this.orphanedFingerprints ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sqliteErrors.hashCode^missingFingerprints.hashCode^unreadableFingerprints.hashCode^orphanedFingerprints.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IntegrityReport &&
+                runtimeType == other.runtimeType
+                && sqliteErrors == other.sqliteErrors&& missingFingerprints == other.missingFingerprints&& unreadableFingerprints == other.unreadableFingerprints&& orphanedFingerprints == other.orphanedFingerprints;
+        
+            }
+
+/// Progress of an `optimize` run
+class OptimizeProgress  {
+                /// Step currently running (the last step once finished)
+final OptimizeStep step;
+/// Steps completed so far
+final BigInt completed;
+final BigInt total;
+final bool finished;
+
+                const OptimizeProgress({required this.step ,required this.completed ,required this.total ,required this.finished ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => step.hashCode^completed.hashCode^total.hashCode^finished.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OptimizeProgress &&
+                runtimeType == other.runtimeType
+                && step == other.step&& completed == other.completed&& total == other.total&& finished == other.finished;
+        
+            }
+
+/// One step of `optimize`, in the order they run
+enum OptimizeStep {
+                    /// Refresh the query planner's statistics
+analyze,
+/// Rebuild every index
+reindex,
+/// Merge the full-text index segments
+searchIndex,
+/// Rewrite the file without free pages
+vacuum,
+                    ;
+                    
+                }
+
+/// Database size before and after `optimize`, in bytes
+class OptimizeSummary  {
+                final PlatformInt64 sizeBefore;
+final PlatformInt64 sizeAfter;
+
+                const OptimizeSummary({required this.sizeBefore ,required this.sizeAfter ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sizeBefore.hashCode^sizeAfter.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OptimizeSummary &&
+                runtimeType == other.runtimeType
+                && sizeBefore == other.sizeBefore&& sizeAfter == other.sizeAfter;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What to do with a sound both palettes contain
+enum ConflictPolicy {
+                    /// Keep this palette's metadata and fingerprint; add the other's tags
+keepExisting,
+/// Take the other palette's metadata (title, artist, notes, source,
+/// rating, tempo, key) and fingerprint, and add its tags
+preferIncoming,
+/// Leave the sound exactly as it is here
+skip,
+                    ;
+                    
+                }
+
+/// Result of a merge
+class MergeSummary  {
+                /// Sounds new to this palette
+final BigInt added;
+/// Sounds both palettes contain, merged according to the policy
+final BigInt merged;
+/// Sounds both palettes contain, left untouched (`ConflictPolicy::Skip`)
+final BigInt skipped;
+/// Collections created or extended
+final BigInt collections;
+/// Categories new to this palette
+final BigInt categories;
+
+                const MergeSummary({required this.added ,required this.merged ,required this.skipped ,required this.collections ,required this.categories ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => added.hashCode^merged.hashCode^skipped.hashCode^collections.hashCode^categories.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MergeSummary &&
+                runtimeType == other.runtimeType
+                && added == other.added&& merged == other.merged&& skipped == other.skipped&& collections == other.collections&& categories == other.categories;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One property of a sound
+class SoundProperty  {
+                final String key;
+final String value;
+
+                const SoundProperty({required this.key ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundProperty &&
+                runtimeType == other.runtimeType
+                && key == other.key&& value == other.value;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,103 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One page of a listing, with the size of the whole listing
+class SoundPage  {
+                final List<SoundRecord> sounds;
+final PlatformInt64 total;
+
+                const SoundPage({required this.sounds ,required this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sounds.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundPage &&
+                runtimeType == other.runtimeType
+                && sounds == other.sounds&& total == other.total;
+        
+            }
+
+/// Structured library query
+///
+/// Unset fields don't filter. Built with chained calls, e.g. WAV one-shots
+/// between 0.1 and 2 seconds at 44.1 kHz or more, in stereo:
+///
+/// ```ignore
+/// SoundQuery::new().format("wav").duration(0.1, 2.0).min_sample_rate(44100).channels(2)
+/// ```
+class SoundQuery  {
+                /// Free text matched like `search` (prefix terms, all required)
+final String? text;
+/// File formats / extensions, any of which may match (case-insensitive)
+final List<String> formats;
+final double? minDuration;
+final double? maxDuration;
+final int? minSampleRate;
+final int? maxSampleRate;
+final int? channels;
+final int? minRating;
+final double? minBpm;
+final double? maxBpm;
+/// Canonical key names (`MusicalKey::name`), any of which may match
+final List<String> keys;
+/// Tags (including classifier categories such as "kick"), any of which may match
+final List<String> tags;
+/// Confidence a tag needs to count
+final double minTagConfidence;
+final SoundSort sort;
+final bool descending;
+final PlatformInt64 offset;
+/// None for all remaining rows
+final PlatformInt64? limit;
+
+                const SoundQuery({this.text ,required this.formats ,this.minDuration ,this.maxDuration ,this.minSampleRate ,this.maxSampleRate ,this.channels ,this.minRating ,this.minBpm ,this.maxBpm ,required this.keys ,required this.tags ,required this.minTagConfidence ,required this.sort ,required this.descending ,required this.offset ,this.limit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^formats.hashCode^minDuration.hashCode^maxDuration.hashCode^minSampleRate.hashCode^maxSampleRate.hashCode^channels.hashCode^minRating.hashCode^minBpm.hashCode^maxBpm.hashCode^keys.hashCode^tags.hashCode^minTagConfidence.hashCode^sort.hashCode^descending.hashCode^offset.hashCode^limit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundQuery &&
+                runtimeType == other.runtimeType
+                && text == other.text&& formats == other.formats&& minDuration == other.minDuration&& maxDuration == other.maxDuration&& minSampleRate == other.minSampleRate&& maxSampleRate == other.maxSampleRate&& channels == other.channels&& minRating == other.minRating&& minBpm == other.minBpm&& maxBpm == other.maxBpm&& keys == other.keys&& tags == other.tags&& minTagConfidence == other.minTagConfidence&& sort == other.sort&& descending == other.descending&& offset == other.offset&& limit == other.limit;
+        
+            }
+
+/// Sort key for library listings
+enum SoundSort {
+                    /// Filename, case-insensitive
+name,
+dateAdded,
+duration,
+rating,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A marked section of a sound
+class Region  {
+                final PlatformInt64 id;
+final PlatformInt64 soundId;
+/// Bounds in seconds from the start of the file
+final double startTime;
+final double endTime;
+final String label;
+/// Display colour chosen by the client (e.g. "#ff8800"); empty for the default
+final String color;
+
+                const Region({required this.id ,required this.soundId ,required this.startTime ,required this.endTime ,required this.label ,required this.color ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^soundId.hashCode^startTime.hashCode^endTime.hashCode^label.hashCode^color.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Region &&
+                runtimeType == other.runtimeType
+                && id == other.id&& soundId == other.soundId&& startTime == other.startTime&& endTime == other.endTime&& label == other.label&& color == other.color;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A recorded search, newest first in `get_recent_searches`
+class RecentSearch  {
+                final PlatformInt64 id;
+final SearchKind kind;
+/// Query text, tag list, query file path or sound id
+final String query;
+/// Remaining arguments as a JSON object (threshold, max_results, filter, ...)
+final String parameters;
+final PlatformInt64 resultCount;
+final String timestamp;
+
+                const RecentSearch({required this.id ,required this.kind ,required this.query ,required this.parameters ,required this.resultCount ,required this.timestamp ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^kind.hashCode^query.hashCode^parameters.hashCode^resultCount.hashCode^timestamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecentSearch &&
+                runtimeType == other.runtimeType
+                && id == other.id&& kind == other.kind&& query == other.query&& parameters == other.parameters&& resultCount == other.resultCount&& timestamp == other.timestamp;
+        
+            }
+
+/// Which search was run (one per API search function)
+enum SearchKind {
+                    /// `search_sounds`
+text,
+/// `find_by_tags`
+tags,
+/// `find_similar`
+similar,
+/// `find_similar_with_segments`
+similarSegments,
+/// `find_similar_in_regions`
+similarInRegions,
+/// `find_similar_matching`
+similarMatching,
+/// `find_similar_with_options`
+similarWithOptions,
+/// `find_similar_to_examples`
+similarToExamples,
+/// `find_similar_by_id`
+similarToSound,
+/// `find_containing`
+containment,
+/// `find_melody`
+melody,
+/// `find_similar_with_text`
+similarWithText,
+/// `find_similar_in_directory`
+directory,
+/// `find_loop_sources`
+loopSources,
+/// `find_similar_from_region`
+similarToRegion,
+                    ;
+                    
+                }
+            
\ No newline at end of file
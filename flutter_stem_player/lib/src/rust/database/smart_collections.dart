@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'query.dart';
+
+
+            
+
+            
+
+            /// A saved smart collection
+class SmartCollection  {
+                final PlatformInt64 id;
+final String name;
+final SmartQuery query;
+final String dateCreated;
+
+                const SmartCollection({required this.id ,required this.name ,required this.query ,required this.dateCreated ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^query.hashCode^dateCreated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SmartCollection &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& query == other.query&& dateCreated == other.dateCreated;
+        
+            }
+
+/// What a smart collection contains
+class SmartQuery  {
+                /// Metadata filter; its sort orders results without a reference, and
+/// its offset/limit apply to the final results
+final SoundQuery filter;
+/// Tags any of which members must have (empty for no tag filter)
+final List<String> tags;
+final double minTagConfidence;
+/// File the reference fingerprint was taken from (None for no similarity filter)
+final String? referencePath;
+/// Minimum similarity to the reference (0-100)
+final double threshold;
+
+                const SmartQuery({required this.filter ,required this.tags ,required this.minTagConfidence ,this.referencePath ,required this.threshold ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filter.hashCode^tags.hashCode^minTagConfidence.hashCode^referencePath.hashCode^threshold.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SmartQuery &&
+                runtimeType == other.runtimeType
+                && filter == other.filter&& tags == other.tags&& minTagConfidence == other.minTagConfidence&& referencePath == other.referencePath&& threshold == other.threshold;
+        
+            }
+            
\ No newline at end of file
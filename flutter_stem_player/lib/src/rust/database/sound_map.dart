@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A sound's position on the map; both coordinates run 0-1, with the
+/// shorter side of the layout centred
+class MapPoint  {
+                final PlatformInt64 soundId;
+final double x;
+final double y;
+
+                const MapPoint({required this.soundId ,required this.x ,required this.y ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => soundId.hashCode^x.hashCode^y.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MapPoint &&
+                runtimeType == other.runtimeType
+                && soundId == other.soundId&& x == other.x&& y == other.y;
+        
+            }
+
+/// Progress of a `compute_sound_map` run
+class MapProgress  {
+                /// Layout step reached (the last one once finished)
+final BigInt iteration;
+final BigInt maxIterations;
+final bool finished;
+
+                const MapProgress({required this.iteration ,required this.maxIterations ,required this.finished ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => iteration.hashCode^maxIterations.hashCode^finished.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MapProgress &&
+                runtimeType == other.runtimeType
+                && iteration == other.iteration&& maxIterations == other.maxIterations&& finished == other.finished;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Usage counters for one sound
+class SoundUsage  {
+                final PlatformInt64 timesReturned;
+final PlatformInt64 timesAuditioned;
+final PlatformInt64 timesExported;
+/// When the sound was last auditioned or exported (None if never)
+final String? lastUsed;
+
+                const SoundUsage({required this.timesReturned ,required this.timesAuditioned ,required this.timesExported ,this.lastUsed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timesReturned.hashCode^timesAuditioned.hashCode^timesExported.hashCode^lastUsed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundUsage &&
+                runtimeType == other.runtimeType
+                && timesReturned == other.timesReturned&& timesAuditioned == other.timesAuditioned&& timesExported == other.timesExported&& lastUsed == other.lastUsed;
+        
+            }
+
+/// A sound together with its usage counters
+class UsedSound  {
+                final SoundRecord sound;
+final SoundUsage usage;
+
+                const UsedSound({required this.sound ,required this.usage ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sound.hashCode^usage.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UsedSound &&
+                runtimeType == other.runtimeType
+                && sound == other.sound&& usage == other.usage;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One feature group's part in a similarity score
+class FeatureContribution  {
+                final FeatureGroup group;
+/// Similarity of this group on its own (0-100)
+final double similarity;
+/// Share of the overall cosine carried by this group; the overall cosine
+/// equals the sum of `weight * group cosine` over all groups
+final double weight;
+
+                const FeatureContribution({required this.group ,required this.similarity ,required this.weight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => group.hashCode^similarity.hashCode^weight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FeatureContribution &&
+                runtimeType == other.runtimeType
+                && group == other.group&& similarity == other.similarity&& weight == other.weight;
+        
+            }
+
+/// Perceptual feature groups making up the fingerprint vector
+enum FeatureGroup {
+                    /// MFCC mean and std
+timbre,
+/// Spectral centroid, bandwidth, rolloff
+spectrum,
+/// RMS level/variation and zero-crossing rate
+energy,
+/// Chroma (pitch classes)
+harmony,
+/// Stereo width and L/R correlation
+stereo,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Kind of time-series analysis
+enum AnalysisKind {
+                    /// Note/hit onset times in seconds
+onsets,
+/// Beat times in seconds (empty when no steady tempo was found)
+beats,
+/// Fundamental frequency in Hz every `hop_seconds` (0 = unvoiced)
+pitch,
+                    ;
+                    
+                }
+
+/// One analysis result for a sound
+class AnalysisTrack  {
+                final AnalysisKind kind;
+/// Spacing of `values` for sampled curves; 0 for event lists, whose
+/// values are times in seconds
+final double hopSeconds;
+final Float64List values;
+
+                const AnalysisTrack({required this.kind ,required this.hopSeconds ,required this.values ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^hopSeconds.hashCode^values.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AnalysisTrack &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& hopSeconds == other.hopSeconds&& values == other.values;
+        
+            }
+            
\ No newline at end of file
@@ -6,973 +6,6869 @@
 import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'database/archive.dart';
+import 'database/clusters.dart';
+import 'database/collections.dart';
+import 'database/history.dart';
+import 'database/maintenance.dart';
+import 'database/merge.dart';
+import 'database/properties.dart';
+import 'database/query.dart';
+import 'database/regions.dart';
+import 'database/searches.dart';
+import 'database/smart_collections.dart';
+import 'database/sound_map.dart';
+import 'database/usage.dart';
+import 'fingerprint.dart';
+import 'fingerprint/analysis.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'indexer.dart';
 import 'lib.dart';
+import 'midi/csv.dart';
+import 'midi/dspreset.dart';
+import 'midi/import.dart';
+import 'midi/mapping.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'render/colormap.dart';
+import 'render/thumbnail.dart';
+import 'render/waveform.dart';
+import 'search/batch.dart';
+import 'search/containment.dart';
+import 'search/folder.dart';
+import 'search/loops.dart';
+import 'search/presets.dart';
 
-/// Main entrypoint of the Rust API
-class AudioPalette extends BaseEntrypoint<AudioPaletteApi, AudioPaletteApiImpl,
-    AudioPaletteWire> {
-  @internal
-  static final instance = AudioPalette._();
-
-  AudioPalette._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    AudioPaletteApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required AudioPaletteApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<AudioPaletteApiImpl, AudioPaletteWire>
-      get apiImplConstructor => AudioPaletteApiImpl.new;
-
-  @override
-  WireConstructor<AudioPaletteWire> get wireConstructor =>
-      AudioPaletteWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -663634271;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'audio_palette',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class AudioPaletteApi extends BaseApi {
-  Future<PlatformInt64> crateApiAddSound({required String filepath});
-
-  double crateApiComputeSimilarity(
-      {required String fp1Path, required String fp2Path});
-
-  Future<void> crateApiExportToCsv(
-      {required List<MatchResult> matches, required String outputPath});
-
-  Future<void> crateApiExportToMarkers(
-      {required List<MatchResult> matches, required String outputPath});
-
-  Future<void> crateApiExportToMidi(
-      {required List<MatchResult> matches,
-      required String outputPath,
-      required int tempoBpm,
-      required int baseNote});
-
-  Future<List<MatchResult>> crateApiFindSimilar(
-      {required String queryPath,
-      required double threshold,
-      required BigInt maxResults});
-
-  Future<List<MatchResult>> crateApiFindSimilarFromSamples(
-      {required List<double> samples,
-      required int sampleRate,
-      required double threshold,
-      required BigInt maxResults});
-
-  Future<List<MatchResult>> crateApiFindSimilarWithSegments(
-      {required String queryPath,
-      required double threshold,
-      required BigInt maxResults});
-
-  Future<List<SoundRecord>> crateApiGetAllSounds();
-
-  Future<AudioFingerprintInfo> crateApiGetFingerprint(
-      {required String filepath});
-
-  PlatformInt64 crateApiGetSoundCount();
-
-  void crateApiInitDatabase({required String dbPath});
-
-  Future<void> crateApiRemoveSound({required PlatformInt64 soundId});
-
-  Future<List<SoundRecord>> crateApiSearchSounds({required String query});
-}
-
-class AudioPaletteApiImpl extends AudioPaletteApiImplPlatform
-    implements AudioPaletteApi {
-  AudioPaletteApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<PlatformInt64> crateApiAddSound({required String filepath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(filepath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_64,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiAddSoundConstMeta,
-      argValues: [filepath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAddSoundConstMeta => const TaskConstMeta(
-        debugName: "add_sound",
-        argNames: ["filepath"],
-      );
-
-  @override
-  double crateApiComputeSimilarity(
-      {required String fp1Path, required String fp2Path}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(fp1Path, serializer);
-        sse_encode_String(fp2Path, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiComputeSimilarityConstMeta,
-      argValues: [fp1Path, fp2Path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiComputeSimilarityConstMeta => const TaskConstMeta(
-        debugName: "compute_similarity",
-        argNames: ["fp1Path", "fp2Path"],
-      );
-
-  @override
-  Future<void> crateApiExportToCsv(
-      {required List<MatchResult> matches, required String outputPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_match_result(matches, serializer);
-        sse_encode_String(outputPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiExportToCsvConstMeta,
-      argValues: [matches, outputPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiExportToCsvConstMeta => const TaskConstMeta(
-        debugName: "export_to_csv",
-        argNames: ["matches", "outputPath"],
-      );
-
-  @override
-  Future<void> crateApiExportToMarkers(
-      {required List<MatchResult> matches, required String outputPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_match_result(matches, serializer);
-        sse_encode_String(outputPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiExportToMarkersConstMeta,
-      argValues: [matches, outputPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiExportToMarkersConstMeta => const TaskConstMeta(
-        debugName: "export_to_markers",
-        argNames: ["matches", "outputPath"],
-      );
-
-  @override
-  Future<void> crateApiExportToMidi(
-      {required List<MatchResult> matches,
-      required String outputPath,
-      required int tempoBpm,
-      required int baseNote}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_match_result(matches, serializer);
-        sse_encode_String(outputPath, serializer);
-        sse_encode_u_32(tempoBpm, serializer);
-        sse_encode_u_8(baseNote, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiExportToMidiConstMeta,
-      argValues: [matches, outputPath, tempoBpm, baseNote],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiExportToMidiConstMeta => const TaskConstMeta(
-        debugName: "export_to_midi",
-        argNames: ["matches", "outputPath", "tempoBpm", "baseNote"],
-      );
-
-  @override
-  Future<List<MatchResult>> crateApiFindSimilar(
-      {required String queryPath,
-      required double threshold,
-      required BigInt maxResults}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(queryPath, serializer);
-        sse_encode_f_64(threshold, serializer);
-        sse_encode_usize(maxResults, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_match_result,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiFindSimilarConstMeta,
-      argValues: [queryPath, threshold, maxResults],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiFindSimilarConstMeta => const TaskConstMeta(
-        debugName: "find_similar",
-        argNames: ["queryPath", "threshold", "maxResults"],
-      );
-
-  @override
-  Future<List<MatchResult>> crateApiFindSimilarFromSamples(
-      {required List<double> samples,
-      required int sampleRate,
-      required double threshold,
-      required BigInt maxResults}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_f_32_loose(samples, serializer);
-        sse_encode_u_32(sampleRate, serializer);
-        sse_encode_f_64(threshold, serializer);
-        sse_encode_usize(maxResults, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_match_result,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiFindSimilarFromSamplesConstMeta,
-      argValues: [samples, sampleRate, threshold, maxResults],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiFindSimilarFromSamplesConstMeta =>
-      const TaskConstMeta(
-        debugName: "find_similar_from_samples",
-        argNames: ["samples", "sampleRate", "threshold", "maxResults"],
-      );
-
-  @override
-  Future<List<MatchResult>> crateApiFindSimilarWithSegments(
-      {required String queryPath,
-      required double threshold,
-      required BigInt maxResults}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(queryPath, serializer);
-        sse_encode_f_64(threshold, serializer);
-        sse_encode_usize(maxResults, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_match_result,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiFindSimilarWithSegmentsConstMeta,
-      argValues: [queryPath, threshold, maxResults],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiFindSimilarWithSegmentsConstMeta =>
-      const TaskConstMeta(
-        debugName: "find_similar_with_segments",
-        argNames: ["queryPath", "threshold", "maxResults"],
-      );
-
-  @override
-  Future<List<SoundRecord>> crateApiGetAllSounds() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_sound_record,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllSoundsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllSoundsConstMeta => const TaskConstMeta(
-        debugName: "get_all_sounds",
-        argNames: [],
-      );
-
-  @override
-  Future<AudioFingerprintInfo> crateApiGetFingerprint(
-      {required String filepath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(filepath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_audio_fingerprint_info,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetFingerprintConstMeta,
-      argValues: [filepath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetFingerprintConstMeta => const TaskConstMeta(
-        debugName: "get_fingerprint",
-        argNames: ["filepath"],
-      );
-
-  @override
-  PlatformInt64 crateApiGetSoundCount() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_64,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSoundCountConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSoundCountConstMeta => const TaskConstMeta(
-        debugName: "get_sound_count",
-        argNames: [],
-      );
-
-  @override
-  void crateApiInitDatabase({required String dbPath}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dbPath, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiInitDatabaseConstMeta,
-      argValues: [dbPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiInitDatabaseConstMeta => const TaskConstMeta(
-        debugName: "init_database",
-        argNames: ["dbPath"],
-      );
-
-  @override
-  Future<void> crateApiRemoveSound({required PlatformInt64 soundId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_64(soundId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiRemoveSoundConstMeta,
-      argValues: [soundId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiRemoveSoundConstMeta => const TaskConstMeta(
-        debugName: "remove_sound",
-        argNames: ["soundId"],
-      );
-
-  @override
-  Future<List<SoundRecord>> crateApiSearchSounds({required String query}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_sound_record,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiSearchSoundsConstMeta,
-      argValues: [query],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSearchSoundsConstMeta => const TaskConstMeta(
-        debugName: "search_sounds",
-        argNames: ["query"],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  AudioFingerprintInfo dco_decode_audio_fingerprint_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return AudioFingerprintInfo(
-      duration: dco_decode_f_64(arr[0]),
-      spectralCentroid: dco_decode_f_64(arr[1]),
-      spectralBandwidth: dco_decode_f_64(arr[2]),
-      spectralRolloff: dco_decode_f_64(arr[3]),
-      mfccMean: dco_decode_list_prim_f_64_strict(arr[4]),
-      mfccStd: dco_decode_list_prim_f_64_strict(arr[5]),
-    );
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  List<MatchResult> dco_decode_list_match_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_match_result).toList();
-  }
-
-  @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<double>;
-  }
-
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
-  }
-
-  @protected
-  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float64List;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<SoundRecord> dco_decode_list_sound_record(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_sound_record).toList();
-  }
-
-  @protected
-  MatchResult dco_decode_match_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return MatchResult(
-      soundId: dco_decode_i_64(arr[0]),
-      filepath: dco_decode_String(arr[1]),
-      filename: dco_decode_String(arr[2]),
-      score: dco_decode_f_64(arr[3]),
-      matchStart: dco_decode_f_64(arr[4]),
-      matchEnd: dco_decode_f_64(arr[5]),
-      fileDuration: dco_decode_f_64(arr[6]),
-    );
-  }
-
-  @protected
-  SoundRecord dco_decode_sound_record(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return SoundRecord(
-      id: dco_decode_i_64(arr[0]),
-      filepath: dco_decode_String(arr[1]),
-      filename: dco_decode_String(arr[2]),
-      duration: dco_decode_f_64(arr[3]),
-      sampleRate: dco_decode_u_32(arr[4]),
-      channels: dco_decode_u_16(arr[5]),
-      format: dco_decode_String(arr[6]),
-      dateAdded: dco_decode_String(arr[7]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  AudioFingerprintInfo sse_decode_audio_fingerprint_info(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_duration = sse_decode_f_64(deserializer);
-    final var_spectralCentroid = sse_decode_f_64(deserializer);
-    final var_spectralBandwidth = sse_decode_f_64(deserializer);
-    final var_spectralRolloff = sse_decode_f_64(deserializer);
-    final var_mfccMean = sse_decode_list_prim_f_64_strict(deserializer);
-    final var_mfccStd = sse_decode_list_prim_f_64_strict(deserializer);
-    return AudioFingerprintInfo(
-        duration: var_duration,
-        spectralCentroid: var_spectralCentroid,
-        spectralBandwidth: var_spectralBandwidth,
-        spectralRolloff: var_spectralRolloff,
-        mfccMean: var_mfccMean,
-        mfccStd: var_mfccStd);
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  List<MatchResult> sse_decode_list_match_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <MatchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_match_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat64List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<SoundRecord> sse_decode_list_sound_record(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <SoundRecord>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sound_record(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  MatchResult sse_decode_match_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_soundId = sse_decode_i_64(deserializer);
-    final var_filepath = sse_decode_String(deserializer);
-    final var_filename = sse_decode_String(deserializer);
-    final var_score = sse_decode_f_64(deserializer);
-    final var_matchStart = sse_decode_f_64(deserializer);
-    final var_matchEnd = sse_decode_f_64(deserializer);
-    final var_fileDuration = sse_decode_f_64(deserializer);
-    return MatchResult(
-        soundId: var_soundId,
-        filepath: var_filepath,
-        filename: var_filename,
-        score: var_score,
-        matchStart: var_matchStart,
-        matchEnd: var_matchEnd,
-        fileDuration: var_fileDuration);
-  }
-
-  @protected
-  SoundRecord sse_decode_sound_record(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_i_64(deserializer);
-    final var_filepath = sse_decode_String(deserializer);
-    final var_filename = sse_decode_String(deserializer);
-    final var_duration = sse_decode_f_64(deserializer);
-    final var_sampleRate = sse_decode_u_32(deserializer);
-    final var_channels = sse_decode_u_16(deserializer);
-    final var_format = sse_decode_String(deserializer);
-    final var_dateAdded = sse_decode_String(deserializer);
-    return SoundRecord(
-        id: var_id,
-        filepath: var_filepath,
-        filename: var_filename,
-        duration: var_duration,
-        sampleRate: var_sampleRate,
-        channels: var_channels,
-        format: var_format,
-        dateAdded: var_dateAdded);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_audio_fingerprint_info(
-      AudioFingerprintInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.duration, serializer);
-    sse_encode_f_64(self.spectralCentroid, serializer);
-    sse_encode_f_64(self.spectralBandwidth, serializer);
-    sse_encode_f_64(self.spectralRolloff, serializer);
-    sse_encode_list_prim_f_64_strict(self.mfccMean, serializer);
-    sse_encode_list_prim_f_64_strict(self.mfccStd, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_list_match_result(
-      List<MatchResult> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_match_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_loose(
-      List<double> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(
-        self is Float32List ? self : Float32List.fromList(self));
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-      Float32List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_f_64_strict(
-      Float64List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat64List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_sound_record(
-      List<SoundRecord> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sound_record(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_match_result(MatchResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.soundId, serializer);
-    sse_encode_String(self.filepath, serializer);
-    sse_encode_String(self.filename, serializer);
-    sse_encode_f_64(self.score, serializer);
-    sse_encode_f_64(self.matchStart, serializer);
-    sse_encode_f_64(self.matchEnd, serializer);
-    sse_encode_f_64(self.fileDuration, serializer);
-  }
-
-  @protected
-  void sse_encode_sound_record(SoundRecord self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.id, serializer);
-    sse_encode_String(self.filepath, serializer);
-    sse_encode_String(self.filename, serializer);
-    sse_encode_f_64(self.duration, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_16(self.channels, serializer);
-    sse_encode_String(self.format, serializer);
-    sse_encode_String(self.dateAdded, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class AudioPalette extends BaseEntrypoint<AudioPaletteApi, AudioPaletteApiImpl, AudioPaletteWire> {
+                  @internal
+                  static final instance = AudioPalette._();
+
+                  AudioPalette._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    AudioPaletteApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required AudioPaletteApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<AudioPaletteApiImpl, AudioPaletteWire> get apiImplConstructor => AudioPaletteApiImpl.new;
+
+                  @override
+                  WireConstructor<AudioPaletteWire> get wireConstructor => AudioPaletteWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -882870957;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'audio_palette',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class AudioPaletteApi extends BaseApi {
+                  Future<Int64List> crateApiAddMatchesToCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required List<MatchResult> matches });
+
+Future<PlatformInt64> crateApiAddRegion({required PlatformInt64 palette , required PlatformInt64 soundId , required double startTime , required double endTime , required String label , required String color });
+
+Future<PlatformInt64> crateApiAddSound({required PlatformInt64 palette , required String filepath });
+
+Future<List<PlatformInt64?>> crateApiAddSoundsBatch({required PlatformInt64 palette , required List<String> filepaths });
+
+Future<Int64List> crateApiAddSoundsToCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required Int64List soundIds });
+
+Future<void> crateApiAddTag({required PlatformInt64 palette , required PlatformInt64 soundId , required String tag });
+
+void crateApiCancelIndexing({required PlatformInt64 palette });
+
+void crateApiCancelSearches({required PlatformInt64 palette });
+
+Future<IntegrityReport> crateApiCheckIntegrity({required PlatformInt64 palette });
+
+Future<List<SoundTag>> crateApiClassifyInstrument({required String filepath });
+
+Future<void> crateApiClearSearchHistory({required PlatformInt64 palette });
+
+void crateApiClosePalette({required PlatformInt64 palette });
+
+Future<ClusteringSummary> crateApiClusterLibrary({required PlatformInt64 palette , required BigInt clusters });
+
+double crateApiComputeSimilarity({required String fp1Path , required String fp2Path });
+
+Future<BigInt> crateApiComputeSoundMap({required PlatformInt64 palette });
+
+Future<PlatformInt64> crateApiCreateCollection({required PlatformInt64 palette , required String name , required String description });
+
+Future<PlatformInt64> crateApiCreateSmartCollection({required PlatformInt64 palette , required String name , required SmartQuery query });
+
+Future<void> crateApiDeleteCollection({required PlatformInt64 palette , required PlatformInt64 collectionId });
+
+Future<void> crateApiDeleteRecentSearch({required PlatformInt64 palette , required PlatformInt64 searchId });
+
+Future<void> crateApiDeleteRegion({required PlatformInt64 palette , required PlatformInt64 regionId });
+
+Future<void> crateApiDeleteSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId });
+
+Future<void> crateApiDeleteTag({required PlatformInt64 palette , required String name });
+
+Future<bool> crateApiEnableGpuScoring({required PlatformInt64 palette , required bool enabled });
+
+bool crateApiEncryptionSupported();
+
+Future<List<MatchResult>> crateApiEvaluateSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId });
+
+Future<List<FeatureContribution>> crateApiExplainMatch({required String queryPath , required MatchResult result });
+
+Future<BigInt> crateApiExportArrangementToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required List<String> labels , required String outputPath , required int tempoBpm , required int baseNote , required bool followDetectedTempo });
+
+Future<BigInt> crateApiExportClickTrackToMidi({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int beatsPerBar , required int firstDownbeat });
+
+Future<BigInt> crateApiExportCollectionToDspreset({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required int lowestNote , required int keysPerSample , required SamplerEnvelope envelope });
+
+Future<void> crateApiExportCollectionToM3U({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required bool relativePaths });
+
+Future<BigInt> crateApiExportCollectionToSfz({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required int lowestNote , required int keysPerSample });
+
+Future<BigInt> crateApiExportCollectionToXpm({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required String name , required bool chromatic });
+
+Future<BigInt> crateApiExportDrumsToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm , required int quantizeDivision , required bool followDetectedTempo });
+
+Future<ArchiveSummary> crateApiExportLibrary({required PlatformInt64 palette , required String archivePath , required bool includeAudio });
+
+Future<BigInt> crateApiExportMelodyToMidi({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int tempoBpm , required int quantizeDivision , required bool followDetectedTempo });
+
+Future<BigInt> crateApiExportMelodyToMusicxml({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int tempoBpm });
+
+Future<void> crateApiExportReport({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required String outputPath , required bool includeImages });
+
+Future<void> crateApiExportToAls({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm });
+
+Future<BigInt> crateApiExportToBundle({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath });
+
+Future<void> crateApiExportToCsv({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath });
+
+Future<void> crateApiExportToCsvWithOptions({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required CsvExportOptions options });
+
+Future<void> crateApiExportToCue({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required String outputPath });
+
+Future<void> crateApiExportToDawproject({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm });
+
+Future<BigInt> crateApiExportToDspreset({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int lowestNote , required int keysPerSample , required SamplerEnvelope envelope });
+
+Future<void> crateApiExportToEdl({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required int fps , required String outputPath });
+
+Future<void> crateApiExportToJson({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , String? queryPath , required bool includeFingerprints });
+
+Future<void> crateApiExportToM3U({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required bool relativePaths });
+
+Future<void> crateApiExportToMarkers({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath });
+
+Future<void> crateApiExportToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm , required int baseNote , required int quantizeNoteValue , required double swing , required bool followDetectedTempo , required MidiNoteMapping noteMapping });
+
+Future<void> crateApiExportToReaperRegions({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath });
+
+Future<BigInt> crateApiExportToSfz({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int lowestNote , required int keysPerSample });
+
+Future<BigInt> crateApiExportToXpm({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required String name , required bool chromatic });
+
+Future<List<SoundRecord>> crateApiFindByProperty({required PlatformInt64 palette , required String key , required String value });
+
+Future<List<SoundRecord>> crateApiFindByTags({required PlatformInt64 palette , required List<String> tags , required bool matchAll , required double minConfidence });
+
+Future<List<ContainmentMatch>> crateApiFindContaining({required PlatformInt64 palette , required String queryPath , required double minCorrelation , required BigInt maxResults });
+
+Future<SoundRecord?> crateApiFindDuplicate({required PlatformInt64 palette , required String filepath });
+
+Future<List<List<SoundRecord>>> crateApiFindDuplicateGroups({required PlatformInt64 palette });
+
+Future<List<List<SoundRecord>>> crateApiFindDuplicates({required PlatformInt64 palette , required double threshold });
+
+Future<List<LoopMatch>> crateApiFindLoopSources({required PlatformInt64 palette , required String queryPath , required double threshold , required double minCoverage , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindMelody({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Future<List<SoundRecord>> crateApiFindMissingSounds({required PlatformInt64 palette });
+
+Future<List<MatchResult>> crateApiFindSimilar({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Future<List<BatchMatches>> crateApiFindSimilarBatch({required PlatformInt64 palette , required List<BatchQuery> queries , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarById({required PlatformInt64 palette , required PlatformInt64 soundId , required double threshold , required BigInt maxResults });
+
+Future<List<BatchMatches>> crateApiFindSimilarFromMidi({required PlatformInt64 palette , required String midiPath , required String audioPath , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarFromRegion({required PlatformInt64 palette , required PlatformInt64 soundId , required double startS , required double endS , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarFromSamples({required PlatformInt64 palette , required List<double> samples , required int sampleRate , required double threshold , required BigInt maxResults });
+
+Future<List<FolderMatch>> crateApiFindSimilarInDirectory({required PlatformInt64 palette , required String queryPath , required String directory , required List<String> extensions , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarInRegions({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Stream<List<MatchResult>> crateApiFindSimilarInRegionsStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarMatching({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults , required SoundQuery filter });
+
+Future<List<MatchResult>> crateApiFindSimilarPage({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt offset , required BigInt limit });
+
+Stream<List<MatchResult>> crateApiFindSimilarStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarToExamples({required PlatformInt64 palette , required List<String> positivePaths , required List<String> negativePaths , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarWithOptions({required PlatformInt64 palette , required String queryPath , required SearchOptions options });
+
+Stream<List<MatchResult>> crateApiFindSimilarWithOptionsStream({required PlatformInt64 palette , required String queryPath , required SearchOptions options });
+
+Future<List<MatchResult>> crateApiFindSimilarWithSegments({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Stream<List<MatchResult>> crateApiFindSimilarWithSegmentsStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults });
+
+Future<List<MatchResult>> crateApiFindSimilarWithText({required PlatformInt64 palette , required String queryPath , required String text , required double textWeight , required double threshold , required BigInt maxResults });
+
+Future<List<SoundRecord>> crateApiGetAllSounds({required PlatformInt64 palette });
+
+Future<List<TagSummary>> crateApiGetAllTags({required PlatformInt64 palette });
+
+Future<AnalysisTrack> crateApiGetAnalysis({required PlatformInt64 palette , required PlatformInt64 soundId , required AnalysisKind kind });
+
+Future<List<Thumbnail?>> crateApiGetCachedThumbnails({required PlatformInt64 palette , required Int64List soundIds , required ThumbnailKind kind });
+
+ClusterProgress? crateApiGetClusterProgress({required PlatformInt64 palette });
+
+Future<List<SoundRecord>> crateApiGetClusterSounds({required PlatformInt64 palette , required PlatformInt64 clusterId });
+
+Future<List<SoundCluster>> crateApiGetClusters({required PlatformInt64 palette });
+
+Future<List<CollectionItem>> crateApiGetCollectionItems({required PlatformInt64 palette , required PlatformInt64 collectionId });
+
+Future<List<Collection>> crateApiGetCollections({required PlatformInt64 palette });
+
+Future<AudioFingerprintInfo> crateApiGetFingerprint({required String filepath });
+
+Future<List<HistoryEntry>> crateApiGetHistory({required PlatformInt64 palette , required PlatformInt64 limit });
+
+IndexProgress? crateApiGetIndexProgress({required PlatformInt64 palette });
+
+String? crateApiGetLibraryRoot({required PlatformInt64 palette });
+
+Future<MapPoint?> crateApiGetMapPosition({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+MapProgress? crateApiGetMapProgress({required PlatformInt64 palette });
+
+Future<List<UsedSound>> crateApiGetMostUsedSounds({required PlatformInt64 palette , required PlatformInt64 limit });
+
+Future<NoiseInfo?> crateApiGetNoiseInfo({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Int64List crateApiGetOpenPalettes();
+
+OptimizeProgress? crateApiGetOptimizeProgress({required PlatformInt64 palette });
+
+Future<PlatformInt64> crateApiGetOutdatedFingerprintCount({required PlatformInt64 palette });
+
+Future<List<RecentSearch>> crateApiGetRecentSearches({required PlatformInt64 palette , required PlatformInt64 limit });
+
+Future<List<UsedSound>> crateApiGetRecentlyUsedSounds({required PlatformInt64 palette , required PlatformInt64 limit });
+
+Future<List<MatchResult>> crateApiGetRegionMatches({required PlatformInt64 palette , required Int64List regionIds });
+
+Future<List<Region>> crateApiGetRegions({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Future<List<SmartCollection>> crateApiGetSmartCollections({required PlatformInt64 palette });
+
+Future<PlatformInt64?> crateApiGetSoundCluster({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+PlatformInt64 crateApiGetSoundCount({required PlatformInt64 palette });
+
+Future<List<MapPoint>> crateApiGetSoundMap({required PlatformInt64 palette });
+
+Future<List<SoundProperty>> crateApiGetSoundProperties({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Future<String?> crateApiGetSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key });
+
+Future<SoundUsage> crateApiGetSoundUsage({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Future<SoundPage> crateApiGetSoundsPage({required PlatformInt64 palette , required PlatformInt64 offset , required PlatformInt64 limit , required SoundSort sort , required bool descending });
+
+Future<List<SoundTag>> crateApiGetTags({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Future<Thumbnail?> crateApiGetThumbnail({required PlatformInt64 palette , required PlatformInt64 soundId , required ThumbnailKind kind });
+
+Future<WaveformPeaks> crateApiGetWaveform({required PlatformInt64 palette , required PlatformInt64 soundId , required BigInt buckets });
+
+Future<ImportSummary> crateApiImportLibrary({required PlatformInt64 palette , required String archivePath , String? audioDir });
+
+Future<IndexProgress> crateApiIndexDirectory({required PlatformInt64 palette , required String path , required List<String> extensions , BigInt? maxFileSize });
+
+Future<void> crateApiLoadTaggingModel({required String modelPath , required String labelsPath , required double threshold });
+
+Future<MergeSummary> crateApiMergeDatabase({required PlatformInt64 palette , required String otherPath , required ConflictPolicy policy });
+
+Future<void> crateApiMoveCollectionItem({required PlatformInt64 palette , required PlatformInt64 itemId , required BigInt position });
+
+PlatformInt64 crateApiOpenEncryptedPalette({required String dbPath , required String passphrase });
+
+PlatformInt64 crateApiOpenPalette({required String dbPath });
+
+Future<OptimizeSummary> crateApiOptimizeDatabase({required PlatformInt64 palette });
+
+Future<void> crateApiPushLiveAudio({required PlatformInt64 session , required List<double> samples });
+
+Future<SoundPage> crateApiQuerySounds({required PlatformInt64 palette , required SoundQuery query });
+
+Future<List<MidiRegion>> crateApiReadMidiRegions({required String midiPath });
+
+Future<void> crateApiRecordAudition({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Future<double> crateApiRelinkSound({required PlatformInt64 palette , required PlatformInt64 soundId , required String newPath });
+
+Future<void> crateApiRemoveFromCollection({required PlatformInt64 palette , required PlatformInt64 itemId });
+
+Future<void> crateApiRemoveSound({required PlatformInt64 palette , required PlatformInt64 soundId });
+
+Future<void> crateApiRemoveSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key });
+
+Future<void> crateApiRemoveTag({required PlatformInt64 palette , required PlatformInt64 soundId , required String tag });
+
+Future<void> crateApiRenameTag({required PlatformInt64 palette , required String oldName , required String newName });
+
+Future<Uint8List> crateApiRenderSpectrogramPng({required String filepath , double? startS , double? endS , required int width , required int height , required Colormap colormap , required double minDb , required double maxDb });
+
+Future<void> crateApiReorderCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required Int64List itemIds });
+
+Future<RescanSummary> crateApiRescanLibrary({required PlatformInt64 palette });
+
+SearchOptions crateApiSearchPresetOptions({required SearchPreset preset , required BigInt maxResults });
+
+Future<List<SoundRecord>> crateApiSearchSounds({required PlatformInt64 palette , required String query });
+
+Future<BigInt> crateApiSetLibraryRoot({required PlatformInt64 palette , String? root });
+
+Future<void> crateApiSetSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key , required String value });
+
+Future<void> crateApiSetSoundRating({required PlatformInt64 palette , required PlatformInt64 soundId , required int rating });
+
+Stream<List<MatchResult>> crateApiStartLiveQuery({required PlatformInt64 palette , required int sampleRate , required double windowSeconds , required double threshold , required BigInt maxResults });
+
+void crateApiStopLiveQuery({required PlatformInt64 session });
+
+Future<HistoryEntry> crateApiUndoChange({required PlatformInt64 palette , required PlatformInt64 entryId });
+
+Future<HistoryEntry?> crateApiUndoLastChange({required PlatformInt64 palette });
+
+void crateApiUnloadTaggingModel();
+
+Future<void> crateApiUpdateCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required String name , required String description });
+
+Future<void> crateApiUpdateRegion({required PlatformInt64 palette , required PlatformInt64 regionId , required double startTime , required double endTime , required String label , required String color });
+
+Future<void> crateApiUpdateSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required String name , required SmartQuery query });
+
+Future<void> crateApiUpdateSoundMetadata({required PlatformInt64 palette , required PlatformInt64 soundId , String? title , String? artist , String? notes , String? source });
+
+Future<VerifyReport> crateApiVerifyLibrary({required PlatformInt64 palette });
+
+Future<BigInt> crateApiWriteClusterCategories({required PlatformInt64 palette , required String parent });
+
+
+                }
+                
+
+                class AudioPaletteApiImpl extends AudioPaletteApiImplPlatform implements AudioPaletteApi {
+                  AudioPaletteApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<Int64List> crateApiAddMatchesToCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required List<MatchResult> matches })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_list_match_result(matches, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddMatchesToCollectionConstMeta,
+            argValues: [palette, collectionId, matches],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddMatchesToCollectionConstMeta => const TaskConstMeta(
+            debugName: "add_matches_to_collection",
+            argNames: ["palette", "collectionId", "matches"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiAddRegion({required PlatformInt64 palette , required PlatformInt64 soundId , required double startTime , required double endTime , required String label , required String color })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_f_64(startTime, serializer);
+sse_encode_f_64(endTime, serializer);
+sse_encode_String(label, serializer);
+sse_encode_String(color, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddRegionConstMeta,
+            argValues: [palette, soundId, startTime, endTime, label, color],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddRegionConstMeta => const TaskConstMeta(
+            debugName: "add_region",
+            argNames: ["palette", "soundId", "startTime", "endTime", "label", "color"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiAddSound({required PlatformInt64 palette , required String filepath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(filepath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddSoundConstMeta,
+            argValues: [palette, filepath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddSoundConstMeta => const TaskConstMeta(
+            debugName: "add_sound",
+            argNames: ["palette", "filepath"],
+        );
+        
+
+@override Future<List<PlatformInt64?>> crateApiAddSoundsBatch({required PlatformInt64 palette , required List<String> filepaths })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_String(filepaths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_opt_box_autoadd_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddSoundsBatchConstMeta,
+            argValues: [palette, filepaths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddSoundsBatchConstMeta => const TaskConstMeta(
+            debugName: "add_sounds_batch",
+            argNames: ["palette", "filepaths"],
+        );
+        
+
+@override Future<Int64List> crateApiAddSoundsToCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required Int64List soundIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_list_prim_i_64_strict(soundIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddSoundsToCollectionConstMeta,
+            argValues: [palette, collectionId, soundIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddSoundsToCollectionConstMeta => const TaskConstMeta(
+            debugName: "add_sounds_to_collection",
+            argNames: ["palette", "collectionId", "soundIds"],
+        );
+        
+
+@override Future<void> crateApiAddTag({required PlatformInt64 palette , required PlatformInt64 soundId , required String tag })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_String(tag, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddTagConstMeta,
+            argValues: [palette, soundId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddTagConstMeta => const TaskConstMeta(
+            debugName: "add_tag",
+            argNames: ["palette", "soundId", "tag"],
+        );
+        
+
+@override void crateApiCancelIndexing({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCancelIndexingConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelIndexingConstMeta => const TaskConstMeta(
+            debugName: "cancel_indexing",
+            argNames: ["palette"],
+        );
+        
+
+@override void crateApiCancelSearches({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCancelSearchesConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelSearchesConstMeta => const TaskConstMeta(
+            debugName: "cancel_searches",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<IntegrityReport> crateApiCheckIntegrity({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_integrity_report,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCheckIntegrityConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckIntegrityConstMeta => const TaskConstMeta(
+            debugName: "check_integrity",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<SoundTag>> crateApiClassifyInstrument({required String filepath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filepath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_tag,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiClassifyInstrumentConstMeta,
+            argValues: [filepath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClassifyInstrumentConstMeta => const TaskConstMeta(
+            debugName: "classify_instrument",
+            argNames: ["filepath"],
+        );
+        
+
+@override Future<void> crateApiClearSearchHistory({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiClearSearchHistoryConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClearSearchHistoryConstMeta => const TaskConstMeta(
+            debugName: "clear_search_history",
+            argNames: ["palette"],
+        );
+        
+
+@override void crateApiClosePalette({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiClosePaletteConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClosePaletteConstMeta => const TaskConstMeta(
+            debugName: "close_palette",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<ClusteringSummary> crateApiClusterLibrary({required PlatformInt64 palette , required BigInt clusters })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_usize(clusters, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_clustering_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiClusterLibraryConstMeta,
+            argValues: [palette, clusters],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClusterLibraryConstMeta => const TaskConstMeta(
+            debugName: "cluster_library",
+            argNames: ["palette", "clusters"],
+        );
+        
+
+@override double crateApiComputeSimilarity({required String fp1Path , required String fp2Path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(fp1Path, serializer);
+sse_encode_String(fp2Path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiComputeSimilarityConstMeta,
+            argValues: [fp1Path, fp2Path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiComputeSimilarityConstMeta => const TaskConstMeta(
+            debugName: "compute_similarity",
+            argNames: ["fp1Path", "fp2Path"],
+        );
+        
+
+@override Future<BigInt> crateApiComputeSoundMap({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiComputeSoundMapConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiComputeSoundMapConstMeta => const TaskConstMeta(
+            debugName: "compute_sound_map",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiCreateCollection({required PlatformInt64 palette , required String name , required String description })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(description, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateCollectionConstMeta,
+            argValues: [palette, name, description],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateCollectionConstMeta => const TaskConstMeta(
+            debugName: "create_collection",
+            argNames: ["palette", "name", "description"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiCreateSmartCollection({required PlatformInt64 palette , required String name , required SmartQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(name, serializer);
+sse_encode_box_autoadd_smart_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateSmartCollectionConstMeta,
+            argValues: [palette, name, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateSmartCollectionConstMeta => const TaskConstMeta(
+            debugName: "create_smart_collection",
+            argNames: ["palette", "name", "query"],
+        );
+        
+
+@override Future<void> crateApiDeleteCollection({required PlatformInt64 palette , required PlatformInt64 collectionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteCollectionConstMeta,
+            argValues: [palette, collectionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteCollectionConstMeta => const TaskConstMeta(
+            debugName: "delete_collection",
+            argNames: ["palette", "collectionId"],
+        );
+        
+
+@override Future<void> crateApiDeleteRecentSearch({required PlatformInt64 palette , required PlatformInt64 searchId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(searchId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteRecentSearchConstMeta,
+            argValues: [palette, searchId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteRecentSearchConstMeta => const TaskConstMeta(
+            debugName: "delete_recent_search",
+            argNames: ["palette", "searchId"],
+        );
+        
+
+@override Future<void> crateApiDeleteRegion({required PlatformInt64 palette , required PlatformInt64 regionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(regionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteRegionConstMeta,
+            argValues: [palette, regionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteRegionConstMeta => const TaskConstMeta(
+            debugName: "delete_region",
+            argNames: ["palette", "regionId"],
+        );
+        
+
+@override Future<void> crateApiDeleteSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteSmartCollectionConstMeta,
+            argValues: [palette, collectionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteSmartCollectionConstMeta => const TaskConstMeta(
+            debugName: "delete_smart_collection",
+            argNames: ["palette", "collectionId"],
+        );
+        
+
+@override Future<void> crateApiDeleteTag({required PlatformInt64 palette , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteTagConstMeta,
+            argValues: [palette, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteTagConstMeta => const TaskConstMeta(
+            debugName: "delete_tag",
+            argNames: ["palette", "name"],
+        );
+        
+
+@override Future<bool> crateApiEnableGpuScoring({required PlatformInt64 palette , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEnableGpuScoringConstMeta,
+            argValues: [palette, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnableGpuScoringConstMeta => const TaskConstMeta(
+            debugName: "enable_gpu_scoring",
+            argNames: ["palette", "enabled"],
+        );
+        
+
+@override bool crateApiEncryptionSupported()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEncryptionSupportedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptionSupportedConstMeta => const TaskConstMeta(
+            debugName: "encryption_supported",
+            argNames: [],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiEvaluateSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEvaluateSmartCollectionConstMeta,
+            argValues: [palette, collectionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEvaluateSmartCollectionConstMeta => const TaskConstMeta(
+            debugName: "evaluate_smart_collection",
+            argNames: ["palette", "collectionId"],
+        );
+        
+
+@override Future<List<FeatureContribution>> crateApiExplainMatch({required String queryPath , required MatchResult result })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryPath, serializer);
+sse_encode_box_autoadd_match_result(result, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_feature_contribution,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExplainMatchConstMeta,
+            argValues: [queryPath, result],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExplainMatchConstMeta => const TaskConstMeta(
+            debugName: "explain_match",
+            argNames: ["queryPath", "result"],
+        );
+        
+
+@override Future<BigInt> crateApiExportArrangementToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required List<String> labels , required String outputPath , required int tempoBpm , required int baseNote , required bool followDetectedTempo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_list_String(labels, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+sse_encode_u_8(baseNote, serializer);
+sse_encode_bool(followDetectedTempo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportArrangementToMidiConstMeta,
+            argValues: [palette, matches, labels, outputPath, tempoBpm, baseNote, followDetectedTempo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportArrangementToMidiConstMeta => const TaskConstMeta(
+            debugName: "export_arrangement_to_midi",
+            argNames: ["palette", "matches", "labels", "outputPath", "tempoBpm", "baseNote", "followDetectedTempo"],
+        );
+        
+
+@override Future<BigInt> crateApiExportClickTrackToMidi({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int beatsPerBar , required int firstDownbeat })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_box_autoadd_match_result(result, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_8(beatsPerBar, serializer);
+sse_encode_u_32(firstDownbeat, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportClickTrackToMidiConstMeta,
+            argValues: [palette, result, outputPath, beatsPerBar, firstDownbeat],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportClickTrackToMidiConstMeta => const TaskConstMeta(
+            debugName: "export_click_track_to_midi",
+            argNames: ["palette", "result", "outputPath", "beatsPerBar", "firstDownbeat"],
+        );
+        
+
+@override Future<BigInt> crateApiExportCollectionToDspreset({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required int lowestNote , required int keysPerSample , required SamplerEnvelope envelope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_8(lowestNote, serializer);
+sse_encode_u_8(keysPerSample, serializer);
+sse_encode_box_autoadd_sampler_envelope(envelope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportCollectionToDspresetConstMeta,
+            argValues: [palette, collectionId, outputPath, lowestNote, keysPerSample, envelope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCollectionToDspresetConstMeta => const TaskConstMeta(
+            debugName: "export_collection_to_dspreset",
+            argNames: ["palette", "collectionId", "outputPath", "lowestNote", "keysPerSample", "envelope"],
+        );
+        
+
+@override Future<void> crateApiExportCollectionToM3U({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required bool relativePaths })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_bool(relativePaths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportCollectionToM3UConstMeta,
+            argValues: [palette, collectionId, outputPath, relativePaths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCollectionToM3UConstMeta => const TaskConstMeta(
+            debugName: "export_collection_to_m3u",
+            argNames: ["palette", "collectionId", "outputPath", "relativePaths"],
+        );
+        
+
+@override Future<BigInt> crateApiExportCollectionToSfz({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required int lowestNote , required int keysPerSample })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_8(lowestNote, serializer);
+sse_encode_u_8(keysPerSample, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportCollectionToSfzConstMeta,
+            argValues: [palette, collectionId, outputPath, lowestNote, keysPerSample],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCollectionToSfzConstMeta => const TaskConstMeta(
+            debugName: "export_collection_to_sfz",
+            argNames: ["palette", "collectionId", "outputPath", "lowestNote", "keysPerSample"],
+        );
+        
+
+@override Future<BigInt> crateApiExportCollectionToXpm({required PlatformInt64 palette , required PlatformInt64 collectionId , required String outputPath , required String name , required bool chromatic })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_String(name, serializer);
+sse_encode_bool(chromatic, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportCollectionToXpmConstMeta,
+            argValues: [palette, collectionId, outputPath, name, chromatic],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportCollectionToXpmConstMeta => const TaskConstMeta(
+            debugName: "export_collection_to_xpm",
+            argNames: ["palette", "collectionId", "outputPath", "name", "chromatic"],
+        );
+        
+
+@override Future<BigInt> crateApiExportDrumsToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm , required int quantizeDivision , required bool followDetectedTempo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+sse_encode_u_16(quantizeDivision, serializer);
+sse_encode_bool(followDetectedTempo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportDrumsToMidiConstMeta,
+            argValues: [palette, matches, outputPath, tempoBpm, quantizeDivision, followDetectedTempo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportDrumsToMidiConstMeta => const TaskConstMeta(
+            debugName: "export_drums_to_midi",
+            argNames: ["palette", "matches", "outputPath", "tempoBpm", "quantizeDivision", "followDetectedTempo"],
+        );
+        
+
+@override Future<ArchiveSummary> crateApiExportLibrary({required PlatformInt64 palette , required String archivePath , required bool includeAudio })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(archivePath, serializer);
+sse_encode_bool(includeAudio, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_archive_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportLibraryConstMeta,
+            argValues: [palette, archivePath, includeAudio],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportLibraryConstMeta => const TaskConstMeta(
+            debugName: "export_library",
+            argNames: ["palette", "archivePath", "includeAudio"],
+        );
+        
+
+@override Future<BigInt> crateApiExportMelodyToMidi({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int tempoBpm , required int quantizeDivision , required bool followDetectedTempo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_box_autoadd_match_result(result, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+sse_encode_u_16(quantizeDivision, serializer);
+sse_encode_bool(followDetectedTempo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportMelodyToMidiConstMeta,
+            argValues: [palette, result, outputPath, tempoBpm, quantizeDivision, followDetectedTempo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportMelodyToMidiConstMeta => const TaskConstMeta(
+            debugName: "export_melody_to_midi",
+            argNames: ["palette", "result", "outputPath", "tempoBpm", "quantizeDivision", "followDetectedTempo"],
+        );
+        
+
+@override Future<BigInt> crateApiExportMelodyToMusicxml({required PlatformInt64 palette , required MatchResult result , required String outputPath , required int tempoBpm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_box_autoadd_match_result(result, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportMelodyToMusicxmlConstMeta,
+            argValues: [palette, result, outputPath, tempoBpm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportMelodyToMusicxmlConstMeta => const TaskConstMeta(
+            debugName: "export_melody_to_musicxml",
+            argNames: ["palette", "result", "outputPath", "tempoBpm"],
+        );
+        
+
+@override Future<void> crateApiExportReport({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required String outputPath , required bool includeImages })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_bool(includeImages, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportReportConstMeta,
+            argValues: [palette, matches, title, outputPath, includeImages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportReportConstMeta => const TaskConstMeta(
+            debugName: "export_report",
+            argNames: ["palette", "matches", "title", "outputPath", "includeImages"],
+        );
+        
+
+@override Future<void> crateApiExportToAls({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToAlsConstMeta,
+            argValues: [palette, matches, outputPath, tempoBpm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToAlsConstMeta => const TaskConstMeta(
+            debugName: "export_to_als",
+            argNames: ["palette", "matches", "outputPath", "tempoBpm"],
+        );
+        
+
+@override Future<BigInt> crateApiExportToBundle({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToBundleConstMeta,
+            argValues: [palette, matches, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToBundleConstMeta => const TaskConstMeta(
+            debugName: "export_to_bundle",
+            argNames: ["palette", "matches", "outputPath"],
+        );
+        
+
+@override Future<void> crateApiExportToCsv({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToCsvConstMeta,
+            argValues: [palette, matches, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToCsvConstMeta => const TaskConstMeta(
+            debugName: "export_to_csv",
+            argNames: ["palette", "matches", "outputPath"],
+        );
+        
+
+@override Future<void> crateApiExportToCsvWithOptions({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required CsvExportOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_box_autoadd_csv_export_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToCsvWithOptionsConstMeta,
+            argValues: [palette, matches, outputPath, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToCsvWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "export_to_csv_with_options",
+            argNames: ["palette", "matches", "outputPath", "options"],
+        );
+        
+
+@override Future<void> crateApiExportToCue({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToCueConstMeta,
+            argValues: [palette, matches, title, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToCueConstMeta => const TaskConstMeta(
+            debugName: "export_to_cue",
+            argNames: ["palette", "matches", "title", "outputPath"],
+        );
+        
+
+@override Future<void> crateApiExportToDawproject({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToDawprojectConstMeta,
+            argValues: [palette, matches, outputPath, tempoBpm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToDawprojectConstMeta => const TaskConstMeta(
+            debugName: "export_to_dawproject",
+            argNames: ["palette", "matches", "outputPath", "tempoBpm"],
+        );
+        
+
+@override Future<BigInt> crateApiExportToDspreset({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int lowestNote , required int keysPerSample , required SamplerEnvelope envelope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_8(lowestNote, serializer);
+sse_encode_u_8(keysPerSample, serializer);
+sse_encode_box_autoadd_sampler_envelope(envelope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToDspresetConstMeta,
+            argValues: [palette, matches, outputPath, lowestNote, keysPerSample, envelope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToDspresetConstMeta => const TaskConstMeta(
+            debugName: "export_to_dspreset",
+            argNames: ["palette", "matches", "outputPath", "lowestNote", "keysPerSample", "envelope"],
+        );
+        
+
+@override Future<void> crateApiExportToEdl({required PlatformInt64 palette , required List<MatchResult> matches , required String title , required int fps , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(title, serializer);
+sse_encode_u_32(fps, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToEdlConstMeta,
+            argValues: [palette, matches, title, fps, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToEdlConstMeta => const TaskConstMeta(
+            debugName: "export_to_edl",
+            argNames: ["palette", "matches", "title", "fps", "outputPath"],
+        );
+        
+
+@override Future<void> crateApiExportToJson({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , String? queryPath , required bool includeFingerprints })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_opt_String(queryPath, serializer);
+sse_encode_bool(includeFingerprints, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToJsonConstMeta,
+            argValues: [palette, matches, outputPath, queryPath, includeFingerprints],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToJsonConstMeta => const TaskConstMeta(
+            debugName: "export_to_json",
+            argNames: ["palette", "matches", "outputPath", "queryPath", "includeFingerprints"],
+        );
+        
+
+@override Future<void> crateApiExportToM3U({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required bool relativePaths })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_bool(relativePaths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToM3UConstMeta,
+            argValues: [palette, matches, outputPath, relativePaths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToM3UConstMeta => const TaskConstMeta(
+            debugName: "export_to_m3u",
+            argNames: ["palette", "matches", "outputPath", "relativePaths"],
+        );
+        
+
+@override Future<void> crateApiExportToMarkers({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToMarkersConstMeta,
+            argValues: [palette, matches, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToMarkersConstMeta => const TaskConstMeta(
+            debugName: "export_to_markers",
+            argNames: ["palette", "matches", "outputPath"],
+        );
+        
+
+@override Future<void> crateApiExportToMidi({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int tempoBpm , required int baseNote , required int quantizeNoteValue , required double swing , required bool followDetectedTempo , required MidiNoteMapping noteMapping })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_32(tempoBpm, serializer);
+sse_encode_u_8(baseNote, serializer);
+sse_encode_u_16(quantizeNoteValue, serializer);
+sse_encode_f_64(swing, serializer);
+sse_encode_bool(followDetectedTempo, serializer);
+sse_encode_midi_note_mapping(noteMapping, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToMidiConstMeta,
+            argValues: [palette, matches, outputPath, tempoBpm, baseNote, quantizeNoteValue, swing, followDetectedTempo, noteMapping],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToMidiConstMeta => const TaskConstMeta(
+            debugName: "export_to_midi",
+            argNames: ["palette", "matches", "outputPath", "tempoBpm", "baseNote", "quantizeNoteValue", "swing", "followDetectedTempo", "noteMapping"],
+        );
+        
+
+@override Future<void> crateApiExportToReaperRegions({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToReaperRegionsConstMeta,
+            argValues: [palette, matches, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToReaperRegionsConstMeta => const TaskConstMeta(
+            debugName: "export_to_reaper_regions",
+            argNames: ["palette", "matches", "outputPath"],
+        );
+        
+
+@override Future<BigInt> crateApiExportToSfz({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required int lowestNote , required int keysPerSample })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_u_8(lowestNote, serializer);
+sse_encode_u_8(keysPerSample, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToSfzConstMeta,
+            argValues: [palette, matches, outputPath, lowestNote, keysPerSample],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToSfzConstMeta => const TaskConstMeta(
+            debugName: "export_to_sfz",
+            argNames: ["palette", "matches", "outputPath", "lowestNote", "keysPerSample"],
+        );
+        
+
+@override Future<BigInt> crateApiExportToXpm({required PlatformInt64 palette , required List<MatchResult> matches , required String outputPath , required String name , required bool chromatic })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_match_result(matches, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_String(name, serializer);
+sse_encode_bool(chromatic, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportToXpmConstMeta,
+            argValues: [palette, matches, outputPath, name, chromatic],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportToXpmConstMeta => const TaskConstMeta(
+            debugName: "export_to_xpm",
+            argNames: ["palette", "matches", "outputPath", "name", "chromatic"],
+        );
+        
+
+@override Future<List<SoundRecord>> crateApiFindByProperty({required PlatformInt64 palette , required String key , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindByPropertyConstMeta,
+            argValues: [palette, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindByPropertyConstMeta => const TaskConstMeta(
+            debugName: "find_by_property",
+            argNames: ["palette", "key", "value"],
+        );
+        
+
+@override Future<List<SoundRecord>> crateApiFindByTags({required PlatformInt64 palette , required List<String> tags , required bool matchAll , required double minConfidence })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_String(tags, serializer);
+sse_encode_bool(matchAll, serializer);
+sse_encode_f_64(minConfidence, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindByTagsConstMeta,
+            argValues: [palette, tags, matchAll, minConfidence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindByTagsConstMeta => const TaskConstMeta(
+            debugName: "find_by_tags",
+            argNames: ["palette", "tags", "matchAll", "minConfidence"],
+        );
+        
+
+@override Future<List<ContainmentMatch>> crateApiFindContaining({required PlatformInt64 palette , required String queryPath , required double minCorrelation , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(minCorrelation, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_containment_match,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindContainingConstMeta,
+            argValues: [palette, queryPath, minCorrelation, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindContainingConstMeta => const TaskConstMeta(
+            debugName: "find_containing",
+            argNames: ["palette", "queryPath", "minCorrelation", "maxResults"],
+        );
+        
+
+@override Future<SoundRecord?> crateApiFindDuplicate({required PlatformInt64 palette , required String filepath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(filepath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindDuplicateConstMeta,
+            argValues: [palette, filepath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindDuplicateConstMeta => const TaskConstMeta(
+            debugName: "find_duplicate",
+            argNames: ["palette", "filepath"],
+        );
+        
+
+@override Future<List<List<SoundRecord>>> crateApiFindDuplicateGroups({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindDuplicateGroupsConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindDuplicateGroupsConstMeta => const TaskConstMeta(
+            debugName: "find_duplicate_groups",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<List<SoundRecord>>> crateApiFindDuplicates({required PlatformInt64 palette , required double threshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_f_64(threshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindDuplicatesConstMeta,
+            argValues: [palette, threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindDuplicatesConstMeta => const TaskConstMeta(
+            debugName: "find_duplicates",
+            argNames: ["palette", "threshold"],
+        );
+        
+
+@override Future<List<LoopMatch>> crateApiFindLoopSources({required PlatformInt64 palette , required String queryPath , required double threshold , required double minCoverage , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_f_64(minCoverage, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loop_match,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindLoopSourcesConstMeta,
+            argValues: [palette, queryPath, threshold, minCoverage, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindLoopSourcesConstMeta => const TaskConstMeta(
+            debugName: "find_loop_sources",
+            argNames: ["palette", "queryPath", "threshold", "minCoverage", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindMelody({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindMelodyConstMeta,
+            argValues: [palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindMelodyConstMeta => const TaskConstMeta(
+            debugName: "find_melody",
+            argNames: ["palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<SoundRecord>> crateApiFindMissingSounds({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindMissingSoundsConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindMissingSoundsConstMeta => const TaskConstMeta(
+            debugName: "find_missing_sounds",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilar({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarConstMeta,
+            argValues: [palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarConstMeta => const TaskConstMeta(
+            debugName: "find_similar",
+            argNames: ["palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<BatchMatches>> crateApiFindSimilarBatch({required PlatformInt64 palette , required List<BatchQuery> queries , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_batch_query(queries, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_batch_matches,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarBatchConstMeta,
+            argValues: [palette, queries, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarBatchConstMeta => const TaskConstMeta(
+            debugName: "find_similar_batch",
+            argNames: ["palette", "queries", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarById({required PlatformInt64 palette , required PlatformInt64 soundId , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarByIdConstMeta,
+            argValues: [palette, soundId, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarByIdConstMeta => const TaskConstMeta(
+            debugName: "find_similar_by_id",
+            argNames: ["palette", "soundId", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<BatchMatches>> crateApiFindSimilarFromMidi({required PlatformInt64 palette , required String midiPath , required String audioPath , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(midiPath, serializer);
+sse_encode_String(audioPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_batch_matches,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarFromMidiConstMeta,
+            argValues: [palette, midiPath, audioPath, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarFromMidiConstMeta => const TaskConstMeta(
+            debugName: "find_similar_from_midi",
+            argNames: ["palette", "midiPath", "audioPath", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarFromRegion({required PlatformInt64 palette , required PlatformInt64 soundId , required double startS , required double endS , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_f_64(startS, serializer);
+sse_encode_f_64(endS, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarFromRegionConstMeta,
+            argValues: [palette, soundId, startS, endS, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarFromRegionConstMeta => const TaskConstMeta(
+            debugName: "find_similar_from_region",
+            argNames: ["palette", "soundId", "startS", "endS", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarFromSamples({required PlatformInt64 palette , required List<double> samples , required int sampleRate , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_prim_f_32_loose(samples, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarFromSamplesConstMeta,
+            argValues: [palette, samples, sampleRate, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarFromSamplesConstMeta => const TaskConstMeta(
+            debugName: "find_similar_from_samples",
+            argNames: ["palette", "samples", "sampleRate", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<FolderMatch>> crateApiFindSimilarInDirectory({required PlatformInt64 palette , required String queryPath , required String directory , required List<String> extensions , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_String(directory, serializer);
+sse_encode_list_String(extensions, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_folder_match,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarInDirectoryConstMeta,
+            argValues: [palette, queryPath, directory, extensions, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarInDirectoryConstMeta => const TaskConstMeta(
+            debugName: "find_similar_in_directory",
+            argNames: ["palette", "queryPath", "directory", "extensions", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarInRegions({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarInRegionsConstMeta,
+            argValues: [palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarInRegionsConstMeta => const TaskConstMeta(
+            debugName: "find_similar_in_regions",
+            argNames: ["palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Stream<List<MatchResult>> crateApiFindSimilarInRegionsStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { 
+            final sink = RustStreamSink<List<MatchResult>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_list_match_result_Sse(sink, serializer);
+sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarInRegionsStreamConstMeta,
+            argValues: [sink, palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiFindSimilarInRegionsStreamConstMeta => const TaskConstMeta(
+            debugName: "find_similar_in_regions_stream",
+            argNames: ["sink", "palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarMatching({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults , required SoundQuery filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+sse_encode_box_autoadd_sound_query(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarMatchingConstMeta,
+            argValues: [palette, queryPath, threshold, maxResults, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarMatchingConstMeta => const TaskConstMeta(
+            debugName: "find_similar_matching",
+            argNames: ["palette", "queryPath", "threshold", "maxResults", "filter"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarPage({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt offset , required BigInt limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(offset, serializer);
+sse_encode_usize(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarPageConstMeta,
+            argValues: [palette, queryPath, threshold, offset, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarPageConstMeta => const TaskConstMeta(
+            debugName: "find_similar_page",
+            argNames: ["palette", "queryPath", "threshold", "offset", "limit"],
+        );
+        
+
+@override Stream<List<MatchResult>> crateApiFindSimilarStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { 
+            final sink = RustStreamSink<List<MatchResult>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_list_match_result_Sse(sink, serializer);
+sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarStreamConstMeta,
+            argValues: [sink, palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiFindSimilarStreamConstMeta => const TaskConstMeta(
+            debugName: "find_similar_stream",
+            argNames: ["sink", "palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarToExamples({required PlatformInt64 palette , required List<String> positivePaths , required List<String> negativePaths , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_String(positivePaths, serializer);
+sse_encode_list_String(negativePaths, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarToExamplesConstMeta,
+            argValues: [palette, positivePaths, negativePaths, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarToExamplesConstMeta => const TaskConstMeta(
+            debugName: "find_similar_to_examples",
+            argNames: ["palette", "positivePaths", "negativePaths", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarWithOptions({required PlatformInt64 palette , required String queryPath , required SearchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarWithOptionsConstMeta,
+            argValues: [palette, queryPath, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "find_similar_with_options",
+            argNames: ["palette", "queryPath", "options"],
+        );
+        
+
+@override Stream<List<MatchResult>> crateApiFindSimilarWithOptionsStream({required PlatformInt64 palette , required String queryPath , required SearchOptions options })  { 
+            final sink = RustStreamSink<List<MatchResult>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_list_match_result_Sse(sink, serializer);
+sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarWithOptionsStreamConstMeta,
+            argValues: [sink, palette, queryPath, options],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiFindSimilarWithOptionsStreamConstMeta => const TaskConstMeta(
+            debugName: "find_similar_with_options_stream",
+            argNames: ["sink", "palette", "queryPath", "options"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarWithSegments({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarWithSegmentsConstMeta,
+            argValues: [palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarWithSegmentsConstMeta => const TaskConstMeta(
+            debugName: "find_similar_with_segments",
+            argNames: ["palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Stream<List<MatchResult>> crateApiFindSimilarWithSegmentsStream({required PlatformInt64 palette , required String queryPath , required double threshold , required BigInt maxResults })  { 
+            final sink = RustStreamSink<List<MatchResult>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_list_match_result_Sse(sink, serializer);
+sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarWithSegmentsStreamConstMeta,
+            argValues: [sink, palette, queryPath, threshold, maxResults],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiFindSimilarWithSegmentsStreamConstMeta => const TaskConstMeta(
+            debugName: "find_similar_with_segments_stream",
+            argNames: ["sink", "palette", "queryPath", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiFindSimilarWithText({required PlatformInt64 palette , required String queryPath , required String text , required double textWeight , required double threshold , required BigInt maxResults })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(queryPath, serializer);
+sse_encode_String(text, serializer);
+sse_encode_f_64(textWeight, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFindSimilarWithTextConstMeta,
+            argValues: [palette, queryPath, text, textWeight, threshold, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFindSimilarWithTextConstMeta => const TaskConstMeta(
+            debugName: "find_similar_with_text",
+            argNames: ["palette", "queryPath", "text", "textWeight", "threshold", "maxResults"],
+        );
+        
+
+@override Future<List<SoundRecord>> crateApiGetAllSounds({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllSoundsConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllSoundsConstMeta => const TaskConstMeta(
+            debugName: "get_all_sounds",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<TagSummary>> crateApiGetAllTags({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_tag_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllTagsConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllTagsConstMeta => const TaskConstMeta(
+            debugName: "get_all_tags",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<AnalysisTrack> crateApiGetAnalysis({required PlatformInt64 palette , required PlatformInt64 soundId , required AnalysisKind kind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_analysis_kind(kind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_analysis_track,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAnalysisConstMeta,
+            argValues: [palette, soundId, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAnalysisConstMeta => const TaskConstMeta(
+            debugName: "get_analysis",
+            argNames: ["palette", "soundId", "kind"],
+        );
+        
+
+@override Future<List<Thumbnail?>> crateApiGetCachedThumbnails({required PlatformInt64 palette , required Int64List soundIds , required ThumbnailKind kind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_prim_i_64_strict(soundIds, serializer);
+sse_encode_thumbnail_kind(kind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_opt_box_autoadd_thumbnail,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCachedThumbnailsConstMeta,
+            argValues: [palette, soundIds, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCachedThumbnailsConstMeta => const TaskConstMeta(
+            debugName: "get_cached_thumbnails",
+            argNames: ["palette", "soundIds", "kind"],
+        );
+        
+
+@override ClusterProgress? crateApiGetClusterProgress({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_cluster_progress,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetClusterProgressConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetClusterProgressConstMeta => const TaskConstMeta(
+            debugName: "get_cluster_progress",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<SoundRecord>> crateApiGetClusterSounds({required PlatformInt64 palette , required PlatformInt64 clusterId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(clusterId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetClusterSoundsConstMeta,
+            argValues: [palette, clusterId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetClusterSoundsConstMeta => const TaskConstMeta(
+            debugName: "get_cluster_sounds",
+            argNames: ["palette", "clusterId"],
+        );
+        
+
+@override Future<List<SoundCluster>> crateApiGetClusters({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_cluster,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetClustersConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetClustersConstMeta => const TaskConstMeta(
+            debugName: "get_clusters",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<CollectionItem>> crateApiGetCollectionItems({required PlatformInt64 palette , required PlatformInt64 collectionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_collection_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCollectionItemsConstMeta,
+            argValues: [palette, collectionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCollectionItemsConstMeta => const TaskConstMeta(
+            debugName: "get_collection_items",
+            argNames: ["palette", "collectionId"],
+        );
+        
+
+@override Future<List<Collection>> crateApiGetCollections({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_collection,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCollectionsConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCollectionsConstMeta => const TaskConstMeta(
+            debugName: "get_collections",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<AudioFingerprintInfo> crateApiGetFingerprint({required String filepath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filepath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_audio_fingerprint_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetFingerprintConstMeta,
+            argValues: [filepath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetFingerprintConstMeta => const TaskConstMeta(
+            debugName: "get_fingerprint",
+            argNames: ["filepath"],
+        );
+        
+
+@override Future<List<HistoryEntry>> crateApiGetHistory({required PlatformInt64 palette , required PlatformInt64 limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetHistoryConstMeta,
+            argValues: [palette, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_history",
+            argNames: ["palette", "limit"],
+        );
+        
+
+@override IndexProgress? crateApiGetIndexProgress({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_index_progress,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetIndexProgressConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetIndexProgressConstMeta => const TaskConstMeta(
+            debugName: "get_index_progress",
+            argNames: ["palette"],
+        );
+        
+
+@override String? crateApiGetLibraryRoot({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetLibraryRootConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLibraryRootConstMeta => const TaskConstMeta(
+            debugName: "get_library_root",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<MapPoint?> crateApiGetMapPosition({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_map_point,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetMapPositionConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMapPositionConstMeta => const TaskConstMeta(
+            debugName: "get_map_position",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override MapProgress? crateApiGetMapProgress({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_map_progress,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetMapProgressConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMapProgressConstMeta => const TaskConstMeta(
+            debugName: "get_map_progress",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<UsedSound>> crateApiGetMostUsedSounds({required PlatformInt64 palette , required PlatformInt64 limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_used_sound,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetMostUsedSoundsConstMeta,
+            argValues: [palette, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMostUsedSoundsConstMeta => const TaskConstMeta(
+            debugName: "get_most_used_sounds",
+            argNames: ["palette", "limit"],
+        );
+        
+
+@override Future<NoiseInfo?> crateApiGetNoiseInfo({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_noise_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNoiseInfoConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNoiseInfoConstMeta => const TaskConstMeta(
+            debugName: "get_noise_info",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Int64List crateApiGetOpenPalettes()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetOpenPalettesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOpenPalettesConstMeta => const TaskConstMeta(
+            debugName: "get_open_palettes",
+            argNames: [],
+        );
+        
+
+@override OptimizeProgress? crateApiGetOptimizeProgress({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_optimize_progress,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetOptimizeProgressConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOptimizeProgressConstMeta => const TaskConstMeta(
+            debugName: "get_optimize_progress",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiGetOutdatedFingerprintCount({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOutdatedFingerprintCountConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOutdatedFingerprintCountConstMeta => const TaskConstMeta(
+            debugName: "get_outdated_fingerprint_count",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<RecentSearch>> crateApiGetRecentSearches({required PlatformInt64 palette , required PlatformInt64 limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_recent_search,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRecentSearchesConstMeta,
+            argValues: [palette, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRecentSearchesConstMeta => const TaskConstMeta(
+            debugName: "get_recent_searches",
+            argNames: ["palette", "limit"],
+        );
+        
+
+@override Future<List<UsedSound>> crateApiGetRecentlyUsedSounds({required PlatformInt64 palette , required PlatformInt64 limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_used_sound,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRecentlyUsedSoundsConstMeta,
+            argValues: [palette, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRecentlyUsedSoundsConstMeta => const TaskConstMeta(
+            debugName: "get_recently_used_sounds",
+            argNames: ["palette", "limit"],
+        );
+        
+
+@override Future<List<MatchResult>> crateApiGetRegionMatches({required PlatformInt64 palette , required Int64List regionIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_list_prim_i_64_strict(regionIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_match_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRegionMatchesConstMeta,
+            argValues: [palette, regionIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRegionMatchesConstMeta => const TaskConstMeta(
+            debugName: "get_region_matches",
+            argNames: ["palette", "regionIds"],
+        );
+        
+
+@override Future<List<Region>> crateApiGetRegions({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_region,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRegionsConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRegionsConstMeta => const TaskConstMeta(
+            debugName: "get_regions",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Future<List<SmartCollection>> crateApiGetSmartCollections({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_smart_collection,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSmartCollectionsConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSmartCollectionsConstMeta => const TaskConstMeta(
+            debugName: "get_smart_collections",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<PlatformInt64?> crateApiGetSoundCluster({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundClusterConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundClusterConstMeta => const TaskConstMeta(
+            debugName: "get_sound_cluster",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override PlatformInt64 crateApiGetSoundCount({required PlatformInt64 palette })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundCountConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundCountConstMeta => const TaskConstMeta(
+            debugName: "get_sound_count",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<MapPoint>> crateApiGetSoundMap({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_map_point,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundMapConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundMapConstMeta => const TaskConstMeta(
+            debugName: "get_sound_map",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<List<SoundProperty>> crateApiGetSoundProperties({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_property,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundPropertiesConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundPropertiesConstMeta => const TaskConstMeta(
+            debugName: "get_sound_properties",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Future<String?> crateApiGetSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_String(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundPropertyConstMeta,
+            argValues: [palette, soundId, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundPropertyConstMeta => const TaskConstMeta(
+            debugName: "get_sound_property",
+            argNames: ["palette", "soundId", "key"],
+        );
+        
+
+@override Future<SoundUsage> crateApiGetSoundUsage({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sound_usage,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundUsageConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundUsageConstMeta => const TaskConstMeta(
+            debugName: "get_sound_usage",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Future<SoundPage> crateApiGetSoundsPage({required PlatformInt64 palette , required PlatformInt64 offset , required PlatformInt64 limit , required SoundSort sort , required bool descending })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(offset, serializer);
+sse_encode_i_64(limit, serializer);
+sse_encode_sound_sort(sort, serializer);
+sse_encode_bool(descending, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sound_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSoundsPageConstMeta,
+            argValues: [palette, offset, limit, sort, descending],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSoundsPageConstMeta => const TaskConstMeta(
+            debugName: "get_sounds_page",
+            argNames: ["palette", "offset", "limit", "sort", "descending"],
+        );
+        
+
+@override Future<List<SoundTag>> crateApiGetTags({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_tag,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetTagsConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTagsConstMeta => const TaskConstMeta(
+            debugName: "get_tags",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Future<Thumbnail?> crateApiGetThumbnail({required PlatformInt64 palette , required PlatformInt64 soundId , required ThumbnailKind kind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_thumbnail_kind(kind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_thumbnail,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetThumbnailConstMeta,
+            argValues: [palette, soundId, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetThumbnailConstMeta => const TaskConstMeta(
+            debugName: "get_thumbnail",
+            argNames: ["palette", "soundId", "kind"],
+        );
+        
+
+@override Future<WaveformPeaks> crateApiGetWaveform({required PlatformInt64 palette , required PlatformInt64 soundId , required BigInt buckets })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_usize(buckets, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_waveform_peaks,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetWaveformConstMeta,
+            argValues: [palette, soundId, buckets],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWaveformConstMeta => const TaskConstMeta(
+            debugName: "get_waveform",
+            argNames: ["palette", "soundId", "buckets"],
+        );
+        
+
+@override Future<ImportSummary> crateApiImportLibrary({required PlatformInt64 palette , required String archivePath , String? audioDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(archivePath, serializer);
+sse_encode_opt_String(audioDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_import_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportLibraryConstMeta,
+            argValues: [palette, archivePath, audioDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportLibraryConstMeta => const TaskConstMeta(
+            debugName: "import_library",
+            argNames: ["palette", "archivePath", "audioDir"],
+        );
+        
+
+@override Future<IndexProgress> crateApiIndexDirectory({required PlatformInt64 palette , required String path , required List<String> extensions , BigInt? maxFileSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(path, serializer);
+sse_encode_list_String(extensions, serializer);
+sse_encode_opt_box_autoadd_u_64(maxFileSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_progress,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIndexDirectoryConstMeta,
+            argValues: [palette, path, extensions, maxFileSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIndexDirectoryConstMeta => const TaskConstMeta(
+            debugName: "index_directory",
+            argNames: ["palette", "path", "extensions", "maxFileSize"],
+        );
+        
+
+@override Future<void> crateApiLoadTaggingModel({required String modelPath , required String labelsPath , required double threshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(modelPath, serializer);
+sse_encode_String(labelsPath, serializer);
+sse_encode_f_64(threshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLoadTaggingModelConstMeta,
+            argValues: [modelPath, labelsPath, threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoadTaggingModelConstMeta => const TaskConstMeta(
+            debugName: "load_tagging_model",
+            argNames: ["modelPath", "labelsPath", "threshold"],
+        );
+        
+
+@override Future<MergeSummary> crateApiMergeDatabase({required PlatformInt64 palette , required String otherPath , required ConflictPolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(otherPath, serializer);
+sse_encode_conflict_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_merge_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMergeDatabaseConstMeta,
+            argValues: [palette, otherPath, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMergeDatabaseConstMeta => const TaskConstMeta(
+            debugName: "merge_database",
+            argNames: ["palette", "otherPath", "policy"],
+        );
+        
+
+@override Future<void> crateApiMoveCollectionItem({required PlatformInt64 palette , required PlatformInt64 itemId , required BigInt position })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(itemId, serializer);
+sse_encode_usize(position, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMoveCollectionItemConstMeta,
+            argValues: [palette, itemId, position],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoveCollectionItemConstMeta => const TaskConstMeta(
+            debugName: "move_collection_item",
+            argNames: ["palette", "itemId", "position"],
+        );
+        
+
+@override PlatformInt64 crateApiOpenEncryptedPalette({required String dbPath , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dbPath, serializer);
+sse_encode_String(passphrase, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOpenEncryptedPaletteConstMeta,
+            argValues: [dbPath, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenEncryptedPaletteConstMeta => const TaskConstMeta(
+            debugName: "open_encrypted_palette",
+            argNames: ["dbPath", "passphrase"],
+        );
+        
+
+@override PlatformInt64 crateApiOpenPalette({required String dbPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dbPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOpenPaletteConstMeta,
+            argValues: [dbPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenPaletteConstMeta => const TaskConstMeta(
+            debugName: "open_palette",
+            argNames: ["dbPath"],
+        );
+        
+
+@override Future<OptimizeSummary> crateApiOptimizeDatabase({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_optimize_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOptimizeDatabaseConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOptimizeDatabaseConstMeta => const TaskConstMeta(
+            debugName: "optimize_database",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<void> crateApiPushLiveAudio({required PlatformInt64 session , required List<double> samples })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(session, serializer);
+sse_encode_list_prim_f_32_loose(samples, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPushLiveAudioConstMeta,
+            argValues: [session, samples],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPushLiveAudioConstMeta => const TaskConstMeta(
+            debugName: "push_live_audio",
+            argNames: ["session", "samples"],
+        );
+        
+
+@override Future<SoundPage> crateApiQuerySounds({required PlatformInt64 palette , required SoundQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_box_autoadd_sound_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sound_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiQuerySoundsConstMeta,
+            argValues: [palette, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQuerySoundsConstMeta => const TaskConstMeta(
+            debugName: "query_sounds",
+            argNames: ["palette", "query"],
+        );
+        
+
+@override Future<List<MidiRegion>> crateApiReadMidiRegions({required String midiPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(midiPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_midi_region,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiReadMidiRegionsConstMeta,
+            argValues: [midiPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadMidiRegionsConstMeta => const TaskConstMeta(
+            debugName: "read_midi_regions",
+            argNames: ["midiPath"],
+        );
+        
+
+@override Future<void> crateApiRecordAudition({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordAuditionConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordAuditionConstMeta => const TaskConstMeta(
+            debugName: "record_audition",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Future<double> crateApiRelinkSound({required PlatformInt64 palette , required PlatformInt64 soundId , required String newPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_String(newPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRelinkSoundConstMeta,
+            argValues: [palette, soundId, newPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRelinkSoundConstMeta => const TaskConstMeta(
+            debugName: "relink_sound",
+            argNames: ["palette", "soundId", "newPath"],
+        );
+        
+
+@override Future<void> crateApiRemoveFromCollection({required PlatformInt64 palette , required PlatformInt64 itemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(itemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRemoveFromCollectionConstMeta,
+            argValues: [palette, itemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveFromCollectionConstMeta => const TaskConstMeta(
+            debugName: "remove_from_collection",
+            argNames: ["palette", "itemId"],
+        );
+        
+
+@override Future<void> crateApiRemoveSound({required PlatformInt64 palette , required PlatformInt64 soundId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRemoveSoundConstMeta,
+            argValues: [palette, soundId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveSoundConstMeta => const TaskConstMeta(
+            debugName: "remove_sound",
+            argNames: ["palette", "soundId"],
+        );
+        
+
+@override Future<void> crateApiRemoveSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_String(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRemoveSoundPropertyConstMeta,
+            argValues: [palette, soundId, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveSoundPropertyConstMeta => const TaskConstMeta(
+            debugName: "remove_sound_property",
+            argNames: ["palette", "soundId", "key"],
+        );
+        
+
+@override Future<void> crateApiRemoveTag({required PlatformInt64 palette , required PlatformInt64 soundId , required String tag })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_String(tag, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRemoveTagConstMeta,
+            argValues: [palette, soundId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveTagConstMeta => const TaskConstMeta(
+            debugName: "remove_tag",
+            argNames: ["palette", "soundId", "tag"],
+        );
+        
+
+@override Future<void> crateApiRenameTag({required PlatformInt64 palette , required String oldName , required String newName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(oldName, serializer);
+sse_encode_String(newName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRenameTagConstMeta,
+            argValues: [palette, oldName, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenameTagConstMeta => const TaskConstMeta(
+            debugName: "rename_tag",
+            argNames: ["palette", "oldName", "newName"],
+        );
+        
+
+@override Future<Uint8List> crateApiRenderSpectrogramPng({required String filepath , double? startS , double? endS , required int width , required int height , required Colormap colormap , required double minDb , required double maxDb })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filepath, serializer);
+sse_encode_opt_box_autoadd_f_64(startS, serializer);
+sse_encode_opt_box_autoadd_f_64(endS, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_colormap(colormap, serializer);
+sse_encode_f_64(minDb, serializer);
+sse_encode_f_64(maxDb, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRenderSpectrogramPngConstMeta,
+            argValues: [filepath, startS, endS, width, height, colormap, minDb, maxDb],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderSpectrogramPngConstMeta => const TaskConstMeta(
+            debugName: "render_spectrogram_png",
+            argNames: ["filepath", "startS", "endS", "width", "height", "colormap", "minDb", "maxDb"],
+        );
+        
+
+@override Future<void> crateApiReorderCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required Int64List itemIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_list_prim_i_64_strict(itemIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiReorderCollectionConstMeta,
+            argValues: [palette, collectionId, itemIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReorderCollectionConstMeta => const TaskConstMeta(
+            debugName: "reorder_collection",
+            argNames: ["palette", "collectionId", "itemIds"],
+        );
+        
+
+@override Future<RescanSummary> crateApiRescanLibrary({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rescan_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRescanLibraryConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRescanLibraryConstMeta => const TaskConstMeta(
+            debugName: "rescan_library",
+            argNames: ["palette"],
+        );
+        
+
+@override SearchOptions crateApiSearchPresetOptions({required SearchPreset preset , required BigInt maxResults })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_search_preset(preset, serializer);
+sse_encode_usize(maxResults, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchPresetOptionsConstMeta,
+            argValues: [preset, maxResults],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchPresetOptionsConstMeta => const TaskConstMeta(
+            debugName: "search_preset_options",
+            argNames: ["preset", "maxResults"],
+        );
+        
+
+@override Future<List<SoundRecord>> crateApiSearchSounds({required PlatformInt64 palette , required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sound_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchSoundsConstMeta,
+            argValues: [palette, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSoundsConstMeta => const TaskConstMeta(
+            debugName: "search_sounds",
+            argNames: ["palette", "query"],
+        );
+        
+
+@override Future<BigInt> crateApiSetLibraryRoot({required PlatformInt64 palette , String? root })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_opt_String(root, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetLibraryRootConstMeta,
+            argValues: [palette, root],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetLibraryRootConstMeta => const TaskConstMeta(
+            debugName: "set_library_root",
+            argNames: ["palette", "root"],
+        );
+        
+
+@override Future<void> crateApiSetSoundProperty({required PlatformInt64 palette , required PlatformInt64 soundId , required String key , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetSoundPropertyConstMeta,
+            argValues: [palette, soundId, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetSoundPropertyConstMeta => const TaskConstMeta(
+            debugName: "set_sound_property",
+            argNames: ["palette", "soundId", "key", "value"],
+        );
+        
+
+@override Future<void> crateApiSetSoundRating({required PlatformInt64 palette , required PlatformInt64 soundId , required int rating })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_u_8(rating, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetSoundRatingConstMeta,
+            argValues: [palette, soundId, rating],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetSoundRatingConstMeta => const TaskConstMeta(
+            debugName: "set_sound_rating",
+            argNames: ["palette", "soundId", "rating"],
+        );
+        
+
+@override Stream<List<MatchResult>> crateApiStartLiveQuery({required PlatformInt64 palette , required int sampleRate , required double windowSeconds , required double threshold , required BigInt maxResults })  { 
+            final sink = RustStreamSink<List<MatchResult>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_list_match_result_Sse(sink, serializer);
+sse_encode_i_64(palette, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_f_64(windowSeconds, serializer);
+sse_encode_f_64(threshold, serializer);
+sse_encode_usize(maxResults, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartLiveQueryConstMeta,
+            argValues: [sink, palette, sampleRate, windowSeconds, threshold, maxResults],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiStartLiveQueryConstMeta => const TaskConstMeta(
+            debugName: "start_live_query",
+            argNames: ["sink", "palette", "sampleRate", "windowSeconds", "threshold", "maxResults"],
+        );
+        
+
+@override void crateApiStopLiveQuery({required PlatformInt64 session })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(session, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiStopLiveQueryConstMeta,
+            argValues: [session],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopLiveQueryConstMeta => const TaskConstMeta(
+            debugName: "stop_live_query",
+            argNames: ["session"],
+        );
+        
+
+@override Future<HistoryEntry> crateApiUndoChange({required PlatformInt64 palette , required PlatformInt64 entryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(entryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_history_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUndoChangeConstMeta,
+            argValues: [palette, entryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUndoChangeConstMeta => const TaskConstMeta(
+            debugName: "undo_change",
+            argNames: ["palette", "entryId"],
+        );
+        
+
+@override Future<HistoryEntry?> crateApiUndoLastChange({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_history_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUndoLastChangeConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUndoLastChangeConstMeta => const TaskConstMeta(
+            debugName: "undo_last_change",
+            argNames: ["palette"],
+        );
+        
+
+@override void crateApiUnloadTaggingModel()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUnloadTaggingModelConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnloadTaggingModelConstMeta => const TaskConstMeta(
+            debugName: "unload_tagging_model",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiUpdateCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required String name , required String description })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(description, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateCollectionConstMeta,
+            argValues: [palette, collectionId, name, description],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateCollectionConstMeta => const TaskConstMeta(
+            debugName: "update_collection",
+            argNames: ["palette", "collectionId", "name", "description"],
+        );
+        
+
+@override Future<void> crateApiUpdateRegion({required PlatformInt64 palette , required PlatformInt64 regionId , required double startTime , required double endTime , required String label , required String color })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(regionId, serializer);
+sse_encode_f_64(startTime, serializer);
+sse_encode_f_64(endTime, serializer);
+sse_encode_String(label, serializer);
+sse_encode_String(color, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateRegionConstMeta,
+            argValues: [palette, regionId, startTime, endTime, label, color],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateRegionConstMeta => const TaskConstMeta(
+            debugName: "update_region",
+            argNames: ["palette", "regionId", "startTime", "endTime", "label", "color"],
+        );
+        
+
+@override Future<void> crateApiUpdateSmartCollection({required PlatformInt64 palette , required PlatformInt64 collectionId , required String name , required SmartQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(collectionId, serializer);
+sse_encode_String(name, serializer);
+sse_encode_box_autoadd_smart_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateSmartCollectionConstMeta,
+            argValues: [palette, collectionId, name, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateSmartCollectionConstMeta => const TaskConstMeta(
+            debugName: "update_smart_collection",
+            argNames: ["palette", "collectionId", "name", "query"],
+        );
+        
+
+@override Future<void> crateApiUpdateSoundMetadata({required PlatformInt64 palette , required PlatformInt64 soundId , String? title , String? artist , String? notes , String? source })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_i_64(soundId, serializer);
+sse_encode_opt_String(title, serializer);
+sse_encode_opt_String(artist, serializer);
+sse_encode_opt_String(notes, serializer);
+sse_encode_opt_String(source, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateSoundMetadataConstMeta,
+            argValues: [palette, soundId, title, artist, notes, source],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateSoundMetadataConstMeta => const TaskConstMeta(
+            debugName: "update_sound_metadata",
+            argNames: ["palette", "soundId", "title", "artist", "notes", "source"],
+        );
+        
+
+@override Future<VerifyReport> crateApiVerifyLibrary({required PlatformInt64 palette })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_verify_report,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiVerifyLibraryConstMeta,
+            argValues: [palette],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifyLibraryConstMeta => const TaskConstMeta(
+            debugName: "verify_library",
+            argNames: ["palette"],
+        );
+        
+
+@override Future<BigInt> crateApiWriteClusterCategories({required PlatformInt64 palette , required String parent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(palette, serializer);
+sse_encode_String(parent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWriteClusterCategoriesConstMeta,
+            argValues: [palette, parent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWriteClusterCategoriesConstMeta => const TaskConstMeta(
+            debugName: "write_cluster_categories",
+            argNames: ["palette", "parent"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected String dco_decode_Char(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return String.fromCharCode(raw); }
+
+@protected RustStreamSink<List<MatchResult>> dco_decode_StreamSink_list_match_result_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AnalysisKind dco_decode_analysis_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnalysisKind.values[raw as int]; }
+
+@protected AnalysisTrack dco_decode_analysis_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return AnalysisTrack(kind: dco_decode_analysis_kind(arr[0]),
+hopSeconds: dco_decode_f_64(arr[1]),
+values: dco_decode_list_prim_f_64_strict(arr[2]),); }
+
+@protected ArchiveSummary dco_decode_archive_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ArchiveSummary(sounds: dco_decode_usize(arr[0]),
+audioFiles: dco_decode_usize(arr[1]),); }
+
+@protected AudioFingerprintInfo dco_decode_audio_fingerprint_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return AudioFingerprintInfo(duration: dco_decode_f_64(arr[0]),
+spectralCentroid: dco_decode_f_64(arr[1]),
+spectralBandwidth: dco_decode_f_64(arr[2]),
+spectralRolloff: dco_decode_f_64(arr[3]),
+mfccMean: dco_decode_list_prim_f_64_strict(arr[4]),
+mfccStd: dco_decode_list_prim_f_64_strict(arr[5]),); }
+
+@protected BatchMatches dco_decode_batch_matches(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BatchMatches(query: dco_decode_batch_query(arr[0]),
+matches: dco_decode_list_match_result(arr[1]),
+error: dco_decode_opt_String(arr[2]),); }
+
+@protected BatchQuery dco_decode_batch_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BatchQuery(path: dco_decode_String(arr[0]),
+startTime: dco_decode_opt_box_autoadd_f_64(arr[1]),
+endTime: dco_decode_opt_box_autoadd_f_64(arr[2]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected ClusterProgress dco_decode_box_autoadd_cluster_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cluster_progress(raw); }
+
+@protected CsvExportOptions dco_decode_box_autoadd_csv_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_csv_export_options(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected HistoryEntry dco_decode_box_autoadd_history_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_history_entry(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected IndexProgress dco_decode_box_autoadd_index_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_index_progress(raw); }
+
+@protected MapPoint dco_decode_box_autoadd_map_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_map_point(raw); }
+
+@protected MapProgress dco_decode_box_autoadd_map_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_map_progress(raw); }
+
+@protected MatchResult dco_decode_box_autoadd_match_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_match_result(raw); }
+
+@protected NoiseInfo dco_decode_box_autoadd_noise_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_noise_info(raw); }
+
+@protected OptimizeProgress dco_decode_box_autoadd_optimize_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_optimize_progress(raw); }
+
+@protected SamplerEnvelope dco_decode_box_autoadd_sampler_envelope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_sampler_envelope(raw); }
+
+@protected SearchOptions dco_decode_box_autoadd_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_options(raw); }
+
+@protected SmartQuery dco_decode_box_autoadd_smart_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_smart_query(raw); }
+
+@protected SoundQuery dco_decode_box_autoadd_sound_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_sound_query(raw); }
+
+@protected SoundRecord dco_decode_box_autoadd_sound_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_sound_record(raw); }
+
+@protected Thumbnail dco_decode_box_autoadd_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_thumbnail(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected ClusterProgress dco_decode_cluster_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ClusterProgress(iteration: dco_decode_usize(arr[0]),
+maxIterations: dco_decode_usize(arr[1]),
+finished: dco_decode_bool(arr[2]),); }
+
+@protected ClusteringSummary dco_decode_clustering_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ClusteringSummary(clusters: dco_decode_usize(arr[0]),
+sounds: dco_decode_usize(arr[1]),
+iterations: dco_decode_usize(arr[2]),); }
+
+@protected Collection dco_decode_collection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return Collection(id: dco_decode_i_64(arr[0]),
+name: dco_decode_String(arr[1]),
+description: dco_decode_String(arr[2]),
+dateCreated: dco_decode_String(arr[3]),
+itemCount: dco_decode_i_64(arr[4]),); }
+
+@protected CollectionItem dco_decode_collection_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CollectionItem(id: dco_decode_i_64(arr[0]),
+position: dco_decode_i_64(arr[1]),
+startTime: dco_decode_opt_box_autoadd_f_64(arr[2]),
+endTime: dco_decode_opt_box_autoadd_f_64(arr[3]),
+sound: dco_decode_sound_record(arr[4]),); }
+
+@protected Colormap dco_decode_colormap(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Colormap.values[raw as int]; }
+
+@protected ConflictPolicy dco_decode_conflict_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConflictPolicy.values[raw as int]; }
+
+@protected ContainmentMatch dco_decode_containment_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return ContainmentMatch(soundId: dco_decode_i_64(arr[0]),
+filepath: dco_decode_String(arr[1]),
+filename: dco_decode_String(arr[2]),
+offsetSamples: dco_decode_usize(arr[3]),
+sampleRate: dco_decode_u_32(arr[4]),
+matchStart: dco_decode_f_64(arr[5]),
+matchEnd: dco_decode_f_64(arr[6]),
+correlation: dco_decode_f_64(arr[7]),
+gainDb: dco_decode_f_64(arr[8]),); }
+
+@protected CsvColumn dco_decode_csv_column(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CsvColumn.values[raw as int]; }
+
+@protected CsvExportOptions dco_decode_csv_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CsvExportOptions(delimiter: dco_decode_Char(arr[0]),
+columns: dco_decode_list_csv_column(arr[1]),
+header: dco_decode_bool(arr[2]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FeatureContribution dco_decode_feature_contribution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FeatureContribution(group: dco_decode_feature_group(arr[0]),
+similarity: dco_decode_f_64(arr[1]),
+weight: dco_decode_f_64(arr[2]),); }
+
+@protected FeatureGroup dco_decode_feature_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FeatureGroup.values[raw as int]; }
+
+@protected FolderMatch dco_decode_folder_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FolderMatch(filepath: dco_decode_String(arr[0]),
+filename: dco_decode_String(arr[1]),
+score: dco_decode_f_64(arr[2]),
+duration: dco_decode_f_64(arr[3]),); }
+
+@protected HistoryEntry dco_decode_history_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return HistoryEntry(id: dco_decode_i_64(arr[0]),
+operation: dco_decode_history_operation(arr[1]),
+soundId: dco_decode_i_64(arr[2]),
+filename: dco_decode_String(arr[3]),
+timestamp: dco_decode_String(arr[4]),
+undone: dco_decode_bool(arr[5]),); }
+
+@protected HistoryOperation dco_decode_history_operation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HistoryOperation.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImportSummary dco_decode_import_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ImportSummary(added: dco_decode_usize(arr[0]),
+merged: dco_decode_usize(arr[1]),
+audioFiles: dco_decode_usize(arr[2]),); }
+
+@protected IndexProgress dco_decode_index_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return IndexProgress(total: dco_decode_usize(arr[0]),
+processed: dco_decode_usize(arr[1]),
+added: dco_decode_usize(arr[2]),
+duplicates: dco_decode_usize(arr[3]),
+skipped: dco_decode_usize(arr[4]),
+reanalyzed: dco_decode_usize(arr[5]),
+failed: dco_decode_usize(arr[6]),
+finished: dco_decode_bool(arr[7]),
+cancelled: dco_decode_bool(arr[8]),); }
+
+@protected IntegrityReport dco_decode_integrity_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IntegrityReport(sqliteErrors: dco_decode_list_String(arr[0]),
+missingFingerprints: dco_decode_list_prim_i_64_strict(arr[1]),
+unreadableFingerprints: dco_decode_list_prim_i_64_strict(arr[2]),
+orphanedFingerprints: dco_decode_list_prim_i_64_strict(arr[3]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<BatchMatches> dco_decode_list_batch_matches(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_batch_matches).toList(); }
+
+@protected List<BatchQuery> dco_decode_list_batch_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_batch_query).toList(); }
+
+@protected List<Collection> dco_decode_list_collection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_collection).toList(); }
+
+@protected List<CollectionItem> dco_decode_list_collection_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_collection_item).toList(); }
+
+@protected List<ContainmentMatch> dco_decode_list_containment_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_containment_match).toList(); }
+
+@protected List<CsvColumn> dco_decode_list_csv_column(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_csv_column).toList(); }
+
+@protected List<FeatureContribution> dco_decode_list_feature_contribution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_feature_contribution).toList(); }
+
+@protected List<FeatureGroup> dco_decode_list_feature_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_feature_group).toList(); }
+
+@protected List<FolderMatch> dco_decode_list_folder_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_folder_match).toList(); }
+
+@protected List<HistoryEntry> dco_decode_list_history_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_history_entry).toList(); }
+
+@protected List<List<SoundRecord>> dco_decode_list_list_sound_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_sound_record).toList(); }
+
+@protected List<LoopMatch> dco_decode_list_loop_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_loop_match).toList(); }
+
+@protected List<MapPoint> dco_decode_list_map_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_map_point).toList(); }
+
+@protected List<MatchResult> dco_decode_list_match_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_match_result).toList(); }
+
+@protected List<MidiRegion> dco_decode_list_midi_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_midi_region).toList(); }
+
+@protected List<PlatformInt64?> dco_decode_list_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_i_64).toList(); }
+
+@protected List<Thumbnail?> dco_decode_list_opt_box_autoadd_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_thumbnail).toList(); }
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float64List; }
+
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeInt64List(raw); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<RecentSearch> dco_decode_list_recent_search(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_recent_search).toList(); }
+
+@protected List<Region> dco_decode_list_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_region).toList(); }
+
+@protected List<SmartCollection> dco_decode_list_smart_collection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_smart_collection).toList(); }
+
+@protected List<SoundCluster> dco_decode_list_sound_cluster(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sound_cluster).toList(); }
+
+@protected List<SoundProperty> dco_decode_list_sound_property(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sound_property).toList(); }
+
+@protected List<SoundRecord> dco_decode_list_sound_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sound_record).toList(); }
+
+@protected List<SoundTag> dco_decode_list_sound_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sound_tag).toList(); }
+
+@protected List<TagSummary> dco_decode_list_tag_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_tag_summary).toList(); }
+
+@protected List<UsedSound> dco_decode_list_used_sound(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_used_sound).toList(); }
+
+@protected LoopMatch dco_decode_loop_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return LoopMatch(soundId: dco_decode_i_64(arr[0]),
+filepath: dco_decode_String(arr[1]),
+filename: dco_decode_String(arr[2]),
+score: dco_decode_f_64(arr[3]),
+coverage: dco_decode_f_64(arr[4]),
+loopDuration: dco_decode_f_64(arr[5]),
+repetitions: dco_decode_f_64(arr[6]),); }
+
+@protected MapPoint dco_decode_map_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MapPoint(soundId: dco_decode_i_64(arr[0]),
+x: dco_decode_f_64(arr[1]),
+y: dco_decode_f_64(arr[2]),); }
+
+@protected MapProgress dco_decode_map_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MapProgress(iteration: dco_decode_usize(arr[0]),
+maxIterations: dco_decode_usize(arr[1]),
+finished: dco_decode_bool(arr[2]),); }
+
+@protected MatchResult dco_decode_match_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MatchResult(soundId: dco_decode_i_64(arr[0]),
+filepath: dco_decode_String(arr[1]),
+filename: dco_decode_String(arr[2]),
+score: dco_decode_f_64(arr[3]),
+matchStart: dco_decode_f_64(arr[4]),
+matchEnd: dco_decode_f_64(arr[5]),
+fileDuration: dco_decode_f_64(arr[6]),
+timeResolution: dco_decode_f_64(arr[7]),); }
+
+@protected MergeSummary dco_decode_merge_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MergeSummary(added: dco_decode_usize(arr[0]),
+merged: dco_decode_usize(arr[1]),
+skipped: dco_decode_usize(arr[2]),
+collections: dco_decode_usize(arr[3]),
+categories: dco_decode_usize(arr[4]),); }
+
+@protected MidiNoteMapping dco_decode_midi_note_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MidiNoteMapping.values[raw as int]; }
+
+@protected MidiRegion dco_decode_midi_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MidiRegion(startTime: dco_decode_f_64(arr[0]),
+endTime: dco_decode_f_64(arr[1]),
+note: dco_decode_u_8(arr[2]),
+velocity: dco_decode_u_8(arr[3]),
+track: dco_decode_usize(arr[4]),
+trackName: dco_decode_String(arr[5]),); }
+
+@protected NoiseInfo dco_decode_noise_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NoiseInfo(noiseFloorDb: dco_decode_f_64(arr[0]),
+snrDb: dco_decode_f_64(arr[1]),
+isNoisy: dco_decode_bool(arr[2]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected ClusterProgress? dco_decode_opt_box_autoadd_cluster_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cluster_progress(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected HistoryEntry? dco_decode_opt_box_autoadd_history_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_history_entry(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected IndexProgress? dco_decode_opt_box_autoadd_index_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_index_progress(raw); }
+
+@protected MapPoint? dco_decode_opt_box_autoadd_map_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_map_point(raw); }
+
+@protected MapProgress? dco_decode_opt_box_autoadd_map_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_map_progress(raw); }
+
+@protected NoiseInfo? dco_decode_opt_box_autoadd_noise_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_noise_info(raw); }
+
+@protected OptimizeProgress? dco_decode_opt_box_autoadd_optimize_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_optimize_progress(raw); }
+
+@protected SoundRecord? dco_decode_opt_box_autoadd_sound_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_sound_record(raw); }
+
+@protected Thumbnail? dco_decode_opt_box_autoadd_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_thumbnail(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected OptimizeProgress dco_decode_optimize_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return OptimizeProgress(step: dco_decode_optimize_step(arr[0]),
+completed: dco_decode_usize(arr[1]),
+total: dco_decode_usize(arr[2]),
+finished: dco_decode_bool(arr[3]),); }
+
+@protected OptimizeStep dco_decode_optimize_step(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OptimizeStep.values[raw as int]; }
+
+@protected OptimizeSummary dco_decode_optimize_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return OptimizeSummary(sizeBefore: dco_decode_i_64(arr[0]),
+sizeAfter: dco_decode_i_64(arr[1]),); }
+
+@protected RecentSearch dco_decode_recent_search(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RecentSearch(id: dco_decode_i_64(arr[0]),
+kind: dco_decode_search_kind(arr[1]),
+query: dco_decode_String(arr[2]),
+parameters: dco_decode_String(arr[3]),
+resultCount: dco_decode_i_64(arr[4]),
+timestamp: dco_decode_String(arr[5]),); }
+
+@protected Region dco_decode_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return Region(id: dco_decode_i_64(arr[0]),
+soundId: dco_decode_i_64(arr[1]),
+startTime: dco_decode_f_64(arr[2]),
+endTime: dco_decode_f_64(arr[3]),
+label: dco_decode_String(arr[4]),
+color: dco_decode_String(arr[5]),); }
+
+@protected RescanSummary dco_decode_rescan_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RescanSummary(checked: dco_decode_usize(arr[0]),
+changed: dco_decode_usize(arr[1]),
+touched: dco_decode_usize(arr[2]),
+outdated: dco_decode_usize(arr[3]),
+missing: dco_decode_usize(arr[4]),
+failed: dco_decode_usize(arr[5]),); }
+
+@protected SamplerEnvelope dco_decode_sampler_envelope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SamplerEnvelope(attack: dco_decode_f_64(arr[0]),
+decay: dco_decode_f_64(arr[1]),
+sustain: dco_decode_f_64(arr[2]),
+release: dco_decode_f_64(arr[3]),); }
+
+@protected SearchKind dco_decode_search_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SearchKind.values[raw as int]; }
+
+@protected SearchOptions dco_decode_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 18) throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+                return SearchOptions(threshold: dco_decode_f_64(arr[0]),
+maxResults: dco_decode_usize(arr[1]),
+dtwRerank: dco_decode_bool(arr[2]),
+dtwWeight: dco_decode_f_64(arr[3]),
+dtwAlign: dco_decode_bool(arr[4]),
+refineOffsets: dco_decode_bool(arr[5]),
+allSegments: dco_decode_bool(arr[6]),
+diversity: dco_decode_f_64(arr[7]),
+ignoredFeatures: dco_decode_list_feature_group(arr[8]),
+snrPenalty: dco_decode_f_64(arr[9]),
+calibrateScores: dco_decode_bool(arr[10]),
+excludedSounds: dco_decode_list_prim_i_64_strict(arr[11]),
+exclusionThreshold: dco_decode_f_64(arr[12]),
+lshPrefilter: dco_decode_bool(arr[13]),
+memoryBudgetMb: dco_decode_usize(arr[14]),
+segmentWindowS: dco_decode_f_64(arr[15]),
+segmentOverlap: dco_decode_f_64(arr[16]),
+maxSegmentWindows: dco_decode_usize(arr[17]),); }
+
+@protected SearchPreset dco_decode_search_preset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SearchPreset.values[raw as int]; }
+
+@protected SmartCollection dco_decode_smart_collection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SmartCollection(id: dco_decode_i_64(arr[0]),
+name: dco_decode_String(arr[1]),
+query: dco_decode_smart_query(arr[2]),
+dateCreated: dco_decode_String(arr[3]),); }
+
+@protected SmartQuery dco_decode_smart_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SmartQuery(filter: dco_decode_sound_query(arr[0]),
+tags: dco_decode_list_String(arr[1]),
+minTagConfidence: dco_decode_f_64(arr[2]),
+referencePath: dco_decode_opt_String(arr[3]),
+threshold: dco_decode_f_64(arr[4]),); }
+
+@protected SoundCluster dco_decode_sound_cluster(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SoundCluster(id: dco_decode_i_64(arr[0]),
+size: dco_decode_i_64(arr[1]),
+representative: dco_decode_opt_box_autoadd_sound_record(arr[2]),
+label: dco_decode_opt_String(arr[3]),); }
+
+@protected SoundPage dco_decode_sound_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SoundPage(sounds: dco_decode_list_sound_record(arr[0]),
+total: dco_decode_i_64(arr[1]),); }
+
+@protected SoundProperty dco_decode_sound_property(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SoundProperty(key: dco_decode_String(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
+@protected SoundQuery dco_decode_sound_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+                return SoundQuery(text: dco_decode_opt_String(arr[0]),
+formats: dco_decode_list_String(arr[1]),
+minDuration: dco_decode_opt_box_autoadd_f_64(arr[2]),
+maxDuration: dco_decode_opt_box_autoadd_f_64(arr[3]),
+minSampleRate: dco_decode_opt_box_autoadd_u_32(arr[4]),
+maxSampleRate: dco_decode_opt_box_autoadd_u_32(arr[5]),
+channels: dco_decode_opt_box_autoadd_u_16(arr[6]),
+minRating: dco_decode_opt_box_autoadd_u_8(arr[7]),
+minBpm: dco_decode_opt_box_autoadd_f_64(arr[8]),
+maxBpm: dco_decode_opt_box_autoadd_f_64(arr[9]),
+keys: dco_decode_list_String(arr[10]),
+tags: dco_decode_list_String(arr[11]),
+minTagConfidence: dco_decode_f_64(arr[12]),
+sort: dco_decode_sound_sort(arr[13]),
+descending: dco_decode_bool(arr[14]),
+offset: dco_decode_i_64(arr[15]),
+limit: dco_decode_opt_box_autoadd_i_64(arr[16]),); }
+
+@protected SoundRecord dco_decode_sound_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return SoundRecord(id: dco_decode_i_64(arr[0]),
+filepath: dco_decode_String(arr[1]),
+filename: dco_decode_String(arr[2]),
+duration: dco_decode_f_64(arr[3]),
+sampleRate: dco_decode_u_32(arr[4]),
+channels: dco_decode_u_16(arr[5]),
+format: dco_decode_String(arr[6]),
+dateAdded: dco_decode_String(arr[7]),
+title: dco_decode_String(arr[8]),
+artist: dco_decode_String(arr[9]),
+notes: dco_decode_String(arr[10]),
+source: dco_decode_String(arr[11]),
+rating: dco_decode_u_8(arr[12]),
+bpm: dco_decode_f_64(arr[13]),
+musicalKey: dco_decode_String(arr[14]),); }
+
+@protected SoundSort dco_decode_sound_sort(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SoundSort.values[raw as int]; }
+
+@protected SoundTag dco_decode_sound_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SoundTag(name: dco_decode_String(arr[0]),
+confidence: dco_decode_f_64(arr[1]),
+source: dco_decode_String(arr[2]),); }
+
+@protected SoundUsage dco_decode_sound_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SoundUsage(timesReturned: dco_decode_i_64(arr[0]),
+timesAuditioned: dco_decode_i_64(arr[1]),
+timesExported: dco_decode_i_64(arr[2]),
+lastUsed: dco_decode_opt_String(arr[3]),); }
+
+@protected TagSummary dco_decode_tag_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TagSummary(name: dco_decode_String(arr[0]),
+soundCount: dco_decode_i_64(arr[1]),); }
+
+@protected Thumbnail dco_decode_thumbnail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Thumbnail(kind: dco_decode_thumbnail_kind(arr[0]),
+mimeType: dco_decode_String(arr[1]),
+data: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected ThumbnailKind dco_decode_thumbnail_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ThumbnailKind.values[raw as int]; }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UsedSound dco_decode_used_sound(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UsedSound(sound: dco_decode_sound_record(arr[0]),
+usage: dco_decode_sound_usage(arr[1]),); }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected VerifyReport dco_decode_verify_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VerifyReport(verified: dco_decode_usize(arr[0]),
+hashed: dco_decode_usize(arr[1]),
+modified: dco_decode_list_prim_i_64_strict(arr[2]),
+missing: dco_decode_list_prim_i_64_strict(arr[3]),); }
+
+@protected WaveformPeaks dco_decode_waveform_peaks(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return WaveformPeaks(min: dco_decode_list_prim_f_32_strict(arr[0]),
+max: dco_decode_list_prim_f_32_strict(arr[1]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected String sse_decode_Char(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return inner; }
+
+@protected RustStreamSink<List<MatchResult>> sse_decode_StreamSink_list_match_result_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AnalysisKind sse_decode_analysis_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AnalysisKind.values[inner]; }
+
+@protected AnalysisTrack sse_decode_analysis_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_analysis_kind(deserializer);
+var var_hopSeconds = sse_decode_f_64(deserializer);
+var var_values = sse_decode_list_prim_f_64_strict(deserializer);
+return AnalysisTrack(kind: var_kind, hopSeconds: var_hopSeconds, values: var_values); }
+
+@protected ArchiveSummary sse_decode_archive_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sounds = sse_decode_usize(deserializer);
+var var_audioFiles = sse_decode_usize(deserializer);
+return ArchiveSummary(sounds: var_sounds, audioFiles: var_audioFiles); }
+
+@protected AudioFingerprintInfo sse_decode_audio_fingerprint_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_duration = sse_decode_f_64(deserializer);
+var var_spectralCentroid = sse_decode_f_64(deserializer);
+var var_spectralBandwidth = sse_decode_f_64(deserializer);
+var var_spectralRolloff = sse_decode_f_64(deserializer);
+var var_mfccMean = sse_decode_list_prim_f_64_strict(deserializer);
+var var_mfccStd = sse_decode_list_prim_f_64_strict(deserializer);
+return AudioFingerprintInfo(duration: var_duration, spectralCentroid: var_spectralCentroid, spectralBandwidth: var_spectralBandwidth, spectralRolloff: var_spectralRolloff, mfccMean: var_mfccMean, mfccStd: var_mfccStd); }
+
+@protected BatchMatches sse_decode_batch_matches(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_query = sse_decode_batch_query(deserializer);
+var var_matches = sse_decode_list_match_result(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return BatchMatches(query: var_query, matches: var_matches, error: var_error); }
+
+@protected BatchQuery sse_decode_batch_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_startTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_endTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+return BatchQuery(path: var_path, startTime: var_startTime, endTime: var_endTime); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected ClusterProgress sse_decode_box_autoadd_cluster_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cluster_progress(deserializer)); }
+
+@protected CsvExportOptions sse_decode_box_autoadd_csv_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_csv_export_options(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected HistoryEntry sse_decode_box_autoadd_history_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_history_entry(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected IndexProgress sse_decode_box_autoadd_index_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_index_progress(deserializer)); }
+
+@protected MapPoint sse_decode_box_autoadd_map_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_map_point(deserializer)); }
+
+@protected MapProgress sse_decode_box_autoadd_map_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_map_progress(deserializer)); }
+
+@protected MatchResult sse_decode_box_autoadd_match_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_match_result(deserializer)); }
+
+@protected NoiseInfo sse_decode_box_autoadd_noise_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_noise_info(deserializer)); }
+
+@protected OptimizeProgress sse_decode_box_autoadd_optimize_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_optimize_progress(deserializer)); }
+
+@protected SamplerEnvelope sse_decode_box_autoadd_sampler_envelope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_sampler_envelope(deserializer)); }
+
+@protected SearchOptions sse_decode_box_autoadd_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_options(deserializer)); }
+
+@protected SmartQuery sse_decode_box_autoadd_smart_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_smart_query(deserializer)); }
+
+@protected SoundQuery sse_decode_box_autoadd_sound_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_sound_query(deserializer)); }
+
+@protected SoundRecord sse_decode_box_autoadd_sound_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_sound_record(deserializer)); }
+
+@protected Thumbnail sse_decode_box_autoadd_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_thumbnail(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected ClusterProgress sse_decode_cluster_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_iteration = sse_decode_usize(deserializer);
+var var_maxIterations = sse_decode_usize(deserializer);
+var var_finished = sse_decode_bool(deserializer);
+return ClusterProgress(iteration: var_iteration, maxIterations: var_maxIterations, finished: var_finished); }
+
+@protected ClusteringSummary sse_decode_clustering_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_clusters = sse_decode_usize(deserializer);
+var var_sounds = sse_decode_usize(deserializer);
+var var_iterations = sse_decode_usize(deserializer);
+return ClusteringSummary(clusters: var_clusters, sounds: var_sounds, iterations: var_iterations); }
+
+@protected Collection sse_decode_collection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_dateCreated = sse_decode_String(deserializer);
+var var_itemCount = sse_decode_i_64(deserializer);
+return Collection(id: var_id, name: var_name, description: var_description, dateCreated: var_dateCreated, itemCount: var_itemCount); }
+
+@protected CollectionItem sse_decode_collection_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_position = sse_decode_i_64(deserializer);
+var var_startTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_endTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_sound = sse_decode_sound_record(deserializer);
+return CollectionItem(id: var_id, position: var_position, startTime: var_startTime, endTime: var_endTime, sound: var_sound); }
+
+@protected Colormap sse_decode_colormap(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Colormap.values[inner]; }
+
+@protected ConflictPolicy sse_decode_conflict_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConflictPolicy.values[inner]; }
+
+@protected ContainmentMatch sse_decode_containment_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_soundId = sse_decode_i_64(deserializer);
+var var_filepath = sse_decode_String(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_offsetSamples = sse_decode_usize(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_matchStart = sse_decode_f_64(deserializer);
+var var_matchEnd = sse_decode_f_64(deserializer);
+var var_correlation = sse_decode_f_64(deserializer);
+var var_gainDb = sse_decode_f_64(deserializer);
+return ContainmentMatch(soundId: var_soundId, filepath: var_filepath, filename: var_filename, offsetSamples: var_offsetSamples, sampleRate: var_sampleRate, matchStart: var_matchStart, matchEnd: var_matchEnd, correlation: var_correlation, gainDb: var_gainDb); }
+
+@protected CsvColumn sse_decode_csv_column(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CsvColumn.values[inner]; }
+
+@protected CsvExportOptions sse_decode_csv_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_delimiter = sse_decode_Char(deserializer);
+var var_columns = sse_decode_list_csv_column(deserializer);
+var var_header = sse_decode_bool(deserializer);
+return CsvExportOptions(delimiter: var_delimiter, columns: var_columns, header: var_header); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FeatureContribution sse_decode_feature_contribution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_group = sse_decode_feature_group(deserializer);
+var var_similarity = sse_decode_f_64(deserializer);
+var var_weight = sse_decode_f_64(deserializer);
+return FeatureContribution(group: var_group, similarity: var_similarity, weight: var_weight); }
+
+@protected FeatureGroup sse_decode_feature_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FeatureGroup.values[inner]; }
+
+@protected FolderMatch sse_decode_folder_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filepath = sse_decode_String(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_duration = sse_decode_f_64(deserializer);
+return FolderMatch(filepath: var_filepath, filename: var_filename, score: var_score, duration: var_duration); }
+
+@protected HistoryEntry sse_decode_history_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_operation = sse_decode_history_operation(deserializer);
+var var_soundId = sse_decode_i_64(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+var var_undone = sse_decode_bool(deserializer);
+return HistoryEntry(id: var_id, operation: var_operation, soundId: var_soundId, filename: var_filename, timestamp: var_timestamp, undone: var_undone); }
+
+@protected HistoryOperation sse_decode_history_operation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HistoryOperation.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImportSummary sse_decode_import_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_added = sse_decode_usize(deserializer);
+var var_merged = sse_decode_usize(deserializer);
+var var_audioFiles = sse_decode_usize(deserializer);
+return ImportSummary(added: var_added, merged: var_merged, audioFiles: var_audioFiles); }
+
+@protected IndexProgress sse_decode_index_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_total = sse_decode_usize(deserializer);
+var var_processed = sse_decode_usize(deserializer);
+var var_added = sse_decode_usize(deserializer);
+var var_duplicates = sse_decode_usize(deserializer);
+var var_skipped = sse_decode_usize(deserializer);
+var var_reanalyzed = sse_decode_usize(deserializer);
+var var_failed = sse_decode_usize(deserializer);
+var var_finished = sse_decode_bool(deserializer);
+var var_cancelled = sse_decode_bool(deserializer);
+return IndexProgress(total: var_total, processed: var_processed, added: var_added, duplicates: var_duplicates, skipped: var_skipped, reanalyzed: var_reanalyzed, failed: var_failed, finished: var_finished, cancelled: var_cancelled); }
+
+@protected IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sqliteErrors = sse_decode_list_String(deserializer);
+var var_missingFingerprints = sse_decode_list_prim_i_64_strict(deserializer);
+var var_unreadableFingerprints = sse_decode_list_prim_i_64_strict(deserializer);
+var var_orphanedFingerprints = sse_decode_list_prim_i_64_strict(deserializer);
+return IntegrityReport(sqliteErrors: var_sqliteErrors, missingFingerprints: var_missingFingerprints, unreadableFingerprints: var_unreadableFingerprints, orphanedFingerprints: var_orphanedFingerprints); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BatchMatches> sse_decode_list_batch_matches(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BatchMatches>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_batch_matches(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BatchQuery> sse_decode_list_batch_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BatchQuery>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_batch_query(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Collection> sse_decode_list_collection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Collection>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_collection(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CollectionItem> sse_decode_list_collection_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CollectionItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_collection_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ContainmentMatch> sse_decode_list_containment_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ContainmentMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_containment_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CsvColumn> sse_decode_list_csv_column(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CsvColumn>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_csv_column(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FeatureContribution> sse_decode_list_feature_contribution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FeatureContribution>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_feature_contribution(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FeatureGroup> sse_decode_list_feature_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FeatureGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_feature_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FolderMatch> sse_decode_list_folder_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FolderMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_folder_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HistoryEntry> sse_decode_list_history_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HistoryEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_history_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<SoundRecord>> sse_decode_list_list_sound_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<SoundRecord>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_sound_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LoopMatch> sse_decode_list_loop_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LoopMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_loop_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MapPoint> sse_decode_list_map_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MapPoint>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_map_point(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MatchResult> sse_decode_list_match_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MatchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_match_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MidiRegion> sse_decode_list_midi_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MidiRegion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_midi_region(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PlatformInt64?> sse_decode_list_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PlatformInt64?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_box_autoadd_i_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Thumbnail?> sse_decode_list_opt_box_autoadd_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Thumbnail?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_box_autoadd_thumbnail(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt64List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<RecentSearch> sse_decode_list_recent_search(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RecentSearch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_recent_search(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Region> sse_decode_list_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Region>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_region(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SmartCollection> sse_decode_list_smart_collection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SmartCollection>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_smart_collection(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SoundCluster> sse_decode_list_sound_cluster(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SoundCluster>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sound_cluster(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SoundProperty> sse_decode_list_sound_property(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SoundProperty>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sound_property(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SoundRecord> sse_decode_list_sound_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SoundRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sound_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SoundTag> sse_decode_list_sound_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SoundTag>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sound_tag(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TagSummary> sse_decode_list_tag_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TagSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_tag_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UsedSound> sse_decode_list_used_sound(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UsedSound>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_used_sound(deserializer)); }
+        return ans_;
+         }
+
+@protected LoopMatch sse_decode_loop_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_soundId = sse_decode_i_64(deserializer);
+var var_filepath = sse_decode_String(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_coverage = sse_decode_f_64(deserializer);
+var var_loopDuration = sse_decode_f_64(deserializer);
+var var_repetitions = sse_decode_f_64(deserializer);
+return LoopMatch(soundId: var_soundId, filepath: var_filepath, filename: var_filename, score: var_score, coverage: var_coverage, loopDuration: var_loopDuration, repetitions: var_repetitions); }
+
+@protected MapPoint sse_decode_map_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_soundId = sse_decode_i_64(deserializer);
+var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+return MapPoint(soundId: var_soundId, x: var_x, y: var_y); }
+
+@protected MapProgress sse_decode_map_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_iteration = sse_decode_usize(deserializer);
+var var_maxIterations = sse_decode_usize(deserializer);
+var var_finished = sse_decode_bool(deserializer);
+return MapProgress(iteration: var_iteration, maxIterations: var_maxIterations, finished: var_finished); }
+
+@protected MatchResult sse_decode_match_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_soundId = sse_decode_i_64(deserializer);
+var var_filepath = sse_decode_String(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_matchStart = sse_decode_f_64(deserializer);
+var var_matchEnd = sse_decode_f_64(deserializer);
+var var_fileDuration = sse_decode_f_64(deserializer);
+var var_timeResolution = sse_decode_f_64(deserializer);
+return MatchResult(soundId: var_soundId, filepath: var_filepath, filename: var_filename, score: var_score, matchStart: var_matchStart, matchEnd: var_matchEnd, fileDuration: var_fileDuration, timeResolution: var_timeResolution); }
+
+@protected MergeSummary sse_decode_merge_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_added = sse_decode_usize(deserializer);
+var var_merged = sse_decode_usize(deserializer);
+var var_skipped = sse_decode_usize(deserializer);
+var var_collections = sse_decode_usize(deserializer);
+var var_categories = sse_decode_usize(deserializer);
+return MergeSummary(added: var_added, merged: var_merged, skipped: var_skipped, collections: var_collections, categories: var_categories); }
+
+@protected MidiNoteMapping sse_decode_midi_note_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MidiNoteMapping.values[inner]; }
+
+@protected MidiRegion sse_decode_midi_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startTime = sse_decode_f_64(deserializer);
+var var_endTime = sse_decode_f_64(deserializer);
+var var_note = sse_decode_u_8(deserializer);
+var var_velocity = sse_decode_u_8(deserializer);
+var var_track = sse_decode_usize(deserializer);
+var var_trackName = sse_decode_String(deserializer);
+return MidiRegion(startTime: var_startTime, endTime: var_endTime, note: var_note, velocity: var_velocity, track: var_track, trackName: var_trackName); }
+
+@protected NoiseInfo sse_decode_noise_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noiseFloorDb = sse_decode_f_64(deserializer);
+var var_snrDb = sse_decode_f_64(deserializer);
+var var_isNoisy = sse_decode_bool(deserializer);
+return NoiseInfo(noiseFloorDb: var_noiseFloorDb, snrDb: var_snrDb, isNoisy: var_isNoisy); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ClusterProgress? sse_decode_opt_box_autoadd_cluster_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cluster_progress(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HistoryEntry? sse_decode_opt_box_autoadd_history_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_history_entry(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected IndexProgress? sse_decode_opt_box_autoadd_index_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_index_progress(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MapPoint? sse_decode_opt_box_autoadd_map_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_map_point(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MapProgress? sse_decode_opt_box_autoadd_map_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_map_progress(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NoiseInfo? sse_decode_opt_box_autoadd_noise_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_noise_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OptimizeProgress? sse_decode_opt_box_autoadd_optimize_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_optimize_progress(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SoundRecord? sse_decode_opt_box_autoadd_sound_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_sound_record(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Thumbnail? sse_decode_opt_box_autoadd_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_thumbnail(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OptimizeProgress sse_decode_optimize_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_step = sse_decode_optimize_step(deserializer);
+var var_completed = sse_decode_usize(deserializer);
+var var_total = sse_decode_usize(deserializer);
+var var_finished = sse_decode_bool(deserializer);
+return OptimizeProgress(step: var_step, completed: var_completed, total: var_total, finished: var_finished); }
+
+@protected OptimizeStep sse_decode_optimize_step(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OptimizeStep.values[inner]; }
+
+@protected OptimizeSummary sse_decode_optimize_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sizeBefore = sse_decode_i_64(deserializer);
+var var_sizeAfter = sse_decode_i_64(deserializer);
+return OptimizeSummary(sizeBefore: var_sizeBefore, sizeAfter: var_sizeAfter); }
+
+@protected RecentSearch sse_decode_recent_search(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_kind = sse_decode_search_kind(deserializer);
+var var_query = sse_decode_String(deserializer);
+var var_parameters = sse_decode_String(deserializer);
+var var_resultCount = sse_decode_i_64(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+return RecentSearch(id: var_id, kind: var_kind, query: var_query, parameters: var_parameters, resultCount: var_resultCount, timestamp: var_timestamp); }
+
+@protected Region sse_decode_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_soundId = sse_decode_i_64(deserializer);
+var var_startTime = sse_decode_f_64(deserializer);
+var var_endTime = sse_decode_f_64(deserializer);
+var var_label = sse_decode_String(deserializer);
+var var_color = sse_decode_String(deserializer);
+return Region(id: var_id, soundId: var_soundId, startTime: var_startTime, endTime: var_endTime, label: var_label, color: var_color); }
+
+@protected RescanSummary sse_decode_rescan_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_checked = sse_decode_usize(deserializer);
+var var_changed = sse_decode_usize(deserializer);
+var var_touched = sse_decode_usize(deserializer);
+var var_outdated = sse_decode_usize(deserializer);
+var var_missing = sse_decode_usize(deserializer);
+var var_failed = sse_decode_usize(deserializer);
+return RescanSummary(checked: var_checked, changed: var_changed, touched: var_touched, outdated: var_outdated, missing: var_missing, failed: var_failed); }
+
+@protected SamplerEnvelope sse_decode_sampler_envelope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_attack = sse_decode_f_64(deserializer);
+var var_decay = sse_decode_f_64(deserializer);
+var var_sustain = sse_decode_f_64(deserializer);
+var var_release = sse_decode_f_64(deserializer);
+return SamplerEnvelope(attack: var_attack, decay: var_decay, sustain: var_sustain, release: var_release); }
+
+@protected SearchKind sse_decode_search_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SearchKind.values[inner]; }
+
+@protected SearchOptions sse_decode_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_threshold = sse_decode_f_64(deserializer);
+var var_maxResults = sse_decode_usize(deserializer);
+var var_dtwRerank = sse_decode_bool(deserializer);
+var var_dtwWeight = sse_decode_f_64(deserializer);
+var var_dtwAlign = sse_decode_bool(deserializer);
+var var_refineOffsets = sse_decode_bool(deserializer);
+var var_allSegments = sse_decode_bool(deserializer);
+var var_diversity = sse_decode_f_64(deserializer);
+var var_ignoredFeatures = sse_decode_list_feature_group(deserializer);
+var var_snrPenalty = sse_decode_f_64(deserializer);
+var var_calibrateScores = sse_decode_bool(deserializer);
+var var_excludedSounds = sse_decode_list_prim_i_64_strict(deserializer);
+var var_exclusionThreshold = sse_decode_f_64(deserializer);
+var var_lshPrefilter = sse_decode_bool(deserializer);
+var var_memoryBudgetMb = sse_decode_usize(deserializer);
+var var_segmentWindowS = sse_decode_f_64(deserializer);
+var var_segmentOverlap = sse_decode_f_64(deserializer);
+var var_maxSegmentWindows = sse_decode_usize(deserializer);
+return SearchOptions(threshold: var_threshold, maxResults: var_maxResults, dtwRerank: var_dtwRerank, dtwWeight: var_dtwWeight, dtwAlign: var_dtwAlign, refineOffsets: var_refineOffsets, allSegments: var_allSegments, diversity: var_diversity, ignoredFeatures: var_ignoredFeatures, snrPenalty: var_snrPenalty, calibrateScores: var_calibrateScores, excludedSounds: var_excludedSounds, exclusionThreshold: var_exclusionThreshold, lshPrefilter: var_lshPrefilter, memoryBudgetMb: var_memoryBudgetMb, segmentWindowS: var_segmentWindowS, segmentOverlap: var_segmentOverlap, maxSegmentWindows: var_maxSegmentWindows); }
+
+@protected SearchPreset sse_decode_search_preset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SearchPreset.values[inner]; }
+
+@protected SmartCollection sse_decode_smart_collection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_query = sse_decode_smart_query(deserializer);
+var var_dateCreated = sse_decode_String(deserializer);
+return SmartCollection(id: var_id, name: var_name, query: var_query, dateCreated: var_dateCreated); }
+
+@protected SmartQuery sse_decode_smart_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filter = sse_decode_sound_query(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_minTagConfidence = sse_decode_f_64(deserializer);
+var var_referencePath = sse_decode_opt_String(deserializer);
+var var_threshold = sse_decode_f_64(deserializer);
+return SmartQuery(filter: var_filter, tags: var_tags, minTagConfidence: var_minTagConfidence, referencePath: var_referencePath, threshold: var_threshold); }
+
+@protected SoundCluster sse_decode_sound_cluster(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_size = sse_decode_i_64(deserializer);
+var var_representative = sse_decode_opt_box_autoadd_sound_record(deserializer);
+var var_label = sse_decode_opt_String(deserializer);
+return SoundCluster(id: var_id, size: var_size, representative: var_representative, label: var_label); }
+
+@protected SoundPage sse_decode_sound_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sounds = sse_decode_list_sound_record(deserializer);
+var var_total = sse_decode_i_64(deserializer);
+return SoundPage(sounds: var_sounds, total: var_total); }
+
+@protected SoundProperty sse_decode_sound_property(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return SoundProperty(key: var_key, value: var_value); }
+
+@protected SoundQuery sse_decode_sound_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_opt_String(deserializer);
+var var_formats = sse_decode_list_String(deserializer);
+var var_minDuration = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_maxDuration = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_minSampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_maxSampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_channels = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_minRating = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_minBpm = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_maxBpm = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_keys = sse_decode_list_String(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_minTagConfidence = sse_decode_f_64(deserializer);
+var var_sort = sse_decode_sound_sort(deserializer);
+var var_descending = sse_decode_bool(deserializer);
+var var_offset = sse_decode_i_64(deserializer);
+var var_limit = sse_decode_opt_box_autoadd_i_64(deserializer);
+return SoundQuery(text: var_text, formats: var_formats, minDuration: var_minDuration, maxDuration: var_maxDuration, minSampleRate: var_minSampleRate, maxSampleRate: var_maxSampleRate, channels: var_channels, minRating: var_minRating, minBpm: var_minBpm, maxBpm: var_maxBpm, keys: var_keys, tags: var_tags, minTagConfidence: var_minTagConfidence, sort: var_sort, descending: var_descending, offset: var_offset, limit: var_limit); }
+
+@protected SoundRecord sse_decode_sound_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_filepath = sse_decode_String(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_duration = sse_decode_f_64(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_16(deserializer);
+var var_format = sse_decode_String(deserializer);
+var var_dateAdded = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_artist = sse_decode_String(deserializer);
+var var_notes = sse_decode_String(deserializer);
+var var_source = sse_decode_String(deserializer);
+var var_rating = sse_decode_u_8(deserializer);
+var var_bpm = sse_decode_f_64(deserializer);
+var var_musicalKey = sse_decode_String(deserializer);
+return SoundRecord(id: var_id, filepath: var_filepath, filename: var_filename, duration: var_duration, sampleRate: var_sampleRate, channels: var_channels, format: var_format, dateAdded: var_dateAdded, title: var_title, artist: var_artist, notes: var_notes, source: var_source, rating: var_rating, bpm: var_bpm, musicalKey: var_musicalKey); }
+
+@protected SoundSort sse_decode_sound_sort(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SoundSort.values[inner]; }
+
+@protected SoundTag sse_decode_sound_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_confidence = sse_decode_f_64(deserializer);
+var var_source = sse_decode_String(deserializer);
+return SoundTag(name: var_name, confidence: var_confidence, source: var_source); }
+
+@protected SoundUsage sse_decode_sound_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timesReturned = sse_decode_i_64(deserializer);
+var var_timesAuditioned = sse_decode_i_64(deserializer);
+var var_timesExported = sse_decode_i_64(deserializer);
+var var_lastUsed = sse_decode_opt_String(deserializer);
+return SoundUsage(timesReturned: var_timesReturned, timesAuditioned: var_timesAuditioned, timesExported: var_timesExported, lastUsed: var_lastUsed); }
+
+@protected TagSummary sse_decode_tag_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_soundCount = sse_decode_i_64(deserializer);
+return TagSummary(name: var_name, soundCount: var_soundCount); }
+
+@protected Thumbnail sse_decode_thumbnail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_thumbnail_kind(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return Thumbnail(kind: var_kind, mimeType: var_mimeType, data: var_data); }
+
+@protected ThumbnailKind sse_decode_thumbnail_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ThumbnailKind.values[inner]; }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UsedSound sse_decode_used_sound(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sound = sse_decode_sound_record(deserializer);
+var var_usage = sse_decode_sound_usage(deserializer);
+return UsedSound(sound: var_sound, usage: var_usage); }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected VerifyReport sse_decode_verify_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_verified = sse_decode_usize(deserializer);
+var var_hashed = sse_decode_usize(deserializer);
+var var_modified = sse_decode_list_prim_i_64_strict(deserializer);
+var var_missing = sse_decode_list_prim_i_64_strict(deserializer);
+return VerifyReport(verified: var_verified, hashed: var_hashed, modified: var_modified, missing: var_missing); }
+
+@protected WaveformPeaks sse_decode_waveform_peaks(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_min = sse_decode_list_prim_f_32_strict(deserializer);
+var var_max = sse_decode_list_prim_f_32_strict(deserializer);
+return WaveformPeaks(min: var_min, max: var_max); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Char(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self, serializer); }
+
+@protected void sse_encode_StreamSink_list_match_result_Sse(RustStreamSink<List<MatchResult>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_list_match_result,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_analysis_kind(AnalysisKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_analysis_track(AnalysisTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_analysis_kind(self.kind, serializer);
+sse_encode_f_64(self.hopSeconds, serializer);
+sse_encode_list_prim_f_64_strict(self.values, serializer);
+ }
+
+@protected void sse_encode_archive_summary(ArchiveSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.sounds, serializer);
+sse_encode_usize(self.audioFiles, serializer);
+ }
+
+@protected void sse_encode_audio_fingerprint_info(AudioFingerprintInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.duration, serializer);
+sse_encode_f_64(self.spectralCentroid, serializer);
+sse_encode_f_64(self.spectralBandwidth, serializer);
+sse_encode_f_64(self.spectralRolloff, serializer);
+sse_encode_list_prim_f_64_strict(self.mfccMean, serializer);
+sse_encode_list_prim_f_64_strict(self.mfccStd, serializer);
+ }
+
+@protected void sse_encode_batch_matches(BatchMatches self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_batch_query(self.query, serializer);
+sse_encode_list_match_result(self.matches, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_batch_query(BatchQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_opt_box_autoadd_f_64(self.startTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.endTime, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_cluster_progress(ClusterProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cluster_progress(self, serializer); }
+
+@protected void sse_encode_box_autoadd_csv_export_options(CsvExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_csv_export_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_history_entry(HistoryEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_history_entry(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_index_progress(IndexProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_index_progress(self, serializer); }
+
+@protected void sse_encode_box_autoadd_map_point(MapPoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_map_point(self, serializer); }
+
+@protected void sse_encode_box_autoadd_map_progress(MapProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_map_progress(self, serializer); }
+
+@protected void sse_encode_box_autoadd_match_result(MatchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_match_result(self, serializer); }
+
+@protected void sse_encode_box_autoadd_noise_info(NoiseInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_noise_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_optimize_progress(OptimizeProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_optimize_progress(self, serializer); }
+
+@protected void sse_encode_box_autoadd_sampler_envelope(SamplerEnvelope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sampler_envelope(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_options(SearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_smart_query(SmartQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_smart_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_sound_query(SoundQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sound_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_sound_record(SoundRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sound_record(self, serializer); }
+
+@protected void sse_encode_box_autoadd_thumbnail(Thumbnail self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_thumbnail(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_cluster_progress(ClusterProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.iteration, serializer);
+sse_encode_usize(self.maxIterations, serializer);
+sse_encode_bool(self.finished, serializer);
+ }
+
+@protected void sse_encode_clustering_summary(ClusteringSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.clusters, serializer);
+sse_encode_usize(self.sounds, serializer);
+sse_encode_usize(self.iterations, serializer);
+ }
+
+@protected void sse_encode_collection(Collection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_String(self.dateCreated, serializer);
+sse_encode_i_64(self.itemCount, serializer);
+ }
+
+@protected void sse_encode_collection_item(CollectionItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_i_64(self.position, serializer);
+sse_encode_opt_box_autoadd_f_64(self.startTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.endTime, serializer);
+sse_encode_sound_record(self.sound, serializer);
+ }
+
+@protected void sse_encode_colormap(Colormap self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_conflict_policy(ConflictPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_containment_match(ContainmentMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.soundId, serializer);
+sse_encode_String(self.filepath, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_usize(self.offsetSamples, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_f_64(self.matchStart, serializer);
+sse_encode_f_64(self.matchEnd, serializer);
+sse_encode_f_64(self.correlation, serializer);
+sse_encode_f_64(self.gainDb, serializer);
+ }
+
+@protected void sse_encode_csv_column(CsvColumn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_csv_export_options(CsvExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Char(self.delimiter, serializer);
+sse_encode_list_csv_column(self.columns, serializer);
+sse_encode_bool(self.header, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_feature_contribution(FeatureContribution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_feature_group(self.group, serializer);
+sse_encode_f_64(self.similarity, serializer);
+sse_encode_f_64(self.weight, serializer);
+ }
+
+@protected void sse_encode_feature_group(FeatureGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_folder_match(FolderMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.filepath, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_f_64(self.duration, serializer);
+ }
+
+@protected void sse_encode_history_entry(HistoryEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_history_operation(self.operation, serializer);
+sse_encode_i_64(self.soundId, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_String(self.timestamp, serializer);
+sse_encode_bool(self.undone, serializer);
+ }
+
+@protected void sse_encode_history_operation(HistoryOperation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_import_summary(ImportSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.added, serializer);
+sse_encode_usize(self.merged, serializer);
+sse_encode_usize(self.audioFiles, serializer);
+ }
+
+@protected void sse_encode_index_progress(IndexProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.total, serializer);
+sse_encode_usize(self.processed, serializer);
+sse_encode_usize(self.added, serializer);
+sse_encode_usize(self.duplicates, serializer);
+sse_encode_usize(self.skipped, serializer);
+sse_encode_usize(self.reanalyzed, serializer);
+sse_encode_usize(self.failed, serializer);
+sse_encode_bool(self.finished, serializer);
+sse_encode_bool(self.cancelled, serializer);
+ }
+
+@protected void sse_encode_integrity_report(IntegrityReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.sqliteErrors, serializer);
+sse_encode_list_prim_i_64_strict(self.missingFingerprints, serializer);
+sse_encode_list_prim_i_64_strict(self.unreadableFingerprints, serializer);
+sse_encode_list_prim_i_64_strict(self.orphanedFingerprints, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_batch_matches(List<BatchMatches> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_batch_matches(item, serializer); } }
+
+@protected void sse_encode_list_batch_query(List<BatchQuery> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_batch_query(item, serializer); } }
+
+@protected void sse_encode_list_collection(List<Collection> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_collection(item, serializer); } }
+
+@protected void sse_encode_list_collection_item(List<CollectionItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_collection_item(item, serializer); } }
+
+@protected void sse_encode_list_containment_match(List<ContainmentMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_containment_match(item, serializer); } }
+
+@protected void sse_encode_list_csv_column(List<CsvColumn> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_csv_column(item, serializer); } }
+
+@protected void sse_encode_list_feature_contribution(List<FeatureContribution> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_feature_contribution(item, serializer); } }
+
+@protected void sse_encode_list_feature_group(List<FeatureGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_feature_group(item, serializer); } }
+
+@protected void sse_encode_list_folder_match(List<FolderMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_folder_match(item, serializer); } }
+
+@protected void sse_encode_list_history_entry(List<HistoryEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_history_entry(item, serializer); } }
+
+@protected void sse_encode_list_list_sound_record(List<List<SoundRecord>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_sound_record(item, serializer); } }
+
+@protected void sse_encode_list_loop_match(List<LoopMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_loop_match(item, serializer); } }
+
+@protected void sse_encode_list_map_point(List<MapPoint> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_map_point(item, serializer); } }
+
+@protected void sse_encode_list_match_result(List<MatchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_match_result(item, serializer); } }
+
+@protected void sse_encode_list_midi_region(List<MidiRegion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_midi_region(item, serializer); } }
+
+@protected void sse_encode_list_opt_box_autoadd_i_64(List<PlatformInt64?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_box_autoadd_i_64(item, serializer); } }
+
+@protected void sse_encode_list_opt_box_autoadd_thumbnail(List<Thumbnail?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_box_autoadd_thumbnail(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self is Float32List ? self : Float32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self); }
+
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt64List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_recent_search(List<RecentSearch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_recent_search(item, serializer); } }
+
+@protected void sse_encode_list_region(List<Region> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_region(item, serializer); } }
+
+@protected void sse_encode_list_smart_collection(List<SmartCollection> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_smart_collection(item, serializer); } }
+
+@protected void sse_encode_list_sound_cluster(List<SoundCluster> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sound_cluster(item, serializer); } }
+
+@protected void sse_encode_list_sound_property(List<SoundProperty> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sound_property(item, serializer); } }
+
+@protected void sse_encode_list_sound_record(List<SoundRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sound_record(item, serializer); } }
+
+@protected void sse_encode_list_sound_tag(List<SoundTag> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sound_tag(item, serializer); } }
+
+@protected void sse_encode_list_tag_summary(List<TagSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_tag_summary(item, serializer); } }
+
+@protected void sse_encode_list_used_sound(List<UsedSound> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_used_sound(item, serializer); } }
+
+@protected void sse_encode_loop_match(LoopMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.soundId, serializer);
+sse_encode_String(self.filepath, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_f_64(self.coverage, serializer);
+sse_encode_f_64(self.loopDuration, serializer);
+sse_encode_f_64(self.repetitions, serializer);
+ }
+
+@protected void sse_encode_map_point(MapPoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.soundId, serializer);
+sse_encode_f_64(self.x, serializer);
+sse_encode_f_64(self.y, serializer);
+ }
+
+@protected void sse_encode_map_progress(MapProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.iteration, serializer);
+sse_encode_usize(self.maxIterations, serializer);
+sse_encode_bool(self.finished, serializer);
+ }
+
+@protected void sse_encode_match_result(MatchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.soundId, serializer);
+sse_encode_String(self.filepath, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_f_64(self.matchStart, serializer);
+sse_encode_f_64(self.matchEnd, serializer);
+sse_encode_f_64(self.fileDuration, serializer);
+sse_encode_f_64(self.timeResolution, serializer);
+ }
+
+@protected void sse_encode_merge_summary(MergeSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.added, serializer);
+sse_encode_usize(self.merged, serializer);
+sse_encode_usize(self.skipped, serializer);
+sse_encode_usize(self.collections, serializer);
+sse_encode_usize(self.categories, serializer);
+ }
+
+@protected void sse_encode_midi_note_mapping(MidiNoteMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_midi_region(MidiRegion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.startTime, serializer);
+sse_encode_f_64(self.endTime, serializer);
+sse_encode_u_8(self.note, serializer);
+sse_encode_u_8(self.velocity, serializer);
+sse_encode_usize(self.track, serializer);
+sse_encode_String(self.trackName, serializer);
+ }
+
+@protected void sse_encode_noise_info(NoiseInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.noiseFloorDb, serializer);
+sse_encode_f_64(self.snrDb, serializer);
+sse_encode_bool(self.isNoisy, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cluster_progress(ClusterProgress? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cluster_progress(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_history_entry(HistoryEntry? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_history_entry(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_index_progress(IndexProgress? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_index_progress(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_map_point(MapPoint? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_map_point(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_map_progress(MapProgress? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_map_progress(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_noise_info(NoiseInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_noise_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_optimize_progress(OptimizeProgress? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_optimize_progress(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_sound_record(SoundRecord? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_sound_record(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_thumbnail(Thumbnail? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_thumbnail(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_optimize_progress(OptimizeProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_optimize_step(self.step, serializer);
+sse_encode_usize(self.completed, serializer);
+sse_encode_usize(self.total, serializer);
+sse_encode_bool(self.finished, serializer);
+ }
+
+@protected void sse_encode_optimize_step(OptimizeStep self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_optimize_summary(OptimizeSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sizeBefore, serializer);
+sse_encode_i_64(self.sizeAfter, serializer);
+ }
+
+@protected void sse_encode_recent_search(RecentSearch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_search_kind(self.kind, serializer);
+sse_encode_String(self.query, serializer);
+sse_encode_String(self.parameters, serializer);
+sse_encode_i_64(self.resultCount, serializer);
+sse_encode_String(self.timestamp, serializer);
+ }
+
+@protected void sse_encode_region(Region self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_i_64(self.soundId, serializer);
+sse_encode_f_64(self.startTime, serializer);
+sse_encode_f_64(self.endTime, serializer);
+sse_encode_String(self.label, serializer);
+sse_encode_String(self.color, serializer);
+ }
+
+@protected void sse_encode_rescan_summary(RescanSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.checked, serializer);
+sse_encode_usize(self.changed, serializer);
+sse_encode_usize(self.touched, serializer);
+sse_encode_usize(self.outdated, serializer);
+sse_encode_usize(self.missing, serializer);
+sse_encode_usize(self.failed, serializer);
+ }
+
+@protected void sse_encode_sampler_envelope(SamplerEnvelope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.attack, serializer);
+sse_encode_f_64(self.decay, serializer);
+sse_encode_f_64(self.sustain, serializer);
+sse_encode_f_64(self.release, serializer);
+ }
+
+@protected void sse_encode_search_kind(SearchKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_search_options(SearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.threshold, serializer);
+sse_encode_usize(self.maxResults, serializer);
+sse_encode_bool(self.dtwRerank, serializer);
+sse_encode_f_64(self.dtwWeight, serializer);
+sse_encode_bool(self.dtwAlign, serializer);
+sse_encode_bool(self.refineOffsets, serializer);
+sse_encode_bool(self.allSegments, serializer);
+sse_encode_f_64(self.diversity, serializer);
+sse_encode_list_feature_group(self.ignoredFeatures, serializer);
+sse_encode_f_64(self.snrPenalty, serializer);
+sse_encode_bool(self.calibrateScores, serializer);
+sse_encode_list_prim_i_64_strict(self.excludedSounds, serializer);
+sse_encode_f_64(self.exclusionThreshold, serializer);
+sse_encode_bool(self.lshPrefilter, serializer);
+sse_encode_usize(self.memoryBudgetMb, serializer);
+sse_encode_f_64(self.segmentWindowS, serializer);
+sse_encode_f_64(self.segmentOverlap, serializer);
+sse_encode_usize(self.maxSegmentWindows, serializer);
+ }
+
+@protected void sse_encode_search_preset(SearchPreset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_smart_collection(SmartCollection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_smart_query(self.query, serializer);
+sse_encode_String(self.dateCreated, serializer);
+ }
+
+@protected void sse_encode_smart_query(SmartQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sound_query(self.filter, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_f_64(self.minTagConfidence, serializer);
+sse_encode_opt_String(self.referencePath, serializer);
+sse_encode_f_64(self.threshold, serializer);
+ }
+
+@protected void sse_encode_sound_cluster(SoundCluster self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_i_64(self.size, serializer);
+sse_encode_opt_box_autoadd_sound_record(self.representative, serializer);
+sse_encode_opt_String(self.label, serializer);
+ }
+
+@protected void sse_encode_sound_page(SoundPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_sound_record(self.sounds, serializer);
+sse_encode_i_64(self.total, serializer);
+ }
+
+@protected void sse_encode_sound_property(SoundProperty self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_sound_query(SoundQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.text, serializer);
+sse_encode_list_String(self.formats, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minDuration, serializer);
+sse_encode_opt_box_autoadd_f_64(self.maxDuration, serializer);
+sse_encode_opt_box_autoadd_u_32(self.minSampleRate, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxSampleRate, serializer);
+sse_encode_opt_box_autoadd_u_16(self.channels, serializer);
+sse_encode_opt_box_autoadd_u_8(self.minRating, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minBpm, serializer);
+sse_encode_opt_box_autoadd_f_64(self.maxBpm, serializer);
+sse_encode_list_String(self.keys, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_f_64(self.minTagConfidence, serializer);
+sse_encode_sound_sort(self.sort, serializer);
+sse_encode_bool(self.descending, serializer);
+sse_encode_i_64(self.offset, serializer);
+sse_encode_opt_box_autoadd_i_64(self.limit, serializer);
+ }
+
+@protected void sse_encode_sound_record(SoundRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.filepath, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_f_64(self.duration, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_16(self.channels, serializer);
+sse_encode_String(self.format, serializer);
+sse_encode_String(self.dateAdded, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.artist, serializer);
+sse_encode_String(self.notes, serializer);
+sse_encode_String(self.source, serializer);
+sse_encode_u_8(self.rating, serializer);
+sse_encode_f_64(self.bpm, serializer);
+sse_encode_String(self.musicalKey, serializer);
+ }
+
+@protected void sse_encode_sound_sort(SoundSort self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_sound_tag(SoundTag self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_f_64(self.confidence, serializer);
+sse_encode_String(self.source, serializer);
+ }
+
+@protected void sse_encode_sound_usage(SoundUsage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.timesReturned, serializer);
+sse_encode_i_64(self.timesAuditioned, serializer);
+sse_encode_i_64(self.timesExported, serializer);
+sse_encode_opt_String(self.lastUsed, serializer);
+ }
+
+@protected void sse_encode_tag_summary(TagSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_i_64(self.soundCount, serializer);
+ }
+
+@protected void sse_encode_thumbnail(Thumbnail self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_thumbnail_kind(self.kind, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+ }
+
+@protected void sse_encode_thumbnail_kind(ThumbnailKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_used_sound(UsedSound self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sound_record(self.sound, serializer);
+sse_encode_sound_usage(self.usage, serializer);
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_verify_report(VerifyReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.verified, serializer);
+sse_encode_usize(self.hashed, serializer);
+sse_encode_list_prim_i_64_strict(self.modified, serializer);
+sse_encode_list_prim_i_64_strict(self.missing, serializer);
+ }
+
+@protected void sse_encode_waveform_peaks(WaveformPeaks self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_f_32_strict(self.min, serializer);
+sse_encode_list_prim_f_32_strict(self.max, serializer);
+ }
+                }
+                
@@ -10,9 +10,37 @@
 import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'database/archive.dart';
+import 'database/clusters.dart';
+import 'database/collections.dart';
+import 'database/history.dart';
+import 'database/maintenance.dart';
+import 'database/merge.dart';
+import 'database/properties.dart';
+import 'database/query.dart';
+import 'database/regions.dart';
+import 'database/searches.dart';
+import 'database/smart_collections.dart';
+import 'database/sound_map.dart';
+import 'database/usage.dart';
+import 'fingerprint.dart';
+import 'fingerprint/analysis.dart';
 import 'frb_generated.dart';
+import 'indexer.dart';
 import 'lib.dart';
+import 'midi/csv.dart';
+import 'midi/dspreset.dart';
+import 'midi/import.dart';
+import 'midi/mapping.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'render/colormap.dart';
+import 'render/thumbnail.dart';
+import 'render/waveform.dart';
+import 'search/batch.dart';
+import 'search/containment.dart';
+import 'search/folder.dart';
+import 'search/loops.dart';
+import 'search/presets.dart';
 
 
 
@@ -27,121 +55,851 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
                   
 
-                  @protected String dco_decode_String(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected String dco_decode_Char(dynamic raw);
+
+@protected RustStreamSink<List<MatchResult>> dco_decode_StreamSink_list_match_result_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AnalysisKind dco_decode_analysis_kind(dynamic raw);
+
+@protected AnalysisTrack dco_decode_analysis_track(dynamic raw);
+
+@protected ArchiveSummary dco_decode_archive_summary(dynamic raw);
 
 @protected AudioFingerprintInfo dco_decode_audio_fingerprint_info(dynamic raw);
 
+@protected BatchMatches dco_decode_batch_matches(dynamic raw);
+
+@protected BatchQuery dco_decode_batch_query(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected ClusterProgress dco_decode_box_autoadd_cluster_progress(dynamic raw);
+
+@protected CsvExportOptions dco_decode_box_autoadd_csv_export_options(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
+@protected HistoryEntry dco_decode_box_autoadd_history_entry(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected IndexProgress dco_decode_box_autoadd_index_progress(dynamic raw);
+
+@protected MapPoint dco_decode_box_autoadd_map_point(dynamic raw);
+
+@protected MapProgress dco_decode_box_autoadd_map_progress(dynamic raw);
+
+@protected MatchResult dco_decode_box_autoadd_match_result(dynamic raw);
+
+@protected NoiseInfo dco_decode_box_autoadd_noise_info(dynamic raw);
+
+@protected OptimizeProgress dco_decode_box_autoadd_optimize_progress(dynamic raw);
+
+@protected SamplerEnvelope dco_decode_box_autoadd_sampler_envelope(dynamic raw);
+
+@protected SearchOptions dco_decode_box_autoadd_search_options(dynamic raw);
+
+@protected SmartQuery dco_decode_box_autoadd_smart_query(dynamic raw);
+
+@protected SoundQuery dco_decode_box_autoadd_sound_query(dynamic raw);
+
+@protected SoundRecord dco_decode_box_autoadd_sound_record(dynamic raw);
+
+@protected Thumbnail dco_decode_box_autoadd_thumbnail(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
+
+@protected ClusterProgress dco_decode_cluster_progress(dynamic raw);
+
+@protected ClusteringSummary dco_decode_clustering_summary(dynamic raw);
+
+@protected Collection dco_decode_collection(dynamic raw);
+
+@protected CollectionItem dco_decode_collection_item(dynamic raw);
+
+@protected Colormap dco_decode_colormap(dynamic raw);
+
+@protected ConflictPolicy dco_decode_conflict_policy(dynamic raw);
+
+@protected ContainmentMatch dco_decode_containment_match(dynamic raw);
+
+@protected CsvColumn dco_decode_csv_column(dynamic raw);
+
+@protected CsvExportOptions dco_decode_csv_export_options(dynamic raw);
+
 @protected double dco_decode_f_32(dynamic raw);
 
 @protected double dco_decode_f_64(dynamic raw);
 
+@protected FeatureContribution dco_decode_feature_contribution(dynamic raw);
+
+@protected FeatureGroup dco_decode_feature_group(dynamic raw);
+
+@protected FolderMatch dco_decode_folder_match(dynamic raw);
+
+@protected HistoryEntry dco_decode_history_entry(dynamic raw);
+
+@protected HistoryOperation dco_decode_history_operation(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
 @protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
+@protected ImportSummary dco_decode_import_summary(dynamic raw);
+
+@protected IndexProgress dco_decode_index_progress(dynamic raw);
+
+@protected IntegrityReport dco_decode_integrity_report(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<BatchMatches> dco_decode_list_batch_matches(dynamic raw);
+
+@protected List<BatchQuery> dco_decode_list_batch_query(dynamic raw);
+
+@protected List<Collection> dco_decode_list_collection(dynamic raw);
+
+@protected List<CollectionItem> dco_decode_list_collection_item(dynamic raw);
+
+@protected List<ContainmentMatch> dco_decode_list_containment_match(dynamic raw);
+
+@protected List<CsvColumn> dco_decode_list_csv_column(dynamic raw);
+
+@protected List<FeatureContribution> dco_decode_list_feature_contribution(dynamic raw);
+
+@protected List<FeatureGroup> dco_decode_list_feature_group(dynamic raw);
+
+@protected List<FolderMatch> dco_decode_list_folder_match(dynamic raw);
+
+@protected List<HistoryEntry> dco_decode_list_history_entry(dynamic raw);
+
+@protected List<List<SoundRecord>> dco_decode_list_list_sound_record(dynamic raw);
+
+@protected List<LoopMatch> dco_decode_list_loop_match(dynamic raw);
+
+@protected List<MapPoint> dco_decode_list_map_point(dynamic raw);
+
 @protected List<MatchResult> dco_decode_list_match_result(dynamic raw);
 
+@protected List<MidiRegion> dco_decode_list_midi_region(dynamic raw);
+
+@protected List<PlatformInt64?> dco_decode_list_opt_box_autoadd_i_64(dynamic raw);
+
+@protected List<Thumbnail?> dco_decode_list_opt_box_autoadd_thumbnail(dynamic raw);
+
 @protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
 
 @protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
 @protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
 
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+@protected List<RecentSearch> dco_decode_list_recent_search(dynamic raw);
+
+@protected List<Region> dco_decode_list_region(dynamic raw);
+
+@protected List<SmartCollection> dco_decode_list_smart_collection(dynamic raw);
+
+@protected List<SoundCluster> dco_decode_list_sound_cluster(dynamic raw);
+
+@protected List<SoundProperty> dco_decode_list_sound_property(dynamic raw);
+
 @protected List<SoundRecord> dco_decode_list_sound_record(dynamic raw);
 
+@protected List<SoundTag> dco_decode_list_sound_tag(dynamic raw);
+
+@protected List<TagSummary> dco_decode_list_tag_summary(dynamic raw);
+
+@protected List<UsedSound> dco_decode_list_used_sound(dynamic raw);
+
+@protected LoopMatch dco_decode_loop_match(dynamic raw);
+
+@protected MapPoint dco_decode_map_point(dynamic raw);
+
+@protected MapProgress dco_decode_map_progress(dynamic raw);
+
 @protected MatchResult dco_decode_match_result(dynamic raw);
 
+@protected MergeSummary dco_decode_merge_summary(dynamic raw);
+
+@protected MidiNoteMapping dco_decode_midi_note_mapping(dynamic raw);
+
+@protected MidiRegion dco_decode_midi_region(dynamic raw);
+
+@protected NoiseInfo dco_decode_noise_info(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected ClusterProgress? dco_decode_opt_box_autoadd_cluster_progress(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected HistoryEntry? dco_decode_opt_box_autoadd_history_entry(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected IndexProgress? dco_decode_opt_box_autoadd_index_progress(dynamic raw);
+
+@protected MapPoint? dco_decode_opt_box_autoadd_map_point(dynamic raw);
+
+@protected MapProgress? dco_decode_opt_box_autoadd_map_progress(dynamic raw);
+
+@protected NoiseInfo? dco_decode_opt_box_autoadd_noise_info(dynamic raw);
+
+@protected OptimizeProgress? dco_decode_opt_box_autoadd_optimize_progress(dynamic raw);
+
+@protected SoundRecord? dco_decode_opt_box_autoadd_sound_record(dynamic raw);
+
+@protected Thumbnail? dco_decode_opt_box_autoadd_thumbnail(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+@protected OptimizeProgress dco_decode_optimize_progress(dynamic raw);
+
+@protected OptimizeStep dco_decode_optimize_step(dynamic raw);
+
+@protected OptimizeSummary dco_decode_optimize_summary(dynamic raw);
+
+@protected RecentSearch dco_decode_recent_search(dynamic raw);
+
+@protected Region dco_decode_region(dynamic raw);
+
+@protected RescanSummary dco_decode_rescan_summary(dynamic raw);
+
+@protected SamplerEnvelope dco_decode_sampler_envelope(dynamic raw);
+
+@protected SearchKind dco_decode_search_kind(dynamic raw);
+
+@protected SearchOptions dco_decode_search_options(dynamic raw);
+
+@protected SearchPreset dco_decode_search_preset(dynamic raw);
+
+@protected SmartCollection dco_decode_smart_collection(dynamic raw);
+
+@protected SmartQuery dco_decode_smart_query(dynamic raw);
+
+@protected SoundCluster dco_decode_sound_cluster(dynamic raw);
+
+@protected SoundPage dco_decode_sound_page(dynamic raw);
+
+@protected SoundProperty dco_decode_sound_property(dynamic raw);
+
+@protected SoundQuery dco_decode_sound_query(dynamic raw);
+
 @protected SoundRecord dco_decode_sound_record(dynamic raw);
 
+@protected SoundSort dco_decode_sound_sort(dynamic raw);
+
+@protected SoundTag dco_decode_sound_tag(dynamic raw);
+
+@protected SoundUsage dco_decode_sound_usage(dynamic raw);
+
+@protected TagSummary dco_decode_tag_summary(dynamic raw);
+
+@protected Thumbnail dco_decode_thumbnail(dynamic raw);
+
+@protected ThumbnailKind dco_decode_thumbnail_kind(dynamic raw);
+
 @protected int dco_decode_u_16(dynamic raw);
 
 @protected int dco_decode_u_32(dynamic raw);
 
+@protected BigInt dco_decode_u_64(dynamic raw);
+
 @protected int dco_decode_u_8(dynamic raw);
 
 @protected void dco_decode_unit(dynamic raw);
 
+@protected UsedSound dco_decode_used_sound(dynamic raw);
+
 @protected BigInt dco_decode_usize(dynamic raw);
 
+@protected VerifyReport dco_decode_verify_report(dynamic raw);
+
+@protected WaveformPeaks dco_decode_waveform_peaks(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected String sse_decode_Char(SseDeserializer deserializer);
+
+@protected RustStreamSink<List<MatchResult>> sse_decode_StreamSink_list_match_result_Sse(SseDeserializer deserializer);
+
 @protected String sse_decode_String(SseDeserializer deserializer);
 
+@protected AnalysisKind sse_decode_analysis_kind(SseDeserializer deserializer);
+
+@protected AnalysisTrack sse_decode_analysis_track(SseDeserializer deserializer);
+
+@protected ArchiveSummary sse_decode_archive_summary(SseDeserializer deserializer);
+
 @protected AudioFingerprintInfo sse_decode_audio_fingerprint_info(SseDeserializer deserializer);
 
+@protected BatchMatches sse_decode_batch_matches(SseDeserializer deserializer);
+
+@protected BatchQuery sse_decode_batch_query(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected ClusterProgress sse_decode_box_autoadd_cluster_progress(SseDeserializer deserializer);
+
+@protected CsvExportOptions sse_decode_box_autoadd_csv_export_options(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected HistoryEntry sse_decode_box_autoadd_history_entry(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IndexProgress sse_decode_box_autoadd_index_progress(SseDeserializer deserializer);
+
+@protected MapPoint sse_decode_box_autoadd_map_point(SseDeserializer deserializer);
+
+@protected MapProgress sse_decode_box_autoadd_map_progress(SseDeserializer deserializer);
+
+@protected MatchResult sse_decode_box_autoadd_match_result(SseDeserializer deserializer);
+
+@protected NoiseInfo sse_decode_box_autoadd_noise_info(SseDeserializer deserializer);
+
+@protected OptimizeProgress sse_decode_box_autoadd_optimize_progress(SseDeserializer deserializer);
+
+@protected SamplerEnvelope sse_decode_box_autoadd_sampler_envelope(SseDeserializer deserializer);
+
+@protected SearchOptions sse_decode_box_autoadd_search_options(SseDeserializer deserializer);
+
+@protected SmartQuery sse_decode_box_autoadd_smart_query(SseDeserializer deserializer);
+
+@protected SoundQuery sse_decode_box_autoadd_sound_query(SseDeserializer deserializer);
+
+@protected SoundRecord sse_decode_box_autoadd_sound_record(SseDeserializer deserializer);
+
+@protected Thumbnail sse_decode_box_autoadd_thumbnail(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected ClusterProgress sse_decode_cluster_progress(SseDeserializer deserializer);
+
+@protected ClusteringSummary sse_decode_clustering_summary(SseDeserializer deserializer);
+
+@protected Collection sse_decode_collection(SseDeserializer deserializer);
+
+@protected CollectionItem sse_decode_collection_item(SseDeserializer deserializer);
+
+@protected Colormap sse_decode_colormap(SseDeserializer deserializer);
+
+@protected ConflictPolicy sse_decode_conflict_policy(SseDeserializer deserializer);
+
+@protected ContainmentMatch sse_decode_containment_match(SseDeserializer deserializer);
+
+@protected CsvColumn sse_decode_csv_column(SseDeserializer deserializer);
+
+@protected CsvExportOptions sse_decode_csv_export_options(SseDeserializer deserializer);
+
 @protected double sse_decode_f_32(SseDeserializer deserializer);
 
 @protected double sse_decode_f_64(SseDeserializer deserializer);
 
+@protected FeatureContribution sse_decode_feature_contribution(SseDeserializer deserializer);
+
+@protected FeatureGroup sse_decode_feature_group(SseDeserializer deserializer);
+
+@protected FolderMatch sse_decode_folder_match(SseDeserializer deserializer);
+
+@protected HistoryEntry sse_decode_history_entry(SseDeserializer deserializer);
+
+@protected HistoryOperation sse_decode_history_operation(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
 @protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+@protected ImportSummary sse_decode_import_summary(SseDeserializer deserializer);
+
+@protected IndexProgress sse_decode_index_progress(SseDeserializer deserializer);
+
+@protected IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<BatchMatches> sse_decode_list_batch_matches(SseDeserializer deserializer);
+
+@protected List<BatchQuery> sse_decode_list_batch_query(SseDeserializer deserializer);
+
+@protected List<Collection> sse_decode_list_collection(SseDeserializer deserializer);
+
+@protected List<CollectionItem> sse_decode_list_collection_item(SseDeserializer deserializer);
+
+@protected List<ContainmentMatch> sse_decode_list_containment_match(SseDeserializer deserializer);
+
+@protected List<CsvColumn> sse_decode_list_csv_column(SseDeserializer deserializer);
+
+@protected List<FeatureContribution> sse_decode_list_feature_contribution(SseDeserializer deserializer);
+
+@protected List<FeatureGroup> sse_decode_list_feature_group(SseDeserializer deserializer);
+
+@protected List<FolderMatch> sse_decode_list_folder_match(SseDeserializer deserializer);
+
+@protected List<HistoryEntry> sse_decode_list_history_entry(SseDeserializer deserializer);
+
+@protected List<List<SoundRecord>> sse_decode_list_list_sound_record(SseDeserializer deserializer);
+
+@protected List<LoopMatch> sse_decode_list_loop_match(SseDeserializer deserializer);
+
+@protected List<MapPoint> sse_decode_list_map_point(SseDeserializer deserializer);
+
 @protected List<MatchResult> sse_decode_list_match_result(SseDeserializer deserializer);
 
+@protected List<MidiRegion> sse_decode_list_midi_region(SseDeserializer deserializer);
+
+@protected List<PlatformInt64?> sse_decode_list_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected List<Thumbnail?> sse_decode_list_opt_box_autoadd_thumbnail(SseDeserializer deserializer);
+
 @protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
 
 @protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
 
 @protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
 
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
 @protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+@protected List<RecentSearch> sse_decode_list_recent_search(SseDeserializer deserializer);
+
+@protected List<Region> sse_decode_list_region(SseDeserializer deserializer);
+
+@protected List<SmartCollection> sse_decode_list_smart_collection(SseDeserializer deserializer);
+
+@protected List<SoundCluster> sse_decode_list_sound_cluster(SseDeserializer deserializer);
+
+@protected List<SoundProperty> sse_decode_list_sound_property(SseDeserializer deserializer);
+
 @protected List<SoundRecord> sse_decode_list_sound_record(SseDeserializer deserializer);
 
+@protected List<SoundTag> sse_decode_list_sound_tag(SseDeserializer deserializer);
+
+@protected List<TagSummary> sse_decode_list_tag_summary(SseDeserializer deserializer);
+
+@protected List<UsedSound> sse_decode_list_used_sound(SseDeserializer deserializer);
+
+@protected LoopMatch sse_decode_loop_match(SseDeserializer deserializer);
+
+@protected MapPoint sse_decode_map_point(SseDeserializer deserializer);
+
+@protected MapProgress sse_decode_map_progress(SseDeserializer deserializer);
+
 @protected MatchResult sse_decode_match_result(SseDeserializer deserializer);
 
+@protected MergeSummary sse_decode_merge_summary(SseDeserializer deserializer);
+
+@protected MidiNoteMapping sse_decode_midi_note_mapping(SseDeserializer deserializer);
+
+@protected MidiRegion sse_decode_midi_region(SseDeserializer deserializer);
+
+@protected NoiseInfo sse_decode_noise_info(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected ClusterProgress? sse_decode_opt_box_autoadd_cluster_progress(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected HistoryEntry? sse_decode_opt_box_autoadd_history_entry(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IndexProgress? sse_decode_opt_box_autoadd_index_progress(SseDeserializer deserializer);
+
+@protected MapPoint? sse_decode_opt_box_autoadd_map_point(SseDeserializer deserializer);
+
+@protected MapProgress? sse_decode_opt_box_autoadd_map_progress(SseDeserializer deserializer);
+
+@protected NoiseInfo? sse_decode_opt_box_autoadd_noise_info(SseDeserializer deserializer);
+
+@protected OptimizeProgress? sse_decode_opt_box_autoadd_optimize_progress(SseDeserializer deserializer);
+
+@protected SoundRecord? sse_decode_opt_box_autoadd_sound_record(SseDeserializer deserializer);
+
+@protected Thumbnail? sse_decode_opt_box_autoadd_thumbnail(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected OptimizeProgress sse_decode_optimize_progress(SseDeserializer deserializer);
+
+@protected OptimizeStep sse_decode_optimize_step(SseDeserializer deserializer);
+
+@protected OptimizeSummary sse_decode_optimize_summary(SseDeserializer deserializer);
+
+@protected RecentSearch sse_decode_recent_search(SseDeserializer deserializer);
+
+@protected Region sse_decode_region(SseDeserializer deserializer);
+
+@protected RescanSummary sse_decode_rescan_summary(SseDeserializer deserializer);
+
+@protected SamplerEnvelope sse_decode_sampler_envelope(SseDeserializer deserializer);
+
+@protected SearchKind sse_decode_search_kind(SseDeserializer deserializer);
+
+@protected SearchOptions sse_decode_search_options(SseDeserializer deserializer);
+
+@protected SearchPreset sse_decode_search_preset(SseDeserializer deserializer);
+
+@protected SmartCollection sse_decode_smart_collection(SseDeserializer deserializer);
+
+@protected SmartQuery sse_decode_smart_query(SseDeserializer deserializer);
+
+@protected SoundCluster sse_decode_sound_cluster(SseDeserializer deserializer);
+
+@protected SoundPage sse_decode_sound_page(SseDeserializer deserializer);
+
+@protected SoundProperty sse_decode_sound_property(SseDeserializer deserializer);
+
+@protected SoundQuery sse_decode_sound_query(SseDeserializer deserializer);
+
 @protected SoundRecord sse_decode_sound_record(SseDeserializer deserializer);
 
+@protected SoundSort sse_decode_sound_sort(SseDeserializer deserializer);
+
+@protected SoundTag sse_decode_sound_tag(SseDeserializer deserializer);
+
+@protected SoundUsage sse_decode_sound_usage(SseDeserializer deserializer);
+
+@protected TagSummary sse_decode_tag_summary(SseDeserializer deserializer);
+
+@protected Thumbnail sse_decode_thumbnail(SseDeserializer deserializer);
+
+@protected ThumbnailKind sse_decode_thumbnail_kind(SseDeserializer deserializer);
+
 @protected int sse_decode_u_16(SseDeserializer deserializer);
 
 @protected int sse_decode_u_32(SseDeserializer deserializer);
 
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
 @protected int sse_decode_u_8(SseDeserializer deserializer);
 
 @protected void sse_decode_unit(SseDeserializer deserializer);
 
+@protected UsedSound sse_decode_used_sound(SseDeserializer deserializer);
+
 @protected BigInt sse_decode_usize(SseDeserializer deserializer);
 
-@protected int sse_decode_i_32(SseDeserializer deserializer);
+@protected VerifyReport sse_decode_verify_report(SseDeserializer deserializer);
 
-@protected bool sse_decode_bool(SseDeserializer deserializer);
+@protected WaveformPeaks sse_decode_waveform_peaks(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Char(String self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_list_match_result_Sse(RustStreamSink<List<MatchResult>> self, SseSerializer serializer);
 
 @protected void sse_encode_String(String self, SseSerializer serializer);
 
+@protected void sse_encode_analysis_kind(AnalysisKind self, SseSerializer serializer);
+
+@protected void sse_encode_analysis_track(AnalysisTrack self, SseSerializer serializer);
+
+@protected void sse_encode_archive_summary(ArchiveSummary self, SseSerializer serializer);
+
 @protected void sse_encode_audio_fingerprint_info(AudioFingerprintInfo self, SseSerializer serializer);
 
+@protected void sse_encode_batch_matches(BatchMatches self, SseSerializer serializer);
+
+@protected void sse_encode_batch_query(BatchQuery self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cluster_progress(ClusterProgress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_csv_export_options(CsvExportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_history_entry(HistoryEntry self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_index_progress(IndexProgress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_map_point(MapPoint self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_map_progress(MapProgress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_match_result(MatchResult self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_noise_info(NoiseInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_optimize_progress(OptimizeProgress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_sampler_envelope(SamplerEnvelope self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_options(SearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_smart_query(SmartQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_sound_query(SoundQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_sound_record(SoundRecord self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_thumbnail(Thumbnail self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_cluster_progress(ClusterProgress self, SseSerializer serializer);
+
+@protected void sse_encode_clustering_summary(ClusteringSummary self, SseSerializer serializer);
+
+@protected void sse_encode_collection(Collection self, SseSerializer serializer);
+
+@protected void sse_encode_collection_item(CollectionItem self, SseSerializer serializer);
+
+@protected void sse_encode_colormap(Colormap self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_policy(ConflictPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_containment_match(ContainmentMatch self, SseSerializer serializer);
+
+@protected void sse_encode_csv_column(CsvColumn self, SseSerializer serializer);
+
+@protected void sse_encode_csv_export_options(CsvExportOptions self, SseSerializer serializer);
+
 @protected void sse_encode_f_32(double self, SseSerializer serializer);
 
 @protected void sse_encode_f_64(double self, SseSerializer serializer);
 
+@protected void sse_encode_feature_contribution(FeatureContribution self, SseSerializer serializer);
+
+@protected void sse_encode_feature_group(FeatureGroup self, SseSerializer serializer);
+
+@protected void sse_encode_folder_match(FolderMatch self, SseSerializer serializer);
+
+@protected void sse_encode_history_entry(HistoryEntry self, SseSerializer serializer);
+
+@protected void sse_encode_history_operation(HistoryOperation self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
 @protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+@protected void sse_encode_import_summary(ImportSummary self, SseSerializer serializer);
+
+@protected void sse_encode_index_progress(IndexProgress self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_report(IntegrityReport self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_matches(List<BatchMatches> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_query(List<BatchQuery> self, SseSerializer serializer);
+
+@protected void sse_encode_list_collection(List<Collection> self, SseSerializer serializer);
+
+@protected void sse_encode_list_collection_item(List<CollectionItem> self, SseSerializer serializer);
+
+@protected void sse_encode_list_containment_match(List<ContainmentMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_csv_column(List<CsvColumn> self, SseSerializer serializer);
+
+@protected void sse_encode_list_feature_contribution(List<FeatureContribution> self, SseSerializer serializer);
+
+@protected void sse_encode_list_feature_group(List<FeatureGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_folder_match(List<FolderMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_history_entry(List<HistoryEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_sound_record(List<List<SoundRecord>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_loop_match(List<LoopMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_map_point(List<MapPoint> self, SseSerializer serializer);
+
 @protected void sse_encode_list_match_result(List<MatchResult> self, SseSerializer serializer);
 
+@protected void sse_encode_list_midi_region(List<MidiRegion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_i_64(List<PlatformInt64?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_thumbnail(List<Thumbnail?> self, SseSerializer serializer);
+
 @protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer);
 
 @protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
 
 @protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer);
 
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer);
+
 @protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
+@protected void sse_encode_list_recent_search(List<RecentSearch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_region(List<Region> self, SseSerializer serializer);
+
+@protected void sse_encode_list_smart_collection(List<SmartCollection> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sound_cluster(List<SoundCluster> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sound_property(List<SoundProperty> self, SseSerializer serializer);
+
 @protected void sse_encode_list_sound_record(List<SoundRecord> self, SseSerializer serializer);
 
+@protected void sse_encode_list_sound_tag(List<SoundTag> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tag_summary(List<TagSummary> self, SseSerializer serializer);
+
+@protected void sse_encode_list_used_sound(List<UsedSound> self, SseSerializer serializer);
+
+@protected void sse_encode_loop_match(LoopMatch self, SseSerializer serializer);
+
+@protected void sse_encode_map_point(MapPoint self, SseSerializer serializer);
+
+@protected void sse_encode_map_progress(MapProgress self, SseSerializer serializer);
+
 @protected void sse_encode_match_result(MatchResult self, SseSerializer serializer);
 
+@protected void sse_encode_merge_summary(MergeSummary self, SseSerializer serializer);
+
+@protected void sse_encode_midi_note_mapping(MidiNoteMapping self, SseSerializer serializer);
+
+@protected void sse_encode_midi_region(MidiRegion self, SseSerializer serializer);
+
+@protected void sse_encode_noise_info(NoiseInfo self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cluster_progress(ClusterProgress? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_history_entry(HistoryEntry? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_index_progress(IndexProgress? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_map_point(MapPoint? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_map_progress(MapProgress? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_noise_info(NoiseInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_optimize_progress(OptimizeProgress? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_sound_record(SoundRecord? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_thumbnail(Thumbnail? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_optimize_progress(OptimizeProgress self, SseSerializer serializer);
+
+@protected void sse_encode_optimize_step(OptimizeStep self, SseSerializer serializer);
+
+@protected void sse_encode_optimize_summary(OptimizeSummary self, SseSerializer serializer);
+
+@protected void sse_encode_recent_search(RecentSearch self, SseSerializer serializer);
+
+@protected void sse_encode_region(Region self, SseSerializer serializer);
+
+@protected void sse_encode_rescan_summary(RescanSummary self, SseSerializer serializer);
+
+@protected void sse_encode_sampler_envelope(SamplerEnvelope self, SseSerializer serializer);
+
+@protected void sse_encode_search_kind(SearchKind self, SseSerializer serializer);
+
+@protected void sse_encode_search_options(SearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_search_preset(SearchPreset self, SseSerializer serializer);
+
+@protected void sse_encode_smart_collection(SmartCollection self, SseSerializer serializer);
+
+@protected void sse_encode_smart_query(SmartQuery self, SseSerializer serializer);
+
+@protected void sse_encode_sound_cluster(SoundCluster self, SseSerializer serializer);
+
+@protected void sse_encode_sound_page(SoundPage self, SseSerializer serializer);
+
+@protected void sse_encode_sound_property(SoundProperty self, SseSerializer serializer);
+
+@protected void sse_encode_sound_query(SoundQuery self, SseSerializer serializer);
+
 @protected void sse_encode_sound_record(SoundRecord self, SseSerializer serializer);
 
+@protected void sse_encode_sound_sort(SoundSort self, SseSerializer serializer);
+
+@protected void sse_encode_sound_tag(SoundTag self, SseSerializer serializer);
+
+@protected void sse_encode_sound_usage(SoundUsage self, SseSerializer serializer);
+
+@protected void sse_encode_tag_summary(TagSummary self, SseSerializer serializer);
+
+@protected void sse_encode_thumbnail(Thumbnail self, SseSerializer serializer);
+
+@protected void sse_encode_thumbnail_kind(ThumbnailKind self, SseSerializer serializer);
+
 @protected void sse_encode_u_16(int self, SseSerializer serializer);
 
 @protected void sse_encode_u_32(int self, SseSerializer serializer);
 
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
 @protected void sse_encode_u_8(int self, SseSerializer serializer);
 
 @protected void sse_encode_unit(void self, SseSerializer serializer);
 
+@protected void sse_encode_used_sound(UsedSound self, SseSerializer serializer);
+
 @protected void sse_encode_usize(BigInt self, SseSerializer serializer);
 
-@protected void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_verify_report(VerifyReport self, SseSerializer serializer);
 
-@protected void sse_encode_bool(bool self, SseSerializer serializer);
+@protected void sse_encode_waveform_peaks(WaveformPeaks self, SseSerializer serializer);
                 }
                 
 
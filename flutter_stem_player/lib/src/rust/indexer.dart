@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Progress of a directory index run
+class IndexProgress  {
+                /// Files matching the filters
+final BigInt total;
+/// Files handled so far (added, duplicate, skipped, re-analyzed or failed)
+final BigInt processed;
+final BigInt added;
+/// Same content already indexed under another path
+final BigInt duplicates;
+/// Already indexed under the same path
+final BigInt skipped;
+/// Already indexed under the same path, but re-analyzed because the
+/// fingerprint was computed by an older analysis (`FINGERPRINT_VERSION`)
+final BigInt reanalyzed;
+/// Could not be decoded or analyzed
+final BigInt failed;
+final bool finished;
+final bool cancelled;
+
+                const IndexProgress({required this.total ,required this.processed ,required this.added ,required this.duplicates ,required this.skipped ,required this.reanalyzed ,required this.failed ,required this.finished ,required this.cancelled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => total.hashCode^processed.hashCode^added.hashCode^duplicates.hashCode^skipped.hashCode^reanalyzed.hashCode^failed.hashCode^finished.hashCode^cancelled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexProgress &&
+                runtimeType == other.runtimeType
+                && total == other.total&& processed == other.processed&& added == other.added&& duplicates == other.duplicates&& skipped == other.skipped&& reanalyzed == other.reanalyzed&& failed == other.failed&& finished == other.finished&& cancelled == other.cancelled;
+        
+            }
+
+/// Result of a library rescan
+class RescanSummary  {
+                /// Sounds whose file was checked
+final BigInt checked;
+/// Modified on disk and re-analyzed
+final BigInt changed;
+/// Size or mtime changed but the contents are identical
+final BigInt touched;
+/// Unchanged, but re-analyzed because its fingerprint was missing or
+/// computed by an older analysis (`FINGERPRINT_VERSION`)
+final BigInt outdated;
+/// File no longer exists (see `PaletteDatabase::find_missing_files`)
+final BigInt missing;
+/// Changed but could not be re-analyzed
+final BigInt failed;
+
+                const RescanSummary({required this.checked ,required this.changed ,required this.touched ,required this.outdated ,required this.missing ,required this.failed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => checked.hashCode^changed.hashCode^touched.hashCode^outdated.hashCode^missing.hashCode^failed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RescanSummary &&
+                runtimeType == other.runtimeType
+                && checked == other.checked&& changed == other.changed&& touched == other.touched&& outdated == other.outdated&& missing == other.missing&& failed == other.failed;
+        
+            }
+
+/// Result of checking indexed files against their content hashes
+class VerifyReport  {
+                /// Files whose contents still match the indexed hash
+final BigInt verified;
+/// Sounds indexed without a hash that had one computed now
+final BigInt hashed;
+/// Sounds whose file contents differ from what was indexed
+final Int64List modified;
+/// Sounds whose file no longer exists
+final Int64List missing;
+
+                const VerifyReport({required this.verified ,required this.hashed ,required this.modified ,required this.missing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => verified.hashCode^hashed.hashCode^modified.hashCode^missing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VerifyReport &&
+                runtimeType == other.runtimeType
+                && verified == other.verified&& hashed == other.hashed&& modified == other.modified&& missing == other.missing;
+        
+            }
+            
\ No newline at end of file
@@ -6,94 +6,135 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Match result with time range
-class MatchResult {
-  final PlatformInt64 soundId;
-  final String filepath;
-  final String filename;
-  final double score;
-  final double matchStart;
-  final double matchEnd;
-  final double fileDuration;
-
-  const MatchResult({
-    required this.soundId,
-    required this.filepath,
-    required this.filename,
-    required this.score,
-    required this.matchStart,
-    required this.matchEnd,
-    required this.fileDuration,
-  });
-
-  @override
-  int get hashCode =>
-      soundId.hashCode ^
-      filepath.hashCode ^
-      filename.hashCode ^
-      score.hashCode ^
-      matchStart.hashCode ^
-      matchEnd.hashCode ^
-      fileDuration.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is MatchResult &&
-          runtimeType == other.runtimeType &&
-          soundId == other.soundId &&
-          filepath == other.filepath &&
-          filename == other.filename &&
-          score == other.score &&
-          matchStart == other.matchStart &&
-          matchEnd == other.matchEnd &&
-          fileDuration == other.fileDuration;
-}
+
+            
+
+            
+
+            /// Match result with time range
+class MatchResult  {
+                final PlatformInt64 soundId;
+final String filepath;
+final String filename;
+final double score;
+final double matchStart;
+final double matchEnd;
+final double fileDuration;
+/// Seconds between the positions `match_start` was chosen among: the
+/// window hop, one sample once refined, 0 when the whole sound (or a
+/// fixed range) was compared
+final double timeResolution;
+
+                const MatchResult({required this.soundId ,required this.filepath ,required this.filename ,required this.score ,required this.matchStart ,required this.matchEnd ,required this.fileDuration ,required this.timeResolution ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => soundId.hashCode^filepath.hashCode^filename.hashCode^score.hashCode^matchStart.hashCode^matchEnd.hashCode^fileDuration.hashCode^timeResolution.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MatchResult &&
+                runtimeType == other.runtimeType
+                && soundId == other.soundId&& filepath == other.filepath&& filename == other.filename&& score == other.score&& matchStart == other.matchStart&& matchEnd == other.matchEnd&& fileDuration == other.fileDuration&& timeResolution == other.timeResolution;
+        
+            }
 
 /// Sound record from database
-class SoundRecord {
-  final PlatformInt64 id;
-  final String filepath;
-  final String filename;
-  final double duration;
-  final int sampleRate;
-  final int channels;
-  final String format;
-  final String dateAdded;
-
-  const SoundRecord({
-    required this.id,
-    required this.filepath,
-    required this.filename,
-    required this.duration,
-    required this.sampleRate,
-    required this.channels,
-    required this.format,
-    required this.dateAdded,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      filepath.hashCode ^
-      filename.hashCode ^
-      duration.hashCode ^
-      sampleRate.hashCode ^
-      channels.hashCode ^
-      format.hashCode ^
-      dateAdded.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SoundRecord &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          filepath == other.filepath &&
-          filename == other.filename &&
-          duration == other.duration &&
-          sampleRate == other.sampleRate &&
-          channels == other.channels &&
-          format == other.format &&
-          dateAdded == other.dateAdded;
-}
+class SoundRecord  {
+                final PlatformInt64 id;
+final String filepath;
+final String filename;
+final double duration;
+final int sampleRate;
+final int channels;
+final String format;
+final String dateAdded;
+final String title;
+final String artist;
+final String notes;
+/// Where the recording came from (library, session, location...)
+final String source;
+/// User rating from 1 to 5 stars; 0 when unrated
+final int rating;
+/// Detected tempo; 0 when unknown
+final double bpm;
+/// Detected key, e.g. "A minor"; empty when unknown
+final String musicalKey;
+
+                const SoundRecord({required this.id ,required this.filepath ,required this.filename ,required this.duration ,required this.sampleRate ,required this.channels ,required this.format ,required this.dateAdded ,required this.title ,required this.artist ,required this.notes ,required this.source ,required this.rating ,required this.bpm ,required this.musicalKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^filepath.hashCode^filename.hashCode^duration.hashCode^sampleRate.hashCode^channels.hashCode^format.hashCode^dateAdded.hashCode^title.hashCode^artist.hashCode^notes.hashCode^source.hashCode^rating.hashCode^bpm.hashCode^musicalKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundRecord &&
+                runtimeType == other.runtimeType
+                && id == other.id&& filepath == other.filepath&& filename == other.filename&& duration == other.duration&& sampleRate == other.sampleRate&& channels == other.channels&& format == other.format&& dateAdded == other.dateAdded&& title == other.title&& artist == other.artist&& notes == other.notes&& source == other.source&& rating == other.rating&& bpm == other.bpm&& musicalKey == other.musicalKey;
+        
+            }
+
+/// Tag attached to a sound, with confidence for predicted labels
+class SoundTag  {
+                final String name;
+final double confidence;
+final String source;
+
+                const SoundTag({required this.name ,required this.confidence ,required this.source ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^confidence.hashCode^source.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SoundTag &&
+                runtimeType == other.runtimeType
+                && name == other.name&& confidence == other.confidence&& source == other.source;
+        
+            }
+
+/// Tag with the number of sounds carrying it
+class TagSummary  {
+                final String name;
+final PlatformInt64 soundCount;
+
+                const TagSummary({required this.name ,required this.soundCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^soundCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TagSummary &&
+                runtimeType == other.runtimeType
+                && name == other.name&& soundCount == other.soundCount;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// How matches become notes
+enum MidiNoteMapping {
+  /// `base_note` plus the match's index, on channel 0
+  sequential,
+
+  /// Drums on the percussion channel, other classes on channels of their
+  /// own with a program to match
+  instrument,
+
+  /// As `Instrument`, with pitched matches at their detected note
+  pitch,
+  ;
+}
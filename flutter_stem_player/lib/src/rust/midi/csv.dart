@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A column of the CSV export
+enum CsvColumn {
+                    soundId,
+filename,
+filepath,
+score,
+matchStart,
+matchEnd,
+matchDuration,
+fileDuration,
+                    ;
+                    
+                }
+
+/// CSV export options
+class CsvExportOptions  {
+                /// Field separator, e.g. ',', ';' or '\t'
+final String delimiter;
+/// Columns in order (empty for the defaults)
+final List<CsvColumn> columns;
+/// Write a header row of column names
+final bool header;
+
+                const CsvExportOptions({required this.delimiter ,required this.columns ,required this.header ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => delimiter.hashCode^columns.hashCode^header.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CsvExportOptions &&
+                runtimeType == other.runtimeType
+                && delimiter == other.delimiter&& columns == other.columns&& header == other.header;
+        
+            }
+            
\ No newline at end of file
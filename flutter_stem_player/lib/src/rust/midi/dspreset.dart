@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Amplitude envelope of an exported instrument, times in seconds
+class SamplerEnvelope  {
+                final double attack;
+final double decay;
+/// Level held while the key is down, 0 to 1
+final double sustain;
+final double release;
+
+                const SamplerEnvelope({required this.attack ,required this.decay ,required this.sustain ,required this.release ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => attack.hashCode^decay.hashCode^sustain.hashCode^release.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SamplerEnvelope &&
+                runtimeType == other.runtimeType
+                && attack == other.attack&& decay == other.decay&& sustain == other.sustain&& release == other.release;
+        
+            }
+            
\ No newline at end of file
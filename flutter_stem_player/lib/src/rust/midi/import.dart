@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One note of a MIDI clip, as a time range
+class MidiRegion  {
+                /// Bounds in seconds from the start of the clip
+final double startTime;
+final double endTime;
+final int note;
+final int velocity;
+/// Index of the track the note is on, and its name (empty if unnamed)
+final BigInt track;
+final String trackName;
+
+                const MidiRegion({required this.startTime ,required this.endTime ,required this.note ,required this.velocity ,required this.track ,required this.trackName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startTime.hashCode^endTime.hashCode^note.hashCode^velocity.hashCode^track.hashCode^trackName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MidiRegion &&
+                runtimeType == other.runtimeType
+                && startTime == other.startTime&& endTime == other.endTime&& note == other.note&& velocity == other.velocity&& track == other.track&& trackName == other.trackName;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An encoded image
+class Thumbnail  {
+                final ThumbnailKind kind;
+/// e.g. "image/png", or the artwork's own type
+final String mimeType;
+final Uint8List data;
+
+                const Thumbnail({required this.kind ,required this.mimeType ,required this.data ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^mimeType.hashCode^data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Thumbnail &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& mimeType == other.mimeType&& data == other.data;
+        
+            }
+
+/// Kind of thumbnail
+enum ThumbnailKind {
+                    waveform,
+spectrogram,
+/// Cover art embedded in the file, if any
+artwork,
+                    ;
+                    
+                }
+            
\ No newline at end of file
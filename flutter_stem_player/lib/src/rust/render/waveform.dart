@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Minimum and maximum sample value for each of `min.len()` equal slices
+class WaveformPeaks  {
+                final Float32List min;
+final Float32List max;
+
+                const WaveformPeaks({required this.min ,required this.max ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => min.hashCode^max.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WaveformPeaks &&
+                runtimeType == other.runtimeType
+                && min == other.min&& max == other.max;
+        
+            }
+            
\ No newline at end of file
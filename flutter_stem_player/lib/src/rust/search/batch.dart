@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Results of one batch query
+class BatchMatches  {
+                final BatchQuery query;
+/// Best first
+final List<MatchResult> matches;
+/// Why the query couldn't be fingerprinted (its matches are then empty)
+final String? error;
+
+                const BatchMatches({required this.query ,required this.matches ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => query.hashCode^matches.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchMatches &&
+                runtimeType == other.runtimeType
+                && query == other.query&& matches == other.matches&& error == other.error;
+        
+            }
+
+/// One query of a batch: a file, or a time range of it
+class BatchQuery  {
+                final String path;
+/// Range in seconds (the whole file if either end is None)
+final double? startTime;
+final double? endTime;
+
+                const BatchQuery({required this.path ,this.startTime ,this.endTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^startTime.hashCode^endTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchQuery &&
+                runtimeType == other.runtimeType
+                && path == other.path&& startTime == other.startTime&& endTime == other.endTime;
+        
+            }
+            
\ No newline at end of file
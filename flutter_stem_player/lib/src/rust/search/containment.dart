@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Where a file contains the query audio
+class ContainmentMatch  {
+                final PlatformInt64 soundId;
+final String filepath;
+final String filename;
+/// First sample of the occurrence, at the file's own sample rate
+final BigInt offsetSamples;
+final int sampleRate;
+/// Occurrence in seconds
+final double matchStart;
+final double matchEnd;
+/// Normalized cross-correlation at the offset (1 is an exact copy)
+final double correlation;
+/// Level of the occurrence relative to the query, in dB
+final double gainDb;
+
+                const ContainmentMatch({required this.soundId ,required this.filepath ,required this.filename ,required this.offsetSamples ,required this.sampleRate ,required this.matchStart ,required this.matchEnd ,required this.correlation ,required this.gainDb ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => soundId.hashCode^filepath.hashCode^filename.hashCode^offsetSamples.hashCode^sampleRate.hashCode^matchStart.hashCode^matchEnd.hashCode^correlation.hashCode^gainDb.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ContainmentMatch &&
+                runtimeType == other.runtimeType
+                && soundId == other.soundId&& filepath == other.filepath&& filename == other.filename&& offsetSamples == other.offsetSamples&& sampleRate == other.sampleRate&& matchStart == other.matchStart&& matchEnd == other.matchEnd&& correlation == other.correlation&& gainDb == other.gainDb;
+        
+            }
+            
\ No newline at end of file
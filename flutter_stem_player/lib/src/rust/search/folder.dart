@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A file of the searched folder matching the query
+class FolderMatch  {
+                final String filepath;
+final String filename;
+/// Similarity (0-100)
+final double score;
+/// Seconds
+final double duration;
+
+                const FolderMatch({required this.filepath ,required this.filename ,required this.score ,required this.duration ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filepath.hashCode^filename.hashCode^score.hashCode^duration.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FolderMatch &&
+                runtimeType == other.runtimeType
+                && filepath == other.filepath&& filename == other.filename&& score == other.score&& duration == other.duration;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A sound that loops through part of the query
+class LoopMatch  {
+                final PlatformInt64 soundId;
+final String filepath;
+final String filename;
+/// Mean similarity (0-100) of the query parts it matches
+final double score;
+/// Share of the query (0-1) covered by matching parts
+final double coverage;
+/// Seconds of the loop
+final double loopDuration;
+/// Times the loop fits into the query
+final double repetitions;
+
+                const LoopMatch({required this.soundId ,required this.filepath ,required this.filename ,required this.score ,required this.coverage ,required this.loopDuration ,required this.repetitions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => soundId.hashCode^filepath.hashCode^filename.hashCode^score.hashCode^coverage.hashCode^loopDuration.hashCode^repetitions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoopMatch &&
+                runtimeType == other.runtimeType
+                && soundId == other.soundId&& filepath == other.filepath&& filename == other.filename&& score == other.score&& coverage == other.coverage&& loopDuration == other.loopDuration&& repetitions == other.repetitions;
+        
+            }
+            
\ No newline at end of file
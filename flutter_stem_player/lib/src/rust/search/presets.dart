@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Kind of material a search is tuned for
+enum SearchPreset {
+                    /// Single drum hits: short, pitch-agnostic, matched at sample accuracy
+drumOneShots,
+/// Bars of a pitched part: harmony counts and tempo may drift
+melodicLoops,
+/// Beds, room tones and textures: broad spectral character over seconds
+ambience,
+/// Speech: voice timbre, tolerant of pace and background noise
+dialogue,
+                    ;
+                    
+                }
+            
\ No newline at end of file
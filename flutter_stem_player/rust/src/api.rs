@@ -1,147 +1,2304 @@
 //! Flutter API - functions exposed to Dart via flutter_rust_bridge
 
-use crate::database::PaletteDatabase;
-use crate::fingerprint::{AudioFingerprint, Fingerprinter};
-use crate::midi::{export_matches_to_csv, export_matches_to_markers, export_matches_to_midi, MidiExportConfig};
-use crate::search::SearchEngine;
-use crate::{MatchResult, SoundRecord};
-use std::sync::Mutex;
+use crate::database::{
+    ArchiveOptions, ArchiveSummary, ClusterProgress, ClusteringSummary, Collection, CollectionEntry, CollectionItem,
+    ConflictPolicy, HistoryEntry, ImportSummary, IntegrityReport, MapPoint, MapProgress, MergeSummary, OptimizeProgress,
+    OptimizeSummary, PaletteDatabase, RecentSearch, Region, SearchKind, SmartCollection, SmartQuery, SoundCluster, SoundPage,
+    SoundProperty, SoundQuery, SoundSort, SoundUsage, UsageEvent, UsedSound,
+};
+use crate::fingerprint::{
+    analyze_time_series, AnalysisKind, AnalysisTrack, AudioFingerprint, FeatureContribution, FeatureGroup,
+    FeatureMask, Fingerprinter, InstrumentClass, InstrumentClassifier,
+};
+use crate::frb_generated::StreamSink;
+use crate::indexer::{
+    IndexOptions, IndexProgress, Indexer, RescanSummary, VerifyReport, CLASSIFIER_TAG_SOURCE, RELINK_MIN_SIMILARITY,
+};
+use crate::midi::{
+    assign_tracks, detect_drum_hits, export_beat_grid_to_midi, export_drum_hits_to_midi, export_matches_bundle,
+    export_matches_to_als, export_matches_to_arrangement, export_matches_to_csv, export_matches_to_cue,
+    export_matches_to_dawproject, export_matches_to_dspreset, export_matches_to_edl, export_matches_to_json,
+    export_matches_to_m3u, export_matches_to_markers, export_matches_to_midi, export_matches_to_reaper_regions,
+    export_matches_to_sfz, export_matches_to_xpm, export_notes_to_midi, export_notes_to_musicxml, median_note,
+    transcribe_melody, CsvExportOptions, MidiExportConfig, MidiNoteMapping, MidiQuantize, MidiRegion, SamplerEnvelope,
+    TranscribedNote,
+};
+use crate::render::{
+    export_match_report, render_file_spectrogram, render_thumbnails, Colormap, ReportEntry, SpectrogramConfig, Thumbnail,
+    ThumbnailKind, WaveformPeaks, DEFAULT_WAVEFORM_BUCKETS, MAX_ARTWORK_BYTES,
+};
+use crate::search::{
+    BatchMatches, BatchQuery, ContainmentMatch, ExampleQuery, FolderCache, FolderMatch, LiveQuery, LoopMatch,
+    GpuScorer, QueryCache, SearchConfig, SearchEngine, SearchPreset, DEFAULT_MEMORY_BUDGET,
+};
+use crate::tagging::{load_labels, ModelTagger, TaggerConfig};
+use crate::{CancellationToken, MatchResult, SoundMetadataUpdate, SoundRecord, SoundTag, TagSummary};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
 
-/// Global database instance (lazily initialized)
-static DATABASE: std::sync::OnceLock<Mutex<Option<PaletteDatabase>>> = std::sync::OnceLock::new();
+/// An open palette and the state of its long-running operations
+struct Palette {
+    db: Arc<PaletteDatabase>,
+    /// Progress of the current (or last) directory index run
+    index_progress: Mutex<Option<IndexProgress>>,
+    /// Token of the running directory index
+    index_cancel: Mutex<CancellationToken>,
+    /// Token shared by similarity searches started since the last
+    /// `cancel_searches`
+    search_cancel: Mutex<CancellationToken>,
+    /// Progress of the current (or last) `optimize_database` run
+    optimize_progress: Mutex<Option<OptimizeProgress>>,
+    /// Progress of the current (or last) `cluster_library` run
+    cluster_progress: Mutex<Option<ClusterProgress>>,
+    /// Progress of the current (or last) `compute_sound_map` run
+    map_progress: Mutex<Option<MapProgress>>,
+    /// Recent query fingerprints and search results
+    query_cache: QueryCache,
+    /// Library matrix uploaded by `enable_gpu_scoring`
+    gpu: Mutex<Option<Arc<GpuScorer>>>,
+}
+
+/// Open palettes by handle
+///
+/// The mutex only guards the map; calls clone the `Arc` and release it, so
+/// queries from the UI don't wait behind indexing, on the same palette or
+/// another one.
+static PALETTES: std::sync::OnceLock<Mutex<HashMap<i64, Arc<Palette>>>> = std::sync::OnceLock::new();
+
+/// Handle given to the next opened palette (handles are never reused)
+static NEXT_PALETTE: AtomicI64 = AtomicI64::new(1);
+
+fn palettes() -> &'static Mutex<HashMap<i64, Arc<Palette>>> {
+    PALETTES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn palette(handle: i64) -> Result<Arc<Palette>, String> {
+    palettes()
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Palette {} is not open", handle))
+}
+
+fn database(handle: i64) -> Result<Arc<PaletteDatabase>, String> {
+    Ok(palette(handle)?.db.clone())
+}
+
+fn register_palette(db: PaletteDatabase) -> i64 {
+    let handle = NEXT_PALETTE.fetch_add(1, Ordering::Relaxed);
+    let palette = Palette {
+        db: Arc::new(db),
+        index_progress: Mutex::new(None),
+        index_cancel: Mutex::new(CancellationToken::new()),
+        search_cancel: Mutex::new(CancellationToken::new()),
+        optimize_progress: Mutex::new(None),
+        cluster_progress: Mutex::new(None),
+        map_progress: Mutex::new(None),
+        query_cache: QueryCache::default(),
+        gpu: Mutex::new(None),
+    };
+    palettes().lock().unwrap().insert(handle, Arc::new(palette));
+    handle
+}
+
+/// Optional genre/mood tagging model applied at index time
+static TAGGER: std::sync::OnceLock<Mutex<Option<Arc<ModelTagger>>>> = std::sync::OnceLock::new();
+
+fn get_tagger() -> &'static Mutex<Option<Arc<ModelTagger>>> {
+    TAGGER.get_or_init(|| Mutex::new(None))
+}
+
+/// Fingerprints of files searched by `find_similar_in_directory`, shared
+/// by every palette since they don't belong to any
+static FOLDER_CACHE: std::sync::OnceLock<FolderCache> = std::sync::OnceLock::new();
 
-fn get_db() -> &'static Mutex<Option<PaletteDatabase>> {
-    DATABASE.get_or_init(|| Mutex::new(None))
+/// Update usage statistics for sounds a call returned or exported
+///
+/// Failures are only logged: statistics must never break the search or
+/// export that produced them, and exports work with the palette closed.
+fn track_usage(palette: i64, sound_ids: impl IntoIterator<Item = i64>, event: UsageEvent) {
+    let ids: Vec<i64> = sound_ids.into_iter().collect();
+    if ids.is_empty() {
+        return;
+    }
+    if let Ok(db) = database(palette) {
+        if let Err(e) = db.record_usage(&ids, event) {
+            log::warn!("Failed to record usage: {}", e);
+        }
+    }
 }
 
-/// Initialize the audio palette database
+/// Add a search to the palette's recent searches; like usage tracking,
+/// failures are logged rather than failing the search
+fn track_search(palette: i64, kind: SearchKind, query: &str, parameters: serde_json::Value, result_count: usize) {
+    if let Ok(db) = database(palette) {
+        if let Err(e) = db.record_search(kind, query, &parameters.to_string(), result_count) {
+            log::warn!("Failed to record search: {}", e);
+        }
+    }
+}
+
+/// Open (or create) a palette database, returning the handle that every
+/// other call takes to target it
+///
+/// Several palettes can be open at once (e.g. "drums" and "foley").
 #[flutter_rust_bridge::frb(sync)]
-pub fn init_database(db_path: String) -> Result<(), String> {
+pub fn open_palette(db_path: String) -> Result<i64, String> {
     let db = PaletteDatabase::open(&db_path).map_err(|e| e.to_string())?;
-    let mut guard = get_db().lock().unwrap();
-    *guard = Some(db);
-    Ok(())
+    Ok(register_palette(db))
+}
+
+/// Open (or create) an encrypted palette, returning its handle
+///
+/// The passphrase is needed every time the palette is opened; there is no
+/// way to recover a palette whose passphrase is lost.
+#[flutter_rust_bridge::frb(sync)]
+pub fn open_encrypted_palette(db_path: String, passphrase: String) -> Result<i64, String> {
+    let db = PaletteDatabase::open_encrypted(&db_path, &passphrase).map_err(|e| e.to_string())?;
+    Ok(register_palette(db))
+}
+
+/// Close a palette; calls already running on it finish first
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_palette(palette: i64) {
+    palettes().lock().unwrap().remove(&palette);
+}
+
+/// Handles of the open palettes, in the order they were opened
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_open_palettes() -> Vec<i64> {
+    let mut handles: Vec<i64> = palettes().lock().unwrap().keys().copied().collect();
+    handles.sort_unstable();
+    handles
+}
+
+/// Whether this build can open encrypted palettes
+#[flutter_rust_bridge::frb(sync)]
+pub fn encryption_supported() -> bool {
+    crate::database::ENCRYPTION_SUPPORTED
 }
 
 /// Add a sound file to the database
-pub fn add_sound(filepath: String) -> Result<i64, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
-
-    // Load audio and extract metadata
-    let audio = crate::audio::AudioData::load(&filepath).map_err(|e| e.to_string())?;
-    let filename = std::path::Path::new(&filepath)
-        .file_name()
-        .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_else(|| filepath.clone());
-
-    let sound_id = db.add_sound(
-        &filepath,
-        &filename,
-        audio.duration,
-        audio.sample_rate,
-        audio.channels as u16,
-        "unknown",
-    ).map_err(|e| e.to_string())?;
-
-    // Extract fingerprint
+///
+/// If the same audio (identical file contents) is already indexed under
+/// another path, returns the existing sound's id without re-indexing.
+pub fn add_sound(palette: i64, filepath: String) -> Result<i64, String> {
+    let db = database(palette)?;
+    let tagger = get_tagger().lock().unwrap().clone();
+    Indexer::new(&db, tagger.as_deref()).add_file(&filepath).map_err(|e| e.to_string())
+}
+
+/// Add many sound files, analyzing them in parallel and writing them in a
+/// single transaction
+///
+/// Returns one entry per input path: the sound id (the existing one for
+/// exact duplicates), or None if the file could not be loaded.
+pub fn add_sounds_batch(palette: i64, filepaths: Vec<String>) -> Result<Vec<Option<i64>>, String> {
+    let db = database(palette)?;
+    let tagger = get_tagger().lock().unwrap().clone();
+    Indexer::new(&db, tagger.as_deref()).add_files(&filepaths).map_err(|e| e.to_string())
+}
+
+/// Re-analyze sounds whose files were edited on disk since indexing, or
+/// whose fingerprint is outdated
+pub fn rescan_library(palette: i64) -> Result<RescanSummary, String> {
+    let db = database(palette)?;
+    let tagger = get_tagger().lock().unwrap().clone();
+    Indexer::new(&db, tagger.as_deref()).rescan().map_err(|e| e.to_string())
+}
+
+/// Check every indexed file against the content hash it was indexed with
+pub fn verify_library(palette: i64) -> Result<VerifyReport, String> {
+    let db = database(palette)?;
+    Indexer::new(&db, None).verify().map_err(|e| e.to_string())
+}
+
+/// Groups of sounds whose files have identical contents
+pub fn find_duplicate_groups(palette: i64) -> Result<Vec<Vec<SoundRecord>>, String> {
+    let db = database(palette)?;
+    db.find_duplicate_groups().map_err(|e| e.to_string())
+}
+
+/// Groups of perceptually near-identical sounds (other encodes, trims or
+/// bitrates of the same sample) scoring at least `threshold` (0-100, e.g.
+/// 98) against another member, largest group first
+pub fn find_duplicates(palette: i64, threshold: f64) -> Result<Vec<Vec<SoundRecord>>, String> {
+    let db = database(palette)?;
+    db.find_near_duplicate_groups(threshold).map_err(|e| e.to_string())
+}
+
+/// List sounds whose file no longer exists at the indexed path
+pub fn find_missing_sounds(palette: i64) -> Result<Vec<SoundRecord>, String> {
+    let db = database(palette)?;
+    db.find_missing_files().map_err(|e| e.to_string())
+}
+
+/// Point a sound at its file's new location
+///
+/// The new file must have the same contents or a closely matching
+/// fingerprint; returns the fingerprint similarity (0-100).
+pub fn relink_sound(palette: i64, sound_id: i64, new_path: String) -> Result<f64, String> {
+    let db = database(palette)?;
+    Indexer::new(&db, None)
+        .relink(sound_id, &new_path, RELINK_MIN_SIMILARITY)
+        .map_err(|e| e.to_string())
+}
+
+/// Recursively index a folder
+///
+/// Only files with one of `extensions` (all supported formats when empty)
+/// and at most `max_file_size` bytes are considered; files already in the
+/// library are skipped unless their fingerprint is outdated. Poll
+/// `get_index_progress` while this runs.
+pub fn index_directory(
+    palette: i64,
+    path: String,
+    extensions: Vec<String>,
+    max_file_size: Option<u64>,
+) -> Result<IndexProgress, String> {
+    let palette = self::palette(palette)?;
+    let tagger = get_tagger().lock().unwrap().clone();
+
+    let mut options = IndexOptions {
+        max_file_size,
+        ..IndexOptions::default()
+    };
+    if !extensions.is_empty() {
+        options.extensions = extensions;
+    }
+
+    let cancel = CancellationToken::new();
+    *palette.index_cancel.lock().unwrap() = cancel.clone();
+    Indexer::new(&palette.db, tagger.as_deref())
+        .with_cancellation(cancel)
+        .index_directory(std::path::Path::new(&path), &options, |progress| {
+            *palette.index_progress.lock().unwrap() = Some(progress.clone());
+            true
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Progress of the running (or last finished) `index_directory` call
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_index_progress(palette: i64) -> Option<IndexProgress> {
+    self::palette(palette).ok()?.index_progress.lock().unwrap().clone()
+}
+
+/// Stop the running `index_directory` call once the files it is
+/// analyzing are stored; it returns with `cancelled` set
+#[flutter_rust_bridge::frb(sync)]
+pub fn cancel_indexing(palette: i64) {
+    if let Ok(palette) = self::palette(palette) {
+        palette.index_cancel.lock().unwrap().cancel();
+    }
+}
+
+/// Compact the database and rebuild its indexes and statistics
+///
+/// Worth running occasionally on long-lived palettes with many add/remove
+/// cycles. Writes wait until it finishes; poll `get_optimize_progress`.
+pub fn optimize_database(palette: i64) -> Result<OptimizeSummary, String> {
+    let palette = self::palette(palette)?;
+    palette
+        .db
+        .optimize(|progress| *palette.optimize_progress.lock().unwrap() = Some(progress.clone()))
+        .map_err(|e| e.to_string())
+}
+
+/// Check the database file and its fingerprints for damage
+pub fn check_integrity(palette: i64) -> Result<IntegrityReport, String> {
+    let db = database(palette)?;
+    db.check_integrity().map_err(|e| e.to_string())
+}
+
+/// Library root of a portable palette (None if paths are stored absolute)
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_library_root(palette: i64) -> Result<Option<String>, String> {
+    let db = database(palette)?;
+    Ok(db.library_root())
+}
+
+/// Store paths under `root` relative to it, so the palette keeps working
+/// when the drive holding the library is mounted elsewhere; call again
+/// with the new location after it moves. None stores absolute paths
+/// again. Returns how many paths changed form.
+pub fn set_library_root(palette: i64, root: Option<String>) -> Result<usize, String> {
+    let db = database(palette)?;
+    db.set_library_root(root.as_deref()).map_err(|e| e.to_string())
+}
+
+/// Progress of the running (or last) `optimize_database` call
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_optimize_progress(palette: i64) -> Option<OptimizeProgress> {
+    self::palette(palette).ok()?.optimize_progress.lock().unwrap().clone()
+}
+
+/// Group the library into clusters of similar sounds for browsing,
+/// replacing the previous clustering
+///
+/// `clusters` of 0 picks the number that separates the library best
+/// (by silhouette). Each cluster gets a proposed label. Sounds added
+/// afterwards stay unclustered until the next run; poll
+/// `get_cluster_progress` meanwhile.
+pub fn cluster_library(palette: i64, clusters: usize) -> Result<ClusteringSummary, String> {
+    let palette = self::palette(palette)?;
+    palette
+        .db
+        .cluster_library(clusters, |progress| *palette.cluster_progress.lock().unwrap() = Some(progress.clone()))
+        .map_err(|e| e.to_string())
+}
+
+/// Progress of the running (or last) `cluster_library` call
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_cluster_progress(palette: i64) -> Option<ClusterProgress> {
+    self::palette(palette).ok()?.cluster_progress.lock().unwrap().clone()
+}
+
+/// Clusters from the last `cluster_library` run, largest first
+pub fn get_clusters(palette: i64) -> Result<Vec<SoundCluster>, String> {
+    let db = database(palette)?;
+    db.get_clusters().map_err(|e| e.to_string())
+}
+
+/// Sounds in a cluster ("browse this cluster")
+pub fn get_cluster_sounds(palette: i64, cluster_id: i64) -> Result<Vec<SoundRecord>, String> {
+    let db = database(palette)?;
+    db.get_cluster_sounds(cluster_id).map_err(|e| e.to_string())
+}
+
+/// Save the last clustering as categories under the top-level category
+/// `parent` (one per cluster, named by its label), replacing the ones a
+/// previous call put there; returns how many were written
+pub fn write_cluster_categories(palette: i64, parent: String) -> Result<usize, String> {
+    let db = database(palette)?;
+    db.write_cluster_categories(&parent).map_err(|e| e.to_string())
+}
+
+/// Cluster a sound belongs to, to browse its neighbours (None if unclustered)
+pub fn get_sound_cluster(palette: i64, sound_id: i64) -> Result<Option<i64>, String> {
+    let db = database(palette)?;
+    db.get_sound_cluster(sound_id).map_err(|e| e.to_string())
+}
+
+/// Lay the library out on a 2D map where similar sounds sit near each
+/// other, replacing the previous layout; returns the number of sounds placed
+///
+/// Sounds added afterwards have no position until the next run; poll
+/// `get_map_progress` meanwhile.
+pub fn compute_sound_map(palette: i64) -> Result<usize, String> {
+    let palette = self::palette(palette)?;
+    palette
+        .db
+        .compute_sound_map(|progress| *palette.map_progress.lock().unwrap() = Some(progress.clone()))
+        .map_err(|e| e.to_string())
+}
+
+/// Progress of the running (or last) `compute_sound_map` call
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_map_progress(palette: i64) -> Option<MapProgress> {
+    self::palette(palette).ok()?.map_progress.lock().unwrap().clone()
+}
+
+/// Every placed sound's map position (coordinates 0-1)
+pub fn get_sound_map(palette: i64) -> Result<Vec<MapPoint>, String> {
+    let db = database(palette)?;
+    db.get_sound_map().map_err(|e| e.to_string())
+}
+
+/// A sound's map position, to centre the map on it (None if not placed)
+pub fn get_map_position(palette: i64, sound_id: i64) -> Result<Option<MapPoint>, String> {
+    let db = database(palette)?;
+    db.get_map_position(sound_id).map_err(|e| e.to_string())
+}
+
+/// Report an indexed sound with exactly the same contents as a file stored
+/// under a different path
+pub fn find_duplicate(palette: i64, filepath: String) -> Result<Option<SoundRecord>, String> {
+    let db = database(palette)?;
+    let hash = crate::audio::content_hash(&filepath).map_err(|e| e.to_string())?;
+    db.find_duplicate(&hash, &filepath).map_err(|e| e.to_string())
+}
+
+/// Load an ONNX genre/mood tagging model to run when sounds are added
+/// (requires a build with the `onnx` feature)
+pub fn load_tagging_model(model_path: String, labels_path: String, threshold: f64) -> Result<(), String> {
+    let labels = load_labels(&labels_path).map_err(|e| e.to_string())?;
+    let config = TaggerConfig {
+        threshold,
+        ..TaggerConfig::default()
+    };
+    let tagger = ModelTagger::load(&model_path, labels, config).map_err(|e| e.to_string())?;
+    *get_tagger().lock().unwrap() = Some(Arc::new(tagger));
+    Ok(())
+}
+
+/// Stop applying the tagging model to new sounds
+#[flutter_rust_bridge::frb(sync)]
+pub fn unload_tagging_model() {
+    *get_tagger().lock().unwrap() = None;
+}
+
+/// Predict instrument labels for a file without indexing it
+pub fn classify_instrument(filepath: String) -> Result<Vec<SoundTag>, String> {
     let fingerprinter = Fingerprinter::default();
-    let fp = fingerprinter.extract(&audio).map_err(|e| e.to_string())?;
-    db.store_fingerprint(sound_id, &fp).map_err(|e| e.to_string())?;
+    let fp = fingerprinter.extract_from_file(&filepath).map_err(|e| e.to_string())?;
 
-    Ok(sound_id)
+    Ok(InstrumentClassifier::default()
+        .classify(&fp)
+        .into_iter()
+        .map(|p| SoundTag {
+            name: p.class.tag_name().to_string(),
+            confidence: p.confidence,
+            source: CLASSIFIER_TAG_SOURCE.to_string(),
+        })
+        .collect())
 }
 
 /// Get all sounds in the database
-pub fn get_all_sounds() -> Result<Vec<SoundRecord>, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
+pub fn get_all_sounds(palette: i64) -> Result<Vec<SoundRecord>, String> {
+    let db = database(palette)?;
     db.get_all_sounds().map_err(|e| e.to_string())
 }
 
+/// Get one page of the library in the given order
+///
+/// Prefer this over `get_all_sounds` for large libraries.
+pub fn get_sounds_page(palette: i64, offset: i64, limit: i64, sort: SoundSort, descending: bool) -> Result<SoundPage, String> {
+    let db = database(palette)?;
+    db.list_sounds(sort, descending, offset, limit).map_err(|e| e.to_string())
+}
+
+/// Find sounds by duration, format, sample rate, channels, rating and
+/// text, returning one page of the sorted results
+pub fn query_sounds(palette: i64, query: SoundQuery) -> Result<SoundPage, String> {
+    let db = database(palette)?;
+    db.query_sounds(&query).map_err(|e| e.to_string())
+}
+
+/// Rate a sound from 1 to 5 stars, or 0 to clear the rating
+pub fn set_sound_rating(palette: i64, sound_id: i64, rating: u8) -> Result<(), String> {
+    let db = database(palette)?;
+    db.set_sound_rating(sound_id, rating).map_err(|e| e.to_string())
+}
+
+/// Count a playback of a sound towards its usage statistics
+pub fn record_audition(palette: i64, sound_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.record_usage(&[sound_id], UsageEvent::Auditioned).map_err(|e| e.to_string())
+}
+
+/// How often a sound was returned, auditioned and exported
+pub fn get_sound_usage(palette: i64, sound_id: i64) -> Result<SoundUsage, String> {
+    let db = database(palette)?;
+    db.get_sound_usage(sound_id).map_err(|e| e.to_string())
+}
+
+/// Sounds auditioned or exported most often
+pub fn get_most_used_sounds(palette: i64, limit: i64) -> Result<Vec<UsedSound>, String> {
+    let db = database(palette)?;
+    db.most_used_sounds(limit).map_err(|e| e.to_string())
+}
+
+/// Sounds auditioned or exported most recently
+pub fn get_recently_used_sounds(palette: i64, limit: i64) -> Result<Vec<UsedSound>, String> {
+    let db = database(palette)?;
+    db.recently_used_sounds(limit).map_err(|e| e.to_string())
+}
+
+/// Waveform overview of a sound with `buckets` min/max pairs
+///
+/// Served from the database cache when the file is unchanged since the
+/// peaks were computed; otherwise decoded once and cached.
+pub fn get_waveform(palette: i64, sound_id: i64, buckets: usize) -> Result<WaveformPeaks, String> {
+    let db = database(palette)?;
+    if let Some(peaks) = db.get_waveform(sound_id, buckets).map_err(|e| e.to_string())? {
+        return Ok(peaks);
+    }
+
+    let sound = db
+        .get_sound(sound_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Sound {} not found", sound_id))?;
+    let audio = crate::audio::AudioData::load(&sound.filepath).map_err(|e| e.to_string())?;
+    let peaks = WaveformPeaks::compute(&audio.samples, buckets);
+    db.store_waveform(sound_id, &peaks).map_err(|e| e.to_string())?;
+    Ok(peaks)
+}
+
+/// Thumbnail image of a sound (PNG for waveforms and spectrograms)
+///
+/// Rendered at index time; sounds indexed earlier, or changed since, are
+/// rendered on first request and cached. None if `kind` is `Artwork` and
+/// the file has no (small enough) embedded picture.
+pub fn get_thumbnail(palette: i64, sound_id: i64, kind: ThumbnailKind) -> Result<Option<Thumbnail>, String> {
+    let db = database(palette)?;
+    if let Some(thumbnail) = db.get_thumbnail(sound_id, kind).map_err(|e| e.to_string())? {
+        return Ok(Some(thumbnail));
+    }
+
+    let sound = db
+        .get_sound(sound_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Sound {} not found", sound_id))?;
+    let thumbnails = if kind == ThumbnailKind::Artwork {
+        let artwork = crate::audio::extract_artwork(&sound.filepath).map_err(|e| e.to_string())?;
+        artwork
+            .filter(|(_, data)| data.len() <= MAX_ARTWORK_BYTES)
+            .map(|(mime_type, data)| Thumbnail { kind, mime_type, data })
+            .into_iter()
+            .collect()
+    } else {
+        let audio = crate::audio::AudioData::load(&sound.filepath).map_err(|e| e.to_string())?;
+        let peaks = WaveformPeaks::compute(&audio.samples, DEFAULT_WAVEFORM_BUCKETS);
+        render_thumbnails(&audio, &peaks).map_err(|e| e.to_string())?
+    };
+    db.store_thumbnails_batch(&[(sound_id, thumbnails.clone())]).map_err(|e| e.to_string())?;
+    Ok(thumbnails.into_iter().find(|t| t.kind == kind))
+}
+
+/// Cached thumbnails for a page of sounds, parallel to `sound_ids`
+///
+/// Never decodes audio, so grid views can call it for every page; fill
+/// gaps with `get_thumbnail`.
+pub fn get_cached_thumbnails(palette: i64, sound_ids: Vec<i64>, kind: ThumbnailKind) -> Result<Vec<Option<Thumbnail>>, String> {
+    let db = database(palette)?;
+    db.get_thumbnails(&sound_ids, kind).map_err(|e| e.to_string())
+}
+
+/// Onsets, beat grid or pitch curve of a sound
+///
+/// Computed at index time; sounds indexed earlier, or changed since, are
+/// analyzed on first request and the results stored.
+pub fn get_analysis(palette: i64, sound_id: i64, kind: AnalysisKind) -> Result<AnalysisTrack, String> {
+    let db = database(palette)?;
+    if let Some(track) = db.get_analysis(sound_id, kind).map_err(|e| e.to_string())? {
+        return Ok(track);
+    }
+
+    let sound = db
+        .get_sound(sound_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Sound {} not found", sound_id))?;
+    let audio = crate::audio::AudioData::load(&sound.filepath).map_err(|e| e.to_string())?;
+    let tracks = analyze_time_series(&audio.samples, audio.sample_rate);
+    db.store_analyses_batch(&[(sound_id, tracks.clone())]).map_err(|e| e.to_string())?;
+    tracks
+        .into_iter()
+        .find(|t| t.kind == kind)
+        .ok_or_else(|| format!("No {} analysis", kind.name()))
+}
+
 /// Get sound count
 #[flutter_rust_bridge::frb(sync)]
-pub fn get_sound_count() -> Result<i64, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
+pub fn get_sound_count(palette: i64) -> Result<i64, String> {
+    let db = database(palette)?;
     db.count().map_err(|e| e.to_string())
 }
 
-/// Search sounds by filename
-pub fn search_sounds(query: String) -> Result<Vec<SoundRecord>, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
-    db.search(&query).map_err(|e| e.to_string())
+/// Number of sounds similarity search skips because their fingerprint is
+/// missing or outdated; `index_directory` and `rescan_library` re-analyze them
+pub fn get_outdated_fingerprint_count(palette: i64) -> Result<i64, String> {
+    let db = database(palette)?;
+    db.count_outdated_fingerprints().map_err(|e| e.to_string())
+}
+
+/// Search sounds by filename, path, metadata and tags (prefix matching, ranked)
+pub fn search_sounds(palette: i64, query: String) -> Result<Vec<SoundRecord>, String> {
+    let db = database(palette)?;
+    let sounds = db.search(&query).map_err(|e| e.to_string())?;
+    track_usage(palette, sounds.iter().map(|s| s.id), UsageEvent::Returned);
+    track_search(palette, SearchKind::Text, &query, serde_json::json!({}), sounds.len());
+    Ok(sounds)
+}
+
+/// Export the whole library (records, tags, fingerprints and optionally the
+/// audio files) to a zip archive
+pub fn export_library(palette: i64, archive_path: String, include_audio: bool) -> Result<ArchiveSummary, String> {
+    let db = database(palette)?;
+    db.export_archive(&archive_path, &ArchiveOptions { include_audio })
+        .map_err(|e| e.to_string())
+}
+
+/// Merge a library archive into the current database, extracting any
+/// bundled audio into `audio_dir`
+pub fn import_library(palette: i64, archive_path: String, audio_dir: Option<String>) -> Result<ImportSummary, String> {
+    let db = database(palette)?;
+    db.import_archive(&archive_path, audio_dir.as_deref().map(std::path::Path::new))
+        .map_err(|e| e.to_string())
+}
+
+/// Merge another palette database (e.g. a teammate's) into this one
+///
+/// Sounds already here, by path or content hash, are handled according to
+/// `policy`; tags and collections are combined.
+pub fn merge_database(palette: i64, other_path: String, policy: ConflictPolicy) -> Result<MergeSummary, String> {
+    let db = database(palette)?;
+    db.merge_database(&other_path, policy).map_err(|e| e.to_string())
+}
+
+/// Edit the descriptive metadata of a sound (`None` leaves a field unchanged)
+pub fn update_sound_metadata(
+    palette: i64,
+    sound_id: i64,
+    title: Option<String>,
+    artist: Option<String>,
+    notes: Option<String>,
+    source: Option<String>,
+) -> Result<(), String> {
+    let db = database(palette)?;
+    let update = SoundMetadataUpdate { title, artist, notes, source };
+    db.update_sound_metadata(sound_id, &update).map_err(|e| e.to_string())
+}
+
+/// Add a user tag to a sound
+pub fn add_tag(palette: i64, sound_id: i64, tag: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.add_tag(sound_id, &tag).map_err(|e| e.to_string())
+}
+
+/// Remove a tag from a sound
+pub fn remove_tag(palette: i64, sound_id: i64, tag: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.remove_tag(sound_id, &tag).map_err(|e| e.to_string())
+}
+
+/// Get the tags of a sound (user tags and predicted labels)
+pub fn get_tags(palette: i64, sound_id: i64) -> Result<Vec<SoundTag>, String> {
+    let db = database(palette)?;
+    db.get_sound_tags(sound_id).map_err(|e| e.to_string())
+}
+
+/// List all tags with usage counts
+pub fn get_all_tags(palette: i64) -> Result<Vec<TagSummary>, String> {
+    let db = database(palette)?;
+    db.get_all_tags().map_err(|e| e.to_string())
+}
+
+/// Rename a tag on every sound
+pub fn rename_tag(palette: i64, old_name: String, new_name: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.rename_tag(&old_name, &new_name).map_err(|e| e.to_string())
+}
+
+/// Delete a tag from the library
+pub fn delete_tag(palette: i64, name: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.delete_tag(&name).map_err(|e| e.to_string())
+}
+
+/// Find sounds with any (or all, if `match_all`) of the given tags
+pub fn find_by_tags(palette: i64, tags: Vec<String>, match_all: bool, min_confidence: f64) -> Result<Vec<SoundRecord>, String> {
+    let db = database(palette)?;
+    let sounds = db.find_by_tags(&tags, match_all, min_confidence).map_err(|e| e.to_string())?;
+    track_usage(palette, sounds.iter().map(|s| s.id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "tags": tags, "match_all": match_all, "min_confidence": min_confidence });
+    track_search(palette, SearchKind::Tags, &tags.join(", "), parameters, sounds.len());
+    Ok(sounds)
+}
+
+/// Recent searches, newest first, with the parameters to re-run them
+///
+/// Selection-based searches (`find_similar_from_samples`) have no query
+/// file to re-run and are not recorded.
+pub fn get_recent_searches(palette: i64, limit: i64) -> Result<Vec<RecentSearch>, String> {
+    let db = database(palette)?;
+    db.get_recent_searches(limit).map_err(|e| e.to_string())
+}
+
+/// Remove one entry from the recent searches
+pub fn delete_recent_search(palette: i64, search_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.delete_recent_search(search_id).map_err(|e| e.to_string())
+}
+
+/// Forget all recent searches
+pub fn clear_search_history(palette: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.clear_search_history().map_err(|e| e.to_string())
+}
+
+/// Recent changes (adds, removals, tag and metadata edits), newest first
+pub fn get_history(palette: i64, limit: i64) -> Result<Vec<HistoryEntry>, String> {
+    let db = database(palette)?;
+    db.get_history(limit).map_err(|e| e.to_string())
+}
+
+/// Reverse a change from the history
+pub fn undo_change(palette: i64, entry_id: i64) -> Result<HistoryEntry, String> {
+    let db = database(palette)?;
+    db.undo(entry_id).map_err(|e| e.to_string())
+}
+
+/// Reverse the most recent change not yet undone (None if there is none)
+pub fn undo_last_change(palette: i64) -> Result<Option<HistoryEntry>, String> {
+    let db = database(palette)?;
+    db.undo_last().map_err(|e| e.to_string())
+}
+
+/// Attach a custom property to a sound, replacing any previous value
+pub fn set_sound_property(palette: i64, sound_id: i64, key: String, value: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.set_property(sound_id, &key, &value).map_err(|e| e.to_string())
+}
+
+/// Get one custom property of a sound
+pub fn get_sound_property(palette: i64, sound_id: i64, key: String) -> Result<Option<String>, String> {
+    let db = database(palette)?;
+    db.get_property(sound_id, &key).map_err(|e| e.to_string())
+}
+
+/// Get all custom properties of a sound, sorted by key
+pub fn get_sound_properties(palette: i64, sound_id: i64) -> Result<Vec<SoundProperty>, String> {
+    let db = database(palette)?;
+    db.get_properties(sound_id).map_err(|e| e.to_string())
+}
+
+/// Remove a custom property from a sound
+pub fn remove_sound_property(palette: i64, sound_id: i64, key: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.remove_property(sound_id, &key).map_err(|e| e.to_string())
+}
+
+/// Find sounds by a custom property value (e.g. an external id)
+pub fn find_by_property(palette: i64, key: String, value: String) -> Result<Vec<SoundRecord>, String> {
+    let db = database(palette)?;
+    db.find_by_property(&key, &value).map_err(|e| e.to_string())
+}
+
+/// Create an empty collection, returning its id
+pub fn create_collection(palette: i64, name: String, description: String) -> Result<i64, String> {
+    let db = database(palette)?;
+    db.create_collection(&name, &description).map_err(|e| e.to_string())
+}
+
+/// Rename a collection and change its description
+pub fn update_collection(palette: i64, collection_id: i64, name: String, description: String) -> Result<(), String> {
+    let db = database(palette)?;
+    db.update_collection(collection_id, &name, &description).map_err(|e| e.to_string())
+}
+
+/// Delete a collection (its sounds stay in the library)
+pub fn delete_collection(palette: i64, collection_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.delete_collection(collection_id).map_err(|e| e.to_string())
+}
+
+/// List all collections with their item counts
+pub fn get_collections(palette: i64) -> Result<Vec<Collection>, String> {
+    let db = database(palette)?;
+    db.get_collections().map_err(|e| e.to_string())
+}
+
+/// Append whole sounds to a collection, returning the new item ids
+pub fn add_sounds_to_collection(palette: i64, collection_id: i64, sound_ids: Vec<i64>) -> Result<Vec<i64>, String> {
+    let db = database(palette)?;
+    let entries: Vec<CollectionEntry> = sound_ids.into_iter().map(CollectionEntry::sound).collect();
+    db.add_to_collection(collection_id, &entries).map_err(|e| e.to_string())
+}
+
+/// Append the matched segments of search results to a collection,
+/// returning the new item ids
+pub fn add_matches_to_collection(palette: i64, collection_id: i64, matches: Vec<MatchResult>) -> Result<Vec<i64>, String> {
+    let db = database(palette)?;
+    let entries: Vec<CollectionEntry> = matches.iter().map(CollectionEntry::from).collect();
+    db.add_to_collection(collection_id, &entries).map_err(|e| e.to_string())
+}
+
+/// Remove an item from its collection
+pub fn remove_from_collection(palette: i64, item_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.remove_from_collection(item_id).map_err(|e| e.to_string())
+}
+
+/// Move a collection item to a new index
+pub fn move_collection_item(palette: i64, item_id: i64, position: usize) -> Result<(), String> {
+    let db = database(palette)?;
+    db.move_collection_item(item_id, position).map_err(|e| e.to_string())
+}
+
+/// Put all items of a collection in the given order
+pub fn reorder_collection(palette: i64, collection_id: i64, item_ids: Vec<i64>) -> Result<(), String> {
+    let db = database(palette)?;
+    db.reorder_collection(collection_id, &item_ids).map_err(|e| e.to_string())
+}
+
+/// Get the items of a collection in order
+pub fn get_collection_items(palette: i64, collection_id: i64) -> Result<Vec<CollectionItem>, String> {
+    let db = database(palette)?;
+    db.get_collection_items(collection_id).map_err(|e| e.to_string())
+}
+
+/// Fingerprint the reference file of a smart query, if it has one
+fn smart_reference(query: &SmartQuery) -> Result<Option<AudioFingerprint>, String> {
+    query
+        .reference_path
+        .as_deref()
+        .map(|path| SearchEngine::new().fingerprint_file(path))
+        .transpose()
+        .map_err(|e| e.to_string())
+}
+
+/// Save a smart collection (a query evaluated whenever it is opened),
+/// returning its id
+///
+/// The reference file, if any, is fingerprinted now; it need not exist
+/// later.
+pub fn create_smart_collection(palette: i64, name: String, query: SmartQuery) -> Result<i64, String> {
+    let db = database(palette)?;
+    let reference = smart_reference(&query)?;
+    db.create_smart_collection(&name, &query, reference.as_ref())
+        .map_err(|e| e.to_string())
+}
+
+/// Rename a smart collection or change its query
+///
+/// An unchanged reference path keeps the stored fingerprint.
+pub fn update_smart_collection(palette: i64, collection_id: i64, name: String, query: SmartQuery) -> Result<(), String> {
+    let db = database(palette)?;
+    let (current, stored) = db
+        .get_smart_collection(collection_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Smart collection {} not found", collection_id))?;
+    let reference = if current.query.reference_path == query.reference_path {
+        stored
+    } else {
+        smart_reference(&query)?
+    };
+    db.update_smart_collection(collection_id, &name, &query, reference.as_ref())
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a smart collection
+pub fn delete_smart_collection(palette: i64, collection_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.delete_smart_collection(collection_id).map_err(|e| e.to_string())
+}
+
+/// List all smart collections
+pub fn get_smart_collections(palette: i64) -> Result<Vec<SmartCollection>, String> {
+    let db = database(palette)?;
+    db.get_smart_collections().map_err(|e| e.to_string())
+}
+
+/// Evaluate a smart collection against the palette as it is now
+pub fn evaluate_smart_collection(palette: i64, collection_id: i64) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+    let (collection, reference) = db
+        .get_smart_collection(collection_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Smart collection {} not found", collection_id))?;
+    let matches = SearchEngine::new()
+        .evaluate_smart_query(&collection.query, reference.as_ref(), &db)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    Ok(matches)
+}
+
+/// Mark a region of a sound (times in seconds), returning its id
+pub fn add_region(
+    palette: i64,
+    sound_id: i64,
+    start_time: f64,
+    end_time: f64,
+    label: String,
+    color: String,
+) -> Result<i64, String> {
+    let db = database(palette)?;
+    db.add_region(sound_id, start_time, end_time, &label, &color).map_err(|e| e.to_string())
+}
+
+/// Move, relabel or recolour a region
+pub fn update_region(
+    palette: i64,
+    region_id: i64,
+    start_time: f64,
+    end_time: f64,
+    label: String,
+    color: String,
+) -> Result<(), String> {
+    let db = database(palette)?;
+    db.update_region(region_id, start_time, end_time, &label, &color).map_err(|e| e.to_string())
+}
+
+/// Delete a region
+pub fn delete_region(palette: i64, region_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
+    db.delete_region(region_id).map_err(|e| e.to_string())
+}
+
+/// Get the regions of a sound in time order
+pub fn get_regions(palette: i64, sound_id: i64) -> Result<Vec<Region>, String> {
+    let db = database(palette)?;
+    db.get_regions(sound_id).map_err(|e| e.to_string())
+}
+
+/// Regions as match results, to pass to the MIDI/CSV/marker exports
+pub fn get_region_matches(palette: i64, region_ids: Vec<i64>) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+    db.get_region_matches(&region_ids).map_err(|e| e.to_string())
+}
+
+/// Search engine that `cancel_searches` can stop
+fn search_engine(palette: i64, config: SearchConfig) -> Result<SearchEngine, String> {
+    let cancel = self::palette(palette)?.search_cancel.lock().unwrap().clone();
+    Ok(SearchEngine::with_config(config).with_cancellation(cancel))
+}
+
+/// Abort every similarity search running on the palette; they fail with
+/// "Operation cancelled" (streams end with that error). Searches started
+/// afterwards run normally.
+#[flutter_rust_bridge::frb(sync)]
+pub fn cancel_searches(palette: i64) {
+    if let Ok(palette) = self::palette(palette) {
+        let mut cancel = palette.search_cancel.lock().unwrap();
+        cancel.cancel();
+        *cancel = CancellationToken::new();
+    }
 }
 
 /// Find similar sounds to a query file
-pub fn find_similar(query_path: String, threshold: f64, max_results: usize) -> Result<Vec<MatchResult>, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
+///
+/// Repeating a search (or asking for fewer results) while the library is
+/// unchanged is answered from the palette's query cache.
+pub fn find_similar(palette: i64, query_path: String, threshold: f64, max_results: usize) -> Result<Vec<MatchResult>, String> {
+    let matches = cached_similar(palette, &query_path, threshold, max_results)?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::Similar, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// One page (`offset`, `limit`) of `find_similar`'s ranking; pages of an
+/// unchanged library come from the query cache where possible
+pub fn find_similar_page(
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<MatchResult>, String> {
+    let ranked = cached_similar(palette, &query_path, threshold, offset.saturating_add(limit))?;
+    let page: Vec<MatchResult> = ranked.into_iter().skip(offset).collect();
+    track_usage(palette, page.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    Ok(page)
+}
+
+/// Top `max_results` similar sounds, through the palette's query cache
+fn cached_similar(palette: i64, query_path: &str, threshold: f64, max_results: usize) -> Result<Vec<MatchResult>, String> {
+    let state = self::palette(palette)?;
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query_fp = state.query_cache.fingerprint(&engine, query_path).map_err(|e| e.to_string())?;
+    let key = QueryCache::key("similar", &query_fp.to_bytes(), &threshold.to_string());
+    state
+        .query_cache
+        .results(&state.db, key, max_results, || engine.find_similar(&query_fp, &state.db, threshold, max_results))
+        .map_err(|e| e.to_string())
+}
 
-    let engine = SearchEngine::new();
+/// Find sounds similar to one already in the palette ("more like this"),
+/// using its stored fingerprint instead of decoding the file again
+pub fn find_similar_by_id(palette: i64, sound_id: i64, threshold: f64, max_results: usize) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let matches = engine
+        .find_similar_to_sound(sound_id, &db, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::SimilarToSound, &sound_id.to_string(), parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find sounds that literally contain the query file's audio (same
+/// recording, possibly at another level), with the sample-accurate offset
+/// and correlation of each occurrence
+///
+/// Decodes every sound at least as long as the query, so it is much slower
+/// than a similarity search. `min_correlation` of 0.9 suits most uses.
+pub fn find_containing(
+    palette: i64,
+    query_path: String,
+    min_correlation: f64,
+    max_results: usize,
+) -> Result<Vec<ContainmentMatch>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_containing(&query, &db, min_correlation, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "min_correlation": min_correlation, "max_results": max_results });
+    track_search(palette, SearchKind::Containment, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find shorter sounds (loops) that repeat through a long query file,
+/// e.g. the two-bar loops a phrase was built from, with the share of the
+/// query each one covers
+///
+/// A part of the query matches a loop when it scores at least
+/// `threshold`; loops covering less than `min_coverage` (0-1) are left out.
+pub fn find_loop_sources(
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    min_coverage: f64,
+    max_results: usize,
+) -> Result<Vec<LoopMatch>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_loop_sources(&query, &db, threshold, min_coverage, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({
+        "threshold": threshold,
+        "min_coverage": min_coverage,
+        "max_results": max_results,
+    });
+    track_search(palette, SearchKind::LoopSources, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find sounds containing the melody hummed or sung in the query file
+///
+/// Only sounds with a stored pitch curve (computed at indexing) are
+/// searched; match times span the melody in each result.
+pub fn find_melody(palette: i64, query_path: String, threshold: f64, max_results: usize) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let audio = crate::audio::AudioData::load(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_melody(&audio.samples, audio.sample_rate, &db, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::Melody, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find sounds matching both words and a sound, e.g. "vinyl kick" like
+/// this file, in one ranking
+///
+/// `text_weight` (0-1) is the share of the score given to text relevance
+/// (0.3 is a good default); the rest is audio similarity.
+pub fn find_similar_with_text(
+    palette: i64,
+    query_path: String,
+    text: String,
+    text_weight: f64,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
     let query_fp = engine.fingerprint_file(&query_path).map_err(|e| e.to_string())?;
-    engine.find_similar(&query_fp, db, threshold, max_results).map_err(|e| e.to_string())
+    let matches = engine
+        .find_similar_with_text(&query_fp, &text, &db, text_weight, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({
+        "text": text,
+        "text_weight": text_weight,
+        "threshold": threshold,
+        "max_results": max_results,
+    });
+    track_search(palette, SearchKind::SimilarWithText, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Rank the files of a folder by similarity to the query without adding
+/// them to the library, e.g. to triage a sample pack before indexing it
+///
+/// Only files with one of `extensions` (all supported formats when empty)
+/// are considered. Fingerprints are kept for the session, so another
+/// query against the same folder only decodes files changed since.
+pub fn find_similar_in_directory(
+    palette: i64,
+    query_path: String,
+    directory: String,
+    extensions: Vec<String>,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<FolderMatch>, String> {
+    let state = self::palette(palette)?;
+    let mut options = IndexOptions::default();
+    if !extensions.is_empty() {
+        options.extensions = extensions;
+    }
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query_fp = state.query_cache.fingerprint(&engine, &query_path).map_err(|e| e.to_string())?;
+    let cache = FOLDER_CACHE.get_or_init(FolderCache::default);
+    let matches = engine
+        .find_similar_in_directory(&query_fp, std::path::Path::new(&directory), &options, cache, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    let parameters = serde_json::json!({
+        "directory": directory,
+        "extensions": options.extensions,
+        "threshold": threshold,
+        "max_results": max_results,
+    });
+    track_search(palette, SearchKind::Directory, &query_path, parameters, matches.len());
+    Ok(matches)
 }
 
 /// Find similar sounds with segment matching (returns exact time ranges)
 pub fn find_similar_with_segments(
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let key = QueryCache::file_key("similar_segments", &query_path, &threshold.to_string()).map_err(|e| e.to_string())?;
+    let matches = self::palette(palette)?
+        .query_cache
+        .results(&db, key, max_results, || {
+            let query = engine.query_from_file(&query_path)?;
+            engine.find_similar_with_segments(&query, &db, threshold, max_results)
+        })
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::SimilarSegments, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find the marked regions most similar to a query file
+pub fn find_similar_in_regions(
+    palette: i64,
     query_path: String,
     threshold: f64,
     max_results: usize,
 ) -> Result<Vec<MatchResult>, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
+    let db = database(palette)?;
 
-    let engine = SearchEngine::new();
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_in_regions(&query, &db, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::SimilarInRegions, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find similar sounds among those matching `filter`, e.g. only loops
+/// at 118-126 BPM in a key compatible with A minor
+pub fn find_similar_matching(
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    max_results: usize,
+    filter: SoundQuery,
+) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+    let engine = search_engine(palette, SearchConfig::default())?;
     let query_fp = engine.fingerprint_file(&query_path).map_err(|e| e.to_string())?;
-    engine.find_similar_with_segments(&query_fp, db, threshold, max_results).map_err(|e| e.to_string())
+    let matches = engine
+        .find_similar_matching(&query_fp, &db, &filter, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results, "filter": filter });
+    track_search(palette, SearchKind::SimilarMatching, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Find sounds like every file in `positive_paths` and unlike those in
+/// `negative_paths`, for when one example alone is ambiguous
+///
+/// The example files themselves are left out of the results.
+pub fn find_similar_to_examples(
+    palette: i64,
+    positive_paths: Vec<String>,
+    negative_paths: Vec<String>,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let fingerprint_all = |paths: &[String]| -> Result<Vec<AudioFingerprint>, String> {
+        paths.iter().map(|p| engine.fingerprint_file(p).map_err(|e| e.to_string())).collect()
+    };
+    let query = ExampleQuery::new(fingerprint_all(&positive_paths)?, fingerprint_all(&negative_paths)?);
+    let examples = positive_paths.len() + negative_paths.len();
+    let mut matches = engine
+        .find_similar_to_examples(&query, &db, threshold, max_results.saturating_add(examples))
+        .map_err(|e| e.to_string())?;
+    matches.retain(|m| !positive_paths.contains(&m.filepath) && !negative_paths.contains(&m.filepath));
+    matches.truncate(max_results);
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({
+        "positives": positive_paths,
+        "negatives": negative_paths,
+        "threshold": threshold,
+        "max_results": max_results,
+    });
+    let first = positive_paths.first().map_or("", String::as_str);
+    track_search(palette, SearchKind::SimilarToExamples, first, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Score batch searches on the GPU (for very large libraries), or go
+/// back to the CPU with `enabled` false
+///
+/// Uploads the library's fingerprints once; sounds added later are
+/// searchable straight away and the library is uploaded again once enough
+/// of it changed. Returns whether a GPU is in use: false when none
+/// is usable (or the crate was built without the `gpu` feature), in which
+/// case batches keep scoring on the CPU.
+pub fn enable_gpu_scoring(palette: i64, enabled: bool) -> Result<bool, String> {
+    let state = self::palette(palette)?;
+    let scorer = if enabled {
+        GpuScorer::upload(&state.db)
+            .map_err(|e| log::warn!("GPU scoring unavailable: {}", e))
+            .ok()
+            .map(Arc::new)
+    } else {
+        None
+    };
+    let in_use = scorer.is_some();
+    *state.gpu.lock().unwrap() = scorer;
+    Ok(in_use)
+}
+
+/// The palette's GPU matrix, caught up with the library (re-uploaded if
+/// much of it changed)
+fn gpu_scorer(palette: i64) -> Result<Option<Arc<GpuScorer>>, String> {
+    let state = self::palette(palette)?;
+    let mut gpu = state.gpu.lock().unwrap();
+    let Some(scorer) = gpu.as_ref() else {
+        return Ok(None);
+    };
+    // New sounds are scored on the CPU next to the matrix until there are
+    // enough of them to be worth uploading again
+    scorer.sync(&state.db).map_err(|e| e.to_string())?;
+    if scorer.needs_upload() {
+        *gpu = GpuScorer::upload(&state.db)
+            .map_err(|e| log::warn!("GPU re-upload failed, using the CPU: {}", e))
+            .ok()
+            .map(Arc::new);
+    }
+    Ok(gpu.clone())
+}
+
+/// Find similar sounds for many query files (or ranges of them) at once,
+/// e.g. to match a whole folder against the palette
+///
+/// Results line up with `queries`; a file that can't be decoded reports
+/// its error instead of failing the batch. With `enable_gpu_scoring` on,
+/// candidates are shortlisted on the GPU.
+pub fn find_similar_batch(
+    palette: i64,
+    queries: Vec<BatchQuery>,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<BatchMatches>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig { gpu: gpu_scorer(palette)?, ..SearchConfig::default() })?;
+    let results = engine
+        .find_similar_batch(&queries, &db, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, results.iter().flat_map(|r| r.matches.iter().map(|m| m.sound_id)), UsageEvent::Returned);
+    Ok(results)
+}
+
+/// Notes of a MIDI clip as time regions, by start time, e.g. to preview
+/// what `find_similar_from_midi` will search
+pub fn read_midi_regions(midi_path: String) -> Result<Vec<MidiRegion>, String> {
+    crate::midi::read_midi_regions(&midi_path).map_err(|e| e.to_string())
+}
+
+/// Search each region of `audio_path` marked by a note of a MIDI clip
+/// drawn against it in a DAW
+///
+/// Results line up with `read_midi_regions`, as in `find_similar_batch`.
+pub fn find_similar_from_midi(
+    palette: i64,
+    midi_path: String,
+    audio_path: String,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<BatchMatches>, String> {
+    let queries = crate::midi::read_midi_regions(&midi_path)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|region| BatchQuery {
+            path: audio_path.clone(),
+            start_time: Some(region.start_time),
+            end_time: Some(region.end_time),
+        })
+        .collect();
+    find_similar_batch(palette, queries, threshold, max_results)
+}
+
+/// Search options for Flutter
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    pub threshold: f64,
+    pub max_results: usize,
+    /// Rerank segment matches with DTW over frame-level MFCCs
+    pub dtw_rerank: bool,
+    /// Blend weight of the DTW score (0-1)
+    pub dtw_weight: f64,
+    /// Warp-align segment matches to the query so slower or faster
+    /// performances still match, reporting the warped boundaries
+    pub dtw_align: bool,
+    /// Refine match offsets to sample accuracy via cross-correlation
+    pub refine_offsets: bool,
+    /// Return every non-overlapping match above the threshold in each file
+    /// (e.g. all three hits in a stem containing the query three times)
+    /// instead of only the best one
+    pub all_segments: bool,
+    /// Spread results over different-sounding options rather than near
+    /// copies of the best match (0 disables, up to 1)
+    pub diversity: f64,
+    /// Feature groups to ignore, e.g. everything but timbre to match
+    /// regardless of pitch
+    pub ignored_features: Vec<FeatureGroup>,
+    /// Score points subtracted per dB of SNR below the noisy threshold (0 disables)
+    pub snr_penalty: f64,
+    /// Scores (and the threshold) as the percentage of the library the
+    /// query is less similar to, so 80 means "better than 80% of it"
+    pub calibrate_scores: bool,
+    /// Library sounds whose family to leave out ("not like these"), e.g.
+    /// ones the user already rejected
+    pub excluded_sounds: Vec<i64>,
+    /// Similarity (0-100) to an excluded sound at which results are left
+    /// out (85 is a good default)
+    pub exclusion_threshold: f64,
+    /// Only score the sounds a quick hash comparison says are close:
+    /// much faster on large libraries, occasionally misses a match
+    pub lsh_prefilter: bool,
+    /// Most decoded audio a segment search holds at once, in MB (0 for
+    /// the default of 256); lower it on devices short of memory
+    pub memory_budget_mb: usize,
+    /// Sliding-window length in seconds when scanning files without
+    /// stored segments (0 uses the query's length)
+    pub segment_window_s: f64,
+    /// Overlap of consecutive windows, 0 to 0.95 (0.75 is the usual):
+    /// more gives finer match positions but takes longer
+    pub segment_overlap: f64,
+    /// Most windows per file before the hop widens (50 is the usual, 0
+    /// for no cap); results report the resolution each match got
+    pub max_segment_windows: usize,
+}
+
+impl SearchOptions {
+    fn to_config(&self, db: &PaletteDatabase) -> Result<SearchConfig, String> {
+        let excluded = self
+            .excluded_sounds
+            .iter()
+            .filter_map(|&id| db.get_fingerprint(id).transpose())
+            .collect::<crate::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())?;
+        Ok(SearchConfig {
+            dtw_rerank: self.dtw_rerank,
+            dtw_weight: self.dtw_weight,
+            dtw_align: self.dtw_align,
+            refine_offsets: self.refine_offsets,
+            all_segments: self.all_segments,
+            diversity: self.diversity,
+            feature_mask: FeatureMask::ignoring(&self.ignored_features),
+            snr_penalty: self.snr_penalty,
+            calibrate_scores: self.calibrate_scores,
+            excluded,
+            exclusion_threshold: self.exclusion_threshold,
+            lsh_prefilter: self.lsh_prefilter,
+            memory_budget: match self.memory_budget_mb {
+                0 => DEFAULT_MEMORY_BUDGET,
+                mb => mb << 20,
+            },
+            segment_window: self.segment_window_s,
+            segment_overlap: self.segment_overlap,
+            max_segment_windows: self.max_segment_windows,
+            ..SearchConfig::default()
+        })
+    }
+}
+
+/// Search options tuned for a kind of material (drum one-shots, melodic
+/// loops, ambience, dialogue), to pass to `find_similar_with_options` as
+/// they are or adjusted
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_preset_options(preset: SearchPreset, max_results: usize) -> SearchOptions {
+    let config = preset.config();
+    SearchOptions {
+        threshold: preset.threshold(),
+        max_results,
+        dtw_rerank: config.dtw_rerank,
+        dtw_weight: config.dtw_weight,
+        dtw_align: config.dtw_align,
+        refine_offsets: config.refine_offsets,
+        all_segments: config.all_segments,
+        diversity: config.diversity,
+        ignored_features: config.feature_mask.ignored,
+        snr_penalty: config.snr_penalty,
+        calibrate_scores: config.calibrate_scores,
+        excluded_sounds: Vec::new(),
+        exclusion_threshold: config.exclusion_threshold,
+        lsh_prefilter: config.lsh_prefilter,
+        memory_budget_mb: 0,
+        segment_window_s: config.segment_window,
+        segment_overlap: config.segment_overlap,
+        max_segment_windows: config.max_segment_windows,
+    }
+}
+
+/// Find similar sounds with segment matching using explicit search options
+pub fn find_similar_with_options(palette: i64, query_path: String, options: SearchOptions) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, options.to_config(&db)?)?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_with_segments(&query, &db, options.threshold, options.max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({
+        "threshold": options.threshold,
+        "max_results": options.max_results,
+        "dtw_rerank": options.dtw_rerank,
+        "dtw_weight": options.dtw_weight,
+        "dtw_align": options.dtw_align,
+        "refine_offsets": options.refine_offsets,
+        "all_segments": options.all_segments,
+        "diversity": options.diversity,
+        "ignored_features": options.ignored_features.iter().map(|g| g.name()).collect::<Vec<_>>(),
+        "snr_penalty": options.snr_penalty,
+        "calibrate_scores": options.calibrate_scores,
+        "excluded_sounds": options.excluded_sounds,
+        "exclusion_threshold": options.exclusion_threshold,
+        "lsh_prefilter": options.lsh_prefilter,
+        "segment_window_s": options.segment_window_s,
+        "segment_overlap": options.segment_overlap,
+        "max_segment_windows": options.max_segment_windows,
+    });
+    track_search(palette, SearchKind::SimilarWithOptions, &query_path, parameters, matches.len());
+    Ok(matches)
+}
+
+/// Forward best-so-far results to a Dart stream; once Dart stops
+/// listening, updates are dropped and the search runs to completion
+fn send_updates(sink: &StreamSink<Vec<MatchResult>>) -> impl Fn(&[MatchResult]) + Sync + '_ {
+    move |best| {
+        let _ = sink.add(best.to_vec());
+    }
+}
+
+/// `find_similar`, streaming results as they are found
+///
+/// Each event is the best results so far, best first; the last event is
+/// the final list (empty if nothing matched).
+pub fn find_similar_stream(
+    sink: StreamSink<Vec<MatchResult>>,
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    max_results: usize,
+) -> Result<(), String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query_fp = engine.fingerprint_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_streaming(&query_fp, &db, threshold, max_results, send_updates(&sink))
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::Similar, &query_path, parameters, matches.len());
+    let _ = sink.add(matches);
+    Ok(())
+}
+
+/// A running live query and where its matches go
+struct LiveSession {
+    palette: i64,
+    threshold: f64,
+    max_results: usize,
+    query: Mutex<LiveQuery>,
+    /// Held while a window is being searched; windows due meanwhile are skipped
+    searching: Mutex<()>,
+    sink: StreamSink<Vec<MatchResult>>,
+}
+
+/// Live queries by handle
+static LIVE_SESSIONS: std::sync::OnceLock<Mutex<HashMap<i64, Arc<LiveSession>>>> = std::sync::OnceLock::new();
+
+/// Handle given to the next live query
+static NEXT_LIVE_SESSION: AtomicI64 = AtomicI64::new(1);
+
+fn live_sessions() -> &'static Mutex<HashMap<i64, Arc<LiveSession>>> {
+    LIVE_SESSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Start matching live audio (e.g. from the microphone) against the
+/// palette; returns the handle `push_live_audio` and `stop_live_query` take
+///
+/// The last `window_seconds` of audio are searched each time another
+/// second has been pushed, and each search's best matches are sent to
+/// the stream. Silence isn't searched, so the last matches stand.
+pub fn start_live_query(
+    sink: StreamSink<Vec<MatchResult>>,
+    palette: i64,
+    sample_rate: u32,
+    window_seconds: f64,
+    threshold: f64,
+    max_results: usize,
+) -> Result<i64, String> {
+    self::palette(palette)?;
+    let session = LiveSession {
+        palette,
+        threshold,
+        max_results,
+        query: Mutex::new(LiveQuery::new(sample_rate, window_seconds)),
+        searching: Mutex::new(()),
+        sink,
+    };
+    let handle = NEXT_LIVE_SESSION.fetch_add(1, Ordering::Relaxed);
+    live_sessions().lock().unwrap().insert(handle, Arc::new(session));
+    Ok(handle)
+}
+
+/// Add recorded mono samples to a live query, searching its window when due
+///
+/// If the previous search is still running, the window is skipped rather
+/// than queued, so matches never lag behind the audio.
+pub fn push_live_audio(session: i64, samples: Vec<f32>) -> Result<(), String> {
+    let session = live_sessions()
+        .lock()
+        .unwrap()
+        .get(&session)
+        .cloned()
+        .ok_or_else(|| format!("Live query {} is not running", session))?;
+    let (window, sample_rate) = {
+        let mut query = session.query.lock().unwrap();
+        (query.push(&samples), query.sample_rate())
+    };
+    let Some(window) = window else {
+        return Ok(());
+    };
+    let Ok(_searching) = session.searching.try_lock() else {
+        return Ok(());
+    };
+
+    let db = database(session.palette)?;
+    let engine = search_engine(session.palette, SearchConfig::default())?;
+    let matches = engine
+        .find_similar_live(&window, sample_rate, &db, session.threshold, session.max_results)
+        .map_err(|e| e.to_string())?;
+    let _ = session.sink.add(matches);
+    Ok(())
+}
+
+/// End a live query; its stream receives no further matches
+#[flutter_rust_bridge::frb(sync)]
+pub fn stop_live_query(session: i64) {
+    live_sessions().lock().unwrap().remove(&session);
+}
+
+/// `find_similar_with_segments`, streaming results as each candidate is
+/// scored (events as in `find_similar_stream`)
+pub fn find_similar_with_segments_stream(
+    sink: StreamSink<Vec<MatchResult>>,
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    max_results: usize,
+) -> Result<(), String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_with_segments_streaming(&query, &db, threshold, max_results, send_updates(&sink))
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::SimilarSegments, &query_path, parameters, matches.len());
+    let _ = sink.add(matches);
+    Ok(())
+}
+
+/// `find_similar_in_regions`, streaming results as each region is scored
+/// (events as in `find_similar_stream`)
+pub fn find_similar_in_regions_stream(
+    sink: StreamSink<Vec<MatchResult>>,
+    palette: i64,
+    query_path: String,
+    threshold: f64,
+    max_results: usize,
+) -> Result<(), String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_in_regions_streaming(&query, &db, threshold, max_results, send_updates(&sink))
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({ "threshold": threshold, "max_results": max_results });
+    track_search(palette, SearchKind::SimilarInRegions, &query_path, parameters, matches.len());
+    let _ = sink.add(matches);
+    Ok(())
+}
+
+/// `find_similar_with_options`, streaming results as each candidate is
+/// scored (events as in `find_similar_stream`)
+pub fn find_similar_with_options_stream(
+    sink: StreamSink<Vec<MatchResult>>,
+    palette: i64,
+    query_path: String,
+    options: SearchOptions,
+) -> Result<(), String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, options.to_config(&db)?)?;
+    let query = engine.query_from_file(&query_path).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_with_segments_streaming(&query, &db, options.threshold, options.max_results, send_updates(&sink))
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({
+        "threshold": options.threshold,
+        "max_results": options.max_results,
+        "dtw_rerank": options.dtw_rerank,
+        "dtw_weight": options.dtw_weight,
+        "dtw_align": options.dtw_align,
+        "refine_offsets": options.refine_offsets,
+        "all_segments": options.all_segments,
+        "diversity": options.diversity,
+        "ignored_features": options.ignored_features.iter().map(|g| g.name()).collect::<Vec<_>>(),
+        "snr_penalty": options.snr_penalty,
+        "calibrate_scores": options.calibrate_scores,
+        "excluded_sounds": options.excluded_sounds,
+        "exclusion_threshold": options.exclusion_threshold,
+        "lsh_prefilter": options.lsh_prefilter,
+        "segment_window_s": options.segment_window_s,
+        "segment_overlap": options.segment_overlap,
+        "max_segment_windows": options.max_segment_windows,
+    });
+    track_search(palette, SearchKind::SimilarWithOptions, &query_path, parameters, matches.len());
+    let _ = sink.add(matches);
+    Ok(())
 }
 
 /// Find similar sounds from audio samples (for selection-based search)
 pub fn find_similar_from_samples(
+    palette: i64,
     samples: Vec<f32>,
     sample_rate: u32,
     threshold: f64,
     max_results: usize,
 ) -> Result<Vec<MatchResult>, String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
+    let db = database(palette)?;
 
-    let engine = SearchEngine::new();
-    let query_fp = engine.fingerprint_samples(&samples, sample_rate).map_err(|e| e.to_string())?;
-    engine.find_similar_with_segments(&query_fp, db, threshold, max_results).map_err(|e| e.to_string())
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let query = engine.query_from_samples(&samples, sample_rate).map_err(|e| e.to_string())?;
+    let matches = engine
+        .find_similar_with_segments(&query, &db, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    Ok(matches)
 }
 
-/// Export match results to MIDI file
+/// Find sounds similar to a time selection of a stored sound, decoding
+/// just that range here rather than passing its samples over the bridge
+///
+/// Matches overlapping the selection itself are left out.
+pub fn find_similar_from_region(
+    palette: i64,
+    sound_id: i64,
+    start_s: f64,
+    end_s: f64,
+    threshold: f64,
+    max_results: usize,
+) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+
+    let engine = search_engine(palette, SearchConfig::default())?;
+    let matches = engine
+        .find_similar_from_region(sound_id, start_s, end_s, &db, threshold, max_results)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Returned);
+    let parameters = serde_json::json!({
+        "start_s": start_s,
+        "end_s": end_s,
+        "threshold": threshold,
+        "max_results": max_results,
+    });
+    track_search(palette, SearchKind::SimilarToRegion, &sound_id.to_string(), parameters, matches.len());
+    Ok(matches)
+}
+
+/// Export match results to MIDI file, one track per match named after its
+/// file, with the sound's classified instrument as the instrument name
+///
+/// Note starts and lengths snap to a grid of `quantize_note_value` notes
+/// (4 for quarters through 32 for thirty-seconds; 0 keeps the timing of
+/// the matches), every second grid line pushed later by `swing` of a step.
+/// With `follow_detected_tempo`, the tempo track follows the beats
+/// detected in the first match's sound instead of `tempo_bpm`, for matches
+/// that are regions of one file. `note_mapping` picks each track's channel,
+/// program and note from its instrument (and detected pitch) rather than
+/// `base_note` plus the match's index.
+#[allow(clippy::too_many_arguments)]
 pub fn export_to_midi(
+    palette: i64,
     matches: Vec<MatchResult>,
     output_path: String,
     tempo_bpm: u32,
     base_note: u8,
+    quantize_note_value: u16,
+    swing: f64,
+    follow_detected_tempo: bool,
+    note_mapping: MidiNoteMapping,
 ) -> Result<(), String> {
+    let beats = match matches.first() {
+        Some(m) if follow_detected_tempo => detected_beats(palette, m.sound_id, 0.0)?,
+        _ => Vec::new(),
+    };
+    let db = database(palette)?;
+    let instruments = matches
+        .iter()
+        .map(|m| {
+            let tags = db.get_sound_tags(m.sound_id)?;
+            // Most confident first
+            Ok(tags.into_iter().find(|t| t.source == CLASSIFIER_TAG_SOURCE).map(|t| t.name))
+        })
+        .collect::<crate::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())?;
+    let classes: Vec<_> =
+        instruments.iter().map(|name| name.as_deref().and_then(InstrumentClass::from_tag_name)).collect();
+    let pitches = match note_mapping {
+        MidiNoteMapping::Pitch => matches
+            .iter()
+            .map(|m| {
+                let pitch = get_analysis(palette, m.sound_id, AnalysisKind::Pitch)?;
+                let end = if m.match_end > m.match_start { m.match_end } else { f64::INFINITY };
+                Ok(median_note(&pitch, m.match_start, end))
+            })
+            .collect::<Result<Vec<_>, String>>()?,
+        _ => Vec::new(),
+    };
     let config = MidiExportConfig {
         tempo_bpm,
         base_note,
         ticks_per_beat: 480,
+        quantize: (quantize_note_value > 0).then_some(MidiQuantize { note_value: quantize_note_value, swing }),
+        beats,
+        tracks: assign_tracks(note_mapping, &classes, &pitches),
+    };
+    export_matches_to_midi(&matches, &instruments, &output_path, &config).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export the matches found in one long file (that of the first match) as
+/// a MIDI arrangement: each at its original time on a single timeline,
+/// with a track per label (lining up with `matches`, e.g. the palette
+/// sound each was found for); returns how many were placed
+///
+/// With `follow_detected_tempo` the tempo track follows the beats detected
+/// in that file, so the map lines up with the source in a DAW.
+pub fn export_arrangement_to_midi(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    labels: Vec<String>,
+    output_path: String,
+    tempo_bpm: u32,
+    base_note: u8,
+    follow_detected_tempo: bool,
+) -> Result<usize, String> {
+    let beats = match matches.first() {
+        Some(m) if follow_detected_tempo => detected_beats(palette, m.sound_id, 0.0)?,
+        _ => Vec::new(),
+    };
+    let config = MidiExportConfig { tempo_bpm, base_note, beats, ..Default::default() };
+    let placed =
+        export_matches_to_arrangement(&matches, &labels, &output_path, &config).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.first().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(placed)
+}
+
+/// Detected beats of a sound in seconds from `offset`, for a tempo track
+/// that follows them
+fn detected_beats(palette: i64, sound_id: i64, offset: f64) -> Result<Vec<f64>, String> {
+    let beats = get_analysis(palette, sound_id, AnalysisKind::Beats)?;
+    Ok(beats.values.into_iter().filter(|&t| t >= offset).map(|t| t - offset).collect())
+}
+
+/// Melody notes of a match (or whole sound), times relative to its start,
+/// and that start
+fn match_melody(palette: i64, result: &MatchResult) -> Result<(Vec<TranscribedNote>, f64), String> {
+    let pitch = get_analysis(palette, result.sound_id, AnalysisKind::Pitch)?;
+    let onsets = get_analysis(palette, result.sound_id, AnalysisKind::Onsets)?;
+    let audio = crate::audio::AudioData::load(&result.filepath).map_err(|e| e.to_string())?;
+    let (start, end) = if result.match_end > result.match_start {
+        (result.match_start, result.match_end)
+    } else {
+        (0.0, audio.duration)
+    };
+
+    let notes: Vec<_> = transcribe_melody(&pitch, &onsets.values, &audio.samples, audio.sample_rate)
+        .into_iter()
+        .filter(|n| n.end_time > start && n.start_time < end)
+        .map(|mut n| {
+            n.start_time = n.start_time.max(start) - start;
+            n.end_time = n.end_time.min(end) - start;
+            n
+        })
+        .collect();
+    Ok((notes, start))
+}
+
+/// Transcribe the melody of a match (e.g. a found vocal or bass phrase)
+/// to a MIDI file, notes snapped to `quantize_division` steps per beat at
+/// `tempo_bpm` (0 leaves the timing as played), returning the note count
+///
+/// Times are relative to the start of the match. With
+/// `follow_detected_tempo` the tempo track follows the sound's detected
+/// beats instead of `tempo_bpm`, so the grid matches the performance.
+pub fn export_melody_to_midi(
+    palette: i64,
+    result: MatchResult,
+    output_path: String,
+    tempo_bpm: u32,
+    quantize_division: u16,
+    follow_detected_tempo: bool,
+) -> Result<usize, String> {
+    let (notes, start) = match_melody(palette, &result)?;
+    let beats = if follow_detected_tempo { detected_beats(palette, result.sound_id, start)? } else { Vec::new() };
+    let ticks_per_beat = MidiExportConfig::default().ticks_per_beat;
+    export_notes_to_midi(&notes, &result.filename, &output_path, tempo_bpm, &beats, ticks_per_beat, quantize_division)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, [result.sound_id], UsageEvent::Exported);
+    Ok(notes.len())
+}
+
+/// Transcribe the melody of a match to a MusicXML score for notation
+/// software, returning the note count
+///
+/// A `tempo_bpm` of 0 uses the sound's detected tempo (or 120 BPM when
+/// unknown); the key signature comes from its detected key.
+pub fn export_melody_to_musicxml(
+    palette: i64,
+    result: MatchResult,
+    output_path: String,
+    tempo_bpm: u32,
+) -> Result<usize, String> {
+    let sound = database(palette)?.get_sound(result.sound_id).map_err(|e| e.to_string())?;
+    let (notes, _) = match_melody(palette, &result)?;
+    let detected_bpm = sound.as_ref().map(|s| s.bpm.round() as u32).filter(|&bpm| bpm > 0);
+    let tempo_bpm = if tempo_bpm > 0 { tempo_bpm } else { detected_bpm.unwrap_or(120) };
+    let key = sound.as_ref().map(|s| s.musical_key.as_str()).unwrap_or_default();
+    export_notes_to_musicxml(&notes, &result.filename, key, &output_path, tempo_bpm).map_err(|e| e.to_string())?;
+    track_usage(palette, [result.sound_id], UsageEvent::Exported);
+    Ok(notes.len())
+}
+
+/// Export the onsets of each match (or whole sound) as General MIDI drum
+/// hits, one track per match, for a groove template from a sampled break;
+/// returns the number of hits
+///
+/// Hits are classified as kick, snare or hi-hat, snapped to
+/// `quantize_division` steps per beat at `tempo_bpm` (0 leaves the timing
+/// as played) and timed from the start of their match. With
+/// `follow_detected_tempo` the tempo track follows the beats detected in
+/// the first match instead of `tempo_bpm`.
+pub fn export_drums_to_midi(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    tempo_bpm: u32,
+    quantize_division: u16,
+    follow_detected_tempo: bool,
+) -> Result<usize, String> {
+    let mut tracks = Vec::with_capacity(matches.len());
+    for m in &matches {
+        let onsets = get_analysis(palette, m.sound_id, AnalysisKind::Onsets)?;
+        let (start, end) =
+            if m.match_end > m.match_start { (m.match_start, m.match_end) } else { (0.0, f64::INFINITY) };
+        let onsets: Vec<f64> = onsets.values.into_iter().filter(|&t| t >= start && t < end).collect();
+        let audio = crate::audio::AudioData::load(&m.filepath).map_err(|e| e.to_string())?;
+        let mut hits = detect_drum_hits(&audio.samples, audio.sample_rate, &onsets);
+        for hit in &mut hits {
+            hit.time -= start;
+        }
+        tracks.push((m.filename.clone(), hits));
+    }
+    let beats = match matches.first() {
+        Some(m) if follow_detected_tempo => detected_beats(palette, m.sound_id, m.match_start)?,
+        _ => Vec::new(),
     };
-    export_matches_to_midi(&matches, &output_path, &config).map_err(|e| e.to_string())
+    let ticks_per_beat = MidiExportConfig::default().ticks_per_beat;
+    export_drum_hits_to_midi(&tracks, &output_path, tempo_bpm, &beats, ticks_per_beat, quantize_division)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(tracks.iter().map(|(_, hits)| hits.len()).sum())
+}
+
+/// Export the beats detected in a match (or whole sound) as a MIDI click
+/// track, timed from the start of the match, with a tempo track following
+/// them; returns how many beats were written
+///
+/// Every `beats_per_bar`th beat from `first_downbeat` (counted within the
+/// match) is accented.
+pub fn export_click_track_to_midi(
+    palette: i64,
+    result: MatchResult,
+    output_path: String,
+    beats_per_bar: u8,
+    first_downbeat: u32,
+) -> Result<usize, String> {
+    let end = if result.match_end > result.match_start { result.match_end - result.match_start } else { f64::INFINITY };
+    let beats: Vec<f64> =
+        detected_beats(palette, result.sound_id, result.match_start)?.into_iter().filter(|&t| t < end).collect();
+    let ticks_per_beat = MidiExportConfig::default().ticks_per_beat;
+    let written = export_beat_grid_to_midi(&beats, &output_path, beats_per_bar, first_downbeat as usize, ticks_per_beat)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, [result.sound_id], UsageEvent::Exported);
+    Ok(written)
 }
 
 /// Export match results to CSV file
-pub fn export_to_csv(matches: Vec<MatchResult>, output_path: String) -> Result<(), String> {
-    export_matches_to_csv(&matches, &output_path).map_err(|e| e.to_string())
+pub fn export_to_csv(palette: i64, matches: Vec<MatchResult>, output_path: String) -> Result<(), String> {
+    export_to_csv_with_options(palette, matches, output_path, CsvExportOptions::default())
+}
+
+/// Export match results to CSV with a chosen delimiter, columns and
+/// header row
+pub fn export_to_csv_with_options(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    options: CsvExportOptions,
+) -> Result<(), String> {
+    export_matches_to_csv(&matches, &output_path, &options).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export match results to a JSON file
+///
+/// With `query_path`, each match also gets per-feature scores against the
+/// query (as in `explain_match`); with `include_fingerprints`, the query's
+/// and each matched range's fingerprints are written too. Either means
+/// fingerprinting every match, which takes a while for long lists.
+pub fn export_to_json(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    query_path: Option<String>,
+    include_fingerprints: bool,
+) -> Result<(), String> {
+    let fingerprinter = Fingerprinter::default();
+    let query = query_path
+        .map(|path| fingerprinter.extract_from_file(&path).map_err(|e| e.to_string()))
+        .transpose()?;
+    let candidates = if query.is_some() || include_fingerprints {
+        // A match whose file is gone still exports, just without them
+        matches.iter().map(|m| match_fingerprint(&fingerprinter, m).ok()).collect()
+    } else {
+        Vec::new()
+    };
+    export_matches_to_json(&matches, query.as_ref(), &candidates, include_fingerprints, &output_path)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
 }
 
 /// Export match results to markers file
-pub fn export_to_markers(matches: Vec<MatchResult>, output_path: String) -> Result<(), String> {
-    export_matches_to_markers(&matches, &output_path).map_err(|e| e.to_string())
+pub fn export_to_markers(palette: i64, matches: Vec<MatchResult>, output_path: String) -> Result<(), String> {
+    export_matches_to_markers(&matches, &output_path).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export match results as a REAPER region CSV (Region/Marker Manager
+/// import), regions colored by score
+pub fn export_to_reaper_regions(palette: i64, matches: Vec<MatchResult>, output_path: String) -> Result<(), String> {
+    export_matches_to_reaper_regions(&matches, &output_path).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Sample rate of each match's sound, 0 when unknown
+fn sample_rates(palette: i64, matches: &[MatchResult]) -> Result<Vec<u32>, String> {
+    let db = database(palette)?;
+    matches
+        .iter()
+        .map(|m| Ok(db.get_sound(m.sound_id)?.map_or(0, |sound| sound.sample_rate)))
+        .collect::<crate::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())
+}
+
+/// Export match results as an Ableton Live Set at `tempo_bpm`, each match
+/// an audio clip of its source file on a track of its own
+pub fn export_to_als(palette: i64, matches: Vec<MatchResult>, output_path: String, tempo_bpm: u32) -> Result<(), String> {
+    let sample_rates = sample_rates(palette, &matches)?;
+    export_matches_to_als(&matches, &sample_rates, &output_path, tempo_bpm).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export match results as a DAWproject file at `tempo_bpm`, each match
+/// an audio clip of its source file on a track of its own
+pub fn export_to_dawproject(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    tempo_bpm: u32,
+) -> Result<(), String> {
+    let db = database(palette)?;
+    let formats = matches
+        .iter()
+        .map(|m| Ok(db.get_sound(m.sound_id)?.map_or((0, 0), |sound| (sound.sample_rate, sound.channels))))
+        .collect::<crate::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())?;
+    export_matches_to_dawproject(&matches, &formats, &output_path, tempo_bpm).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export match results as a cue sheet titled `title`, indexing each
+/// match's position in its source file
+pub fn export_to_cue(palette: i64, matches: Vec<MatchResult>, title: String, output_path: String) -> Result<(), String> {
+    export_matches_to_cue(&matches, &title, &output_path).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export match results as a CMX3600 EDL titled `title` at `fps` frames per
+/// second, for conforming found material into a video edit
+pub fn export_to_edl(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    title: String,
+    fps: u32,
+    output_path: String,
+) -> Result<(), String> {
+    export_matches_to_edl(&matches, &title, fps, &output_path).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Export match results as an M3U playlist, with paths relative to the
+/// playlist's folder if `relative_paths`
+pub fn export_to_m3u(palette: i64, matches: Vec<MatchResult>, output_path: String, relative_paths: bool) -> Result<(), String> {
+    export_matches_to_m3u(&matches, &output_path, relative_paths).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// The items of a collection, in order, as whole-score matches of their
+/// sounds or regions
+fn collection_matches(palette: i64, collection_id: i64) -> Result<Vec<MatchResult>, String> {
+    let db = database(palette)?;
+    let matches = db
+        .get_collection_items(collection_id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|item| MatchResult {
+            sound_id: item.sound.id,
+            score: 100.0,
+            match_start: item.start_time.unwrap_or(0.0),
+            match_end: item.end_time.unwrap_or(item.sound.duration),
+            file_duration: item.sound.duration,
+            time_resolution: 0.0,
+            filepath: item.sound.filepath,
+            filename: item.sound.filename,
+        })
+        .collect();
+    Ok(matches)
+}
+
+/// Export a collection, in order, as an M3U playlist
+pub fn export_collection_to_m3u(
+    palette: i64,
+    collection_id: i64,
+    output_path: String,
+    relative_paths: bool,
+) -> Result<(), String> {
+    let matches = collection_matches(palette, collection_id)?;
+    export_to_m3u(palette, matches, output_path, relative_paths)
+}
+
+/// Export match results as an SFZ sampler instrument, each match on
+/// `keys_per_sample` keys from `lowest_note` up, returning how many fit on
+/// the keyboard
+pub fn export_to_sfz(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    lowest_note: u8,
+    keys_per_sample: u8,
+) -> Result<usize, String> {
+    let sample_rates = sample_rates(palette, &matches)?;
+    let mapped = export_matches_to_sfz(&matches, &sample_rates, &output_path, lowest_note, keys_per_sample)
+        .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().take(mapped).map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(mapped)
+}
+
+/// Export match results as a DecentSampler preset, laid out on the
+/// keyboard as for SFZ, returning how many fit
+pub fn export_to_dspreset(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    lowest_note: u8,
+    keys_per_sample: u8,
+    envelope: SamplerEnvelope,
+) -> Result<usize, String> {
+    let sample_rates = sample_rates(palette, &matches)?;
+    let mapped =
+        export_matches_to_dspreset(&matches, &sample_rates, &output_path, lowest_note, keys_per_sample, &envelope)
+            .map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().take(mapped).map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(mapped)
+}
+
+/// Export up to 16 match results as an Akai MPC drum program named
+/// `name`, one per pad, in the classic pad layout or chromatic; returns
+/// how many were placed
+pub fn export_to_xpm(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    output_path: String,
+    name: String,
+    chromatic: bool,
+) -> Result<usize, String> {
+    let sample_rates = sample_rates(palette, &matches)?;
+    let pads =
+        export_matches_to_xpm(&matches, &sample_rates, &output_path, &name, chromatic).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().take(pads).map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(pads)
+}
+
+/// Export a collection of one-shots (or regions), in order, as an SFZ
+/// sampler instrument
+pub fn export_collection_to_sfz(
+    palette: i64,
+    collection_id: i64,
+    output_path: String,
+    lowest_note: u8,
+    keys_per_sample: u8,
+) -> Result<usize, String> {
+    let matches = collection_matches(palette, collection_id)?;
+    export_to_sfz(palette, matches, output_path, lowest_note, keys_per_sample)
+}
+
+/// Export a collection of one-shots (or regions), in order, as a
+/// DecentSampler preset
+pub fn export_collection_to_dspreset(
+    palette: i64,
+    collection_id: i64,
+    output_path: String,
+    lowest_note: u8,
+    keys_per_sample: u8,
+    envelope: SamplerEnvelope,
+) -> Result<usize, String> {
+    let matches = collection_matches(palette, collection_id)?;
+    export_to_dspreset(palette, matches, output_path, lowest_note, keys_per_sample, envelope)
+}
+
+/// Export a collection of one-shots (or regions), in order, as an Akai
+/// MPC drum program
+pub fn export_collection_to_xpm(
+    palette: i64,
+    collection_id: i64,
+    output_path: String,
+    name: String,
+    chromatic: bool,
+) -> Result<usize, String> {
+    let matches = collection_matches(palette, collection_id)?;
+    export_to_xpm(palette, matches, output_path, name, chromatic)
+}
+
+/// Export match results as a zip of WAV clips of their ranges, with JSON
+/// and CSV manifests, returning how many clips were written (matches
+/// whose file can't be decoded are only listed)
+pub fn export_to_bundle(palette: i64, matches: Vec<MatchResult>, output_path: String) -> Result<usize, String> {
+    let written = export_matches_bundle(&matches, &output_path).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(written)
+}
+
+/// Export match results as a self-contained HTML report titled `title`,
+/// with each match's scores, time range, metadata and tags, and (with
+/// `include_images`) its waveform and spectrogram
+///
+/// Images are drawn from the audio, so a report with them takes a moment
+/// per match; printing the page from a browser gives a PDF version.
+pub fn export_report(
+    palette: i64,
+    matches: Vec<MatchResult>,
+    title: String,
+    output_path: String,
+    include_images: bool,
+) -> Result<(), String> {
+    let db = database(palette)?;
+    let entries = matches
+        .iter()
+        .map(|m| {
+            // A tag may come from several sources
+            let mut tags: Vec<String> = Vec::new();
+            for tag in db.get_sound_tags(m.sound_id)? {
+                if !tags.contains(&tag.name) {
+                    tags.push(tag.name);
+                }
+            }
+            Ok(ReportEntry { result: m.clone(), sound: db.get_sound(m.sound_id)?, tags })
+        })
+        .collect::<crate::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())?;
+    export_match_report(&entries, &title, include_images, &output_path).map_err(|e| e.to_string())?;
+    track_usage(palette, matches.iter().map(|m| m.sound_id), UsageEvent::Exported);
+    Ok(())
+}
+
+/// Render a spectrogram of a file (or a time range of it) as PNG bytes
+#[allow(clippy::too_many_arguments)]
+pub fn render_spectrogram_png(
+    filepath: String,
+    start_s: Option<f64>,
+    end_s: Option<f64>,
+    width: u32,
+    height: u32,
+    colormap: Colormap,
+    min_db: f64,
+    max_db: f64,
+) -> Result<Vec<u8>, String> {
+    let config = SpectrogramConfig {
+        width,
+        height,
+        colormap,
+        min_db,
+        max_db,
+        ..SpectrogramConfig::default()
+    };
+    render_file_spectrogram(&filepath, start_s, end_s, &config).map_err(|e| e.to_string())
 }
 
 /// Remove a sound from the database
-pub fn remove_sound(sound_id: i64) -> Result<(), String> {
-    let guard = get_db().lock().unwrap();
-    let db = guard.as_ref().ok_or("Database not initialized")?;
+pub fn remove_sound(palette: i64, sound_id: i64) -> Result<(), String> {
+    let db = database(palette)?;
     db.remove_sound(sound_id).map_err(|e| e.to_string())
 }
 
@@ -171,6 +2328,50 @@ pub struct AudioFingerprintInfo {
     pub mfcc_std: Vec<f64>,
 }
 
+/// Noise estimate for a stored sound
+#[derive(Debug, Clone)]
+pub struct NoiseInfo {
+    pub noise_floor_db: f64,
+    pub snr_db: f64,
+    /// True if the recording is likely too noisy to be useful
+    pub is_noisy: bool,
+}
+
+/// Get the noise floor / SNR estimate for an indexed sound
+/// (None if the sound was indexed before noise estimation existed)
+pub fn get_noise_info(palette: i64, sound_id: i64) -> Result<Option<NoiseInfo>, String> {
+    let db = database(palette)?;
+
+    let fp = db.get_fingerprint(sound_id).map_err(|e| e.to_string())?;
+    Ok(fp.and_then(|fp| fp.noise).map(|noise| NoiseInfo {
+        noise_floor_db: noise.noise_floor_db,
+        snr_db: noise.snr_db,
+        is_noisy: noise.is_noisy(),
+    }))
+}
+
+/// Fingerprint of the matched time range of a result
+fn match_fingerprint(fingerprinter: &Fingerprinter, result: &MatchResult) -> Result<AudioFingerprint, String> {
+    let audio = crate::audio::AudioData::load(&result.filepath).map_err(|e| e.to_string())?;
+    let sr = audio.sample_rate as f64;
+    let segment = audio.get_range((result.match_start * sr) as usize, (result.match_end * sr).ceil() as usize);
+    if segment.len() == audio.samples.len() {
+        fingerprinter.extract(&audio)
+    } else {
+        fingerprinter.extract_from_samples(&segment, audio.sample_rate)
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Explain a match: per-feature-group similarity between the query and the
+/// matched time range of the result (e.g. timbre 92%, harmony 40%, energy 85%)
+pub fn explain_match(query_path: String, result: MatchResult) -> Result<Vec<FeatureContribution>, String> {
+    let fingerprinter = Fingerprinter::default();
+    let query_fp = fingerprinter.extract_from_file(&query_path).map_err(|e| e.to_string())?;
+    let match_fp = match_fingerprint(&fingerprinter, &result)?;
+    Ok(query_fp.similarity_breakdown(&match_fp))
+}
+
 /// Compute similarity between two fingerprints (0-100)
 #[flutter_rust_bridge::frb(sync)]
 pub fn compute_similarity(fp1_path: String, fp2_path: String) -> Result<f64, String> {
@@ -179,3 +2380,32 @@ pub fn compute_similarity(fp1_path: String, fp2_path: String) -> Result<f64, Str
     let fp2 = fingerprinter.extract_from_file(&fp2_path).map_err(|e| e.to_string())?;
     Ok(fp1.similarity(&fp2))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_handles() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name).to_string_lossy().to_string();
+        let drums = open_palette(path("drums.db")).unwrap();
+        let foley = open_palette(path("foley.db")).unwrap();
+        assert_ne!(drums, foley);
+        assert!(get_open_palettes().contains(&drums));
+
+        // Operations target one palette only
+        let collection = create_collection(drums, "Kicks".to_string(), String::new()).unwrap();
+        assert_eq!(get_collections(drums).unwrap()[0].id, collection);
+        assert!(get_collections(foley).unwrap().is_empty());
+
+        cancel_indexing(foley);
+        assert!(get_index_progress(drums).is_none());
+
+        close_palette(drums);
+        assert!(get_sound_count(drums).is_err());
+        assert!(!get_open_palettes().contains(&drums));
+        assert_eq!(get_sound_count(foley).unwrap(), 0);
+        close_palette(foley);
+    }
+}
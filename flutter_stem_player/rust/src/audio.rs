@@ -2,15 +2,17 @@
 //!
 //! Supports: WAV, MP3, FLAC, OGG, AAC via Symphonia
 
-use crate::{AudioMetadata, AudioPaletteError, Result};
+use crate::{AudioMetadata, AudioPaletteError, FileStamp, Result, StereoImage};
 use std::fs::File;
+use std::io::Read;
 use std::path::Path;
+use std::time::UNIX_EPOCH;
 use symphonia::core::audio::SampleBuffer;
 use symphonia::core::codecs::DecoderOptions;
 use symphonia::core::formats::FormatOptions;
 use symphonia::core::io::MediaSourceStream;
-use symphonia::core::meta::MetadataOptions;
-use symphonia::core::probe::Hint;
+use symphonia::core::meta::{MetadataOptions, StandardVisualKey};
+use symphonia::core::probe::{Hint, ProbeResult};
 
 /// Loaded audio data
 #[derive(Debug, Clone)]
@@ -19,88 +21,64 @@ pub struct AudioData {
     pub sample_rate: u32,
     pub channels: u16,
     pub duration: f64,
+    /// Stereo image of the first two channels (`StereoImage::MONO` for mono
+    /// files, None when unknown)
+    pub stereo: Option<StereoImage>,
 }
 
-impl AudioData {
-    /// Load audio from file path
-    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let path = path.as_ref();
-        let file = File::open(path)
-            .map_err(|e| AudioPaletteError::AudioLoadError(format!("Cannot open file: {}", e)))?;
+/// Running L/R statistics for stereo image features
+#[derive(Default)]
+struct StereoAccumulator {
+    sum_ll: f64,
+    sum_rr: f64,
+    sum_lr: f64,
+}
 
-        let mss = MediaSourceStream::new(Box::new(file), Default::default());
+impl StereoAccumulator {
+    fn push(&mut self, left: f32, right: f32) {
+        let (l, r) = (left as f64, right as f64);
+        self.sum_ll += l * l;
+        self.sum_rr += r * r;
+        self.sum_lr += l * r;
+    }
 
-        // Probe the format
-        let mut hint = Hint::new();
-        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-            hint.with_extension(ext);
+    fn finish(&self) -> Option<StereoImage> {
+        // Mid/side energies: M = (L+R)/2, S = (L-R)/2
+        let mid = (self.sum_ll + self.sum_rr + 2.0 * self.sum_lr) / 4.0;
+        let side = (self.sum_ll + self.sum_rr - 2.0 * self.sum_lr) / 4.0;
+        if mid + side <= 1e-12 {
+            return None;
         }
 
-        let probed = symphonia::default::get_probe()
-            .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
-            .map_err(|e| AudioPaletteError::AudioLoadError(format!("Format probe failed: {}", e)))?;
+        let norm = (self.sum_ll * self.sum_rr).sqrt();
+        let correlation = if norm > 1e-12 { (self.sum_lr / norm).clamp(-1.0, 1.0) } else { 0.0 };
 
-        let mut format = probed.format;
-
-        // Get the default track
-        let track = format
-            .default_track()
-            .ok_or_else(|| AudioPaletteError::AudioLoadError("No audio track found".to_string()))?;
-
-        let sample_rate = track.codec_params.sample_rate.unwrap_or(44100);
-        let channels = track.codec_params.channels.map(|c| c.count() as u16).unwrap_or(2);
-
-        // Create decoder
-        let mut decoder = symphonia::default::get_codecs()
-            .make(&track.codec_params, &DecoderOptions::default())
-            .map_err(|e| AudioPaletteError::AudioLoadError(format!("Decoder creation failed: {}", e)))?;
+        Some(StereoImage {
+            width: (side / (mid + side)).clamp(0.0, 1.0),
+            correlation,
+        })
+    }
+}
 
-        let track_id = track.id;
+impl AudioData {
+    /// Load audio from file path
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
         let mut samples: Vec<f32> = Vec::new();
-
-        // Decode all packets
-        loop {
-            let packet = match format.next_packet() {
-                Ok(packet) => packet,
-                Err(symphonia::core::errors::Error::IoError(e))
-                    if e.kind() == std::io::ErrorKind::UnexpectedEof =>
-                {
-                    break;
-                }
-                Err(e) => {
-                    // Log but continue - some packets may fail
-                    log::warn!("Packet decode error: {}", e);
-                    continue;
+        let mut stereo_acc = StereoAccumulator::default();
+        let mut is_stereo = false;
+
+        let (sample_rate, channels) = decode(path.as_ref(), |interleaved, ch, _| {
+            // Convert to mono by averaging channels
+            for chunk in interleaved.chunks(ch) {
+                let mono: f32 = chunk.iter().sum::<f32>() / ch as f32;
+                samples.push(mono);
+                if chunk.len() >= 2 {
+                    stereo_acc.push(chunk[0], chunk[1]);
                 }
-            };
-
-            if packet.track_id() != track_id {
-                continue;
             }
-
-            match decoder.decode(&packet) {
-                Ok(decoded) => {
-                    let spec = *decoded.spec();
-                    let duration = decoded.capacity() as u64;
-
-                    let mut sample_buf = SampleBuffer::<f32>::new(duration, spec);
-                    sample_buf.copy_interleaved_ref(decoded);
-
-                    // Convert to mono by averaging channels
-                    let interleaved = sample_buf.samples();
-                    let ch = spec.channels.count();
-
-                    for chunk in interleaved.chunks(ch) {
-                        let mono: f32 = chunk.iter().sum::<f32>() / ch as f32;
-                        samples.push(mono);
-                    }
-                }
-                Err(e) => {
-                    log::warn!("Decode error: {}", e);
-                    continue;
-                }
-            }
-        }
+            is_stereo |= ch >= 2;
+            true
+        })?;
 
         let duration = samples.len() as f64 / sample_rate as f64;
 
@@ -109,9 +87,43 @@ impl AudioData {
             sample_rate,
             channels,
             duration,
+            stereo: if is_stereo { stereo_acc.finish() } else { Some(StereoImage::MONO) },
         })
     }
 
+    /// Decode a file a packet at a time, handing each packet's mono
+    /// samples and the sample rate to `on_samples` without keeping them
+    ///
+    /// Memory stays at one packet however long the file; decoding stops
+    /// early when `on_samples` returns false. Returns the sample rate.
+    pub fn stream<P: AsRef<Path>>(path: P, mut on_samples: impl FnMut(&[f32], u32) -> bool) -> Result<u32> {
+        let mut mono = Vec::new();
+        let (sample_rate, _) = decode(path.as_ref(), |interleaved, ch, rate| {
+            mono.clear();
+            mono.extend(interleaved.chunks(ch).map(|chunk| chunk.iter().sum::<f32>() / ch as f32));
+            on_samples(&mono, rate)
+        })?;
+        Ok(sample_rate)
+    }
+
+    /// Load the `start`..`end` seconds of a file (mono), decoding only
+    /// up to `end`
+    pub fn load_range<P: AsRef<Path>>(path: P, start: f64, end: f64) -> Result<Self> {
+        let mut samples: Vec<f32> = Vec::new();
+        // Samples decoded before the current packet
+        let mut position = 0;
+        let sample_rate = Self::stream(path, |packet, rate| {
+            let first = (start.max(0.0) * rate as f64) as usize;
+            let last = (end.max(0.0) * rate as f64) as usize;
+            let from = first.saturating_sub(position).min(packet.len());
+            let to = last.saturating_sub(position).min(packet.len());
+            samples.extend_from_slice(&packet[from..to.max(from)]);
+            position += packet.len();
+            position < last
+        })?;
+        Ok(Self::from_samples(samples, sample_rate))
+    }
+
     /// Load audio from raw samples (for processing selections)
     pub fn from_samples(samples: Vec<f32>, sample_rate: u32) -> Self {
         let duration = samples.len() as f64 / sample_rate as f64;
@@ -120,6 +132,7 @@ impl AudioData {
             sample_rate,
             channels: 1,
             duration,
+            stereo: None,
         }
     }
 
@@ -130,6 +143,34 @@ impl AudioData {
         self.samples[start..end].to_vec()
     }
 
+    /// Resample to another rate using linear interpolation
+    pub fn resample(&self, target_rate: u32) -> AudioData {
+        if target_rate == self.sample_rate || self.samples.is_empty() || target_rate == 0 {
+            return self.clone();
+        }
+
+        let ratio = self.sample_rate as f64 / target_rate as f64;
+        let out_len = (self.samples.len() as f64 / ratio).floor() as usize;
+        let samples = (0..out_len)
+            .map(|i| {
+                let pos = i as f64 * ratio;
+                let idx = pos as usize;
+                let frac = (pos - idx as f64) as f32;
+                let a = self.samples[idx];
+                let b = self.samples.get(idx + 1).copied().unwrap_or(a);
+                a + (b - a) * frac
+            })
+            .collect();
+
+        AudioData {
+            samples,
+            sample_rate: target_rate,
+            channels: self.channels,
+            duration: self.duration,
+            stereo: self.stereo,
+        }
+    }
+
     /// Get metadata for this audio
     pub fn metadata(&self, filepath: &str) -> AudioMetadata {
         let path = Path::new(filepath);
@@ -156,9 +197,8 @@ impl AudioData {
     }
 }
 
-/// Get audio metadata without fully decoding
-pub fn get_metadata<P: AsRef<Path>>(path: P) -> Result<AudioMetadata> {
-    let path = path.as_ref();
+/// Open a file and probe its container format
+fn probe(path: &Path) -> Result<ProbeResult> {
     let file = File::open(path)
         .map_err(|e| AudioPaletteError::AudioLoadError(format!("Cannot open file: {}", e)))?;
 
@@ -169,39 +209,172 @@ pub fn get_metadata<P: AsRef<Path>>(path: P) -> Result<AudioMetadata> {
         hint.with_extension(ext);
     }
 
-    let probed = symphonia::default::get_probe()
+    symphonia::default::get_probe()
         .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
-        .map_err(|e| AudioPaletteError::AudioLoadError(format!("Format probe failed: {}", e)))?;
+        .map_err(|e| AudioPaletteError::AudioLoadError(format!("Format probe failed: {}", e)))
+}
+
+/// Decode the default track of a file, handing each packet's interleaved
+/// samples, channel count and sample rate to `on_packet` until it returns
+/// false; returns (sample rate, channels)
+fn decode(path: &Path, mut on_packet: impl FnMut(&[f32], usize, u32) -> bool) -> Result<(u32, u16)> {
+    let probed = probe(path)?;
+    let mut format = probed.format;
 
-    let track = probed
-        .format
+    // Get the default track
+    let track = format
         .default_track()
         .ok_or_else(|| AudioPaletteError::AudioLoadError("No audio track found".to_string()))?;
 
     let sample_rate = track.codec_params.sample_rate.unwrap_or(44100);
     let channels = track.codec_params.channels.map(|c| c.count() as u16).unwrap_or(2);
 
-    let n_frames = track.codec_params.n_frames.unwrap_or(0);
-    let duration = n_frames as f64 / sample_rate as f64;
-
-    let filename = path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("unknown")
-        .to_string();
-
-    let format = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("unknown")
-        .to_lowercase();
-
-    Ok(AudioMetadata {
-        filepath: path.to_string_lossy().to_string(),
-        filename,
-        duration,
-        sample_rate,
-        channels,
-        format,
-    })
+    // Create decoder
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| AudioPaletteError::AudioLoadError(format!("Decoder creation failed: {}", e)))?;
+
+    let track_id = track.id;
+
+    // Decode packets until the end or until told to stop
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(symphonia::core::errors::Error::IoError(e))
+                if e.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(e) => {
+                // Log but continue - some packets may fail
+                log::warn!("Packet decode error: {}", e);
+                continue;
+            }
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let spec = *decoded.spec();
+                let duration = decoded.capacity() as u64;
+
+                let mut sample_buf = SampleBuffer::<f32>::new(duration, spec);
+                sample_buf.copy_interleaved_ref(decoded);
+
+                if !on_packet(sample_buf.samples(), spec.channels.count(), sample_rate) {
+                    break;
+                }
+            }
+            Err(e) => {
+                log::warn!("Decode error: {}", e);
+                continue;
+            }
+        }
+    }
+
+    Ok((sample_rate, channels))
+}
+
+/// Load the `start`..`end` seconds of a file with every channel kept,
+/// decoding only up to `end`; returns the interleaved samples, sample rate
+/// and channel count
+pub fn load_range_interleaved<P: AsRef<Path>>(path: P, start: f64, end: f64) -> Result<(Vec<f32>, u32, u16)> {
+    let mut samples: Vec<f32> = Vec::new();
+    let mut channels = 1;
+    // Frames decoded before the current packet
+    let mut position = 0;
+    let (sample_rate, _) = decode(path.as_ref(), |interleaved, ch, rate| {
+        let ch = ch.max(1);
+        let frames = interleaved.len() / ch;
+        let first = (start.max(0.0) * rate as f64) as usize;
+        let last = (end.max(0.0) * rate as f64) as usize;
+        let from = first.saturating_sub(position).min(frames);
+        let to = last.saturating_sub(position).min(frames);
+        samples.extend_from_slice(&interleaved[from * ch..to.max(from) * ch]);
+        channels = ch as u16;
+        position += frames;
+        position < last
+    })?;
+    Ok((samples, sample_rate, channels))
+}
+
+/// Cover art embedded in a file as (media type, encoded image), preferring
+/// the front cover; None if the file has no pictures
+pub fn extract_artwork<P: AsRef<Path>>(path: P) -> Result<Option<(String, Vec<u8>)>> {
+    let mut probed = probe(path.as_ref())?;
+
+    // Pictures may sit in tags ahead of the container (ID3) or inside it
+    let mut pictures: Vec<(bool, String, Vec<u8>)> = Vec::new();
+    let mut collect = |revision: Option<&symphonia::core::meta::MetadataRevision>| {
+        for visual in revision.map(|r| r.visuals()).unwrap_or_default() {
+            let front = visual.usage == Some(StandardVisualKey::FrontCover);
+            pictures.push((front, visual.media_type.clone(), visual.data.to_vec()));
+        }
+    };
+    if let Some(metadata) = probed.metadata.get() {
+        collect(metadata.current());
+    }
+    collect(probed.format.metadata().current());
+
+    let best = pictures.iter().position(|(front, ..)| *front).unwrap_or(0);
+    Ok((best < pictures.len()).then(|| {
+        let (_, media_type, data) = pictures.swap_remove(best);
+        (media_type, data)
+    }))
+}
+
+/// Hash of a file's bytes, identifying exact duplicates regardless of path
+pub fn content_hash<P: AsRef<Path>>(path: P) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = xxhash_rust::xxh3::Xxh3::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+    Ok(format!("{:032x}", hasher.digest128()))
+}
+
+/// Current size and modification time of a file
+pub fn file_stamp<P: AsRef<Path>>(path: P) -> Result<FileStamp> {
+    let metadata = std::fs::metadata(path)?;
+    let mtime = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0);
+    Ok(FileStamp { size: metadata.len() as i64, mtime })
+}
+
+/// Write mono samples to `dir/name` as a 32-bit float WAV, for tests;
+/// decoding it gives back exactly these samples
+#[cfg(test)]
+pub(crate) fn write_test_wav(dir: &Path, name: &str, samples: &[f32], rate: u32) -> std::path::PathBuf {
+    write_test_wav_channels(dir, name, samples, rate, 1)
+}
+
+/// `write_test_wav` for interleaved samples with `channels` channels
+#[cfg(test)]
+pub(crate) fn write_test_wav_channels(
+    dir: &Path,
+    name: &str,
+    samples: &[f32],
+    rate: u32,
+    channels: u16,
+) -> std::path::PathBuf {
+    let path = dir.join(name);
+    let spec =
+        hound::WavSpec { channels, sample_rate: rate, bits_per_sample: 32, sample_format: hound::SampleFormat::Float };
+    let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+    for &s in samples {
+        writer.write_sample(s).unwrap();
+    }
+    writer.finalize().unwrap();
+    path
 }
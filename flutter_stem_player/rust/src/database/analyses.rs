@@ -0,0 +1,105 @@
+//! Stored time-series analysis (onsets, beats, pitch)
+//!
+//! Computed at index time and kept per sound and kind, tagged with the
+//! content hash they were computed from like cached waveforms, so results
+//! for a file that has since changed read as missing.
+
+use super::{PaletteDatabase, WriteBatch};
+use crate::fingerprint::{AnalysisKind, AnalysisTrack};
+use crate::{AudioPaletteError, Result};
+use rusqlite::{params, OptionalExtension};
+
+/// Pack values as little-endian f32
+fn values_blob(values: &[f64]) -> Vec<u8> {
+    values.iter().flat_map(|&v| (v as f32).to_le_bytes()).collect()
+}
+
+fn values_from_blob(bytes: &[u8]) -> Result<Vec<f64>> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(AudioPaletteError::FingerprintError("Malformed analysis blob".to_string()));
+    }
+    Ok(bytes.chunks_exact(4).map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]) as f64).collect())
+}
+
+impl PaletteDatabase {
+    /// Store analysis results for many sounds in one transaction, replacing
+    /// earlier results of the same kinds
+    pub fn store_analyses_batch(&self, analyses: &[(i64, Vec<AnalysisTrack>)]) -> Result<()> {
+        self.write_batch(|batch| batch.store_analyses(analyses))
+    }
+
+    /// Stored analysis of a sound (None if not computed for its current contents)
+    pub fn get_analysis(&self, sound_id: i64, kind: AnalysisKind) -> Result<Option<AnalysisTrack>> {
+        let conn = self.reader()?;
+        let row: Option<(f64, Vec<u8>)> = conn
+            .query_row(
+                "SELECT a.hop_seconds, a.data FROM analyses a JOIN sounds s ON s.id = a.sound_id
+                 WHERE a.sound_id = ?1 AND a.kind = ?2 AND a.content_hash IS s.content_hash",
+                params![sound_id, kind.name()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        row.map(|(hop_seconds, data)| Ok(AnalysisTrack { kind, hop_seconds, values: values_from_blob(&data)? }))
+            .transpose()
+    }
+
+    /// Every sound's stored analysis of one kind, by sound id (only those
+    /// computed for the sounds' current contents)
+    pub fn get_analyses(&self, kind: AnalysisKind) -> Result<Vec<(i64, AnalysisTrack)>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT a.sound_id, a.hop_seconds, a.data FROM analyses a JOIN sounds s ON s.id = a.sound_id
+             WHERE a.kind = ?1 AND a.content_hash IS s.content_hash
+             ORDER BY a.sound_id",
+        )?;
+        let rows = stmt.query_map(params![kind.name()], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, f64>(1)?, row.get::<_, Vec<u8>>(2)?))
+        })?;
+        rows.map(|row| {
+            let (sound_id, hop_seconds, data) = row?;
+            Ok((sound_id, AnalysisTrack { kind, hop_seconds, values: values_from_blob(&data)? }))
+        })
+        .collect()
+    }
+}
+
+impl WriteBatch<'_> {
+    pub(crate) fn store_analyses(&self, analyses: &[(i64, Vec<AnalysisTrack>)]) -> Result<()> {
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT OR REPLACE INTO analyses (sound_id, kind, content_hash, hop_seconds, data)
+             SELECT id, ?2, content_hash, ?3, ?4 FROM sounds WHERE id = ?1",
+        )?;
+        for (sound_id, tracks) in analyses {
+            for track in tracks {
+                stmt.execute(params![sound_id, track.kind.name(), track.hop_seconds, values_blob(&track.values)])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stored_analyses() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound("/loops/break.wav", "break.wav", 4.0, 44100, 2, "wav").unwrap();
+        db.set_content_hash(id, "v1").unwrap();
+        assert_eq!(db.get_analysis(id, AnalysisKind::Onsets).unwrap(), None);
+
+        let onsets = AnalysisTrack { kind: AnalysisKind::Onsets, hop_seconds: 0.0, values: vec![0.0, 0.5, 1.25] };
+        let pitch = AnalysisTrack { kind: AnalysisKind::Pitch, hop_seconds: 0.01, values: vec![0.0, 440.0, 441.5] };
+        db.store_analyses_batch(&[(id, vec![onsets.clone(), pitch.clone()])]).unwrap();
+        assert_eq!(db.get_analysis(id, AnalysisKind::Onsets).unwrap(), Some(onsets));
+        assert_eq!(db.get_analysis(id, AnalysisKind::Pitch).unwrap(), Some(pitch.clone()));
+        assert_eq!(db.get_analysis(id, AnalysisKind::Beats).unwrap(), None);
+        assert_eq!(db.get_analyses(AnalysisKind::Pitch).unwrap(), vec![(id, pitch)]);
+
+        // Results for earlier file contents are stale
+        db.set_content_hash(id, "v2").unwrap();
+        assert_eq!(db.get_analysis(id, AnalysisKind::Onsets).unwrap(), None);
+        assert!(db.get_analyses(AnalysisKind::Pitch).unwrap().is_empty());
+    }
+}
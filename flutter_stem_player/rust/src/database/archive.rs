@@ -0,0 +1,325 @@
+//! Portable library archives
+//!
+//! An archive is a zip file holding a `library.json` manifest (sound
+//! records, editable metadata, tags and fingerprints) and, optionally, the
+//! audio files themselves under `audio/`. Importing merges the archive into
+//! an existing library: sounds already present (same path, or same file
+//! contents by content hash) keep their local
+//! metadata and fingerprint, and only gain the archive's tags. Fingerprints
+//! computed by another analysis (`FINGERPRINT_VERSION`) are left out, so
+//! those sounds are re-analyzed by the next rescan.
+
+use super::{insert_fingerprint, insert_sound, insert_sound_tag, normalize_tag, PaletteDatabase};
+use crate::fingerprint::{AudioFingerprint, FINGERPRINT_VERSION};
+use crate::{AudioPaletteError, NewSound, Result, SoundRecord, SoundTag};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const MANIFEST_NAME: &str = "library.json";
+const ARCHIVE_FORMAT: &str = "audio-palette-library";
+/// 2: fingerprints carry the `FINGERPRINT_VERSION` they were computed with
+const ARCHIVE_VERSION: u32 = 2;
+
+/// What to include when exporting a library
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveOptions {
+    /// Copy the audio files into the archive (otherwise only their paths)
+    pub include_audio: bool,
+}
+
+/// Result of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveSummary {
+    pub sounds: usize,
+    pub audio_files: usize,
+}
+
+/// Result of an import
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Sounds new to this library
+    pub added: usize,
+    /// Sounds already indexed under the same path or content hash
+    pub merged: usize,
+    /// Audio files extracted from the archive
+    pub audio_files: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    format: String,
+    version: u32,
+    /// `FINGERPRINT_VERSION` of the exported fingerprints (0 before archive version 2)
+    #[serde(default)]
+    feature_version: u32,
+    sounds: Vec<ArchivedSound>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedSound {
+    #[serde(flatten)]
+    record: SoundRecord,
+    /// Absent in archives from before content hashes were exported
+    #[serde(default)]
+    content_hash: Option<String>,
+    #[serde(default)]
+    tags: Vec<SoundTag>,
+    #[serde(default)]
+    fingerprint: Option<AudioFingerprint>,
+    /// Archive entry holding the audio, if it was included
+    #[serde(default)]
+    audio: Option<String>,
+}
+
+fn archive_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::ArchiveError(e.to_string())
+}
+
+impl PaletteDatabase {
+    /// Export the whole library to a zip archive
+    ///
+    /// Sounds whose audio file is missing are still exported (without audio)
+    /// when `include_audio` is set.
+    pub fn export_archive<P: AsRef<Path>>(&self, path: P, options: &ArchiveOptions) -> Result<ArchiveSummary> {
+        let mut fingerprints: HashMap<i64, AudioFingerprint> = self.get_all_fingerprints()?.into_iter().collect();
+        let mut hashes: HashMap<i64, String> = self
+            .get_indexed_files()?
+            .into_iter()
+            .filter_map(|f| Some((f.id, f.content_hash?)))
+            .collect();
+
+        let mut zip = ZipWriter::new(File::create(path)?);
+        // Audio is usually compressed already; don't spend time deflating it
+        let audio_options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Stored)
+            .large_file(true);
+
+        let mut sounds = Vec::new();
+        let mut audio_files = 0;
+        for (index, record) in self.get_all_sounds()?.into_iter().enumerate() {
+            let tags = self.get_sound_tags(record.id)?;
+
+            let audio = if options.include_audio && Path::new(&record.filepath).is_file() {
+                let entry = format!("audio/{:05}_{}", index, record.filename);
+                zip.start_file(entry.as_str(), audio_options).map_err(archive_error)?;
+                std::io::copy(&mut File::open(&record.filepath)?, &mut zip)?;
+                audio_files += 1;
+                Some(entry)
+            } else {
+                None
+            };
+
+            sounds.push(ArchivedSound {
+                fingerprint: fingerprints.remove(&record.id),
+                content_hash: hashes.remove(&record.id),
+                record,
+                tags,
+                audio,
+            });
+        }
+
+        let manifest = Manifest {
+            format: ARCHIVE_FORMAT.to_string(),
+            version: ARCHIVE_VERSION,
+            feature_version: FINGERPRINT_VERSION,
+            sounds,
+        };
+        zip.start_file(MANIFEST_NAME, SimpleFileOptions::default().compression_method(CompressionMethod::Deflated))
+            .map_err(archive_error)?;
+        serde_json::to_writer(&mut zip, &manifest).map_err(archive_error)?;
+        zip.finish().map_err(archive_error)?;
+
+        Ok(ArchiveSummary { sounds: manifest.sounds.len(), audio_files })
+    }
+
+    /// Merge an exported archive into this library
+    ///
+    /// With `audio_dir`, audio files contained in the archive are extracted
+    /// there and the imported sounds point at the extracted copies; otherwise
+    /// sounds keep the paths they had on the exporting machine.
+    pub fn import_archive<P: AsRef<Path>>(&self, path: P, audio_dir: Option<&Path>) -> Result<ImportSummary> {
+        let mut zip = ZipArchive::new(File::open(path)?).map_err(archive_error)?;
+        let mut manifest: Manifest =
+            serde_json::from_reader(zip.by_name(MANIFEST_NAME).map_err(archive_error)?).map_err(archive_error)?;
+
+        if manifest.format != ARCHIVE_FORMAT {
+            return Err(archive_error("not an audio palette library archive"));
+        }
+        if manifest.version > ARCHIVE_VERSION {
+            return Err(archive_error(format!("unsupported archive version {}", manifest.version)));
+        }
+
+        // Extract audio before touching the database
+        let mut audio_files = 0;
+        if let Some(dir) = audio_dir {
+            std::fs::create_dir_all(dir)?;
+            for sound in &mut manifest.sounds {
+                let Some(entry) = &sound.audio else { continue };
+                let Some(name) = Path::new(entry).file_name() else { continue };
+                let target = dir.join(name);
+                let mut source = zip.by_name(entry).map_err(archive_error)?;
+                std::io::copy(&mut source, &mut File::create(&target)?)?;
+                sound.record.filepath = target.to_string_lossy().to_string();
+                audio_files += 1;
+            }
+        }
+
+        let current_fingerprints = manifest.feature_version == FINGERPRINT_VERSION;
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let (mut added, mut merged) = (0, 0);
+
+        for sound in &manifest.sounds {
+            let record = &sound.record;
+            let existing: Option<i64> = tx
+                .query_row(
+                    "SELECT id FROM sounds WHERE filepath = store_path(?1) OR (?2 IS NOT NULL AND content_hash = ?2)
+                     ORDER BY filepath = store_path(?1) DESC LIMIT 1",
+                    params![record.filepath, sound.content_hash],
+                    |row| row.get(0),
+                )
+                .optional()?;
+
+            let id = match existing {
+                Some(id) => {
+                    merged += 1;
+                    id
+                }
+                None => {
+                    added += 1;
+                    let id = insert_sound(&tx, &NewSound {
+                        filepath: record.filepath.clone(),
+                        filename: record.filename.clone(),
+                        duration: record.duration,
+                        sample_rate: record.sample_rate,
+                        channels: record.channels,
+                        format: record.format.clone(),
+                        content_hash: sound.content_hash.clone(),
+                        file_stamp: None,
+                        bpm: (record.bpm > 0.0).then_some(record.bpm),
+                        musical_key: (!record.musical_key.is_empty()).then(|| record.musical_key.clone()),
+                    })?;
+                    tx.execute(
+                        "UPDATE sounds SET date_added = ?2, title = ?3, artist = ?4, notes = ?5, source = ?6,
+                             rating = ?7
+                         WHERE id = ?1",
+                        params![
+                            id,
+                            record.date_added,
+                            record.title,
+                            record.artist,
+                            record.notes,
+                            record.source,
+                            record.rating
+                        ],
+                    )?;
+                    if let Some(fp) = sound.fingerprint.as_ref().filter(|_| current_fingerprints) {
+                        insert_fingerprint(&tx, id, fp)?;
+                    }
+                    id
+                }
+            };
+
+            for tag in &sound.tags {
+                let name = normalize_tag(&tag.name);
+                if !name.is_empty() {
+                    insert_sound_tag(&tx, id, &name, tag.confidence, &tag.source)?;
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(ImportSummary { added, merged, audio_files })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SoundMetadataUpdate;
+
+    #[test]
+    fn test_archive_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let audio_path = dir.path().join("ZOOM0041.WAV");
+        std::fs::write(&audio_path, b"RIFF fake audio").unwrap();
+
+        let source = PaletteDatabase::open_in_memory().unwrap();
+        let id = source
+            .add_sound(audio_path.to_str().unwrap(), "ZOOM0041.WAV", 12.0, 48000, 2, "wav")
+            .unwrap();
+        source.add_sound("/missing/kick.wav", "kick.wav", 0.4, 44100, 1, "wav").unwrap();
+        source
+            .update_sound_metadata(id, &SoundMetadataUpdate {
+                title: Some("Harbour".to_string()),
+                ..SoundMetadataUpdate::default()
+            })
+            .unwrap();
+        source.add_tag(id, "field").unwrap();
+        source.set_content_hash(id, "9f86d081884c7d65").unwrap();
+
+        let archive = dir.path().join("library.zip");
+        let summary = source
+            .export_archive(&archive, &ArchiveOptions { include_audio: true })
+            .unwrap();
+        assert_eq!(summary, ArchiveSummary { sounds: 2, audio_files: 1 });
+
+        let target = PaletteDatabase::open_in_memory().unwrap();
+        let audio_dir = dir.path().join("imported");
+        let summary = target.import_archive(&archive, Some(&audio_dir)).unwrap();
+        assert_eq!(summary, ImportSummary { added: 2, merged: 0, audio_files: 1 });
+
+        let imported = &target.search("harbour").unwrap()[0];
+        assert!(imported.filepath.starts_with(audio_dir.to_str().unwrap()));
+        assert_eq!(std::fs::read(&imported.filepath).unwrap(), b"RIFF fake audio");
+        assert_eq!(target.get_sound_tags(imported.id).unwrap()[0].name, "field");
+        assert_eq!(target.get_content_hash(imported.id).unwrap().as_deref(), Some("9f86d081884c7d65"));
+
+        // Importing again merges instead of duplicating
+        let summary = target.import_archive(&archive, Some(&audio_dir)).unwrap();
+        assert_eq!((summary.added, summary.merged), (0, 2));
+        assert_eq!(target.count().unwrap(), 2);
+
+        // Extracted somewhere else, the same contents still merge by hash
+        let summary = target.import_archive(&archive, Some(&dir.path().join("elsewhere"))).unwrap();
+        assert_eq!((summary.added, summary.merged), (0, 2));
+        assert_eq!(target.count().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_archive_fingerprint_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = PaletteDatabase::open_in_memory().unwrap();
+        let id = source.add_sound("/kit/kick.wav", "kick.wav", 0.4, 44100, 1, "wav").unwrap();
+        source.store_fingerprint(id, &crate::fingerprint::test_util::fingerprint(1.0)).unwrap();
+        let archive = dir.path().join("library.zip");
+        source.export_archive(&archive, &ArchiveOptions::default()).unwrap();
+
+        let target = PaletteDatabase::open_in_memory().unwrap();
+        target.import_archive(&archive, None).unwrap();
+        assert!(target.get_fingerprint(target.get_all_sounds().unwrap()[0].id).unwrap().is_some());
+
+        // The same fingerprints in an archive from before versioned features
+        let mut manifest: serde_json::Value =
+            serde_json::from_reader(ZipArchive::new(File::open(&archive).unwrap()).unwrap().by_name(MANIFEST_NAME).unwrap())
+                .unwrap();
+        manifest["version"] = 1.into();
+        manifest.as_object_mut().unwrap().remove("feature_version");
+        let old_archive = dir.path().join("old.zip");
+        let mut zip = ZipWriter::new(File::create(&old_archive).unwrap());
+        zip.start_file(MANIFEST_NAME, SimpleFileOptions::default()).unwrap();
+        serde_json::to_writer(&mut zip, &manifest).unwrap();
+        zip.finish().unwrap();
+
+        let target = PaletteDatabase::open_in_memory().unwrap();
+        assert_eq!(target.import_archive(&old_archive, None).unwrap().added, 1);
+        let imported = target.get_all_sounds().unwrap()[0].id;
+        assert!(target.get_fingerprint(imported).unwrap().is_none());
+        assert_eq!(target.count_outdated_fingerprints().unwrap(), 1);
+    }
+}
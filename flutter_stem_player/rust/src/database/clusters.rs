@@ -0,0 +1,555 @@
+//! Library clustering
+//!
+//! An offline job groups the whole library by sound: spherical k-means
+//! over the stored fingerprint vectors (cosine, like similarity search),
+//! seeded with k-means++ on a sample so large libraries never need every
+//! pairwise similarity. Each sound gets a cluster id and each cluster a
+//! representative (the member nearest its centre), for an automatically
+//! organized palette view and "browse this cluster". Sounds added later
+//! have no cluster until the job runs again; removed ones drop out.
+//!
+//! Left to choose the number of clusters, the job tries a range of them on
+//! a sample and keeps the one with the best mean silhouette (how much
+//! nearer each sound is to its own cluster than to the next nearest).
+//! Clusters are labelled by their dominant tag, or failing that the word
+//! most of their filenames share, and can be written out as categories.
+
+use super::{sound_from_row, vector_index, PaletteDatabase, SOUND_COLUMNS};
+use crate::fingerprint::FINGERPRINT_VERSION;
+use crate::{Result, SoundRecord};
+use rayon::prelude::*;
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Most clusters chosen automatically
+pub const MAX_AUTO_CLUSTERS: usize = 64;
+/// Cluster counts tried when choosing automatically
+const AUTO_CANDIDATES: [usize; 11] = [2, 3, 4, 6, 8, 12, 16, 24, 32, 48, 64];
+/// Sounds the silhouette of each candidate count is measured on
+const SILHOUETTE_SAMPLE: usize = 1_000;
+/// Filename words too generic to name a cluster
+const LABEL_STOP_WORDS: [&str; 4] = ["and", "bpm", "the", "wav"];
+/// Assignment passes before settling for the current clustering
+const MAX_ITERATIONS: usize = 25;
+/// Sounds k-means++ seeding draws from
+const SEEDING_SAMPLE: usize = 5_000;
+
+/// Progress of a `cluster_library` run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterProgress {
+    /// Assignment pass running (the last one once finished)
+    pub iteration: usize,
+    pub max_iterations: usize,
+    pub finished: bool,
+}
+
+/// Outcome of `cluster_library`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusteringSummary {
+    pub clusters: usize,
+    /// Sounds assigned a cluster (those with a fingerprint)
+    pub sounds: usize,
+    /// Assignment passes run; below the maximum means it converged
+    pub iterations: usize,
+}
+
+/// A cluster as listed for browsing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundCluster {
+    pub id: i64,
+    pub size: i64,
+    /// Member nearest the cluster centre when it was computed
+    pub representative: Option<SoundRecord>,
+    /// Proposed name: the tag or filename word most members share
+    pub label: Option<String>,
+}
+
+/// Deterministic random numbers (splitmix64), so reclustering an unchanged
+/// library gives the same clusters
+struct SplitMix(u64);
+
+impl SplitMix {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.unit() * n as f64) as usize
+    }
+}
+
+pub(super) fn normalized(mut v: Vec<f32>) -> Vec<f32> {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+    v
+}
+
+pub(super) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn nearest_centroid(v: &[f32], centroids: &[Vec<f32>]) -> usize {
+    (0..centroids.len()).max_by(|&a, &b| dot(v, &centroids[a]).total_cmp(&dot(v, &centroids[b]))).unwrap_or(0)
+}
+
+/// k-means++ centres drawn from a sample of `vectors` (unit length);
+/// fewer than `k` if there aren't that many distinct sounds
+fn seed_centroids(vectors: &[Vec<f32>], k: usize, rng: &mut SplitMix) -> Vec<Vec<f32>> {
+    let sample: Vec<&Vec<f32>> = if vectors.len() <= SEEDING_SAMPLE {
+        vectors.iter().collect()
+    } else {
+        (0..SEEDING_SAMPLE).map(|_| &vectors[rng.below(vectors.len())]).collect()
+    };
+    let mut centroids = vec![sample[rng.below(sample.len())].clone()];
+    let mut distance: Vec<f64> = sample.iter().map(|v| (1.0 - dot(v, &centroids[0]) as f64).max(0.0)).collect();
+    while centroids.len() < k {
+        let total: f64 = distance.iter().map(|d| d * d).sum();
+        if total <= 1e-12 {
+            break;
+        }
+        let mut target = rng.unit() * total;
+        let pick = distance
+            .iter()
+            .position(|d| {
+                target -= d * d;
+                target <= 0.0
+            })
+            .unwrap_or(sample.len() - 1);
+        let centre = sample[pick].clone();
+        for (d, v) in distance.iter_mut().zip(&sample) {
+            *d = d.min((1.0 - dot(v, &centre) as f64).max(0.0));
+        }
+        centroids.push(centre);
+    }
+    centroids
+}
+
+/// Spherical k-means: each vector's cluster index, the centres, and the
+/// passes run
+fn kmeans(
+    vectors: &[Vec<f32>],
+    k: usize,
+    progress: &mut impl FnMut(&ClusterProgress),
+) -> (Vec<usize>, Vec<Vec<f32>>, usize) {
+    let mut centroids = seed_centroids(vectors, k, &mut SplitMix(0x5eed));
+    let mut assignment: Vec<usize> = Vec::new();
+    let mut iterations = 0;
+    while iterations < MAX_ITERATIONS {
+        iterations += 1;
+        progress(&ClusterProgress { iteration: iterations, max_iterations: MAX_ITERATIONS, finished: false });
+        let next: Vec<usize> = vectors.par_iter().map(|v| nearest_centroid(v, &centroids)).collect();
+        if next == assignment {
+            break;
+        }
+        assignment = next;
+
+        let dims = vectors[0].len();
+        let mut sums = vec![vec![0.0f32; dims]; centroids.len()];
+        for (v, &cluster) in vectors.iter().zip(&assignment) {
+            sums[cluster].iter_mut().zip(v).for_each(|(s, x)| *s += x);
+        }
+        // Clusters left empty keep their old centre
+        for (centroid, sum) in centroids.iter_mut().zip(sums) {
+            if sum.iter().any(|&x| x != 0.0) {
+                *centroid = normalized(sum);
+            }
+        }
+    }
+    (assignment, centroids, iterations)
+}
+
+/// Mean silhouette of a clustering given all pairwise distances (sounds
+/// alone in their cluster count 0)
+fn silhouette(distances: &[Vec<f32>], assignment: &[usize], clusters: usize) -> f64 {
+    let mut sizes = vec![0usize; clusters];
+    assignment.iter().for_each(|&c| sizes[c] += 1);
+    let total: f64 = (0..assignment.len())
+        .into_par_iter()
+        .map(|i| {
+            let own = assignment[i];
+            if sizes[own] < 2 {
+                return 0.0;
+            }
+            let mut sums = vec![0.0f64; clusters];
+            for (j, &c) in assignment.iter().enumerate() {
+                sums[c] += distances[i][j] as f64;
+            }
+            let within = sums[own] / (sizes[own] - 1) as f64;
+            let nearest = (0..clusters)
+                .filter(|&c| c != own && sizes[c] > 0)
+                .map(|c| sums[c] / sizes[c] as f64)
+                .fold(f64::INFINITY, f64::min);
+            if nearest.is_finite() && within.max(nearest) > 0.0 {
+                (nearest - within) / within.max(nearest)
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    total / assignment.len() as f64
+}
+
+/// Cluster count with the best silhouette on an evenly spread sample
+fn auto_cluster_count(vectors: &[Vec<f32>]) -> usize {
+    let size = vectors.len().min(SILHOUETTE_SAMPLE);
+    let sample: Vec<Vec<f32>> = (0..size).map(|i| vectors[i * vectors.len() / size].clone()).collect();
+    let distances: Vec<Vec<f32>> =
+        sample.par_iter().map(|a| sample.iter().map(|b| (1.0 - dot(a, b)).max(0.0)).collect()).collect();
+
+    let mut best = (1, 0.0);
+    for k in AUTO_CANDIDATES.into_iter().filter(|&k| k <= MAX_AUTO_CLUSTERS && k < size) {
+        let (assignment, centroids, _) = kmeans(&sample, k, &mut |_| {});
+        let score = silhouette(&distances, &assignment, centroids.len());
+        if score > best.1 {
+            best = (k, score);
+        }
+    }
+    best.0
+}
+
+/// Lowercase words of a filename (extension dropped), each once
+fn filename_words(filename: &str) -> Vec<String> {
+    let stem = filename.rsplit_once('.').map_or(filename, |(stem, _)| stem);
+    let mut words: Vec<String> = stem
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .filter(|w| !LABEL_STOP_WORDS.contains(&w.as_str()))
+        .collect();
+    words.sort_unstable();
+    words.dedup();
+    words
+}
+
+impl PaletteDatabase {
+    /// Every stored fingerprint vector at unit length with its sound id,
+    /// in id order
+    ///
+    /// Vectors computed by an older analysis (`FINGERPRINT_VERSION`) or of
+    /// another layout than the first can't be compared and are left out.
+    pub fn unit_vectors(&self) -> Result<(Vec<i64>, Vec<Vec<f32>>)> {
+        self.unit_vectors_since(-1)
+    }
+
+    /// `unit_vectors` of the fingerprints written after library revision
+    /// `revision`, e.g. to bring an index built at that revision up to date
+    pub fn unit_vectors_since(&self, revision: i64) -> Result<(Vec<i64>, Vec<Vec<f32>>)> {
+        let rows: Vec<(i64, Vec<u8>)> = {
+            let conn = self.reader()?;
+            let mut stmt = conn.prepare(
+                "SELECT sound_id, vector FROM fingerprints
+                 WHERE vector IS NOT NULL AND revision > ?1 AND feature_version = ?2
+                 ORDER BY sound_id",
+            )?;
+            let rows = stmt.query_map(params![revision, FINGERPRINT_VERSION], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+        let dims = rows.first().map_or(0, |(_, blob)| blob.len() / 4);
+        Ok(rows
+            .into_iter()
+            .filter(|(_, blob)| blob.len() / 4 == dims && dims > 0)
+            .map(|(id, blob)| (id, normalized(vector_index::unpack_vector(&blob))))
+            .unzip())
+    }
+
+    /// Cluster every fingerprinted sound into `clusters` groups (0 picks
+    /// the number by silhouette), replacing the previous clustering
+    ///
+    /// Reports progress before each assignment pass and once when done.
+    /// Cluster ids run from 1, largest cluster first.
+    pub fn cluster_library(
+        &self,
+        clusters: usize,
+        mut progress: impl FnMut(&ClusterProgress),
+    ) -> Result<ClusteringSummary> {
+        let (ids, vectors) = self.unit_vectors()?;
+
+        let (assignment, centroids, iterations) = if vectors.is_empty() {
+            (Vec::new(), Vec::new(), 0)
+        } else {
+            let k = if clusters == 0 { auto_cluster_count(&vectors) } else { clusters.min(vectors.len()) };
+            kmeans(&vectors, k, &mut progress)
+        };
+
+        // Number non-empty clusters by size, largest first
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); centroids.len()];
+        for (i, &cluster) in assignment.iter().enumerate() {
+            members[cluster].push(i);
+        }
+        let mut order: Vec<usize> = (0..centroids.len()).filter(|&c| !members[c].is_empty()).collect();
+        order.sort_by_key(|&c| std::cmp::Reverse(members[c].len()));
+        let labels = order
+            .iter()
+            .map(|&c| self.propose_cluster_label(&members[c].iter().map(|&i| ids[i]).collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM sound_clusters", [])?;
+        tx.execute("DELETE FROM clusters", [])?;
+        {
+            let mut add_cluster =
+                tx.prepare("INSERT INTO clusters (id, representative_id, label) VALUES (?1, ?2, ?3)")?;
+            let mut add_member = tx.prepare("INSERT INTO sound_clusters (sound_id, cluster_id) VALUES (?1, ?2)")?;
+            for (number, &c) in order.iter().enumerate() {
+                let cluster_id = number as i64 + 1;
+                let representative = members[c]
+                    .iter()
+                    .copied()
+                    .max_by(|&a, &b| dot(&vectors[a], &centroids[c]).total_cmp(&dot(&vectors[b], &centroids[c])))
+                    .map(|i| ids[i]);
+                add_cluster.execute(params![cluster_id, representative, labels[number]])?;
+                for &i in &members[c] {
+                    add_member.execute(params![ids[i], cluster_id])?;
+                }
+            }
+        }
+        tx.commit()?;
+
+        progress(&ClusterProgress { iteration: iterations, max_iterations: MAX_ITERATIONS, finished: true });
+        Ok(ClusteringSummary { clusters: order.len(), sounds: ids.len(), iterations })
+    }
+
+    /// A name for a group of sounds: the tag at least half of them carry,
+    /// else the filename word at least half of them share (most common
+    /// first, then alphabetical)
+    fn propose_cluster_label(&self, sound_ids: &[i64]) -> Result<Option<String>> {
+        let conn = self.reader()?;
+        let ids = format!("[{}]", sound_ids.iter().map(i64::to_string).collect::<Vec<_>>().join(","));
+        let quorum = sound_ids.len().div_ceil(2) as i64;
+        let tag: Option<String> = conn
+            .query_row(
+                "SELECT t.name FROM sound_tags st JOIN tags t ON t.id = st.tag_id
+                 WHERE st.sound_id IN (SELECT value FROM json_each(?1))
+                 GROUP BY t.name HAVING COUNT(DISTINCT st.sound_id) >= ?2
+                 ORDER BY COUNT(DISTINCT st.sound_id) DESC, t.name LIMIT 1",
+                params![ids, quorum],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if tag.is_some() {
+            return Ok(tag);
+        }
+
+        let mut stmt = conn.prepare("SELECT filename FROM sounds WHERE id IN (SELECT value FROM json_each(?1))")?;
+        let filenames = stmt.query_map(params![ids], |row| row.get::<_, String>(0))?;
+        let mut counts: HashMap<String, i64> = HashMap::new();
+        for filename in filenames {
+            for word in filename_words(&filename?) {
+                *counts.entry(word).or_default() += 1;
+            }
+        }
+        Ok(counts
+            .into_iter()
+            .filter(|(_, count)| *count >= quorum)
+            .min_by(|(a, ca), (b, cb)| cb.cmp(ca).then(a.cmp(b)))
+            .map(|(word, _)| word))
+    }
+
+    /// Write the last clustering into the categories: one category per
+    /// cluster, named by its label (or "Cluster N"), under the top-level
+    /// category `parent`, whose earlier subcategories are replaced
+    ///
+    /// Returns the number of categories written.
+    pub fn write_cluster_categories(&self, parent: &str) -> Result<usize> {
+        let clusters: Vec<(i64, Option<String>)> = {
+            let conn = self.reader()?;
+            let mut stmt = conn.prepare("SELECT id, label FROM clusters ORDER BY id")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let parent_id: i64 = match tx
+            .query_row("SELECT id FROM categories WHERE name = ?1 AND parent_id IS NULL", params![parent], |row| {
+                row.get(0)
+            })
+            .optional()?
+        {
+            Some(id) => {
+                tx.execute(
+                    "DELETE FROM sound_categories WHERE category_id IN (SELECT id FROM categories WHERE parent_id = ?1)",
+                    params![id],
+                )?;
+                tx.execute("DELETE FROM categories WHERE parent_id = ?1", params![id])?;
+                id
+            }
+            None => {
+                tx.execute("INSERT INTO categories (name) VALUES (?1)", params![parent])?;
+                tx.last_insert_rowid()
+            }
+        };
+
+        let mut used: HashMap<String, usize> = HashMap::new();
+        let mut written = 0;
+        for (cluster_id, label) in clusters {
+            let name = label.unwrap_or_else(|| format!("Cluster {cluster_id}"));
+            // Two clusters of kicks become "kick" and "kick 2"
+            let seen = used.entry(name.clone()).or_default();
+            *seen += 1;
+            let name = if *seen > 1 { format!("{name} {seen}") } else { name };
+
+            tx.execute("INSERT INTO categories (name, parent_id) VALUES (?1, ?2)", params![name, parent_id])?;
+            let category_id = tx.last_insert_rowid();
+            tx.execute(
+                "INSERT INTO sound_categories (sound_id, category_id)
+                 SELECT sound_id, ?1 FROM sound_clusters WHERE cluster_id = ?2",
+                params![category_id, cluster_id],
+            )?;
+            written += 1;
+        }
+        tx.commit()?;
+        Ok(written)
+    }
+
+    /// Clusters from the last `cluster_library` run, largest first
+    pub fn get_clusters(&self) -> Result<Vec<SoundCluster>> {
+        let rows: Vec<(i64, i64, Option<i64>, Option<String>)> = {
+            let conn = self.reader()?;
+            let mut stmt = conn.prepare(
+                "SELECT c.id, COUNT(*), COALESCE(c.representative_id, MIN(sc.sound_id)), c.label
+                 FROM clusters c JOIN sound_clusters sc ON sc.cluster_id = c.id
+                 GROUP BY c.id ORDER BY COUNT(*) DESC, c.id",
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+        rows.into_iter()
+            .map(|(id, size, representative, label)| {
+                let representative = representative.map(|r| self.get_sound(r)).transpose()?.flatten();
+                Ok(SoundCluster { id, size, representative, label })
+            })
+            .collect()
+    }
+
+    /// Sounds in a cluster, by name
+    pub fn get_cluster_sounds(&self, cluster_id: i64) -> Result<Vec<SoundRecord>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SOUND_COLUMNS} FROM sounds
+             WHERE id IN (SELECT sound_id FROM sound_clusters WHERE cluster_id = ?1)
+             ORDER BY filename COLLATE NOCASE, id"
+        ))?;
+        let sounds = stmt.query_map(params![cluster_id], sound_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(sounds)
+    }
+
+    /// Cluster a sound belongs to (None if unclustered)
+    pub fn get_sound_cluster(&self, sound_id: i64) -> Result<Option<i64>> {
+        let conn = self.reader()?;
+        let cluster = conn
+            .query_row("SELECT cluster_id FROM sound_clusters WHERE sound_id = ?1", params![sound_id], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        Ok(cluster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::AudioFingerprint;
+
+    fn fingerprint(family: usize, variant: usize) -> AudioFingerprint {
+        let wobble = 1.0 + variant as f64 * 0.02;
+        AudioFingerprint {
+            duration: 1.0,
+            sample_rate: 44100,
+            mfcc_mean: (0..13).map(|i| if i % 3 == family { 10.0 * wobble } else { 0.5 }).collect(),
+            mfcc_std: vec![1.0; 13],
+            spectral_centroid: 1000.0 + 3000.0 * family as f64,
+            spectral_bandwidth: 1500.0,
+            spectral_rolloff: 5000.0,
+            rms_mean: 0.2,
+            rms_std: 0.05,
+            zero_crossing_rate: 0.1,
+            chroma_mean: vec![0.5; 12],
+            noise: None,
+            stereo: None,
+        }
+    }
+
+    #[test]
+    fn test_cluster_library() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut families = vec![Vec::new(); 3];
+        for variant in 0..4 {
+            for (family, ids) in families.iter_mut().enumerate() {
+                let name = format!("{}_{variant:02}.wav", ["Kick", "Snare", "Hat"][family]);
+                let id = db.add_sound(&format!("/lib/{name}"), &name, 1.0, 44100, 1, "wav").unwrap();
+                db.store_fingerprint(id, &fingerprint(family, variant)).unwrap();
+                // Most of the snares are tagged as rimshots
+                if family == 1 && variant < 3 {
+                    db.add_tag(id, "rimshot").unwrap();
+                }
+                ids.push(id);
+            }
+        }
+        let unfingerprinted = db.add_sound("/lib/new.wav", "new.wav", 1.0, 44100, 1, "wav").unwrap();
+
+        let mut updates = Vec::new();
+        let summary = db.cluster_library(3, |p| updates.push(p.clone())).unwrap();
+        assert_eq!((summary.clusters, summary.sounds), (3, 12));
+        assert!(updates.last().unwrap().finished);
+
+        // Each family lands in a cluster of its own
+        for ids in &families {
+            let cluster = db.get_sound_cluster(ids[0]).unwrap().unwrap();
+            let members: Vec<i64> = db.get_cluster_sounds(cluster).unwrap().iter().map(|s| s.id).collect();
+            assert_eq!(members, *ids);
+        }
+        let clusters = db.get_clusters().unwrap();
+        assert_eq!(clusters.iter().map(|c| c.size).collect::<Vec<_>>(), vec![4, 4, 4]);
+        assert!(clusters.iter().all(|c| c.representative.is_some()));
+        assert_eq!(db.get_sound_cluster(unfingerprinted).unwrap(), None);
+
+        // Labels come from a dominant tag, else a shared filename word
+        let label = |ids: &[i64]| {
+            let cluster = db.get_sound_cluster(ids[0]).unwrap().unwrap();
+            clusters.iter().find(|c| c.id == cluster).unwrap().label.clone()
+        };
+        let labels: Vec<Option<String>> = families.iter().map(|ids| label(ids)).collect();
+        assert_eq!(labels, ["kick", "rimshot", "hat"].map(|l| Some(l.to_string())));
+
+        // Clusters become subcategories of "Auto", replaced on every write
+        assert_eq!(db.write_cluster_categories("Auto").unwrap(), 3);
+        assert_eq!(db.write_cluster_categories("Auto").unwrap(), 3);
+        let categorized: Vec<(String, i64)> = {
+            let conn = db.reader().unwrap();
+            let mut stmt = conn
+                .prepare(
+                    "SELECT c.name, COUNT(*) FROM categories c JOIN sound_categories sc ON sc.category_id = c.id
+                     WHERE c.parent_id = (SELECT id FROM categories WHERE name = 'Auto') GROUP BY c.id ORDER BY c.name",
+                )
+                .unwrap();
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+            rows.collect::<rusqlite::Result<_>>().unwrap()
+        };
+        assert_eq!(categorized, [("hat", 4), ("kick", 4), ("rimshot", 4)].map(|(n, c)| (n.to_string(), c)));
+
+        // Removed sounds drop out, representatives included
+        let representative = clusters[0].representative.as_ref().unwrap().id;
+        db.remove_sound(representative).unwrap();
+        let cluster = db.get_clusters().unwrap().into_iter().find(|c| c.id == clusters[0].id).unwrap();
+        assert_eq!(cluster.size, 3);
+        assert!(cluster.representative.is_some_and(|r| r.id != representative));
+
+        // Reclustering replaces the previous run; the silhouette finds the three families
+        let summary = db.cluster_library(0, |_| {}).unwrap();
+        assert_eq!((summary.clusters, summary.sounds), (3, 11));
+    }
+}
@@ -0,0 +1,336 @@
+//! Ordered collections of sounds
+//!
+//! A collection is a user-curated, ordered list of items. Each item points
+//! at a sound and optionally narrows it to a segment (e.g. the matched
+//! region of a segment search). Positions are kept contiguous from 0, so
+//! clients can treat them as list indices.
+
+use super::{qualified_sound_columns, sound_from_row, PaletteDatabase, SOUND_COLUMN_COUNT};
+use crate::{AudioPaletteError, MatchResult, Result, SoundRecord};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// A collection with its item count
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collection {
+    pub id: i64,
+    pub name: String,
+    pub description: String,
+    pub date_created: String,
+    pub item_count: i64,
+}
+
+/// A sound (or a segment of one) to add to a collection
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CollectionEntry {
+    pub sound_id: i64,
+    /// Segment bounds in seconds; None for the whole sound
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+}
+
+impl CollectionEntry {
+    pub fn sound(sound_id: i64) -> Self {
+        CollectionEntry { sound_id, start_time: None, end_time: None }
+    }
+}
+
+impl From<&MatchResult> for CollectionEntry {
+    /// Keep only the matched region of a search result
+    fn from(m: &MatchResult) -> Self {
+        CollectionEntry {
+            sound_id: m.sound_id,
+            start_time: Some(m.match_start),
+            end_time: Some(m.match_end),
+        }
+    }
+}
+
+/// An entry of a collection, in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionItem {
+    pub id: i64,
+    pub position: i64,
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+    pub sound: SoundRecord,
+}
+
+fn collection_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::CollectionError(e.to_string())
+}
+
+fn validate_name(name: &str) -> Result<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(collection_error("collection name is empty"));
+    }
+    Ok(name)
+}
+
+fn validate_entry(entry: &CollectionEntry) -> Result<()> {
+    match (entry.start_time, entry.end_time) {
+        (None, None) => Ok(()),
+        (Some(start), Some(end)) if start >= 0.0 && end > start => Ok(()),
+        _ => Err(collection_error(format!(
+            "invalid segment {:?}..{:?} for sound {}",
+            entry.start_time, entry.end_time, entry.sound_id
+        ))),
+    }
+}
+
+/// Item ids of a collection in position order
+pub(super) fn item_ids(conn: &Connection, collection_id: i64) -> rusqlite::Result<Vec<i64>> {
+    conn.prepare_cached("SELECT id FROM collection_items WHERE collection_id = ?1 ORDER BY position, id")?
+        .query_map(params![collection_id], |row| row.get(0))?
+        .collect()
+}
+
+/// Rewrite positions so `ids` occupy 0..n in order
+pub(super) fn write_positions(conn: &Connection, ids: &[i64]) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare_cached("UPDATE collection_items SET position = ?2 WHERE id = ?1")?;
+    for (position, id) in ids.iter().enumerate() {
+        stmt.execute(params![id, position as i64])?;
+    }
+    Ok(())
+}
+
+/// Collection an item belongs to
+fn item_collection(conn: &Connection, item_id: i64) -> Result<i64> {
+    conn.query_row("SELECT collection_id FROM collection_items WHERE id = ?1", params![item_id], |row| row.get(0))
+        .optional()?
+        .ok_or_else(|| collection_error(format!("no collection item {}", item_id)))
+}
+
+impl PaletteDatabase {
+    /// Create an empty collection, returning its id
+    pub fn create_collection(&self, name: &str, description: &str) -> Result<i64> {
+        let name = validate_name(name)?;
+        let conn = self.writer();
+        conn.execute(
+            "INSERT INTO collections (name, description) VALUES (?1, ?2)",
+            params![name, description],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Change a collection's name and description
+    pub fn update_collection(&self, collection_id: i64, name: &str, description: &str) -> Result<()> {
+        let name = validate_name(name)?;
+        let changed = self.writer().execute(
+            "UPDATE collections SET name = ?2, description = ?3 WHERE id = ?1",
+            params![collection_id, name, description],
+        )?;
+        if changed == 0 {
+            return Err(collection_error(format!("no collection {}", collection_id)));
+        }
+        Ok(())
+    }
+
+    /// Delete a collection and its items (the sounds stay in the library)
+    pub fn delete_collection(&self, collection_id: i64) -> Result<()> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM collection_items WHERE collection_id = ?1", params![collection_id])?;
+        tx.execute("DELETE FROM collections WHERE id = ?1", params![collection_id])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// List all collections by name
+    pub fn get_collections(&self) -> Result<Vec<Collection>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.name, c.description, c.date_created, COUNT(ci.id)
+             FROM collections c LEFT JOIN collection_items ci ON ci.collection_id = c.id
+             GROUP BY c.id ORDER BY c.name COLLATE NOCASE, c.id"
+        )?;
+
+        let collections = stmt
+            .query_map([], |row| {
+                Ok(Collection {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    description: row.get(2)?,
+                    date_created: row.get(3)?,
+                    item_count: row.get(4)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(collections)
+    }
+
+    /// Append entries to the end of a collection, returning the new item ids
+    ///
+    /// The same sound may appear several times (e.g. different segments).
+    pub fn add_to_collection(&self, collection_id: i64, entries: &[CollectionEntry]) -> Result<Vec<i64>> {
+        for entry in entries {
+            validate_entry(entry)?;
+        }
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let exists: Option<i64> = tx
+            .query_row("SELECT id FROM collections WHERE id = ?1", params![collection_id], |row| row.get(0))
+            .optional()?;
+        if exists.is_none() {
+            return Err(collection_error(format!("no collection {}", collection_id)));
+        }
+
+        let mut position: i64 = tx.query_row(
+            "SELECT COALESCE(MAX(position) + 1, 0) FROM collection_items WHERE collection_id = ?1",
+            params![collection_id],
+            |row| row.get(0),
+        )?;
+
+        let mut ids = Vec::with_capacity(entries.len());
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO collection_items (collection_id, sound_id, position, start_time, end_time)
+                 SELECT ?1, id, ?3, ?4, ?5 FROM sounds WHERE id = ?2",
+            )?;
+            for entry in entries {
+                let inserted =
+                    stmt.execute(params![collection_id, entry.sound_id, position, entry.start_time, entry.end_time])?;
+                if inserted == 0 {
+                    return Err(collection_error(format!("no sound {}", entry.sound_id)));
+                }
+                ids.push(tx.last_insert_rowid());
+                position += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(ids)
+    }
+
+    /// Remove an item from its collection
+    pub fn remove_from_collection(&self, item_id: i64) -> Result<()> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let collection_id = item_collection(&tx, item_id)?;
+        tx.execute("DELETE FROM collection_items WHERE id = ?1", params![item_id])?;
+        write_positions(&tx, &item_ids(&tx, collection_id)?)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Move an item to `position` within its collection (clamped to the end)
+    pub fn move_collection_item(&self, item_id: i64, position: usize) -> Result<()> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let collection_id = item_collection(&tx, item_id)?;
+
+        let mut ids = item_ids(&tx, collection_id)?;
+        ids.retain(|&id| id != item_id);
+        ids.insert(position.min(ids.len()), item_id);
+        write_positions(&tx, &ids)?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Put a collection's items in the given order
+    ///
+    /// `order` must list every item of the collection exactly once.
+    pub fn reorder_collection(&self, collection_id: i64, order: &[i64]) -> Result<()> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+
+        let mut current = item_ids(&tx, collection_id)?;
+        let mut requested = order.to_vec();
+        current.sort_unstable();
+        requested.sort_unstable();
+        if current != requested {
+            return Err(collection_error(format!(
+                "new order must contain each of the {} items of collection {} once",
+                current.len(),
+                collection_id
+            )));
+        }
+
+        write_positions(&tx, order)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Get a collection's items in order
+    pub fn get_collection_items(&self, collection_id: i64) -> Result<Vec<CollectionItem>> {
+        let conn = self.reader()?;
+        let columns = qualified_sound_columns("s");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {columns}, ci.id, ci.position, ci.start_time, ci.end_time
+             FROM collection_items ci JOIN sounds s ON s.id = ci.sound_id
+             WHERE ci.collection_id = ?1 ORDER BY ci.position, ci.id"
+        ))?;
+
+        let items = stmt
+            .query_map(params![collection_id], |row| {
+                Ok(CollectionItem {
+                    sound: sound_from_row(row)?,
+                    id: row.get(SOUND_COLUMN_COUNT)?,
+                    position: row.get(SOUND_COLUMN_COUNT + 1)?,
+                    start_time: row.get(SOUND_COLUMN_COUNT + 2)?,
+                    end_time: row.get(SOUND_COLUMN_COUNT + 3)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collections() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let kick = db.add_sound("/test/kick.wav", "kick.wav", 0.5, 44100, 1, "wav").unwrap();
+        let snare = db.add_sound("/test/snare.wav", "snare.wav", 0.4, 44100, 1, "wav").unwrap();
+        let pad = db.add_sound("/test/pad.wav", "pad.wav", 8.0, 44100, 2, "wav").unwrap();
+
+        let id = db.create_collection("  Drums ", "").unwrap();
+        assert!(db.create_collection(" ", "").is_err());
+
+        let items = db
+            .add_to_collection(id, &[
+                CollectionEntry::sound(kick),
+                CollectionEntry::sound(snare),
+                CollectionEntry { sound_id: pad, start_time: Some(1.0), end_time: Some(2.5) },
+            ])
+            .unwrap();
+        assert!(db.add_to_collection(id, &[CollectionEntry::sound(999)]).is_err());
+        assert!(db
+            .add_to_collection(id, &[CollectionEntry { sound_id: pad, start_time: Some(2.0), end_time: Some(1.0) }])
+            .is_err());
+
+        let collections = db.get_collections().unwrap();
+        assert_eq!(collections[0].name, "Drums");
+        assert_eq!(collections[0].item_count, 3);
+
+        // Move the pad segment to the front
+        db.move_collection_item(items[2], 0).unwrap();
+        let listed = db.get_collection_items(id).unwrap();
+        assert_eq!(listed.iter().map(|i| i.id).collect::<Vec<_>>(), vec![items[2], items[0], items[1]]);
+        assert_eq!(listed[0].end_time, Some(2.5));
+        assert_eq!(listed[0].sound.filename, "pad.wav");
+
+        db.reorder_collection(id, &[items[1], items[0], items[2]]).unwrap();
+        assert!(db.reorder_collection(id, &[items[0], items[1]]).is_err());
+
+        // Positions stay contiguous after removals, including via remove_sound
+        db.remove_from_collection(items[1]).unwrap();
+        db.remove_sound(pad).unwrap();
+        let listed = db.get_collection_items(id).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!((listed[0].id, listed[0].position), (items[0], 0));
+
+        db.delete_collection(id).unwrap();
+        assert!(db.get_collections().unwrap().is_empty());
+        assert_eq!(db.count().unwrap(), 2);
+    }
+}
@@ -0,0 +1,105 @@
+//! Near-duplicate detection
+//!
+//! `find_duplicate_groups` only catches byte-identical files. Different
+//! encodes, trims or bitrates of the same sample hash differently but
+//! fingerprint almost identically, so they are found by similarity: each
+//! sound's nearest stored neighbours above the threshold are linked, and
+//! linked sounds form a group.
+
+use super::PaletteDatabase;
+use crate::{Result, SoundRecord};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Neighbours looked at per sound; a group can still grow larger through
+/// its members' own neighbours
+const NEIGHBOURS: usize = 20;
+
+/// Union-find root of `i`, flattening the path on the way
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+impl PaletteDatabase {
+    /// Groups of perceptually near-identical sounds: every member scores
+    /// at least `threshold` (0-100) against some other member
+    ///
+    /// Groups come largest first, members in id order (usually the
+    /// original first).
+    pub fn find_near_duplicate_groups(&self, threshold: f64) -> Result<Vec<Vec<SoundRecord>>> {
+        let fingerprints = self.get_all_fingerprints()?;
+        let index: HashMap<i64, usize> = fingerprints.iter().enumerate().map(|(i, (id, _))| (*id, i)).collect();
+
+        let links: Vec<(usize, usize)> = fingerprints
+            .par_iter()
+            .enumerate()
+            .map(|(i, (id, fp))| {
+                let neighbours = self.nearest_fingerprints(fp, threshold, NEIGHBOURS + 1, 0.0, 0.0)?;
+                Ok(neighbours
+                    .into_iter()
+                    .filter(|m| m.sound_id != *id)
+                    .filter_map(|m| index.get(&m.sound_id).map(|&j| (i, j)))
+                    .collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let mut parents: Vec<usize> = (0..fingerprints.len()).collect();
+        for (a, b) in links {
+            let (a, b) = (root(&mut parents, a), root(&mut parents, b));
+            parents[a.max(b)] = a.min(b);
+        }
+        let mut members: HashMap<usize, Vec<i64>> = HashMap::new();
+        for (i, (id, _)) in fingerprints.iter().enumerate() {
+            members.entry(root(&mut parents, i)).or_default().push(*id);
+        }
+
+        let mut groups: Vec<Vec<i64>> = members.into_values().filter(|ids| ids.len() > 1).collect();
+        for ids in &mut groups {
+            ids.sort_unstable();
+        }
+        groups.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+        groups
+            .into_iter()
+            .map(|ids| {
+                let sounds = ids.into_iter().map(|id| self.get_sound(id)).collect::<Result<Vec<_>>>()?;
+                Ok(sounds.into_iter().flatten().collect())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::test_util::fingerprint;
+
+    #[test]
+    fn test_near_duplicate_groups() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        // Two encodes of one kick, a lone snare, three trims of one pad
+        let seeds = [1.0, 1.0005, 2.0, 3.0, 3.0005, 3.001];
+        let ids: Vec<i64> = seeds
+            .iter()
+            .enumerate()
+            .map(|(i, &seed)| {
+                let id = db.add_sound(&format!("/lib/{i}.wav"), &format!("{i}.wav"), 1.0, 44100, 1, "wav").unwrap();
+                db.store_fingerprint(id, &fingerprint(seed)).unwrap();
+                id
+            })
+            .collect();
+
+        let groups = db.find_near_duplicate_groups(99.9).unwrap();
+        let group_ids: Vec<Vec<i64>> = groups.iter().map(|g| g.iter().map(|s| s.id).collect()).collect();
+        assert_eq!(group_ids, vec![vec![ids[3], ids[4], ids[5]], vec![ids[0], ids[1]]]);
+
+        // None of them is bit-for-bit the same sound
+        assert!(db.find_near_duplicate_groups(100.0).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+//! Optional at-rest encryption with SQLCipher
+//!
+//! Encrypted palettes are whole-file encrypted (pages, WAL and indexes)
+//! with a key derived from the user's passphrase. Every connection, the
+//! pooled readers included, must be keyed before its first statement.
+//!
+//! Requires the `encryption` cargo feature, which builds SQLCipher in place
+//! of plain SQLite; without it, opening an encrypted palette returns an
+//! error.
+
+use crate::{AudioPaletteError, Result};
+use rusqlite::Connection;
+
+/// Whether this build can open encrypted palettes
+pub const ENCRYPTION_SUPPORTED: bool = cfg!(feature = "encryption");
+
+fn encryption_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::EncryptionError(e.to_string())
+}
+
+/// Key a freshly opened connection
+///
+/// A new file is created encrypted with this passphrase; an existing one
+/// must have been created with it.
+#[cfg(feature = "encryption")]
+pub(super) fn unlock(conn: &Connection, passphrase: &str) -> Result<()> {
+    if passphrase.is_empty() {
+        return Err(encryption_error("passphrase is empty"));
+    }
+    conn.pragma_update(None, "key", passphrase)?;
+    // SQLCipher only notices a wrong key when it first reads a page
+    conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(()))
+        .map_err(|_| encryption_error("wrong passphrase, or not an encrypted palette"))
+}
+
+#[cfg(not(feature = "encryption"))]
+pub(super) fn unlock(_conn: &Connection, _passphrase: &str) -> Result<()> {
+    Err(encryption_error("built without encryption support (enable the `encryption` feature)"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::PaletteDatabase;
+
+    #[test]
+    fn test_encrypted_palette() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("masters.db");
+
+        let db = PaletteDatabase::open_encrypted(&path, "correct horse");
+        if !ENCRYPTION_SUPPORTED {
+            assert!(matches!(db, Err(AudioPaletteError::EncryptionError(_))));
+            return;
+        }
+        let db = db.unwrap();
+        db.add_sound("/masters/final mix.wav", "final mix.wav", 180.0, 48000, 2, "wav").unwrap();
+        assert_eq!(db.search("final").unwrap().len(), 1);
+        drop(db);
+
+        let header = std::fs::read(&path).unwrap();
+        assert!(!header.starts_with(b"SQLite format 3"));
+
+        assert!(matches!(
+            PaletteDatabase::open_encrypted(&path, "wrong"),
+            Err(AudioPaletteError::EncryptionError(_))
+        ));
+        assert!(PaletteDatabase::open(&path).is_err());
+
+        let db = PaletteDatabase::open_encrypted(&path, "correct horse").unwrap();
+        assert_eq!(db.count().unwrap(), 1);
+        assert_eq!(db.search("mix").unwrap()[0].filename, "final mix.wav");
+        assert!(db.open_readers() > 0);
+    }
+}
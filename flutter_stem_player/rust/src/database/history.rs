@@ -0,0 +1,395 @@
+//! Change history with undo
+//!
+//! Adding and removing sounds, editing tags and editing metadata each
+//! record an entry holding what is needed to reverse it, in the same
+//! transaction as the change. Indexing and imports record one entry per
+//! new sound; automatic tagging runs (`replace_tags_batch`), merges and
+//! archive imports are not recorded. Only the newest `HISTORY_LIMIT`
+//! entries are kept.
+//!
+//! Undoing an entry restores the state it recorded, overwriting any later
+//! change to the same data; undo itself is not recorded.
+
+use super::regions::{region_from_row, REGION_COLUMNS};
+use super::{
+    delete_sound, insert_fingerprint, insert_sound_tag, sound_from_row, PaletteDatabase, Region, SoundProperty,
+    SOUND_COLUMNS,
+};
+use crate::fingerprint::{AudioFingerprint, FINGERPRINT_VERSION};
+use crate::{AudioPaletteError, FileStamp, Result, SoundRecord, SoundTag};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// Entries kept; older ones are dropped as new ones are recorded
+pub const HISTORY_LIMIT: i64 = 10_000;
+
+/// Kind of change an entry records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HistoryOperation {
+    Add,
+    Remove,
+    Retag,
+    EditMetadata,
+}
+
+impl HistoryOperation {
+    fn name(self) -> &'static str {
+        match self {
+            HistoryOperation::Add => "add",
+            HistoryOperation::Remove => "remove",
+            HistoryOperation::Retag => "retag",
+            HistoryOperation::EditMetadata => "edit_metadata",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [HistoryOperation::Add, HistoryOperation::Remove, HistoryOperation::Retag, HistoryOperation::EditMetadata]
+            .into_iter()
+            .find(|op| op.name() == name)
+    }
+}
+
+/// A recorded change, newest first in `get_history`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub operation: HistoryOperation,
+    pub sound_id: i64,
+    /// File name of the sound at the time (kept after it is removed)
+    pub filename: String,
+    pub timestamp: String,
+    pub undone: bool,
+}
+
+/// Editable metadata of a sound before an edit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct MetadataSnapshot {
+    title: String,
+    artist: String,
+    notes: String,
+    source: String,
+    rating: u8,
+}
+
+/// Everything `remove_sound` deletes that undo can put back (collection
+/// memberships, usage counters and cached waveforms are not restored)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct SoundSnapshot {
+    sound: SoundRecord,
+    content_hash: Option<String>,
+    file_stamp: Option<FileStamp>,
+    tags: Vec<SoundTag>,
+    fingerprint: Option<AudioFingerprint>,
+    regions: Vec<Region>,
+    properties: Vec<SoundProperty>,
+}
+
+/// A change, with the state to restore on undo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) enum Change {
+    Added,
+    Removed(Box<SoundSnapshot>),
+    Retagged(Vec<SoundTag>),
+    MetadataEdited(MetadataSnapshot),
+}
+
+impl Change {
+    fn operation(&self) -> HistoryOperation {
+        match self {
+            Change::Added => HistoryOperation::Add,
+            Change::Removed(_) => HistoryOperation::Remove,
+            Change::Retagged(_) => HistoryOperation::Retag,
+            Change::MetadataEdited(_) => HistoryOperation::EditMetadata,
+        }
+    }
+}
+
+fn history_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::HistoryError(e.to_string())
+}
+
+/// Record a change to `sound_id` (skipped if there is no such sound)
+pub(super) fn record(conn: &Connection, sound_id: i64, change: &Change) -> Result<()> {
+    let filename: Option<String> = match change {
+        Change::Removed(snapshot) => Some(snapshot.sound.filename.clone()),
+        _ => conn
+            .prepare_cached("SELECT filename FROM sounds WHERE id = ?1")?
+            .query_row(params![sound_id], |row| row.get(0))
+            .optional()?,
+    };
+    let Some(filename) = filename else {
+        return Ok(());
+    };
+
+    let details = serde_json::to_string(change).map_err(history_error)?;
+    conn.prepare_cached("INSERT INTO history (operation, sound_id, filename, details) VALUES (?1, ?2, ?3, ?4)")?
+        .execute(params![change.operation().name(), sound_id, filename, details])?;
+    conn.prepare_cached("DELETE FROM history WHERE id <= ?1 - ?2")?
+        .execute(params![conn.last_insert_rowid(), HISTORY_LIMIT])?;
+    Ok(())
+}
+
+pub(super) fn sound_tags(conn: &Connection, sound_id: i64) -> rusqlite::Result<Vec<SoundTag>> {
+    conn.prepare_cached(
+        "SELECT t.name, st.confidence, st.source FROM sound_tags st JOIN tags t ON t.id = st.tag_id
+         WHERE st.sound_id = ?1 ORDER BY t.name",
+    )?
+    .query_map(params![sound_id], |row| {
+        Ok(SoundTag { name: row.get(0)?, confidence: row.get(1)?, source: row.get(2)? })
+    })?
+    .collect()
+}
+
+pub(super) fn metadata(conn: &Connection, sound_id: i64) -> rusqlite::Result<Option<MetadataSnapshot>> {
+    conn.prepare_cached("SELECT title, artist, notes, source, rating FROM sounds WHERE id = ?1")?
+        .query_row(params![sound_id], |row| {
+            Ok(MetadataSnapshot {
+                title: row.get(0)?,
+                artist: row.get(1)?,
+                notes: row.get(2)?,
+                source: row.get(3)?,
+                rating: row.get(4)?,
+            })
+        })
+        .optional()
+}
+
+pub(super) fn snapshot(conn: &Connection, sound_id: i64) -> Result<Option<SoundSnapshot>> {
+    let row = conn
+        .query_row(
+            &format!("SELECT {SOUND_COLUMNS}, content_hash, file_size, file_mtime FROM sounds WHERE id = ?1"),
+            params![sound_id],
+            |row| {
+                let size: Option<i64> = row.get(16)?;
+                let mtime: Option<i64> = row.get(17)?;
+                Ok((sound_from_row(row)?, row.get(15)?, size.zip(mtime).map(|(size, mtime)| FileStamp { size, mtime })))
+            },
+        )
+        .optional()?;
+    let Some((sound, content_hash, file_stamp)) = row else {
+        return Ok(None);
+    };
+
+    let fingerprint = conn
+        .query_row(
+            "SELECT fingerprint FROM fingerprints WHERE sound_id = ?1 AND feature_version = ?2",
+            params![sound_id, FINGERPRINT_VERSION],
+            |row| row.get::<_, Option<Vec<u8>>>(0),
+        )
+        .optional()?
+        .flatten()
+        .and_then(|bytes| AudioFingerprint::from_bytes(&bytes).ok());
+    let regions = conn
+        .prepare(&format!("SELECT {REGION_COLUMNS} FROM regions WHERE sound_id = ?1"))?
+        .query_map(params![sound_id], region_from_row)?
+        .collect::<rusqlite::Result<_>>()?;
+    let properties = conn
+        .prepare("SELECT key, value FROM sound_properties WHERE sound_id = ?1")?
+        .query_map(params![sound_id], |row| Ok(SoundProperty { key: row.get(0)?, value: row.get(1)? }))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(Some(SoundSnapshot {
+        tags: sound_tags(conn, sound_id)?,
+        sound,
+        content_hash,
+        file_stamp,
+        fingerprint,
+        regions,
+        properties,
+    }))
+}
+
+fn entry_from_row(row: &rusqlite::Row) -> rusqlite::Result<HistoryEntry> {
+    let operation: String = row.get(1)?;
+    Ok(HistoryEntry {
+        id: row.get(0)?,
+        operation: HistoryOperation::from_name(&operation).ok_or_else(|| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, operation.into())
+        })?,
+        sound_id: row.get(2)?,
+        filename: row.get(3)?,
+        timestamp: row.get(4)?,
+        undone: row.get(5)?,
+    })
+}
+
+const ENTRY_COLUMNS: &str = "id, operation, sound_id, filename, timestamp, undone";
+
+fn restore_tags(conn: &Connection, sound_id: i64, tags: &[SoundTag]) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM sound_tags WHERE sound_id = ?1", params![sound_id])?;
+    for tag in tags {
+        insert_sound_tag(conn, sound_id, &tag.name, tag.confidence, &tag.source)?;
+    }
+    Ok(())
+}
+
+fn restore_sound(conn: &Connection, snapshot: &SoundSnapshot) -> Result<()> {
+    let sound = &snapshot.sound;
+    let taken: Option<i64> = conn
+        .query_row(
+            "SELECT id FROM sounds WHERE id = ?1 OR filepath = store_path(?2)",
+            params![sound.id, sound.filepath],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if taken.is_some() {
+        return Err(history_error(format!("{} is in the library again", sound.filepath)));
+    }
+
+    conn.execute(
+        "INSERT INTO sounds (id, filepath, filename, duration, sample_rate, channels, format, date_added, title, artist,
+             notes, source, rating, bpm, musical_key, content_hash, file_size, file_mtime)
+         VALUES (?1, store_path(?2), ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+        params![
+            sound.id,
+            sound.filepath,
+            sound.filename,
+            sound.duration,
+            sound.sample_rate,
+            sound.channels,
+            sound.format,
+            sound.date_added,
+            sound.title,
+            sound.artist,
+            sound.notes,
+            sound.source,
+            sound.rating,
+            (sound.bpm > 0.0).then_some(sound.bpm),
+            (!sound.musical_key.is_empty()).then_some(&sound.musical_key),
+            snapshot.content_hash,
+            snapshot.file_stamp.map(|s| s.size),
+            snapshot.file_stamp.map(|s| s.mtime)
+        ],
+    )?;
+    restore_tags(conn, sound.id, &snapshot.tags)?;
+    if let Some(fp) = &snapshot.fingerprint {
+        insert_fingerprint(conn, sound.id, fp)?;
+    }
+    for region in &snapshot.regions {
+        conn.execute(
+            "INSERT INTO regions (sound_id, start_time, end_time, label, color) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![sound.id, region.start_time, region.end_time, region.label, region.color],
+        )?;
+    }
+    for property in &snapshot.properties {
+        conn.execute(
+            "INSERT INTO sound_properties (sound_id, key, value) VALUES (?1, ?2, ?3)",
+            params![sound.id, property.key, property.value],
+        )?;
+    }
+    Ok(())
+}
+
+impl PaletteDatabase {
+    /// Recorded changes, newest first
+    pub fn get_history(&self, limit: i64) -> Result<Vec<HistoryEntry>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(&format!("SELECT {ENTRY_COLUMNS} FROM history ORDER BY id DESC LIMIT ?1"))?;
+        let entries = stmt.query_map(params![limit], entry_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(entries)
+    }
+
+    /// Reverse a recorded change, returning the entry (now marked undone)
+    pub fn undo(&self, entry_id: i64) -> Result<HistoryEntry> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let (entry, details): (HistoryEntry, String) = tx
+            .query_row(
+                &format!("SELECT {ENTRY_COLUMNS}, details FROM history WHERE id = ?1"),
+                params![entry_id],
+                |row| Ok((entry_from_row(row)?, row.get(6)?)),
+            )
+            .optional()?
+            .ok_or_else(|| history_error(format!("no history entry {}", entry_id)))?;
+        if entry.undone {
+            return Err(history_error(format!("history entry {} is already undone", entry_id)));
+        }
+
+        let exists = |id: i64| -> Result<()> {
+            match tx.query_row("SELECT 1 FROM sounds WHERE id = ?1", params![id], |_| Ok(())).optional()? {
+                Some(()) => Ok(()),
+                None => Err(history_error(format!("sound {} is no longer in the library", id))),
+            }
+        };
+        match serde_json::from_str(&details).map_err(history_error)? {
+            Change::Added => {
+                exists(entry.sound_id)?;
+                delete_sound(&tx, entry.sound_id)?;
+            }
+            Change::Removed(snapshot) => restore_sound(&tx, &snapshot)?,
+            Change::Retagged(tags) => {
+                exists(entry.sound_id)?;
+                restore_tags(&tx, entry.sound_id, &tags)?;
+            }
+            Change::MetadataEdited(before) => {
+                exists(entry.sound_id)?;
+                tx.execute(
+                    "UPDATE sounds SET title = ?2, artist = ?3, notes = ?4, source = ?5, rating = ?6 WHERE id = ?1",
+                    params![entry.sound_id, before.title, before.artist, before.notes, before.source, before.rating],
+                )?;
+            }
+        }
+        tx.execute("UPDATE history SET undone = 1 WHERE id = ?1", params![entry_id])?;
+        tx.commit()?;
+        Ok(HistoryEntry { undone: true, ..entry })
+    }
+
+    /// Undo the most recent change not yet undone (None if there is none)
+    pub fn undo_last(&self) -> Result<Option<HistoryEntry>> {
+        let last: Option<i64> = self
+            .reader()?
+            .query_row("SELECT MAX(id) FROM history WHERE undone = 0", [], |row| row.get(0))?;
+        last.map(|id| self.undo(id)).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SoundMetadataUpdate;
+
+    #[test]
+    fn test_history_and_undo() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound("/lib/thunder.wav", "thunder.wav", 8.0, 48000, 2, "wav").unwrap();
+        db.add_sound("/lib/thunder.wav", "thunder.wav", 8.0, 48000, 2, "wav").unwrap();
+        db.add_tag(id, "storm").unwrap();
+        db.update_sound_metadata(id, &SoundMetadataUpdate {
+            title: Some("Thunder roll".to_string()),
+            ..SoundMetadataUpdate::default()
+        })
+        .unwrap();
+        db.add_region(id, 1.0, 3.0, "crack", "").unwrap();
+        db.set_property(id, "license", "CC0").unwrap();
+        db.remove_sound(id).unwrap();
+
+        // Re-adding an indexed path is not a change
+        let ops: Vec<HistoryOperation> = db.get_history(10).unwrap().iter().map(|e| e.operation).collect();
+        assert_eq!(
+            ops,
+            [HistoryOperation::Remove, HistoryOperation::EditMetadata, HistoryOperation::Retag, HistoryOperation::Add]
+        );
+
+        // Undoing the removal restores the sound with its tags, metadata and regions
+        let undone = db.undo_last().unwrap().unwrap();
+        assert_eq!(undone.operation, HistoryOperation::Remove);
+        assert_eq!((undone.filename.as_str(), undone.undone), ("thunder.wav", true));
+        let sound = db.get_sound(id).unwrap().unwrap();
+        assert_eq!(sound.title, "Thunder roll");
+        assert_eq!(db.get_sound_tags(id).unwrap()[0].name, "storm");
+        assert_eq!(db.get_regions(id).unwrap().len(), 1);
+        assert_eq!(db.get_property(id, "license").unwrap().as_deref(), Some("CC0"));
+        assert_eq!(db.search("storm").unwrap().len(), 1);
+
+        db.undo_last().unwrap();
+        assert_eq!(db.get_sound(id).unwrap().unwrap().title, "");
+        db.undo_last().unwrap();
+        assert!(db.get_sound_tags(id).unwrap().is_empty());
+        assert!(matches!(db.undo(undone.id), Err(AudioPaletteError::HistoryError(_))));
+
+        db.undo_last().unwrap();
+        assert_eq!(db.count().unwrap(), 0);
+        assert_eq!(db.undo_last().unwrap(), None);
+        assert!(db.get_history(10).unwrap().iter().all(|e| e.undone));
+    }
+}
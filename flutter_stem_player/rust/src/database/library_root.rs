@@ -0,0 +1,182 @@
+//! Portable libraries: paths relative to a library root
+//!
+//! With a library root set, paths under it are stored relative to it (with
+//! '/' separators) and resolved against the current root whenever they
+//! are read. A palette kept on an external drive then survives the drive
+//! being mounted elsewhere (another drive letter or mount point): set the
+//! root to the new location and every sound resolves there. Paths outside
+//! the root stay absolute.
+//!
+//! Resolution happens inside SQLite through two functions registered on
+//! every connection, so queries select `resolve_path(filepath)` and
+//! write or compare against `store_path(?)`.
+
+use super::PaletteDatabase;
+use crate::{AudioPaletteError, Result};
+use rusqlite::functions::FunctionFlags;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// The current root, shared by the writer and every pooled reader
+pub(super) type SharedRoot = Arc<RwLock<Option<PathBuf>>>;
+
+/// Conversion between stored and resolved path forms
+type PathConversion = fn(&str, Option<&Path>) -> String;
+
+const ROOT_SETTING: &str = "library_root";
+
+fn root_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::LibraryRootError(e.to_string())
+}
+
+/// Stored form of `path`: relative with '/' separators if under `root`
+fn to_stored(path: &str, root: Option<&Path>) -> String {
+    let relative = root.and_then(|root| Path::new(path).strip_prefix(root).ok());
+    match relative {
+        Some(rest) if Path::new(path).is_absolute() && rest.components().next().is_some() => rest
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/"),
+        _ => path.to_string(),
+    }
+}
+
+/// Absolute form of a stored path
+fn to_resolved(stored: &str, root: Option<&Path>) -> String {
+    match root {
+        Some(root) if !Path::new(stored).is_absolute() => {
+            stored.split('/').fold(root.to_path_buf(), |path, part| path.join(part)).to_string_lossy().to_string()
+        }
+        _ => stored.to_string(),
+    }
+}
+
+/// Register `resolve_path` and `store_path` on a connection
+pub(super) fn register_functions(conn: &Connection, root: &SharedRoot) -> rusqlite::Result<()> {
+    let functions: [(&str, PathConversion); 2] =
+        [("resolve_path", to_resolved), ("store_path", to_stored)];
+    for (name, convert) in functions {
+        let root = Arc::clone(root);
+        conn.create_scalar_function(name, 1, FunctionFlags::SQLITE_UTF8, move |ctx| {
+            let path: Option<String> = ctx.get(0)?;
+            let root = root.read().unwrap_or_else(|e| e.into_inner());
+            Ok(path.map(|p| convert(&p, root.as_deref())))
+        })?;
+    }
+    Ok(())
+}
+
+/// The library root saved in a palette
+pub(super) fn load(conn: &Connection) -> rusqlite::Result<Option<PathBuf>> {
+    let root: Option<String> = conn
+        .query_row("SELECT value FROM settings WHERE key = ?1", params![ROOT_SETTING], |row| row.get(0))
+        .optional()?;
+    Ok(root.map(PathBuf::from))
+}
+
+impl PaletteDatabase {
+    /// The library root, if the palette is portable
+    pub fn library_root(&self) -> Option<String> {
+        let root = self.library_root.read().unwrap_or_else(|e| e.into_inner());
+        root.as_ref().map(|r| r.to_string_lossy().to_string())
+    }
+
+    /// Make the palette portable with paths under `root` stored relative to
+    /// it, or with None store every path absolute again
+    ///
+    /// Paths already stored relative keep their relative part, so after a
+    /// drive moves, setting the new mount point is all it takes. Returns
+    /// how many stored paths changed form.
+    pub fn set_library_root(&self, root: Option<&str>) -> Result<usize> {
+        let root = match root.map(|r| r.trim()) {
+            Some(r) if !Path::new(r).is_absolute() => return Err(root_error(format!("{} is not an absolute path", r))),
+            Some(r) => Some(PathBuf::from(r)),
+            None => None,
+        };
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let previous = self.library_root.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let result = (|| -> rusqlite::Result<usize> {
+            match &root {
+                Some(root) => {
+                    *self.library_root.write().unwrap_or_else(|e| e.into_inner()) = Some(root.clone());
+                    tx.execute(
+                        "INSERT OR REPLACE INTO settings (key, value) VALUES (?1, ?2)",
+                        params![ROOT_SETTING, root.to_string_lossy()],
+                    )?;
+                    tx.execute(
+                        "UPDATE sounds SET filepath = store_path(filepath) WHERE filepath != store_path(filepath)",
+                        [],
+                    )
+                }
+                None => {
+                    let changed = tx.execute(
+                        "UPDATE sounds SET filepath = resolve_path(filepath) WHERE filepath != resolve_path(filepath)",
+                        [],
+                    )?;
+                    *self.library_root.write().unwrap_or_else(|e| e.into_inner()) = None;
+                    tx.execute("DELETE FROM settings WHERE key = ?1", params![ROOT_SETTING])?;
+                    Ok(changed)
+                }
+            }
+        })();
+
+        match result.and_then(|changed| tx.commit().map(|_| changed)) {
+            Ok(changed) => Ok(changed),
+            Err(e) => {
+                *self.library_root.write().unwrap_or_else(|e| e.into_inner()) = previous;
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_library_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let (old_mount, new_mount) = (dir.path().join("E"), dir.path().join("F"));
+        let old_kick = old_mount.join("drums").join("kick.wav");
+        let old_kick = old_kick.to_str().unwrap();
+        let db_path = dir.path().join("palette.db");
+
+        let db = PaletteDatabase::open(&db_path).unwrap();
+        let kick = db.add_sound(old_kick, "kick.wav", 0.5, 44100, 1, "wav").unwrap();
+        let outside = db.add_sound("/elsewhere/pad.wav", "pad.wav", 8.0, 44100, 2, "wav").unwrap();
+        assert!(db.set_library_root(Some("relative/root")).is_err());
+        assert_eq!(db.set_library_root(Some(old_mount.to_str().unwrap())).unwrap(), 1);
+
+        // Stored relative, read back absolute; lookups by absolute path still work
+        let stored: String = db
+            .writer()
+            .query_row("SELECT filepath FROM sounds WHERE id = ?1", params![kick], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, "drums/kick.wav");
+        assert_eq!(db.get_sound(kick).unwrap().unwrap().filepath, old_kick);
+        assert_eq!(db.add_sound(old_kick, "kick.wav", 0.5, 44100, 1, "wav").unwrap(), kick);
+        drop(db);
+
+        // The drive comes back under another mount point
+        let db = PaletteDatabase::open(&db_path).unwrap();
+        assert_eq!(db.library_root().as_deref(), old_mount.to_str());
+        assert_eq!(db.set_library_root(Some(new_mount.to_str().unwrap())).unwrap(), 0);
+        let new_kick = new_mount.join("drums").join("kick.wav");
+        assert_eq!(db.get_sound(kick).unwrap().unwrap().filepath, new_kick.to_str().unwrap());
+        assert_eq!(db.get_indexed_files().unwrap()[0].filepath, new_kick.to_str().unwrap());
+        assert_eq!(db.get_sound(outside).unwrap().unwrap().filepath, "/elsewhere/pad.wav");
+
+        // Leaving portable mode stores absolute paths again
+        assert_eq!(db.set_library_root(None).unwrap(), 1);
+        assert_eq!(db.library_root(), None);
+        assert_eq!(db.get_sound(kick).unwrap().unwrap().filepath, new_kick.to_str().unwrap());
+    }
+}
@@ -0,0 +1,143 @@
+//! Locality-sensitive hashing of fingerprint vectors
+//!
+//! Exact scoring reads and compares a full vector per stored sound. Each
+//! fingerprint also keeps a 64-bit signature: one bit per random
+//! hyperplane, set when the vector lies on its positive side. Two vectors
+//! disagree on a bit with probability proportional to the angle between
+//! them, so the stored signatures fewest bits away from the query's are a
+//! cheap shortlist that exact scoring then ranks. Hyperplanes are derived
+//! from fixed seeds, so signatures never need recomputing, and the
+//! shortlist is computed from one integer per sound without any index
+//! beyond the column itself.
+
+use super::{vector_index, PaletteDatabase};
+use crate::fingerprint::{AudioFingerprint, FINGERPRINT_VERSION};
+use crate::Result;
+use rusqlite::{params, Connection};
+
+/// Bits per signature
+pub const LSH_BITS: usize = 64;
+/// Shortlisted candidates per result asked for, at least `LSH_MIN_CANDIDATES`
+const LSH_OVERSAMPLING: usize = 8;
+const LSH_MIN_CANDIDATES: usize = 200;
+
+/// Uniform in (0, 1] from a seed (splitmix64)
+fn unit(seed: u64) -> f64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+}
+
+/// Normal hyperplanes for vectors of `dims` dimensions, Gaussian
+/// components by Box-Muller so every direction is equally likely
+fn hyperplanes(dims: usize) -> Vec<Vec<f32>> {
+    (0..LSH_BITS as u64)
+        .map(|bit| {
+            (0..dims as u64)
+                .map(|dim| {
+                    let seed = (bit << 32 | dim) * 2;
+                    let (u, v) = (unit(seed), unit(seed + 1));
+                    ((-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()) as f32
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Signature of a feature vector
+pub(super) fn signature(vector: &[f32]) -> i64 {
+    hyperplanes(vector.len()).iter().enumerate().fold(0u64, |bits, (bit, plane)| {
+        let side: f32 = plane.iter().zip(vector).map(|(p, x)| p * x).sum();
+        if side > 0.0 { bits | 1 << bit } else { bits }
+    }) as i64
+}
+
+/// Sign stored vectors that have no signature yet; returns how many
+pub(super) fn backfill(conn: &Connection) -> rusqlite::Result<usize> {
+    let rows: Vec<(i64, Vec<u8>)> = {
+        let mut stmt = conn.prepare("SELECT sound_id, vector FROM fingerprints WHERE vector IS NOT NULL AND lsh_signature IS NULL")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+    let mut update = conn.prepare("UPDATE fingerprints SET lsh_signature = ?2 WHERE sound_id = ?1")?;
+    for (sound_id, vector) in &rows {
+        update.execute(params![sound_id, signature(&vector_index::unpack_vector(vector))])?;
+    }
+    Ok(rows.len())
+}
+
+impl PaletteDatabase {
+    /// Shortlist of sounds for `limit` results most similar to `query`
+    /// (among `among` if given): those whose signatures differ from the
+    /// query's in the fewest bits, nearest first
+    ///
+    /// The shortlist is several times `limit`; rank it exactly with
+    /// `nearest_fingerprints_among`. Large limits shortlist everything.
+    pub fn lsh_candidates(&self, query: &AudioFingerprint, among: Option<&[i64]>, limit: usize) -> Result<Vec<i64>> {
+        let (vector, _) = super::vectors::fingerprint_vectors(query);
+        let query_signature = signature(&vector_index::unpack_vector(&vector));
+
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(match among {
+            Some(_) => {
+                "SELECT sound_id, lsh_signature FROM fingerprints
+                 WHERE lsh_signature IS NOT NULL AND feature_version = ?1
+                   AND sound_id IN (SELECT value FROM json_each(?2))"
+            }
+            None => {
+                "SELECT sound_id, lsh_signature FROM fingerprints
+                 WHERE lsh_signature IS NOT NULL AND feature_version = ?1"
+            }
+        })?;
+        let map = |row: &rusqlite::Row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?));
+        let signed: Vec<(i64, i64)> = match among {
+            Some(ids) => {
+                let ids = format!("[{}]", ids.iter().map(i64::to_string).collect::<Vec<_>>().join(","));
+                stmt.query_map(params![FINGERPRINT_VERSION, ids], map)?.collect::<rusqlite::Result<_>>()?
+            }
+            None => stmt.query_map([FINGERPRINT_VERSION], map)?.collect::<rusqlite::Result<_>>()?,
+        };
+
+        let mut ranked: Vec<(u32, i64)> =
+            signed.into_iter().map(|(id, sig)| ((sig ^ query_signature).count_ones(), id)).collect();
+        let count = limit.saturating_mul(LSH_OVERSAMPLING).max(LSH_MIN_CANDIDATES);
+        if ranked.len() > count {
+            ranked.select_nth_unstable(count);
+            ranked.truncate(count);
+        }
+        ranked.sort_unstable();
+        Ok(ranked.into_iter().map(|(_, id)| id).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::test_util::fingerprint;
+
+    #[test]
+    fn test_lsh_candidates() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut ids = Vec::new();
+        for i in 0..500 {
+            let id = db.add_sound(&format!("/lib/{i}.wav"), &format!("{i}.wav"), 1.0, 44100, 1, "wav").unwrap();
+            db.store_fingerprint(id, &fingerprint(1.0 + i as f64 * 0.37)).unwrap();
+            ids.push(id);
+        }
+
+        // A stored sound shortlists itself first
+        let query = fingerprint(1.0 + 250.0 * 0.37);
+        let shortlist = db.lsh_candidates(&query, None, 5).unwrap();
+        assert_eq!(shortlist.len(), LSH_MIN_CANDIDATES);
+        assert_eq!(shortlist[0], ids[250]);
+        // ...and the exact top five are all on it
+        let exact = db.nearest_fingerprints(&query, 0.0, 5, 0.0, 0.0).unwrap();
+        assert!(exact.iter().all(|m| shortlist.contains(&m.sound_id)));
+
+        // Restricted shortlists and large limits
+        assert_eq!(db.lsh_candidates(&query, Some(&ids[..10]), 5).unwrap().len(), 10);
+        assert_eq!(db.lsh_candidates(&query, None, usize::MAX).unwrap().len(), 500);
+    }
+}
@@ -0,0 +1,211 @@
+//! Database maintenance
+//!
+//! Palettes that see many add/remove cycles accumulate free pages,
+//! fragmented indexes and stale planner statistics. `optimize` rebuilds
+//! them in place; it holds the writer for the duration, so other writes
+//! wait, while WAL readers keep working until the final VACUUM.
+//! `check_integrity` only reads, and can run alongside other work.
+
+use super::{decode_json_fingerprint, PaletteDatabase};
+use crate::fingerprint::AudioFingerprint;
+use crate::Result;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+/// One step of `optimize`, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptimizeStep {
+    /// Refresh the query planner's statistics
+    Analyze,
+    /// Rebuild every index
+    Reindex,
+    /// Merge the full-text index segments
+    SearchIndex,
+    /// Rewrite the file without free pages
+    Vacuum,
+}
+
+impl OptimizeStep {
+    pub const ALL: [OptimizeStep; 4] =
+        [OptimizeStep::Analyze, OptimizeStep::Reindex, OptimizeStep::SearchIndex, OptimizeStep::Vacuum];
+
+    fn run(self, conn: &Connection) -> rusqlite::Result<()> {
+        match self {
+            OptimizeStep::Analyze => conn.execute_batch("ANALYZE"),
+            OptimizeStep::Reindex => conn.execute_batch("REINDEX"),
+            OptimizeStep::SearchIndex => conn.execute_batch("INSERT INTO sounds_fts(sounds_fts) VALUES ('optimize')"),
+            OptimizeStep::Vacuum => conn.execute_batch("VACUUM"),
+        }
+    }
+}
+
+/// Progress of an `optimize` run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OptimizeProgress {
+    /// Step currently running (the last step once finished)
+    pub step: OptimizeStep,
+    /// Steps completed so far
+    pub completed: usize,
+    pub total: usize,
+    pub finished: bool,
+}
+
+/// Database size before and after `optimize`, in bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OptimizeSummary {
+    pub size_before: i64,
+    pub size_after: i64,
+}
+
+/// Result of `check_integrity`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// Problems reported by SQLite's own check (empty when the file is sound)
+    pub sqlite_errors: Vec<String>,
+    /// Sounds that have no fingerprint, so similarity search can't find them
+    pub missing_fingerprints: Vec<i64>,
+    /// Sounds whose stored fingerprint can't be decoded
+    pub unreadable_fingerprints: Vec<i64>,
+    /// Fingerprint rows whose sound no longer exists
+    pub orphaned_fingerprints: Vec<i64>,
+}
+
+impl IntegrityReport {
+    pub fn is_ok(&self) -> bool {
+        self.sqlite_errors.is_empty()
+            && self.missing_fingerprints.is_empty()
+            && self.unreadable_fingerprints.is_empty()
+            && self.orphaned_fingerprints.is_empty()
+    }
+}
+
+fn database_size(conn: &Connection) -> rusqlite::Result<i64> {
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+    Ok(page_count * page_size)
+}
+
+impl PaletteDatabase {
+    /// Run ANALYZE, REINDEX, an FTS merge and VACUUM, reporting progress
+    /// before each step and once when done
+    pub fn optimize(&self, mut progress: impl FnMut(&OptimizeProgress)) -> Result<OptimizeSummary> {
+        let conn = self.writer();
+        let size_before = database_size(&conn)?;
+
+        let total = OptimizeStep::ALL.len();
+        for (completed, step) in OptimizeStep::ALL.into_iter().enumerate() {
+            progress(&OptimizeProgress { step, completed, total, finished: false });
+            step.run(&conn)?;
+        }
+        // Fold the rewritten pages back into the main file
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        progress(&OptimizeProgress { step: OptimizeStep::Vacuum, completed: total, total, finished: true });
+
+        Ok(OptimizeSummary { size_before, size_after: database_size(&conn)? })
+    }
+
+    /// Run SQLite's integrity check and verify every sound has a decodable
+    /// fingerprint and every fingerprint a sound
+    pub fn check_integrity(&self) -> Result<IntegrityReport> {
+        let conn = self.reader()?;
+        let mut report = IntegrityReport::default();
+
+        let messages = conn
+            .prepare("PRAGMA integrity_check")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        report.sqlite_errors = messages.into_iter().filter(|m| m != "ok").collect();
+
+        let mut stmt = conn.prepare(
+            "SELECT s.id, f.sound_id, f.fingerprint, f.fingerprint_json
+             FROM sounds s LEFT JOIN fingerprints f ON f.sound_id = s.id
+             UNION ALL
+             SELECT NULL, f.sound_id, NULL, NULL
+             FROM fingerprints f WHERE f.sound_id NOT IN (SELECT id FROM sounds)
+             ORDER BY 2",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let sound_id: Option<i64> = row.get(0)?;
+            let fingerprint_id: Option<i64> = row.get(1)?;
+            match (sound_id, fingerprint_id) {
+                (Some(id), None) => report.missing_fingerprints.push(id),
+                (None, Some(id)) => report.orphaned_fingerprints.push(id),
+                (Some(id), Some(_)) => {
+                    let readable = match (row.get::<_, Option<Vec<u8>>>(2)?, row.get::<_, Option<String>>(3)?) {
+                        (Some(blob), _) => AudioFingerprint::from_bytes(&blob).is_ok(),
+                        (None, Some(json)) => decode_json_fingerprint(&json).is_ok(),
+                        (None, None) => false,
+                    };
+                    if !readable {
+                        report.unreadable_fingerprints.push(id);
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+        report.missing_fingerprints.sort_unstable();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optimize() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = PaletteDatabase::open(dir.path().join("palette.db")).unwrap();
+        let ids: Vec<i64> = (0..200)
+            .map(|i| {
+                let name = format!("long descriptive sample name number {} for padding.wav", i);
+                db.add_sound(&format!("/library/{}", name), &name, 1.0, 44100, 2, "wav").unwrap()
+            })
+            .collect();
+        for id in &ids[..180] {
+            db.remove_sound(*id).unwrap();
+        }
+
+        let mut steps = Vec::new();
+        let summary = db.optimize(|p| steps.push(p.clone())).unwrap();
+        assert_eq!(steps.len(), OptimizeStep::ALL.len() + 1);
+        assert_eq!(steps[0].step, OptimizeStep::Analyze);
+        assert!(steps.last().unwrap().finished);
+        assert_eq!(steps.last().unwrap().completed, steps.last().unwrap().total);
+        assert!(summary.size_after < summary.size_before, "{:?}", summary);
+
+        // Still fully usable afterwards
+        assert_eq!(db.count().unwrap(), 20);
+        assert_eq!(db.search("number 190").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let ids: Vec<i64> = ["ok", "missing", "corrupt"]
+            .iter()
+            .map(|name| db.add_sound(&format!("/kit/{}.wav", name), &format!("{}.wav", name), 1.0, 44100, 1, "wav").unwrap())
+            .collect();
+        let fp = crate::search::SearchEngine::new()
+            .fingerprint_samples(&(0..44100).map(|i| (i as f32 * 0.03).sin()).collect::<Vec<_>>(), 44100)
+            .unwrap();
+        db.store_fingerprint(ids[0], &fp).unwrap();
+        assert_eq!(db.check_integrity().unwrap().missing_fingerprints, vec![ids[1], ids[2]]);
+
+        {
+            let conn = db.writer();
+            conn.execute("INSERT INTO fingerprints (sound_id, fingerprint) VALUES (?1, x'00ff')", [ids[2]]).unwrap();
+            // Orphans come from libraries written before foreign keys were enforced
+            conn.pragma_update(None, "foreign_keys", false).unwrap();
+            conn.execute("INSERT INTO fingerprints (sound_id, fingerprint) VALUES (999, x'00ff')", []).unwrap();
+            conn.pragma_update(None, "foreign_keys", true).unwrap();
+        }
+        let report = db.check_integrity().unwrap();
+        assert!(report.sqlite_errors.is_empty());
+        assert_eq!(report.missing_fingerprints, vec![ids[1]]);
+        assert_eq!(report.unreadable_fingerprints, vec![ids[2]]);
+        assert_eq!(report.orphaned_fingerprints, vec![999]);
+        assert!(!report.is_ok());
+    }
+}
@@ -0,0 +1,479 @@
+//! Merging another palette database into this one
+//!
+//! Sounds are matched by path and then by content hash, so the same audio
+//! indexed on two machines (under different paths) becomes one sound.
+//! Collections are matched by name: entries are appended to an existing
+//! collection of the same name, otherwise the collection is created.
+//! Categories are matched by name under the same parent, so a category
+//! tree present in both palettes is joined rather than duplicated.
+
+use super::{
+    decode_json_fingerprint, insert_fingerprint, insert_sound, insert_sound_tag, migrations, normalize_tag, IndexedFile,
+    PaletteDatabase, BUSY_TIMEOUT,
+};
+use crate::fingerprint::{AudioFingerprint, FINGERPRINT_VERSION};
+use crate::{NewSound, Result};
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// What to do with a sound both palettes contain
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictPolicy {
+    /// Keep this palette's metadata and fingerprint; add the other's tags
+    #[default]
+    KeepExisting,
+    /// Take the other palette's metadata (title, artist, notes, source,
+    /// rating, tempo, key) and fingerprint, and add its tags
+    PreferIncoming,
+    /// Leave the sound exactly as it is here
+    Skip,
+}
+
+/// Result of a merge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeSummary {
+    /// Sounds new to this palette
+    pub added: usize,
+    /// Sounds both palettes contain, merged according to the policy
+    pub merged: usize,
+    /// Sounds both palettes contain, left untouched (`ConflictPolicy::Skip`)
+    pub skipped: usize,
+    /// Collections created or extended
+    pub collections: usize,
+    /// Categories new to this palette
+    pub categories: usize,
+}
+
+impl PaletteDatabase {
+    /// Import sounds, fingerprints, tags, categories and collections from
+    /// the palette at `other_path`, deduplicating by path and content hash
+    ///
+    /// The other palette is never written: one at this build's schema is
+    /// read in place, an older one through an upgraded scratch copy.
+    /// Everything is written in one transaction, so a failed merge leaves
+    /// this palette unchanged.
+    pub fn merge_database<P: AsRef<Path>>(&self, other_path: P, policy: ConflictPolicy) -> Result<MergeSummary> {
+        let (other, _scratch) = open_source(other_path.as_ref())?;
+        let mut files: HashMap<i64, IndexedFile> = other.get_indexed_files()?.into_iter().map(|f| (f.id, f)).collect();
+        let mut fingerprints = read_fingerprints(&other)?;
+        let sounds = other.get_all_sounds()?;
+        let mut tags = HashMap::with_capacity(sounds.len());
+        for sound in &sounds {
+            tags.insert(sound.id, other.get_sound_tags(sound.id)?);
+        }
+        let mut collections = Vec::new();
+        for collection in other.get_collections()? {
+            let items = other.get_collection_items(collection.id)?;
+            collections.push((collection, items));
+        }
+        let (categories, sound_categories) = {
+            let conn = other.reader()?;
+            let mut stmt = conn.prepare("SELECT id, name, parent_id FROM categories ORDER BY id")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+            let categories: Vec<(i64, String, Option<i64>)> = rows.collect::<rusqlite::Result<_>>()?;
+            let mut stmt = conn.prepare("SELECT sound_id, category_id FROM sound_categories")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            let sound_categories: Vec<(i64, i64)> = rows.collect::<rusqlite::Result<_>>()?;
+            (categories, sound_categories)
+        };
+        drop(other);
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let mut summary = MergeSummary::default();
+        // Other palette's sound id -> id here
+        let mut ids: HashMap<i64, i64> = HashMap::with_capacity(sounds.len());
+        // Other palette's ids of sounds left untouched
+        let mut skipped = HashSet::new();
+
+        for record in &sounds {
+            let file = files.remove(&record.id);
+            let hash = file.as_ref().and_then(|f| f.content_hash.clone());
+            let existing: Option<i64> = tx
+                .query_row(
+                    "SELECT id FROM sounds WHERE filepath = store_path(?1) OR (?2 IS NOT NULL AND content_hash = ?2)
+                     ORDER BY filepath = store_path(?1) DESC LIMIT 1",
+                    params![record.filepath, hash],
+                    |row| row.get(0),
+                )
+                .optional()?;
+
+            let (id, incoming) = match (existing, policy) {
+                (Some(id), ConflictPolicy::Skip) => {
+                    summary.skipped += 1;
+                    ids.insert(record.id, id);
+                    skipped.insert(record.id);
+                    continue;
+                }
+                (Some(id), ConflictPolicy::KeepExisting) => {
+                    summary.merged += 1;
+                    (id, false)
+                }
+                (Some(id), ConflictPolicy::PreferIncoming) => {
+                    summary.merged += 1;
+                    (id, true)
+                }
+                (None, _) => {
+                    summary.added += 1;
+                    let id = insert_sound(&tx, &NewSound {
+                        filepath: record.filepath.clone(),
+                        filename: record.filename.clone(),
+                        duration: record.duration,
+                        sample_rate: record.sample_rate,
+                        channels: record.channels,
+                        format: record.format.clone(),
+                        content_hash: hash,
+                        file_stamp: file.and_then(|f| f.file_stamp),
+                        bpm: None,
+                        musical_key: None,
+                    })?;
+                    (id, true)
+                }
+            };
+            ids.insert(record.id, id);
+
+            if incoming {
+                tx.execute(
+                    "UPDATE sounds SET title = ?2, artist = ?3, notes = ?4, source = ?5, rating = ?6, bpm = ?7,
+                         musical_key = ?8
+                     WHERE id = ?1",
+                    params![
+                        id,
+                        record.title,
+                        record.artist,
+                        record.notes,
+                        record.source,
+                        record.rating,
+                        (record.bpm > 0.0).then_some(record.bpm),
+                        (!record.musical_key.is_empty()).then_some(&record.musical_key)
+                    ],
+                )?;
+                if let Some(fp) = fingerprints.remove(&record.id) {
+                    insert_fingerprint(&tx, id, &fp)?;
+                }
+            }
+
+            for tag in tags.get(&record.id).into_iter().flatten() {
+                let name = normalize_tag(&tag.name);
+                if !name.is_empty() {
+                    insert_sound_tag(&tx, id, &name, tag.confidence, &tag.source)?;
+                }
+            }
+        }
+
+        // Parents before children: a category waits until its parent is placed
+        let mut category_ids: HashMap<i64, i64> = HashMap::with_capacity(categories.len());
+        let mut pending = categories;
+        while !pending.is_empty() {
+            let before = pending.len();
+            let mut waiting = Vec::new();
+            for (id, name, parent) in pending {
+                let parent_here = match parent {
+                    Some(parent) => match category_ids.get(&parent) {
+                        Some(&here) => Some(here),
+                        None => {
+                            waiting.push((id, name, Some(parent)));
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+                let existing: Option<i64> = tx
+                    .query_row(
+                        "SELECT id FROM categories WHERE name = ?1 COLLATE NOCASE AND parent_id IS ?2
+                         ORDER BY id LIMIT 1",
+                        params![name, parent_here],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                let here = match existing {
+                    Some(here) => here,
+                    None => {
+                        tx.execute(
+                            "INSERT INTO categories (name, parent_id) VALUES (?1, ?2)",
+                            params![name, parent_here],
+                        )?;
+                        summary.categories += 1;
+                        tx.last_insert_rowid()
+                    }
+                };
+                category_ids.insert(id, here);
+            }
+            // Parents that are missing or form a cycle leave the rest out
+            if waiting.len() == before {
+                break;
+            }
+            pending = waiting;
+        }
+        for (sound, category) in sound_categories {
+            if skipped.contains(&sound) {
+                continue;
+            }
+            if let (Some(sound_id), Some(category_id)) = (ids.get(&sound), category_ids.get(&category)) {
+                tx.execute(
+                    "INSERT OR IGNORE INTO sound_categories (sound_id, category_id) VALUES (?1, ?2)",
+                    params![sound_id, category_id],
+                )?;
+            }
+        }
+
+        for (collection, items) in collections {
+            let existing: Option<i64> = tx
+                .query_row(
+                    "SELECT id FROM collections WHERE name = ?1 COLLATE NOCASE ORDER BY id LIMIT 1",
+                    params![collection.name],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let collection_id = match existing {
+                Some(id) => id,
+                None => {
+                    tx.execute(
+                        "INSERT INTO collections (name, description) VALUES (?1, ?2)",
+                        params![collection.name, collection.description],
+                    )?;
+                    tx.last_insert_rowid()
+                }
+            };
+
+            let mut position: i64 = tx.query_row(
+                "SELECT COALESCE(MAX(position) + 1, 0) FROM collection_items WHERE collection_id = ?1",
+                params![collection_id],
+                |row| row.get(0),
+            )?;
+            let mut changed = existing.is_none();
+            for item in items {
+                let Some(&sound_id) = ids.get(&item.sound.id) else { continue };
+                let duplicate: Option<i64> = tx
+                    .query_row(
+                        "SELECT id FROM collection_items
+                         WHERE collection_id = ?1 AND sound_id = ?2 AND start_time IS ?3 AND end_time IS ?4",
+                        params![collection_id, sound_id, item.start_time, item.end_time],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                if duplicate.is_some() {
+                    continue;
+                }
+                tx.execute(
+                    "INSERT INTO collection_items (collection_id, sound_id, position, start_time, end_time)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![collection_id, sound_id, position, item.start_time, item.end_time],
+                )?;
+                position += 1;
+                changed = true;
+            }
+            if changed {
+                summary.collections += 1;
+            }
+        }
+
+        tx.commit()?;
+        Ok(summary)
+    }
+}
+
+/// Open the palette being merged without changing it
+///
+/// Older palettes are copied with `VACUUM INTO` and the copy is upgraded;
+/// it lives in the returned directory until that is dropped. Newer ones
+/// fail with `MigrationError`.
+fn open_source(path: &Path) -> Result<(PaletteDatabase, Option<tempfile::TempDir>)> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX)?;
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    if migrations::stored_version(&conn)? >= migrations::SCHEMA_VERSION {
+        drop(conn);
+        return Ok((PaletteDatabase::open_read_only(path)?, None));
+    }
+
+    let scratch = tempfile::tempdir()?;
+    let copy = scratch.path().join("palette.db");
+    conn.execute("VACUUM INTO ?1", params![copy.to_string_lossy()])?;
+    drop(conn);
+    Ok((PaletteDatabase::open(&copy)?, Some(scratch)))
+}
+
+/// Current-version fingerprints by sound id
+///
+/// Unlike `get_all_fingerprints`, rows still stored as JSON are decoded
+/// without being rewritten, since the database may be read-only.
+fn read_fingerprints(db: &PaletteDatabase) -> Result<HashMap<i64, AudioFingerprint>> {
+    let conn = db.reader()?;
+    let mut stmt =
+        conn.prepare("SELECT sound_id, fingerprint, fingerprint_json FROM fingerprints WHERE feature_version = ?1")?;
+    let mut rows = stmt.query([FINGERPRINT_VERSION])?;
+    let mut fingerprints = HashMap::new();
+    while let Some(row) = rows.next()? {
+        let (blob, json): (Option<Vec<u8>>, Option<String>) = (row.get(1)?, row.get(2)?);
+        let fingerprint = match (blob, json) {
+            (Some(blob), _) => AudioFingerprint::from_bytes(&blob).ok(),
+            (None, Some(json)) => decode_json_fingerprint(&json).ok(),
+            (None, None) => None,
+        };
+        if let Some(fingerprint) = fingerprint {
+            fingerprints.insert(row.get(0)?, fingerprint);
+        }
+    }
+    Ok(fingerprints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::CollectionEntry;
+    use crate::SoundMetadataUpdate;
+
+    fn new_sound(filepath: &str, hash: &str) -> NewSound {
+        NewSound {
+            filepath: filepath.to_string(),
+            filename: Path::new(filepath).file_name().unwrap().to_string_lossy().to_string(),
+            duration: 0.5,
+            sample_rate: 44100,
+            channels: 1,
+            format: "wav".to_string(),
+            content_hash: Some(hash.to_string()),
+            file_stamp: None,
+            bpm: None,
+            musical_key: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let other_path = dir.path().join("foley.db");
+        {
+            let other = PaletteDatabase::open(&other_path).unwrap();
+            let ids = other
+                .add_sounds_batch(&[
+                    new_sound("/studio-b/kick.wav", "hash-kick"),
+                    new_sound("/studio-b/door.wav", "hash-door"),
+                ])
+                .unwrap();
+            other
+                .update_sound_metadata(ids[0], &SoundMetadataUpdate {
+                    title: Some("Studio B kick".to_string()),
+                    ..SoundMetadataUpdate::default()
+                })
+                .unwrap();
+            other.add_tag(ids[0], "punchy").unwrap();
+            other.add_tag(ids[1], "foley").unwrap();
+            let collection = other.create_collection("Favourites", "").unwrap();
+            other
+                .add_to_collection(collection, &[CollectionEntry::sound(ids[0]), CollectionEntry::sound(ids[1])])
+                .unwrap();
+            let conn = other.writer();
+            conn.execute_batch(
+                "INSERT INTO categories (id, name) VALUES (1, 'drums');
+                 INSERT INTO categories (id, name, parent_id) VALUES (2, 'Kicks', 1);",
+            )
+            .unwrap();
+            conn.execute("INSERT INTO sound_categories (sound_id, category_id) VALUES (?1, 2)", params![ids[0]]).unwrap();
+        }
+        let untouched = std::fs::read(&other_path).unwrap();
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let kick = db.add_sounds_batch(&[new_sound("/studio-a/kick.wav", "hash-kick")]).unwrap()[0];
+        db.update_sound_metadata(kick, &SoundMetadataUpdate {
+            title: Some("My kick".to_string()),
+            ..SoundMetadataUpdate::default()
+        })
+        .unwrap();
+        let favourites = db.create_collection("favourites", "").unwrap();
+        db.add_to_collection(favourites, &[CollectionEntry::sound(kick)]).unwrap();
+        db.writer().execute("INSERT INTO categories (name) VALUES ('Drums')", []).unwrap();
+
+        let summary = db.merge_database(&other_path, ConflictPolicy::KeepExisting).unwrap();
+        assert_eq!(summary, MergeSummary { added: 1, merged: 1, skipped: 0, collections: 1, categories: 1 });
+        assert_eq!(db.count().unwrap(), 2);
+        // The other palette is only read, never migrated or switched to WAL
+        assert_eq!(std::fs::read(&other_path).unwrap(), untouched);
+
+        // Categories join the existing tree and keep their sounds
+        let categorized: Vec<(String, Option<String>, i64)> = {
+            let conn = db.reader().unwrap();
+            let mut stmt = conn
+                .prepare(
+                    "SELECT c.name, p.name, sc.sound_id FROM sound_categories sc
+                     JOIN categories c ON c.id = sc.category_id LEFT JOIN categories p ON p.id = c.parent_id",
+                )
+                .unwrap();
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?))).unwrap();
+            rows.collect::<rusqlite::Result<_>>().unwrap()
+        };
+        assert_eq!(categorized, [("Kicks".to_string(), Some("Drums".to_string()), kick)]);
+
+        // Same content under another path is one sound, with local metadata and both tags
+        let local = db.get_sound(kick).unwrap().unwrap();
+        assert_eq!(local.filepath, "/studio-a/kick.wav");
+        assert_eq!(local.title, "My kick");
+        assert_eq!(db.get_sound_tags(kick).unwrap()[0].name, "punchy");
+
+        // Same-named collection gains only the entries it lacked
+        assert_eq!(db.get_collections().unwrap().len(), 1);
+        let items = db.get_collection_items(favourites).unwrap();
+        let names: Vec<&str> = items.iter().map(|i| i.sound.filename.as_str()).collect();
+        assert_eq!(names, ["kick.wav", "door.wav"]);
+
+        // Merging again adds nothing; preferring incoming takes their metadata
+        let summary = db.merge_database(&other_path, ConflictPolicy::PreferIncoming).unwrap();
+        assert_eq!(summary, MergeSummary { added: 0, merged: 2, skipped: 0, collections: 0, categories: 0 });
+        assert_eq!(db.get_sound(kick).unwrap().unwrap().title, "Studio B kick");
+        assert_eq!(db.get_collection_items(favourites).unwrap().len(), 2);
+
+        let summary = db.merge_database(&other_path, ConflictPolicy::Skip).unwrap();
+        assert_eq!(summary.skipped, 2);
+    }
+
+    #[test]
+    fn test_merge_other_schema_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let other_path = dir.path().join("old.db");
+        let fp = crate::fingerprint::test_util::fingerprint(1.0);
+        {
+            let other = PaletteDatabase::open(&other_path).unwrap();
+            let id = other.add_sound("/studio-b/kick.wav", "kick.wav", 1.0, 44100, 1, "wav").unwrap();
+            other.store_fingerprint(id, &fp).unwrap();
+        }
+        // Fingerprint still stored as JSON, as written by old builds
+        let conn = rusqlite::Connection::open(&other_path).unwrap();
+        conn.execute(
+            "UPDATE fingerprints SET fingerprint = NULL, fingerprint_json = ?1",
+            params![serde_json::to_string(&fp).unwrap()],
+        )
+        .unwrap();
+        drop(conn);
+        let untouched = std::fs::read(&other_path).unwrap();
+
+        // Read in place without converting it
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        assert_eq!(db.merge_database(&other_path, ConflictPolicy::KeepExisting).unwrap().added, 1);
+        let kick = db.get_all_sounds().unwrap()[0].id;
+        assert_eq!(db.get_fingerprint(kick).unwrap().unwrap().spectral_centroid, fp.spectral_centroid);
+        assert_eq!(std::fs::read(&other_path).unwrap(), untouched);
+
+        // An older palette is read through an upgraded copy
+        let old_version = crate::database::migrations::SCHEMA_VERSION - 1;
+        let conn = rusqlite::Connection::open(&other_path).unwrap();
+        conn.execute("DELETE FROM schema_version WHERE version > ?1", params![old_version]).unwrap();
+        drop(conn);
+        let untouched = std::fs::read(&other_path).unwrap();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        assert_eq!(db.merge_database(&other_path, ConflictPolicy::KeepExisting).unwrap().added, 1);
+        assert_eq!(db.get_all_fingerprints().unwrap().len(), 1);
+        assert_eq!(std::fs::read(&other_path).unwrap(), untouched);
+
+        // A newer one can't be read
+        let conn = rusqlite::Connection::open(&other_path).unwrap();
+        conn.execute(
+            "INSERT INTO schema_version (version, description) VALUES (?1, 'future')",
+            params![crate::database::migrations::SCHEMA_VERSION + 1],
+        )
+        .unwrap();
+        drop(conn);
+        let err = db.merge_database(&other_path, ConflictPolicy::KeepExisting).unwrap_err();
+        assert!(matches!(err, crate::AudioPaletteError::MigrationError(_)));
+    }
+}
@@ -0,0 +1,729 @@
+//! Versioned schema migrations
+//!
+//! Each step upgrades the schema by one version and runs in its own
+//! transaction; the applied versions are recorded in `schema_version`.
+//! Libraries created before version tracking have no `schema_version`
+//! table but may already contain some of these changes, so every step
+//! must be safe to run against a schema that already has it.
+
+use crate::{AudioPaletteError, Result};
+use crate::fingerprint::AudioFingerprint;
+use rusqlite::{Connection, params};
+
+/// A single schema upgrade step
+struct Migration {
+    version: u32,
+    description: &'static str,
+    apply: fn(&Connection) -> rusqlite::Result<()>,
+}
+
+/// Ordered list of migrations; append new steps, never edit applied ones
+const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, description: "initial schema", apply: initial_schema },
+    Migration { version: 2, description: "tags", apply: tags },
+    Migration { version: 3, description: "editable sound metadata", apply: sound_metadata },
+    Migration { version: 4, description: "full-text search index", apply: search_index },
+    Migration { version: 5, description: "binary fingerprints", apply: binary_fingerprints },
+    Migration { version: 6, description: "content hashes", apply: content_hashes },
+    Migration { version: 7, description: "collections", apply: collections },
+    Migration { version: 8, description: "file change tracking", apply: file_stamps },
+    Migration { version: 9, description: "ratings", apply: ratings },
+    Migration { version: 10, description: "waveform cache", apply: waveform_cache },
+    Migration { version: 11, description: "tempo and key", apply: tempo_and_key },
+    Migration { version: 12, description: "fingerprint vectors", apply: fingerprint_vectors },
+    Migration { version: 13, description: "usage statistics", apply: usage_statistics },
+    Migration { version: 14, description: "regions", apply: regions },
+    Migration { version: 15, description: "sound properties", apply: sound_properties },
+    Migration { version: 16, description: "change history", apply: history },
+    Migration { version: 17, description: "analysis results", apply: analyses },
+    Migration { version: 18, description: "search history", apply: search_history },
+    Migration { version: 19, description: "smart collections", apply: smart_collections },
+    Migration { version: 20, description: "thumbnails", apply: thumbnails },
+    Migration { version: 21, description: "settings", apply: settings },
+    Migration { version: 22, description: "vector index", apply: vector_index },
+    Migration { version: 23, description: "segment fingerprints", apply: segment_fingerprints },
+    Migration { version: 24, description: "sound clusters", apply: sound_clusters },
+    Migration { version: 25, description: "library revision", apply: library_revision },
+    Migration { version: 26, description: "cluster labels", apply: cluster_labels },
+    Migration { version: 27, description: "sound map", apply: sound_map },
+    Migration { version: 28, description: "lsh signatures", apply: lsh_signatures },
+    Migration { version: 29, description: "fingerprint revisions", apply: fingerprint_revisions },
+    Migration { version: 30, description: "fingerprint feature version", apply: fingerprint_feature_version },
+];
+
+/// Schema version written by this build
+pub const SCHEMA_VERSION: u32 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+/// Bring the database up to `SCHEMA_VERSION`
+pub(crate) fn migrate(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );"
+    )?;
+
+    let current = current_version(conn)?;
+    if current > SCHEMA_VERSION {
+        return Err(AudioPaletteError::MigrationError(format!(
+            "database schema version {} is newer than supported version {}",
+            current, SCHEMA_VERSION
+        )));
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        let tx = conn.unchecked_transaction()?;
+        (migration.apply)(&tx).map_err(|e| {
+            AudioPaletteError::MigrationError(format!(
+                "v{} ({}): {}", migration.version, migration.description, e
+            ))
+        })?;
+        tx.execute(
+            "INSERT INTO schema_version (version, description) VALUES (?1, ?2)",
+            params![migration.version, migration.description],
+        )?;
+        tx.commit()?;
+    }
+
+    Ok(())
+}
+
+/// Highest applied migration (0 for a new or untracked database)
+pub(crate) fn current_version(conn: &Connection) -> Result<u32> {
+    let version: Option<u32> =
+        conn.query_row("SELECT MAX(version) FROM schema_version", [], |row| row.get(0))?;
+    Ok(version.unwrap_or(0))
+}
+
+/// `current_version` without creating `schema_version`, for databases
+/// that are only read
+pub(crate) fn stored_version(conn: &Connection) -> Result<u32> {
+    let tracked: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_version')",
+        [],
+        |row| row.get(0),
+    )?;
+    if tracked { current_version(conn) } else { Ok(0) }
+}
+
+fn has_column(conn: &Connection, table: &str, column: &str) -> rusqlite::Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({table})"))?;
+    let found = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+    Ok(found)
+}
+
+fn initial_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS sounds (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            filepath TEXT NOT NULL UNIQUE,
+            filename TEXT NOT NULL,
+            duration REAL,
+            sample_rate INTEGER,
+            channels INTEGER,
+            format TEXT,
+            date_added TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+
+        CREATE TABLE IF NOT EXISTS fingerprints (
+            sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
+            fingerprint_json TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS categories (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            parent_id INTEGER REFERENCES categories(id)
+        );
+
+        CREATE TABLE IF NOT EXISTS sound_categories (
+            sound_id INTEGER REFERENCES sounds(id) ON DELETE CASCADE,
+            category_id INTEGER REFERENCES categories(id) ON DELETE CASCADE,
+            PRIMARY KEY (sound_id, category_id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_sounds_filepath ON sounds(filepath);
+        CREATE INDEX IF NOT EXISTS idx_sounds_filename ON sounds(filename);
+        "#
+    )
+}
+
+fn tags(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS tags (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE
+        );
+
+        CREATE TABLE IF NOT EXISTS sound_tags (
+            sound_id INTEGER REFERENCES sounds(id) ON DELETE CASCADE,
+            tag_id INTEGER REFERENCES tags(id) ON DELETE CASCADE,
+            confidence REAL NOT NULL DEFAULT 1.0,
+            source TEXT NOT NULL DEFAULT 'user',
+            PRIMARY KEY (sound_id, tag_id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_sound_tags_tag ON sound_tags(tag_id);
+        "#
+    )
+}
+
+fn sound_metadata(conn: &Connection) -> rusqlite::Result<()> {
+    for column in ["title", "artist", "notes", "source"] {
+        if !has_column(conn, "sounds", column)? {
+            conn.execute_batch(&format!(
+                "ALTER TABLE sounds ADD COLUMN {column} TEXT NOT NULL DEFAULT ''"
+            ))?;
+        }
+    }
+    Ok(())
+}
+
+/// Full-text index over the searchable text of each sound (rowid = sound id),
+/// kept current by triggers on `sounds` and `sound_tags`
+fn search_index(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE VIRTUAL TABLE IF NOT EXISTS sounds_fts USING fts5(
+            filename, filepath, title, artist, notes, tags,
+            tokenize = 'unicode61 remove_diacritics 2'
+        );
+
+        CREATE TRIGGER IF NOT EXISTS sounds_fts_insert AFTER INSERT ON sounds BEGIN
+            INSERT INTO sounds_fts (rowid, filename, filepath, title, artist, notes, tags)
+            VALUES (new.id, new.filename, new.filepath, new.title, new.artist, new.notes, '');
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sounds_fts_update
+        AFTER UPDATE OF filename, filepath, title, artist, notes ON sounds BEGIN
+            UPDATE sounds_fts SET filename = new.filename, filepath = new.filepath,
+                title = new.title, artist = new.artist, notes = new.notes
+            WHERE rowid = new.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sounds_fts_delete AFTER DELETE ON sounds BEGIN
+            DELETE FROM sounds_fts WHERE rowid = old.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sounds_fts_tag_insert AFTER INSERT ON sound_tags BEGIN
+            UPDATE sounds_fts SET tags = (
+                SELECT COALESCE(group_concat(t.name, ' '), '') FROM sound_tags st
+                JOIN tags t ON t.id = st.tag_id WHERE st.sound_id = new.sound_id
+            ) WHERE rowid = new.sound_id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sounds_fts_tag_delete AFTER DELETE ON sound_tags BEGIN
+            UPDATE sounds_fts SET tags = (
+                SELECT COALESCE(group_concat(t.name, ' '), '') FROM sound_tags st
+                JOIN tags t ON t.id = st.tag_id WHERE st.sound_id = old.sound_id
+            ) WHERE rowid = old.sound_id;
+        END;
+
+        DELETE FROM sounds_fts;
+        INSERT INTO sounds_fts (rowid, filename, filepath, title, artist, notes, tags)
+        SELECT s.id, s.filename, s.filepath, s.title, s.artist, s.notes,
+               COALESCE((SELECT group_concat(t.name, ' ') FROM sound_tags st
+                         JOIN tags t ON t.id = st.tag_id WHERE st.sound_id = s.id), '')
+        FROM sounds s;
+        "#
+    )
+}
+
+/// Add the `fingerprint` BLOB column and make the legacy JSON column optional
+///
+/// Existing JSON rows are left in place and converted on first read.
+fn binary_fingerprints(conn: &Connection) -> rusqlite::Result<()> {
+    if has_column(conn, "fingerprints", "fingerprint")? {
+        return Ok(());
+    }
+    conn.execute_batch(
+        r#"
+        CREATE TABLE fingerprints_v5 (
+            sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
+            fingerprint BLOB,
+            fingerprint_json TEXT
+        );
+        INSERT INTO fingerprints_v5 (sound_id, fingerprint_json)
+        SELECT sound_id, fingerprint_json FROM fingerprints;
+        DROP TABLE fingerprints;
+        ALTER TABLE fingerprints_v5 RENAME TO fingerprints;
+        "#
+    )
+}
+
+fn content_hashes(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_column(conn, "sounds", "content_hash")? {
+        conn.execute_batch("ALTER TABLE sounds ADD COLUMN content_hash TEXT")?;
+    }
+    conn.execute_batch("CREATE INDEX IF NOT EXISTS idx_sounds_content_hash ON sounds(content_hash)")
+}
+
+fn collections(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS collections (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            date_created TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+
+        CREATE TABLE IF NOT EXISTS collection_items (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            collection_id INTEGER NOT NULL REFERENCES collections(id) ON DELETE CASCADE,
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            position INTEGER NOT NULL,
+            start_time REAL,
+            end_time REAL
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_collection_items_collection ON collection_items(collection_id, position);
+        CREATE INDEX IF NOT EXISTS idx_collection_items_sound ON collection_items(sound_id);
+        "#
+    )
+}
+
+fn file_stamps(conn: &Connection) -> rusqlite::Result<()> {
+    for column in ["file_size", "file_mtime"] {
+        if !has_column(conn, "sounds", column)? {
+            conn.execute_batch(&format!("ALTER TABLE sounds ADD COLUMN {column} INTEGER"))?;
+        }
+    }
+    Ok(())
+}
+
+fn ratings(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_column(conn, "sounds", "rating")? {
+        conn.execute_batch("ALTER TABLE sounds ADD COLUMN rating INTEGER NOT NULL DEFAULT 0")?;
+    }
+    Ok(())
+}
+
+fn waveform_cache(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS waveforms (
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            buckets INTEGER NOT NULL,
+            content_hash TEXT,
+            peaks BLOB NOT NULL,
+            PRIMARY KEY (sound_id, buckets)
+        );
+        "#
+    )
+}
+
+fn tempo_and_key(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_column(conn, "sounds", "bpm")? {
+        conn.execute_batch("ALTER TABLE sounds ADD COLUMN bpm REAL")?;
+    }
+    if !has_column(conn, "sounds", "musical_key")? {
+        conn.execute_batch("ALTER TABLE sounds ADD COLUMN musical_key TEXT")?;
+    }
+    conn.execute_batch(
+        "CREATE INDEX IF NOT EXISTS idx_sounds_bpm ON sounds(bpm);
+         CREATE INDEX IF NOT EXISTS idx_sounds_musical_key ON sounds(musical_key);"
+    )
+}
+
+/// Add the packed feature vectors used for similarity search and fill
+/// them in for existing fingerprints (binary or legacy JSON)
+fn fingerprint_vectors(conn: &Connection) -> rusqlite::Result<()> {
+    for (column, kind) in [("vector", "BLOB"), ("stereo_vector", "BLOB"), ("snr_db", "REAL")] {
+        if !has_column(conn, "fingerprints", column)? {
+            conn.execute_batch(&format!("ALTER TABLE fingerprints ADD COLUMN {column} {kind}"))?;
+        }
+    }
+
+    let rows: Vec<(i64, Option<Vec<u8>>, Option<String>)> = {
+        let mut stmt = conn.prepare("SELECT sound_id, fingerprint, fingerprint_json FROM fingerprints WHERE vector IS NULL")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    let mut update = conn.prepare(
+        "UPDATE fingerprints SET vector = ?2, stereo_vector = ?3, snr_db = ?4 WHERE sound_id = ?1"
+    )?;
+    for (sound_id, blob, json) in rows {
+        let fingerprint = match (blob, json) {
+            (Some(blob), _) => AudioFingerprint::from_bytes(&blob).ok(),
+            (None, Some(json)) => super::decode_json_fingerprint(&json).ok(),
+            (None, None) => None,
+        };
+        // Undecodable rows stay out of similarity search, as before
+        if let Some(fp) = fingerprint {
+            let (vector, stereo_vector) = super::vectors::fingerprint_vectors(&fp);
+            update.execute(params![sound_id, vector, stereo_vector, fp.noise.map(|n| n.snr_db)])?;
+        }
+    }
+    Ok(())
+}
+
+fn usage_statistics(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS sound_usage (
+            sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
+            times_returned INTEGER NOT NULL DEFAULT 0,
+            times_auditioned INTEGER NOT NULL DEFAULT 0,
+            times_exported INTEGER NOT NULL DEFAULT 0,
+            last_used TEXT
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_sound_usage_last_used ON sound_usage(last_used);
+        "#
+    )
+}
+
+fn regions(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS regions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            start_time REAL NOT NULL,
+            end_time REAL NOT NULL,
+            label TEXT NOT NULL DEFAULT '',
+            color TEXT NOT NULL DEFAULT ''
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_regions_sound ON regions(sound_id, start_time);
+        "#
+    )
+}
+
+fn sound_properties(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS sound_properties (
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            key TEXT NOT NULL,
+            value TEXT NOT NULL,
+            PRIMARY KEY (sound_id, key)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_sound_properties_key ON sound_properties(key, value);
+        "#
+    )
+}
+
+/// Undo log; no foreign key, since entries outlive the sounds they describe
+fn history(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            operation TEXT NOT NULL,
+            sound_id INTEGER NOT NULL,
+            filename TEXT NOT NULL,
+            details TEXT NOT NULL,
+            timestamp TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+            undone INTEGER NOT NULL DEFAULT 0
+        );
+        "#
+    )
+}
+
+fn analyses(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS analyses (
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            kind TEXT NOT NULL,
+            content_hash TEXT,
+            hop_seconds REAL NOT NULL,
+            data BLOB NOT NULL,
+            PRIMARY KEY (sound_id, kind)
+        );
+        "#
+    )
+}
+
+fn search_history(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS search_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            kind TEXT NOT NULL,
+            query TEXT NOT NULL,
+            parameters TEXT NOT NULL,
+            result_count INTEGER NOT NULL,
+            timestamp TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+        );
+        "#
+    )
+}
+
+/// Saved queries; `reference` is the fingerprint blob of the query's reference sound
+fn smart_collections(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS smart_collections (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            query TEXT NOT NULL,
+            reference BLOB,
+            date_created TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        );
+        "#
+    )
+}
+
+fn thumbnails(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS thumbnails (
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            kind TEXT NOT NULL,
+            content_hash TEXT,
+            mime_type TEXT NOT NULL,
+            data BLOB NOT NULL,
+            PRIMARY KEY (sound_id, kind)
+        );
+        "#
+    )
+}
+
+/// Palette-wide settings (e.g. the library root of a portable palette)
+fn settings(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+        "#
+    )
+}
+
+/// HNSW neighbour lists (packed i64 sound ids), built from the stored vectors
+fn vector_index(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS vector_links (
+            sound_id INTEGER NOT NULL REFERENCES sounds(id) ON DELETE CASCADE,
+            level INTEGER NOT NULL,
+            neighbors BLOB NOT NULL,
+            PRIMARY KEY (sound_id, level)
+        ) WITHOUT ROWID;
+        CREATE INDEX IF NOT EXISTS idx_vector_links_level ON vector_links(level);
+        "#
+    )?;
+    super::vector_index::build(conn)?;
+    Ok(())
+}
+
+fn segment_fingerprints(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS segment_fingerprints (
+            sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
+            content_hash TEXT,
+            window_seconds REAL NOT NULL,
+            hop_seconds REAL NOT NULL,
+            data BLOB NOT NULL
+        );
+        "#
+    )
+}
+
+fn sound_clusters(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS clusters (
+            id INTEGER PRIMARY KEY,
+            representative_id INTEGER REFERENCES sounds(id) ON DELETE SET NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS sound_clusters (
+            sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
+            cluster_id INTEGER NOT NULL REFERENCES clusters(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_sound_clusters_cluster ON sound_clusters(cluster_id);
+        "#
+    )
+}
+
+/// Counter bumped whenever a sound or fingerprint is added, removed or
+/// replaced, so cached search results can tell they are stale
+fn library_revision(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS library_revision (
+            id INTEGER PRIMARY KEY CHECK (id = 0),
+            revision INTEGER NOT NULL
+        );
+        INSERT OR IGNORE INTO library_revision (id, revision) VALUES (0, 0);
+
+        CREATE TRIGGER IF NOT EXISTS library_revision_sound_insert AFTER INSERT ON sounds BEGIN
+            UPDATE library_revision SET revision = revision + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS library_revision_sound_delete AFTER DELETE ON sounds BEGIN
+            UPDATE library_revision SET revision = revision + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS library_revision_sound_update
+        AFTER UPDATE OF filepath, duration, content_hash ON sounds BEGIN
+            UPDATE library_revision SET revision = revision + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS library_revision_fingerprint_insert AFTER INSERT ON fingerprints BEGIN
+            UPDATE library_revision SET revision = revision + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS library_revision_fingerprint_update AFTER UPDATE ON fingerprints BEGIN
+            UPDATE library_revision SET revision = revision + 1;
+        END;
+        CREATE TRIGGER IF NOT EXISTS library_revision_fingerprint_delete AFTER DELETE ON fingerprints BEGIN
+            UPDATE library_revision SET revision = revision + 1;
+        END;
+        "#
+    )
+}
+
+fn cluster_labels(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_column(conn, "clusters", "label")? {
+        conn.execute("ALTER TABLE clusters ADD COLUMN label TEXT", [])?;
+    }
+    Ok(())
+}
+
+fn sound_map(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS sound_map (
+            sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
+            x REAL NOT NULL,
+            y REAL NOT NULL
+        );
+        "#
+    )
+}
+
+fn lsh_signatures(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_column(conn, "fingerprints", "lsh_signature")? {
+        conn.execute("ALTER TABLE fingerprints ADD COLUMN lsh_signature INTEGER", [])?;
+    }
+    super::lsh::backfill(conn)?;
+    Ok(())
+}
+
+/// Library revision each fingerprint was written at, so an index built at
+/// some revision can catch up on what changed since instead of rebuilding
+fn fingerprint_revisions(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_column(conn, "fingerprints", "revision")? {
+        conn.execute("ALTER TABLE fingerprints ADD COLUMN revision INTEGER NOT NULL DEFAULT 0", [])?;
+    }
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_fingerprints_revision ON fingerprints(revision)", [])?;
+    Ok(())
+}
+
+/// `FINGERPRINT_VERSION` each fingerprint was computed with; rows written
+/// before it existed are version 0 and are left out until re-analyzed
+fn fingerprint_feature_version(conn: &Connection) -> rusqlite::Result<()> {
+    for table in ["fingerprints", "segment_fingerprints"] {
+        if !has_column(conn, table, "feature_version")? {
+            conn.execute(&format!("ALTER TABLE {} ADD COLUMN feature_version INTEGER NOT NULL DEFAULT 0", table), [])?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::PaletteDatabase;
+
+    #[test]
+    fn test_migrate_untracked_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("palette.db");
+
+        let fp = AudioFingerprint {
+            duration: 12.0,
+            sample_rate: 48000,
+            mfcc_mean: vec![1.5; 13],
+            mfcc_std: vec![0.25; 13],
+            spectral_centroid: 900.0,
+            spectral_bandwidth: 400.0,
+            spectral_rolloff: 2000.0,
+            rms_mean: 0.1,
+            rms_std: 0.02,
+            zero_crossing_rate: 0.05,
+            chroma_mean: vec![0.5; 12],
+            noise: None,
+            stereo: None,
+        };
+
+        // Library written by a build without tags, metadata, version tracking
+        // or binary fingerprints
+        {
+            let conn = Connection::open(&path).unwrap();
+            initial_schema(&conn).unwrap();
+            conn.execute(
+                "INSERT INTO sounds (filepath, filename, duration, sample_rate, channels, format)
+                 VALUES ('/rec/ZOOM0041.WAV', 'ZOOM0041.WAV', 12.0, 48000, 2, 'wav')",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO fingerprints (sound_id, fingerprint_json) VALUES (1, ?1)",
+                params![serde_json::to_string(&fp).unwrap()],
+            )
+            .unwrap();
+        }
+
+        let db = PaletteDatabase::open(&path).unwrap();
+        assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+
+        let sound = &db.search("zoom0041").unwrap()[0];
+        assert_eq!(sound.title, "");
+        db.add_tag(sound.id, "field").unwrap();
+
+        // Vectors are filled in for existing fingerprints, but an older
+        // analysis can't be compared until the sound is rescanned
+        let vectors: i64 = db
+            .reader()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM fingerprints WHERE vector IS NOT NULL", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(vectors, 1);
+        assert!(db.nearest_fingerprints(&fp, 99.0, 10, 0.0, 0.0).unwrap().is_empty());
+
+        // JSON fingerprints are read and rewritten as blobs
+        assert!(db.get_all_fingerprints().unwrap().is_empty());
+        drop(db);
+
+        let conn = Connection::open(&path).unwrap();
+        let (blob, json): (Vec<u8>, Option<String>) = conn
+            .query_row("SELECT fingerprint, fingerprint_json FROM fingerprints", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(json, None);
+        assert_eq!(AudioFingerprint::from_bytes(&blob).unwrap().spectral_centroid, 900.0);
+        drop(conn);
+
+        // Reopening is a no-op
+        let db = PaletteDatabase::open(&path).unwrap();
+        assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+        assert_eq!(db.search("field").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_refuses_newer_schema() {
+        let conn = Connection::open_in_memory().unwrap();
+        migrate(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO schema_version (version, description) VALUES (?1, 'future')",
+            params![SCHEMA_VERSION + 1],
+        )
+        .unwrap();
+
+        assert!(matches!(migrate(&conn), Err(AudioPaletteError::MigrationError(_))));
+    }
+}
@@ -1,159 +1,718 @@
 //! SQLite database for sound indexing and fingerprint storage
 
-use crate::{AudioPaletteError, Result, SoundRecord};
-use crate::fingerprint::AudioFingerprint;
-use rusqlite::{Connection, params};
+use crate::{AudioPaletteError, FileStamp, NewSound, Result, SoundMetadataUpdate, SoundRecord, SoundTag, TagSummary};
+use crate::fingerprint::{AudioFingerprint, FINGERPRINT_VERSION};
+use crate::render::WaveformPeaks;
+use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
+use std::collections::HashMap;
+use std::ops::Deref;
 use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::Duration;
+
+mod analyses;
+pub mod archive;
+pub mod clusters;
+pub mod collections;
+mod duplicates;
+mod encryption;
+pub mod history;
+mod library_root;
+mod lsh;
+pub mod maintenance;
+pub mod merge;
+mod migrations;
+mod pool;
+pub mod properties;
+pub mod query;
+pub mod regions;
+pub mod searches;
+mod segments;
+pub mod smart_collections;
+pub mod sound_map;
+mod thumbnails;
+mod top_k;
+pub mod usage;
+mod vector_index;
+mod vectors;
+
+use pool::{PooledConnection, ReaderPool};
+
+pub use archive::{ArchiveOptions, ArchiveSummary, ImportSummary};
+pub use clusters::{ClusterProgress, ClusteringSummary, SoundCluster, MAX_AUTO_CLUSTERS};
+pub use collections::{Collection, CollectionEntry, CollectionItem};
+pub use encryption::ENCRYPTION_SUPPORTED;
+pub use history::{HistoryEntry, HistoryOperation, HISTORY_LIMIT};
+pub use lsh::LSH_BITS;
+pub use maintenance::{IntegrityReport, OptimizeProgress, OptimizeStep, OptimizeSummary};
+pub use merge::{ConflictPolicy, MergeSummary};
+
+pub use migrations::SCHEMA_VERSION;
+pub use properties::SoundProperty;
+pub use query::{SoundPage, SoundQuery, SoundSort};
+pub use regions::Region;
+pub use searches::{RecentSearch, SearchKind, SEARCH_HISTORY_LIMIT};
+pub use smart_collections::{SmartCollection, SmartQuery};
+pub use sound_map::{MapPoint, MapProgress, MAP_LANDMARKS};
+pub use usage::{SoundUsage, UsageEvent, UsedSound};
+pub use vectors::VectorMatch;
+
+/// Columns selected for `SoundRecord` rows (in `sound_from_row` order)
+const SOUND_COLUMNS: &str =
+    "id, resolve_path(filepath), filename, duration, sample_rate, channels, format, date_added, title, artist, notes, \
+     source, rating, bpm, musical_key";
+
+/// Number of columns in `SOUND_COLUMNS`; extra selected columns start here
+const SOUND_COLUMN_COUNT: usize = 15;
+
+/// `SOUND_COLUMNS` qualified with a table alias, for joins
+fn qualified_sound_columns(alias: &str) -> String {
+    SOUND_COLUMNS
+        .split(", ")
+        .map(|c| match c.strip_prefix("resolve_path(") {
+            Some(column) => format!("resolve_path({alias}.{column}"),
+            None => format!("{alias}.{c}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+
+fn sound_from_row(row: &rusqlite::Row) -> rusqlite::Result<SoundRecord> {
+    Ok(SoundRecord {
+        id: row.get(0)?,
+        filepath: row.get(1)?,
+        filename: row.get(2)?,
+        duration: row.get(3)?,
+        sample_rate: row.get(4)?,
+        channels: row.get(5)?,
+        format: row.get(6)?,
+        date_added: row.get(7)?,
+        title: row.get(8)?,
+        artist: row.get(9)?,
+        notes: row.get(10)?,
+        source: row.get(11)?,
+        rating: row.get(12)?,
+        bpm: row.get::<_, Option<f64>>(13)?.unwrap_or(0.0),
+        musical_key: row.get::<_, Option<String>>(14)?.unwrap_or_default(),
+    })
+}
+
+/// bm25 column weights for `sounds_fts` (filename, filepath, title, artist, notes, tags)
+const SEARCH_RANK: &str = "bm25(sounds_fts, 10.0, 1.0, 8.0, 5.0, 3.0, 6.0)";
+
+/// Turn free text into an FTS5 query: every word must match, as a prefix
+fn fts_query(text: &str) -> Option<String> {
+    let terms: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| format!("\"{}\"*", t.to_lowercase()))
+        .collect();
+
+    if terms.is_empty() { None } else { Some(terms.join(" ")) }
+}
+
+/// Sound id, binary and JSON fingerprint, and feature version of a stored row
+type StoredFingerprint = (i64, Option<Vec<u8>>, Option<String>, u32);
+
+fn decode_json_fingerprint(json: &str) -> Result<AudioFingerprint> {
+    serde_json::from_str(json).map_err(|e| AudioPaletteError::FingerprintError(e.to_string()))
+}
+
+/// Insert a sound and return its id
+///
+/// Returns the existing row instead when the path is already indexed, or
+/// when the same content (by hash) is indexed under another path.
+fn insert_sound(conn: &Connection, sound: &NewSound) -> rusqlite::Result<i64> {
+    if let Some(hash) = &sound.content_hash {
+        let duplicate: Option<i64> = conn
+            .prepare_cached("SELECT id FROM sounds WHERE content_hash = ?1 AND filepath != store_path(?2) LIMIT 1")?
+            .query_row(params![hash, sound.filepath], |row| row.get(0))
+            .optional()?;
+        if let Some(id) = duplicate {
+            return Ok(id);
+        }
+    }
+
+    conn.prepare_cached(
+        "INSERT OR IGNORE INTO sounds
+             (filepath, filename, duration, sample_rate, channels, format, content_hash, file_size, file_mtime,
+              bpm, musical_key)
+         VALUES (store_path(?1), ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+    )?
+    .execute(params![
+        sound.filepath,
+        sound.filename,
+        sound.duration,
+        sound.sample_rate,
+        sound.channels,
+        sound.format,
+        sound.content_hash,
+        sound.file_stamp.map(|s| s.size),
+        sound.file_stamp.map(|s| s.mtime),
+        sound.bpm,
+        sound.musical_key
+    ])?;
+
+    conn.prepare_cached("SELECT id FROM sounds WHERE filepath = store_path(?1)")?
+        .query_row(params![sound.filepath], |row| row.get(0))
+}
+
+/// Cache peaks, tagged with the sound's current content hash
+fn insert_waveform(conn: &Connection, sound_id: i64, peaks: &WaveformPeaks) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "INSERT OR REPLACE INTO waveforms (sound_id, buckets, content_hash, peaks)
+         SELECT id, ?2, content_hash, ?3 FROM sounds WHERE id = ?1",
+    )?
+    .execute(params![sound_id, peaks.buckets() as i64, peaks.to_bytes()])?;
+    Ok(())
+}
+
+fn insert_fingerprint(conn: &Connection, sound_id: i64, fingerprint: &AudioFingerprint) -> rusqlite::Result<()> {
+    let (vector, stereo_vector) = vectors::fingerprint_vectors(fingerprint);
+    let unpacked = vector_index::unpack_vector(&vector);
+    conn.prepare_cached(
+        "INSERT OR REPLACE INTO fingerprints
+             (sound_id, fingerprint, vector, stereo_vector, snr_db, lsh_signature, revision, feature_version)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, (SELECT revision + 1 FROM library_revision), ?7)",
+    )?
+    .execute(params![
+        sound_id,
+        fingerprint.to_bytes(),
+        vector,
+        stereo_vector,
+        fingerprint.noise.map(|n| n.snr_db),
+        lsh::signature(&unpacked),
+        FINGERPRINT_VERSION
+    ])?;
+    vector_index::insert(conn, sound_id, &unpacked)
+}
+
+/// Attach an already-normalized tag to a sound
+fn insert_sound_tag(conn: &Connection, sound_id: i64, tag: &str, confidence: f64, source: &str) -> rusqlite::Result<()> {
+    conn.prepare_cached("INSERT OR IGNORE INTO tags (name) VALUES (?1)")?
+        .execute(params![tag])?;
+    conn.prepare_cached(
+        "INSERT OR REPLACE INTO sound_tags (sound_id, tag_id, confidence, source)
+         SELECT ?1, id, ?3, ?4 FROM tags WHERE name = ?2",
+    )?
+    .execute(params![sound_id, tag, confidence, source])?;
+    Ok(())
+}
+
+/// Delete a sound and everything attached to it, keeping the positions of
+/// the collections that held it contiguous
+fn delete_sound(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    let collections: Vec<i64> = conn
+        .prepare("SELECT DISTINCT collection_id FROM collection_items WHERE sound_id = ?1")?
+        .query_map(params![id], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+    conn.execute("DELETE FROM collection_items WHERE sound_id = ?1", params![id])?;
+    for collection_id in collections {
+        collections::write_positions(conn, &collections::item_ids(conn, collection_id)?)?;
+    }
+
+    conn.execute("DELETE FROM sound_tags WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM sound_categories WHERE sound_id = ?1", params![id])?;
+    vector_index::remove(conn, id)?;
+    conn.execute("DELETE FROM fingerprints WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM waveforms WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM sound_usage WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM regions WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM sound_properties WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM analyses WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM thumbnails WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM segment_fingerprints WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM sound_clusters WHERE sound_id = ?1", params![id])?;
+    conn.execute("DELETE FROM sound_map WHERE sound_id = ?1", params![id])?;
+    conn.execute("UPDATE clusters SET representative_id = NULL WHERE representative_id = ?1", params![id])?;
+    conn.execute("DELETE FROM sounds WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+/// Normalize a tag name (trimmed, lowercase)
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+impl WriteBatch<'_> {
+    /// Add sounds, returning their ids in input order (see `add_sounds_batch`)
+    pub(crate) fn add_sounds(&self, sounds: &[NewSound]) -> Result<Vec<i64>> {
+        let newest: i64 = self.conn.query_row("SELECT IFNULL(MAX(id), 0) FROM sounds", [], |row| row.get(0))?;
+        let ids = sounds
+            .iter()
+            .map(|sound| insert_sound(self.conn, sound))
+            .collect::<rusqlite::Result<Vec<i64>>>()?;
+
+        // Ids are never reused, so only rows above the previous newest are new
+        let mut added: Vec<i64> = ids.iter().copied().filter(|&id| id > newest).collect();
+        added.sort_unstable();
+        added.dedup();
+        for id in added {
+            history::record(self.conn, id, &history::Change::Added)?;
+        }
+        Ok(ids)
+    }
+
+    /// Overwrite the analysis-derived fields of existing sounds (see `refresh_sounds_batch`)
+    pub(crate) fn refresh_sounds(&self, sounds: &[(i64, NewSound)]) -> Result<()> {
+        let mut stmt = self.conn.prepare_cached(
+            "UPDATE sounds SET duration = ?2, sample_rate = ?3, channels = ?4, format = ?5,
+                 content_hash = ?6, file_size = ?7, file_mtime = ?8, bpm = ?9, musical_key = ?10
+             WHERE id = ?1",
+        )?;
+        for (id, sound) in sounds {
+            stmt.execute(params![
+                id,
+                sound.duration,
+                sound.sample_rate,
+                sound.channels,
+                sound.format,
+                sound.content_hash,
+                sound.file_stamp.map(|s| s.size),
+                sound.file_stamp.map(|s| s.mtime),
+                sound.bpm,
+                sound.musical_key
+            ])?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn store_fingerprints(&self, fingerprints: &[(i64, AudioFingerprint)]) -> Result<()> {
+        for (sound_id, fingerprint) in fingerprints {
+            insert_fingerprint(self.conn, *sound_id, fingerprint)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn store_waveforms(&self, waveforms: &[(i64, WaveformPeaks)]) -> Result<()> {
+        for (sound_id, peaks) in waveforms {
+            insert_waveform(self.conn, *sound_id, peaks)?;
+        }
+        Ok(())
+    }
+
+    /// Replace the tags written by `source` (see `replace_tags_batch`)
+    pub(crate) fn replace_tags(&self, source: &str, tags: &[(i64, Vec<(String, f64)>)]) -> Result<()> {
+        for (sound_id, sound_tags) in tags {
+            self.conn
+                .prepare_cached("DELETE FROM sound_tags WHERE sound_id = ?1 AND source = ?2")?
+                .execute(params![sound_id, source])?;
+            for (tag, confidence) in sound_tags {
+                let tag = normalize_tag(tag);
+                if !tag.is_empty() {
+                    insert_sound_tag(self.conn, *sound_id, &tag, *confidence, source)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Location and on-disk state of an indexed sound
+#[derive(Debug, Clone)]
+pub struct IndexedFile {
+    pub id: i64,
+    pub filepath: String,
+    pub content_hash: Option<String>,
+    pub file_stamp: Option<FileStamp>,
+    /// `FINGERPRINT_VERSION` of the stored fingerprint (None without one)
+    pub fingerprint_version: Option<u32>,
+}
+
+/// How long a statement waits on a locked database before failing
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default upper bound on pooled reader connections
+fn default_max_readers() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4).clamp(2, 8)
+}
 
 /// Database for sound palette management
+///
+/// File-backed databases run in WAL mode with one writer connection and a
+/// pool of read-only connections, so listing, search and similarity
+/// candidate lookups run in parallel with each other and with an indexing
+/// write in flight. The database is safe to share between threads (e.g.
+/// behind an `Arc`).
 pub struct PaletteDatabase {
-    conn: Connection,
+    writer: Mutex<Connection>,
+    /// None for in-memory and read-only databases, which use one connection
+    readers: Option<ReaderPool>,
+    library_root: library_root::SharedRoot,
+}
+
+/// Writes sharing one transaction (see `PaletteDatabase::write_batch`)
+pub(crate) struct WriteBatch<'a> {
+    conn: &'a Connection,
+}
+
+/// A connection for queries: pooled, or the writer for in-memory databases
+enum ReadConnection<'a> {
+    Pooled(PooledConnection<'a>),
+    Writer(MutexGuard<'a, Connection>),
+}
+
+impl Deref for ReadConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        match self {
+            ReadConnection::Pooled(conn) => conn,
+            ReadConnection::Writer(conn) => conn,
+        }
+    }
 }
 
 impl PaletteDatabase {
     /// Open or create database at path
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let conn = Connection::open(path)?;
-        let db = PaletteDatabase { conn };
-        db.create_schema()?;
-        Ok(db)
+        Self::open_with_readers(path, default_max_readers())
+    }
+
+    /// Open or create database at path with at most `max_readers` concurrent
+    /// read connections
+    pub fn open_with_readers<P: AsRef<Path>>(path: P, max_readers: usize) -> Result<Self> {
+        Self::open_with(path.as_ref(), max_readers, None)
+    }
+
+    /// Open or create a palette encrypted with `passphrase`
+    ///
+    /// Fails with `EncryptionError` on a wrong passphrase, an unencrypted
+    /// file, or a build without the `encryption` feature.
+    pub fn open_encrypted<P: AsRef<Path>>(path: P, passphrase: &str) -> Result<Self> {
+        Self::open_with(path.as_ref(), default_max_readers(), Some(passphrase))
+    }
+
+    fn open_with(path: &Path, max_readers: usize, passphrase: Option<&str>) -> Result<Self> {
+        vectors::register_extension();
+        let writer = Connection::open(path)?;
+        if let Some(passphrase) = passphrase {
+            encryption::unlock(&writer, passphrase)?;
+        }
+        writer.busy_timeout(BUSY_TIMEOUT)?;
+        writer.pragma_update(None, "journal_mode", "WAL")?;
+        writer.pragma_update(None, "synchronous", "NORMAL")?;
+        migrations::migrate(&writer)?;
+        let root: library_root::SharedRoot = Arc::new(RwLock::new(library_root::load(&writer)?));
+        library_root::register_functions(&writer, &root)?;
+
+        let reader_root = Arc::clone(&root);
+        let init = Box::new(move |conn: &Connection| library_root::register_functions(conn, &reader_root));
+        Ok(PaletteDatabase {
+            writer: Mutex::new(writer),
+            readers: Some(ReaderPool::new(path, max_readers, BUSY_TIMEOUT, passphrase, init)),
+            library_root: root,
+        })
     }
 
     /// Create in-memory database (for testing)
     pub fn open_in_memory() -> Result<Self> {
+        vectors::register_extension();
         let conn = Connection::open_in_memory()?;
-        let db = PaletteDatabase { conn };
-        db.create_schema()?;
-        Ok(db)
-    }
-
-    fn create_schema(&self) -> Result<()> {
-        self.conn.execute_batch(
-            r#"
-            CREATE TABLE IF NOT EXISTS sounds (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                filepath TEXT NOT NULL UNIQUE,
-                filename TEXT NOT NULL,
-                duration REAL,
-                sample_rate INTEGER,
-                channels INTEGER,
-                format TEXT,
-                date_added TEXT DEFAULT CURRENT_TIMESTAMP
-            );
-
-            CREATE TABLE IF NOT EXISTS fingerprints (
-                sound_id INTEGER PRIMARY KEY REFERENCES sounds(id) ON DELETE CASCADE,
-                fingerprint_json TEXT NOT NULL
-            );
-
-            CREATE TABLE IF NOT EXISTS categories (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                name TEXT NOT NULL,
-                parent_id INTEGER REFERENCES categories(id)
-            );
-
-            CREATE TABLE IF NOT EXISTS sound_categories (
-                sound_id INTEGER REFERENCES sounds(id) ON DELETE CASCADE,
-                category_id INTEGER REFERENCES categories(id) ON DELETE CASCADE,
-                PRIMARY KEY (sound_id, category_id)
-            );
-
-            CREATE INDEX IF NOT EXISTS idx_sounds_filepath ON sounds(filepath);
-            CREATE INDEX IF NOT EXISTS idx_sounds_filename ON sounds(filename);
-            "#
-        )?;
-        Ok(())
+        migrations::migrate(&conn)?;
+        let root: library_root::SharedRoot = Arc::new(RwLock::new(library_root::load(&conn)?));
+        library_root::register_functions(&conn, &root)?;
+        Ok(PaletteDatabase { writer: Mutex::new(conn), readers: None, library_root: root })
+    }
+
+    /// Open an existing palette read-only and as it is: no migrations and
+    /// no change of journal mode, so the file is left untouched
+    ///
+    /// Fails with `MigrationError` unless the palette is at this build's
+    /// schema version, since every query assumes that schema. Writes
+    /// through the returned database fail.
+    pub fn open_read_only<P: AsRef<Path>>(path: P) -> Result<Self> {
+        vectors::register_extension();
+        let conn =
+            Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX)?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        let version = migrations::stored_version(&conn)?;
+        if version != migrations::SCHEMA_VERSION {
+            return Err(AudioPaletteError::MigrationError(format!(
+                "palette is at schema version {} but only version {} can be read without upgrading it",
+                version,
+                migrations::SCHEMA_VERSION
+            )));
+        }
+        let root: library_root::SharedRoot = Arc::new(RwLock::new(library_root::load(&conn)?));
+        library_root::register_functions(&conn, &root)?;
+        Ok(PaletteDatabase { writer: Mutex::new(conn), readers: None, library_root: root })
+    }
+
+    /// Connection for inserts, updates and deletes
+    fn writer(&self) -> MutexGuard<'_, Connection> {
+        self.writer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Run `write` in one transaction, committed only if it succeeds, so a
+    /// batch touching several tables syncs once
+    pub(crate) fn write_batch<T>(&self, write: impl FnOnce(&WriteBatch) -> Result<T>) -> Result<T> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let result = write(&WriteBatch { conn: &tx })?;
+        tx.commit()?;
+        Ok(result)
+    }
+
+    /// Connection for queries (blocks while every pooled reader is busy)
+    fn reader(&self) -> Result<ReadConnection<'_>> {
+        match &self.readers {
+            Some(pool) => Ok(ReadConnection::Pooled(pool.get()?)),
+            None => Ok(ReadConnection::Writer(self.writer())),
+        }
+    }
+
+    /// Number of read connections opened so far (0 for in-memory databases)
+    pub fn open_readers(&self) -> usize {
+        self.readers.as_ref().map_or(0, |pool| pool.size())
+    }
+
+    /// Version of the schema this database is at
+    pub fn schema_version(&self) -> Result<u32> {
+        migrations::current_version(&*self.reader()?)
+    }
+
+    /// Counter that changes whenever sounds or fingerprints are added,
+    /// removed or replaced (for telling cached search results are stale)
+    pub fn library_revision(&self) -> Result<i64> {
+        let conn = self.reader()?;
+        Ok(conn.query_row("SELECT revision FROM library_revision", [], |row| row.get(0))?)
     }
 
     /// Add a sound to the database
     pub fn add_sound(&self, filepath: &str, filename: &str, duration: f64,
                      sample_rate: u32, channels: u16, format: &str) -> Result<i64> {
-        self.conn.execute(
-            "INSERT OR IGNORE INTO sounds (filepath, filename, duration, sample_rate, channels, format)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![filepath, filename, duration, sample_rate, channels, format],
+        let sound = NewSound {
+            filepath: filepath.to_string(),
+            filename: filename.to_string(),
+            duration,
+            sample_rate,
+            channels,
+            format: format.to_string(),
+            content_hash: None,
+            file_stamp: None,
+            bpm: None,
+            musical_key: None,
+        };
+        Ok(self.add_sounds_batch(std::slice::from_ref(&sound))?[0])
+    }
+
+    /// Find a sound with the given content hash stored under a path other
+    /// than `filepath` (an exact duplicate)
+    pub fn find_duplicate(&self, content_hash: &str, filepath: &str) -> Result<Option<SoundRecord>> {
+        let conn = self.reader()?;
+        let sound = conn
+            .query_row(
+                &format!("SELECT {SOUND_COLUMNS} FROM sounds WHERE content_hash = ?1 AND filepath != store_path(?2) LIMIT 1"),
+                params![content_hash, filepath],
+                sound_from_row,
+            )
+            .optional()?;
+        Ok(sound)
+    }
+
+    /// Add many sounds in one transaction, returning their ids in input order
+    ///
+    /// Much faster than repeated `add_sound` calls, which each commit (and
+    /// sync) separately. Paths already in the library keep their existing row.
+    pub fn add_sounds_batch(&self, sounds: &[NewSound]) -> Result<Vec<i64>> {
+        self.write_batch(|batch| batch.add_sounds(sounds))
+    }
+
+    /// Path, content hash, file stamp and fingerprint version of every
+    /// sound, for change detection
+    pub fn get_indexed_files(&self) -> Result<Vec<IndexedFile>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(
+            "SELECT s.id, resolve_path(s.filepath), s.content_hash, s.file_size, s.file_mtime, f.feature_version
+             FROM sounds s LEFT JOIN fingerprints f ON f.sound_id = s.id ORDER BY s.id",
         )?;
 
-        let id = self.conn.query_row(
-            "SELECT id FROM sounds WHERE filepath = ?1",
-            params![filepath],
-            |row| row.get(0),
+        let files = stmt
+            .query_map([], |row| {
+                let size: Option<i64> = row.get(3)?;
+                let mtime: Option<i64> = row.get(4)?;
+                Ok(IndexedFile {
+                    id: row.get(0)?,
+                    filepath: row.get(1)?,
+                    content_hash: row.get(2)?,
+                    file_stamp: size.zip(mtime).map(|(size, mtime)| FileStamp { size, mtime }),
+                    fingerprint_version: row.get(5)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(files)
+    }
+
+    /// Overwrite the analysis-derived fields of existing sounds (duration,
+    /// format details, hash and file stamp) after their files changed,
+    /// in one transaction. User metadata, tags and collections are kept.
+    pub fn refresh_sounds_batch(&self, sounds: &[(i64, NewSound)]) -> Result<()> {
+        self.write_batch(|batch| batch.refresh_sounds(sounds))
+    }
+
+    /// Record a file's current stamp without re-analyzing it (the contents
+    /// are unchanged)
+    pub fn update_file_stamp(&self, sound_id: i64, stamp: FileStamp) -> Result<()> {
+        self.writer().execute(
+            "UPDATE sounds SET file_size = ?2, file_mtime = ?3 WHERE id = ?1",
+            params![sound_id, stamp.size, stamp.mtime],
         )?;
+        Ok(())
+    }
 
-        Ok(id)
+    /// Update the user-editable metadata of a sound (`None` fields are left unchanged)
+    pub fn update_sound_metadata(&self, sound_id: i64, update: &SoundMetadataUpdate) -> Result<()> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let before = history::metadata(&tx, sound_id)?;
+        let changed = tx.execute(
+            "UPDATE sounds SET
+                 title = COALESCE(?2, title),
+                 artist = COALESCE(?3, artist),
+                 notes = COALESCE(?4, notes),
+                 source = COALESCE(?5, source)
+             WHERE id = ?1",
+            params![sound_id, update.title, update.artist, update.notes, update.source],
+        )?;
+
+        match before {
+            Some(before) if changed > 0 => history::record(&tx, sound_id, &history::Change::MetadataEdited(before))?,
+            _ => return Err(AudioPaletteError::DatabaseError(rusqlite::Error::QueryReturnedNoRows)),
+        }
+        tx.commit()?;
+        Ok(())
     }
 
     /// Store fingerprint for a sound
     pub fn store_fingerprint(&self, sound_id: i64, fingerprint: &AudioFingerprint) -> Result<()> {
-        let json = serde_json::to_string(fingerprint)
-            .map_err(|e| AudioPaletteError::FingerprintError(e.to_string()))?;
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        insert_fingerprint(&tx, sound_id, fingerprint)?;
+        tx.commit()?;
+        Ok(())
+    }
 
-        self.conn.execute(
-            "INSERT OR REPLACE INTO fingerprints (sound_id, fingerprint_json) VALUES (?1, ?2)",
-            params![sound_id, json],
-        )?;
+    /// Store many fingerprints in one transaction
+    pub fn store_fingerprints_batch(&self, fingerprints: &[(i64, AudioFingerprint)]) -> Result<()> {
+        self.write_batch(|batch| batch.store_fingerprints(fingerprints))
+    }
 
-        Ok(())
+    /// Cache waveform peaks for a sound
+    pub fn store_waveform(&self, sound_id: i64, peaks: &WaveformPeaks) -> Result<()> {
+        Ok(insert_waveform(&self.writer(), sound_id, peaks)?)
+    }
+
+    /// Cache many waveforms in one transaction
+    pub fn store_waveforms_batch(&self, waveforms: &[(i64, WaveformPeaks)]) -> Result<()> {
+        self.write_batch(|batch| batch.store_waveforms(waveforms))
+    }
+
+    /// Cached waveform peaks at the given resolution
+    ///
+    /// Peaks computed from different file contents than the sound currently
+    /// has (by content hash) are treated as missing.
+    pub fn get_waveform(&self, sound_id: i64, buckets: usize) -> Result<Option<WaveformPeaks>> {
+        let conn = self.reader()?;
+        let blob: Option<Vec<u8>> = conn
+            .query_row(
+                "SELECT w.peaks FROM waveforms w JOIN sounds s ON s.id = w.sound_id
+                 WHERE w.sound_id = ?1 AND w.buckets = ?2 AND w.content_hash IS s.content_hash",
+                params![sound_id, buckets as i64],
+                |row| row.get(0),
+            )
+            .optional()?;
+        blob.map(|b| WaveformPeaks::from_bytes(&b)).transpose()
     }
 
     /// Get fingerprint for a sound
+    ///
+    /// None as well when it was computed by an older analysis
+    /// (`FINGERPRINT_VERSION`), since it cannot be compared with current ones.
     pub fn get_fingerprint(&self, sound_id: i64) -> Result<Option<AudioFingerprint>> {
-        let result: rusqlite::Result<String> = self.conn.query_row(
-            "SELECT fingerprint_json FROM fingerprints WHERE sound_id = ?1",
-            params![sound_id],
-            |row| row.get(0),
+        let result = self.reader()?.query_row(
+            "SELECT fingerprint, fingerprint_json FROM fingerprints WHERE sound_id = ?1 AND feature_version = ?2",
+            params![sound_id, FINGERPRINT_VERSION],
+            |row| Ok((row.get::<_, Option<Vec<u8>>>(0)?, row.get::<_, Option<String>>(1)?)),
         );
 
         match result {
-            Ok(json) => {
-                let fp: AudioFingerprint = serde_json::from_str(&json)
-                    .map_err(|e| AudioPaletteError::FingerprintError(e.to_string()))?;
+            Ok((Some(blob), _)) => Ok(Some(AudioFingerprint::from_bytes(&blob)?)),
+            Ok((None, Some(json))) => {
+                let fp = decode_json_fingerprint(&json)?;
+                self.store_fingerprint(sound_id, &fp)?;
                 Ok(Some(fp))
             }
-            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Ok((None, None)) | Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(e.into()),
         }
     }
 
     /// Get all fingerprints for similarity search
+    ///
+    /// Rows still stored as JSON (libraries indexed before binary storage)
+    /// are converted in place. Fingerprints computed by an older analysis
+    /// (`FINGERPRINT_VERSION`) are left out until their sounds are rescanned.
     pub fn get_all_fingerprints(&self) -> Result<Vec<(i64, AudioFingerprint)>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT sound_id, fingerprint_json FROM fingerprints"
-        )?;
+        let rows: Vec<StoredFingerprint> = {
+            let conn = self.reader()?;
+            let mut stmt = conn.prepare(
+                "SELECT sound_id, fingerprint, fingerprint_json, feature_version FROM fingerprints"
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?;
+            rows.filter_map(|r| r.ok()).collect()
+        };
 
-        let results: Vec<(i64, AudioFingerprint)> = stmt
-            .query_map([], |row| {
-                let id: i64 = row.get(0)?;
-                let json: String = row.get(1)?;
-                Ok((id, json))
-            })?
-            .filter_map(|r| r.ok())
-            .filter_map(|(id, json)| {
-                serde_json::from_str(&json).ok().map(|fp| (id, fp))
-            })
-            .collect();
+        let mut results = Vec::with_capacity(rows.len());
+        let mut converted = Vec::new();
+        for (id, blob, json, version) in rows {
+            let current = version == FINGERPRINT_VERSION;
+            match (blob, json) {
+                (Some(blob), _) if current => {
+                    if let Ok(fp) = AudioFingerprint::from_bytes(&blob) {
+                        results.push((id, fp));
+                    }
+                }
+                (Some(_), _) => {}
+                (None, Some(json)) => {
+                    if let Ok(fp) = decode_json_fingerprint(&json) {
+                        converted.push((id, fp.to_bytes()));
+                        if current {
+                            results.push((id, fp));
+                        }
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        if !converted.is_empty() {
+            let conn = self.writer();
+            let tx = conn.unchecked_transaction()?;
+            {
+                let mut update = tx.prepare(
+                    "UPDATE fingerprints SET fingerprint = ?2, fingerprint_json = NULL WHERE sound_id = ?1"
+                )?;
+                for (id, blob) in &converted {
+                    update.execute(params![id, blob])?;
+                }
+            }
+            tx.commit()?;
+        }
 
         Ok(results)
     }
 
     /// Get sound by ID
     pub fn get_sound(&self, id: i64) -> Result<Option<SoundRecord>> {
-        let result = self.conn.query_row(
-            "SELECT id, filepath, filename, duration, sample_rate, channels, format, date_added
-             FROM sounds WHERE id = ?1",
+        let conn = self.reader()?;
+        let result = conn.query_row(
+            &format!("SELECT {SOUND_COLUMNS} FROM sounds WHERE id = ?1"),
             params![id],
-            |row| {
-                Ok(SoundRecord {
-                    id: row.get(0)?,
-                    filepath: row.get(1)?,
-                    filename: row.get(2)?,
-                    duration: row.get(3)?,
-                    sample_rate: row.get(4)?,
-                    channels: row.get(5)?,
-                    format: row.get(6)?,
-                    date_added: row.get(7)?,
-                })
-            },
+            sound_from_row,
         );
 
         match result {
@@ -165,67 +724,347 @@ impl PaletteDatabase {
 
     /// Get all sounds
     pub fn get_all_sounds(&self) -> Result<Vec<SoundRecord>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, filepath, filename, duration, sample_rate, channels, format, date_added
-             FROM sounds ORDER BY date_added DESC"
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(
+            &format!("SELECT {SOUND_COLUMNS} FROM sounds ORDER BY date_added DESC")
         )?;
 
         let sounds = stmt
+            .query_map([], sound_from_row)?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(sounds)
+    }
+
+    /// Full-text search over filename, path, metadata and tags, best match first
+    ///
+    /// Every word must match the start of an indexed word, so `kic sn` finds
+    /// `Kick_Snare_01.wav`. An empty query returns all sounds.
+    pub fn search(&self, query: &str) -> Result<Vec<SoundRecord>> {
+        self.search_limited(query, -1)
+    }
+
+    /// Full-text search returning at most `limit` results (negative = no limit)
+    pub fn search_limited(&self, query: &str, limit: i64) -> Result<Vec<SoundRecord>> {
+        let Some(fts) = fts_query(query) else {
+            return self.get_all_sounds();
+        };
+
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SOUND_COLUMNS} FROM sounds
+             JOIN (SELECT rowid AS hit_id, {SEARCH_RANK} AS hit_rank
+                   FROM sounds_fts WHERE sounds_fts MATCH ?1) ON hit_id = sounds.id
+             ORDER BY hit_rank, filename LIMIT ?2"
+        ))?;
+
+        let sounds = stmt
+            .query_map(params![fts, limit], sound_from_row)?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(sounds)
+    }
+
+    /// Full-text relevance of every sound matching `query`, scaled so the
+    /// best match scores 100 (None if the query has no words)
+    pub fn text_relevance(&self, query: &str) -> Result<Option<HashMap<i64, f64>>> {
+        let Some(fts) = fts_query(query) else {
+            return Ok(None);
+        };
+
+        let conn = self.reader()?;
+        let mut stmt =
+            conn.prepare_cached(&format!("SELECT rowid, {SEARCH_RANK} FROM sounds_fts WHERE sounds_fts MATCH ?1"))?;
+        let ranks: Vec<(i64, f64)> =
+            stmt.query_map(params![fts], |row| Ok((row.get(0)?, row.get(1)?)))?.collect::<rusqlite::Result<_>>()?;
+
+        // bm25 is negative, more so for better matches
+        let best = ranks.iter().map(|(_, rank)| *rank).fold(0.0, f64::min);
+        Ok(Some(
+            ranks
+                .into_iter()
+                .map(|(id, rank)| (id, if best < 0.0 { 100.0 * rank / best } else { 100.0 }))
+                .collect(),
+        ))
+    }
+
+    /// Attach a tag to a sound (creating the tag if needed)
+    pub fn tag_sound(&self, sound_id: i64, tag: &str, confidence: f64, source: &str) -> Result<()> {
+        let tag = normalize_tag(tag);
+        if tag.is_empty() {
+            return Err(AudioPaletteError::DatabaseError(rusqlite::Error::InvalidParameterName(
+                "empty tag".to_string(),
+            )));
+        }
+        self.retag(sound_id, |conn| insert_sound_tag(conn, sound_id, &tag, confidence, source))
+    }
+
+    /// Change the tags of one sound, recording the change in the history
+    fn retag(&self, sound_id: i64, change: impl FnOnce(&Connection) -> rusqlite::Result<()>) -> Result<()> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let before = history::sound_tags(&tx, sound_id)?;
+        change(&tx)?;
+        if history::sound_tags(&tx, sound_id)? != before {
+            history::record(&tx, sound_id, &history::Change::Retagged(before))?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Replace the tags written by `source` on many sounds in one transaction
+    ///
+    /// Each entry lists `(tag, confidence)` pairs for one sound; empty tag
+    /// names are skipped.
+    pub fn replace_tags_batch(&self, source: &str, tags: &[(i64, Vec<(String, f64)>)]) -> Result<()> {
+        self.write_batch(|batch| batch.replace_tags(source, tags))
+    }
+
+    /// Attach a user tag to a sound
+    pub fn add_tag(&self, sound_id: i64, tag: &str) -> Result<()> {
+        self.tag_sound(sound_id, tag, 1.0, "user")
+    }
+
+    /// Detach a tag from a sound
+    pub fn remove_tag(&self, sound_id: i64, tag: &str) -> Result<()> {
+        self.retag(sound_id, |conn| {
+            conn.execute(
+                "DELETE FROM sound_tags WHERE sound_id = ?1
+                 AND tag_id = (SELECT id FROM tags WHERE name = ?2)",
+                params![sound_id, normalize_tag(tag)],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Rename a tag everywhere (merging into the target tag if it already exists)
+    pub fn rename_tag(&self, old_name: &str, new_name: &str) -> Result<()> {
+        let (old_name, new_name) = (normalize_tag(old_name), normalize_tag(new_name));
+        if old_name == new_name {
+            return Ok(());
+        }
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        tx.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1)", params![new_name])?;
+        tx.execute(
+            "INSERT OR IGNORE INTO sound_tags (sound_id, tag_id, confidence, source)
+             SELECT st.sound_id, (SELECT id FROM tags WHERE name = ?2), st.confidence, st.source
+             FROM sound_tags st JOIN tags t ON t.id = st.tag_id WHERE t.name = ?1",
+            params![old_name, new_name],
+        )?;
+        tx.execute(
+            "DELETE FROM sound_tags WHERE tag_id = (SELECT id FROM tags WHERE name = ?1)",
+            params![old_name],
+        )?;
+        tx.execute("DELETE FROM tags WHERE name = ?1", params![old_name])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Delete a tag and all its assignments
+    pub fn delete_tag(&self, name: &str) -> Result<()> {
+        let name = normalize_tag(name);
+        let conn = self.writer();
+        conn.execute(
+            "DELETE FROM sound_tags WHERE tag_id = (SELECT id FROM tags WHERE name = ?1)",
+            params![name],
+        )?;
+        conn.execute("DELETE FROM tags WHERE name = ?1", params![name])?;
+        Ok(())
+    }
+
+    /// List all tags with the number of sounds carrying each
+    pub fn get_all_tags(&self) -> Result<Vec<TagSummary>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(
+            "SELECT t.name, COUNT(st.sound_id)
+             FROM tags t LEFT JOIN sound_tags st ON st.tag_id = t.id
+             GROUP BY t.id ORDER BY t.name"
+        )?;
+
+        let tags = stmt
             .query_map([], |row| {
-                Ok(SoundRecord {
-                    id: row.get(0)?,
-                    filepath: row.get(1)?,
-                    filename: row.get(2)?,
-                    duration: row.get(3)?,
-                    sample_rate: row.get(4)?,
-                    channels: row.get(5)?,
-                    format: row.get(6)?,
-                    date_added: row.get(7)?,
+                Ok(TagSummary {
+                    name: row.get(0)?,
+                    sound_count: row.get(1)?,
                 })
             })?
             .filter_map(|r| r.ok())
             .collect();
 
+        Ok(tags)
+    }
+
+    /// Find sounds carrying any (or, with `match_all`, every) of the given tags
+    /// with at least `min_confidence`
+    pub fn find_by_tags(&self, tags: &[String], match_all: bool, min_confidence: f64) -> Result<Vec<SoundRecord>> {
+        let tags: Vec<String> = tags.iter().map(|t| normalize_tag(t)).filter(|t| !t.is_empty()).collect();
+        if tags.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = (0..tags.len()).map(|i| format!("?{}", i + 3)).collect::<Vec<_>>().join(", ");
+        let sql = format!(
+            "SELECT {SOUND_COLUMNS} FROM sounds WHERE id IN (
+                 SELECT st.sound_id FROM sound_tags st JOIN tags t ON t.id = st.tag_id
+                 WHERE t.name IN ({placeholders}) AND st.confidence >= ?1
+                 GROUP BY st.sound_id HAVING COUNT(DISTINCT t.id) >= ?2
+             ) ORDER BY filename"
+        );
+        let required = if match_all { tags.len() as i64 } else { 1 };
+
+        let mut values: Vec<&dyn rusqlite::ToSql> = vec![&min_confidence, &required];
+        values.extend(tags.iter().map(|t| t as &dyn rusqlite::ToSql));
+
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(&sql)?;
+        let sounds = stmt
+            .query_map(values.as_slice(), sound_from_row)?
+            .filter_map(|r| r.ok())
+            .collect();
+
         Ok(sounds)
     }
 
-    /// Search sounds by filename
-    pub fn search(&self, query: &str) -> Result<Vec<SoundRecord>> {
-        let pattern = format!("%{}%", query);
-        let mut stmt = self.conn.prepare(
-            "SELECT id, filepath, filename, duration, sample_rate, channels, format, date_added
-             FROM sounds WHERE filename LIKE ?1 ORDER BY filename"
+    /// Remove all tags from a sound that were written by the given source
+    pub fn clear_sound_tags(&self, sound_id: i64, source: &str) -> Result<()> {
+        self.retag(sound_id, |conn| {
+            conn.execute(
+                "DELETE FROM sound_tags WHERE sound_id = ?1 AND source = ?2",
+                params![sound_id, source],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Get tags for a sound, most confident first
+    pub fn get_sound_tags(&self, sound_id: i64) -> Result<Vec<SoundTag>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(
+            "SELECT t.name, st.confidence, st.source
+             FROM sound_tags st JOIN tags t ON t.id = st.tag_id
+             WHERE st.sound_id = ?1 ORDER BY st.confidence DESC, t.name"
         )?;
 
-        let sounds = stmt
-            .query_map(params![pattern], |row| {
-                Ok(SoundRecord {
-                    id: row.get(0)?,
-                    filepath: row.get(1)?,
-                    filename: row.get(2)?,
-                    duration: row.get(3)?,
-                    sample_rate: row.get(4)?,
-                    channels: row.get(5)?,
-                    format: row.get(6)?,
-                    date_added: row.get(7)?,
+        let tags = stmt
+            .query_map(params![sound_id], |row| {
+                Ok(SoundTag {
+                    name: row.get(0)?,
+                    confidence: row.get(1)?,
+                    source: row.get(2)?,
                 })
             })?
             .filter_map(|r| r.ok())
             .collect();
 
-        Ok(sounds)
+        Ok(tags)
     }
 
-    /// Remove sound from database
+    /// Content hash recorded when a sound was indexed
+    pub fn get_content_hash(&self, sound_id: i64) -> Result<Option<String>> {
+        let conn = self.reader()?;
+        let hash = conn
+            .query_row("SELECT content_hash FROM sounds WHERE id = ?1", params![sound_id], |row| row.get(0))
+            .optional()?;
+        Ok(hash.flatten())
+    }
+
+    /// Record the content hash of a sound indexed without one
+    pub fn set_content_hash(&self, sound_id: i64, content_hash: &str) -> Result<()> {
+        self.writer().execute(
+            "UPDATE sounds SET content_hash = ?2 WHERE id = ?1",
+            params![sound_id, content_hash],
+        )?;
+        Ok(())
+    }
+
+    /// Groups of sounds sharing a content hash (identical files indexed
+    /// under several paths, e.g. before duplicate detection or by import)
+    pub fn find_duplicate_groups(&self) -> Result<Vec<Vec<SoundRecord>>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SOUND_COLUMNS}, content_hash FROM sounds WHERE content_hash IN (
+                 SELECT content_hash FROM sounds WHERE content_hash IS NOT NULL
+                 GROUP BY content_hash HAVING COUNT(*) > 1
+             ) ORDER BY content_hash, id"
+        ))?;
+
+        let mut groups: Vec<Vec<SoundRecord>> = Vec::new();
+        let mut last_hash: Option<String> = None;
+        for row in stmt.query_map([], |row| Ok((sound_from_row(row)?, row.get::<_, String>(SOUND_COLUMN_COUNT)?)))? {
+            let (sound, hash) = row?;
+            match groups.last_mut() {
+                Some(group) if last_hash.as_deref() == Some(hash.as_str()) => group.push(sound),
+                _ => groups.push(vec![sound]),
+            }
+            last_hash = Some(hash);
+        }
+        Ok(groups)
+    }
+
+    /// Sounds whose file no longer exists on disk
+    pub fn find_missing_files(&self) -> Result<Vec<SoundRecord>> {
+        Ok(self
+            .get_all_sounds()?
+            .into_iter()
+            .filter(|s| !Path::new(&s.filepath).is_file())
+            .collect())
+    }
+
+    /// Point a sound at a new file location, keeping its tags, metadata,
+    /// fingerprint and collection memberships
+    pub fn relink_sound(&self, sound_id: i64, filepath: &str, content_hash: Option<&str>) -> Result<()> {
+        let filename = Path::new(filepath)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| filepath.to_string());
+
+        let conn = self.writer();
+        let changed = conn.execute(
+            "UPDATE sounds SET filepath = store_path(?2), filename = ?3, content_hash = COALESCE(?4, content_hash)
+             WHERE id = ?1",
+            params![sound_id, filepath, filename, content_hash],
+        )?;
+
+        if changed == 0 {
+            return Err(AudioPaletteError::DatabaseError(rusqlite::Error::QueryReturnedNoRows));
+        }
+        Ok(())
+    }
+
+    /// Remove sound from database (and from any collections holding it)
     pub fn remove_sound(&self, id: i64) -> Result<()> {
-        self.conn.execute("DELETE FROM fingerprints WHERE sound_id = ?1", params![id])?;
-        self.conn.execute("DELETE FROM sounds WHERE id = ?1", params![id])?;
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        if let Some(snapshot) = history::snapshot(&tx, id)? {
+            history::record(&tx, id, &history::Change::Removed(Box::new(snapshot)))?;
+        }
+        delete_sound(&tx, id)?;
+        tx.commit()?;
         Ok(())
     }
 
     /// Get sound count
     pub fn count(&self) -> Result<i64> {
-        let count: i64 = self.conn.query_row("SELECT COUNT(*) FROM sounds", [], |row| row.get(0))?;
+        let conn = self.reader()?;
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM sounds", [], |row| row.get(0))?;
+        Ok(count)
+    }
+
+    /// Number of sounds whose fingerprint is missing or was computed by an
+    /// older analysis (`FINGERPRINT_VERSION`), so similarity search skips
+    /// them until they are re-analyzed
+    pub fn count_outdated_fingerprints(&self) -> Result<i64> {
+        let conn = self.reader()?;
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM sounds s LEFT JOIN fingerprints f ON f.sound_id = s.id
+             WHERE f.feature_version IS NOT ?1",
+            [FINGERPRINT_VERSION],
+            |row| row.get(0),
+        )?;
         Ok(count)
     }
 }
@@ -250,6 +1089,20 @@ mod tests {
         let results = db.search("sound").unwrap();
         assert_eq!(results.len(), 1);
 
+        // Editable metadata
+        let update = SoundMetadataUpdate {
+            title: Some("Harbour ambience".to_string()),
+            notes: Some("gulls, distant engine".to_string()),
+            ..SoundMetadataUpdate::default()
+        };
+        db.update_sound_metadata(id, &update).unwrap();
+        db.update_sound_metadata(id, &SoundMetadataUpdate { artist: Some("me".to_string()), ..SoundMetadataUpdate::default() }).unwrap();
+        let sound = db.get_sound(id).unwrap().unwrap();
+        assert_eq!(sound.title, "Harbour ambience");
+        assert_eq!(sound.artist, "me");
+        assert_eq!(db.search("gulls").unwrap().len(), 1);
+        assert!(db.update_sound_metadata(id + 100, &update).is_err());
+
         // Count
         assert_eq!(db.count().unwrap(), 1);
 
@@ -257,4 +1110,178 @@ mod tests {
         db.remove_sound(id).unwrap();
         assert_eq!(db.count().unwrap(), 0);
     }
+
+    #[test]
+    fn test_batch_insert() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let existing = db.add_sound("/kit/kick.wav", "kick.wav", 0.4, 44100, 1, "wav").unwrap();
+
+        let sounds: Vec<NewSound> = ["snare", "kick", "hat"]
+            .iter()
+            .map(|name| NewSound {
+                filepath: format!("/kit/{}.wav", name),
+                filename: format!("{}.wav", name),
+                duration: 0.3,
+                sample_rate: 44100,
+                channels: 1,
+                format: "wav".to_string(),
+                content_hash: Some(format!("hash-{}", name)),
+                file_stamp: None,
+                bpm: None,
+                musical_key: None,
+            })
+            .collect();
+        let ids = db.add_sounds_batch(&sounds).unwrap();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[1], existing);
+        assert_eq!(db.count().unwrap(), 3);
+
+        // Same content under a new path resolves to the indexed sound
+        let copy = NewSound { filepath: "/backup/snare copy.wav".to_string(), ..sounds[0].clone() };
+        assert_eq!(db.add_sounds_batch(&[copy]).unwrap(), vec![ids[0]]);
+        assert_eq!(db.find_duplicate("hash-snare", "/backup/snare copy.wav").unwrap().unwrap().id, ids[0]);
+        assert!(db.find_duplicate("hash-snare", "/kit/snare.wav").unwrap().is_none());
+        assert_eq!(db.count().unwrap(), 3);
+
+        let fp = AudioFingerprint {
+            duration: 0.3,
+            sample_rate: 44100,
+            mfcc_mean: vec![1.0; 13],
+            mfcc_std: vec![0.5; 13],
+            spectral_centroid: 3000.0,
+            spectral_bandwidth: 1000.0,
+            spectral_rolloff: 6000.0,
+            rms_mean: 0.2,
+            rms_std: 0.1,
+            zero_crossing_rate: 0.1,
+            chroma_mean: vec![0.3; 12],
+            noise: None,
+            stereo: None,
+        };
+        let batch: Vec<(i64, AudioFingerprint)> = ids.iter().map(|&id| (id, fp.clone())).collect();
+        db.store_fingerprints_batch(&batch).unwrap();
+        assert_eq!(db.get_all_fingerprints().unwrap().len(), 3);
+
+        db.replace_tags_batch("classifier", &[(ids[0], vec![("snare".to_string(), 0.9)])]).unwrap();
+        db.replace_tags_batch("classifier", &[(ids[0], vec![("Clap".to_string(), 0.6), (" ".to_string(), 0.1)])]).unwrap();
+        let tags = db.get_sound_tags(ids[0]).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name, "clap");
+    }
+
+    #[test]
+    fn test_reads_during_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = PaletteDatabase::open(dir.path().join("palette.db")).unwrap();
+        db.add_sound("/a.wav", "a.wav", 1.0, 44100, 1, "wav").unwrap();
+
+        let mode: String = db.reader().unwrap().query_row("PRAGMA journal_mode", [], |row| row.get(0)).unwrap();
+        assert_eq!(mode, "wal");
+
+        // An open write transaction doesn't block readers; they see the last commit
+        let writer = db.writer();
+        writer.execute_batch("BEGIN").unwrap();
+        writer
+            .execute("INSERT INTO sounds (filepath, filename) VALUES ('/b.wav', 'b.wav')", [])
+            .unwrap();
+        assert_eq!(db.count().unwrap(), 1);
+        assert_eq!(db.search("a").unwrap().len(), 1);
+        writer.execute_batch("COMMIT").unwrap();
+        drop(writer);
+
+        assert_eq!(db.count().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_parallel_readers() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = std::sync::Arc::new(PaletteDatabase::open_with_readers(dir.path().join("palette.db"), 3).unwrap());
+        for i in 0..20 {
+            db.add_sound(&format!("/s/{i}.wav"), &format!("{i}.wav"), 1.0, 44100, 1, "wav").unwrap();
+        }
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let db = db.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        assert_eq!(db.get_all_sounds().unwrap().len(), 20);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!((1..=3).contains(&db.open_readers()));
+    }
+
+    #[test]
+    fn test_full_text_search() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let zoom = db.add_sound("/field/ZOOM0041.WAV", "ZOOM0041.WAV", 60.0, 48000, 2, "wav").unwrap();
+        let kick = db.add_sound("/kits/Kick_Hard_01.wav", "Kick_Hard_01.wav", 0.4, 44100, 1, "wav").unwrap();
+        let pad = db.add_sound("/synth/pad_warm.wav", "pad_warm.wav", 4.0, 44100, 2, "wav").unwrap();
+
+        db.update_sound_metadata(zoom, &SoundMetadataUpdate {
+            notes: Some("Seagulls over the harbour, kick drum from a nearby festival".to_string()),
+            ..SoundMetadataUpdate::default()
+        }).unwrap();
+        db.add_tag(pad, "ambient").unwrap();
+
+        // Prefix match on notes and on tags
+        let hits = db.search("seagull").unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, zoom);
+        assert_eq!(db.search("ambi").unwrap()[0].id, pad);
+
+        // Filename hits outrank notes hits
+        let hits = db.search("kick").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].id, kick);
+
+        // All words must match; punctuation is ignored
+        assert_eq!(db.search("kick hard").unwrap().len(), 1);
+        assert_eq!(db.search("\"kick\" OR").unwrap().len(), 0);
+        assert_eq!(db.search("  ").unwrap().len(), 3);
+
+        // Index follows tag and sound removal
+        db.remove_tag(pad, "ambient").unwrap();
+        assert!(db.search("ambient").unwrap().is_empty());
+        db.remove_sound(kick).unwrap();
+        assert_eq!(db.search("kick").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_sound_tags() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound("/test/snare.wav", "snare.wav", 0.3, 44100, 1, "wav").unwrap();
+
+        db.tag_sound(id, "snare", 0.8, "classifier").unwrap();
+        db.tag_sound(id, "hat", 0.2, "classifier").unwrap();
+        let tags = db.get_sound_tags(id).unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name, "snare");
+
+        db.clear_sound_tags(id, "classifier").unwrap();
+        assert!(db.get_sound_tags(id).unwrap().is_empty());
+
+        // User tags and lookup
+        let other = db.add_sound("/test/kick.wav", "kick.wav", 0.4, 44100, 1, "wav").unwrap();
+        db.add_tag(id, " Acoustic ").unwrap();
+        db.add_tag(id, "dry").unwrap();
+        db.add_tag(other, "acoustic").unwrap();
+
+        assert_eq!(db.find_by_tags(&["acoustic".to_string()], false, 0.0).unwrap().len(), 2);
+        let both = db.find_by_tags(&["acoustic".to_string(), "dry".to_string()], true, 0.0).unwrap();
+        assert_eq!(both.len(), 1);
+        assert_eq!(both[0].id, id);
+
+        db.rename_tag("dry", "close-mic").unwrap();
+        db.remove_tag(other, "acoustic").unwrap();
+        let summary = db.get_all_tags().unwrap();
+        assert!(summary.iter().any(|t| t.name == "close-mic" && t.sound_count == 1));
+        assert!(summary.iter().any(|t| t.name == "acoustic" && t.sound_count == 1));
+    }
 }
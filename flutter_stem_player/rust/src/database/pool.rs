@@ -0,0 +1,117 @@
+//! Pool of read-only connections
+//!
+//! Connections are opened lazily up to a fixed limit and handed out as
+//! guards that return them to the pool on drop; callers block when every
+//! connection is checked out. Writes never go through the pool, so with
+//! WAL journaling readers only contend with each other for a free slot.
+
+use rusqlite::{Connection, OpenFlags};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+struct PoolState {
+    idle: Vec<Connection>,
+    /// Connections currently open (idle + checked out)
+    open: usize,
+}
+
+/// Setup run on every new connection (e.g. registering SQL functions)
+pub(crate) type ConnectionInit = Box<dyn Fn(&Connection) -> rusqlite::Result<()> + Send + Sync>;
+
+pub(crate) struct ReaderPool {
+    path: PathBuf,
+    max_size: usize,
+    busy_timeout: Duration,
+    /// SQLCipher passphrase for encrypted palettes
+    passphrase: Option<String>,
+    init: ConnectionInit,
+    state: Mutex<PoolState>,
+    returned: Condvar,
+}
+
+impl ReaderPool {
+    pub(crate) fn new(
+        path: &Path,
+        max_size: usize,
+        busy_timeout: Duration,
+        passphrase: Option<&str>,
+        init: ConnectionInit,
+    ) -> Self {
+        ReaderPool {
+            path: path.to_path_buf(),
+            max_size: max_size.max(1),
+            busy_timeout,
+            passphrase: passphrase.map(str::to_string),
+            init,
+            state: Mutex::new(PoolState { idle: Vec::new(), open: 0 }),
+            returned: Condvar::new(),
+        }
+    }
+
+    /// Check out a connection, opening a new one if below the limit
+    pub(crate) fn get(&self) -> rusqlite::Result<PooledConnection<'_>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if let Some(conn) = state.idle.pop() {
+                return Ok(PooledConnection { pool: self, conn: Some(conn) });
+            }
+            if state.open < self.max_size {
+                state.open += 1;
+                drop(state);
+                return match self.connect() {
+                    Ok(conn) => Ok(PooledConnection { pool: self, conn: Some(conn) }),
+                    Err(e) => {
+                        self.state.lock().unwrap_or_else(|e| e.into_inner()).open -= 1;
+                        self.returned.notify_one();
+                        Err(e)
+                    }
+                };
+            }
+            state = self.returned.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    fn connect(&self) -> rusqlite::Result<Connection> {
+        let conn = Connection::open_with_flags(
+            &self.path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        // Must come first; the writer already checked the passphrase
+        if let Some(passphrase) = &self.passphrase {
+            conn.pragma_update(None, "key", passphrase)?;
+        }
+        conn.busy_timeout(self.busy_timeout)?;
+        (self.init)(&conn)?;
+        Ok(conn)
+    }
+
+    /// Number of connections opened so far
+    pub(crate) fn size(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).open
+    }
+}
+
+/// A checked-out reader, returned to the pool on drop
+pub(crate) struct PooledConnection<'a> {
+    pool: &'a ReaderPool,
+    conn: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().expect("connection present until drop")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.state.lock().unwrap_or_else(|e| e.into_inner()).idle.push(conn);
+            self.pool.returned.notify_one();
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Free-form key-value properties per sound
+//!
+//! Lets integrators attach their own data (project ids, licence terms,
+//! external UUIDs) without a schema change. Values are stored as text;
+//! structured data can be stored as JSON by the caller.
+
+use super::{qualified_sound_columns, sound_from_row, PaletteDatabase};
+use crate::{AudioPaletteError, Result, SoundRecord};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// One property of a sound
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SoundProperty {
+    pub key: String,
+    pub value: String,
+}
+
+fn validate_key(key: &str) -> Result<&str> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(AudioPaletteError::PropertyError("property key is empty".to_string()));
+    }
+    Ok(key)
+}
+
+impl PaletteDatabase {
+    /// Set a property, replacing any previous value for the key
+    pub fn set_property(&self, sound_id: i64, key: &str, value: &str) -> Result<()> {
+        let key = validate_key(key)?;
+        let inserted = self.writer().execute(
+            "INSERT INTO sound_properties (sound_id, key, value)
+             SELECT id, ?2, ?3 FROM sounds WHERE id = ?1
+             ON CONFLICT(sound_id, key) DO UPDATE SET value = excluded.value",
+            params![sound_id, key, value],
+        )?;
+        if inserted == 0 {
+            return Err(AudioPaletteError::PropertyError(format!("no sound {}", sound_id)));
+        }
+        Ok(())
+    }
+
+    pub fn get_property(&self, sound_id: i64, key: &str) -> Result<Option<String>> {
+        let value = self
+            .reader()?
+            .query_row(
+                "SELECT value FROM sound_properties WHERE sound_id = ?1 AND key = ?2",
+                params![sound_id, key.trim()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(value)
+    }
+
+    /// All properties of a sound, sorted by key
+    pub fn get_properties(&self, sound_id: i64) -> Result<Vec<SoundProperty>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached("SELECT key, value FROM sound_properties WHERE sound_id = ?1 ORDER BY key")?;
+        let properties = stmt
+            .query_map(params![sound_id], |row| Ok(SoundProperty { key: row.get(0)?, value: row.get(1)? }))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(properties)
+    }
+
+    /// Remove a property (a no-op if the sound doesn't have it)
+    pub fn remove_property(&self, sound_id: i64, key: &str) -> Result<()> {
+        self.writer().execute(
+            "DELETE FROM sound_properties WHERE sound_id = ?1 AND key = ?2",
+            params![sound_id, key.trim()],
+        )?;
+        Ok(())
+    }
+
+    /// Sounds whose property `key` equals `value`, e.g. to look a sound up
+    /// by an external id
+    pub fn find_by_property(&self, key: &str, value: &str) -> Result<Vec<SoundRecord>> {
+        let conn = self.reader()?;
+        let columns = qualified_sound_columns("s");
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT {columns} FROM sound_properties p JOIN sounds s ON s.id = p.sound_id
+             WHERE p.key = ?1 AND p.value = ?2 ORDER BY s.id"
+        ))?;
+        let sounds = stmt
+            .query_map(params![key.trim(), value], sound_from_row)?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(sounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sound_properties() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound("/lib/rain.wav", "rain.wav", 30.0, 48000, 2, "wav").unwrap();
+        let other = db.add_sound("/lib/wind.wav", "wind.wav", 30.0, 48000, 2, "wav").unwrap();
+
+        db.set_property(id, "license", "CC-BY").unwrap();
+        db.set_property(id, " project_id ", "P-17").unwrap();
+        db.set_property(other, "project_id", "P-17").unwrap();
+        db.set_property(id, "license", "CC0").unwrap();
+        assert_eq!(db.get_property(id, "license").unwrap().as_deref(), Some("CC0"));
+        assert_eq!(db.get_property(id, "missing").unwrap(), None);
+        assert_eq!(
+            db.get_properties(id).unwrap(),
+            vec![
+                SoundProperty { key: "license".to_string(), value: "CC0".to_string() },
+                SoundProperty { key: "project_id".to_string(), value: "P-17".to_string() },
+            ]
+        );
+
+        let found: Vec<i64> = db.find_by_property("project_id", "P-17").unwrap().iter().map(|s| s.id).collect();
+        assert_eq!(found, vec![id, other]);
+
+        assert!(matches!(db.set_property(id, "  ", "x"), Err(AudioPaletteError::PropertyError(_))));
+        assert!(matches!(db.set_property(999, "k", "v"), Err(AudioPaletteError::PropertyError(_))));
+
+        db.remove_property(id, "license").unwrap();
+        assert_eq!(db.get_properties(id).unwrap().len(), 1);
+        db.remove_sound(other).unwrap();
+        assert_eq!(db.find_by_property("project_id", "P-17").unwrap().len(), 1);
+    }
+}
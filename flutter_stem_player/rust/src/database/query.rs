@@ -0,0 +1,402 @@
+//! Filtered, sorted and paged library listings
+//!
+//! Large libraries are listed a page at a time instead of shipping every
+//! row across the FFI bridge at once. Pages use limit/offset with a stable
+//! tie-breaker on id, so consecutive pages neither repeat nor skip rows
+//! while the library is unchanged. Filters on duration, format, sample
+//! rate, channels, tempo, key and tags run in SQL rather than on the client.
+
+use super::{fts_query, history, normalize_tag, sound_from_row, PaletteDatabase, SOUND_COLUMNS};
+use crate::fingerprint::MusicalKey;
+use crate::{AudioPaletteError, Result, SoundRecord};
+use rusqlite::params;
+use rusqlite::types::Value;
+use serde::{Deserialize, Serialize};
+
+/// Sort key for library listings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SoundSort {
+    /// Filename, case-insensitive
+    Name,
+    #[default]
+    DateAdded,
+    Duration,
+    Rating,
+}
+
+impl SoundSort {
+    fn column(self) -> &'static str {
+        match self {
+            SoundSort::Name => "filename COLLATE NOCASE",
+            SoundSort::DateAdded => "date_added",
+            SoundSort::Duration => "duration",
+            SoundSort::Rating => "rating",
+        }
+    }
+
+    /// ORDER BY clause, with id as tie-breaker so paging is stable
+    fn order_by(self, descending: bool) -> String {
+        let direction = if descending { "DESC" } else { "ASC" };
+        format!("{} {direction}, id {direction}", self.column())
+    }
+}
+
+/// One page of a listing, with the size of the whole listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundPage {
+    pub sounds: Vec<SoundRecord>,
+    pub total: i64,
+}
+
+/// Structured library query
+///
+/// Unset fields don't filter. Built with chained calls, e.g. WAV one-shots
+/// between 0.1 and 2 seconds at 44.1 kHz or more, in stereo:
+///
+/// ```ignore
+/// SoundQuery::new().format("wav").duration(0.1, 2.0).min_sample_rate(44100).channels(2)
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SoundQuery {
+    /// Free text matched like `search` (prefix terms, all required)
+    pub text: Option<String>,
+    /// File formats / extensions, any of which may match (case-insensitive)
+    pub formats: Vec<String>,
+    pub min_duration: Option<f64>,
+    pub max_duration: Option<f64>,
+    pub min_sample_rate: Option<u32>,
+    pub max_sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+    pub min_rating: Option<u8>,
+    pub min_bpm: Option<f64>,
+    pub max_bpm: Option<f64>,
+    /// Canonical key names (`MusicalKey::name`), any of which may match
+    pub keys: Vec<String>,
+    /// Tags (including classifier categories such as "kick"), any of which may match
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Confidence a tag needs to count
+    #[serde(default)]
+    pub min_tag_confidence: f64,
+    pub sort: SoundSort,
+    pub descending: bool,
+    pub offset: i64,
+    /// None for all remaining rows
+    pub limit: Option<i64>,
+}
+
+/// Append a parameter value, returning its placeholder
+fn bind(value: Value, values: &mut Vec<Value>) -> String {
+    values.push(value);
+    format!("?{}", values.len())
+}
+
+impl SoundQuery {
+    pub fn new() -> Self {
+        SoundQuery::default()
+    }
+
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    pub fn format(mut self, format: &str) -> Self {
+        self.formats.push(format.trim_start_matches('.').to_lowercase());
+        self
+    }
+
+    /// Duration range in seconds (inclusive)
+    pub fn duration(mut self, min: f64, max: f64) -> Self {
+        self.min_duration = Some(min);
+        self.max_duration = Some(max);
+        self
+    }
+
+    pub fn min_sample_rate(mut self, rate: u32) -> Self {
+        self.min_sample_rate = Some(rate);
+        self
+    }
+
+    pub fn max_sample_rate(mut self, rate: u32) -> Self {
+        self.max_sample_rate = Some(rate);
+        self
+    }
+
+    pub fn channels(mut self, channels: u16) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn min_rating(mut self, rating: u8) -> Self {
+        self.min_rating = Some(rating);
+        self
+    }
+
+    /// Tempo range in BPM (inclusive); sounds without a detected tempo never match
+    pub fn bpm(mut self, min: f64, max: f64) -> Self {
+        self.min_bpm = Some(min);
+        self.max_bpm = Some(max);
+        self
+    }
+
+    /// Match a key, optionally together with its harmonically compatible keys
+    pub fn key(mut self, key: MusicalKey, include_compatible: bool) -> Self {
+        if include_compatible {
+            self.keys.extend(key.compatible().iter().map(|k| k.name()));
+        } else {
+            self.keys.push(key.name());
+        }
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn sort_by(mut self, sort: SoundSort, descending: bool) -> Self {
+        self.sort = sort;
+        self.descending = descending;
+        self
+    }
+
+    pub fn page(mut self, offset: i64, limit: i64) -> Self {
+        self.offset = offset;
+        self.limit = Some(limit);
+        self
+    }
+
+    /// WHERE clause and its parameter values
+    fn filter(&self) -> (String, Vec<Value>) {
+        let mut conditions = Vec::new();
+        let mut values = Vec::new();
+
+        if let Some(fts) = self.text.as_deref().and_then(fts_query) {
+            let p = bind(Value::Text(fts), &mut values);
+            conditions.push(format!("id IN (SELECT rowid FROM sounds_fts WHERE sounds_fts MATCH {p})"));
+        }
+        if !self.formats.is_empty() {
+            // Older rows have no format recorded, so also match the extension
+            let alternatives: Vec<String> = self
+                .formats
+                .iter()
+                .map(|f| {
+                    let p = bind(Value::Text(f.to_lowercase()), &mut values);
+                    format!("LOWER(format) = {p} OR LOWER(filename) LIKE '%.' || {p}")
+                })
+                .collect();
+            conditions.push(format!("({})", alternatives.join(" OR ")));
+        }
+        if let Some(min) = self.min_duration {
+            conditions.push(format!("duration >= {}", bind(Value::Real(min), &mut values)));
+        }
+        if let Some(max) = self.max_duration {
+            conditions.push(format!("duration <= {}", bind(Value::Real(max), &mut values)));
+        }
+        if let Some(min) = self.min_sample_rate {
+            conditions.push(format!("sample_rate >= {}", bind(Value::Integer(min.into()), &mut values)));
+        }
+        if let Some(max) = self.max_sample_rate {
+            conditions.push(format!("sample_rate <= {}", bind(Value::Integer(max.into()), &mut values)));
+        }
+        if let Some(channels) = self.channels {
+            conditions.push(format!("channels = {}", bind(Value::Integer(channels.into()), &mut values)));
+        }
+        if let Some(rating) = self.min_rating {
+            conditions.push(format!("rating >= {}", bind(Value::Integer(rating.into()), &mut values)));
+        }
+        if let Some(min) = self.min_bpm {
+            conditions.push(format!("bpm >= {}", bind(Value::Real(min), &mut values)));
+        }
+        if let Some(max) = self.max_bpm {
+            conditions.push(format!("bpm <= {}", bind(Value::Real(max), &mut values)));
+        }
+        if !self.keys.is_empty() {
+            let placeholders: Vec<String> =
+                self.keys.iter().map(|k| bind(Value::Text(k.clone()), &mut values)).collect();
+            conditions.push(format!("musical_key IN ({})", placeholders.join(", ")));
+        }
+        let tags: Vec<String> = self.tags.iter().map(|t| normalize_tag(t)).filter(|t| !t.is_empty()).collect();
+        if !tags.is_empty() {
+            let placeholders: Vec<String> = tags.into_iter().map(|t| bind(Value::Text(t), &mut values)).collect();
+            let confidence = bind(Value::Real(self.min_tag_confidence), &mut values);
+            conditions.push(format!(
+                "id IN (SELECT st.sound_id FROM sound_tags st JOIN tags t ON t.id = st.tag_id
+                        WHERE t.name IN ({}) AND st.confidence >= {confidence})",
+                placeholders.join(", ")
+            ));
+        }
+
+        if conditions.is_empty() {
+            (String::new(), values)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), values)
+        }
+    }
+}
+
+impl PaletteDatabase {
+    /// Run a structured query, returning the requested page and the number
+    /// of sounds matching the filters
+    pub fn query_sounds(&self, query: &SoundQuery) -> Result<SoundPage> {
+        let (filter, mut values) = query.filter();
+        let conn = self.reader()?;
+        let total: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM sounds {filter}"),
+            rusqlite::params_from_iter(&values),
+            |row| row.get(0),
+        )?;
+
+        // LIMIT -1 means no limit in SQLite
+        values.push(Value::Integer(query.limit.map_or(-1, |l| l.max(0))));
+        values.push(Value::Integer(query.offset.max(0)));
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SOUND_COLUMNS} FROM sounds {filter} ORDER BY {} LIMIT ?{} OFFSET ?{}",
+            query.sort.order_by(query.descending),
+            values.len() - 1,
+            values.len()
+        ))?;
+        let sounds = stmt
+            .query_map(rusqlite::params_from_iter(&values), sound_from_row)?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(SoundPage { sounds, total })
+    }
+
+    /// Ids of every sound matching the query's filters (ignoring sort and
+    /// paging), e.g. to restrict similarity search results
+    pub fn query_sound_ids(&self, query: &SoundQuery) -> Result<Vec<i64>> {
+        let (filter, values) = query.filter();
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare(&format!("SELECT id FROM sounds {filter}"))?;
+        let ids = stmt
+            .query_map(rusqlite::params_from_iter(&values), |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(ids)
+    }
+
+    /// List up to `limit` sounds starting at `offset` in the given order
+    pub fn list_sounds(&self, sort: SoundSort, descending: bool, offset: i64, limit: i64) -> Result<SoundPage> {
+        self.query_sounds(&SoundQuery::new().sort_by(sort, descending).page(offset, limit))
+    }
+
+    /// Rate a sound from 1 to 5 stars, or 0 to clear the rating
+    pub fn set_sound_rating(&self, sound_id: i64, rating: u8) -> Result<()> {
+        if rating > 5 {
+            return Err(AudioPaletteError::DatabaseError(rusqlite::Error::InvalidParameterName(format!(
+                "rating {} out of range 0-5",
+                rating
+            ))));
+        }
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        let Some(before) = history::metadata(&tx, sound_id)? else {
+            return Err(AudioPaletteError::DatabaseError(rusqlite::Error::QueryReturnedNoRows));
+        };
+        tx.execute("UPDATE sounds SET rating = ?2 WHERE id = ?1", params![sound_id, rating])?;
+        history::record(&tx, sound_id, &history::Change::MetadataEdited(before))?;
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::NewSound;
+
+    #[test]
+    fn test_paged_listing() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let names = ["snare.wav", "Kick.wav", "pad.wav", "hat.wav", "bass.wav"];
+        let ids: Vec<i64> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| db.add_sound(&format!("/kit/{name}"), name, 0.5 + i as f64, 44100, 1, "wav").unwrap())
+            .collect();
+        db.set_sound_rating(ids[2], 5).unwrap();
+        db.set_sound_rating(ids[0], 3).unwrap();
+        assert!(db.set_sound_rating(ids[0], 6).is_err());
+
+        let page = db.list_sounds(SoundSort::Name, false, 0, 2).unwrap();
+        assert_eq!(page.total, 5);
+        assert_eq!(page.sounds.iter().map(|s| s.filename.as_str()).collect::<Vec<_>>(), ["bass.wav", "hat.wav"]);
+
+        let page = db.list_sounds(SoundSort::Name, false, 4, 2).unwrap();
+        assert_eq!(page.sounds.len(), 1);
+        assert_eq!(page.sounds[0].filename, "snare.wav");
+
+        let page = db.list_sounds(SoundSort::Duration, true, 0, 1).unwrap();
+        assert_eq!(page.sounds[0].id, ids[4]);
+
+        let page = db.list_sounds(SoundSort::Rating, true, 0, 2).unwrap();
+        assert_eq!((page.sounds[0].id, page.sounds[0].rating), (ids[2], 5));
+        assert_eq!(page.sounds[1].id, ids[0]);
+
+        // Same-second inserts fall back to id order
+        let page = db.list_sounds(SoundSort::DateAdded, true, 0, 5).unwrap();
+        let newest_first: Vec<i64> = ids.iter().rev().copied().collect();
+        assert_eq!(page.sounds.iter().map(|s| s.id).collect::<Vec<_>>(), newest_first);
+    }
+
+    #[test]
+    fn test_structured_query() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        db.add_sound("/kit/kick.wav", "kick.wav", 0.4, 44100, 2, "wav").unwrap();
+        db.add_sound("/kit/kick mono.wav", "kick mono.wav", 0.4, 44100, 1, "wav").unwrap();
+        db.add_sound("/kit/kick lofi.wav", "kick lofi.wav", 0.4, 22050, 2, "wav").unwrap();
+        db.add_sound("/kit/kick.mp3", "kick.mp3", 0.4, 48000, 2, "mp3").unwrap();
+        db.add_sound("/loops/break.WAV", "break.WAV", 8.0, 48000, 2, "unknown").unwrap();
+        let snare = db.add_sound("/kit/snare.wav", "snare.wav", 1.5, 96000, 2, "wav").unwrap();
+
+        let one_shots = SoundQuery::new().format("WAV").duration(0.1, 2.0).min_sample_rate(44100).channels(2);
+        let page = db.query_sounds(&one_shots.clone().sort_by(SoundSort::Name, false)).unwrap();
+        let names: Vec<&str> = page.sounds.iter().map(|s| s.filename.as_str()).collect();
+        assert_eq!(names, ["kick.wav", "snare.wav"]);
+        assert_eq!(page.total, 2);
+
+        // Formats fall back to the extension; text narrows via the search index
+        let long_wavs = SoundQuery { min_duration: Some(5.0), ..SoundQuery::new().format(".wav") };
+        assert_eq!(db.query_sounds(&long_wavs).unwrap().sounds[0].filename, "break.WAV");
+        let page = db.query_sounds(&one_shots.clone().text("snare")).unwrap();
+        assert_eq!(page.sounds[0].id, snare);
+
+        // Tempo and key, with compatible keys on request
+        let loops: Vec<NewSound> = [("a", 120.0, "A minor"), ("b", 124.0, "C major"), ("c", 140.0, "A minor")]
+            .iter()
+            .map(|(name, bpm, key)| NewSound {
+                filepath: format!("/loops/{name}.wav"),
+                filename: format!("{name}.wav"),
+                duration: 8.0,
+                sample_rate: 44100,
+                channels: 2,
+                format: "wav".to_string(),
+                content_hash: None,
+                file_stamp: None,
+                bpm: Some(*bpm),
+                musical_key: Some(key.to_string()),
+            })
+            .collect();
+        let loop_ids = db.add_sounds_batch(&loops).unwrap();
+        let a_minor = MusicalKey::parse("Am").unwrap();
+        let strict = SoundQuery::new().bpm(118.0, 126.0).key(a_minor, false);
+        assert_eq!(db.query_sound_ids(&strict).unwrap(), vec![loop_ids[0]]);
+        let relaxed = SoundQuery::new().bpm(118.0, 126.0).key(a_minor, true);
+        assert_eq!(db.query_sounds(&relaxed).unwrap().total, 2);
+
+        // Tags, with a minimum confidence
+        db.tag_sound(loop_ids[1], "Drum Loop", 0.9, "user").unwrap();
+        db.tag_sound(loop_ids[2], "drum loop", 0.4, "classifier").unwrap();
+        assert_eq!(db.query_sound_ids(&SoundQuery::new().tag("drum loop")).unwrap().len(), 2);
+        let confident = SoundQuery { min_tag_confidence: 0.5, ..SoundQuery::new().tag("Drum Loop") };
+        assert_eq!(db.query_sound_ids(&confident).unwrap(), vec![loop_ids[1]]);
+
+        // Paging reports the filtered total
+        let page = db.query_sounds(&SoundQuery::new().format("wav").page(0, 2)).unwrap();
+        assert_eq!((page.sounds.len(), page.total), (2, 8));
+    }
+}
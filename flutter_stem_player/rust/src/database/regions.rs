@@ -0,0 +1,189 @@
+//! Labelled regions within sounds
+//!
+//! Long recordings (field takes, sessions) often hold only a few useful
+//! moments. A region marks one of them with a label and a display colour;
+//! similarity search can be limited to regions, and regions convert to
+//! match results so the MIDI/CSV/marker exports can write them out.
+
+use super::PaletteDatabase;
+use crate::{AudioPaletteError, MatchResult, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// A marked section of a sound
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Region {
+    pub id: i64,
+    pub sound_id: i64,
+    /// Bounds in seconds from the start of the file
+    pub start_time: f64,
+    pub end_time: f64,
+    pub label: String,
+    /// Display colour chosen by the client (e.g. "#ff8800"); empty for the default
+    pub color: String,
+}
+
+pub(super) const REGION_COLUMNS: &str = "id, sound_id, start_time, end_time, label, color";
+
+pub(super) fn region_from_row(row: &rusqlite::Row) -> rusqlite::Result<Region> {
+    Ok(Region {
+        id: row.get(0)?,
+        sound_id: row.get(1)?,
+        start_time: row.get(2)?,
+        end_time: row.get(3)?,
+        label: row.get(4)?,
+        color: row.get(5)?,
+    })
+}
+
+fn region_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::RegionError(e.to_string())
+}
+
+/// Check the bounds lie within the sound, returning its duration
+fn validate_bounds(conn: &Connection, sound_id: i64, start_time: f64, end_time: f64) -> Result<f64> {
+    let duration: f64 = conn
+        .query_row("SELECT duration FROM sounds WHERE id = ?1", params![sound_id], |row| row.get(0))
+        .optional()?
+        .ok_or_else(|| region_error(format!("no sound {}", sound_id)))?;
+    if !(start_time >= 0.0 && end_time > start_time && end_time <= duration) {
+        return Err(region_error(format!(
+            "invalid region {}..{} for sound {} ({}s long)",
+            start_time, end_time, sound_id, duration
+        )));
+    }
+    Ok(duration)
+}
+
+impl PaletteDatabase {
+    /// Mark a region of a sound, returning its id
+    pub fn add_region(&self, sound_id: i64, start_time: f64, end_time: f64, label: &str, color: &str) -> Result<i64> {
+        let conn = self.writer();
+        validate_bounds(&conn, sound_id, start_time, end_time)?;
+        conn.execute(
+            "INSERT INTO regions (sound_id, start_time, end_time, label, color) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![sound_id, start_time, end_time, label.trim(), color],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Move, relabel or recolour a region
+    pub fn update_region(&self, region_id: i64, start_time: f64, end_time: f64, label: &str, color: &str) -> Result<()> {
+        let conn = self.writer();
+        let sound_id: i64 = conn
+            .query_row("SELECT sound_id FROM regions WHERE id = ?1", params![region_id], |row| row.get(0))
+            .optional()?
+            .ok_or_else(|| region_error(format!("no region {}", region_id)))?;
+        validate_bounds(&conn, sound_id, start_time, end_time)?;
+        conn.execute(
+            "UPDATE regions SET start_time = ?2, end_time = ?3, label = ?4, color = ?5 WHERE id = ?1",
+            params![region_id, start_time, end_time, label.trim(), color],
+        )?;
+        Ok(())
+    }
+
+    /// Delete a region (the sound is unaffected)
+    pub fn delete_region(&self, region_id: i64) -> Result<()> {
+        self.writer().execute("DELETE FROM regions WHERE id = ?1", params![region_id])?;
+        Ok(())
+    }
+
+    pub fn get_region(&self, region_id: i64) -> Result<Option<Region>> {
+        let region = self
+            .reader()?
+            .query_row(
+                &format!("SELECT {REGION_COLUMNS} FROM regions WHERE id = ?1"),
+                params![region_id],
+                region_from_row,
+            )
+            .optional()?;
+        Ok(region)
+    }
+
+    /// Regions of a sound in time order
+    pub fn get_regions(&self, sound_id: i64) -> Result<Vec<Region>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT {REGION_COLUMNS} FROM regions WHERE sound_id = ?1 ORDER BY start_time, id"
+        ))?;
+        let regions = stmt.query_map(params![sound_id], region_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(regions)
+    }
+
+    /// Every region in the palette, grouped by sound and in time order
+    pub fn get_all_regions(&self) -> Result<Vec<Region>> {
+        let conn = self.reader()?;
+        let mut stmt =
+            conn.prepare_cached(&format!("SELECT {REGION_COLUMNS} FROM regions ORDER BY sound_id, start_time, id"))?;
+        let regions = stmt.query_map([], region_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(regions)
+    }
+
+    /// Regions as full-score match results, in the given order, for export
+    ///
+    /// Unknown ids are skipped.
+    pub fn get_region_matches(&self, region_ids: &[i64]) -> Result<Vec<MatchResult>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT r.sound_id, resolve_path(s.filepath), s.filename, r.start_time, r.end_time, s.duration
+             FROM regions r JOIN sounds s ON s.id = r.sound_id WHERE r.id = ?1",
+        )?;
+        let mut matches = Vec::with_capacity(region_ids.len());
+        for id in region_ids {
+            let m = stmt
+                .query_row(params![id], |row| {
+                    Ok(MatchResult {
+                        sound_id: row.get(0)?,
+                        filepath: row.get(1)?,
+                        filename: row.get(2)?,
+                        score: 100.0,
+                        match_start: row.get(3)?,
+                        match_end: row.get(4)?,
+                        file_duration: row.get(5)?,
+                        time_resolution: 0.0,
+                    })
+                })
+                .optional()?;
+            matches.extend(m);
+        }
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let take = db.add_sound("/field/forest.wav", "forest.wav", 600.0, 48000, 2, "wav").unwrap();
+
+        let owl = db.add_region(take, 312.5, 318.0, " owl ", "#ffaa00").unwrap();
+        let creek = db.add_region(take, 12.0, 40.0, "creek", "").unwrap();
+        let regions = db.get_regions(take).unwrap();
+        assert_eq!(regions.iter().map(|r| r.id).collect::<Vec<_>>(), vec![creek, owl]);
+        assert_eq!(regions[1].label, "owl");
+
+        // Bounds must lie within the sound
+        for (start, end) in [(-1.0, 5.0), (10.0, 10.0), (590.0, 601.0)] {
+            assert!(matches!(db.add_region(take, start, end, "", ""), Err(AudioPaletteError::RegionError(_))));
+        }
+        assert!(db.add_region(999, 0.0, 1.0, "", "").is_err());
+
+        db.update_region(owl, 310.0, 320.0, "owl call", "#ff0000").unwrap();
+        let updated = db.get_region(owl).unwrap().unwrap();
+        assert_eq!((updated.start_time, updated.end_time, updated.color.as_str()), (310.0, 320.0, "#ff0000"));
+
+        // Regions export as match results spanning the region
+        let matches = db.get_region_matches(&[owl, 999, creek]).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!((matches[0].match_start, matches[0].match_end), (310.0, 320.0));
+        assert_eq!(matches[0].file_duration, 600.0);
+
+        db.delete_region(creek).unwrap();
+        assert_eq!(db.get_all_regions().unwrap().len(), 1);
+        db.remove_sound(take).unwrap();
+        assert!(db.get_all_regions().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+//! Recent searches
+//!
+//! Text, tag and similarity searches made through the API are recorded
+//! with their parameters and result count so the UI can list recent
+//! searches and re-run them. Repeating a search moves it to the top
+//! rather than adding a second entry. Only the newest
+//! `SEARCH_HISTORY_LIMIT` searches are kept.
+
+use super::PaletteDatabase;
+use crate::Result;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+/// Searches kept; older ones are dropped as new ones are recorded
+pub const SEARCH_HISTORY_LIMIT: i64 = 200;
+
+/// Which search was run (one per API search function)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchKind {
+    /// `search_sounds`
+    Text,
+    /// `find_by_tags`
+    Tags,
+    /// `find_similar`
+    Similar,
+    /// `find_similar_with_segments`
+    SimilarSegments,
+    /// `find_similar_in_regions`
+    SimilarInRegions,
+    /// `find_similar_matching`
+    SimilarMatching,
+    /// `find_similar_with_options`
+    SimilarWithOptions,
+    /// `find_similar_to_examples`
+    SimilarToExamples,
+    /// `find_similar_by_id`
+    SimilarToSound,
+    /// `find_containing`
+    Containment,
+    /// `find_melody`
+    Melody,
+    /// `find_similar_with_text`
+    SimilarWithText,
+    /// `find_similar_in_directory`
+    Directory,
+    /// `find_loop_sources`
+    LoopSources,
+    /// `find_similar_from_region`
+    SimilarToRegion,
+}
+
+impl SearchKind {
+    const ALL: [SearchKind; 15] = [
+        SearchKind::Text,
+        SearchKind::Tags,
+        SearchKind::Similar,
+        SearchKind::SimilarSegments,
+        SearchKind::SimilarInRegions,
+        SearchKind::SimilarMatching,
+        SearchKind::SimilarWithOptions,
+        SearchKind::SimilarToExamples,
+        SearchKind::SimilarToSound,
+        SearchKind::Containment,
+        SearchKind::Melody,
+        SearchKind::SimilarWithText,
+        SearchKind::Directory,
+        SearchKind::LoopSources,
+        SearchKind::SimilarToRegion,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            SearchKind::Text => "text",
+            SearchKind::Tags => "tags",
+            SearchKind::Similar => "similar",
+            SearchKind::SimilarSegments => "similar_segments",
+            SearchKind::SimilarInRegions => "similar_in_regions",
+            SearchKind::SimilarMatching => "similar_matching",
+            SearchKind::SimilarWithOptions => "similar_with_options",
+            SearchKind::SimilarToExamples => "similar_to_examples",
+            SearchKind::SimilarToSound => "similar_to_sound",
+            SearchKind::Containment => "containment",
+            SearchKind::Melody => "melody",
+            SearchKind::SimilarWithText => "similar_with_text",
+            SearchKind::Directory => "directory",
+            SearchKind::LoopSources => "loop_sources",
+            SearchKind::SimilarToRegion => "similar_to_region",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+/// A recorded search, newest first in `get_recent_searches`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentSearch {
+    pub id: i64,
+    pub kind: SearchKind,
+    /// Query text, tag list, query file path or sound id
+    pub query: String,
+    /// Remaining arguments as a JSON object (threshold, max_results, filter, ...)
+    pub parameters: String,
+    pub result_count: i64,
+    pub timestamp: String,
+}
+
+fn search_from_row(row: &rusqlite::Row) -> rusqlite::Result<RecentSearch> {
+    let kind: String = row.get(1)?;
+    Ok(RecentSearch {
+        id: row.get(0)?,
+        kind: SearchKind::from_name(&kind)
+            .ok_or_else(|| rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, kind.into()))?,
+        query: row.get(2)?,
+        parameters: row.get(3)?,
+        result_count: row.get(4)?,
+        timestamp: row.get(5)?,
+    })
+}
+
+impl PaletteDatabase {
+    /// Record a search, returning its entry id
+    pub fn record_search(&self, kind: SearchKind, query: &str, parameters: &str, result_count: usize) -> Result<i64> {
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        tx.execute(
+            "DELETE FROM search_history WHERE kind = ?1 AND query = ?2 AND parameters = ?3",
+            params![kind.name(), query, parameters],
+        )?;
+        tx.execute(
+            "INSERT INTO search_history (kind, query, parameters, result_count) VALUES (?1, ?2, ?3, ?4)",
+            params![kind.name(), query, parameters, result_count as i64],
+        )?;
+        let id = tx.last_insert_rowid();
+        tx.execute(
+            "DELETE FROM search_history WHERE id NOT IN (SELECT id FROM search_history ORDER BY id DESC LIMIT ?1)",
+            params![SEARCH_HISTORY_LIMIT],
+        )?;
+        tx.commit()?;
+        Ok(id)
+    }
+
+    /// The most recent searches, newest first
+    pub fn get_recent_searches(&self, limit: i64) -> Result<Vec<RecentSearch>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT id, kind, query, parameters, result_count, timestamp FROM search_history
+             ORDER BY id DESC LIMIT ?1",
+        )?;
+        let searches = stmt.query_map(params![limit], search_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(searches)
+    }
+
+    pub fn delete_recent_search(&self, search_id: i64) -> Result<()> {
+        self.writer().execute("DELETE FROM search_history WHERE id = ?1", params![search_id])?;
+        Ok(())
+    }
+
+    pub fn clear_search_history(&self) -> Result<()> {
+        self.writer().execute("DELETE FROM search_history", [])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_searches() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let params = r#"{"threshold":70.0,"max_results":20}"#;
+        db.record_search(SearchKind::Text, "kick", "{}", 12).unwrap();
+        db.record_search(SearchKind::Similar, "/q/snare.wav", params, 5).unwrap();
+        let repeat = db.record_search(SearchKind::Text, "kick", "{}", 14).unwrap();
+
+        // Repeating a search moves it to the top with the new count
+        let recent = db.get_recent_searches(10).unwrap();
+        assert_eq!(recent.len(), 2);
+        assert_eq!((recent[0].id, recent[0].kind, recent[0].result_count), (repeat, SearchKind::Text, 14));
+        assert_eq!((recent[1].query.as_str(), recent[1].parameters.as_str()), ("/q/snare.wav", params));
+
+        for i in 0..SEARCH_HISTORY_LIMIT {
+            db.record_search(SearchKind::Tags, &format!("tag{i}"), "{}", 0).unwrap();
+        }
+        assert_eq!(db.get_recent_searches(i64::MAX).unwrap().len() as i64, SEARCH_HISTORY_LIMIT);
+        assert_eq!(db.get_recent_searches(1).unwrap()[0].query, format!("tag{}", SEARCH_HISTORY_LIMIT - 1));
+
+        db.delete_recent_search(repeat).unwrap();
+        db.clear_search_history().unwrap();
+        assert!(db.get_recent_searches(10).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+//! Stored segment fingerprints
+//!
+//! The windowed fingerprints segment search matches against, one row per
+//! sound (each fingerprint length-prefixed in a single blob), tagged with
+//! the content hash they were computed from like cached waveforms.
+
+use super::{PaletteDatabase, WriteBatch};
+use crate::fingerprint::{AudioFingerprint, SegmentFingerprints, FINGERPRINT_VERSION};
+use crate::{AudioPaletteError, Result};
+use rusqlite::{params, OptionalExtension};
+
+fn segments_blob(fingerprints: &[AudioFingerprint]) -> Vec<u8> {
+    let mut blob = Vec::new();
+    for fp in fingerprints {
+        let bytes = fp.to_bytes();
+        blob.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        blob.extend_from_slice(&bytes);
+    }
+    blob
+}
+
+fn segments_from_blob(mut bytes: &[u8]) -> Result<Vec<AudioFingerprint>> {
+    let malformed = || AudioPaletteError::FingerprintError("Malformed segment fingerprint blob".to_string());
+    let mut fingerprints = Vec::new();
+    while !bytes.is_empty() {
+        let (len, rest) = bytes.split_first_chunk::<4>().ok_or_else(malformed)?;
+        let len = u32::from_le_bytes(*len) as usize;
+        let (fp, rest) = rest.split_at_checked(len).ok_or_else(malformed)?;
+        fingerprints.push(AudioFingerprint::from_bytes(fp)?);
+        bytes = rest;
+    }
+    Ok(fingerprints)
+}
+
+impl PaletteDatabase {
+    /// Store segment fingerprints for many sounds in one transaction
+    pub fn store_segment_fingerprints_batch(&self, segments: &[(i64, SegmentFingerprints)]) -> Result<()> {
+        self.write_batch(|batch| batch.store_segment_fingerprints(segments))
+    }
+
+    /// Segment fingerprints of a sound (None if not computed for its current
+    /// contents or by an older analysis)
+    pub fn get_segment_fingerprints(&self, sound_id: i64) -> Result<Option<SegmentFingerprints>> {
+        let conn = self.reader()?;
+        let row: Option<(f64, f64, Vec<u8>)> = conn
+            .prepare_cached(
+                "SELECT g.window_seconds, g.hop_seconds, g.data FROM segment_fingerprints g
+                 JOIN sounds s ON s.id = g.sound_id
+                 WHERE g.sound_id = ?1 AND g.content_hash IS s.content_hash AND g.feature_version = ?2",
+            )?
+            .query_row(params![sound_id, FINGERPRINT_VERSION], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .optional()?;
+        row.map(|(window_seconds, hop_seconds, data)| {
+            Ok(SegmentFingerprints { window_seconds, hop_seconds, fingerprints: segments_from_blob(&data)? })
+        })
+        .transpose()
+    }
+}
+
+impl WriteBatch<'_> {
+    pub(crate) fn store_segment_fingerprints(&self, segments: &[(i64, SegmentFingerprints)]) -> Result<()> {
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT OR REPLACE INTO segment_fingerprints
+                 (sound_id, content_hash, window_seconds, hop_seconds, data, feature_version)
+             SELECT id, content_hash, ?2, ?3, ?4, ?5 FROM sounds WHERE id = ?1",
+        )?;
+        for (sound_id, s) in segments {
+            let data = segments_blob(&s.fingerprints);
+            stmt.execute(params![sound_id, s.window_seconds, s.hop_seconds, data, FINGERPRINT_VERSION])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::AudioData;
+    use crate::fingerprint::Fingerprinter;
+
+    #[test]
+    fn test_segment_fingerprints() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound("/loops/pad.wav", "pad.wav", 2.0, 22050, 1, "wav").unwrap();
+        db.set_content_hash(id, "v1").unwrap();
+        assert!(db.get_segment_fingerprints(id).unwrap().is_none());
+
+        let samples: Vec<f32> = (0..44100).map(|i| (i as f32 * 0.05).sin() * 0.5).collect();
+        let segments = Fingerprinter::default().extract_segments(&AudioData::from_samples(samples, 22050)).unwrap();
+        db.store_segment_fingerprints_batch(&[(id, segments.clone())]).unwrap();
+        let stored = db.get_segment_fingerprints(id).unwrap().unwrap();
+        assert_eq!((stored.window_seconds, stored.hop_seconds), (1.0, 0.5));
+        assert_eq!(stored.fingerprints.len(), 3);
+        assert!((stored.fingerprints[1].similarity(&segments.fingerprints[1]) - 100.0).abs() < 1e-6);
+
+        // Segments of earlier file contents are stale
+        db.set_content_hash(id, "v2").unwrap();
+        assert!(db.get_segment_fingerprints(id).unwrap().is_none());
+        db.remove_sound(id).unwrap();
+    }
+}
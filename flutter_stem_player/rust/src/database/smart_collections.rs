@@ -0,0 +1,173 @@
+//! Smart collections (saved queries)
+//!
+//! Unlike a curated collection, a smart collection stores no sounds: it
+//! stores a query (metadata filter, tags and optionally a reference sound
+//! that members must resemble) and is evaluated whenever it is opened, so
+//! it picks up sounds indexed since it was saved. The reference is kept
+//! as a fingerprint, so the query still works if the file goes away.
+//! Evaluation lives in `SearchEngine::evaluate_smart_query`.
+
+use super::{PaletteDatabase, SoundQuery};
+use crate::fingerprint::AudioFingerprint;
+use crate::{AudioPaletteError, Result};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// What a smart collection contains
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SmartQuery {
+    /// Metadata filter; its sort orders results without a reference, and
+    /// its offset/limit apply to the final results
+    pub filter: SoundQuery,
+    /// Tags any of which members must have (empty for no tag filter)
+    pub tags: Vec<String>,
+    pub min_tag_confidence: f64,
+    /// File the reference fingerprint was taken from (None for no similarity filter)
+    pub reference_path: Option<String>,
+    /// Minimum similarity to the reference (0-100)
+    pub threshold: f64,
+}
+
+/// A saved smart collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartCollection {
+    pub id: i64,
+    pub name: String,
+    pub query: SmartQuery,
+    pub date_created: String,
+}
+
+fn collection_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::CollectionError(e.to_string())
+}
+
+fn validate(name: &str, query: &SmartQuery, reference: Option<&AudioFingerprint>) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(collection_error("smart collection name is empty"));
+    }
+    if query.reference_path.is_some() != reference.is_some() {
+        return Err(collection_error("reference path and fingerprint must be given together"));
+    }
+    Ok(name.to_string())
+}
+
+fn smart_collection_from_row(row: &rusqlite::Row) -> rusqlite::Result<SmartCollection> {
+    let query: String = row.get(2)?;
+    Ok(SmartCollection {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        query: serde_json::from_str(&query)
+            .map_err(|e| rusqlite::Error::FromSqlConversionFailure(2, rusqlite::types::Type::Text, e.into()))?,
+        date_created: row.get(3)?,
+    })
+}
+
+impl PaletteDatabase {
+    /// Save a smart collection, returning its id
+    ///
+    /// `reference` is the fingerprint of `query.reference_path`.
+    pub fn create_smart_collection(
+        &self,
+        name: &str,
+        query: &SmartQuery,
+        reference: Option<&AudioFingerprint>,
+    ) -> Result<i64> {
+        let name = validate(name, query, reference)?;
+        let json = serde_json::to_string(query).map_err(collection_error)?;
+        let conn = self.writer();
+        conn.execute(
+            "INSERT INTO smart_collections (name, query, reference) VALUES (?1, ?2, ?3)",
+            params![name, json, reference.map(|fp| fp.to_bytes())],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Replace the name and query of a smart collection
+    pub fn update_smart_collection(
+        &self,
+        collection_id: i64,
+        name: &str,
+        query: &SmartQuery,
+        reference: Option<&AudioFingerprint>,
+    ) -> Result<()> {
+        let name = validate(name, query, reference)?;
+        let json = serde_json::to_string(query).map_err(collection_error)?;
+        let updated = self.writer().execute(
+            "UPDATE smart_collections SET name = ?2, query = ?3, reference = ?4 WHERE id = ?1",
+            params![collection_id, name, json, reference.map(|fp| fp.to_bytes())],
+        )?;
+        if updated == 0 {
+            return Err(collection_error(format!("no smart collection {}", collection_id)));
+        }
+        Ok(())
+    }
+
+    pub fn delete_smart_collection(&self, collection_id: i64) -> Result<()> {
+        self.writer().execute("DELETE FROM smart_collections WHERE id = ?1", params![collection_id])?;
+        Ok(())
+    }
+
+    /// All smart collections, by name
+    pub fn get_smart_collections(&self) -> Result<Vec<SmartCollection>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT id, name, query, date_created FROM smart_collections ORDER BY name COLLATE NOCASE, id",
+        )?;
+        let collections = stmt.query_map([], smart_collection_from_row)?.collect::<rusqlite::Result<_>>()?;
+        Ok(collections)
+    }
+
+    /// A smart collection with its reference fingerprint, if it has one
+    pub fn get_smart_collection(&self, collection_id: i64) -> Result<Option<(SmartCollection, Option<AudioFingerprint>)>> {
+        let row = self
+            .reader()?
+            .query_row(
+                "SELECT id, name, query, date_created, reference FROM smart_collections WHERE id = ?1",
+                params![collection_id],
+                |row| Ok((smart_collection_from_row(row)?, row.get::<_, Option<Vec<u8>>>(4)?)),
+            )
+            .optional()?;
+        row.map(|(collection, reference)| {
+            let reference = reference.map(|bytes| AudioFingerprint::from_bytes(&bytes)).transpose()?;
+            Ok((collection, reference))
+        })
+        .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smart_collections() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let query = SmartQuery { tags: vec!["snare".to_string()], ..SmartQuery::default() };
+        let snares = db.create_smart_collection(" Snares ", &query, None).unwrap();
+        assert!(db.create_smart_collection("", &query, None).is_err());
+
+        // The reference path and fingerprint go together
+        let with_path = SmartQuery { reference_path: Some("/ref.wav".to_string()), threshold: 80.0, ..query.clone() };
+        assert!(matches!(
+            db.create_smart_collection("Like ref", &with_path, None),
+            Err(AudioPaletteError::CollectionError(_))
+        ));
+        let samples: Vec<f32> = (0..33075).map(|i| (i as f32 * 0.05).sin()).collect();
+        let reference = crate::fingerprint::Fingerprinter::default().extract_from_samples(&samples, 22050).unwrap();
+        let like_ref = db.create_smart_collection("Like ref", &with_path, Some(&reference)).unwrap();
+
+        let all = db.get_smart_collections().unwrap();
+        assert_eq!(all.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["Like ref", "Snares"]);
+        let (saved, fp) = db.get_smart_collection(like_ref).unwrap().unwrap();
+        assert_eq!(saved.query.reference_path.as_deref(), Some("/ref.wav"));
+        assert_eq!(fp.unwrap().duration, 1.5);
+        assert!(db.get_smart_collection(snares).unwrap().unwrap().1.is_none());
+
+        db.update_smart_collection(like_ref, "Like ref", &query, None).unwrap();
+        assert!(db.get_smart_collection(like_ref).unwrap().unwrap().1.is_none());
+        assert!(db.update_smart_collection(999, "x", &query, None).is_err());
+        db.delete_smart_collection(snares).unwrap();
+        assert_eq!(db.get_smart_collections().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,346 @@
+//! 2D sound map
+//!
+//! An offline job lays the library out on a plane so similar sounds sit
+//! near each other, for an explorable "sound map" in the app. t-SNE keeps
+//! each sound's nearest neighbours near it (cosine distance over the
+//! stored fingerprint vectors, like similarity search), starting from the
+//! two principal components so reruns over an unchanged library give the
+//! same map. Exact t-SNE compares every pair, so large libraries lay out
+//! an evenly spread sample of landmarks and place every other sound among
+//! the landmarks nearest it. Sounds added later have no position until the
+//! job runs again.
+
+use super::clusters::dot;
+use super::PaletteDatabase;
+use crate::{descending_score, Result};
+use rayon::prelude::*;
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// Sounds laid out by t-SNE itself; the rest are placed among them
+pub const MAP_LANDMARKS: usize = 1_000;
+/// Roughly how many neighbours each sound's surroundings are fitted to
+const PERPLEXITY: f64 = 30.0;
+/// Gradient descent steps
+const MAP_ITERATIONS: usize = 500;
+/// Early steps with exaggerated attraction, which lets groups form and
+/// separate before the finer layout settles
+const EXAGGERATION_ITERATIONS: usize = 100;
+const EXAGGERATION: f64 = 12.0;
+/// Landmarks a sound outside the sample is placed among
+const PLACEMENT_NEIGHBOURS: usize = 5;
+/// Steps between progress reports
+const PROGRESS_INTERVAL: usize = 10;
+
+/// Progress of a `compute_sound_map` run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapProgress {
+    /// Layout step reached (the last one once finished)
+    pub iteration: usize,
+    pub max_iterations: usize,
+    pub finished: bool,
+}
+
+/// A sound's position on the map; both coordinates run 0-1, with the
+/// shorter side of the layout centred
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MapPoint {
+    pub sound_id: i64,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Projection of `vectors` onto their two principal components
+fn principal_components(vectors: &[Vec<f32>]) -> Vec<[f64; 2]> {
+    let dims = vectors[0].len();
+    let mut mean = vec![0.0f64; dims];
+    for v in vectors {
+        mean.iter_mut().zip(v).for_each(|(m, &x)| *m += x as f64 / vectors.len() as f64);
+    }
+    let centred: Vec<Vec<f64>> =
+        vectors.iter().map(|v| v.iter().zip(&mean).map(|(&x, m)| x as f64 - m).collect()).collect();
+    let mut covariance = vec![vec![0.0f64; dims]; dims];
+    for v in &centred {
+        for (a, row) in covariance.iter_mut().enumerate() {
+            row.iter_mut().zip(v).for_each(|(c, &x)| *c += v[a] * x);
+        }
+    }
+
+    // Power iteration, deflating by each component found
+    let mut components: Vec<Vec<f64>> = Vec::new();
+    for _ in 0..2 {
+        let mut axis: Vec<f64> = (0..dims).map(|i| 1.0 + i as f64 / dims as f64).collect();
+        for _ in 0..100 {
+            let mut next: Vec<f64> = covariance.iter().map(|row| row.iter().zip(&axis).map(|(c, a)| c * a).sum()).collect();
+            for component in &components {
+                let along: f64 = next.iter().zip(component).map(|(x, c)| x * c).sum();
+                next.iter_mut().zip(component).for_each(|(x, c)| *x -= along * c);
+            }
+            let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm <= 1e-12 {
+                break;
+            }
+            axis = next.into_iter().map(|x| x / norm).collect();
+        }
+        components.push(axis);
+    }
+    let project = |v: &[f64], axis: &[f64]| v.iter().zip(axis).map(|(x, a)| x * a).sum::<f64>();
+    centred.iter().map(|v| [project(v, &components[0]), project(v, &components[1])]).collect()
+}
+
+/// Symmetric joint probabilities of t-SNE: each sound's neighbours
+/// weighted by a Gaussian whose width gives `perplexity` effective
+/// neighbours
+fn affinities(vectors: &[Vec<f32>], perplexity: f64) -> Vec<Vec<f64>> {
+    let n = vectors.len();
+    let target = perplexity.ln();
+    let conditional: Vec<Vec<f64>> = vectors
+        .par_iter()
+        .enumerate()
+        .map(|(i, v)| {
+            // Squared distance between unit vectors
+            let distances: Vec<f64> = vectors.iter().map(|w| (2.0 - 2.0 * dot(v, w) as f64).max(0.0)).collect();
+            let (mut beta, mut low, mut high) = (1.0f64, 0.0f64, f64::INFINITY);
+            let mut row = vec![0.0; n];
+            for _ in 0..64 {
+                for (j, p) in row.iter_mut().enumerate() {
+                    *p = if j == i { 0.0 } else { (-beta * distances[j]).exp() };
+                }
+                let sum: f64 = row.iter().sum::<f64>().max(1e-300);
+                let entropy = sum.ln() + beta * row.iter().zip(&distances).map(|(p, d)| p * d).sum::<f64>() / sum;
+                row.iter_mut().for_each(|p| *p /= sum);
+                if (entropy - target).abs() < 1e-5 {
+                    break;
+                }
+                if entropy > target {
+                    low = beta;
+                    beta = if high.is_finite() { (beta + high) / 2.0 } else { beta * 2.0 };
+                } else {
+                    high = beta;
+                    beta = (beta + low) / 2.0;
+                }
+            }
+            row
+        })
+        .collect();
+
+    (0..n)
+        .map(|i| (0..n).map(|j| ((conditional[i][j] + conditional[j][i]) / (2.0 * n as f64)).max(1e-12)).collect())
+        .collect()
+}
+
+/// t-SNE layout of `vectors` (unit length), reporting each
+/// `PROGRESS_INTERVAL` steps
+fn tsne(vectors: &[Vec<f32>], progress: &mut impl FnMut(&MapProgress)) -> Vec<[f64; 2]> {
+    let n = vectors.len();
+    let mut layout = principal_components(vectors);
+    // Too few sounds to have neighbourhoods; the projection is the map
+    if n < 4 {
+        return layout;
+    }
+
+    let p = affinities(vectors, PERPLEXITY.min((n - 1) as f64 / 3.0));
+    // Start from the projection shrunk to a tiny spread
+    let spread = (layout.iter().map(|y| y[0] * y[0]).sum::<f64>() / n as f64).sqrt();
+    if spread > 0.0 {
+        layout.iter_mut().for_each(|y| *y = [y[0] / spread * 1e-4, y[1] / spread * 1e-4]);
+    }
+    let learning_rate = (n as f64 / EXAGGERATION).max(50.0);
+    let mut velocity = vec![[0.0f64; 2]; n];
+    let mut gains = vec![[1.0f64; 2]; n];
+
+    for iteration in 0..MAP_ITERATIONS {
+        if iteration % PROGRESS_INTERVAL == 0 {
+            progress(&MapProgress { iteration, max_iterations: MAP_ITERATIONS, finished: false });
+        }
+        let early = iteration < EXAGGERATION_ITERATIONS;
+        let (exaggeration, momentum) = if early { (EXAGGERATION, 0.5) } else { (1.0, 0.8) };
+
+        // Student-t similarities in the layout, and their total
+        let kernel = |i: usize, j: usize| {
+            let (dx, dy) = (layout[i][0] - layout[j][0], layout[i][1] - layout[j][1]);
+            1.0 / (1.0 + dx * dx + dy * dy)
+        };
+        let total: f64 =
+            (0..n).into_par_iter().map(|i| (0..n).filter(|&j| j != i).map(|j| kernel(i, j)).sum::<f64>()).sum();
+        let gradients: Vec<[f64; 2]> = (0..n)
+            .into_par_iter()
+            .map(|i| {
+                let mut gradient = [0.0; 2];
+                for j in (0..n).filter(|&j| j != i) {
+                    let q = kernel(i, j);
+                    let force = 4.0 * (exaggeration * p[i][j] - q / total) * q;
+                    gradient[0] += force * (layout[i][0] - layout[j][0]);
+                    gradient[1] += force * (layout[i][1] - layout[j][1]);
+                }
+                gradient
+            })
+            .collect();
+
+        for ((y, (v, g)), gradient) in layout.iter_mut().zip(velocity.iter_mut().zip(&mut gains)).zip(&gradients) {
+            for d in 0..2 {
+                // Speed up while the gradient keeps its direction, slow down when it flips
+                g[d] = if (gradient[d] > 0.0) != (v[d] > 0.0) { g[d] + 0.2 } else { (g[d] * 0.8).max(0.01) };
+                v[d] = momentum * v[d] - learning_rate * g[d] * gradient[d];
+                y[d] += v[d];
+            }
+        }
+        let centre = layout.iter().fold([0.0; 2], |c, y| [c[0] + y[0] / n as f64, c[1] + y[1] / n as f64]);
+        layout.iter_mut().for_each(|y| *y = [y[0] - centre[0], y[1] - centre[1]]);
+    }
+    layout
+}
+
+/// Position of a sound outside the landmark sample: the mean of its
+/// nearest landmarks' positions, nearer ones counting more
+fn place(v: &[f32], landmarks: &[Vec<f32>], positions: &[[f64; 2]]) -> [f64; 2] {
+    let mut nearest: Vec<(f64, usize)> = landmarks.iter().enumerate().map(|(i, l)| (dot(v, l) as f64, i)).collect();
+    nearest.sort_by(|a, b| descending_score(a.0, b.0).then(a.1.cmp(&b.1)));
+    let (mut sum, mut total) = ([0.0; 2], 0.0);
+    for &(similarity, i) in nearest.iter().take(PLACEMENT_NEIGHBOURS) {
+        let weight = 1.0 / (1.0 - similarity).max(1e-6);
+        sum = [sum[0] + weight * positions[i][0], sum[1] + weight * positions[i][1]];
+        total += weight;
+    }
+    [sum[0] / total, sum[1] / total]
+}
+
+/// Scale a layout into the unit square, keeping its aspect ratio
+fn fit_unit_square(layout: &mut [[f64; 2]]) {
+    let bounds = |d: usize| {
+        let values = layout.iter().map(|y| y[d]);
+        (values.clone().fold(f64::INFINITY, f64::min), values.fold(f64::NEG_INFINITY, f64::max))
+    };
+    let (x, y) = (bounds(0), bounds(1));
+    let span = (x.1 - x.0).max(y.1 - y.0);
+    for point in layout.iter_mut() {
+        *point = if span > 0.0 {
+            [
+                (point[0] - x.0 + (span - (x.1 - x.0)) / 2.0) / span,
+                (point[1] - y.0 + (span - (y.1 - y.0)) / 2.0) / span,
+            ]
+        } else {
+            [0.5, 0.5]
+        };
+    }
+}
+
+impl PaletteDatabase {
+    /// Lay out every fingerprinted sound on the 2D map, replacing the
+    /// previous layout; returns the number of sounds placed
+    ///
+    /// Reports progress every few layout steps and once when done.
+    pub fn compute_sound_map(&self, mut progress: impl FnMut(&MapProgress)) -> Result<usize> {
+        let (ids, vectors) = self.unit_vectors()?;
+        let mut layout = if vectors.is_empty() {
+            Vec::new()
+        } else if vectors.len() <= MAP_LANDMARKS {
+            tsne(&vectors, &mut progress)
+        } else {
+            let landmarks: Vec<Vec<f32>> =
+                (0..MAP_LANDMARKS).map(|i| vectors[i * vectors.len() / MAP_LANDMARKS].clone()).collect();
+            let positions = tsne(&landmarks, &mut progress);
+            vectors.par_iter().map(|v| place(v, &landmarks, &positions)).collect()
+        };
+        fit_unit_square(&mut layout);
+
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM sound_map", [])?;
+        {
+            let mut insert = tx.prepare("INSERT INTO sound_map (sound_id, x, y) VALUES (?1, ?2, ?3)")?;
+            for (id, [x, y]) in ids.iter().zip(&layout) {
+                insert.execute(params![id, x, y])?;
+            }
+        }
+        tx.commit()?;
+
+        progress(&MapProgress { iteration: MAP_ITERATIONS, max_iterations: MAP_ITERATIONS, finished: true });
+        Ok(ids.len())
+    }
+
+    /// Every sound's position from the last `compute_sound_map` run
+    pub fn get_sound_map(&self) -> Result<Vec<MapPoint>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare("SELECT sound_id, x, y FROM sound_map ORDER BY sound_id")?;
+        let points = stmt.query_map([], |row| Ok(MapPoint { sound_id: row.get(0)?, x: row.get(1)?, y: row.get(2)? }))?;
+        Ok(points.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// A sound's position on the map (None if it hasn't been placed)
+    pub fn get_map_position(&self, sound_id: i64) -> Result<Option<MapPoint>> {
+        let conn = self.reader()?;
+        let point = conn
+            .query_row("SELECT sound_id, x, y FROM sound_map WHERE sound_id = ?1", params![sound_id], |row| {
+                Ok(MapPoint { sound_id: row.get(0)?, x: row.get(1)?, y: row.get(2)? })
+            })
+            .optional()?;
+        Ok(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::AudioFingerprint;
+
+    fn fingerprint(family: usize, variant: usize) -> AudioFingerprint {
+        let wobble = 1.0 + variant as f64 * 0.05;
+        AudioFingerprint {
+            duration: 1.0,
+            sample_rate: 44100,
+            mfcc_mean: (0..13).map(|i| if i % 3 == family { 10.0 * wobble } else { 0.5 * wobble }).collect(),
+            mfcc_std: vec![1.0; 13],
+            spectral_centroid: 1000.0 + 3000.0 * family as f64,
+            spectral_bandwidth: 1500.0,
+            spectral_rolloff: 5000.0,
+            rms_mean: 0.2,
+            rms_std: 0.05,
+            zero_crossing_rate: 0.1,
+            chroma_mean: vec![0.5; 12],
+            noise: None,
+            stereo: None,
+        }
+    }
+
+    #[test]
+    fn test_sound_map() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut family_of = std::collections::HashMap::new();
+        for variant in 0..5 {
+            for family in 0..3 {
+                let name = format!("{family}_{variant}.wav");
+                let id = db.add_sound(&format!("/lib/{name}"), &name, 1.0, 44100, 1, "wav").unwrap();
+                db.store_fingerprint(id, &fingerprint(family, variant)).unwrap();
+                family_of.insert(id, family);
+            }
+        }
+        let unfingerprinted = db.add_sound("/lib/new.wav", "new.wav", 1.0, 44100, 1, "wav").unwrap();
+
+        let mut updates = Vec::new();
+        assert_eq!(db.compute_sound_map(|p| updates.push(p.clone())).unwrap(), 15);
+        assert!(updates.last().unwrap().finished);
+
+        let map = db.get_sound_map().unwrap();
+        assert_eq!(map.len(), 15);
+        assert!(map.iter().all(|p| (0.0..=1.0).contains(&p.x) && (0.0..=1.0).contains(&p.y)));
+        // Every sound's nearest neighbour on the map is of its own family
+        for point in &map {
+            let nearest = map
+                .iter()
+                .filter(|other| other.sound_id != point.sound_id)
+                .min_by(|a, b| {
+                    let distance = |o: &MapPoint| (o.x - point.x).powi(2) + (o.y - point.y).powi(2);
+                    distance(a).total_cmp(&distance(b))
+                })
+                .unwrap();
+            assert_eq!(family_of[&nearest.sound_id], family_of[&point.sound_id]);
+        }
+        assert_eq!(db.get_map_position(map[0].sound_id).unwrap(), Some(map[0]));
+        assert_eq!(db.get_map_position(unfingerprinted).unwrap(), None);
+
+        // The same library gives the same map
+        db.compute_sound_map(|_| {}).unwrap();
+        assert_eq!(db.get_sound_map().unwrap(), map);
+    }
+}
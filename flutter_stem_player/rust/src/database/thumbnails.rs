@@ -0,0 +1,81 @@
+//! Cached thumbnails
+//!
+//! One image per sound and kind, tagged with the content hash it was
+//! rendered from like cached waveforms, so thumbnails of a file that has
+//! since changed read as missing.
+
+use super::{PaletteDatabase, WriteBatch};
+use crate::render::{Thumbnail, ThumbnailKind};
+use crate::Result;
+use rusqlite::{params, OptionalExtension};
+
+impl PaletteDatabase {
+    /// Store thumbnails for many sounds in one transaction, replacing
+    /// earlier ones of the same kinds
+    pub fn store_thumbnails_batch(&self, thumbnails: &[(i64, Vec<Thumbnail>)]) -> Result<()> {
+        self.write_batch(|batch| batch.store_thumbnails(thumbnails))
+    }
+
+    /// Cached thumbnail of a sound (None if not rendered for its current contents)
+    pub fn get_thumbnail(&self, sound_id: i64, kind: ThumbnailKind) -> Result<Option<Thumbnail>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT t.mime_type, t.data FROM thumbnails t JOIN sounds s ON s.id = t.sound_id
+             WHERE t.sound_id = ?1 AND t.kind = ?2 AND t.content_hash IS s.content_hash",
+        )?;
+        let thumbnail = stmt
+            .query_row(params![sound_id, kind.name()], |row| {
+                Ok(Thumbnail { kind, mime_type: row.get(0)?, data: row.get(1)? })
+            })
+            .optional()?;
+        Ok(thumbnail)
+    }
+
+    /// Cached thumbnails for a page of sounds, parallel to `sound_ids`
+    pub fn get_thumbnails(&self, sound_ids: &[i64], kind: ThumbnailKind) -> Result<Vec<Option<Thumbnail>>> {
+        sound_ids.iter().map(|&id| self.get_thumbnail(id, kind)).collect()
+    }
+}
+
+impl WriteBatch<'_> {
+    pub(crate) fn store_thumbnails(&self, thumbnails: &[(i64, Vec<Thumbnail>)]) -> Result<()> {
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT OR REPLACE INTO thumbnails (sound_id, kind, content_hash, mime_type, data)
+             SELECT id, ?2, content_hash, ?3, ?4 FROM sounds WHERE id = ?1",
+        )?;
+        for (sound_id, images) in thumbnails {
+            for image in images {
+                stmt.execute(params![sound_id, image.kind.name(), image.mime_type, image.data])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnails() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound("/kit/kick.wav", "kick.wav", 0.5, 44100, 1, "wav").unwrap();
+        let other = db.add_sound("/kit/hat.wav", "hat.wav", 0.2, 44100, 1, "wav").unwrap();
+        db.set_content_hash(id, "v1").unwrap();
+
+        let waveform = Thumbnail { kind: ThumbnailKind::Waveform, mime_type: "image/png".to_string(), data: vec![1, 2] };
+        let artwork = Thumbnail { kind: ThumbnailKind::Artwork, mime_type: "image/jpeg".to_string(), data: vec![3] };
+        db.store_thumbnails_batch(&[(id, vec![waveform.clone(), artwork.clone()])]).unwrap();
+        assert_eq!(db.get_thumbnail(id, ThumbnailKind::Artwork).unwrap(), Some(artwork));
+        assert_eq!(db.get_thumbnail(id, ThumbnailKind::Spectrogram).unwrap(), None);
+        assert_eq!(
+            db.get_thumbnails(&[other, id], ThumbnailKind::Waveform).unwrap(),
+            vec![None, Some(waveform)]
+        );
+
+        // Thumbnails of earlier file contents are stale
+        db.set_content_hash(id, "v2").unwrap();
+        assert_eq!(db.get_thumbnail(id, ThumbnailKind::Waveform).unwrap(), None);
+        db.remove_sound(id).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+//! Bounded top-k selection of scored fingerprints
+//!
+//! Scoring every stored fingerprint to return the best ten shouldn't
+//! collect and sort them all. A max-heap ordered worst-on-top keeps only
+//! the `k` best seen so far: each candidate is compared with the worst
+//! kept and replaces it or is dropped, and that worst score is a bar a
+//! candidate's upper bound must reach before it is scored at all.
+
+use super::VectorMatch;
+use crate::descending_score;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A match ordered by rank: better matches compare less, so the heap's
+/// top is the worst one kept
+struct Ranked(VectorMatch);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        descending_score(self.0.score, other.0.score).then(self.0.sound_id.cmp(&other.0.sound_id))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+/// The `k` best matches offered, best score first, ties by sound id
+pub(super) struct TopK {
+    k: usize,
+    heap: BinaryHeap<Ranked>,
+}
+
+impl TopK {
+    pub(super) fn new(k: usize) -> Self {
+        // Large limits ("everything above the threshold") grow as needed
+        TopK { k, heap: BinaryHeap::with_capacity(k.min(1024)) }
+    }
+
+    /// Score a candidate has to beat once `k` are kept (None until then)
+    pub(super) fn floor(&self) -> Option<f64> {
+        if self.heap.len() < self.k {
+            return None;
+        }
+        self.heap.peek().map(|worst| worst.0.score)
+    }
+
+    pub(super) fn push(&mut self, candidate: VectorMatch) {
+        let candidate = Ranked(candidate);
+        if self.heap.len() < self.k {
+            self.heap.push(candidate);
+        } else if let Some(mut worst) = self.heap.peek_mut() {
+            if candidate < *worst {
+                *worst = candidate;
+            }
+        }
+    }
+
+    pub(super) fn into_sorted_vec(self) -> Vec<VectorMatch> {
+        self.heap.into_sorted_vec().into_iter().map(|ranked| ranked.0).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_k() {
+        let scored = |sound_id: i64, score: f64| VectorMatch { sound_id, score, snr_penalty: 0.0 };
+        let candidates: Vec<VectorMatch> =
+            (0..1_000).map(|i| scored(i, ((i * 7919) % 101) as f64)).chain([scored(5_000, f64::NAN)]).collect();
+
+        let mut expected = candidates.clone();
+        expected.sort_by(|a, b| descending_score(a.score, b.score).then(a.sound_id.cmp(&b.sound_id)));
+        for k in [0, 1, 10, 2_000] {
+            let mut top = TopK::new(k);
+            for m in candidates.iter().cloned() {
+                top.push(m);
+            }
+            let kept = top.into_sorted_vec();
+            let ids = |ms: &[VectorMatch]| ms.iter().map(|m| m.sound_id).collect::<Vec<_>>();
+            assert_eq!(ids(&kept), ids(&expected[..k.min(expected.len())]));
+        }
+
+        let mut top = TopK::new(2);
+        assert_eq!(top.floor(), None);
+        top.push(scored(1, 50.0));
+        top.push(scored(2, 80.0));
+        top.push(scored(3, 60.0));
+        assert_eq!(top.floor(), Some(60.0));
+    }
+}
@@ -0,0 +1,187 @@
+//! Per-sound usage statistics
+//!
+//! Counts how often each sound shows up in search results, is auditioned
+//! and is exported, for "most used" and "recently used" views. Showing up
+//! in results is passive, so only auditions and exports move `last_used`.
+
+use super::{qualified_sound_columns, sound_from_row, PaletteDatabase, SOUND_COLUMN_COUNT};
+use crate::{Result, SoundRecord};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// Something that happened to a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UsageEvent {
+    /// Listed in search results
+    Returned,
+    /// Played back by the user
+    Auditioned,
+    /// Written out by an export (MIDI, CSV, markers, ...)
+    Exported,
+}
+
+impl UsageEvent {
+    fn column(self) -> &'static str {
+        match self {
+            UsageEvent::Returned => "times_returned",
+            UsageEvent::Auditioned => "times_auditioned",
+            UsageEvent::Exported => "times_exported",
+        }
+    }
+}
+
+/// Usage counters for one sound
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SoundUsage {
+    pub times_returned: i64,
+    pub times_auditioned: i64,
+    pub times_exported: i64,
+    /// When the sound was last auditioned or exported (None if never)
+    pub last_used: Option<String>,
+}
+
+/// A sound together with its usage counters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsedSound {
+    pub sound: SoundRecord,
+    pub usage: SoundUsage,
+}
+
+/// Millisecond timestamps, so "recently used" orders events within a second
+const NOW: &str = "strftime('%Y-%m-%d %H:%M:%f', 'now')";
+
+impl PaletteDatabase {
+    /// Count one `event` for each sound (unknown ids are ignored)
+    pub fn record_usage(&self, sound_ids: &[i64], event: UsageEvent) -> Result<()> {
+        let column = event.column();
+        let last_used = if event == UsageEvent::Returned { "NULL".to_string() } else { NOW.to_string() };
+        let conn = self.writer();
+        let tx = conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare(&format!(
+                "INSERT INTO sound_usage (sound_id, {column}, last_used)
+                 SELECT id, 1, {last_used} FROM sounds WHERE id = ?1
+                 ON CONFLICT(sound_id) DO UPDATE SET
+                     {column} = {column} + 1,
+                     last_used = IFNULL(excluded.last_used, last_used)"
+            ))?;
+            for id in sound_ids {
+                stmt.execute(params![id])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Usage counters for a sound (all zero if it was never used)
+    pub fn get_sound_usage(&self, sound_id: i64) -> Result<SoundUsage> {
+        let usage = self
+            .reader()?
+            .query_row(
+                "SELECT times_returned, times_auditioned, times_exported, last_used
+                 FROM sound_usage WHERE sound_id = ?1",
+                params![sound_id],
+                |row| {
+                    Ok(SoundUsage {
+                        times_returned: row.get(0)?,
+                        times_auditioned: row.get(1)?,
+                        times_exported: row.get(2)?,
+                        last_used: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(usage.unwrap_or_default())
+    }
+
+    /// Sounds auditioned or exported most often, most used first
+    ///
+    /// Ties go to the sound returned in results more often, then to the
+    /// one used more recently.
+    pub fn most_used_sounds(&self, limit: i64) -> Result<Vec<UsedSound>> {
+        self.used_sounds(
+            "u.times_auditioned + u.times_exported > 0",
+            "u.times_auditioned + u.times_exported DESC, u.times_returned DESC, u.last_used DESC",
+            limit,
+        )
+    }
+
+    /// Sounds auditioned or exported, most recent first
+    pub fn recently_used_sounds(&self, limit: i64) -> Result<Vec<UsedSound>> {
+        self.used_sounds("u.last_used IS NOT NULL", "u.last_used DESC", limit)
+    }
+
+    fn used_sounds(&self, filter: &str, order: &str, limit: i64) -> Result<Vec<UsedSound>> {
+        let conn = self.reader()?;
+        let columns = qualified_sound_columns("s");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {columns}, u.times_returned, u.times_auditioned, u.times_exported, u.last_used
+             FROM sound_usage u JOIN sounds s ON s.id = u.sound_id
+             WHERE {filter} ORDER BY {order}, s.id LIMIT ?1"
+        ))?;
+
+        let sounds = stmt
+            .query_map(params![limit], |row| {
+                Ok(UsedSound {
+                    sound: sound_from_row(row)?,
+                    usage: SoundUsage {
+                        times_returned: row.get(SOUND_COLUMN_COUNT)?,
+                        times_auditioned: row.get(SOUND_COLUMN_COUNT + 1)?,
+                        times_exported: row.get(SOUND_COLUMN_COUNT + 2)?,
+                        last_used: row.get(SOUND_COLUMN_COUNT + 3)?,
+                    },
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(sounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_statistics() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let ids: Vec<i64> = ["kick", "snare", "hat"]
+            .iter()
+            .map(|name| db.add_sound(&format!("/kit/{}.wav", name), &format!("{}.wav", name), 0.5, 44100, 1, "wav").unwrap())
+            .collect();
+        assert_eq!(db.get_sound_usage(ids[0]).unwrap(), SoundUsage::default());
+
+        // Being returned counts but doesn't make a sound "used"
+        db.record_usage(&ids, UsageEvent::Returned).unwrap();
+        db.record_usage(&[ids[0], 999], UsageEvent::Returned).unwrap();
+        let usage = db.get_sound_usage(ids[0]).unwrap();
+        assert_eq!(usage.times_returned, 2);
+        assert!(usage.last_used.is_none());
+        assert!(db.most_used_sounds(10).unwrap().is_empty());
+        assert!(db.recently_used_sounds(10).unwrap().is_empty());
+
+        db.record_usage(&[ids[1]], UsageEvent::Auditioned).unwrap();
+        db.record_usage(&[ids[1]], UsageEvent::Auditioned).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        db.record_usage(&[ids[2]], UsageEvent::Exported).unwrap();
+
+        let usage = db.get_sound_usage(ids[1]).unwrap();
+        assert_eq!((usage.times_returned, usage.times_auditioned, usage.times_exported), (1, 2, 0));
+        assert!(usage.last_used.is_some());
+
+        let most: Vec<i64> = db.most_used_sounds(10).unwrap().iter().map(|u| u.sound.id).collect();
+        assert_eq!(most, vec![ids[1], ids[2]]);
+        let recent = db.recently_used_sounds(1).unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].sound.id, ids[2]);
+        assert_eq!(recent[0].usage.times_exported, 1);
+
+        // Returned again: last_used is kept
+        db.record_usage(&[ids[2]], UsageEvent::Returned).unwrap();
+        assert_eq!(db.get_sound_usage(ids[2]).unwrap().last_used, recent[0].usage.last_used);
+
+        db.remove_sound(ids[1]).unwrap();
+        assert_eq!(db.get_sound_usage(ids[1]).unwrap(), SoundUsage::default());
+    }
+}
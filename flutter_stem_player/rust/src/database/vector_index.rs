@@ -0,0 +1,401 @@
+//! Approximate nearest-neighbour index over fingerprint vectors
+//!
+//! Scoring every stored vector is fine for a few thousand sounds but not
+//! for 100k+, so fingerprints are also linked into an HNSW graph
+//! (hierarchical navigable small world). Each node's neighbour lists live
+//! in `vector_links`, one row per level, and are updated in the same
+//! transaction that stores or deletes the fingerprint: the index is
+//! persisted with the palette, follows rollbacks, and never needs a
+//! rebuild. A search reads only the nodes it visits.
+//!
+//! The graph ranks by the mono `vector` alone; `nearest_fingerprints`
+//! rescores the candidates it returns exactly.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Neighbours kept per node above level 0
+const MAX_LINKS: usize = 12;
+/// Neighbours kept per node on level 0
+const MAX_LINKS_BASE: usize = 2 * MAX_LINKS;
+/// Candidate list size while linking a new node
+const EF_CONSTRUCTION: usize = 64;
+const MAX_LEVEL: usize = 16;
+
+fn max_links(level: usize) -> usize {
+    if level == 0 { MAX_LINKS_BASE } else { MAX_LINKS }
+}
+
+/// Top level of a node, drawn from the usual exponential distribution
+///
+/// Seeded by the sound id so rebuilding a palette reproduces its graph.
+fn node_level(sound_id: i64) -> usize {
+    // splitmix64
+    let mut z = (sound_id as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    let uniform = ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+    ((-uniform.ln() / (MAX_LINKS as f64).ln()) as usize).min(MAX_LEVEL)
+}
+
+pub(super) fn unpack_vector(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect()
+}
+
+fn pack_links(links: &[i64]) -> Vec<u8> {
+    links.iter().flat_map(|id| id.to_le_bytes()).collect()
+}
+
+fn unpack_links(blob: &[u8]) -> Vec<i64> {
+    blob.chunks_exact(8).map(|b| i64::from_le_bytes(b.try_into().unwrap())).collect()
+}
+
+/// Cosine distance (0-2) as sqlite-vec computes it; vectors of another
+/// length are as far away as possible
+fn cosine_distance(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 2.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 1.0;
+    }
+    1.0 - dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// A node at some distance from the vector being searched for
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f32,
+    sound_id: i64,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance).then(self.sound_id.cmp(&other.sound_id))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The graph as seen through one connection, caching vectors read during
+/// a single insert, removal or search
+struct Graph<'a> {
+    conn: &'a Connection,
+    vectors: HashMap<i64, Option<Vec<f32>>>,
+}
+
+impl<'a> Graph<'a> {
+    fn new(conn: &'a Connection) -> Self {
+        Graph { conn, vectors: HashMap::new() }
+    }
+
+    /// Vector of a node (None once its fingerprint is gone)
+    fn vector(&mut self, sound_id: i64) -> rusqlite::Result<Option<&[f32]>> {
+        if !self.vectors.contains_key(&sound_id) {
+            let blob: Option<Vec<u8>> = self
+                .conn
+                .prepare_cached("SELECT vector FROM fingerprints WHERE sound_id = ?1")?
+                .query_row(params![sound_id], |row| row.get(0))
+                .optional()?
+                .flatten();
+            self.vectors.insert(sound_id, blob.map(|b| unpack_vector(&b)));
+        }
+        Ok(self.vectors[&sound_id].as_deref())
+    }
+
+    fn distance(&mut self, query: &[f32], sound_id: i64) -> rusqlite::Result<Option<f32>> {
+        Ok(self.vector(sound_id)?.map(|v| cosine_distance(query, v)))
+    }
+
+    fn links(&self, sound_id: i64, level: usize) -> rusqlite::Result<Vec<i64>> {
+        let blob: Option<Vec<u8>> = self
+            .conn
+            .prepare_cached("SELECT neighbors FROM vector_links WHERE sound_id = ?1 AND level = ?2")?
+            .query_row(params![sound_id, level as i64], |row| row.get(0))
+            .optional()?;
+        Ok(blob.map(|b| unpack_links(&b)).unwrap_or_default())
+    }
+
+    fn set_links(&self, sound_id: i64, level: usize, links: &[i64]) -> rusqlite::Result<()> {
+        self.conn
+            .prepare_cached("INSERT OR REPLACE INTO vector_links (sound_id, level, neighbors) VALUES (?1, ?2, ?3)")?
+            .execute(params![sound_id, level as i64, pack_links(links)])?;
+        Ok(())
+    }
+
+    /// Node on the highest level, where searches start
+    fn entry_point(&self) -> rusqlite::Result<Option<(i64, usize)>> {
+        self.conn
+            .prepare_cached("SELECT sound_id, level FROM vector_links ORDER BY level DESC, sound_id LIMIT 1")?
+            .query_row([], |row| Ok((row.get(0)?, row.get::<_, i64>(1)? as usize)))
+            .optional()
+    }
+
+    /// Greedy best-first search of one level, returning up to `ef` nodes
+    /// nearest to `query`, nearest first
+    fn search_level(
+        &mut self,
+        query: &[f32],
+        entry: &[Candidate],
+        ef: usize,
+        level: usize,
+    ) -> rusqlite::Result<Vec<Candidate>> {
+        let mut visited: HashSet<i64> = entry.iter().map(|c| c.sound_id).collect();
+        let mut frontier: BinaryHeap<Reverse<Candidate>> = entry.iter().copied().map(Reverse).collect();
+        let mut nearest: BinaryHeap<Candidate> = entry.iter().copied().collect();
+
+        while let Some(Reverse(current)) = frontier.pop() {
+            if nearest.len() >= ef && nearest.peek().is_some_and(|far| current.distance > far.distance) {
+                break;
+            }
+            for neighbour in self.links(current.sound_id, level)? {
+                if !visited.insert(neighbour) {
+                    continue;
+                }
+                // Links to removed nodes are skipped until the next repair
+                let Some(distance) = self.distance(query, neighbour)? else {
+                    continue;
+                };
+                let candidate = Candidate { distance, sound_id: neighbour };
+                if nearest.len() < ef || nearest.peek().is_some_and(|far| distance < far.distance) {
+                    frontier.push(Reverse(candidate));
+                    nearest.push(candidate);
+                    if nearest.len() > ef {
+                        nearest.pop();
+                    }
+                }
+            }
+        }
+        Ok(nearest.into_sorted_vec())
+    }
+
+    /// Pick up to `count` neighbours from `candidates` (nearest first),
+    /// preferring ones that are not already reachable through a closer pick
+    fn select_neighbours(&mut self, candidates: &[Candidate], count: usize) -> rusqlite::Result<Vec<i64>> {
+        let mut selected: Vec<i64> = Vec::with_capacity(count);
+        let mut skipped = Vec::new();
+        for candidate in candidates {
+            if selected.len() == count {
+                break;
+            }
+            let Some(vector) = self.vector(candidate.sound_id)?.map(<[f32]>::to_vec) else {
+                continue;
+            };
+            let mut diverse = true;
+            for &chosen in &selected {
+                if self.distance(&vector, chosen)?.is_some_and(|d| d < candidate.distance) {
+                    diverse = false;
+                    break;
+                }
+            }
+            if diverse {
+                selected.push(candidate.sound_id);
+            } else {
+                skipped.push(candidate.sound_id);
+            }
+        }
+        // Top up with the nearest skipped ones so nodes stay well connected
+        let missing = count.saturating_sub(selected.len());
+        selected.extend(skipped.into_iter().take(missing));
+        Ok(selected)
+    }
+
+    /// Rank `ids` by distance to `sound_id`'s vector and keep the best
+    /// `max_links(level)` as its neighbours on `level`
+    fn relink(&mut self, sound_id: i64, level: usize, ids: impl IntoIterator<Item = i64>) -> rusqlite::Result<()> {
+        let Some(base) = self.vector(sound_id)?.map(<[f32]>::to_vec) else {
+            return Ok(());
+        };
+        let mut candidates = Vec::new();
+        for id in ids.into_iter().filter(|&id| id != sound_id).collect::<HashSet<_>>() {
+            if let Some(distance) = self.distance(&base, id)? {
+                candidates.push(Candidate { distance, sound_id: id });
+            }
+        }
+        candidates.sort();
+        let links = self.select_neighbours(&candidates, max_links(level))?;
+        self.set_links(sound_id, level, &links)
+    }
+}
+
+/// Link a sound's vector into the graph, replacing an earlier node for it
+pub(super) fn insert(conn: &Connection, sound_id: i64, vector: &[f32]) -> rusqlite::Result<()> {
+    remove(conn, sound_id)?;
+    let mut graph = Graph::new(conn);
+    graph.vectors.insert(sound_id, Some(vector.to_vec()));
+    let level = node_level(sound_id);
+
+    let Some((entry, top)) = graph.entry_point()? else {
+        for l in 0..=level {
+            graph.set_links(sound_id, l, &[])?;
+        }
+        return Ok(());
+    };
+
+    let Some(distance) = graph.distance(vector, entry)? else {
+        return Ok(());
+    };
+    let mut nearest = vec![Candidate { distance, sound_id: entry }];
+    for l in (level + 1..=top).rev() {
+        nearest = graph.search_level(vector, &nearest, 1, l)?;
+    }
+    for l in (0..=level.min(top)).rev() {
+        nearest = graph.search_level(vector, &nearest, EF_CONSTRUCTION, l)?;
+        let links = graph.select_neighbours(&nearest, max_links(l))?;
+        graph.set_links(sound_id, l, &links)?;
+        for neighbour in links {
+            let mut neighbour_links = graph.links(neighbour, l)?;
+            neighbour_links.push(sound_id);
+            if neighbour_links.len() > max_links(l) {
+                graph.relink(neighbour, l, neighbour_links)?;
+            } else {
+                graph.set_links(neighbour, l, &neighbour_links)?;
+            }
+        }
+    }
+    // The new node may start levels above the current top
+    for l in top + 1..=level {
+        graph.set_links(sound_id, l, &[])?;
+    }
+    Ok(())
+}
+
+/// Unlink a sound from the graph, reconnecting its former neighbours
+pub(super) fn remove(conn: &Connection, sound_id: i64) -> rusqlite::Result<()> {
+    let levels: Vec<(usize, Vec<i64>)> = conn
+        .prepare_cached("SELECT level, neighbors FROM vector_links WHERE sound_id = ?1")?
+        .query_map(params![sound_id], |row| {
+            Ok((row.get::<_, i64>(0)? as usize, unpack_links(&row.get::<_, Vec<u8>>(1)?)))
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    if levels.is_empty() {
+        return Ok(());
+    }
+    conn.prepare_cached("DELETE FROM vector_links WHERE sound_id = ?1")?.execute(params![sound_id])?;
+
+    let mut graph = Graph::new(conn);
+    for (level, neighbours) in levels {
+        for &neighbour in &neighbours {
+            let links = graph.links(neighbour, level)?;
+            if links.contains(&sound_id) {
+                let others = neighbours.iter().copied().filter(|&id| id != neighbour);
+                graph.relink(neighbour, level, links.into_iter().filter(|&id| id != sound_id).chain(others))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Number of sounds in the graph
+pub(super) fn len(conn: &Connection) -> rusqlite::Result<usize> {
+    conn.prepare_cached("SELECT COUNT(*) FROM vector_links WHERE level = 0")?
+        .query_row([], |row| row.get::<_, i64>(0))
+        .map(|n| n as usize)
+}
+
+/// Up to `ef` sounds whose vectors are (approximately) nearest to
+/// `query`, nearest first
+pub(super) fn search(conn: &Connection, query: &[f32], ef: usize) -> rusqlite::Result<Vec<i64>> {
+    let mut graph = Graph::new(conn);
+    let Some((entry, top)) = graph.entry_point()? else {
+        return Ok(Vec::new());
+    };
+    let Some(distance) = graph.distance(query, entry)? else {
+        return Ok(Vec::new());
+    };
+    let mut nearest = vec![Candidate { distance, sound_id: entry }];
+    for level in (1..=top).rev() {
+        nearest = graph.search_level(query, &nearest, 1, level)?;
+    }
+    let nearest = graph.search_level(query, &nearest, ef.max(1), 0)?;
+    Ok(nearest.into_iter().map(|c| c.sound_id).collect())
+}
+
+/// Link every stored vector that is not in the graph yet
+pub(super) fn build(conn: &Connection) -> rusqlite::Result<usize> {
+    let missing: Vec<(i64, Vec<u8>)> = conn
+        .prepare(
+            "SELECT sound_id, vector FROM fingerprints
+             WHERE vector IS NOT NULL AND sound_id NOT IN (SELECT sound_id FROM vector_links WHERE level = 0)
+             ORDER BY sound_id",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    for (sound_id, blob) in &missing {
+        insert(conn, *sound_id, &unpack_vector(blob))?;
+    }
+    Ok(missing.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::PaletteDatabase;
+
+    #[test]
+    fn test_vector_index_recall() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let conn = db.writer();
+        let vector = |i: usize| -> Vec<f32> { (0..16).map(|d| ((i * 7 + d * 13) as f32 * 0.37).sin()).collect() };
+        let blob = |v: &[f32]| -> Vec<u8> { v.iter().flat_map(|x| x.to_le_bytes()).collect() };
+        for i in 0..400 {
+            conn.execute(
+                "INSERT INTO sounds (filepath, filename, duration, sample_rate, channels, format)
+                 VALUES (?1, ?1, 1.0, 44100, 1, 'wav')",
+                params![format!("/kit/{i}.wav")],
+            )
+            .unwrap();
+            let id = conn.last_insert_rowid();
+            conn.execute("INSERT INTO fingerprints (sound_id, fingerprint, vector) VALUES (?1, x'00', ?2)", params![
+                id,
+                blob(&vector(i))
+            ])
+            .unwrap();
+        }
+        assert_eq!(build(&conn).unwrap(), 400);
+        assert_eq!(len(&conn).unwrap(), 400);
+
+        let exact = |query: &[f32], removed: &HashSet<i64>| -> Vec<i64> {
+            let mut all: Vec<Candidate> = (0..400)
+                .map(|i| Candidate { distance: cosine_distance(query, &vector(i)), sound_id: i as i64 + 1 })
+                .filter(|c| !removed.contains(&c.sound_id))
+                .collect();
+            all.sort();
+            all.into_iter().take(10).map(|c| c.sound_id).collect()
+        };
+        let recall = |removed: &HashSet<i64>| -> f64 {
+            let mut found = 0;
+            for q in 0..20 {
+                let query: Vec<f32> = vector(q * 19 + 3).iter().map(|x| x + 0.05).collect();
+                let approximate: HashSet<i64> = search(&conn, &query, 40).unwrap().into_iter().take(10).collect();
+                found += exact(&query, removed).iter().filter(|id| approximate.contains(id)).count();
+            }
+            found as f64 / 200.0
+        };
+        assert!(recall(&HashSet::new()) >= 0.9);
+
+        // Removing nodes reconnects their neighbours
+        let removed: HashSet<i64> = (1..=400).step_by(3).collect();
+        for &id in &removed {
+            remove(&conn, id).unwrap();
+            conn.execute("DELETE FROM fingerprints WHERE sound_id = ?1", params![id]).unwrap();
+        }
+        assert_eq!(len(&conn).unwrap(), 400 - removed.len());
+        assert!(recall(&removed) >= 0.9);
+        assert!(search(&conn, &vector(5), 40).unwrap().iter().all(|id| !removed.contains(id)));
+    }
+}
@@ -0,0 +1,340 @@
+//! Nearest-neighbour fingerprint search inside SQLite
+//!
+//! Each fingerprint row also stores its feature vector as a packed f32
+//! blob, and similarity is ranked with sqlite-vec's `vec_distance_cosine`,
+//! so a search reads one row per match instead of decoding every stored
+//! fingerprint. Two vectors are kept because `AudioFingerprint::similarity`
+//! only compares stereo features when both sides measured them: `vector`
+//! leaves the stereo group out, `stereo_vector` (stereo sources only)
+//! includes it.
+//!
+//! Large libraries take their candidates from the HNSW graph in
+//! `vector_index` instead of scoring every row; see `nearest_fingerprints`.
+//! Searches ignoring some feature groups can't be ranked by sqlite-vec and
+//! score the stored blobs in Rust instead.
+
+use super::top_k::TopK;
+use super::{vector_index, PaletteDatabase};
+use crate::Result;
+use crate::fingerprint::{cosine_score, AudioFingerprint, FeatureMask, FINGERPRINT_VERSION};
+use rusqlite::{ffi, params, ToSql};
+use std::sync::Once;
+
+/// Register sqlite-vec for every connection opened from now on
+pub(super) fn register_extension() {
+    // sqlite-vec declares its entry point without arguments; the real
+    // signature is the standard extension init sqlite3_auto_extension expects
+    type ExtensionInit = unsafe extern "C" fn(
+        *mut ffi::sqlite3,
+        *mut *const std::os::raw::c_char,
+        *const ffi::sqlite3_api_routines,
+    ) -> std::os::raw::c_int;
+
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| unsafe {
+        let init = std::mem::transmute::<*const (), ExtensionInit>(sqlite_vec::sqlite3_vec_init as *const ());
+        ffi::sqlite3_auto_extension(Some(init));
+    });
+}
+
+/// Pack features as little-endian f32, the layout sqlite-vec reads
+fn vector_blob(features: &[f64]) -> Vec<u8> {
+    features.iter().flat_map(|&x| (x as f32).to_le_bytes()).collect()
+}
+
+/// (`vector`, `stereo_vector`) blobs for a fingerprint
+pub(super) fn fingerprint_vectors(fingerprint: &AudioFingerprint) -> (Vec<u8>, Option<Vec<u8>>) {
+    let stereo = fingerprint.stereo.map(|_| vector_blob(&fingerprint.feature_vector(true)));
+    (vector_blob(&fingerprint.feature_vector(false)), stereo)
+}
+
+/// A stored fingerprint ranked against a query
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorMatch {
+    pub sound_id: i64,
+    /// Similarity (0-100) after the noise penalty
+    pub score: f64,
+    /// Points subtracted from the similarity for a noisy take
+    pub snr_penalty: f64,
+}
+
+/// Score and noise penalty, computed the same way as `SearchEngine`
+///
+/// ?1/?2: query vectors, ?3: penalty per dB, ?4: minimum SNR, ?5: minimum
+/// score, ?7: `FINGERPRINT_VERSION`. Rows whose vector length differs from
+/// the query's, or computed by an older analysis, are skipped
+/// rather than failing the whole statement, and rows whose penalty alone
+/// puts them under the minimum are never compared. `ORDER BY ... LIMIT`
+/// keeps only the best `limit` rows while sorting, so the rest of the
+/// scored table is never materialized.
+const SCORED_FINGERPRINTS: &str = "
+    SELECT sound_id,
+           MIN(MAX((2.0 - CASE WHEN ?2 IS NOT NULL AND stereo_vector IS NOT NULL
+                               THEN vec_distance_cosine(stereo_vector, ?2)
+                               ELSE vec_distance_cosine(vector, ?1) END) * 50.0, 0.0), 100.0) AS similarity,
+           CASE WHEN ?3 > 0.0 THEN ?3 * MAX(?4 - IFNULL(snr_db, ?4), 0.0) ELSE 0.0 END AS penalty
+    FROM fingerprints
+    WHERE vector IS NOT NULL AND vec_length(vector) = vec_length(?1) AND feature_version = ?7
+      AND (?3 <= 0.0 OR ?3 * MAX(?4 - IFNULL(snr_db, ?4), 0.0) <= 100.0 - ?5)";
+
+/// Below this many fingerprints every row is scored
+const ANN_MIN_FINGERPRINTS: usize = 10_000;
+/// Larger limits (e.g. "everything above the threshold") score every row
+const ANN_MAX_LIMIT: usize = 1_000;
+/// Graph candidates rescored per result asked for, at least `ANN_MIN_CANDIDATES`
+const ANN_OVERSAMPLING: usize = 4;
+const ANN_MIN_CANDIDATES: usize = 100;
+
+impl PaletteDatabase {
+    /// Stored fingerprints scoring at least `min_score` against `query`,
+    /// best first
+    ///
+    /// Candidates whose SNR is below `min_snr_db` lose `snr_penalty` points
+    /// per dB (0 disables the penalty).
+    ///
+    /// With at least `ANN_MIN_FINGERPRINTS` stored and a limit of at most
+    /// `ANN_MAX_LIMIT`, only the nearest candidates from the vector index
+    /// are scored, so a result the index misses can be left out.
+    pub fn nearest_fingerprints(
+        &self,
+        query: &AudioFingerprint,
+        min_score: f64,
+        limit: usize,
+        snr_penalty: f64,
+        min_snr_db: f64,
+    ) -> Result<Vec<VectorMatch>> {
+        self.scored_fingerprints(query, min_score, limit, snr_penalty, min_snr_db, None)
+    }
+
+    /// `nearest_fingerprints` among the given sounds only, e.g. those
+    /// passing a metadata filter
+    ///
+    /// Every one of them is scored exactly; graph candidates could all
+    /// fall outside a narrow filter.
+    pub fn nearest_fingerprints_among(
+        &self,
+        query: &AudioFingerprint,
+        sound_ids: &[i64],
+        min_score: f64,
+        limit: usize,
+        snr_penalty: f64,
+        min_snr_db: f64,
+    ) -> Result<Vec<VectorMatch>> {
+        if sound_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.scored_fingerprints(query, min_score, limit, snr_penalty, min_snr_db, Some(sound_ids))
+    }
+
+    /// `nearest_fingerprints` (or `nearest_fingerprints_among` when
+    /// `among` is given) comparing only the groups `mask` doesn't leave out
+    ///
+    /// Every candidate is scored exactly: the vector index and sqlite-vec
+    /// both measure whole vectors.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nearest_fingerprints_masked(
+        &self,
+        query: &AudioFingerprint,
+        mask: &FeatureMask,
+        among: Option<&[i64]>,
+        min_score: f64,
+        limit: usize,
+        snr_penalty: f64,
+        min_snr_db: f64,
+    ) -> Result<Vec<VectorMatch>> {
+        let (mono_layout, stereo_layout) = (query.vector_layout(false), query.vector_layout(true));
+        let mono_query = query.masked_vector(false, mask);
+        let stereo_query = query.stereo.map(|_| query.masked_vector(true, mask));
+
+        let conn = self.reader()?;
+        let (sql, ids) = match among {
+            Some(ids) => (
+                "SELECT sound_id, vector, stereo_vector, snr_db FROM fingerprints
+                 WHERE vector IS NOT NULL AND feature_version = ?1 AND sound_id IN (SELECT value FROM json_each(?2))",
+                format!("[{}]", ids.iter().map(i64::to_string).collect::<Vec<_>>().join(",")),
+            ),
+            None => (
+                "SELECT sound_id, vector, stereo_vector, snr_db FROM fingerprints
+                 WHERE vector IS NOT NULL AND feature_version = ?1",
+                String::new(),
+            ),
+        };
+        let mut stmt = conn.prepare_cached(sql)?;
+        let mut rows = if among.is_some() {
+            stmt.query(params![FINGERPRINT_VERSION, ids])?
+        } else {
+            stmt.query([FINGERPRINT_VERSION])?
+        };
+
+        let mut top = TopK::new(limit);
+        while let Some(row) = rows.next()? {
+            let (sound_id, snr_db): (i64, Option<f64>) = (row.get(0)?, row.get(3)?);
+            let penalty = if snr_penalty > 0.0 {
+                snr_penalty * (min_snr_db - snr_db.unwrap_or(min_snr_db)).max(0.0)
+            } else {
+                0.0
+            };
+            // Similarity is at most 100, so the penalty alone can rule a
+            // row out before its vector is read
+            let bar = top.floor().map_or(min_score, |floor| floor.max(min_score));
+            if 100.0 - penalty < bar {
+                continue;
+            }
+
+            let (vector, stereo_vector): (Vec<u8>, Option<Vec<u8>>) = (row.get(1)?, row.get(2)?);
+            let (stored, layout, query_vector) = match (&stereo_query, stereo_vector) {
+                (Some(stereo_query), Some(stereo_vector)) => (stereo_vector, &stereo_layout, stereo_query),
+                _ => (vector, &mono_layout, &mono_query),
+            };
+            // Same rule as the SQL ranking: other vector lengths are skipped
+            let Some(stored) = mask.apply(&vector_index::unpack_vector(&stored), layout) else {
+                continue;
+            };
+            let stored: Vec<f64> = stored.into_iter().map(f64::from).collect();
+            let similarity = cosine_score(query_vector, &stored);
+            if similarity - penalty >= min_score {
+                top.push(VectorMatch { sound_id, score: (similarity - penalty).max(0.0), snr_penalty: penalty });
+            }
+        }
+        Ok(top.into_sorted_vec())
+    }
+
+    /// Up to `limit` fingerprinted sounds in a pseudo-random order that
+    /// stays the same from call to call, e.g. to sample the library's
+    /// score distribution
+    pub fn sample_fingerprint_ids(&self, limit: usize) -> Result<Vec<i64>> {
+        let conn = self.reader()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT sound_id FROM fingerprints WHERE vector IS NOT NULL AND feature_version = ?2
+             ORDER BY (sound_id * 2654435761) % 4294967296, sound_id
+             LIMIT ?1",
+        )?;
+        let limit = limit.min(i64::MAX as usize) as i64;
+        let ids = stmt.query_map(params![limit, FINGERPRINT_VERSION], |row| row.get(0))?;
+        Ok(ids.collect::<rusqlite::Result<_>>()?)
+    }
+
+    fn scored_fingerprints(
+        &self,
+        query: &AudioFingerprint,
+        min_score: f64,
+        limit: usize,
+        snr_penalty: f64,
+        min_snr_db: f64,
+        among: Option<&[i64]>,
+    ) -> Result<Vec<VectorMatch>> {
+        let (vector, stereo_vector) = fingerprint_vectors(query);
+        let conn = self.reader()?;
+        let id_list = |ids: &[i64]| format!("[{}]", ids.iter().map(i64::to_string).collect::<Vec<_>>().join(","));
+        let candidates = match among {
+            Some(ids) => Some(id_list(ids)),
+            None if limit <= ANN_MAX_LIMIT && vector_index::len(&conn)? >= ANN_MIN_FINGERPRINTS => {
+                let ef = (limit * ANN_OVERSAMPLING).max(ANN_MIN_CANDIDATES);
+                Some(id_list(&vector_index::search(&conn, &vector_index::unpack_vector(&vector), ef)?))
+            }
+            None => None,
+        };
+
+        let candidate_filter = match candidates {
+            Some(_) => "AND sound_id IN (SELECT value FROM json_each(?8))",
+            None => "",
+        };
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT sound_id, MAX(similarity - penalty, 0.0) AS score, penalty
+             FROM ({SCORED_FINGERPRINTS} {candidate_filter})
+             WHERE similarity - penalty >= ?5
+             ORDER BY score DESC, sound_id
+             LIMIT ?6"
+        ))?;
+        let limit = limit.min(i64::MAX as usize) as i64;
+        let mut values: Vec<&dyn ToSql> =
+            vec![&vector, &stereo_vector, &snr_penalty, &min_snr_db, &min_score, &limit, &FINGERPRINT_VERSION];
+        if let Some(ids) = &candidates {
+            values.push(ids);
+        }
+        let rows = stmt.query_map(
+            values.as_slice(),
+            |row| {
+                Ok(VectorMatch {
+                    sound_id: row.get(0)?,
+                    score: row.get(1)?,
+                    snr_penalty: row.get(2)?,
+                })
+            },
+        )?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::test_util::fingerprint;
+    use crate::fingerprint::{FeatureGroup, NoiseProfile};
+    use crate::StereoImage;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_nearest_fingerprints_match_similarity() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut stored = Vec::new();
+        for i in 0..6 {
+            let id = db.add_sound(&format!("/kit/{}.wav", i), &format!("{}.wav", i), 1.0, 44100, 2, "wav").unwrap();
+            let mut fp = fingerprint(0.5 + i as f64 * 0.3);
+            if i % 2 == 0 {
+                fp.stereo = Some(StereoImage { width: 0.1 * i as f64, correlation: 0.8 });
+            }
+            if i == 4 {
+                fp.noise = Some(NoiseProfile { noise_floor_db: -30.0, signal_level_db: -20.0, snr_db: 10.0 });
+            }
+            db.store_fingerprint(id, &fp).unwrap();
+            stored.push((id, fp));
+        }
+
+        let mut query = fingerprint(0.9);
+        query.stereo = Some(StereoImage { width: 0.3, correlation: 0.7 });
+        let matches = db.nearest_fingerprints(&query, 0.0, 10, 0.0, 20.0).unwrap();
+        assert_eq!(matches.len(), 6);
+        assert!(matches.windows(2).all(|w| w[0].score >= w[1].score));
+        for m in &matches {
+            let (_, fp) = stored.iter().find(|(id, _)| *id == m.sound_id).unwrap();
+            assert!((m.score - query.similarity(fp)).abs() < 0.01, "{} vs {}", m.score, query.similarity(fp));
+        }
+
+        // Threshold and limit are applied in SQL
+        let best = matches[0].score;
+        assert_eq!(db.nearest_fingerprints(&query, best - 1e-3, 10, 0.0, 20.0).unwrap().len(), 1);
+        assert_eq!(db.nearest_fingerprints(&query, 0.0, 2, 0.0, 20.0).unwrap().len(), 2);
+
+        // Noisy takes are penalized per dB below the minimum SNR
+        let noisy = stored[4].0;
+        let penalized = db.nearest_fingerprints(&query, 0.0, 10, 1.0, 20.0).unwrap();
+        let entry = penalized.iter().find(|m| m.sound_id == noisy).unwrap();
+        assert!((entry.snr_penalty - 10.0).abs() < 1e-9);
+        let unpenalized = matches.iter().find(|m| m.sound_id == noisy).unwrap();
+        assert!((entry.score - (unpenalized.score - 10.0).max(0.0)).abs() < 1e-9);
+
+        // Restricted to some sounds, only those are scored
+        let among: Vec<i64> = vec![stored[1].0, stored[3].0];
+        let restricted = db.nearest_fingerprints_among(&query, &among, 0.0, 10, 0.0, 20.0).unwrap();
+        assert_eq!(restricted.iter().map(|m| m.sound_id).collect::<HashSet<_>>(), among.iter().copied().collect());
+        assert!(db.nearest_fingerprints_among(&query, &[], 0.0, 10, 0.0, 20.0).unwrap().is_empty());
+
+        // Masked searches score like `similarity_masked`, restricted or not
+        let mask = FeatureMask::ignoring(&[FeatureGroup::Harmony, FeatureGroup::Stereo]);
+        let masked = db.nearest_fingerprints_masked(&query, &mask, None, 0.0, 10, 1.0, 20.0).unwrap();
+        assert_eq!(masked.len(), 6);
+        for m in &masked {
+            let (_, fp) = stored.iter().find(|(id, _)| *id == m.sound_id).unwrap();
+            let expected = (query.similarity_masked(fp, &mask) - m.snr_penalty).max(0.0);
+            assert!((m.score - expected).abs() < 0.01, "{} vs {}", m.score, expected);
+        }
+        let masked_among = db.nearest_fingerprints_masked(&query, &mask, Some(&among), 0.0, 1, 0.0, 20.0).unwrap();
+        assert_eq!(masked_among.len(), 1);
+        assert!(among.contains(&masked_among[0].sound_id));
+
+        // Removed sounds drop out of the index
+        db.remove_sound(stored[0].0).unwrap();
+        assert_eq!(db.nearest_fingerprints(&query, 0.0, 10, 0.0, 20.0).unwrap().len(), 5);
+    }
+}
@@ -0,0 +1,233 @@
+//! Time-series analysis: onsets, beat grid and pitch curve
+//!
+//! Unlike the fingerprint, which summarizes a whole sound, these keep the
+//! time axis. Onsets are peaks of the onset-strength envelope used for
+//! tempo estimation; the beat grid lays the estimated tempo over that
+//! envelope at the best-aligned phase; the pitch curve is a YIN f0
+//! estimate on a downsampled signal. They are computed once at index
+//! time and stored with the sound.
+
+use super::musical::{onset_envelope, ONSET_HOP_SECONDS};
+use super::estimate_tempo;
+use crate::audio::AudioData;
+use serde::{Deserialize, Serialize};
+
+/// Minimum gap between two onsets
+const MIN_ONSET_GAP_SECONDS: f64 = 0.05;
+
+/// Onsets must exceed the envelope mean by this many standard deviations
+const ONSET_THRESHOLD_STD: f64 = 1.0;
+
+/// Pitch tracking runs at this rate (enough for f0 up to `MAX_F0`)
+const PITCH_SAMPLE_RATE: u32 = 11025;
+const PITCH_HOP_SECONDS: f64 = 0.01;
+const PITCH_WINDOW_SECONDS: f64 = 0.03;
+const MIN_F0: f64 = 60.0;
+const MAX_F0: f64 = 1500.0;
+
+/// YIN threshold on the cumulative mean normalized difference
+const YIN_THRESHOLD: f64 = 0.15;
+
+/// Frames quieter than this RMS are unvoiced
+const SILENCE_RMS: f64 = 1e-3;
+
+/// Kind of time-series analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnalysisKind {
+    /// Note/hit onset times in seconds
+    Onsets,
+    /// Beat times in seconds (empty when no steady tempo was found)
+    Beats,
+    /// Fundamental frequency in Hz every `hop_seconds` (0 = unvoiced)
+    Pitch,
+}
+
+impl AnalysisKind {
+    pub const ALL: [AnalysisKind; 3] = [AnalysisKind::Onsets, AnalysisKind::Beats, AnalysisKind::Pitch];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnalysisKind::Onsets => "onsets",
+            AnalysisKind::Beats => "beats",
+            AnalysisKind::Pitch => "pitch",
+        }
+    }
+}
+
+/// One analysis result for a sound
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisTrack {
+    pub kind: AnalysisKind,
+    /// Spacing of `values` for sampled curves; 0 for event lists, whose
+    /// values are times in seconds
+    pub hop_seconds: f64,
+    pub values: Vec<f64>,
+}
+
+/// Run every analysis on mono audio
+pub fn analyze_time_series(samples: &[f32], sample_rate: u32) -> Vec<AnalysisTrack> {
+    vec![
+        AnalysisTrack { kind: AnalysisKind::Onsets, hop_seconds: 0.0, values: detect_onsets(samples, sample_rate) },
+        AnalysisTrack { kind: AnalysisKind::Beats, hop_seconds: 0.0, values: beat_grid(samples, sample_rate) },
+        AnalysisTrack {
+            kind: AnalysisKind::Pitch,
+            hop_seconds: PITCH_HOP_SECONDS,
+            values: pitch_curve(samples, sample_rate),
+        },
+    ]
+}
+
+fn onset_hop(sample_rate: u32) -> usize {
+    ((ONSET_HOP_SECONDS * sample_rate as f64).round() as usize).max(1)
+}
+
+/// Time in seconds of onset envelope value `i` (the flux into frame i + 1)
+fn envelope_time(i: usize, hop: usize, sample_rate: u32) -> f64 {
+    ((i + 1) * hop) as f64 / sample_rate as f64
+}
+
+/// Onset times in seconds
+pub fn detect_onsets(samples: &[f32], sample_rate: u32) -> Vec<f64> {
+    if sample_rate == 0 || samples.is_empty() {
+        return Vec::new();
+    }
+    let hop = onset_hop(sample_rate);
+    let envelope = onset_envelope(samples, hop);
+    let n = envelope.len() as f64;
+    let std = (envelope.iter().map(|e| e * e).sum::<f64>() / n.max(1.0)).sqrt();
+    if std <= 0.0 {
+        return Vec::new();
+    }
+    let threshold = ONSET_THRESHOLD_STD * std;
+    let min_gap = ((MIN_ONSET_GAP_SECONDS / ONSET_HOP_SECONDS).round() as usize).max(1);
+
+    let mut onsets: Vec<usize> = Vec::new();
+    for i in 0..envelope.len() {
+        let window = &envelope[i.saturating_sub(min_gap)..(i + min_gap + 1).min(envelope.len())];
+        let is_peak = envelope[i] > threshold && window.iter().all(|&e| e <= envelope[i]);
+        if is_peak && onsets.last().is_none_or(|&last| i - last >= min_gap) {
+            onsets.push(i);
+        }
+    }
+    onsets.into_iter().map(|i| envelope_time(i, hop, sample_rate)).collect()
+}
+
+/// Beat times in seconds at the estimated tempo
+pub fn beat_grid(samples: &[f32], sample_rate: u32) -> Vec<f64> {
+    let Some(bpm) = estimate_tempo(samples, sample_rate) else {
+        return Vec::new();
+    };
+    let hop = onset_hop(sample_rate);
+    let envelope = onset_envelope(samples, hop);
+    let period = 60.0 * sample_rate as f64 / hop as f64 / bpm;
+
+    // Phase (in envelope frames) whose beats collect the most onset strength
+    let beats_at = |phase: f64| {
+        (0..)
+            .map(move |k| (phase + k as f64 * period).round() as usize)
+            .take_while(|&i| i < envelope.len())
+    };
+    let phase = (0..period.ceil() as usize)
+        .map(|p| p as f64)
+        .max_by(|&a, &b| {
+            let strength = |phase| beats_at(phase).map(|i| envelope[i]).sum::<f64>();
+            strength(a).total_cmp(&strength(b))
+        })
+        .unwrap_or(0.0);
+    beats_at(phase).map(|i| envelope_time(i, hop, sample_rate)).collect()
+}
+
+/// f0 in Hz every `PITCH_HOP_SECONDS` (0 where unvoiced or silent)
+pub fn pitch_curve(samples: &[f32], sample_rate: u32) -> Vec<f64> {
+    if sample_rate == 0 || samples.is_empty() {
+        return Vec::new();
+    }
+    let audio = AudioData::from_samples(samples.to_vec(), sample_rate).resample(PITCH_SAMPLE_RATE);
+    let sr = audio.sample_rate as f64;
+    let x = &audio.samples;
+    let window = (PITCH_WINDOW_SECONDS * sr) as usize;
+    let hop = (PITCH_HOP_SECONDS * sr).round() as usize;
+    let (min_lag, max_lag) = ((sr / MAX_F0) as usize, (sr / MIN_F0).ceil() as usize);
+    let frames = audio.duration / PITCH_HOP_SECONDS;
+
+    (0..frames.round() as usize)
+        .map(|frame| {
+            let start = frame * hop;
+            if start + window + max_lag > x.len() {
+                return 0.0;
+            }
+            let rms = (x[start..start + window].iter().map(|&s| (s as f64).powi(2)).sum::<f64>() / window as f64).sqrt();
+            if rms < SILENCE_RMS {
+                return 0.0;
+            }
+            yin(&x[start..start + window + max_lag], window, min_lag, max_lag).map_or(0.0, |lag| sr / lag)
+        })
+        .collect()
+}
+
+/// Period in samples of `frame[..window]` by YIN, with parabolic refinement
+fn yin(frame: &[f32], window: usize, min_lag: usize, max_lag: usize) -> Option<f64> {
+    let diff: Vec<f64> = (0..=max_lag)
+        .map(|lag| (0..window).map(|j| (frame[j] as f64 - frame[j + lag] as f64).powi(2)).sum())
+        .collect();
+
+    // Cumulative mean normalized difference
+    let mut cmnd = vec![1.0; max_lag + 1];
+    let mut running = 0.0;
+    for lag in 1..=max_lag {
+        running += diff[lag];
+        cmnd[lag] = if running > 0.0 { diff[lag] * lag as f64 / running } else { 1.0 };
+    }
+
+    let mut lag = (min_lag.max(1)..max_lag).find(|&lag| cmnd[lag] < YIN_THRESHOLD)?;
+    while lag + 1 < max_lag && cmnd[lag + 1] < cmnd[lag] {
+        lag += 1;
+    }
+
+    let (y0, y1, y2) = (cmnd[lag - 1], cmnd[lag], cmnd[lag + 1]);
+    let denom = y0 - 2.0 * y1 + y2;
+    let offset = if denom.abs() > 1e-12 { (0.5 * (y0 - y2) / denom).clamp(-0.5, 0.5) } else { 0.0 };
+    Some(lag as f64 + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_series_analysis() {
+        // Tone bursts every 0.5 s (120 BPM) from 0.1 s, 220 Hz then 440 Hz
+        let sr = 22050;
+        let mut samples = vec![0.0f32; sr as usize * 6];
+        for beat in 0..12 {
+            let start = beat * sr as usize / 2 + sr as usize / 10;
+            let freq = if beat < 6 { 220.0 } else { 440.0 };
+            for (i, s) in samples[start..start + sr as usize / 4].iter_mut().enumerate() {
+                let t = i as f32 / sr as f32;
+                *s = (2.0 * std::f32::consts::PI * freq * t).sin() * 0.8 * (1.0 - t * 3.0).max(0.1);
+            }
+        }
+
+        let onsets = detect_onsets(&samples, sr);
+        assert_eq!(onsets.len(), 12, "{:?}", onsets);
+        assert!(onsets.iter().enumerate().all(|(k, &t)| (t - 0.1 - k as f64 * 0.5).abs() < 0.03), "{:?}", onsets);
+
+        let beats = beat_grid(&samples, sr);
+        assert!((11..=12).contains(&beats.len()), "{:?}", beats);
+        assert!(beats.windows(2).all(|w| (w[1] - w[0] - 0.5).abs() < 0.03));
+        assert!(beats.iter().all(|&t| ((t + 0.15) % 0.5 - 0.25).abs() < 0.03), "{:?}", beats);
+
+        let pitch = pitch_curve(&samples, sr);
+        assert_eq!(pitch.len(), 600);
+        let at = |seconds: f64| pitch[(seconds / PITCH_HOP_SECONDS) as usize];
+        assert!((at(0.2) - 220.0).abs() < 3.0, "{}", at(0.2));
+        assert!((at(4.2) - 440.0).abs() < 6.0, "{}", at(4.2));
+        assert_eq!(at(0.5), 0.0);
+
+        // A one-shot has onsets but no beat grid
+        let tracks = analyze_time_series(&samples[..sr as usize / 2], sr);
+        assert_eq!(tracks.iter().map(|t| t.kind).collect::<Vec<_>>(), AnalysisKind::ALL);
+        assert_eq!(tracks[0].values.len(), 1);
+        assert!(tracks[1].values.is_empty());
+    }
+}
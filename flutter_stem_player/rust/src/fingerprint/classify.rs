@@ -0,0 +1,203 @@
+//! Lightweight instrument classification from fingerprint features
+//!
+//! Scores each class against a hand-tuned prototype in a small feature
+//! space (brightness, noisiness, length, tonality, transientness) and
+//! normalizes the scores into confidences.
+
+use super::AudioFingerprint;
+use crate::descending_score;
+use serde::{Deserialize, Serialize};
+
+/// Instrument classes predicted by the classifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InstrumentClass {
+    Kick,
+    Snare,
+    HiHat,
+    Bass,
+    Synth,
+    Vocal,
+    Fx,
+}
+
+impl InstrumentClass {
+    pub const ALL: [InstrumentClass; 7] = [
+        InstrumentClass::Kick,
+        InstrumentClass::Snare,
+        InstrumentClass::HiHat,
+        InstrumentClass::Bass,
+        InstrumentClass::Synth,
+        InstrumentClass::Vocal,
+        InstrumentClass::Fx,
+    ];
+
+    /// Tag name written to the tags table
+    pub fn tag_name(&self) -> &'static str {
+        match self {
+            InstrumentClass::Kick => "kick",
+            InstrumentClass::Snare => "snare",
+            InstrumentClass::HiHat => "hat",
+            InstrumentClass::Bass => "bass",
+            InstrumentClass::Synth => "synth",
+            InstrumentClass::Vocal => "vocal",
+            InstrumentClass::Fx => "fx",
+        }
+    }
+
+    /// Class written as `tag_name`
+    pub fn from_tag_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|class| class.tag_name() == name)
+    }
+
+    /// Prototype in feature space:
+    /// [log2 centroid, zero-crossing rate, log2 duration, tonality, transientness]
+    fn prototype(&self) -> [f64; 5] {
+        match self {
+            InstrumentClass::Kick => [7.2, 0.01, -1.3, 0.3, 1.2],
+            InstrumentClass::Snare => [11.3, 0.12, -1.7, 0.2, 1.1],
+            InstrumentClass::HiHat => [13.0, 0.35, -2.7, 0.1, 1.0],
+            InstrumentClass::Bass => [8.2, 0.02, 1.0, 0.7, 0.4],
+            InstrumentClass::Synth => [10.5, 0.05, 1.6, 0.8, 0.4],
+            InstrumentClass::Vocal => [10.2, 0.08, 1.6, 0.5, 0.6],
+            InstrumentClass::Fx => [11.5, 0.15, 2.0, 0.2, 0.7],
+        }
+    }
+}
+
+/// Per-dimension spread of the prototypes (larger = more tolerant)
+const FEATURE_SCALE: [f64; 5] = [1.2, 0.08, 1.2, 0.25, 0.35];
+
+/// A predicted label with confidence (0-1)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstrumentPrediction {
+    pub class: InstrumentClass,
+    pub confidence: f64,
+}
+
+/// Prototype-based instrument classifier
+pub struct InstrumentClassifier {
+    min_confidence: f64,
+}
+
+impl Default for InstrumentClassifier {
+    fn default() -> Self {
+        Self::new(0.25)
+    }
+}
+
+impl InstrumentClassifier {
+    pub fn new(min_confidence: f64) -> Self {
+        InstrumentClassifier { min_confidence }
+    }
+
+    /// Confidences for every class, sorted best first (sums to 1)
+    pub fn predict_all(&self, fp: &AudioFingerprint) -> Vec<InstrumentPrediction> {
+        let features = Self::features(fp);
+
+        let log_likelihoods: Vec<f64> = InstrumentClass::ALL
+            .iter()
+            .map(|class| {
+                let proto = class.prototype();
+                -0.5 * features
+                    .iter()
+                    .zip(proto.iter())
+                    .zip(FEATURE_SCALE.iter())
+                    .map(|((x, p), s)| ((x - p) / s).powi(2))
+                    .sum::<f64>()
+            })
+            .collect();
+
+        // Softmax over log-likelihoods
+        let max = log_likelihoods.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let exp: Vec<f64> = log_likelihoods.iter().map(|l| (l - max).exp()).collect();
+        let total: f64 = exp.iter().sum();
+
+        let mut predictions: Vec<InstrumentPrediction> = InstrumentClass::ALL
+            .iter()
+            .zip(exp.iter())
+            .map(|(class, e)| InstrumentPrediction {
+                class: *class,
+                confidence: e / total,
+            })
+            .collect();
+
+        predictions.sort_by(|a, b| descending_score(a.confidence, b.confidence));
+        predictions
+    }
+
+    /// Labels above the confidence cutoff (always at least the top class)
+    pub fn classify(&self, fp: &AudioFingerprint) -> Vec<InstrumentPrediction> {
+        let all = self.predict_all(fp);
+        let mut labels: Vec<InstrumentPrediction> = all
+            .iter()
+            .filter(|p| p.confidence >= self.min_confidence)
+            .cloned()
+            .collect();
+
+        if labels.is_empty() {
+            labels.extend(all.into_iter().take(1));
+        }
+        labels
+    }
+
+    fn features(fp: &AudioFingerprint) -> [f64; 5] {
+        let centroid = fp.spectral_centroid.max(20.0).log2();
+        let duration = fp.duration.max(0.01).log2();
+
+        // Tonality: how peaked the chroma profile is (0 = flat/noisy, 1 = single pitch class)
+        let tonality = if fp.chroma_mean.is_empty() {
+            0.0
+        } else {
+            let mean = fp.chroma_mean.iter().sum::<f64>() / fp.chroma_mean.len() as f64;
+            let max = fp.chroma_mean.iter().cloned().fold(0.0_f64, f64::max);
+            if max > 0.0 { 1.0 - mean / max } else { 0.0 }
+        };
+
+        // Transientness: energy variation relative to level
+        let transientness = if fp.rms_mean > 1e-9 { fp.rms_std / fp.rms_mean } else { 0.0 };
+
+        [centroid, fp.zero_crossing_rate, duration, tonality, transientness]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(centroid: f64, zcr: f64, duration: f64, chroma_peak: bool, rms_std: f64) -> AudioFingerprint {
+        let mut chroma = vec![0.2; 12];
+        if chroma_peak {
+            chroma = vec![0.05; 12];
+            chroma[0] = 1.0;
+        }
+        AudioFingerprint {
+            duration,
+            sample_rate: 44100,
+            mfcc_mean: vec![0.0; 13],
+            mfcc_std: vec![0.0; 13],
+            spectral_centroid: centroid,
+            spectral_bandwidth: 500.0,
+            spectral_rolloff: centroid * 2.0,
+            rms_mean: 0.1,
+            rms_std,
+            zero_crossing_rate: zcr,
+            chroma_mean: chroma,
+            noise: None,
+            stereo: None,
+        }
+    }
+
+    #[test]
+    fn test_classify_prototypes() {
+        let classifier = InstrumentClassifier::default();
+
+        let kick = fingerprint(150.0, 0.01, 0.4, false, 0.12);
+        assert_eq!(classifier.classify(&kick)[0].class, InstrumentClass::Kick);
+
+        let hat = fingerprint(8000.0, 0.35, 0.15, false, 0.1);
+        assert_eq!(classifier.classify(&hat)[0].class, InstrumentClass::HiHat);
+
+        let total: f64 = classifier.predict_all(&kick).iter().map(|p| p.confidence).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,204 @@
+//! Compact binary encoding of fingerprints for database storage
+//!
+//! Layout (little endian): magic `APFP`, format version byte, then the
+//! fields in declaration order. Scalars are f64; the feature vectors are
+//! stored as a u16 length followed by f32 values, which is well below the
+//! precision that matters for similarity and keeps a typical fingerprint
+//! around 250 bytes (JSON text is roughly four times larger). Optional
+//! sections are prefixed by a presence byte.
+
+use super::{AudioFingerprint, NoiseProfile};
+use crate::{AudioPaletteError, Result, StereoImage};
+
+const MAGIC: &[u8; 4] = b"APFP";
+const FORMAT_VERSION: u8 = 1;
+
+impl AudioFingerprint {
+    /// Encode as a compact binary blob
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let vectors = self.mfcc_mean.len() + self.mfcc_std.len() + self.chroma_mean.len();
+        let mut out = Vec::with_capacity(96 + vectors * 4);
+        out.extend_from_slice(MAGIC);
+        out.push(FORMAT_VERSION);
+
+        put_f64(&mut out, self.duration);
+        out.extend_from_slice(&self.sample_rate.to_le_bytes());
+        put_vec(&mut out, &self.mfcc_mean);
+        put_vec(&mut out, &self.mfcc_std);
+        for value in [
+            self.spectral_centroid,
+            self.spectral_bandwidth,
+            self.spectral_rolloff,
+            self.rms_mean,
+            self.rms_std,
+            self.zero_crossing_rate,
+        ] {
+            put_f64(&mut out, value);
+        }
+        put_vec(&mut out, &self.chroma_mean);
+
+        match &self.noise {
+            Some(noise) => {
+                out.push(1);
+                put_f64(&mut out, noise.noise_floor_db);
+                put_f64(&mut out, noise.signal_level_db);
+                put_f64(&mut out, noise.snr_db);
+            }
+            None => out.push(0),
+        }
+        match &self.stereo {
+            Some(stereo) => {
+                out.push(1);
+                put_f64(&mut out, stereo.width);
+                put_f64(&mut out, stereo.correlation);
+            }
+            None => out.push(0),
+        }
+
+        out
+    }
+
+    /// Decode a blob produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader { bytes, pos: 0 };
+        if reader.take(4)? != MAGIC {
+            return Err(AudioPaletteError::FingerprintError("Not a fingerprint blob".to_string()));
+        }
+        let version = reader.take(1)?[0];
+        if version != FORMAT_VERSION {
+            return Err(AudioPaletteError::FingerprintError(format!(
+                "Unsupported fingerprint format version {}", version
+            )));
+        }
+
+        let duration = reader.f64()?;
+        let sample_rate = u32::from_le_bytes(reader.take(4)?.try_into().unwrap());
+        let mfcc_mean = reader.vec()?;
+        let mfcc_std = reader.vec()?;
+        let spectral_centroid = reader.f64()?;
+        let spectral_bandwidth = reader.f64()?;
+        let spectral_rolloff = reader.f64()?;
+        let rms_mean = reader.f64()?;
+        let rms_std = reader.f64()?;
+        let zero_crossing_rate = reader.f64()?;
+        let chroma_mean = reader.vec()?;
+
+        let noise = if reader.flag()? {
+            Some(NoiseProfile {
+                noise_floor_db: reader.f64()?,
+                signal_level_db: reader.f64()?,
+                snr_db: reader.f64()?,
+            })
+        } else {
+            None
+        };
+        let stereo = if reader.flag()? {
+            Some(StereoImage {
+                width: reader.f64()?,
+                correlation: reader.f64()?,
+            })
+        } else {
+            None
+        };
+
+        Ok(AudioFingerprint {
+            duration,
+            sample_rate,
+            mfcc_mean,
+            mfcc_std,
+            spectral_centroid,
+            spectral_bandwidth,
+            spectral_rolloff,
+            rms_mean,
+            rms_std,
+            zero_crossing_rate,
+            chroma_mean,
+            noise,
+            stereo,
+        })
+    }
+}
+
+fn put_f64(out: &mut Vec<u8>, value: f64) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_vec(out: &mut Vec<u8>, values: &[f64]) {
+    let len = values.len().min(u16::MAX as usize);
+    out.extend_from_slice(&(len as u16).to_le_bytes());
+    for &v in &values[..len] {
+        out.extend_from_slice(&(v as f32).to_le_bytes());
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self.pos + n;
+        if end > self.bytes.len() {
+            return Err(AudioPaletteError::FingerprintError("Truncated fingerprint blob".to_string()));
+        }
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn flag(&mut self) -> Result<bool> {
+        Ok(self.take(1)?[0] != 0)
+    }
+
+    fn vec(&mut self) -> Result<Vec<f64>> {
+        let len = u16::from_le_bytes(self.take(2)?.try_into().unwrap()) as usize;
+        let raw = self.take(len * 4)?;
+        Ok(raw
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()) as f64)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_round_trip() {
+        let fp = AudioFingerprint {
+            duration: 2.5,
+            sample_rate: 48000,
+            mfcc_mean: (0..13).map(|i| (i as f64 * 1.7).sin() * 12.3).collect(),
+            mfcc_std: (0..13).map(|i| (i as f64 * 0.37).cos().abs()).collect(),
+            spectral_centroid: 1834.2,
+            spectral_bandwidth: 912.7,
+            spectral_rolloff: 4410.0,
+            rms_mean: 0.12,
+            rms_std: 0.03,
+            zero_crossing_rate: 0.07,
+            chroma_mean: (0..12).map(|i| i as f64 / 11.0).collect(),
+            noise: Some(NoiseProfile { noise_floor_db: -72.0, signal_level_db: -12.0, snr_db: 60.0 }),
+            stereo: None,
+        };
+
+        let bytes = fp.to_bytes();
+        let json = serde_json::to_string(&fp).unwrap();
+        assert!(bytes.len() * 3 < json.len());
+
+        let decoded = AudioFingerprint::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.sample_rate, 48000);
+        assert_eq!(decoded.spectral_centroid, fp.spectral_centroid);
+        assert_eq!(decoded.noise.unwrap().snr_db, 60.0);
+        assert!(decoded.stereo.is_none());
+        assert!((decoded.similarity(&fp) - 100.0).abs() < 1e-3);
+
+        assert!(AudioFingerprint::from_bytes(&bytes[..bytes.len() - 3]).is_err());
+        assert!(AudioFingerprint::from_bytes(json.as_bytes()).is_err());
+    }
+}
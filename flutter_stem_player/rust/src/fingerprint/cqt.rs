@@ -0,0 +1,211 @@
+//! Constant-Q transform (log-spaced frequency bins)
+//!
+//! Uses the spectral-kernel method (Brown & Puckette): each CQT bin is a
+//! windowed complex exponential whose length shrinks with frequency, and
+//! its sparse FFT is precomputed so every frame costs one FFT plus a few
+//! multiply-adds per bin.
+
+use rustfft::{FftPlanner, num_complex::Complex};
+use std::sync::Arc;
+
+/// Kernel coefficients below this fraction of the bin's peak are dropped
+const SPARSITY_THRESHOLD: f64 = 0.0054;
+
+/// Frequency of MIDI note 0
+const MIDI_ZERO_HZ: f64 = 8.175_798_915_643_707;
+
+/// Precomputed constant-Q kernel for one sample rate
+pub struct ConstantQ {
+    sample_rate: u32,
+    f_min: f64,
+    bins_per_octave: usize,
+    n_bins: usize,
+    fft_len: usize,
+    fft: Arc<dyn rustfft::Fft<f64>>,
+    /// Sparse spectral kernels: (fft bin, conjugated coefficient)
+    kernels: Vec<Vec<(usize, Complex<f64>)>>,
+}
+
+impl ConstantQ {
+    /// Build a CQT with `n_bins` bins starting at `f_min` Hz
+    ///
+    /// Bins above Nyquist are dropped, so `n_bins()` may be smaller than requested.
+    pub fn new(sample_rate: u32, f_min: f64, n_bins: usize, bins_per_octave: usize) -> Self {
+        let sr = sample_rate as f64;
+        let bins_per_octave = bins_per_octave.max(1);
+        let q = 1.0 / (2f64.powf(1.0 / bins_per_octave as f64) - 1.0);
+
+        let n_bins = (0..n_bins)
+            .take_while(|&k| Self::bin_frequency(f_min, bins_per_octave, k) < sr / 2.0)
+            .count();
+
+        // Longest kernel belongs to the lowest bin
+        let max_len = (q * sr / f_min).ceil() as usize;
+        let fft_len = max_len.max(2).next_power_of_two();
+
+        let mut planner = FftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(fft_len);
+
+        let kernels = (0..n_bins)
+            .map(|k| {
+                let freq = Self::bin_frequency(f_min, bins_per_octave, k);
+                let len = ((q * sr / freq).ceil() as usize).clamp(2, fft_len);
+                let offset = (fft_len - len) / 2;
+
+                // Centered, Hann-windowed complex exponential at the bin frequency
+                let mut kernel = vec![Complex::new(0.0, 0.0); fft_len];
+                for n in 0..len {
+                    let window = 0.5 * (1.0 - (2.0 * std::f64::consts::PI * n as f64 / (len - 1) as f64).cos());
+                    let phase = 2.0 * std::f64::consts::PI * q * n as f64 / len as f64;
+                    kernel[offset + n] = Complex::from_polar(window / len as f64, phase);
+                }
+                fft.process(&mut kernel);
+
+                let peak = kernel.iter().map(|c| c.norm()).fold(0.0_f64, f64::max);
+                kernel
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| c.norm() >= peak * SPARSITY_THRESHOLD)
+                    .map(|(j, c)| (j, c.conj() / fft_len as f64))
+                    .collect()
+            })
+            .collect();
+
+        ConstantQ {
+            sample_rate,
+            f_min,
+            bins_per_octave,
+            n_bins,
+            fft_len,
+            fft,
+            kernels,
+        }
+    }
+
+    /// CQT suited to chroma: starts at C2, 6 octaves, semitone resolution
+    pub fn for_chroma(sample_rate: u32) -> Self {
+        Self::new(sample_rate, Self::midi_to_hz(36.0), 72, 12)
+    }
+
+    fn bin_frequency(f_min: f64, bins_per_octave: usize, k: usize) -> f64 {
+        f_min * 2f64.powf(k as f64 / bins_per_octave as f64)
+    }
+
+    /// Convert a MIDI note number to Hz
+    pub fn midi_to_hz(midi: f64) -> f64 {
+        MIDI_ZERO_HZ * 2f64.powf(midi / 12.0)
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn n_bins(&self) -> usize {
+        self.n_bins
+    }
+
+    pub fn bins_per_octave(&self) -> usize {
+        self.bins_per_octave
+    }
+
+    /// Frame length consumed by `transform_frame`
+    pub fn frame_len(&self) -> usize {
+        self.fft_len
+    }
+
+    /// Center frequency of every bin
+    pub fn frequencies(&self) -> Vec<f64> {
+        (0..self.n_bins)
+            .map(|k| Self::bin_frequency(self.f_min, self.bins_per_octave, k))
+            .collect()
+    }
+
+    /// CQT magnitudes for one frame (zero-padded/truncated to `frame_len`)
+    pub fn transform_frame(&self, frame: &[f32]) -> Vec<f64> {
+        let mut buffer: Vec<Complex<f64>> = (0..self.fft_len)
+            .map(|i| Complex::new(frame.get(i).copied().unwrap_or(0.0) as f64, 0.0))
+            .collect();
+        self.fft.process(&mut buffer);
+
+        self.kernels
+            .iter()
+            .map(|kernel| {
+                kernel
+                    .iter()
+                    .map(|&(j, coeff)| buffer[j] * coeff)
+                    .sum::<Complex<f64>>()
+                    .norm()
+            })
+            .collect()
+    }
+
+    /// CQT magnitude spectrogram (one vector per hop)
+    ///
+    /// Audio shorter than one frame yields a single zero-padded frame.
+    pub fn transform(&self, samples: &[f32], hop: usize) -> Vec<Vec<f64>> {
+        if samples.is_empty() {
+            return Vec::new();
+        }
+        let last_start = samples.len().saturating_sub(self.fft_len);
+        (0..=last_start)
+            .step_by(hop.max(1))
+            .map(|start| self.transform_frame(&samples[start..]))
+            .collect()
+    }
+
+    /// Pitch class of a bin (0 = C), assuming `f_min` sits on a semitone
+    pub fn pitch_class(&self, bin: usize) -> usize {
+        let semitones_per_bin = 12.0 / self.bins_per_octave as f64;
+        let f_min_midi = 12.0 * (self.f_min / MIDI_ZERO_HZ).log2();
+        let midi = f_min_midi + bin as f64 * semitones_per_bin;
+        ((midi.round() as i64).rem_euclid(12)) as usize
+    }
+
+    /// Mean chroma (12 pitch classes, max-normalized)
+    pub fn chroma(&self, samples: &[f32], hop: usize) -> Vec<f64> {
+        let mut chroma = vec![0.0; 12];
+        for frame in self.transform(samples, hop) {
+            for (bin, magnitude) in frame.iter().enumerate() {
+                chroma[self.pitch_class(bin)] += magnitude;
+            }
+        }
+
+        let max = chroma.iter().cloned().fold(0.0_f64, f64::max);
+        if max > 0.0 {
+            for c in &mut chroma {
+                *c /= max;
+            }
+        }
+        chroma
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cqt_peak_and_chroma() {
+        let sr = 22050;
+        let cqt = ConstantQ::for_chroma(sr);
+
+        // A4 (440 Hz) = MIDI 69 → bin 33 above C2, pitch class 9
+        let freq = 440.0;
+        let samples: Vec<f32> = (0..sr as usize)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * i as f64 / sr as f64).sin() as f32)
+            .collect();
+
+        let frame = cqt.transform_frame(&samples);
+        let peak = frame
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(k, _)| k)
+            .unwrap();
+        assert_eq!(peak, 33);
+        assert!((cqt.frequencies()[peak] - 440.0).abs() < 1.0);
+
+        let chroma = cqt.chroma(&samples, 4096);
+        assert_eq!(chroma[9], 1.0);
+    }
+}
@@ -8,23 +8,71 @@ pub struct MfccExtractor {
     n_mfcc: usize,
     n_fft: usize,
     n_mels: usize,
-    mel_filterbank: Vec<Vec<f64>>,
 }
 
 impl MfccExtractor {
     pub fn new(n_mfcc: usize, n_fft: usize) -> Self {
-        let n_mels = 40;
+        Self::with_mels(n_mfcc, n_fft, 40)
+    }
+
+    /// Create an extractor with a custom number of mel bands
+    pub fn with_mels(n_mfcc: usize, n_fft: usize, n_mels: usize) -> Self {
         MfccExtractor {
             n_mfcc,
             n_fft,
             n_mels,
-            mel_filterbank: Vec::new(), // Will be computed on first use
         }
     }
 
+    /// Samples between the starts of consecutive frames
+    pub fn hop_length(&self) -> usize {
+        self.n_fft / 4
+    }
+
     /// Extract MFCC features from audio samples
     /// Returns (mean, std) for each coefficient
     pub fn extract(&self, samples: &[f32], sample_rate: u32) -> Result<(Vec<f64>, Vec<f64>)> {
+        let all_mfccs = self.extract_frames(samples, sample_rate)?;
+
+        // Compute mean and std for each coefficient
+        let n_frames = all_mfccs.len() as f64;
+        let mut mean = vec![0.0; self.n_mfcc];
+        let mut std = vec![0.0; self.n_mfcc];
+
+        for mfcc in &all_mfccs {
+            for (i, &val) in mfcc.iter().enumerate() {
+                mean[i] += val;
+            }
+        }
+        for m in &mut mean {
+            *m /= n_frames;
+        }
+
+        for mfcc in &all_mfccs {
+            for (i, &val) in mfcc.iter().enumerate() {
+                std[i] += (val - mean[i]).powi(2);
+            }
+        }
+        for s in &mut std {
+            *s = (*s / n_frames).sqrt();
+        }
+
+        Ok((mean, std))
+    }
+
+    /// Extract frame-level MFCCs (one vector of `n_mfcc` coefficients per hop)
+    pub fn extract_frames(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<Vec<f64>>> {
+        let mel_frames = self.log_mel_frames(samples, sample_rate)?;
+
+        // DCT to get MFCCs
+        Ok(mel_frames
+            .iter()
+            .map(|mel_spec| self.dct(mel_spec).into_iter().take(self.n_mfcc).collect())
+            .collect())
+    }
+
+    /// Extract frame-level log-mel spectra (one vector of `n_mels` bands per hop)
+    pub fn log_mel_frames(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<Vec<f64>>> {
         if samples.len() < self.n_fft {
             return Err(AudioPaletteError::FingerprintError(
                 "Audio too short for MFCC extraction".to_string()
@@ -37,8 +85,8 @@ impl MfccExtractor {
         let mut planner = FftPlanner::new();
         let fft = planner.plan_fft_forward(self.n_fft);
 
-        let hop_length = self.n_fft / 4;
-        let mut all_mfccs: Vec<Vec<f64>> = Vec::new();
+        let hop_length = self.hop_length();
+        let mut mel_frames: Vec<Vec<f64>> = Vec::new();
 
         // Process frames
         for start in (0..samples.len().saturating_sub(self.n_fft)).step_by(hop_length) {
@@ -73,41 +121,16 @@ impl MfccExtractor {
                 })
                 .collect();
 
-            // DCT to get MFCCs
-            let mfccs = self.dct(&mel_spec);
-            all_mfccs.push(mfccs.into_iter().take(self.n_mfcc).collect());
+            mel_frames.push(mel_spec);
         }
 
-        if all_mfccs.is_empty() {
+        if mel_frames.is_empty() {
             return Err(AudioPaletteError::FingerprintError(
                 "No frames extracted".to_string()
             ));
         }
 
-        // Compute mean and std for each coefficient
-        let n_frames = all_mfccs.len() as f64;
-        let mut mean = vec![0.0; self.n_mfcc];
-        let mut std = vec![0.0; self.n_mfcc];
-
-        for mfcc in &all_mfccs {
-            for (i, &val) in mfcc.iter().enumerate() {
-                mean[i] += val;
-            }
-        }
-        for m in &mut mean {
-            *m /= n_frames;
-        }
-
-        for mfcc in &all_mfccs {
-            for (i, &val) in mfcc.iter().enumerate() {
-                std[i] += (val - mean[i]).powi(2);
-            }
-        }
-        for s in &mut std {
-            *s = (*s / n_frames).sqrt();
-        }
-
-        Ok((mean, std))
+        Ok(mel_frames)
     }
 
     fn compute_mel_filterbank(&self, sample_rate: u32) -> Vec<Vec<f64>> {
@@ -140,17 +163,13 @@ impl MfccExtractor {
             let end = bin_points[i + 2];
 
             // Rising slope
-            for j in start..center {
-                if center > start {
-                    filterbank[i][j] = (j - start) as f64 / (center - start) as f64;
-                }
+            for (j, weight) in filterbank[i].iter_mut().enumerate().take(center).skip(start) {
+                *weight = (j - start) as f64 / (center - start) as f64;
             }
 
             // Falling slope
-            for j in center..end {
-                if end > center {
-                    filterbank[i][j] = (end - j) as f64 / (end - center) as f64;
-                }
+            for (j, weight) in filterbank[i].iter_mut().enumerate().take(end).skip(center) {
+                *weight = (end - j) as f64 / (end - center) as f64;
             }
         }
 
@@ -169,12 +188,12 @@ impl MfccExtractor {
         let n = x.len();
         let mut result = vec![0.0; n];
 
-        for k in 0..n {
+        for (k, out) in result.iter_mut().enumerate() {
             let mut sum = 0.0;
             for (i, &val) in x.iter().enumerate() {
                 sum += val * (std::f64::consts::PI * k as f64 * (2.0 * i as f64 + 1.0) / (2.0 * n as f64)).cos();
             }
-            result[k] = sum * (2.0 / n as f64).sqrt();
+            *out = sum * (2.0 / n as f64).sqrt();
         }
 
         // Normalize first coefficient
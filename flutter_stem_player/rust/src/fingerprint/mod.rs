@@ -5,17 +5,38 @@
 //! - Spectral centroid, bandwidth, rolloff
 //! - Zero-crossing rate
 //! - RMS energy
-//! - Chroma features
+//! - Chroma features (constant-Q)
+//! - Noise floor / SNR estimate
+//! - Stereo width and L/R correlation (stereo sources)
+//!
+//! Also hosts the instrument classifier that runs on extracted fingerprints.
 
+pub mod analysis;
+mod classify;
+mod codec;
+mod cqt;
 mod mfcc;
+mod musical;
+mod noise;
+mod segments;
 mod spectral;
+#[cfg(test)]
+pub(crate) mod test_util;
 
-use crate::{AudioPaletteError, Result};
+use crate::{AudioPaletteError, Result, StereoImage};
 use crate::audio::AudioData;
-use rustfft::{FftPlanner, num_complex::Complex};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
 
+pub use analysis::{analyze_time_series, beat_grid, detect_onsets, pitch_curve, AnalysisKind, AnalysisTrack};
+pub use classify::{InstrumentClass, InstrumentClassifier, InstrumentPrediction};
+pub use cqt::ConstantQ;
 pub use mfcc::MfccExtractor;
+pub use musical::{estimate_key, estimate_tempo, Mode, MusicalKey};
+pub use noise::{estimate_noise, NoiseProfile, NOISY_SNR_DB};
+pub use segments::{
+    combine_fingerprints, SegmentFingerprints, SegmentMatch, SEGMENT_HOP_SECONDS, SEGMENT_WINDOW_SECONDS,
+};
 pub use spectral::SpectralExtractor;
 
 /// Audio fingerprint containing extracted features
@@ -40,63 +61,264 @@ pub struct AudioFingerprint {
 
     // Chroma features (12 pitch classes)
     pub chroma_mean: Vec<f64>,
+
+    // Noise floor / SNR (absent in fingerprints stored before estimation existed)
+    #[serde(default)]
+    pub noise: Option<NoiseProfile>,
+
+    // Stereo image (None when unknown: raw-sample segments and fingerprints
+    // stored before it was measured)
+    #[serde(default)]
+    pub stereo: Option<StereoImage>,
+}
+
+/// Version of the features `Fingerprinter` computes, stored with every
+/// fingerprint. Bump it whenever a change makes new fingerprints
+/// incomparable with stored ones (1: chroma from the constant-Q transform,
+/// 2: mono files measure a centered stereo image).
+pub const FINGERPRINT_VERSION: u32 = 2;
+
+/// Scale applied to stereo features so they carry weight next to MFCCs
+const STEREO_WEIGHT: f64 = 10.0;
+
+/// Perceptual feature groups making up the fingerprint vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FeatureGroup {
+    /// MFCC mean and std
+    Timbre,
+    /// Spectral centroid, bandwidth, rolloff
+    Spectrum,
+    /// RMS level/variation and zero-crossing rate
+    Energy,
+    /// Chroma (pitch classes)
+    Harmony,
+    /// Stereo width and L/R correlation
+    Stereo,
+}
+
+impl FeatureGroup {
+    /// Groups in vector order
+    pub const ALL: [FeatureGroup; 5] = [
+        FeatureGroup::Timbre,
+        FeatureGroup::Spectrum,
+        FeatureGroup::Energy,
+        FeatureGroup::Harmony,
+        FeatureGroup::Stereo,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FeatureGroup::Timbre => "timbre",
+            FeatureGroup::Spectrum => "spectrum",
+            FeatureGroup::Energy => "energy",
+            FeatureGroup::Harmony => "harmony",
+            FeatureGroup::Stereo => "stereo",
+        }
+    }
+}
+
+/// Feature groups a comparison leaves out, e.g. "timbre only" or
+/// "ignore pitch"
+///
+/// Masked groups are dropped from both vectors before the cosine, so they
+/// neither add to nor dilute the score. Masking every group scores 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureMask {
+    pub ignored: Vec<FeatureGroup>,
+}
+
+impl FeatureMask {
+    /// Leave out `groups`
+    pub fn ignoring(groups: &[FeatureGroup]) -> Self {
+        FeatureMask { ignored: groups.to_vec() }
+    }
+
+    /// Compare on `groups` alone
+    pub fn only(groups: &[FeatureGroup]) -> Self {
+        FeatureMask { ignored: FeatureGroup::ALL.into_iter().filter(|g| !groups.contains(g)).collect() }
+    }
+
+    pub fn ignores(&self, group: FeatureGroup) -> bool {
+        self.ignored.contains(&group)
+    }
+
+    /// Nothing is left out
+    pub fn is_empty(&self) -> bool {
+        self.ignored.is_empty()
+    }
+
+    /// Keep the unmasked parts of a vector made of `layout`'s (group,
+    /// length) runs in order, or None if its length doesn't fit the layout
+    pub(crate) fn apply<T: Copy>(&self, vector: &[T], layout: &[(FeatureGroup, usize)]) -> Option<Vec<T>> {
+        if vector.len() != layout.iter().map(|(_, len)| len).sum::<usize>() {
+            return None;
+        }
+        let mut kept = Vec::with_capacity(vector.len());
+        let mut start = 0;
+        for &(group, len) in layout {
+            if !self.ignores(group) {
+                kept.extend_from_slice(&vector[start..start + len]);
+            }
+            start += len;
+        }
+        Some(kept)
+    }
+}
+
+/// One feature group's part in a similarity score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureContribution {
+    pub group: FeatureGroup,
+    /// Similarity of this group on its own (0-100)
+    pub similarity: f64,
+    /// Share of the overall cosine carried by this group; the overall cosine
+    /// equals the sum of `weight * group cosine` over all groups
+    pub weight: f64,
+}
+
+/// Map a cosine in [-1, 1] to a 0-100 score
+fn cosine_to_score(cosine: f64) -> f64 {
+    ((cosine + 1.0) / 2.0 * 100.0).clamp(0.0, 100.0)
+}
+
+/// Cosine similarity of two vectors as a 0-100 score (0 for mismatched
+/// lengths or a zero vector)
+pub(crate) fn cosine_score(v1: &[f64], v2: &[f64]) -> f64 {
+    if v1.len() != v2.len() {
+        return 0.0;
+    }
+
+    let dot: f64 = v1.iter().zip(v2.iter()).map(|(a, b)| a * b).sum();
+    let norm1: f64 = v1.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm2: f64 = v2.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    if norm1 == 0.0 || norm2 == 0.0 {
+        return 0.0;
+    }
+
+    let cosine = dot / (norm1 * norm2);
+    // Convert from [-1, 1] to [0, 100]
+    cosine_to_score(cosine)
 }
 
 impl AudioFingerprint {
     /// Convert fingerprint to a single feature vector for similarity comparison
+    ///
+    /// An unknown stereo image is filled in as `StereoImage::MONO`.
     pub fn to_vector(&self) -> Vec<f64> {
-        let mut vec = Vec::with_capacity(50);
-
-        // MFCC (26 features)
-        vec.extend(&self.mfcc_mean);
-        vec.extend(&self.mfcc_std);
-
-        // Spectral (3 features, normalized)
-        vec.push(self.spectral_centroid / 10000.0);
-        vec.push(self.spectral_bandwidth / 10000.0);
-        vec.push(self.spectral_rolloff / 10000.0);
+        self.to_masked_vector(&FeatureMask::default())
+    }
 
-        // Energy (3 features)
-        vec.push(self.rms_mean);
-        vec.push(self.rms_std);
-        vec.push(self.zero_crossing_rate);
+    /// `to_vector` without the groups `mask` leaves out
+    pub fn to_masked_vector(&self, mask: &FeatureMask) -> Vec<f64> {
+        self.masked_vector(true, mask)
+    }
 
-        // Chroma (12 features)
-        vec.extend(&self.chroma_mean);
+    pub(crate) fn feature_vector(&self, include_stereo: bool) -> Vec<f64> {
+        self.masked_vector(include_stereo, &FeatureMask::default())
+    }
 
+    pub(crate) fn masked_vector(&self, include_stereo: bool, mask: &FeatureMask) -> Vec<f64> {
+        let mut vec = Vec::with_capacity(52);
+        for group in FeatureGroup::ALL {
+            if (group != FeatureGroup::Stereo || include_stereo) && !mask.ignores(group) {
+                vec.extend(self.group_vector(group));
+            }
+        }
         vec
     }
 
-    /// Compute cosine similarity between two fingerprints (0-100%)
-    pub fn similarity(&self, other: &AudioFingerprint) -> f64 {
-        let v1 = self.to_vector();
-        let v2 = other.to_vector();
+    /// (group, length) runs of `feature_vector(include_stereo)`, in order
+    pub(crate) fn vector_layout(&self, include_stereo: bool) -> Vec<(FeatureGroup, usize)> {
+        FeatureGroup::ALL
+            .into_iter()
+            .filter(|&g| g != FeatureGroup::Stereo || include_stereo)
+            .map(|g| (g, self.group_vector(g).len()))
+            .collect()
+    }
 
-        if v1.len() != v2.len() {
-            return 0.0;
+    /// Features belonging to one group (scaled as in the full vector)
+    pub fn group_vector(&self, group: FeatureGroup) -> Vec<f64> {
+        match group {
+            // MFCC (26 features)
+            FeatureGroup::Timbre => self.mfcc_mean.iter().chain(self.mfcc_std.iter()).copied().collect(),
+            // Spectral (3 features, normalized)
+            FeatureGroup::Spectrum => vec![
+                self.spectral_centroid / 10000.0,
+                self.spectral_bandwidth / 10000.0,
+                self.spectral_rolloff / 10000.0,
+            ],
+            // Energy (3 features)
+            FeatureGroup::Energy => vec![self.rms_mean, self.rms_std, self.zero_crossing_rate],
+            // Chroma (12 features)
+            FeatureGroup::Harmony => self.chroma_mean.clone(),
+            // Stereo (2 features)
+            FeatureGroup::Stereo => {
+                let stereo = self.stereo.unwrap_or(StereoImage::MONO);
+                vec![stereo.width * STEREO_WEIGHT, stereo.correlation * STEREO_WEIGHT]
+            }
         }
+    }
 
-        let dot: f64 = v1.iter().zip(v2.iter()).map(|(a, b)| a * b).sum();
-        let norm1: f64 = v1.iter().map(|x| x * x).sum::<f64>().sqrt();
-        let norm2: f64 = v2.iter().map(|x| x * x).sum::<f64>().sqrt();
+    /// Break a similarity score down by feature group
+    ///
+    /// The stereo group is only reported when both fingerprints carry a
+    /// stereo measurement, matching `similarity`.
+    pub fn similarity_breakdown(&self, other: &AudioFingerprint) -> Vec<FeatureContribution> {
+        let include_stereo = self.stereo.is_some() && other.stereo.is_some();
+        let groups: Vec<(FeatureGroup, Vec<f64>, Vec<f64>)> = FeatureGroup::ALL
+            .iter()
+            .filter(|&&g| g != FeatureGroup::Stereo || include_stereo)
+            .map(|&g| (g, self.group_vector(g), other.group_vector(g)))
+            .collect();
+
+        let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let total_norm1 = groups.iter().map(|(_, a, _)| norm(a).powi(2)).sum::<f64>().sqrt();
+        let total_norm2 = groups.iter().map(|(_, _, b)| norm(b).powi(2)).sum::<f64>().sqrt();
+
+        groups
+            .into_iter()
+            .map(|(group, a, b)| {
+                let (n1, n2) = (norm(&a), norm(&b));
+                let similarity = if a.len() != b.len() || n1 == 0.0 || n2 == 0.0 {
+                    0.0
+                } else {
+                    let dot: f64 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+                    cosine_to_score(dot / (n1 * n2))
+                };
+                let weight = if total_norm1 == 0.0 || total_norm2 == 0.0 {
+                    0.0
+                } else {
+                    n1 * n2 / (total_norm1 * total_norm2)
+                };
+                FeatureContribution { group, similarity, weight }
+            })
+            .collect()
+    }
 
-        if norm1 == 0.0 || norm2 == 0.0 {
-            return 0.0;
-        }
+    /// Compute cosine similarity between two fingerprints (0-100%)
+    ///
+    /// Stereo features only count when both sides carry a stereo measurement,
+    /// so raw-sample segment windows still match stereo queries on timbre
+    /// alone. Decoded mono files measure as `StereoImage::MONO`.
+    pub fn similarity(&self, other: &AudioFingerprint) -> f64 {
+        self.similarity_masked(other, &FeatureMask::default())
+    }
 
-        let cosine = dot / (norm1 * norm2);
-        // Convert from [-1, 1] to [0, 100]
-        ((cosine + 1.0) / 2.0 * 100.0).max(0.0).min(100.0)
+    /// `similarity` on the groups `mask` doesn't leave out
+    pub fn similarity_masked(&self, other: &AudioFingerprint, mask: &FeatureMask) -> f64 {
+        let include_stereo = self.stereo.is_some() && other.stereo.is_some();
+        cosine_score(&self.masked_vector(include_stereo, mask), &other.masked_vector(include_stereo, mask))
     }
 }
 
 /// Fingerprint extractor
 pub struct Fingerprinter {
-    n_mfcc: usize,
     hop_length: usize,
     n_fft: usize,
     mfcc_extractor: MfccExtractor,
     spectral_extractor: SpectralExtractor,
+    cqt_cache: Mutex<Option<Arc<ConstantQ>>>,
 }
 
 impl Default for Fingerprinter {
@@ -108,11 +330,11 @@ impl Default for Fingerprinter {
 impl Fingerprinter {
     pub fn new(n_mfcc: usize, hop_length: usize, n_fft: usize) -> Self {
         Fingerprinter {
-            n_mfcc,
             hop_length,
             n_fft,
             mfcc_extractor: MfccExtractor::new(n_mfcc, n_fft),
             spectral_extractor: SpectralExtractor::new(n_fft, hop_length),
+            cqt_cache: Mutex::new(None),
         }
     }
 
@@ -147,6 +369,9 @@ impl Fingerprinter {
         // Extract chroma features
         let chroma_mean = self.compute_chroma(&audio.samples, audio.sample_rate);
 
+        // Estimate background noise
+        let noise = estimate_noise(&audio.samples, self.n_fft, self.hop_length);
+
         Ok(AudioFingerprint {
             duration: audio.duration,
             sample_rate: audio.sample_rate,
@@ -159,9 +384,27 @@ impl Fingerprinter {
             rms_std,
             zero_crossing_rate: zcr,
             chroma_mean,
+            noise,
+            stereo: audio.stereo,
         })
     }
 
+    /// Extract frame-level MFCC sequence (for time-aligned comparison such as DTW)
+    pub fn extract_mfcc_frames(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<Vec<f64>>> {
+        self.mfcc_extractor.extract_frames(samples, sample_rate)
+    }
+
+    /// Samples between consecutive `extract_mfcc_frames` frames; frame `i`
+    /// covers `i * hop` to `i * hop + n_fft`
+    pub fn mfcc_frame_hop(&self) -> usize {
+        self.mfcc_extractor.hop_length()
+    }
+
+    /// Samples covered by one MFCC frame
+    pub fn mfcc_frame_length(&self) -> usize {
+        self.n_fft
+    }
+
     fn compute_rms(&self, samples: &[f32]) -> (f64, f64) {
         let frame_size = self.n_fft;
         let hop = self.hop_length;
@@ -208,58 +451,21 @@ impl Fingerprinter {
     }
 
     fn compute_chroma(&self, samples: &[f32], sample_rate: u32) -> Vec<f64> {
-        // Simplified chroma computation using FFT
-        let n_chroma = 12;
-        let mut chroma = vec![0.0; n_chroma];
-
-        if samples.len() < self.n_fft {
-            return chroma;
-        }
-
-        let mut planner = FftPlanner::new();
-        let fft = planner.plan_fft_forward(self.n_fft);
-
-        // Process frames
-        let mut frame_count = 0;
-        for start in (0..samples.len() - self.n_fft).step_by(self.hop_length) {
-            let frame: Vec<Complex<f64>> = samples[start..start + self.n_fft]
-                .iter()
-                .enumerate()
-                .map(|(i, &x)| {
-                    // Apply Hann window
-                    let window = 0.5 * (1.0 - (2.0 * std::f64::consts::PI * i as f64 / (self.n_fft - 1) as f64).cos());
-                    Complex::new(x as f64 * window, 0.0)
-                })
-                .collect();
-
-            let mut buffer = frame;
-            fft.process(&mut buffer);
-
-            // Map FFT bins to chroma
-            for (i, c) in buffer.iter().enumerate().take(self.n_fft / 2) {
-                let freq = i as f64 * sample_rate as f64 / self.n_fft as f64;
-                if freq > 0.0 {
-                    // Convert frequency to MIDI note, then to chroma
-                    let midi = 12.0 * (freq / 440.0).log2() + 69.0;
-                    let chroma_bin = ((midi as i32 % 12 + 12) % 12) as usize;
-                    let magnitude = c.norm();
-                    chroma[chroma_bin] += magnitude;
-                }
-            }
-            frame_count += 1;
-        }
+        let cqt = self.constant_q(sample_rate);
+        cqt.chroma(samples, cqt.frame_len() / 4)
+    }
 
-        // Normalize
-        if frame_count > 0 {
-            let max = chroma.iter().cloned().fold(0.0_f64, f64::max);
-            if max > 0.0 {
-                for c in &mut chroma {
-                    *c /= max;
-                }
+    /// Chroma CQT for a sample rate (kernels are built once and reused)
+    fn constant_q(&self, sample_rate: u32) -> Arc<ConstantQ> {
+        let mut cache = self.cqt_cache.lock().unwrap();
+        match cache.as_ref() {
+            Some(cqt) if cqt.sample_rate() == sample_rate => cqt.clone(),
+            _ => {
+                let cqt = Arc::new(ConstantQ::for_chroma(sample_rate));
+                *cache = Some(cqt.clone());
+                cqt
             }
         }
-
-        chroma
     }
 }
 
@@ -281,9 +487,62 @@ mod tests {
             rms_std: 0.05,
             zero_crossing_rate: 0.1,
             chroma_mean: vec![0.0; 12],
+            noise: None,
+            stereo: None,
         };
 
         let similarity = fp1.similarity(&fp1);
         assert!((similarity - 100.0).abs() < 0.01);
+
+        // A wide ambience no longer matches its mono counterpart perfectly
+        let mono = AudioFingerprint { stereo: Some(StereoImage::MONO), ..fp1.clone() };
+        let wide = AudioFingerprint { stereo: Some(StereoImage { width: 0.5, correlation: 0.0 }), ..fp1.clone() };
+        assert!(mono.similarity(&wide) < mono.similarity(&mono));
+
+        // Group weights recombine into the overall cosine
+        let breakdown = mono.similarity_breakdown(&wide);
+        assert_eq!(breakdown.len(), FeatureGroup::ALL.len());
+        let cosine: f64 = breakdown.iter().map(|c| c.weight * (c.similarity / 50.0 - 1.0)).sum();
+        assert!((cosine_to_score(cosine) - mono.similarity(&wide)).abs() < 1e-6);
+
+        // Masking the stereo group leaves identical timbre; masking everything else leaves only the difference
+        assert!((mono.similarity_masked(&wide, &FeatureMask::ignoring(&[FeatureGroup::Stereo])) - 100.0).abs() < 1e-6);
+        let stereo_only = FeatureMask::only(&[FeatureGroup::Stereo]);
+        assert!(mono.similarity_masked(&wide, &stereo_only) < mono.similarity(&wide));
+        assert_eq!(mono.to_masked_vector(&stereo_only).len(), 2);
+        let layout = mono.vector_layout(true);
+        assert_eq!(stereo_only.apply(&mono.to_vector(), &layout), Some(mono.to_masked_vector(&stereo_only)));
+    }
+
+    #[test]
+    fn test_mono_file_against_wide_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let phase = |i: usize| std::f32::consts::TAU * 440.0 * i as f32 / 22050.0;
+        // The wide file's mixdown is the mono file: L and R in quadrature
+        let mono: Vec<f32> = (0..22050).map(|i| phase(i).sin() * 0.4).collect();
+        let wide: Vec<f32> = (0..22050)
+            .flat_map(|i| {
+                let a = 0.4 * std::f32::consts::SQRT_2;
+                [phase(i).sin() * a, phase(i).cos() * a]
+            })
+            .collect();
+        let mono = AudioData::load(crate::audio::write_test_wav(dir.path(), "mono.wav", &mono, 22050)).unwrap();
+        let wide =
+            AudioData::load(crate::audio::write_test_wav_channels(dir.path(), "wide.wav", &wide, 22050, 2)).unwrap();
+        assert_eq!(mono.stereo.map(|s| (s.width, s.correlation)), Some((0.0, 1.0)));
+
+        let fingerprinter = Fingerprinter::default();
+        let mono_fp = fingerprinter.extract(&mono).unwrap();
+        let wide_fp = fingerprinter.extract(&wide).unwrap();
+        let timbre = FeatureMask::ignoring(&[FeatureGroup::Stereo]);
+        assert!(mono_fp.similarity_masked(&wide_fp, &timbre) > 99.0);
+        assert!(mono_fp.similarity(&wide_fp) < mono_fp.similarity_masked(&wide_fp, &timbre) - 0.25);
+        let breakdown = mono_fp.similarity_breakdown(&wide_fp);
+        let stereo = breakdown.iter().find(|c| c.group == FeatureGroup::Stereo).unwrap();
+        assert!(stereo.similarity < 60.0);
+
+        // Raw samples carry no stereo measurement and still match on timbre alone
+        let raw = fingerprinter.extract(&AudioData::from_samples(mono.samples.clone(), 22050)).unwrap();
+        assert!((raw.similarity(&wide_fp) - raw.similarity_masked(&wide_fp, &timbre)).abs() < 1e-9);
     }
 }
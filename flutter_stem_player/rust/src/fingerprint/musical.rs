@@ -0,0 +1,244 @@
+//! Tempo and key estimation
+//!
+//! Tempo comes from the autocorrelation of an onset-strength envelope
+//! (half-wave rectified log-energy flux), weighted towards 120 BPM to
+//! settle octave ambiguity. Key comes from correlating the mean chroma
+//! with the Krumhansl-Kessler major and minor profiles. Both return None
+//! when the evidence is weak (one-shots, noise, atonal material).
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Tempo range considered by `estimate_tempo`
+const MIN_BPM: f64 = 60.0;
+const MAX_BPM: f64 = 180.0;
+
+/// Shorter audio can't establish a pulse
+const MIN_TEMPO_SECONDS: f64 = 3.0;
+
+/// Onset envelope hop in seconds (~11.6 ms at 44.1 kHz with 512 samples)
+pub(super) const ONSET_HOP_SECONDS: f64 = 0.0116;
+
+/// Minimum normalized autocorrelation at the chosen lag
+const MIN_PULSE_CLARITY: f64 = 0.1;
+
+/// Minimum profile correlation for a key estimate
+const MIN_KEY_CORRELATION: f64 = 0.6;
+
+const KK_MAJOR: [f64; 12] = [6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88];
+const KK_MINOR: [f64; 12] = [6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17];
+
+const PITCH_NAMES: [&str; 12] = ["C", "C#", "D", "Eb", "E", "F", "F#", "G", "Ab", "A", "Bb", "B"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mode {
+    Major,
+    Minor,
+}
+
+/// A musical key: tonic pitch class (0 = C) and mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MusicalKey {
+    pub tonic: u8,
+    pub mode: Mode,
+}
+
+impl MusicalKey {
+    pub fn new(tonic: u8, mode: Mode) -> Self {
+        MusicalKey { tonic: tonic % 12, mode }
+    }
+
+    /// Parse names like "A minor", "Am", "F# major", "Bb", "ebm"
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let mut chars = text.chars();
+        let letter = chars.next()?.to_ascii_uppercase();
+        let mut tonic: i32 = match letter {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            _ => return None,
+        };
+
+        let mut rest = chars.as_str();
+        if let Some(r) = rest.strip_prefix(['#', '♯']) {
+            tonic += 1;
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix(['b', '♭']) {
+            tonic -= 1;
+            rest = r;
+        }
+
+        let mode = match rest.trim().to_lowercase().as_str() {
+            "" | "maj" | "major" => Mode::Major,
+            "m" | "min" | "minor" => Mode::Minor,
+            _ => return None,
+        };
+        Some(MusicalKey::new(tonic.rem_euclid(12) as u8, mode))
+    }
+
+    /// Canonical name, e.g. "A minor"
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    /// Keys that mix well with this one: itself, its relative major/minor
+    /// and the neighbours a fifth up and down in the same mode (adjacent
+    /// positions on the circle of fifths)
+    pub fn compatible(&self) -> [MusicalKey; 4] {
+        let relative = match self.mode {
+            Mode::Major => MusicalKey::new(self.tonic + 9, Mode::Minor),
+            Mode::Minor => MusicalKey::new(self.tonic + 3, Mode::Major),
+        };
+        [
+            *self,
+            relative,
+            MusicalKey::new(self.tonic + 7, self.mode),
+            MusicalKey::new(self.tonic + 5, self.mode),
+        ]
+    }
+}
+
+impl fmt::Display for MusicalKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self.mode {
+            Mode::Major => "major",
+            Mode::Minor => "minor",
+        };
+        write!(f, "{} {}", PITCH_NAMES[self.tonic as usize], mode)
+    }
+}
+
+/// Estimate the key from a 12-bin chroma vector (index 0 = C)
+pub fn estimate_key(chroma: &[f64]) -> Option<MusicalKey> {
+    if chroma.len() != 12 || chroma.iter().all(|&c| c <= 0.0) {
+        return None;
+    }
+
+    let mut best: Option<(f64, MusicalKey)> = None;
+    for tonic in 0..12 {
+        for (mode, profile) in [(Mode::Major, &KK_MAJOR), (Mode::Minor, &KK_MINOR)] {
+            let rotated: Vec<f64> = (0..12).map(|i| profile[(i + 12 - tonic) % 12]).collect();
+            let r = pearson(chroma, &rotated);
+            if best.is_none_or(|(b, _)| r > b) {
+                best = Some((r, MusicalKey::new(tonic as u8, mode)));
+            }
+        }
+    }
+
+    best.filter(|(r, _)| *r >= MIN_KEY_CORRELATION).map(|(_, key)| key)
+}
+
+/// Estimate the tempo of mono audio in BPM
+pub fn estimate_tempo(samples: &[f32], sample_rate: u32) -> Option<f64> {
+    let sr = sample_rate as f64;
+    if sample_rate == 0 || (samples.len() as f64) < MIN_TEMPO_SECONDS * sr {
+        return None;
+    }
+
+    let hop = ((ONSET_HOP_SECONDS * sr).round() as usize).max(1);
+    let frame_rate = sr / hop as f64;
+    let envelope = onset_envelope(samples, hop);
+
+    let min_lag = (60.0 * frame_rate / MAX_BPM).floor() as usize;
+    let max_lag = ((60.0 * frame_rate / MIN_BPM).ceil() as usize).min(envelope.len() / 2);
+    if min_lag < 1 || max_lag <= min_lag + 1 {
+        return None;
+    }
+
+    let zero_lag: f64 = envelope.iter().map(|e| e * e).sum();
+    if zero_lag <= 0.0 {
+        return None;
+    }
+    let acf: Vec<f64> = (0..=max_lag + 1)
+        .map(|lag| envelope.iter().zip(&envelope[lag..]).map(|(a, b)| a * b).sum::<f64>() / zero_lag)
+        .collect();
+
+    // Prefer lags near 120 BPM (log-Gaussian, one octave standard deviation)
+    let weight = |lag: usize| {
+        let bpm = 60.0 * frame_rate / lag as f64;
+        (-0.5 * (bpm / 120.0).log2().powi(2)).exp()
+    };
+    let best = (min_lag..=max_lag).max_by(|&a, &b| (acf[a] * weight(a)).total_cmp(&(acf[b] * weight(b))))?;
+    if acf[best] < MIN_PULSE_CLARITY {
+        return None;
+    }
+
+    // Parabolic interpolation around the peak for sub-frame precision
+    let (y0, y1, y2) = (acf[best - 1], acf[best], acf[best + 1]);
+    let denom = y0 - 2.0 * y1 + y2;
+    let offset = if denom.abs() > 1e-12 { (0.5 * (y0 - y2) / denom).clamp(-0.5, 0.5) } else { 0.0 };
+    let bpm = 60.0 * frame_rate / (best as f64 + offset);
+
+    Some((bpm * 10.0).round() / 10.0)
+}
+
+/// Half-wave rectified log-energy difference per hop, mean removed
+pub(super) fn onset_envelope(samples: &[f32], hop: usize) -> Vec<f64> {
+    let energies: Vec<f64> = samples
+        .chunks(hop)
+        .map(|frame| (frame.iter().map(|&s| (s as f64).powi(2)).sum::<f64>() / frame.len() as f64 + 1e-10).ln())
+        .collect();
+
+    let flux: Vec<f64> = energies.windows(2).map(|w| (w[1] - w[0]).max(0.0)).collect();
+    let mean = flux.iter().sum::<f64>() / flux.len().max(1) as f64;
+    flux.into_iter().map(|f| f - mean).collect()
+}
+
+fn pearson(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len() as f64;
+    let (mean_a, mean_b) = (a.iter().sum::<f64>() / n, b.iter().sum::<f64>() / n);
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (x, y) in a.iter().zip(b) {
+        cov += (x - mean_a) * (y - mean_b);
+        var_a += (x - mean_a).powi(2);
+        var_b += (y - mean_b).powi(2);
+    }
+    if var_a <= 0.0 || var_b <= 0.0 {
+        return 0.0;
+    }
+    cov / (var_a * var_b).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tempo_and_key() {
+        // Clicks every 0.5 s = 120 BPM
+        let sr = 22050;
+        let mut samples = vec![0.0f32; sr as usize * 6];
+        for beat in 0..12 {
+            let start = beat * sr as usize / 2;
+            for (i, s) in samples[start..start + 200].iter_mut().enumerate() {
+                *s = (1.0 - i as f32 / 200.0) * if i % 2 == 0 { 0.9 } else { -0.9 };
+            }
+        }
+        let bpm = estimate_tempo(&samples, sr).unwrap();
+        assert!((bpm - 120.0).abs() < 2.0, "bpm {}", bpm);
+        assert!(estimate_tempo(&samples[..sr as usize], sr).is_none());
+
+        // A minor triad (A, C, E) with a little of the scale around it
+        let mut chroma = vec![0.05; 12];
+        chroma[9] = 1.0;
+        chroma[0] = 0.7;
+        chroma[4] = 0.8;
+        chroma[2] = 0.3;
+        chroma[7] = 0.3;
+        let key = estimate_key(&chroma).unwrap();
+        assert_eq!(key.name(), "A minor");
+        assert!(estimate_key(&[1.0; 12]).is_none());
+
+        assert_eq!(MusicalKey::parse("Am"), Some(key));
+        assert_eq!(MusicalKey::parse(" bb major ").unwrap().name(), "Bb major");
+        assert_eq!(MusicalKey::parse("F#m").unwrap().name(), "F# minor");
+        assert!(MusicalKey::parse("H").is_none());
+        let names: Vec<String> = key.compatible().iter().map(|k| k.name()).collect();
+        assert_eq!(names, ["A minor", "C major", "E minor", "D minor"]);
+    }
+}
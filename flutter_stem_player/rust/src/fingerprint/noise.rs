@@ -0,0 +1,94 @@
+//! Noise floor and signal-to-noise ratio estimation
+//!
+//! Uses the distribution of frame energies: the quiet tail approximates the
+//! background noise, the loud tail the signal level. Works well for field
+//! recordings and one-shots with gaps; sustained drones with no quiet frames
+//! read as low SNR.
+
+use serde::{Deserialize, Serialize};
+
+/// Recordings below this SNR are flagged as noisy
+pub const NOISY_SNR_DB: f64 = 10.0;
+
+/// Lowest level reported for the noise floor (digital silence)
+const MIN_LEVEL_DB: f64 = -120.0;
+
+/// Percentile of frame levels taken as noise floor
+const NOISE_PERCENTILE: f64 = 0.10;
+
+/// Percentile of frame levels taken as signal level
+const SIGNAL_PERCENTILE: f64 = 0.95;
+
+/// Estimated noise characteristics of a recording
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct NoiseProfile {
+    pub noise_floor_db: f64,
+    pub signal_level_db: f64,
+    pub snr_db: f64,
+}
+
+impl NoiseProfile {
+    /// Whether the recording is likely too noisy to be useful
+    pub fn is_noisy(&self) -> bool {
+        self.snr_db < NOISY_SNR_DB
+    }
+}
+
+/// Estimate the noise profile from framed RMS levels
+pub fn estimate_noise(samples: &[f32], frame_size: usize, hop: usize) -> Option<NoiseProfile> {
+    let mut levels: Vec<f64> = Vec::new();
+
+    for start in (0..samples.len()).step_by(hop.max(1)) {
+        let end = (start + frame_size).min(samples.len());
+        let frame = &samples[start..end];
+        if frame.len() < 64 {
+            continue;
+        }
+
+        let sum_sq: f64 = frame.iter().map(|&x| (x as f64).powi(2)).sum();
+        let rms = (sum_sq / frame.len() as f64).sqrt();
+        levels.push((20.0 * rms.max(1e-10).log10()).max(MIN_LEVEL_DB));
+    }
+
+    if levels.is_empty() {
+        return None;
+    }
+
+    levels.sort_by(|a, b| a.total_cmp(b));
+    let percentile = |p: f64| levels[((levels.len() - 1) as f64 * p).round() as usize];
+
+    let noise_floor_db = percentile(NOISE_PERCENTILE);
+    let signal_level_db = percentile(SIGNAL_PERCENTILE);
+
+    Some(NoiseProfile {
+        noise_floor_db,
+        signal_level_db,
+        snr_db: signal_level_db - noise_floor_db,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snr_clean_vs_noisy() {
+        // Tone bursts separated by near-silence
+        let clean: Vec<f32> = (0..44100)
+            .map(|i| if (i / 4410) % 2 == 0 { (i as f32 * 0.05).sin() * 0.5 } else { 0.0001 })
+            .collect();
+        // Same bursts buried in broadband noise
+        let noisy: Vec<f32> = clean
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x + 0.3 * (((i * 7919) % 1000) as f32 / 500.0 - 1.0))
+            .collect();
+
+        let clean_profile = estimate_noise(&clean, 2048, 512).unwrap();
+        let noisy_profile = estimate_noise(&noisy, 2048, 512).unwrap();
+
+        assert!(!clean_profile.is_noisy());
+        assert!(noisy_profile.is_noisy());
+        assert!(clean_profile.snr_db > noisy_profile.snr_db);
+    }
+}
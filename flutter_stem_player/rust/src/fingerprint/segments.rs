@@ -0,0 +1,221 @@
+//! Windowed fingerprints for segment search
+//!
+//! Segment search used to decode every candidate and fingerprint a
+//! sliding window over it for each query. Instead, each sound is
+//! fingerprinted once at index time in short overlapping windows; a query
+//! spanning several windows is matched against their combination (every
+//! other window, so the combined ones don't overlap), which needs no
+//! audio at all.
+
+use super::{AudioFingerprint, FeatureMask, Fingerprinter};
+use crate::audio::AudioData;
+use crate::{descending_score, AudioPaletteError, Result};
+
+pub const SEGMENT_WINDOW_SECONDS: f64 = 1.0;
+/// 50% overlap
+pub const SEGMENT_HOP_SECONDS: f64 = 0.5;
+
+/// Fingerprints of consecutive windows of one sound
+#[derive(Debug, Clone)]
+pub struct SegmentFingerprints {
+    pub window_seconds: f64,
+    pub hop_seconds: f64,
+    /// Window `i` starts at `i * hop_seconds`
+    pub fingerprints: Vec<AudioFingerprint>,
+}
+
+/// A span of a sound scored against a query
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentMatch {
+    pub score: f64,
+    pub start: f64,
+    pub end: f64,
+}
+
+impl SegmentMatch {
+    fn overlaps(&self, other: &SegmentMatch) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Highest-scoring span, the earliest on ties
+    pub fn best(spans: impl IntoIterator<Item = SegmentMatch>) -> Option<SegmentMatch> {
+        spans.into_iter().reduce(|best, s| if s.score > best.score { s } else { best })
+    }
+
+    /// Every span scoring at least `min_score` that doesn't overlap a
+    /// better one, in time order
+    ///
+    /// Picked greedily best first, so a sound containing the query three
+    /// times yields the three hits rather than three windows around the
+    /// strongest one.
+    pub fn non_overlapping(mut spans: Vec<SegmentMatch>, min_score: f64) -> Vec<SegmentMatch> {
+        spans.retain(|s| s.score >= min_score);
+        // Ties keep the earlier span
+        spans.sort_by(|a, b| descending_score(a.score, b.score).then(a.start.total_cmp(&b.start)));
+        let mut picked: Vec<SegmentMatch> = Vec::new();
+        for span in spans {
+            if !picked.iter().any(|p| p.overlaps(&span)) {
+                picked.push(span);
+            }
+        }
+        picked.sort_by(|a, b| a.start.total_cmp(&b.start));
+        picked
+    }
+}
+
+/// Pooled standard deviation of equally sized parts
+fn pooled_std(means: &[f64], stds: &[f64], mean: f64) -> f64 {
+    let second_moment = means.iter().zip(stds).map(|(m, s)| s * s + m * m).sum::<f64>() / means.len() as f64;
+    (second_moment - mean * mean).max(0.0).sqrt()
+}
+
+fn average(values: impl Iterator<Item = f64>, count: usize) -> f64 {
+    values.sum::<f64>() / count as f64
+}
+
+/// Fingerprint of non-overlapping windows played back to back
+///
+/// Means average; standard deviations are pooled. Noise and stereo are
+/// left out like for any raw-sample segment.
+pub fn combine_fingerprints(parts: &[&AudioFingerprint]) -> AudioFingerprint {
+    let n = parts.len().max(1);
+    let per_coefficient = |get: fn(&AudioFingerprint) -> &Vec<f64>| -> Vec<f64> {
+        let len = parts.iter().map(|p| get(p).len()).min().unwrap_or(0);
+        (0..len).map(|i| average(parts.iter().map(|p| get(p)[i]), n)).collect()
+    };
+    let mfcc_mean = per_coefficient(|p| &p.mfcc_mean);
+    let mfcc_std = mfcc_mean
+        .iter()
+        .enumerate()
+        .map(|(i, &mean)| {
+            let means: Vec<f64> = parts.iter().map(|p| p.mfcc_mean[i]).collect();
+            let stds: Vec<f64> = parts.iter().map(|p| p.mfcc_std.get(i).copied().unwrap_or(0.0)).collect();
+            pooled_std(&means, &stds, mean)
+        })
+        .collect();
+    let rms_mean = average(parts.iter().map(|p| p.rms_mean), n);
+    let rms_means: Vec<f64> = parts.iter().map(|p| p.rms_mean).collect();
+    let rms_stds: Vec<f64> = parts.iter().map(|p| p.rms_std).collect();
+
+    AudioFingerprint {
+        duration: parts.iter().map(|p| p.duration).sum(),
+        sample_rate: parts.first().map_or(0, |p| p.sample_rate),
+        mfcc_mean,
+        mfcc_std,
+        spectral_centroid: average(parts.iter().map(|p| p.spectral_centroid), n),
+        spectral_bandwidth: average(parts.iter().map(|p| p.spectral_bandwidth), n),
+        spectral_rolloff: average(parts.iter().map(|p| p.spectral_rolloff), n),
+        rms_mean,
+        rms_std: pooled_std(&rms_means, &rms_stds, rms_mean),
+        zero_crossing_rate: average(parts.iter().map(|p| p.zero_crossing_rate), n),
+        chroma_mean: per_coefficient(|p| &p.chroma_mean),
+        noise: None,
+        stereo: None,
+    }
+}
+
+impl Fingerprinter {
+    /// Fingerprint `audio` in `SEGMENT_WINDOW_SECONDS` windows every
+    /// `SEGMENT_HOP_SECONDS` (one window for shorter sounds)
+    pub fn extract_segments(&self, audio: &AudioData) -> Result<SegmentFingerprints> {
+        if audio.samples.is_empty() {
+            return Err(AudioPaletteError::FingerprintError("Empty audio".to_string()));
+        }
+        let rate = audio.sample_rate as f64;
+        let window = ((SEGMENT_WINDOW_SECONDS * rate) as usize).min(audio.samples.len());
+        let hop = ((SEGMENT_HOP_SECONDS * rate) as usize).max(1);
+        let fingerprints = (0..)
+            .map(|i| i * hop)
+            .take_while(|&start| start == 0 || start + window <= audio.samples.len())
+            .map(|start| self.extract_from_samples(&audio.samples[start..start + window], audio.sample_rate))
+            .collect::<Result<_>>()?;
+        Ok(SegmentFingerprints {
+            window_seconds: window as f64 / rate,
+            hop_seconds: hop as f64 / rate,
+            fingerprints,
+        })
+    }
+}
+
+impl SegmentFingerprints {
+    /// Every span of consecutive windows as long as the query rounded to
+    /// whole windows (the whole sound if it is shorter), scored against
+    /// `query` on the groups `mask` doesn't leave out, one per hop
+    pub fn spans(&self, query: &AudioFingerprint, mask: &FeatureMask) -> Vec<SegmentMatch> {
+        if self.fingerprints.is_empty() || self.window_seconds <= 0.0 || self.hop_seconds <= 0.0 {
+            return Vec::new();
+        }
+        // Windows `stride` apart don't overlap
+        let stride = ((self.window_seconds / self.hop_seconds).round() as usize).max(1);
+        let available = (self.fingerprints.len() - 1) / stride + 1;
+        let count = ((query.duration / self.window_seconds).round() as usize).clamp(1, available);
+
+        (0..=self.fingerprints.len() - 1 - (count - 1) * stride)
+            .map(|first| {
+                let parts: Vec<&AudioFingerprint> =
+                    (0..count).map(|k| &self.fingerprints[first + k * stride]).collect();
+                let start = first as f64 * self.hop_seconds;
+                SegmentMatch {
+                    score: query.similarity_masked(&combine_fingerprints(&parts), mask),
+                    start,
+                    end: start + count as f64 * self.window_seconds,
+                }
+            })
+            .collect()
+    }
+
+    /// Span best matching `query`
+    pub fn best_match(&self, query: &AudioFingerprint) -> Option<SegmentMatch> {
+        SegmentMatch::best(self.spans(query, &FeatureMask::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_matching() {
+        // Two seconds of noise-like texture, then two of a low tone, then two of a high tone
+        let rate = 22050;
+        let samples: Vec<f32> = (0..rate * 6)
+            .map(|i| {
+                let t = i as f32 / rate as f32;
+                match i / (rate * 2) {
+                    0 => ((i as f32 * 12.9898).sin() * 43758.545).fract() - 0.5,
+                    1 => 0.5 * (t * 220.0 * std::f32::consts::TAU).sin(),
+                    _ => 0.5 * (t * 1760.0 * std::f32::consts::TAU).sin(),
+                }
+            })
+            .collect();
+        let fingerprinter = Fingerprinter::default();
+        let segments = fingerprinter.extract_segments(&AudioData::from_samples(samples.clone(), rate as u32)).unwrap();
+        assert_eq!(segments.fingerprints.len(), 11);
+
+        // A two-second query on the low tone lands on it
+        let low = &samples[rate * 2..rate * 4];
+        let query = fingerprinter.extract_from_samples(low, rate as u32).unwrap();
+        let found = segments.best_match(&query).unwrap();
+        assert_eq!((found.start, found.end), (2.0, 4.0));
+        assert!(found.score > 95.0, "{}", found.score);
+
+        // Combined windows stand in for fingerprinting the span directly
+        let parts: Vec<&AudioFingerprint> = vec![&segments.fingerprints[4], &segments.fingerprints[6]];
+        assert!(combine_fingerprints(&parts).similarity(&query) > 95.0);
+
+        // Queries longer than the sound compare against all of it
+        let long = AudioFingerprint { duration: 60.0, ..query.clone() };
+        assert_eq!(segments.best_match(&long).unwrap().start, 0.0);
+
+        // A repeated query is found each time, without overlapping hits
+        let mut repeated = samples[rate * 2..rate * 3].to_vec();
+        repeated.extend_from_slice(&samples[rate * 4..rate * 6]);
+        repeated.extend_from_slice(&samples[rate * 2..rate * 3]);
+        repeated.extend_from_slice(&samples[rate * 4..rate * 6]);
+        repeated.extend_from_slice(&samples[rate * 2..rate * 3]);
+        let segments = fingerprinter.extract_segments(&AudioData::from_samples(repeated, rate as u32)).unwrap();
+        let query = fingerprinter.extract_from_samples(&low[..rate], rate as u32).unwrap();
+        let hits = SegmentMatch::non_overlapping(segments.spans(&query, &FeatureMask::default()), 95.0);
+        assert_eq!(hits.iter().map(|h| h.start).collect::<Vec<_>>(), vec![0.0, 3.0, 6.0]);
+    }
+}
@@ -0,0 +1,23 @@
+//! Fingerprints for tests
+
+use super::AudioFingerprint;
+
+/// Synthetic mono fingerprint; nearby seeds give similar fingerprints and
+/// distant seeds dissimilar ones
+pub(crate) fn fingerprint(seed: f64) -> AudioFingerprint {
+    AudioFingerprint {
+        duration: 1.0,
+        sample_rate: 44100,
+        mfcc_mean: (0..13).map(|i| ((i as f64 + 1.0) * seed).sin() * 10.0).collect(),
+        mfcc_std: (0..13).map(|i| ((i as f64 + 2.0) * seed).cos().abs()).collect(),
+        spectral_centroid: 2000.0 + 1000.0 * seed.sin(),
+        spectral_bandwidth: 1500.0,
+        spectral_rolloff: 5000.0,
+        rms_mean: 0.2,
+        rms_std: 0.05,
+        zero_crossing_rate: 0.1,
+        chroma_mean: (0..12).map(|i| ((i as f64) * seed).cos().abs()).collect(),
+        noise: None,
+        stereo: None,
+    }
+}
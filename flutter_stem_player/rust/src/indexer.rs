@@ -0,0 +1,811 @@
+//! Sound indexing pipeline
+//!
+//! Turns audio files into database rows: content hashing (to catch exact
+//! duplicates), fingerprinting (whole file and windowed segments),
+//! time-series analysis (onsets, beats, pitch), thumbnails, instrument
+//! classification and optional model tagging. Analysis runs in parallel;
+//! each batch of results is written in one transaction. Also provides
+//! recursive directory scans so the app can index a whole folder tree
+//! with a single call.
+
+use crate::audio::{content_hash, extract_artwork, file_stamp, AudioData};
+use crate::database::{IndexedFile, PaletteDatabase, WriteBatch};
+use crate::fingerprint::{
+    analyze_time_series, estimate_key, estimate_tempo, AnalysisTrack, AudioFingerprint, Fingerprinter,
+    InstrumentClassifier, SegmentFingerprints, FINGERPRINT_VERSION,
+};
+use crate::render::{
+    render_thumbnails, Thumbnail, ThumbnailKind, WaveformPeaks, DEFAULT_WAVEFORM_BUCKETS, MAX_ARTWORK_BYTES,
+};
+use crate::tagging::ModelTagger;
+use crate::{AudioPaletteError, CancellationToken, FileStamp, NewSound, Result};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Tag source used for classifier predictions
+pub const CLASSIFIER_TAG_SOURCE: &str = "classifier";
+
+/// Tag source used for tagging model predictions
+pub const MODEL_TAG_SOURCE: &str = "model";
+
+/// Minimum fingerprint similarity (0-100) for a relink target to be accepted
+pub const RELINK_MIN_SIMILARITY: f64 = 90.0;
+
+/// Files re-analyzed per batch during a rescan
+const RESCAN_BATCH_SIZE: usize = 32;
+
+/// Directory scan configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexOptions {
+    /// File extensions to index (case-insensitive, without the dot)
+    pub extensions: Vec<String>,
+    /// Skip files smaller than this many bytes
+    pub min_file_size: u64,
+    /// Skip files larger than this many bytes
+    pub max_file_size: Option<u64>,
+    pub follow_links: bool,
+    /// Skip paths that are already in the database
+    pub skip_indexed: bool,
+    /// Files analyzed and written per batch (progress is reported per batch)
+    pub batch_size: usize,
+}
+
+impl Default for IndexOptions {
+    fn default() -> Self {
+        IndexOptions {
+            extensions: ["wav", "flac", "mp3", "ogg", "m4a", "aac"]
+                .iter()
+                .map(|e| e.to_string())
+                .collect(),
+            min_file_size: 0,
+            max_file_size: None,
+            follow_links: false,
+            skip_indexed: true,
+            batch_size: 32,
+        }
+    }
+}
+
+/// Progress of a directory index run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexProgress {
+    /// Files matching the filters
+    pub total: usize,
+    /// Files handled so far (added, duplicate, skipped, re-analyzed or failed)
+    pub processed: usize,
+    pub added: usize,
+    /// Same content already indexed under another path
+    pub duplicates: usize,
+    /// Already indexed under the same path
+    pub skipped: usize,
+    /// Already indexed under the same path, but re-analyzed because the
+    /// fingerprint was computed by an older analysis (`FINGERPRINT_VERSION`)
+    pub reanalyzed: usize,
+    /// Could not be decoded or analyzed
+    pub failed: usize,
+    pub finished: bool,
+    pub cancelled: bool,
+}
+
+/// Result of a library rescan
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RescanSummary {
+    /// Sounds whose file was checked
+    pub checked: usize,
+    /// Modified on disk and re-analyzed
+    pub changed: usize,
+    /// Size or mtime changed but the contents are identical
+    pub touched: usize,
+    /// Unchanged, but re-analyzed because its fingerprint was missing or
+    /// computed by an older analysis (`FINGERPRINT_VERSION`)
+    pub outdated: usize,
+    /// File no longer exists (see `PaletteDatabase::find_missing_files`)
+    pub missing: usize,
+    /// Changed but could not be re-analyzed
+    pub failed: usize,
+}
+
+/// Result of checking indexed files against their content hashes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifyReport {
+    /// Files whose contents still match the indexed hash
+    pub verified: usize,
+    /// Sounds indexed without a hash that had one computed now
+    pub hashed: usize,
+    /// Sounds whose file contents differ from what was indexed
+    pub modified: Vec<i64>,
+    /// Sounds whose file no longer exists
+    pub missing: Vec<i64>,
+}
+
+/// What a rescan found for one indexed file
+enum FileCheck {
+    Unchanged,
+    Missing,
+    Touched(FileStamp),
+    Changed(Box<AnalyzedSound>),
+    Outdated(Box<AnalyzedSound>),
+    Failed,
+}
+
+/// Everything written to the database for one indexed file
+struct AnalyzedSound {
+    sound: NewSound,
+    fingerprint: AudioFingerprint,
+    segments: SegmentFingerprints,
+    classifier_tags: Vec<(String, f64)>,
+    /// None when no tagging model is loaded
+    model_tags: Option<Vec<(String, f64)>>,
+    waveform: WaveformPeaks,
+    analyses: Vec<AnalysisTrack>,
+    thumbnails: Vec<Thumbnail>,
+}
+
+/// Outcome of preparing a file for indexing
+enum Prepared {
+    /// The same content is already indexed under this id
+    Duplicate(i64),
+    New(Box<AnalyzedSound>),
+}
+
+/// Outcome of one file in a batch
+enum BatchEntry {
+    Added(i64),
+    /// Same content already indexed under this id
+    Duplicate(i64),
+    Failed,
+    /// Not started because the run was cancelled
+    NotStarted,
+}
+
+/// Adds sounds to a database
+pub struct Indexer<'a> {
+    db: &'a PaletteDatabase,
+    tagger: Option<&'a ModelTagger>,
+    cancel: CancellationToken,
+}
+
+impl<'a> Indexer<'a> {
+    pub fn new(db: &'a PaletteDatabase, tagger: Option<&'a ModelTagger>) -> Self {
+        Indexer { db, tagger, cancel: CancellationToken::new() }
+    }
+
+    /// Stop batch operations once `token` is cancelled: files not yet
+    /// started are left alone, files already being analyzed are stored
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Add a sound file, returning its id
+    ///
+    /// If the same audio (identical file contents) is already indexed under
+    /// another path, returns the existing sound's id without re-indexing.
+    pub fn add_file(&self, filepath: &str) -> Result<i64> {
+        match self.prepare(filepath)? {
+            Prepared::Duplicate(id) => Ok(id),
+            Prepared::New(analyzed) => Ok(self.store(&[*analyzed])?[0]),
+        }
+    }
+
+    /// Add many files, analyzing them in parallel
+    ///
+    /// Returns one entry per input path: the sound id (the existing one for
+    /// exact duplicates), or None if the file could not be loaded.
+    pub fn add_files(&self, filepaths: &[String]) -> Result<Vec<Option<i64>>> {
+        self.add_batch(filepaths)?
+            .into_iter()
+            .map(|entry| match entry {
+                BatchEntry::Added(id) | BatchEntry::Duplicate(id) => Ok(Some(id)),
+                BatchEntry::Failed => Ok(None),
+                BatchEntry::NotStarted => Err(AudioPaletteError::Cancelled),
+            })
+            .collect()
+    }
+
+    /// Point a sound at a moved or renamed file after checking it is the
+    /// same audio
+    ///
+    /// A file with the indexed content hash is accepted outright; otherwise
+    /// its fingerprint must be at least `min_similarity` similar to the
+    /// stored one (e.g. the file was re-encoded). Returns the similarity.
+    pub fn relink(&self, sound_id: i64, new_path: &str, min_similarity: f64) -> Result<f64> {
+        let hash = content_hash(new_path)?;
+        if self.db.get_content_hash(sound_id)?.as_deref() == Some(hash.as_str()) {
+            self.db.relink_sound(sound_id, new_path, Some(&hash))?;
+            self.db.update_file_stamp(sound_id, file_stamp(new_path)?)?;
+            return Ok(100.0);
+        }
+
+        let stored = self.db.get_fingerprint(sound_id)?.ok_or_else(|| {
+            AudioPaletteError::FingerprintError(format!(
+                "Sound {} has no current fingerprint to verify against (rescan the library first)",
+                sound_id
+            ))
+        })?;
+        let fingerprint = Fingerprinter::default().extract(&AudioData::load(new_path)?)?;
+        let similarity = stored.similarity(&fingerprint);
+        if similarity < min_similarity {
+            return Err(AudioPaletteError::FingerprintError(format!(
+                "{} does not match sound {} ({:.1}% similar)",
+                new_path, sound_id, similarity
+            )));
+        }
+
+        self.db.relink_sound(sound_id, new_path, Some(&hash))?;
+        self.db.update_file_stamp(sound_id, file_stamp(new_path)?)?;
+        self.db.store_fingerprint(sound_id, &fingerprint)?;
+        Ok(similarity)
+    }
+
+    /// Re-analyze sounds whose files changed on disk since they were indexed,
+    /// or whose fingerprint is missing or outdated
+    ///
+    /// Files are compared by size and modification time first, so unchanged
+    /// files are not read at all; a changed stamp with identical contents
+    /// only updates the stamp. Re-analyzed sounds keep their id, user
+    /// metadata, user tags and collection memberships.
+    pub fn rescan(&self) -> Result<RescanSummary> {
+        let files = self.db.get_indexed_files()?;
+        let mut summary = RescanSummary { checked: files.len(), ..RescanSummary::default() };
+
+        for batch in files.chunks(RESCAN_BATCH_SIZE) {
+            self.rescan_batch(batch, &mut summary)?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Check a batch of indexed files, re-analyzing the changed and
+    /// outdated ones, and add the outcome to `summary`
+    fn rescan_batch(&self, batch: &[IndexedFile], summary: &mut RescanSummary) -> Result<()> {
+        let checks: Vec<(i64, FileCheck)> = batch.par_iter().map(|file| (file.id, self.check(file))).collect();
+
+        let mut reanalyzed = Vec::new();
+        for (id, check) in checks {
+            match check {
+                FileCheck::Unchanged => {}
+                FileCheck::Missing => summary.missing += 1,
+                FileCheck::Failed => summary.failed += 1,
+                FileCheck::Touched(stamp) => {
+                    self.db.update_file_stamp(id, stamp)?;
+                    summary.touched += 1;
+                }
+                FileCheck::Changed(analyzed) => {
+                    summary.changed += 1;
+                    reanalyzed.push((id, *analyzed));
+                }
+                FileCheck::Outdated(analyzed) => {
+                    summary.outdated += 1;
+                    reanalyzed.push((id, *analyzed));
+                }
+            }
+        }
+
+        let refreshed: Vec<(i64, NewSound)> = reanalyzed.iter().map(|(id, a)| (*id, a.sound.clone())).collect();
+        let (ids, analyzed): (Vec<i64>, Vec<AnalyzedSound>) = reanalyzed.into_iter().unzip();
+        self.db.write_batch(|batch| {
+            batch.refresh_sounds(&refreshed)?;
+            store_analysis(batch, &ids, &analyzed)
+        })
+    }
+
+    /// Check that every indexed file still has the contents it was indexed
+    /// with, reading each file in full
+    ///
+    /// Sounds indexed before content hashes existed get one recorded, so
+    /// they take part in duplicate detection and relinking from now on.
+    pub fn verify(&self) -> Result<VerifyReport> {
+        let files = self.db.get_indexed_files()?;
+        let hashes: Vec<Option<String>> = files.par_iter().map(|f| content_hash(&f.filepath).ok()).collect();
+
+        let mut report = VerifyReport::default();
+        for (file, hash) in files.iter().zip(hashes) {
+            match (hash, &file.content_hash) {
+                (None, _) => report.missing.push(file.id),
+                (Some(hash), None) => {
+                    self.db.set_content_hash(file.id, &hash)?;
+                    report.hashed += 1;
+                }
+                (Some(hash), Some(indexed)) if &hash == indexed => report.verified += 1,
+                (Some(_), Some(_)) => report.modified.push(file.id),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Compare an indexed file with its current state on disk
+    fn check(&self, file: &IndexedFile) -> FileCheck {
+        let Ok(stamp) = file_stamp(&file.filepath) else {
+            return FileCheck::Missing;
+        };
+        let outdated = file.fingerprint_version != Some(FINGERPRINT_VERSION);
+        if file.file_stamp == Some(stamp) && !outdated {
+            return FileCheck::Unchanged;
+        }
+
+        let result = content_hash(&file.filepath).and_then(|hash| {
+            if file.content_hash.as_deref() != Some(hash.as_str()) {
+                Ok(FileCheck::Changed(Box::new(self.analyze(&file.filepath, hash)?)))
+            } else if outdated {
+                Ok(FileCheck::Outdated(Box::new(self.analyze(&file.filepath, hash)?)))
+            } else {
+                Ok(FileCheck::Touched(stamp))
+            }
+        });
+        result.unwrap_or_else(|e| {
+            log::warn!("Cannot rescan {}: {}", file.filepath, e);
+            FileCheck::Failed
+        })
+    }
+
+    /// Index every matching file below `root`
+    ///
+    /// Files already indexed under the same path are skipped (with
+    /// `skip_indexed`) unless their fingerprint is outdated, in which case
+    /// they are re-analyzed in place like a rescan would.
+    ///
+    /// `progress` is called after the scan and after each batch; returning
+    /// false stops the run after the current batch. Cancelling the
+    /// indexer's token stops it within the batch.
+    pub fn index_directory<F>(&self, root: &Path, options: &IndexOptions, mut progress: F) -> Result<IndexProgress>
+    where
+        F: FnMut(&IndexProgress) -> bool,
+    {
+        let mut files = scan_directory(root, options)?;
+        let mut status = IndexProgress { total: files.len(), ..IndexProgress::default() };
+
+        let mut outdated = Vec::new();
+        if options.skip_indexed {
+            let mut indexed: HashMap<String, IndexedFile> =
+                self.db.get_indexed_files()?.into_iter().map(|f| (f.filepath.clone(), f)).collect();
+            files.retain(|f| match indexed.remove(f) {
+                None => true,
+                Some(file) => {
+                    if file.fingerprint_version != Some(FINGERPRINT_VERSION) {
+                        outdated.push(file);
+                    }
+                    false
+                }
+            });
+            status.skipped = status.total - files.len() - outdated.len();
+            status.processed = status.skipped;
+        }
+
+        if !progress(&status) || self.cancel.is_cancelled() {
+            status.cancelled = true;
+            return Ok(status);
+        }
+
+        for batch in outdated.chunks(options.batch_size.max(1)) {
+            let mut summary = RescanSummary::default();
+            self.rescan_batch(batch, &mut summary)?;
+            status.reanalyzed += summary.changed + summary.outdated;
+            status.failed += summary.missing + summary.failed;
+            status.processed += batch.len();
+
+            let keep_going = progress(&status) && !self.cancel.is_cancelled();
+            if !keep_going && status.processed < status.total {
+                status.cancelled = true;
+                return Ok(status);
+            }
+        }
+
+        for batch in files.chunks(options.batch_size.max(1)) {
+            for entry in self.add_batch(batch)? {
+                match entry {
+                    BatchEntry::Added(_) => status.added += 1,
+                    BatchEntry::Duplicate(_) => status.duplicates += 1,
+                    BatchEntry::Failed => status.failed += 1,
+                    // Not counted as processed
+                    BatchEntry::NotStarted => continue,
+                }
+                status.processed += 1;
+            }
+
+            let keep_going = progress(&status) && !self.cancel.is_cancelled();
+            if !keep_going && status.processed < status.total {
+                status.cancelled = true;
+                return Ok(status);
+            }
+        }
+
+        status.finished = true;
+        progress(&status);
+        Ok(status)
+    }
+
+    /// Prepare and store a batch, with one entry per file
+    fn add_batch(&self, filepaths: &[String]) -> Result<Vec<BatchEntry>> {
+        let prepared: Vec<Option<Result<Prepared>>> = filepaths
+            .par_iter()
+            .map(|path| (!self.cancel.is_cancelled()).then(|| self.prepare(path)))
+            .collect();
+
+        // Existing ids are known up front; new sounds get theirs from the write
+        let mut new_sounds = Vec::new();
+        let slots: Vec<Option<BatchEntry>> = prepared
+            .into_iter()
+            .zip(filepaths)
+            .map(|(p, path)| match p {
+                Some(Ok(Prepared::Duplicate(id))) => Some(BatchEntry::Duplicate(id)),
+                Some(Ok(Prepared::New(analyzed))) => {
+                    new_sounds.push(*analyzed);
+                    None
+                }
+                Some(Err(e)) => {
+                    log::warn!("Skipping {}: {}", path, e);
+                    Some(BatchEntry::Failed)
+                }
+                None => Some(BatchEntry::NotStarted),
+            })
+            .collect();
+        let mut ids = self.store(&new_sounds)?.into_iter();
+
+        Ok(slots
+            .into_iter()
+            .map(|slot| slot.unwrap_or_else(|| ids.next().map_or(BatchEntry::Failed, BatchEntry::Added)))
+            .collect())
+    }
+
+    /// Hash a file and, unless it duplicates an indexed sound, analyze it
+    fn prepare(&self, filepath: &str) -> Result<Prepared> {
+        let hash = content_hash(filepath)?;
+        if let Some(existing) = self.db.find_duplicate(&hash, filepath)? {
+            log::info!("{} duplicates already indexed {}", filepath, existing.filepath);
+            return Ok(Prepared::Duplicate(existing.id));
+        }
+        Ok(Prepared::New(Box::new(self.analyze(filepath, hash)?)))
+    }
+
+    /// Load a file and compute its fingerprint and predicted tags
+    fn analyze(&self, filepath: &str, content_hash: String) -> Result<AnalyzedSound> {
+        let audio = AudioData::load(filepath)?;
+        let filename = Path::new(filepath)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| filepath.to_string());
+
+        let fingerprinter = Fingerprinter::default();
+        let fingerprint = fingerprinter.extract(&audio)?;
+
+        // Instrument labels, plus genre/mood tags from the optional tagging model
+        let classifier_tags = InstrumentClassifier::default()
+            .classify(&fingerprint)
+            .into_iter()
+            .map(|p| (p.class.tag_name().to_string(), p.confidence))
+            .collect();
+        let model_tags = match self.tagger {
+            Some(tagger) => Some(tagger.tag(&audio.samples, audio.sample_rate)?),
+            None => None,
+        };
+
+        let waveform = WaveformPeaks::compute(&audio.samples, DEFAULT_WAVEFORM_BUCKETS);
+        let mut thumbnails = render_thumbnails(&audio, &waveform)?;
+        if let Ok(Some((mime_type, data))) = extract_artwork(filepath) {
+            if data.len() <= MAX_ARTWORK_BYTES {
+                thumbnails.push(Thumbnail { kind: ThumbnailKind::Artwork, mime_type, data });
+            }
+        }
+
+        Ok(AnalyzedSound {
+            sound: NewSound {
+                filepath: filepath.to_string(),
+                filename,
+                duration: audio.duration,
+                sample_rate: audio.sample_rate,
+                channels: audio.channels,
+                format: audio.metadata(filepath).format,
+                content_hash: Some(content_hash),
+                file_stamp: Some(file_stamp(filepath)?),
+                bpm: estimate_tempo(&audio.samples, audio.sample_rate),
+                musical_key: estimate_key(&fingerprint.chroma_mean).map(|k| k.name()),
+            },
+            fingerprint,
+            segments: fingerprinter.extract_segments(&audio)?,
+            classifier_tags,
+            model_tags,
+            waveform,
+            analyses: analyze_time_series(&audio.samples, audio.sample_rate),
+            thumbnails,
+        })
+    }
+
+    /// Write analyzed sounds in one transaction
+    fn store(&self, analyzed: &[AnalyzedSound]) -> Result<Vec<i64>> {
+        let sounds: Vec<NewSound> = analyzed.iter().map(|a| a.sound.clone()).collect();
+        self.db.write_batch(|batch| {
+            let ids = batch.add_sounds(&sounds)?;
+            store_analysis(batch, &ids, analyzed)?;
+            Ok(ids)
+        })
+    }
+}
+
+/// Write fingerprints and predicted tags for sounds already in the
+/// database (`ids` parallel to `analyzed`) as part of `batch`
+fn store_analysis(batch: &WriteBatch, ids: &[i64], analyzed: &[AnalyzedSound]) -> Result<()> {
+    let fingerprints: Vec<(i64, AudioFingerprint)> = ids
+        .iter()
+        .zip(analyzed)
+        .map(|(&id, a)| (id, a.fingerprint.clone()))
+        .collect();
+    batch.store_fingerprints(&fingerprints)?;
+
+    let segments: Vec<(i64, SegmentFingerprints)> = ids
+        .iter()
+        .zip(analyzed)
+        .map(|(&id, a)| (id, a.segments.clone()))
+        .collect();
+    batch.store_segment_fingerprints(&segments)?;
+
+    let waveforms: Vec<(i64, WaveformPeaks)> = ids
+        .iter()
+        .zip(analyzed)
+        .map(|(&id, a)| (id, a.waveform.clone()))
+        .collect();
+    batch.store_waveforms(&waveforms)?;
+
+    let analyses: Vec<(i64, Vec<AnalysisTrack>)> = ids
+        .iter()
+        .zip(analyzed)
+        .map(|(&id, a)| (id, a.analyses.clone()))
+        .collect();
+    batch.store_analyses(&analyses)?;
+
+    let thumbnails: Vec<(i64, Vec<Thumbnail>)> = ids
+        .iter()
+        .zip(analyzed)
+        .map(|(&id, a)| (id, a.thumbnails.clone()))
+        .collect();
+    batch.store_thumbnails(&thumbnails)?;
+
+    let classifier_tags: Vec<(i64, Vec<(String, f64)>)> = ids
+        .iter()
+        .zip(analyzed)
+        .map(|(&id, a)| (id, a.classifier_tags.clone()))
+        .collect();
+    batch.replace_tags(CLASSIFIER_TAG_SOURCE, &classifier_tags)?;
+
+    let model_tags: Vec<(i64, Vec<(String, f64)>)> = ids
+        .iter()
+        .zip(analyzed)
+        .filter_map(|(&id, a)| a.model_tags.clone().map(|tags| (id, tags)))
+        .collect();
+    batch.replace_tags(MODEL_TAG_SOURCE, &model_tags)?;
+    Ok(())
+}
+
+/// List the files below `root` that pass the extension and size filters,
+/// sorted by path
+pub fn scan_directory(root: &Path, options: &IndexOptions) -> Result<Vec<String>> {
+    let extensions: HashSet<String> = options.extensions.iter().map(|e| e.to_lowercase()).collect();
+    let mut files = Vec::new();
+
+    for entry in WalkDir::new(root).follow_links(options.follow_links) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                // Unreadable subdirectories shouldn't abort the whole scan
+                log::warn!("Skipping unreadable entry: {}", e);
+                continue;
+            }
+        };
+        if !entry.file_type().is_file() || !has_extension(entry.path(), &extensions) {
+            continue;
+        }
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        if size < options.min_file_size || options.max_file_size.is_some_and(|max| size > max) {
+            continue;
+        }
+        files.push(entry.path().to_path_buf());
+    }
+
+    files.sort();
+    Ok(files.into_iter().map(|p: PathBuf| p.to_string_lossy().to_string()).collect())
+}
+
+fn has_extension(path: &Path, extensions: &HashSet<String>) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| extensions.contains(&e.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::write_test_wav;
+
+    fn write_tone(path: &Path, freq: f32, seconds: f32) {
+        let samples: Vec<f32> = (0..(22050.0 * seconds) as usize)
+            .map(|i| (2.0 * std::f32::consts::PI * freq * i as f32 / 22050.0).sin() * 0.366)
+            .collect();
+        let name = path.file_name().unwrap().to_str().unwrap();
+        write_test_wav(path.parent().unwrap(), name, &samples, 22050);
+    }
+
+    #[test]
+    fn test_index_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("drums").join("kicks");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        write_tone(&dir.path().join("a.wav"), 220.0, 0.5);
+        write_tone(&nested.join("b.WAV"), 440.0, 0.5);
+        std::fs::copy(dir.path().join("a.wav"), nested.join("a copy.wav")).unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "not audio").unwrap();
+        std::fs::write(dir.path().join("broken.wav"), "not audio either").unwrap();
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let indexer = Indexer::new(&db, None);
+        let options = IndexOptions { batch_size: 2, ..IndexOptions::default() };
+
+        let mut reports = Vec::new();
+        let status = indexer
+            .index_directory(dir.path(), &options, |p| {
+                reports.push(p.clone());
+                true
+            })
+            .unwrap();
+
+        assert_eq!(status.total, 4);
+        assert_eq!((status.added, status.duplicates, status.failed), (2, 1, 1));
+        assert!(status.finished);
+        assert_eq!(db.count().unwrap(), 2);
+        assert!(reports.windows(2).all(|w| w[0].processed <= w[1].processed));
+
+        // A second run skips everything already indexed
+        let status = indexer.index_directory(dir.path(), &options, |_| true).unwrap();
+        assert_eq!(status.skipped, 2);
+        assert_eq!(status.added, 0);
+
+        // Cancelling stops before the first batch
+        let options = IndexOptions { skip_indexed: false, ..options };
+        let status = indexer.index_directory(dir.path(), &options, |_| false).unwrap();
+        assert!(status.cancelled);
+        assert_eq!(status.processed, 0);
+
+        // So does a cancelled token, which also stops batch adds
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let indexer = Indexer::new(&db, None).with_cancellation(cancel);
+        let status = indexer.index_directory(dir.path(), &options, |_| true).unwrap();
+        assert!(status.cancelled);
+        assert!(matches!(indexer.add_files(&["/kit/a.wav".to_string()]), Err(AudioPaletteError::Cancelled)));
+    }
+
+    #[test]
+    fn test_relink() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = dir.path().join("loop.wav");
+        write_tone(&original, 330.0, 0.5);
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let indexer = Indexer::new(&db, None);
+        let id = indexer.add_file(original.to_str().unwrap()).unwrap();
+        assert!(db.find_missing_files().unwrap().is_empty());
+
+        let moved = dir.path().join("moved").join("loop.wav");
+        std::fs::create_dir_all(moved.parent().unwrap()).unwrap();
+        std::fs::rename(&original, &moved).unwrap();
+        assert_eq!(db.find_missing_files().unwrap()[0].id, id);
+
+        // A different sound is rejected
+        let other = dir.path().join("noise.wav");
+        write_tone(&other, 3000.0, 0.5);
+        assert!(indexer.relink(id, other.to_str().unwrap(), RELINK_MIN_SIMILARITY).is_err());
+
+        let similarity = indexer.relink(id, moved.to_str().unwrap(), RELINK_MIN_SIMILARITY).unwrap();
+        assert_eq!(similarity, 100.0);
+        assert!(db.find_missing_files().unwrap().is_empty());
+        assert_eq!(db.get_sound(id).unwrap().unwrap().filepath, moved.to_str().unwrap());
+    }
+
+    #[test]
+    fn test_rescan() {
+        let dir = tempfile::tempdir().unwrap();
+        let edited = dir.path().join("edited.wav");
+        let kept = dir.path().join("kept.wav");
+        let deleted = dir.path().join("gone.wav");
+        write_tone(&edited, 220.0, 0.5);
+        write_tone(&kept, 440.0, 0.5);
+        write_tone(&deleted, 880.0, 0.5);
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let indexer = Indexer::new(&db, None);
+        let ids = indexer
+            .add_files(&[&edited, &kept, &deleted].map(|p| p.to_string_lossy().to_string()))
+            .unwrap();
+        let edited_id = ids[0].unwrap();
+        db.add_tag(edited_id, "keeper").unwrap();
+
+        let summary = indexer.rescan().unwrap();
+        assert_eq!(summary, RescanSummary { checked: 3, ..RescanSummary::default() });
+
+        // Cached peaks only apply to the contents they were computed from
+        assert!(db.get_waveform(edited_id, DEFAULT_WAVEFORM_BUCKETS).unwrap().is_some());
+        db.set_content_hash(edited_id, "stale").unwrap();
+        assert!(db.get_waveform(edited_id, DEFAULT_WAVEFORM_BUCKETS).unwrap().is_none());
+
+        write_tone(&edited, 220.0, 1.5);
+        std::fs::remove_file(&deleted).unwrap();
+        let summary = indexer.rescan().unwrap();
+        assert_eq!((summary.changed, summary.missing, summary.failed), (1, 1, 0));
+
+        let sound = db.get_sound(edited_id).unwrap().unwrap();
+        assert!((sound.duration - 1.5).abs() < 0.01);
+        assert!((db.get_fingerprint(edited_id).unwrap().unwrap().duration - 1.5).abs() < 0.01);
+        assert!(db.get_waveform(edited_id, DEFAULT_WAVEFORM_BUCKETS).unwrap().is_some());
+        assert!(db.get_sound_tags(edited_id).unwrap().iter().any(|t| t.name == "keeper"));
+
+        // Nothing left to do
+        assert_eq!(indexer.rescan().unwrap().changed, 0);
+    }
+
+    #[test]
+    fn test_rescan_outdated_fingerprint() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tone.wav");
+        write_tone(&path, 440.0, 0.5);
+        let palette = dir.path().join("palette.db");
+        let db = PaletteDatabase::open(&palette).unwrap();
+        let indexer = Indexer::new(&db, None);
+        let id = indexer.add_files(&[path.to_string_lossy().to_string()]).unwrap()[0].unwrap();
+
+        // Written by an older analysis: ignored until re-analyzed
+        let conn = rusqlite::Connection::open(&palette).unwrap();
+        conn.execute("UPDATE fingerprints SET feature_version = 0 WHERE sound_id = ?1", [id]).unwrap();
+        drop(conn);
+        assert!(db.get_fingerprint(id).unwrap().is_none());
+
+        let summary = indexer.rescan().unwrap();
+        assert_eq!(summary, RescanSummary { checked: 1, outdated: 1, ..RescanSummary::default() });
+        assert!(db.get_fingerprint(id).unwrap().is_some());
+        assert_eq!(indexer.rescan().unwrap().outdated, 0);
+
+        // Indexing the folder again re-analyzes outdated fingerprints too
+        let conn = rusqlite::Connection::open(&palette).unwrap();
+        conn.execute("UPDATE fingerprints SET feature_version = 0 WHERE sound_id = ?1", [id]).unwrap();
+        drop(conn);
+        assert_eq!(db.count_outdated_fingerprints().unwrap(), 1);
+        let status = indexer.index_directory(dir.path(), &IndexOptions::default(), |_| true).unwrap();
+        assert_eq!((status.skipped, status.reanalyzed, status.processed), (0, 1, 1));
+        assert_eq!(db.count_outdated_fingerprints().unwrap(), 0);
+        assert_eq!(db.count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let (a, b) = (dir.path().join("a.wav"), dir.path().join("b.wav"));
+        write_tone(&a, 220.0, 0.5);
+        write_tone(&b, 440.0, 0.5);
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let indexer = Indexer::new(&db, None);
+        let a_id = indexer.add_file(a.to_str().unwrap()).unwrap();
+        // Indexed without a hash, like libraries from before hashing
+        let b_id = db.add_sound(b.to_str().unwrap(), "b.wav", 0.5, 22050, 1, "wav").unwrap();
+        let copy = db.add_sound("/elsewhere/a.wav", "a.wav", 0.5, 22050, 1, "wav").unwrap();
+
+        let report = indexer.verify().unwrap();
+        assert_eq!((report.verified, report.hashed), (1, 1));
+        assert_eq!(report.missing, vec![copy]);
+        assert!(db.get_content_hash(b_id).unwrap().is_some());
+
+        write_tone(&a, 220.0, 0.6);
+        assert_eq!(indexer.verify().unwrap().modified, vec![a_id]);
+
+        // Identical files under two paths show up as a duplicate group
+        std::fs::copy(&b, dir.path().join("b copy.wav")).unwrap();
+        db.add_sound(dir.path().join("b copy.wav").to_str().unwrap(), "b copy.wav", 0.5, 22050, 1, "wav").unwrap();
+        indexer.verify().unwrap();
+        let groups = db.find_duplicate_groups().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0][0].id, b_id);
+    }
+}
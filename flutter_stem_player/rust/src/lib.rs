@@ -3,19 +3,29 @@
 //! Features:
 //! - Audio fingerprinting (MFCC, spectral features)
 //! - SQLite database for sound indexing
+//! - Recursive folder indexing with parallel analysis
 //! - Similarity search with segment matching
 //! - MIDI export with timestamps
+//! - Spectrogram image rendering
+//! - Optional ONNX genre/mood tagging (`onnx` feature)
+//! - Optional GPU batch similarity scoring (`gpu` feature)
 
 mod frb_generated;
 
 pub mod api;
 pub mod fingerprint;
 pub mod database;
+pub mod indexer;
 pub mod search;
 pub mod midi;
+pub mod render;
+pub mod tagging;
 pub(crate) mod audio;
 
 use serde::{Deserialize, Serialize};
+use std::cmp;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Library error types
@@ -35,10 +45,67 @@ pub enum AudioPaletteError {
 
     #[error("MIDI export failed: {0}")]
     MidiError(String),
+
+    #[error("Rendering failed: {0}")]
+    RenderError(String),
+
+    #[error("Tagging failed: {0}")]
+    TaggingError(String),
+
+    #[error("Schema migration failed: {0}")]
+    MigrationError(String),
+
+    #[error("Library archive error: {0}")]
+    ArchiveError(String),
+
+    #[error("Collection error: {0}")]
+    CollectionError(String),
+
+    #[error("Encryption error: {0}")]
+    EncryptionError(String),
+
+    #[error("Region error: {0}")]
+    RegionError(String),
+
+    #[error("Property error: {0}")]
+    PropertyError(String),
+
+    #[error("History error: {0}")]
+    HistoryError(String),
+
+    #[error("Library root error: {0}")]
+    LibraryRootError(String),
+
+    #[error("GPU scoring failed: {0}")]
+    GpuError(String),
+
+    #[error("Operation cancelled")]
+    Cancelled,
 }
 
 pub type Result<T> = std::result::Result<T, AudioPaletteError>;
 
+/// Stops a long-running search or index run from another thread
+///
+/// Clones share one flag. Work checks it between files or candidates, so
+/// a cancelled run stops after the ones already in progress.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
 /// Audio file metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioMetadata {
@@ -50,6 +117,20 @@ pub struct AudioMetadata {
     pub format: String,
 }
 
+/// Stereo image of a recording
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StereoImage {
+    /// Side energy share: 0 = mono, 0.5 = uncorrelated, 1 = fully out of phase
+    pub width: f64,
+    /// L/R correlation coefficient (-1 to 1)
+    pub correlation: f64,
+}
+
+impl StereoImage {
+    /// Image of a single-channel source: no side signal, identical channels
+    pub const MONO: StereoImage = StereoImage { width: 0.0, correlation: 1.0 };
+}
+
 /// Sound record from database
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SoundRecord {
@@ -61,6 +142,70 @@ pub struct SoundRecord {
     pub channels: u16,
     pub format: String,
     pub date_added: String,
+    pub title: String,
+    pub artist: String,
+    pub notes: String,
+    /// Where the recording came from (library, session, location...)
+    pub source: String,
+    /// User rating from 1 to 5 stars; 0 when unrated
+    #[serde(default)]
+    pub rating: u8,
+    /// Detected tempo; 0 when unknown
+    #[serde(default)]
+    pub bpm: f64,
+    /// Detected key, e.g. "A minor"; empty when unknown
+    #[serde(default)]
+    pub musical_key: String,
+}
+
+/// A sound to insert into the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewSound {
+    pub filepath: String,
+    pub filename: String,
+    pub duration: f64,
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub format: String,
+    /// Hash of the file contents, used to detect the same audio under another path
+    pub content_hash: Option<String>,
+    /// Size and modification time of the file when it was indexed
+    pub file_stamp: Option<FileStamp>,
+    pub bpm: Option<f64>,
+    /// Canonical key name (see `MusicalKey`)
+    pub musical_key: Option<String>,
+}
+
+/// File size and modification time, used to notice edits on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStamp {
+    pub size: i64,
+    /// Milliseconds since the Unix epoch
+    pub mtime: i64,
+}
+
+/// Partial update of a sound's editable metadata (`None` = keep current value)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SoundMetadataUpdate {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub notes: Option<String>,
+    pub source: Option<String>,
+}
+
+/// Tag attached to a sound, with confidence for predicted labels
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoundTag {
+    pub name: String,
+    pub confidence: f64,
+    pub source: String,
+}
+
+/// Tag with the number of sounds carrying it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagSummary {
+    pub name: String,
+    pub sound_count: i64,
 }
 
 /// Match result with time range
@@ -73,6 +218,31 @@ pub struct MatchResult {
     pub match_start: f64,
     pub match_end: f64,
     pub file_duration: f64,
+    /// Seconds between the positions `match_start` was chosen among: the
+    /// window hop, one sample once refined, 0 when the whole sound (or a
+    /// fixed range) was compared
+    #[serde(default)]
+    pub time_resolution: f64,
+}
+
+impl MatchResult {
+    /// Ranking order: higher score first, then lower sound id, then
+    /// earlier match, so equal scores come out the same way every run
+    pub fn rank_cmp(&self, other: &Self) -> cmp::Ordering {
+        descending_score(self.score, other.score)
+            .then(self.sound_id.cmp(&other.sound_id))
+            .then(self.match_start.total_cmp(&other.match_start))
+    }
+}
+
+/// Order of two scores when ranking: higher first, NaN after every number
+///
+/// `f64::total_cmp` alone would rank NaN above every real score.
+pub fn descending_score(a: f64, b: f64) -> cmp::Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => b.total_cmp(&a),
+        (a_nan, b_nan) => a_nan.cmp(&b_nan),
+    }
 }
 
 // FFI exports for Flutter/Dart
@@ -0,0 +1,164 @@
+//! Ableton Live Set export
+//!
+//! A .als file is gzipped XML. Each match becomes an unwarped audio clip
+//! on a track of its own, referencing the source file with the clip's
+//! start and end set to the match, and the clips are laid out one after
+//! another in the arrangement (a beat apart, in result order) so the set
+//! plays the results back like an audition reel. Live fills in whatever
+//! the minimal set leaves out when it opens it.
+
+use super::xml_escape;
+use crate::{MatchResult, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Beats of silence between consecutive clips
+const GAP_BEATS: f64 = 1.0;
+
+/// One audio track holding a clip of `m`, at `time` beats, `length` beats
+/// long
+fn audio_track(xml: &mut String, id: usize, m: &MatchResult, sample_rate: u32, time: f64, length: f64) {
+    let (start, end) = if m.match_end > m.match_start { (m.match_start, m.match_end) } else { (0.0, m.file_duration) };
+    let name = xml_escape(&m.filename);
+    let path = xml_escape(&m.filepath);
+    let _ = write!(
+        xml,
+        r#"			<AudioTrack Id="{id}">
+				<Name>
+					<EffectiveName Value="{name}" />
+					<UserName Value="{name}" />
+				</Name>
+				<DeviceChain>
+					<MainSequencer>
+						<Sample>
+							<ArrangerAutomation>
+								<Events>
+									<AudioClip Id="0" Time="{time}">
+										<CurrentStart Value="{time}" />
+										<CurrentEnd Value="{end_time}" />
+										<Loop>
+											<LoopStart Value="{start}" />
+											<LoopEnd Value="{end}" />
+											<StartRelative Value="0" />
+											<LoopOn Value="false" />
+											<OutMarker Value="{end}" />
+											<HiddenLoopStart Value="{start}" />
+											<HiddenLoopEnd Value="{end}" />
+										</Loop>
+										<Name Value="{score:.1}%" />
+										<SampleRef>
+											<FileRef>
+												<RelativePathType Value="0" />
+												<RelativePath Value="" />
+												<Path Value="{path}" />
+												<Type Value="1" />
+											</FileRef>
+"#,
+        end_time = time + length,
+        score = m.score,
+    );
+    if sample_rate > 0 {
+        let _ = write!(
+            xml,
+            r#"											<DefaultDuration Value="{}" />
+											<DefaultSampleRate Value="{sample_rate}" />
+"#,
+            (m.file_duration * sample_rate as f64).round() as u64,
+        );
+    }
+    xml.push_str(
+        r#"										</SampleRef>
+										<IsWarped Value="false" />
+									</AudioClip>
+								</Events>
+							</ArrangerAutomation>
+						</Sample>
+					</MainSequencer>
+				</DeviceChain>
+			</AudioTrack>
+"#,
+    );
+}
+
+/// Export match results as an Ableton Live Set at `tempo_bpm`, one audio
+/// track and clip per match
+///
+/// `sample_rates` lines up with `matches` (0 or missing when unknown) and
+/// lets Live skip rescanning the files for their length.
+pub fn export_matches_to_als<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    sample_rates: &[u32],
+    output_path: P,
+    tempo_bpm: u32,
+) -> Result<()> {
+    let beats_per_second = tempo_bpm.max(1) as f64 / 60.0;
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<Ableton MajorVersion="5" MinorVersion="11.0_433" SchemaChangeCount="3" Creator="Ableton Live 11.0" Revision="">
+	<LiveSet>
+		<Tracks>
+"#,
+    );
+    let mut time = 0.0;
+    for (i, m) in matches.iter().enumerate() {
+        let seconds = if m.match_end > m.match_start { m.match_end - m.match_start } else { m.file_duration };
+        let length = seconds * beats_per_second;
+        audio_track(&mut xml, i, m, sample_rates.get(i).copied().unwrap_or(0), time, length);
+        time += length + GAP_BEATS;
+    }
+    let _ = write!(
+        xml,
+        r#"		</Tracks>
+		<MasterTrack>
+			<DeviceChain>
+				<Mixer>
+					<Tempo>
+						<Manual Value="{tempo_bpm}" />
+					</Tempo>
+				</Mixer>
+			</DeviceChain>
+		</MasterTrack>
+	</LiveSet>
+</Ableton>
+"#,
+    );
+
+    let mut encoder = GzEncoder::new(File::create(output_path)?, Compression::default());
+    encoder.write_all(xml.as_bytes())?;
+    encoder.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_als_export() {
+        let hit = |filepath: &str, start: f64, end: f64| test_match(filepath, 87.5, start, end, 10.0);
+        let matches = vec![hit("/loops/drums & bass.wav", 2.0, 4.0), hit("/loops/pad.wav", 0.0, 0.0)];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("results.als");
+        export_matches_to_als(&matches, &[44100], &path, 120).unwrap();
+
+        let mut xml = String::new();
+        GzDecoder::new(File::open(&path).unwrap()).read_to_string(&mut xml).unwrap();
+        assert!(xml.contains(r#"<Path Value="/loops/drums &amp; bass.wav" />"#));
+        // Two seconds at 120 BPM are four beats; the second clip follows a
+        // beat later and plays the whole file
+        assert!(xml.contains(r#"<AudioClip Id="0" Time="0">"#));
+        assert!(xml.contains(r#"<LoopStart Value="2" />"#) && xml.contains(r#"<LoopEnd Value="4" />"#));
+        assert!(xml.contains(r#"<AudioClip Id="0" Time="5">"#));
+        assert!(xml.contains(r#"<CurrentEnd Value="25" />"#));
+        assert_eq!(xml.matches("<DefaultSampleRate").count(), 1);
+        assert!(xml.contains(r#"<DefaultDuration Value="441000" />"#));
+        assert!(xml.contains(r#"<Manual Value="120" />"#));
+    }
+}
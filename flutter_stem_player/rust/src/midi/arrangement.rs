@@ -0,0 +1,153 @@
+//! Arrangement MIDI: matches on the timeline of their source
+//!
+//! Where the plain MIDI export gives each match a track of its own, this
+//! lays every match found in one long file (a mix, a field recording) on
+//! a single timeline at its original time, with a track per label, e.g.
+//! the palette sound each occurrence was found for. The result is a map of
+//! where each sound occurs, ready to line up under the source in a DAW.
+
+use super::{meta_event, MidiExportConfig, TempoMap};
+use crate::{AudioPaletteError, MatchResult, Result};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Timing, Track, TrackEvent, TrackEventKind};
+use std::path::Path;
+
+/// Tracks left for labels next to the tempo track
+const MAX_LABELS: usize = 15;
+
+/// Export the matches found in the first match's source file as one MIDI
+/// arrangement at their original times, returning how many were placed
+///
+/// `labels` lines up with `matches` and picks each match's track (missing
+/// or empty labels share a track named after the file); tracks take their
+/// channel, program and note from `config.tracks` by first appearance, and
+/// notes on one track are cut short where the next begins. Matches from
+/// other files, and labels past the fifteenth, are left out.
+pub fn export_matches_to_arrangement<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    labels: &[String],
+    output_path: P,
+    config: &MidiExportConfig,
+) -> Result<usize> {
+    let Some(source) = matches.first() else {
+        return Err(AudioPaletteError::MidiError("No matches to export".to_string()));
+    };
+
+    // Track names in order of first appearance, with their matches
+    let mut lanes: Vec<(&str, Vec<&MatchResult>)> = Vec::new();
+    for (i, m) in matches.iter().enumerate() {
+        if m.filepath != source.filepath {
+            continue;
+        }
+        let label = labels.get(i).map(String::as_str).filter(|l| !l.is_empty()).unwrap_or(&source.filename);
+        match lanes.iter().position(|(name, _)| *name == label) {
+            Some(lane) => lanes[lane].1.push(m),
+            None if lanes.len() < MAX_LABELS => lanes.push((label, vec![m])),
+            None => {}
+        }
+    }
+
+    let tempo = TempoMap::new(&config.beats, config.tempo_bpm, config.ticks_per_beat);
+    let mut tracks: Vec<Track> = vec![tempo.track()];
+    let mut placed = 0;
+    for (i, (name, lane)) in lanes.iter_mut().enumerate() {
+        let assignment = config.tracks.get(i).cloned().unwrap_or_default();
+        let note = assignment.note.unwrap_or(config.base_note.saturating_add(i as u8)).min(127);
+        let channel = assignment.channel.unwrap_or(0).min(15).into();
+
+        let mut track = Track::new();
+        track.push(meta_event(MetaMessage::TrackName(name.as_bytes())));
+        track.push(meta_event(MetaMessage::Text(source.filepath.as_bytes())));
+        if let Some(program) = assignment.program {
+            let message = MidiMessage::ProgramChange { program: program.min(127).into() };
+            track.push(TrackEvent { delta: 0.into(), kind: TrackEventKind::Midi { channel, message } });
+        }
+
+        lane.sort_by(|a, b| a.match_start.total_cmp(&b.match_start));
+        let mut events: Vec<(u32, MidiMessage)> = Vec::new();
+        for (j, m) in lane.iter().enumerate() {
+            let start = tempo.ticks(m.match_start).round() as u32;
+            let mut end = tempo.ticks(m.match_end.max(m.match_start)).round() as u32;
+            if let Some(next) = lane.get(j + 1) {
+                end = end.min(tempo.ticks(next.match_start).round() as u32);
+            }
+            // Velocity based on score (40-127), as in the plain export
+            let vel = (40.0 + (m.score / 100.0) * 87.0).clamp(40.0, 127.0) as u8;
+            events.push((start, MidiMessage::NoteOn { key: note.into(), vel: vel.into() }));
+            events.push((end.max(start + 1), MidiMessage::NoteOff { key: note.into(), vel: 0.into() }));
+            placed += 1;
+        }
+        // A note-off sorts ahead of a note-on at the same tick
+        events.sort_by_key(|&(tick, message)| (tick, matches!(message, MidiMessage::NoteOn { .. })));
+        let mut last = 0;
+        for (tick, message) in events {
+            track.push(TrackEvent { delta: (tick - last).into(), kind: TrackEventKind::Midi { channel, message } });
+            last = tick;
+        }
+        track.push(meta_event(MetaMessage::EndOfTrack));
+        tracks.push(track);
+    }
+
+    let smf = Smf { header: Header::new(Format::Parallel, Timing::Metrical(config.ticks_per_beat.into())), tracks };
+    smf.save(output_path).map_err(|e| AudioPaletteError::MidiError(format!("Failed to write MIDI: {}", e)))?;
+    Ok(placed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_arrangement_export() {
+        let hit = |filepath: &str, start: f64, end: f64| test_match(filepath, 100.0, start, end, 180.0);
+        let matches = vec![
+            hit("/mixes/set.wav", 10.0, 10.5),
+            hit("/mixes/set.wav", 2.0, 2.5),
+            hit("/mixes/other.wav", 1.0, 2.0),
+            hit("/mixes/set.wav", 4.0, 6.0),
+            hit("/mixes/set.wav", 5.0, 5.5),
+            hit("/mixes/set.wav", 20.0, 21.0),
+        ];
+        let labels = ["kick.wav", "kick.wav", "kick.wav", "vox.wav", "vox.wav"].map(String::from);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("map.mid");
+        // 120 BPM at 480 ticks per beat: 960 ticks a second
+        let placed = export_matches_to_arrangement(&matches, &labels, &path, &MidiExportConfig::default()).unwrap();
+        assert_eq!(placed, 5);
+
+        let data = std::fs::read(&path).unwrap();
+        let smf = Smf::parse(&data).unwrap();
+        // Tempo, then the labelled tracks and one for the unlabelled match
+        assert_eq!(smf.tracks.len(), 4);
+        let notes = |track: &Track| -> Vec<(u32, bool, u8)> {
+            let mut tick = 0;
+            track
+                .iter()
+                .filter_map(|e| {
+                    tick += e.delta.as_int();
+                    match e.kind {
+                        TrackEventKind::Midi { message: MidiMessage::NoteOn { key, .. }, .. } => {
+                            Some((tick, true, key.as_int()))
+                        }
+                        TrackEventKind::Midi { message: MidiMessage::NoteOff { key, .. }, .. } => {
+                            Some((tick, false, key.as_int()))
+                        }
+                        _ => None,
+                    }
+                })
+                .collect()
+        };
+        assert!(smf.tracks[1].contains(&meta_event(MetaMessage::TrackName(b"kick.wav"))));
+        assert_eq!(
+            notes(&smf.tracks[1]),
+            vec![(1920, true, 60), (2400, false, 60), (9600, true, 60), (10080, false, 60)]
+        );
+        // The vox occurrence at 4 s is cut short by the one at 5 s
+        assert_eq!(
+            notes(&smf.tracks[2]),
+            vec![(3840, true, 61), (4800, false, 61), (4800, true, 61), (5280, false, 61)]
+        );
+        assert!(smf.tracks[3].contains(&meta_event(MetaMessage::TrackName(b"set.wav"))));
+        assert_eq!(notes(&smf.tracks[3]), vec![(19200, true, 62), (20160, false, 62)]);
+    }
+}
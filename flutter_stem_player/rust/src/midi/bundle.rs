@@ -0,0 +1,151 @@
+//! Zip bundle of matched audio
+//!
+//! For handing a result set over in one file: each match's range is
+//! rendered to a WAV of its own (24-bit, at the source's rate and channel
+//! count) under `audio/`, with `manifest.json` and `manifest.csv` listing
+//! where every clip came from. Matches whose file can't be decoded stay
+//! in the manifests, without audio.
+
+use super::csv::csv_field;
+use crate::audio::load_range_interleaved;
+use crate::{AudioPaletteError, MatchResult, Result};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Format name and version written at the top of `manifest.json`
+const BUNDLE_FORMAT: &str = "audio-palette-bundle";
+const BUNDLE_VERSION: u32 = 1;
+/// Full scale of 24-bit samples
+const FULL_SCALE_24: f32 = 8_388_607.0;
+
+fn archive_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::ArchiveError(e.to_string())
+}
+
+#[derive(Serialize)]
+struct BundleManifest<'a> {
+    format: &'static str,
+    version: u32,
+    clips: Vec<BundleClip<'a>>,
+}
+
+#[derive(Serialize)]
+struct BundleClip<'a> {
+    #[serde(flatten)]
+    result: &'a MatchResult,
+    /// Archive entry holding the clip, if its source could be decoded
+    audio: Option<String>,
+}
+
+/// A match's range as a 24-bit WAV, or None when its file can't be decoded
+fn render_clip(m: &MatchResult) -> Option<Vec<u8>> {
+    let end = if m.match_end > m.match_start { m.match_end } else { f64::INFINITY };
+    let start = if m.match_end > m.match_start { m.match_start } else { 0.0 };
+    let (samples, sample_rate, channels) = load_range_interleaved(&m.filepath, start, end).ok()?;
+    let spec = hound::WavSpec { channels, sample_rate, bits_per_sample: 24, sample_format: hound::SampleFormat::Int };
+    let mut wav = Cursor::new(Vec::new());
+    let mut writer = hound::WavWriter::new(&mut wav, spec).ok()?;
+    for sample in samples {
+        writer.write_sample((sample.clamp(-1.0, 1.0) * FULL_SCALE_24).round() as i32).ok()?;
+    }
+    writer.finalize().ok()?;
+    Some(wav.into_inner())
+}
+
+/// Export match results as a zip of WAV clips of their ranges (whole
+/// files for whole-sound matches) with JSON and CSV manifests, returning
+/// how many clips were written
+pub fn export_matches_bundle<P: AsRef<Path>>(matches: &[MatchResult], output_path: P) -> Result<usize> {
+    let mut zip = ZipWriter::new(File::create(output_path)?);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut clips = Vec::with_capacity(matches.len());
+    for (i, m) in matches.iter().enumerate() {
+        let audio = match render_clip(m) {
+            Some(wav) => {
+                let stem = Path::new(&m.filename).file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+                let entry = format!("audio/{:03}_{}.wav", i + 1, stem);
+                zip.start_file(entry.as_str(), deflated).map_err(archive_error)?;
+                zip.write_all(&wav)?;
+                Some(entry)
+            }
+            None => None,
+        };
+        clips.push(BundleClip { result: m, audio });
+    }
+
+    let mut csv = String::from("Clip,Filename,Filepath,Score,Match Start (s),Match End (s)\n");
+    for clip in &clips {
+        let m = clip.result;
+        csv.push_str(&format!(
+            "{},{},{},{:.1},{:.3},{:.3}\n",
+            csv_field(clip.audio.as_deref().unwrap_or_default(), ','),
+            csv_field(&m.filename, ','),
+            csv_field(&m.filepath, ','),
+            m.score,
+            m.match_start,
+            m.match_end,
+        ));
+    }
+    zip.start_file("manifest.csv", deflated).map_err(archive_error)?;
+    zip.write_all(csv.as_bytes())?;
+
+    let written = clips.iter().filter(|c| c.audio.is_some()).count();
+    let manifest = BundleManifest { format: BUNDLE_FORMAT, version: BUNDLE_VERSION, clips };
+    zip.start_file("manifest.json", deflated).map_err(archive_error)?;
+    serde_json::to_writer_pretty(&mut zip, &manifest).map_err(archive_error)?;
+    zip.finish().map_err(archive_error)?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+    use crate::audio::write_test_wav_channels;
+    use std::io::Read;
+
+    #[test]
+    fn test_matches_bundle() {
+        let dir = tempfile::tempdir().unwrap();
+        // Left rises, right stays silent
+        let samples: Vec<f32> = (0..8000).flat_map(|i| [(i * 2) as f32 / 32768.0, 0.0]).collect();
+        let source = write_test_wav_channels(dir.path(), "stereo, take 1.wav", &samples, 8000, 2);
+
+        let hit =
+            |filepath: &Path, start: f64, end: f64| test_match(&filepath.to_string_lossy(), 90.0, start, end, 1.0);
+        let gone = dir.path().join("gone.wav");
+        let matches = vec![hit(&source, 0.25, 0.5), hit(&source, 0.0, 0.0), hit(&gone, 0.0, 1.0)];
+        let path = dir.path().join("bundle.zip");
+        assert_eq!(export_matches_bundle(&matches, &path).unwrap(), 2);
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let mut wav = Vec::new();
+        archive.by_name("audio/001_stereo, take 1.wav").unwrap().read_to_end(&mut wav).unwrap();
+        let reader = hound::WavReader::new(wav.as_slice()).unwrap();
+        assert_eq!((reader.spec().channels, reader.spec().sample_rate, reader.spec().bits_per_sample), (2, 8000, 24));
+        assert_eq!(reader.duration(), 2000);
+        let first: Vec<i32> = reader.into_samples().take(2).map(|s| s.unwrap()).collect();
+        // Frame 2000 of the source: left 4000 in 16 bits, right silent
+        assert!((first[0] - 4000 * 256).abs() <= 256 && first[1] == 0);
+        // A whole-sound match is the whole file
+        let whole = archive.by_name("audio/002_stereo, take 1.wav").unwrap();
+        assert_eq!(hound::WavReader::new(whole).unwrap().duration(), 8000);
+
+        let mut csv = String::new();
+        archive.by_name("manifest.csv").unwrap().read_to_string(&mut csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[1].starts_with("\"audio/001_stereo, take 1.wav\",\"stereo, take 1.wav\","));
+        assert!(lines[3].starts_with(",gone.wav,"));
+
+        let manifest: serde_json::Value = serde_json::from_reader(archive.by_name("manifest.json").unwrap()).unwrap();
+        assert_eq!(manifest["format"], BUNDLE_FORMAT);
+        assert_eq!(manifest["clips"][0]["audio"], "audio/001_stereo, take 1.wav");
+        assert_eq!(manifest["clips"][0]["match_start"], 0.25);
+        assert!(manifest["clips"][2]["audio"].is_null());
+    }
+}
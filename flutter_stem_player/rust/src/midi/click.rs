@@ -0,0 +1,110 @@
+//! Click track export of a detected beat grid
+//!
+//! A click on every detected beat, accented on downbeats, over a tempo
+//! track that follows the beats. Imported next to a sampled loop, the
+//! DAW's grid then sits on the loop's own beats, and the click makes it
+//! easy to check by ear that the detection got them right.
+
+use super::drums::GM_PERCUSSION_CHANNEL;
+use super::{meta_event, TempoMap};
+use crate::{AudioPaletteError, Result};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Timing, Track, TrackEvent, TrackEventKind};
+use std::path::Path;
+
+/// General MIDI wood blocks: high on downbeats, low on other beats
+const GM_HI_WOOD_BLOCK: u8 = 76;
+const GM_LOW_WOOD_BLOCK: u8 = 77;
+const DOWNBEAT_VELOCITY: u8 = 127;
+const BEAT_VELOCITY: u8 = 80;
+
+/// Export `beats` (seconds, ascending) as a MIDI click track with a tempo
+/// map that follows them, returning how many clicks were written
+///
+/// Every `beats_per_bar`th beat from `first_downbeat` (an index into
+/// `beats`) is accented, and the tempo track carries a matching time
+/// signature. Bar lines fall where the lead-in before the first beat puts
+/// them, so they may not meet the accented beats.
+pub fn export_beat_grid_to_midi<P: AsRef<Path>>(
+    beats: &[f64],
+    output_path: P,
+    beats_per_bar: u8,
+    first_downbeat: usize,
+    ticks_per_beat: u16,
+) -> Result<usize> {
+    let beats: Vec<f64> = beats.iter().copied().filter(|&t| t >= 0.0).collect();
+    if beats.len() < 2 {
+        return Err(AudioPaletteError::MidiError("Need at least two beats for a click track".to_string()));
+    }
+    let beats_per_bar = beats_per_bar.max(1);
+    let tempo = TempoMap::new(&beats, 120, ticks_per_beat);
+
+    let mut tempo_track = tempo.track();
+    let signature = MetaMessage::TimeSignature(beats_per_bar, 2, 24, 8);
+    tempo_track.insert(0, meta_event(signature));
+
+    let mut track = Track::new();
+    track.push(meta_event(MetaMessage::TrackName(b"Click")));
+    let channel = GM_PERCUSSION_CHANNEL.into();
+    let length = (tempo.ticks_per_beat() / 4).max(1) as u32;
+    let mut last = 0;
+    for (i, &beat) in beats.iter().enumerate() {
+        let downbeat = i >= first_downbeat && (i - first_downbeat).is_multiple_of(beats_per_bar as usize);
+        let (key, vel) =
+            if downbeat { (GM_HI_WOOD_BLOCK, DOWNBEAT_VELOCITY) } else { (GM_LOW_WOOD_BLOCK, BEAT_VELOCITY) };
+        let tick = (tempo.ticks(beat).round() as u32).max(last);
+        let message = MidiMessage::NoteOn { key: key.into(), vel: vel.into() };
+        track.push(TrackEvent { delta: (tick - last).into(), kind: TrackEventKind::Midi { channel, message } });
+        let message = MidiMessage::NoteOff { key: key.into(), vel: 0.into() };
+        track.push(TrackEvent { delta: length.into(), kind: TrackEventKind::Midi { channel, message } });
+        last = tick + length;
+    }
+    track.push(meta_event(MetaMessage::EndOfTrack));
+
+    let header = Header::new(Format::Parallel, Timing::Metrical(tempo.ticks_per_beat().into()));
+    let smf = Smf { header, tracks: vec![tempo_track, track] };
+    smf.save(output_path).map_err(|e| AudioPaletteError::MidiError(format!("Failed to write MIDI: {}", e)))?;
+    Ok(beats.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beat_grid_click_track() {
+        // Half a second in, 120 BPM slowing to 100 BPM; the bar starts on the second beat
+        let beats = [0.5, 1.0, 1.5, 2.0, 2.5, 3.1, 3.7];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("click.mid");
+        assert_eq!(export_beat_grid_to_midi(&beats, &path, 3, 1, 480).unwrap(), 7);
+
+        let data = std::fs::read(&path).unwrap();
+        let smf = Smf::parse(&data).unwrap();
+        assert_eq!(smf.tracks.len(), 2);
+        assert!(smf.tracks[0].contains(&meta_event(MetaMessage::TimeSignature(3, 2, 24, 8))));
+        let mut tick = 0;
+        let clicks: Vec<(u32, u8, u8)> = smf.tracks[1]
+            .iter()
+            .filter_map(|e| {
+                tick += e.delta.as_int();
+                match e.kind {
+                    TrackEventKind::Midi { channel, message: MidiMessage::NoteOn { key, vel } } => {
+                        assert_eq!(channel.as_int(), GM_PERCUSSION_CHANNEL);
+                        Some((tick, key.as_int(), vel.as_int()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+        // One beat of lead-in, then a click on every MIDI beat
+        let expected: Vec<(u32, u8, u8)> = (0..7)
+            .map(|i| match i {
+                1 | 4 => (480 * (i + 1), GM_HI_WOOD_BLOCK, DOWNBEAT_VELOCITY),
+                _ => (480 * (i + 1), GM_LOW_WOOD_BLOCK, BEAT_VELOCITY),
+            })
+            .collect();
+        assert_eq!(clicks, expected);
+
+        assert!(export_beat_grid_to_midi(&[1.0], &path, 4, 0, 480).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! CSV export of match results
+//!
+//! Fields holding the delimiter, a quote or a line break are quoted, with
+//! quotes doubled, as RFC 4180 has it, so file paths with commas survive a
+//! round trip through a spreadsheet. Numbers are always written with a
+//! point and fixed decimals whatever the system locale; a spreadsheet set
+//! to a decimal comma reads them correctly with `;` as the delimiter.
+
+use crate::{MatchResult, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// A column of the CSV export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CsvColumn {
+    SoundId,
+    Filename,
+    Filepath,
+    Score,
+    MatchStart,
+    MatchEnd,
+    MatchDuration,
+    FileDuration,
+}
+
+impl CsvColumn {
+    /// Columns written by default, in order
+    pub const DEFAULT: [CsvColumn; 7] = [
+        CsvColumn::Filename,
+        CsvColumn::Filepath,
+        CsvColumn::Score,
+        CsvColumn::MatchStart,
+        CsvColumn::MatchEnd,
+        CsvColumn::MatchDuration,
+        CsvColumn::FileDuration,
+    ];
+
+    fn header(&self) -> &'static str {
+        match self {
+            CsvColumn::SoundId => "Sound ID",
+            CsvColumn::Filename => "Filename",
+            CsvColumn::Filepath => "Filepath",
+            CsvColumn::Score => "Score",
+            CsvColumn::MatchStart => "Match Start (s)",
+            CsvColumn::MatchEnd => "Match End (s)",
+            CsvColumn::MatchDuration => "Match Duration (s)",
+            CsvColumn::FileDuration => "File Duration (s)",
+        }
+    }
+
+    fn value(&self, m: &MatchResult) -> String {
+        match self {
+            CsvColumn::SoundId => m.sound_id.to_string(),
+            CsvColumn::Filename => m.filename.clone(),
+            CsvColumn::Filepath => m.filepath.clone(),
+            CsvColumn::Score => format!("{:.1}", m.score),
+            CsvColumn::MatchStart => format!("{:.3}", m.match_start),
+            CsvColumn::MatchEnd => format!("{:.3}", m.match_end),
+            CsvColumn::MatchDuration => format!("{:.3}", m.match_end - m.match_start),
+            CsvColumn::FileDuration => format!("{:.3}", m.file_duration),
+        }
+    }
+}
+
+/// CSV export options
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvExportOptions {
+    /// Field separator, e.g. ',', ';' or '\t'
+    pub delimiter: char,
+    /// Columns in order (empty for the defaults)
+    pub columns: Vec<CsvColumn>,
+    /// Write a header row of column names
+    pub header: bool,
+}
+
+impl Default for CsvExportOptions {
+    fn default() -> Self {
+        CsvExportOptions { delimiter: ',', columns: CsvColumn::DEFAULT.to_vec(), header: true }
+    }
+}
+
+/// `value` as a CSV field, quoted if it holds `delimiter`, a quote or a
+/// line break
+pub(super) fn csv_field(value: &str, delimiter: char) -> String {
+    if value.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Export match results to CSV, one row per match
+pub fn export_matches_to_csv<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    output_path: P,
+    options: &CsvExportOptions,
+) -> Result<()> {
+    let columns: &[CsvColumn] = if options.columns.is_empty() { &CsvColumn::DEFAULT } else { &options.columns };
+    let delimiter = options.delimiter.to_string();
+    let mut file = BufWriter::new(File::create(output_path)?);
+    let mut write_row = |fields: Vec<String>| {
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f, options.delimiter)).collect();
+        writeln!(file, "{}", fields.join(&delimiter))
+    };
+
+    if options.header {
+        write_row(columns.iter().map(|c| c.header().to_string()).collect())?;
+    }
+    for m in matches {
+        write_row(columns.iter().map(|c| c.value(m)).collect())?;
+    }
+    file.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_csv_export() {
+        let matches = vec![
+            MatchResult {
+                sound_id: 1,
+                filepath: "/test/sound.wav".to_string(),
+                filename: "sound.wav".to_string(),
+                score: 85.5,
+                match_start: 1.0,
+                match_end: 2.5,
+                file_duration: 5.0,
+                time_resolution: 0.0,
+            }
+        ];
+
+        let temp = NamedTempFile::new().unwrap();
+        export_matches_to_csv(&matches, temp.path(), &CsvExportOptions::default()).unwrap();
+
+        let content = std::fs::read_to_string(temp.path()).unwrap();
+        assert!(content.contains("sound.wav"));
+        assert!(content.contains("85.5"));
+    }
+
+    #[test]
+    fn test_csv_escaping_and_options() {
+        let hit = MatchResult {
+            sound_id: 7,
+            filepath: "/takes/Hit, \"loud\".wav".to_string(),
+            filename: "Hit, \"loud\".wav".to_string(),
+            score: 91.25,
+            match_start: 0.5,
+            match_end: 1.75,
+            file_duration: 3.0,
+            time_resolution: 0.0,
+        };
+        let temp = NamedTempFile::new().unwrap();
+        let read = || std::fs::read_to_string(temp.path()).unwrap();
+
+        export_matches_to_csv(std::slice::from_ref(&hit), temp.path(), &CsvExportOptions::default()).unwrap();
+        assert_eq!(
+            read(),
+            "Filename,Filepath,Score,Match Start (s),Match End (s),Match Duration (s),File Duration (s)\n\
+             \"Hit, \"\"loud\"\".wav\",\"/takes/Hit, \"\"loud\"\".wav\",91.2,0.500,1.750,1.250,3.000\n"
+        );
+
+        // Semicolons for decimal-comma locales, chosen columns, no header
+        let options = CsvExportOptions {
+            delimiter: ';',
+            columns: vec![CsvColumn::SoundId, CsvColumn::Filename, CsvColumn::MatchStart],
+            header: false,
+        };
+        export_matches_to_csv(&[hit], temp.path(), &options).unwrap();
+        assert_eq!(read(), "7;\"Hit, \"\"loud\"\".wav\";0.500\n");
+    }
+}
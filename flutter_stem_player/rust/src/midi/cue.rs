@@ -0,0 +1,106 @@
+//! Cue sheet export
+//!
+//! A cue sheet indexes positions in long audio files: each match becomes
+//! a track starting at the match, under a FILE entry for its source, so
+//! DJ software, players and burning tools can jump between the found
+//! moments of a recording. Positions are in CD frames (75 per second).
+
+use crate::{MatchResult, Result};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Most tracks a cue sheet can hold
+const MAX_TRACKS: usize = 99;
+
+/// Seconds as a cue position, MM:SS:FF
+fn cue_time(seconds: f64) -> String {
+    let frames = (seconds.max(0.0) * 75.0).round() as u64;
+    format!("{:02}:{:02}:{:02}", frames / (75 * 60), frames / 75 % 60, frames % 75)
+}
+
+/// `text` as a quoted cue string (which has no escape for quotes)
+fn cue_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "'"))
+}
+
+/// Cue file type of a source, from its extension
+fn file_type(path: &str) -> &'static str {
+    let extension = Path::new(path).extension().map(|e| e.to_string_lossy().to_lowercase());
+    match extension.as_deref() {
+        Some("mp3") => "MP3",
+        Some("aif" | "aiff") => "AIFF",
+        _ => "WAVE",
+    }
+}
+
+/// Export match results as a cue sheet titled `title`, one track per match
+/// (up to 99) at its start, grouped by source file and in time order
+/// within each
+pub fn export_matches_to_cue<P: AsRef<Path>>(matches: &[MatchResult], title: &str, output_path: P) -> Result<()> {
+    let mut sorted: Vec<&MatchResult> = matches.iter().take(MAX_TRACKS).collect();
+    // Files in the order of their first (best) match
+    let first_seen = |path: &str| matches.iter().position(|m| m.filepath == path);
+    sorted.sort_by(|a, b| {
+        first_seen(&a.filepath).cmp(&first_seen(&b.filepath)).then(a.match_start.total_cmp(&b.match_start))
+    });
+
+    let mut file = File::create(output_path)?;
+    writeln!(file, "REM GENERATOR \"Audio Palette\"")?;
+    writeln!(file, "TITLE {}", cue_string(title))?;
+    let mut current_file: Option<&str> = None;
+    for (i, m) in sorted.iter().enumerate() {
+        if current_file != Some(m.filepath.as_str()) {
+            writeln!(file, "FILE {} {}", cue_string(&m.filepath), file_type(&m.filepath))?;
+            current_file = Some(&m.filepath);
+        }
+        writeln!(file, "  TRACK {:02} AUDIO", i + 1)?;
+        writeln!(file, "    TITLE {}", cue_string(&format!("{} ({:.1}%)", m.filename, m.score)))?;
+        writeln!(file, "    REM SCORE {:.1}", m.score)?;
+        writeln!(file, "    INDEX 01 {}", cue_time(m.match_start))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_cue_export() {
+        let hit = |filepath: &str, score: f64, start: f64| test_match(filepath, score, start, start + 2.0, 3600.0);
+        let matches = vec![
+            hit("/archive/side a.wav", 95.0, 754.2),
+            hit("/archive/mix.mp3", 90.0, 10.0),
+            hit("/archive/side a.wav", 85.0, 61.0),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("matches.cue");
+        export_matches_to_cue(&matches, "Found \"hits\"", &path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "REM GENERATOR \"Audio Palette\"",
+                "TITLE \"Found 'hits'\"",
+                "FILE \"/archive/side a.wav\" WAVE",
+                "  TRACK 01 AUDIO",
+                "    TITLE \"side a.wav (85.0%)\"",
+                "    REM SCORE 85.0",
+                "    INDEX 01 01:01:00",
+                "  TRACK 02 AUDIO",
+                "    TITLE \"side a.wav (95.0%)\"",
+                "    REM SCORE 95.0",
+                "    INDEX 01 12:34:15",
+                "FILE \"/archive/mix.mp3\" MP3",
+                "  TRACK 03 AUDIO",
+                "    TITLE \"mix.mp3 (90.0%)\"",
+                "    REM SCORE 90.0",
+                "    INDEX 01 00:10:00",
+            ]
+        );
+    }
+}
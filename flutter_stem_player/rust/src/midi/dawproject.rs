@@ -0,0 +1,140 @@
+//! DAWproject export
+//!
+//! DAWproject is an open interchange format (Bitwig, Studio One and
+//! others): a zip holding `project.xml` and `metadata.xml`. Each match
+//! becomes an audio clip on a track of its own, playing its segment of
+//! the source file (referenced in place, not copied), with the clips
+//! laid out one after another as in the Live Set export. Times are in
+//! seconds throughout, so nothing depends on the tempo being right.
+
+use super::xml_escape;
+use crate::{AudioPaletteError, MatchResult, Result};
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Seconds of silence between consecutive clips
+const GAP_SECONDS: f64 = 1.0;
+
+fn archive_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::ArchiveError(e.to_string())
+}
+
+/// Export match results as a DAWproject file at `tempo_bpm`, one audio
+/// track and clip per match
+///
+/// `formats` lines up with `matches`: each source's sample rate and
+/// channel count (0 when unknown; stereo and 44.1 kHz are assumed).
+pub fn export_matches_to_dawproject<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    formats: &[(u32, u16)],
+    output_path: P,
+    tempo_bpm: u32,
+) -> Result<()> {
+    let mut structure = String::new();
+    let mut lanes = String::new();
+    let mut time = 0.0;
+    for (i, m) in matches.iter().enumerate() {
+        let (start, end) =
+            if m.match_end > m.match_start { (m.match_start, m.match_end) } else { (0.0, m.file_duration) };
+        let (sample_rate, channels) = formats.get(i).copied().unwrap_or((0, 0));
+        let sample_rate = if sample_rate > 0 { sample_rate } else { 44100 };
+        let channels = if channels > 0 { channels } else { 2 };
+        let name = xml_escape(&m.filename);
+        let _ = write!(
+            structure,
+            r#"    <Track contentType="audio" loaded="true" id="track{i}" name="{name}">
+      <Channel audioChannels="{channels}" destination="master" role="regular" id="channel{i}">
+        <Volume max="2.0" min="0.0" unit="linear" value="1.0"/>
+      </Channel>
+    </Track>
+"#,
+        );
+        let _ = write!(
+            lanes,
+            r#"      <Lanes track="track{i}">
+        <Clips>
+          <Clip time="{time}" duration="{duration}" playStart="{start}" name="{name} ({score:.1}%)">
+            <Audio channels="{channels}" duration="{file_duration}" sampleRate="{sample_rate}">
+              <File path="{path}" external="true"/>
+            </Audio>
+          </Clip>
+        </Clips>
+      </Lanes>
+"#,
+            duration = end - start,
+            score = m.score,
+            file_duration = m.file_duration,
+            path = xml_escape(&m.filepath),
+        );
+        time += end - start + GAP_SECONDS;
+    }
+
+    let project = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<Project version="1.0">
+  <Application name="Audio Palette" version="{version}"/>
+  <Transport>
+    <Tempo max="666.0" min="20.0" unit="bpm" value="{tempo_bpm}" id="tempo" name="Tempo"/>
+    <TimeSignature denominator="4" numerator="4" id="signature"/>
+  </Transport>
+  <Structure>
+{structure}    <Channel audioChannels="2" role="master" id="master" name="Master">
+      <Volume max="2.0" min="0.0" unit="linear" value="1.0"/>
+    </Channel>
+  </Structure>
+  <Arrangement id="arrangement">
+    <Lanes timeUnit="seconds" id="lanes">
+{lanes}    </Lanes>
+  </Arrangement>
+</Project>
+"#,
+        version = env!("CARGO_PKG_VERSION"),
+        tempo_bpm = tempo_bpm.max(1),
+    );
+    let metadata = r#"<?xml version="1.0" encoding="UTF-8"?>
+<MetaData>
+  <Title>Audio Palette matches</Title>
+</MetaData>
+"#;
+
+    let mut zip = ZipWriter::new(File::create(output_path)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, content) in [("project.xml", project.as_str()), ("metadata.xml", metadata)] {
+        zip.start_file(name, options).map_err(archive_error)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    zip.finish().map_err(archive_error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+    use std::io::Read;
+
+    #[test]
+    fn test_dawproject_export() {
+        let hit = |filepath: &str, start: f64, end: f64| test_match(filepath, 90.0, start, end, 8.0);
+        let matches = vec![hit("/fx/rain <heavy>.wav", 1.5, 3.0), hit("/fx/wind.wav", 0.0, 0.0)];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("results.dawproject");
+        export_matches_to_dawproject(&matches, &[(48000, 1)], &path, 100).unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        assert!(archive.by_name("metadata.xml").is_ok());
+        let mut xml = String::new();
+        archive.by_name("project.xml").unwrap().read_to_string(&mut xml).unwrap();
+        assert!(xml.contains(r#"unit="bpm" value="100""#));
+        assert!(xml.contains(r#"<Clip time="0" duration="1.5" playStart="1.5" name="rain &lt;heavy&gt;.wav (90.0%)">"#));
+        assert!(xml.contains(r#"<Audio channels="1" duration="8" sampleRate="48000">"#));
+        assert!(xml.contains(r#"<File path="/fx/rain &lt;heavy&gt;.wav" external="true"/>"#));
+        // The whole-sound match follows a second later, at assumed defaults
+        assert!(xml.contains(r#"<Clip time="2.5" duration="8" playStart="0" name="wind.wav (90.0%)">"#));
+        assert!(xml.contains(r#"<Audio channels="2" duration="8" sampleRate="44100">"#));
+    }
+}
@@ -0,0 +1,182 @@
+//! Onsets as General MIDI drum hits
+//!
+//! A sampled break becomes a groove template: every detected onset is a
+//! hit, classified as kick, snare or hi-hat by the instrument classifier
+//! on the audio up to the next onset, and written as the matching General
+//! MIDI percussion note with a velocity from the hit's peak level.
+
+use super::transcribe::{velocity, write_note_tracks, NoteTrack, TranscribedNote};
+use super::TempoMap;
+use crate::fingerprint::{Fingerprinter, InstrumentClass, InstrumentClassifier};
+use crate::{AudioPaletteError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Channel General MIDI plays as drums (10, counting from 1)
+pub const GM_PERCUSSION_CHANNEL: u8 = 9;
+/// General MIDI percussion notes
+pub const GM_KICK: u8 = 36;
+pub const GM_SNARE: u8 = 38;
+pub const GM_CLOSED_HAT: u8 = 42;
+
+/// Most audio after an onset classified as its hit
+const MAX_HIT_SECONDS: f64 = 0.25;
+/// Attack over which a hit's level is measured
+const ATTACK_SECONDS: f64 = 0.03;
+/// Length of each written drum note
+const DRUM_NOTE_SECONDS: f64 = 0.05;
+
+/// A classified hit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrumHit {
+    /// Seconds from the start of the sound
+    pub time: f64,
+    pub note: u8,
+    pub velocity: u8,
+}
+
+impl DrumHit {
+    /// As a short note, for writing
+    fn to_note(&self) -> TranscribedNote {
+        TranscribedNote {
+            start_time: self.time,
+            end_time: self.time + DRUM_NOTE_SECONDS,
+            note: self.note,
+            velocity: self.velocity,
+        }
+    }
+}
+
+/// General MIDI percussion note of a drum class (None for the rest)
+pub(super) fn gm_drum_note(class: InstrumentClass) -> Option<u8> {
+    match class {
+        InstrumentClass::Kick => Some(GM_KICK),
+        InstrumentClass::Snare => Some(GM_SNARE),
+        InstrumentClass::HiHat => Some(GM_CLOSED_HAT),
+        _ => None,
+    }
+}
+
+/// General MIDI note of the likeliest drum class for a hit
+fn drum_note(classifier: &InstrumentClassifier, fingerprinter: &Fingerprinter, hit: &[f32], rate: u32) -> u8 {
+    let Ok(fp) = fingerprinter.extract_from_samples(hit, rate) else {
+        return GM_SNARE;
+    };
+    classifier.predict_all(&fp).into_iter().find_map(|p| gm_drum_note(p.class)).unwrap_or(GM_SNARE)
+}
+
+/// One drum hit per onset (seconds, ascending) of mono audio
+pub fn detect_drum_hits(samples: &[f32], sample_rate: u32, onsets: &[f64]) -> Vec<DrumHit> {
+    let (classifier, fingerprinter) = (InstrumentClassifier::default(), Fingerprinter::default());
+    let sample = |t: f64| ((t.max(0.0) * sample_rate as f64) as usize).min(samples.len());
+    onsets
+        .iter()
+        .enumerate()
+        .filter(|&(_, &time)| sample(time) < samples.len())
+        .map(|(i, &time)| {
+            let next = onsets.get(i + 1).map_or(f64::INFINITY, |&next| next);
+            let hit = &samples[sample(time)..sample(next.min(time + MAX_HIT_SECONDS))];
+            let attack = &hit[..sample(ATTACK_SECONDS).min(hit.len())];
+            DrumHit {
+                time,
+                note: drum_note(&classifier, &fingerprinter, hit, sample_rate),
+                velocity: velocity(attack),
+            }
+        })
+        .collect()
+}
+
+/// Write each named list of hits as a track on the General MIDI
+/// percussion channel at `tempo_bpm` (or following `beats`, when there are
+/// two or more), snapped to `quantize_division` steps per beat (0 leaves
+/// the timing as detected)
+pub fn export_drum_hits_to_midi<P: AsRef<Path>>(
+    tracks: &[(String, Vec<DrumHit>)],
+    output_path: P,
+    tempo_bpm: u32,
+    beats: &[f64],
+    ticks_per_beat: u16,
+    quantize_division: u16,
+) -> Result<()> {
+    if tracks.iter().all(|(_, hits)| hits.is_empty()) {
+        return Err(AudioPaletteError::MidiError("No drum hits to export".to_string()));
+    }
+    let notes: Vec<Vec<TranscribedNote>> =
+        tracks.iter().map(|(_, hits)| hits.iter().map(DrumHit::to_note).collect()).collect();
+    let note_tracks: Vec<NoteTrack> = tracks
+        .iter()
+        .zip(&notes)
+        .map(|((name, _), notes)| NoteTrack { name, channel: GM_PERCUSSION_CHANNEL, notes })
+        .collect();
+    let tempo = TempoMap::new(beats, tempo_bpm, ticks_per_beat);
+    write_note_tracks(&note_tracks, output_path, &tempo, quantize_division)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::detect_onsets;
+    use crate::midi::read_midi_regions;
+
+    #[test]
+    fn test_detect_drum_hits() {
+        // Kick, hat, snare, hat: half a second each
+        let sr = 22050;
+        let mut noise = 0x2545_f491_4f6c_dd1du64;
+        let mut white = move || {
+            noise ^= noise << 13;
+            noise ^= noise >> 7;
+            noise ^= noise << 17;
+            (noise >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+        // A little silence first: an onset needs something before it
+        let mut samples = vec![0.0; sr / 4];
+        let (mut previous, mut smoothed) = (0.0, 0.0);
+        for hit in ["kick", "hat", "snare", "hat"] {
+            for i in 0..sr / 2 {
+                let t = i as f64 / sr as f64;
+                let n = white();
+                smoothed = 0.7 * smoothed + 0.3 * n;
+                let value = match hit {
+                    // Pitch sweeping down from 110 Hz to 50 Hz
+                    "kick" => {
+                        let freq = 50.0 + 60.0 * (-t * 30.0).exp();
+                        0.9 * (-t * 20.0).exp() * (std::f64::consts::TAU * freq * t).sin()
+                    }
+                    // First difference of white noise is mostly highs
+                    "hat" => 0.4 * (-t * 60.0).exp() * (n - previous),
+                    // Low-passed noise over a drum body
+                    _ => 0.5 * (-t * 25.0).exp() * (smoothed + 0.5 * (std::f64::consts::TAU * 190.0 * t).sin()),
+                };
+                previous = n;
+                samples.push(value as f32);
+            }
+        }
+        let onsets = detect_onsets(&samples, sr as u32);
+        let hits = detect_drum_hits(&samples, sr as u32, &onsets);
+        let notes: Vec<u8> = hits.iter().map(|h| h.note).collect();
+        assert_eq!(notes, vec![GM_KICK, GM_CLOSED_HAT, GM_SNARE, GM_CLOSED_HAT]);
+        for (i, hit) in hits.iter().enumerate() {
+            assert!((hit.time - 0.25 - 0.5 * i as f64).abs() < 0.05, "{:?}", hit);
+        }
+        assert!(hits[0].velocity > hits[1].velocity);
+
+        // On the percussion channel, a sixteenth at 120 BPM being 0.125 s
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("groove.mid");
+        export_drum_hits_to_midi(&[("break".to_string(), hits)], &path, 120, &[], 480, 4).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let smf = midly::Smf::parse(&bytes).unwrap();
+        let channels: Vec<u8> = smf.tracks[1]
+            .iter()
+            .filter_map(|e| match e.kind {
+                midly::TrackEventKind::Midi { channel, .. } => Some(channel.as_int()),
+                _ => None,
+            })
+            .collect();
+        assert!(channels.len() == 8 && channels.iter().all(|&c| c == GM_PERCUSSION_CHANNEL));
+        let starts: Vec<f64> = read_midi_regions(&path).unwrap().iter().map(|r| r.start_time).collect();
+        assert_eq!(starts, vec![0.25, 0.75, 1.25, 1.75]);
+        assert!(export_drum_hits_to_midi(&[], &path, 120, &[], 480, 4).is_err());
+    }
+}
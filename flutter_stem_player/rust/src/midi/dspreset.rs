@@ -0,0 +1,112 @@
+//! DecentSampler preset export
+//!
+//! A .dspreset is XML that DecentSampler (desktop, iOS and the plugins
+//! built on it) loads directly. The keyboard is laid out as in the SFZ
+//! export, each match on its own keys playing its segment of the source
+//! file through the `start` and `end` attributes, with one amplitude
+//! envelope for the whole instrument.
+
+use super::sfz::{key_range, sample_path, segment_frames};
+use super::xml_escape;
+use crate::{MatchResult, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Amplitude envelope of an exported instrument, times in seconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SamplerEnvelope {
+    pub attack: f64,
+    pub decay: f64,
+    /// Level held while the key is down, 0 to 1
+    pub sustain: f64,
+    pub release: f64,
+}
+
+impl Default for SamplerEnvelope {
+    fn default() -> Self {
+        SamplerEnvelope { attack: 0.001, decay: 0.0, sustain: 1.0, release: 0.05 }
+    }
+}
+
+/// Export match results as a DecentSampler preset, returning how many fit
+/// on the keyboard
+///
+/// Each match spans `keys_per_sample` keys from `lowest_note` up, in
+/// result order, played at its original pitch on its lowest key.
+/// `sample_rates` lines up with `matches` (0 or missing when unknown) and
+/// turns segment bounds into frames.
+pub fn export_matches_to_dspreset<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    sample_rates: &[u32],
+    output_path: P,
+    lowest_note: u8,
+    keys_per_sample: u8,
+    envelope: &SamplerEnvelope,
+) -> Result<usize> {
+    let output_path = std::path::absolute(output_path.as_ref())?;
+    let preset_dir = output_path.parent().unwrap_or(Path::new(""));
+
+    let mut samples = String::new();
+    let mut mapped = 0;
+    for (i, m) in matches.iter().enumerate() {
+        let Some((lokey, hikey)) = key_range(i, lowest_note, keys_per_sample) else {
+            break;
+        };
+        let path = xml_escape(&sample_path(&m.filepath, preset_dir));
+        let _ = write!(
+            samples,
+            r#"      <sample path="{path}" rootNote="{lokey}" loNote="{lokey}" hiNote="{hikey}""#
+        );
+        if let Some((start, end)) = segment_frames(m, sample_rates.get(i).copied().unwrap_or(0)) {
+            let _ = write!(samples, r#" start="{start}" end="{end}""#);
+        }
+        let _ = writeln!(samples, "/>");
+        mapped += 1;
+    }
+
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Audio Palette instrument -->
+<DecentSampler minVersion="1.0.0">
+  <groups attack="{attack}" decay="{decay}" sustain="{sustain}" release="{release}">
+    <group>
+{samples}    </group>
+  </groups>
+</DecentSampler>
+"#,
+        attack = envelope.attack.max(0.0),
+        decay = envelope.decay.max(0.0),
+        sustain = envelope.sustain.clamp(0.0, 1.0),
+        release = envelope.release.max(0.0),
+    );
+    std::fs::write(&output_path, xml)?;
+    Ok(mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_dspreset_export() {
+        let hit = |filepath: &str, start: f64, end: f64| test_match(filepath, 75.0, start, end, 2.0);
+        let dir = tempfile::tempdir().unwrap();
+        let samples = dir.path().join("samples");
+        let matches = vec![
+            hit(&samples.join("pluck & pick.wav").to_string_lossy(), 0.25, 0.5),
+            hit(&samples.join("pad.wav").to_string_lossy(), 0.0, 0.0),
+        ];
+        let path = dir.path().join("found.dspreset");
+        let envelope = SamplerEnvelope { release: 0.4, ..Default::default() };
+        assert_eq!(export_matches_to_dspreset(&matches, &[22050], &path, 60, 12, &envelope).unwrap(), 2);
+
+        let xml = std::fs::read_to_string(&path).unwrap();
+        assert!(xml.contains(r#"<groups attack="0.001" decay="0" sustain="1" release="0.4">"#));
+        assert!(xml.contains(
+            r#"<sample path="samples/pluck &amp; pick.wav" rootNote="60" loNote="60" hiNote="71" start="5513" end="11024"/>"#
+        ));
+        assert!(xml.contains(r#"<sample path="samples/pad.wav" rootNote="72" loNote="72" hiNote="83"/>"#));
+    }
+}
@@ -0,0 +1,99 @@
+//! CMX3600 edit decision list export
+//!
+//! Each match becomes an audio cut: source in/out at the match's place in
+//! its file, recorded back to back from 01:00:00:00 on the timeline. The
+//! reel is `AX` (an auxiliary source), with the clip name and source path
+//! as comments, which is how Avid and Resolve find the media when
+//! conforming. Timecode is non-drop at a whole frame rate.
+
+use crate::{MatchResult, Result};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Where the record timeline starts, in hours
+const RECORD_START_HOURS: u64 = 1;
+/// Event numbers have three digits
+const MAX_EVENTS: usize = 999;
+
+/// Frames as HH:MM:SS:FF at `fps`
+fn timecode(frames: u64, fps: u64) -> String {
+    let seconds = frames / fps;
+    format!("{:02}:{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60, frames % fps)
+}
+
+/// Export match results as a CMX3600 EDL titled `title` at `fps` frames
+/// per second, one audio event per match (up to 999)
+pub fn export_matches_to_edl<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    title: &str,
+    fps: u32,
+    output_path: P,
+) -> Result<()> {
+    let fps = fps.max(1) as u64;
+    let to_frames = |seconds: f64| (seconds.max(0.0) * fps as f64).round() as u64;
+
+    let mut file = File::create(output_path)?;
+    // Titles are one line of at most 70 characters
+    let title: String = title.chars().filter(|c| !c.is_control()).take(70).collect();
+    writeln!(file, "TITLE: {}", title)?;
+    writeln!(file, "FCM: NON-DROP FRAME")?;
+    writeln!(file)?;
+
+    let mut record = RECORD_START_HOURS * 3600 * fps;
+    for (i, m) in matches.iter().take(MAX_EVENTS).enumerate() {
+        let (start, end) =
+            if m.match_end > m.match_start { (m.match_start, m.match_end) } else { (0.0, m.file_duration) };
+        let (source_in, source_out) = (to_frames(start), to_frames(end).max(to_frames(start) + 1));
+        let record_out = record + (source_out - source_in);
+        writeln!(
+            file,
+            "{:03}  AX       A     C        {} {} {} {}",
+            i + 1,
+            timecode(source_in, fps),
+            timecode(source_out, fps),
+            timecode(record, fps),
+            timecode(record_out, fps)
+        )?;
+        writeln!(file, "* FROM CLIP NAME: {}", m.filename)?;
+        writeln!(file, "* SOURCE FILE: {}", m.filepath)?;
+        writeln!(file, "* COMMENT: MATCH {:.1}%", m.score)?;
+        writeln!(file)?;
+        record = record_out;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_edl_export() {
+        let hit =
+            |filename: &str, start: f64, end: f64| test_match(&format!("/foley/{filename}"), 91.0, start, end, 4.0);
+        let matches = vec![hit("door.wav", 62.4, 64.0), hit("steps.wav", 0.0, 0.0)];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("matches.edl");
+        export_matches_to_edl(&matches, "Found foley", 25, &path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(
+            &lines[..9],
+            [
+                "TITLE: Found foley",
+                "FCM: NON-DROP FRAME",
+                "",
+                "001  AX       A     C        00:01:02:10 00:01:04:00 01:00:00:00 01:00:01:15",
+                "* FROM CLIP NAME: door.wav",
+                "* SOURCE FILE: /foley/door.wav",
+                "* COMMENT: MATCH 91.0%",
+                "",
+                // A whole-sound match cuts in the whole file
+                "002  AX       A     C        00:00:00:00 00:00:04:00 01:00:01:15 01:00:05:15",
+            ]
+        );
+    }
+}
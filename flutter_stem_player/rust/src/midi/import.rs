@@ -0,0 +1,189 @@
+//! MIDI clips as query regions
+//!
+//! A DAW user marks the interesting moments of a reference recording by
+//! drawing notes over them. Each note becomes a time region: note on to
+//! note off, converted to seconds through the file's tempo map, so the
+//! regions line up with the audio the clip was drawn against.
+
+use crate::{AudioPaletteError, Result};
+use midly::{MetaMessage, MidiMessage, Smf, Timing, TrackEventKind};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Tempo until the first tempo event (120 BPM)
+const DEFAULT_TEMPO_US: u32 = 500_000;
+
+/// One note of a MIDI clip, as a time range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MidiRegion {
+    /// Bounds in seconds from the start of the clip
+    pub start_time: f64,
+    pub end_time: f64,
+    pub note: u8,
+    pub velocity: u8,
+    /// Index of the track the note is on, and its name (empty if unnamed)
+    pub track: usize,
+    pub track_name: String,
+}
+
+/// Seconds at each tempo change, for converting ticks to time
+struct TempoMap {
+    /// (tick, seconds at that tick, seconds per tick from there), by tick
+    segments: Vec<(u64, f64, f64)>,
+}
+
+impl TempoMap {
+    /// Tempo changes from every track (type 1 files keep them in the
+    /// first, but some writers scatter them)
+    fn new(smf: &Smf) -> Self {
+        let ticks_per_beat = match smf.header.timing {
+            Timing::Metrical(ticks) => ticks.as_int().max(1) as f64,
+            Timing::Timecode(fps, subframes) => {
+                // Absolute time: one tick is one subframe whatever the tempo
+                let seconds_per_tick = 1.0 / (fps.as_f32() as f64 * subframes.max(1) as f64);
+                return TempoMap { segments: vec![(0, 0.0, seconds_per_tick)] };
+            }
+        };
+        let mut changes: Vec<(u64, u32)> = smf
+            .tracks
+            .iter()
+            .flat_map(|track| {
+                let mut tick = 0u64;
+                track.iter().filter_map(move |event| {
+                    tick += event.delta.as_int() as u64;
+                    match event.kind {
+                        TrackEventKind::Meta(MetaMessage::Tempo(us)) => Some((tick, us.as_int())),
+                        _ => None,
+                    }
+                })
+            })
+            .collect();
+        changes.sort_by_key(|&(tick, _)| tick);
+
+        let seconds_per_tick = |us: u32| us as f64 / 1e6 / ticks_per_beat;
+        let mut segments = vec![(0, 0.0, seconds_per_tick(DEFAULT_TEMPO_US))];
+        for (tick, us) in changes {
+            let &(last_tick, last_seconds, last_rate) = segments.last().unwrap();
+            let seconds = last_seconds + (tick - last_tick) as f64 * last_rate;
+            if tick == last_tick {
+                segments.pop();
+            }
+            segments.push((tick, seconds, seconds_per_tick(us)));
+        }
+        TempoMap { segments }
+    }
+
+    fn seconds(&self, tick: u64) -> f64 {
+        let i = self.segments.partition_point(|&(start, _, _)| start <= tick).max(1) - 1;
+        let (start, seconds, rate) = self.segments[i];
+        seconds + (tick - start) as f64 * rate
+    }
+}
+
+/// Every note of a MIDI file as a region, by start time
+///
+/// A note runs from its note on to the matching note off (or note on at
+/// velocity 0) on the same channel and key; overlapping notes of one key
+/// close first in, first out, and notes never released are dropped.
+/// Tracks of a type 2 (sequential) file are read as if played together.
+pub fn read_midi_regions<P: AsRef<Path>>(path: P) -> Result<Vec<MidiRegion>> {
+    let bytes = std::fs::read(path)?;
+    let smf = Smf::parse(&bytes).map_err(|e| AudioPaletteError::MidiError(format!("Failed to read MIDI: {}", e)))?;
+    let tempo = TempoMap::new(&smf);
+
+    let mut regions = Vec::new();
+    for (track_index, track) in smf.tracks.iter().enumerate() {
+        let mut track_name = String::new();
+        // Ticks and velocities of the notes sounding, by channel and key
+        let mut sounding: HashMap<(u8, u8), Vec<(u64, u8)>> = HashMap::new();
+        let mut tick = 0u64;
+        for event in track {
+            tick += event.delta.as_int() as u64;
+            let (channel, key, velocity) = match event.kind {
+                TrackEventKind::Meta(MetaMessage::TrackName(name)) if track_name.is_empty() => {
+                    track_name = String::from_utf8_lossy(name).trim().to_string();
+                    continue;
+                }
+                TrackEventKind::Midi { channel, message: MidiMessage::NoteOn { key, vel } } => {
+                    (channel.as_int(), key.as_int(), vel.as_int())
+                }
+                TrackEventKind::Midi { channel, message: MidiMessage::NoteOff { key, .. } } => {
+                    (channel.as_int(), key.as_int(), 0)
+                }
+                _ => continue,
+            };
+            let notes = sounding.entry((channel, key)).or_default();
+            if velocity > 0 {
+                notes.push((tick, velocity));
+            } else if !notes.is_empty() {
+                let (start, velocity) = notes.remove(0);
+                regions.push(MidiRegion {
+                    start_time: tempo.seconds(start),
+                    end_time: tempo.seconds(tick),
+                    note: key,
+                    velocity,
+                    track: track_index,
+                    track_name: String::new(),
+                });
+            }
+        }
+        for region in regions.iter_mut().filter(|r| r.track == track_index) {
+            region.track_name.clone_from(&track_name);
+        }
+    }
+
+    regions.retain(|r| r.end_time > r.start_time);
+    regions.sort_by(|a, b| a.start_time.total_cmp(&b.start_time).then(a.track.cmp(&b.track)).then(a.note.cmp(&b.note)));
+    Ok(regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use midly::{Format, Header, Track, TrackEvent};
+
+    #[test]
+    fn test_read_midi_regions() {
+        let event = |delta: u32, kind| TrackEvent { delta: delta.into(), kind };
+        let note = |on: bool, key: u8| TrackEventKind::Midi {
+            channel: 0.into(),
+            message: if on {
+                MidiMessage::NoteOn { key: key.into(), vel: 100.into() }
+            } else {
+                MidiMessage::NoteOff { key: key.into(), vel: 0.into() }
+            },
+        };
+        // 120 BPM for the first beat, then 60 BPM
+        let tempo: Track = vec![
+            event(0, TrackEventKind::Meta(MetaMessage::Tempo(500_000.into()))),
+            event(480, TrackEventKind::Meta(MetaMessage::Tempo(1_000_000.into()))),
+            event(0, TrackEventKind::Meta(MetaMessage::EndOfTrack)),
+        ];
+        let marks: Track = vec![
+            event(0, TrackEventKind::Meta(MetaMessage::TrackName(b"Hits"))),
+            event(240, note(true, 36)),
+            event(480, note(false, 36)),
+            event(0, note(true, 38)),
+            event(480, note(false, 38)),
+            // Never released
+            event(0, note(true, 40)),
+            event(0, TrackEventKind::Meta(MetaMessage::EndOfTrack)),
+        ];
+        let smf = Smf {
+            header: Header::new(Format::Parallel, Timing::Metrical(480.into())),
+            tracks: vec![tempo, marks],
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("marks.mid");
+        smf.save(&path).unwrap();
+
+        let regions = read_midi_regions(&path).unwrap();
+        let bounds: Vec<(f64, f64, u8)> = regions.iter().map(|r| (r.start_time, r.end_time, r.note)).collect();
+        // Ticks 240-720 straddle the tempo change; 720-1200 are all at 60 BPM
+        assert_eq!(bounds, vec![(0.25, 1.0, 36), (1.0, 2.0, 38)]);
+        assert!(regions.iter().all(|r| r.track == 1 && r.track_name == "Hits" && r.velocity == 100));
+
+        assert!(read_midi_regions(dir.path().join("missing.mid")).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+//! JSON export of match results
+//!
+//! For scripts and other tools: every field of every match, plus (when
+//! given) the fingerprints behind the scores and each feature group's
+//! share of them, so results can be re-ranked or analyzed elsewhere.
+
+use crate::fingerprint::{AudioFingerprint, FeatureContribution};
+use crate::{MatchResult, Result};
+use serde::Serialize;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Format name and version written at the top of the file
+const JSON_FORMAT: &str = "audio-palette-matches";
+const JSON_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    format: &'static str,
+    version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_fingerprint: Option<&'a AudioFingerprint>,
+    matches: Vec<JsonMatch<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonMatch<'a> {
+    #[serde(flatten)]
+    result: &'a MatchResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fingerprint: Option<&'a AudioFingerprint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    feature_scores: Option<Vec<FeatureContribution>>,
+}
+
+/// Export match results as JSON
+///
+/// `candidates` lines up with `matches` (empty for none): the fingerprint
+/// of each matched range. Where both it and `query` are known, the match
+/// gets per-feature scores; the fingerprints themselves are written with
+/// `include_fingerprints`.
+pub fn export_matches_to_json<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    query: Option<&AudioFingerprint>,
+    candidates: &[Option<AudioFingerprint>],
+    include_fingerprints: bool,
+    output_path: P,
+) -> Result<()> {
+    let matches = matches
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let candidate = candidates.get(i).and_then(Option::as_ref);
+            JsonMatch {
+                result,
+                fingerprint: candidate.filter(|_| include_fingerprints),
+                feature_scores: query.zip(candidate).map(|(query, candidate)| query.similarity_breakdown(candidate)),
+            }
+        })
+        .collect();
+    let export = JsonExport {
+        format: JSON_FORMAT,
+        version: JSON_VERSION,
+        query_fingerprint: query.filter(|_| include_fingerprints),
+        matches,
+    };
+    let writer = BufWriter::new(File::create(output_path)?);
+    serde_json::to_writer_pretty(writer, &export).map_err(std::io::Error::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+    use crate::fingerprint::Fingerprinter;
+
+    #[test]
+    fn test_json_export() {
+        let sr = 22050;
+        let tone = |freq: f64| -> Vec<f32> {
+            (0..sr).map(|i| (0.5 * (std::f64::consts::TAU * freq * i as f64 / sr as f64).sin()) as f32).collect()
+        };
+        let fingerprinter = Fingerprinter::default();
+        let query = fingerprinter.extract_from_samples(&tone(440.0), sr as u32).unwrap();
+        let candidate = fingerprinter.extract_from_samples(&tone(660.0), sr as u32).unwrap();
+        let hit = |sound_id: i64| MatchResult {
+            sound_id,
+            ..test_match(&format!("/tones/{sound_id}.wav"), 80.0, 0.0, 1.0, 1.0)
+        };
+        let matches = vec![hit(1), hit(2)];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("matches.json");
+        let read = || -> serde_json::Value { serde_json::from_reader(File::open(&path).unwrap()).unwrap() };
+
+        export_matches_to_json(&matches, Some(&query), &[Some(candidate.clone()), None], true, &path).unwrap();
+        let json = read();
+        assert_eq!(json["format"], JSON_FORMAT);
+        assert!(json["query_fingerprint"]["mfcc_mean"].is_array());
+        let first = &json["matches"][0];
+        assert_eq!(first["sound_id"], 1);
+        assert_eq!(first["filepath"], "/tones/1.wav");
+        assert!(first["fingerprint"]["mfcc_mean"].is_array());
+        let scores = first["feature_scores"].as_array().unwrap();
+        assert_eq!(scores.len(), query.similarity_breakdown(&candidate).len());
+        assert_eq!(scores[0]["group"], "Timbre");
+        // No fingerprint, no scores
+        assert!(json["matches"][1].get("feature_scores").is_none());
+
+        // Scores without the fingerprints
+        export_matches_to_json(&matches, Some(&query), &[Some(candidate)], false, &path).unwrap();
+        let json = read();
+        assert!(json.get("query_fingerprint").is_none() && json["matches"][0].get("fingerprint").is_none());
+        assert!(json["matches"][0]["feature_scores"].is_array());
+    }
+}
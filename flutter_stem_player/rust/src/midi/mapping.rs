@@ -0,0 +1,141 @@
+//! Channel, program and note of each exported match
+//!
+//! By default every match is note `base_note` plus its index, all on
+//! channel 0. Mapping by instrument puts drums on the General MIDI
+//! percussion channel at their drum notes and gives every other match a
+//! channel of its own with a program for its class, so a General MIDI
+//! synth plays the export back roughly as it sounds; mapping by pitch
+//! also plays each pitched match at its detected note.
+
+use super::drums::{gm_drum_note, GM_PERCUSSION_CHANNEL};
+use super::transcribe::midi_pitch;
+use crate::fingerprint::{AnalysisTrack, InstrumentClass};
+use serde::{Deserialize, Serialize};
+
+/// How matches become notes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MidiNoteMapping {
+    /// `base_note` plus the match's index, on channel 0
+    #[default]
+    Sequential,
+    /// Drums on the percussion channel, other classes on channels of their
+    /// own with a program to match
+    Instrument,
+    /// As `Instrument`, with pitched matches at their detected note
+    Pitch,
+}
+
+/// Channel, program and note of one match's track (None keeps the default)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MidiTrackAssignment {
+    /// 0-15 (9 is General MIDI percussion)
+    pub channel: Option<u8>,
+    /// Program change at the start of the track
+    pub program: Option<u8>,
+    pub note: Option<u8>,
+}
+
+/// General MIDI program (0-based) for a melodic class
+fn gm_program(class: InstrumentClass) -> Option<u8> {
+    match class {
+        InstrumentClass::Bass => Some(33),  // Electric Bass (finger)
+        InstrumentClass::Synth => Some(81), // Lead 2 (sawtooth)
+        InstrumentClass::Vocal => Some(53), // Voice Oohs
+        InstrumentClass::Fx => Some(102),   // FX 7 (echoes)
+        _ => None,
+    }
+}
+
+/// Assignments for matches of instrument `classes` with detected notes
+/// `pitches` (used by `Pitch`), both lining up with the matches
+///
+/// Melodic tracks take channels in order, skipping the percussion channel
+/// and wrapping after the sixteenth.
+pub fn assign_tracks(
+    mapping: MidiNoteMapping,
+    classes: &[Option<InstrumentClass>],
+    pitches: &[Option<u8>],
+) -> Vec<MidiTrackAssignment> {
+    if mapping == MidiNoteMapping::Sequential {
+        return vec![MidiTrackAssignment::default(); classes.len()];
+    }
+    let melodic_channels: Vec<u8> = (0..16).filter(|&c| c != GM_PERCUSSION_CHANNEL).collect();
+    let mut next_channel = 0;
+    classes
+        .iter()
+        .enumerate()
+        .map(|(i, &class)| {
+            if let Some(note) = class.and_then(gm_drum_note) {
+                return MidiTrackAssignment { channel: Some(GM_PERCUSSION_CHANNEL), program: None, note: Some(note) };
+            }
+            let channel = melodic_channels[next_channel % melodic_channels.len()];
+            next_channel += 1;
+            let note = match mapping {
+                MidiNoteMapping::Pitch => pitches.get(i).copied().flatten(),
+                _ => None,
+            };
+            MidiTrackAssignment { channel: Some(channel), program: class.and_then(gm_program), note }
+        })
+        .collect()
+}
+
+/// Median note of the voiced frames of a pitch curve between `start` and
+/// `end` seconds, or None if none are voiced
+pub fn median_note(pitch: &AnalysisTrack, start: f64, end: f64) -> Option<u8> {
+    if pitch.hop_seconds <= 0.0 {
+        return None;
+    }
+    let mut notes: Vec<f64> = pitch
+        .values
+        .iter()
+        .enumerate()
+        .filter(|&(frame, &f0)| {
+            let time = frame as f64 * pitch.hop_seconds;
+            f0 > 0.0 && time >= start && time < end
+        })
+        .map(|(_, &f0)| midi_pitch(f0))
+        .collect();
+    if notes.is_empty() {
+        return None;
+    }
+    notes.sort_by(f64::total_cmp);
+    Some(notes[notes.len() / 2].round().clamp(0.0, 127.0) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::AnalysisKind;
+
+    #[test]
+    fn test_assign_tracks() {
+        let classes =
+            [Some(InstrumentClass::Kick), Some(InstrumentClass::Bass), None, Some(InstrumentClass::HiHat)];
+        let pitches = [None, Some(40), Some(64), None];
+        assert!(assign_tracks(MidiNoteMapping::Sequential, &classes, &pitches)
+            .iter()
+            .all(|a| *a == MidiTrackAssignment::default()));
+
+        let by_instrument = assign_tracks(MidiNoteMapping::Instrument, &classes, &pitches);
+        let by_pitch = assign_tracks(MidiNoteMapping::Pitch, &classes, &pitches);
+        let drum = |note| MidiTrackAssignment { channel: Some(9), program: None, note: Some(note) };
+        assert_eq!(
+            by_instrument,
+            vec![
+                drum(36),
+                MidiTrackAssignment { channel: Some(0), program: Some(33), note: None },
+                MidiTrackAssignment { channel: Some(1), program: None, note: None },
+                drum(42),
+            ]
+        );
+        assert_eq!(by_pitch[1].note, Some(40));
+        assert_eq!(by_pitch[2].note, Some(64));
+        assert_eq!(by_pitch[0], drum(36));
+
+        // A4 then unvoiced, then a semitone up
+        let pitch = AnalysisTrack { kind: AnalysisKind::Pitch, hop_seconds: 0.1, values: vec![440.0, 440.0, 0.0, 466.2] };
+        assert_eq!(median_note(&pitch, 0.0, 0.25), Some(69));
+        assert_eq!(median_note(&pitch, 0.25, 0.4), Some(70));
+        assert_eq!(median_note(&pitch, 0.2, 0.3), None);
+    }
+}
@@ -1,10 +1,58 @@
-//! MIDI export for match results
+//! MIDI export for match results (per match or as an arrangement of one
+//! file), plus CSV, JSON, marker, REAPER region, M3U playlist, cue sheet,
+//! EDL, Ableton Live Set and DAWproject exports, SFZ, DecentSampler and MPC
+//! instrument exports, zip bundles of the matched audio, MIDI import of
+//! query regions, melody transcription (to MIDI or MusicXML), drum hits
+//! from onsets and click tracks of detected beats
+
+mod als;
+mod arrangement;
+mod bundle;
+mod click;
+pub mod csv;
+mod cue;
+mod dawproject;
+mod drums;
+pub mod dspreset;
+mod edl;
+pub mod import;
+mod json;
+pub mod mapping;
+mod mpc;
+mod musicxml;
+mod reaper;
+mod sfz;
+mod tempo;
+mod transcribe;
+
+pub use als::export_matches_to_als;
+pub use arrangement::export_matches_to_arrangement;
+pub use bundle::export_matches_bundle;
+pub use click::export_beat_grid_to_midi;
+pub use csv::{export_matches_to_csv, CsvColumn, CsvExportOptions};
+pub use cue::export_matches_to_cue;
+pub use dawproject::export_matches_to_dawproject;
+pub use drums::{
+    detect_drum_hits, export_drum_hits_to_midi, DrumHit, GM_CLOSED_HAT, GM_KICK, GM_PERCUSSION_CHANNEL, GM_SNARE,
+};
+pub use dspreset::{export_matches_to_dspreset, SamplerEnvelope};
+pub use edl::export_matches_to_edl;
+pub use import::{read_midi_regions, MidiRegion};
+pub use json::export_matches_to_json;
+pub use mapping::{assign_tracks, median_note, MidiNoteMapping, MidiTrackAssignment};
+pub use mpc::export_matches_to_xpm;
+pub use musicxml::export_notes_to_musicxml;
+pub use reaper::export_matches_to_reaper_regions;
+pub use sfz::export_matches_to_sfz;
+pub use transcribe::{export_notes_to_midi, transcribe_melody, TranscribedNote};
+
+use tempo::TempoMap;
 
 use crate::{AudioPaletteError, MatchResult, Result};
-use midly::{Format, Header, MidiMessage, Smf, Track, TrackEvent, TrackEventKind};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Track, TrackEvent, TrackEventKind};
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 /// MIDI export configuration
 #[derive(Debug, Clone)]
@@ -12,6 +60,16 @@ pub struct MidiExportConfig {
     pub tempo_bpm: u32,
     pub base_note: u8,
     pub ticks_per_beat: u16,
+    /// Grid that note starts and lengths snap to (None keeps the timing
+    /// of the matches)
+    pub quantize: Option<MidiQuantize>,
+    /// Detected beat times in seconds; with two or more the tempo track
+    /// follows them instead of `tempo_bpm`
+    pub beats: Vec<f64>,
+    /// Channel, program and note of each match's track, lining up with the
+    /// matches; missing entries and fields keep channel 0, no program
+    /// change and `base_note` plus the match's index
+    pub tracks: Vec<MidiTrackAssignment>,
 }
 
 impl Default for MidiExportConfig {
@@ -20,13 +78,77 @@ impl Default for MidiExportConfig {
             tempo_bpm: 120,
             base_note: 60, // Middle C
             ticks_per_beat: 480,
+            quantize: None,
+            beats: Vec::new(),
+            tracks: Vec::new(),
         }
     }
 }
 
+/// Musical grid for exported notes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MidiQuantize {
+    /// Grid note value: 4 for quarter notes through 32 for thirty-second
+    /// notes
+    pub note_value: u16,
+    /// How far every second grid line is pushed toward the next, as a
+    /// fraction of a grid step (0 is straight, 1/3 a triplet feel)
+    pub swing: f64,
+}
+
+impl MidiQuantize {
+    /// Grid step in ticks
+    fn step(&self, ticks_per_beat: u16) -> f64 {
+        (ticks_per_beat as f64 * 4.0 / self.note_value.max(1) as f64).max(1.0)
+    }
+
+    /// Grid line nearest to `tick`
+    fn snap_start(&self, tick: f64, ticks_per_beat: u16) -> f64 {
+        let step = self.step(ticks_per_beat);
+        let pair = (tick / (2.0 * step)).floor() * 2.0 * step;
+        let offbeat = pair + step * (1.0 + self.swing.clamp(0.0, 1.0));
+        [pair, offbeat, pair + 2.0 * step]
+            .into_iter()
+            .min_by(|a, b| (a - tick).abs().total_cmp(&(b - tick).abs()))
+            .unwrap_or(pair)
+    }
+
+    /// `length` in ticks rounded to whole grid steps, at least one
+    fn snap_length(&self, length: f64, ticks_per_beat: u16) -> f64 {
+        let step = self.step(ticks_per_beat);
+        (length / step).round().max(1.0) * step
+    }
+}
+
+/// Meta event at the start of a track
+fn meta_event(message: MetaMessage<'_>) -> TrackEvent<'_> {
+    TrackEvent { delta: 0.into(), kind: TrackEventKind::Meta(message) }
+}
+
+/// `text` escaped for an XML attribute or element
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Export match results to MIDI file
+///
+/// Each match gets a track named after its file, with the instrument name
+/// from `instruments` (lining up with `matches`; None or missing leaves it
+/// out) and the source filepath as a text event.
 pub fn export_matches_to_midi<P: AsRef<Path>>(
     matches: &[MatchResult],
+    instruments: &[Option<String>],
     output_path: P,
     config: &MidiExportConfig,
 ) -> Result<()> {
@@ -41,46 +163,56 @@ pub fn export_matches_to_midi<P: AsRef<Path>>(
 
     let mut tracks: Vec<Track> = Vec::new();
 
-    // Tempo track - use static bytes to avoid lifetime issues
-    let mut tempo_track = Track::new();
-    let tempo_us = 60_000_000 / config.tempo_bpm; // Microseconds per beat
-    tempo_track.push(TrackEvent {
-        delta: 0.into(),
-        kind: TrackEventKind::Meta(midly::MetaMessage::Tempo(tempo_us.into())),
-    });
-    tempo_track.push(TrackEvent {
-        delta: 0.into(),
-        kind: TrackEventKind::Meta(midly::MetaMessage::EndOfTrack),
-    });
-    tracks.push(tempo_track);
-
-    // Calculate ticks per second
-    let ticks_per_second = config.ticks_per_beat as f64 * config.tempo_bpm as f64 / 60.0;
+    // Tempo track, fixed or following the detected beats
+    let tempo = TempoMap::new(&config.beats, config.tempo_bpm, config.ticks_per_beat);
+    tracks.push(tempo.track());
 
     // Create a track for each match (up to 15, leaving room for tempo track)
     for (i, m) in matches.iter().take(15).enumerate() {
         let mut track = Track::new();
 
-        // Skip track name to avoid lifetime issues with MetaMessage::TrackName
-        // The MIDI file will still work correctly without track names
+        // Meta events borrow their text, which lives in `matches` and
+        // `instruments` until the file is written
+        track.push(meta_event(MetaMessage::TrackName(m.filename.as_bytes())));
+        if let Some(Some(instrument)) = instruments.get(i) {
+            track.push(meta_event(MetaMessage::InstrumentName(instrument.as_bytes())));
+        }
+        track.push(meta_event(MetaMessage::Text(m.filepath.as_bytes())));
 
         // Calculate timing in ticks
-        let start_ticks = (m.match_start * ticks_per_second) as u32;
-        let duration_ticks = ((m.match_end - m.match_start) * ticks_per_second) as u32;
-        let duration_ticks = duration_ticks.max(1);
+        let mut start_ticks = tempo.ticks(m.match_start);
+        let mut duration_ticks = tempo.ticks(m.match_end) - start_ticks;
+        if let Some(quantize) = &config.quantize {
+            start_ticks = quantize.snap_start(start_ticks, config.ticks_per_beat);
+            duration_ticks = quantize.snap_length(duration_ticks, config.ticks_per_beat);
+        }
+        let start_ticks = start_ticks.round() as u32;
+        let duration_ticks = (duration_ticks.round() as u32).max(1);
 
         // Velocity based on score (40-127)
         let velocity = (40.0 + (m.score / 100.0) * 87.0) as u8;
         let velocity = velocity.clamp(40, 127);
 
-        // Note number (each track gets different pitch)
-        let note = (config.base_note + i as u8).min(127);
+        // Note number (by default each track gets different pitch) and
+        // channel, with the program change ahead of the note
+        let assignment = config.tracks.get(i).cloned().unwrap_or_default();
+        let note = assignment.note.unwrap_or(config.base_note.saturating_add(i as u8)).min(127);
+        let channel = assignment.channel.unwrap_or(0).min(15);
+        if let Some(program) = assignment.program {
+            track.push(TrackEvent {
+                delta: 0.into(),
+                kind: TrackEventKind::Midi {
+                    channel: channel.into(),
+                    message: MidiMessage::ProgramChange { program: program.min(127).into() },
+                },
+            });
+        }
 
         // Note on
         track.push(TrackEvent {
             delta: start_ticks.into(),
             kind: TrackEventKind::Midi {
-                channel: 0.into(),
+                channel: channel.into(),
                 message: MidiMessage::NoteOn {
                     key: note.into(),
                     vel: velocity.into(),
@@ -92,7 +224,7 @@ pub fn export_matches_to_midi<P: AsRef<Path>>(
         track.push(TrackEvent {
             delta: duration_ticks.into(),
             kind: TrackEventKind::Midi {
-                channel: 0.into(),
+                channel: channel.into(),
                 message: MidiMessage::NoteOff {
                     key: note.into(),
                     vel: 0.into(),
@@ -125,34 +257,6 @@ pub fn export_matches_to_midi<P: AsRef<Path>>(
     Ok(())
 }
 
-/// Export match results to CSV
-pub fn export_matches_to_csv<P: AsRef<Path>>(
-    matches: &[MatchResult],
-    output_path: P,
-) -> Result<()> {
-    let mut file = File::create(output_path)?;
-
-    // Header
-    writeln!(file, "Filename,Filepath,Score,Match Start (s),Match End (s),Match Duration (s),File Duration (s)")?;
-
-    // Data rows
-    for m in matches {
-        writeln!(
-            file,
-            "{},{},{:.1},{:.3},{:.3},{:.3},{:.3}",
-            m.filename,
-            m.filepath,
-            m.score,
-            m.match_start,
-            m.match_end,
-            m.match_end - m.match_start,
-            m.file_duration
-        )?;
-    }
-
-    Ok(())
-}
-
 /// Export match results as marker/cue file
 pub fn export_matches_to_markers<P: AsRef<Path>>(
     matches: &[MatchResult],
@@ -181,32 +285,196 @@ pub fn export_matches_to_markers<P: AsRef<Path>>(
     Ok(())
 }
 
+/// `target` relative to the directory `base` (both absolute), or None when
+/// they share no root (e.g. different Windows drives)
+fn relative_path(target: &Path, base: &Path) -> Option<PathBuf> {
+    let target: Vec<Component> = target.components().collect();
+    let base: Vec<Component> = base.components().collect();
+    if target.first() != base.first() {
+        return None;
+    }
+    let common = target.iter().zip(&base).take_while(|(a, b)| a == b).count();
+    let mut relative: PathBuf = base[common..].iter().map(|_| Component::ParentDir).collect();
+    relative.extend(&target[common..]);
+    Some(relative)
+}
+
+/// Export match results as an extended M3U playlist (UTF-8, so also valid
+/// as .m3u8)
+///
+/// With `relative_paths`, entries are written relative to the playlist's
+/// folder where possible, so a folder holding both can be moved as a
+/// whole. Segment matches carry VLC start/stop options; players that
+/// don't understand them play the whole file.
+pub fn export_matches_to_m3u<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    output_path: P,
+    relative_paths: bool,
+) -> Result<()> {
+    let output_path = std::path::absolute(output_path.as_ref())?;
+    let playlist_dir = output_path.parent().unwrap_or(Path::new(""));
+    let mut file = File::create(&output_path)?;
+
+    writeln!(file, "#EXTM3U")?;
+    for m in matches {
+        let target = Path::new(&m.filepath);
+        let path = if relative_paths && target.is_absolute() {
+            relative_path(target, playlist_dir).unwrap_or_else(|| target.to_path_buf())
+        } else {
+            target.to_path_buf()
+        };
+
+        writeln!(file, "#EXTINF:{:.0},{}", m.match_end - m.match_start, m.filename)?;
+        if m.match_start > 0.0 || m.match_end < m.file_duration {
+            writeln!(file, "#EXTVLCOPT:start-time={:.3}", m.match_start)?;
+            writeln!(file, "#EXTVLCOPT:stop-time={:.3}", m.match_end)?;
+        }
+        writeln!(file, "{}", path.display())?;
+    }
+
+    Ok(())
+}
+
+/// Match of sound 1 at `filepath`, for exporter tests
+#[cfg(test)]
+pub(crate) fn test_match(filepath: &str, score: f64, start: f64, end: f64, file_duration: f64) -> MatchResult {
+    MatchResult {
+        sound_id: 1,
+        filepath: filepath.to_string(),
+        filename: std::path::Path::new(filepath).file_name().unwrap().to_string_lossy().to_string(),
+        score,
+        match_start: start,
+        match_end: end,
+        file_duration,
+        time_resolution: 0.0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::Read;
     use tempfile::NamedTempFile;
 
     #[test]
-    fn test_csv_export() {
-        let matches = vec![
-            MatchResult {
-                sound_id: 1,
-                filepath: "/test/sound.wav".to_string(),
-                filename: "sound.wav".to_string(),
-                score: 85.5,
-                match_start: 1.0,
-                match_end: 2.5,
-                file_duration: 5.0,
-            }
+    fn test_midi_export() {
+        let hit = |sound_id: i64, filename: &str| MatchResult {
+            sound_id,
+            ..test_match(&format!("/kit/{filename}"), 90.0, 0.5, 1.0, 1.0)
+        };
+        let matches = vec![hit(1, "kick.wav"), hit(2, "snare.wav")];
+        let temp = NamedTempFile::new().unwrap();
+        export_matches_to_midi(&matches, &[Some("kick".to_string())], temp.path(), &MidiExportConfig::default())
+            .unwrap();
+
+        let bytes = std::fs::read(temp.path()).unwrap();
+        let smf = Smf::parse(&bytes).unwrap();
+        assert_eq!(smf.tracks.len(), 3);
+        fn metas<'a>(track: &Track<'a>) -> Vec<MetaMessage<'a>> {
+            track
+                .iter()
+                .filter_map(|e| match e.kind {
+                    TrackEventKind::Meta(m) if m != MetaMessage::EndOfTrack => Some(m),
+                    _ => None,
+                })
+                .collect()
+        }
+        assert_eq!(
+            metas(&smf.tracks[1]),
+            vec![
+                MetaMessage::TrackName(b"kick.wav"),
+                MetaMessage::InstrumentName(b"kick"),
+                MetaMessage::Text(b"/kit/kick.wav"),
+            ]
+        );
+        assert_eq!(
+            metas(&smf.tracks[2]),
+            vec![MetaMessage::TrackName(b"snare.wav"), MetaMessage::Text(b"/kit/snare.wav")]
+        );
+
+        // Assigned channels, programs and notes
+        let tracks = vec![
+            MidiTrackAssignment { channel: Some(9), program: None, note: Some(36) },
+            MidiTrackAssignment { channel: Some(2), program: Some(33), note: None },
         ];
+        let config = MidiExportConfig { tracks, ..MidiExportConfig::default() };
+        export_matches_to_midi(&matches, &[], temp.path(), &config).unwrap();
+        let bytes = std::fs::read(temp.path()).unwrap();
+        let smf = Smf::parse(&bytes).unwrap();
+        let midi = |track: &Track| -> Vec<(u8, MidiMessage)> {
+            track
+                .iter()
+                .filter_map(|e| match e.kind {
+                    TrackEventKind::Midi { channel, message } => Some((channel.as_int(), message)),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(midi(&smf.tracks[1])[0], (9, MidiMessage::NoteOn { key: 36.into(), vel: 118.into() }));
+        assert_eq!(
+            midi(&smf.tracks[2])[..2],
+            [
+                (2, MidiMessage::ProgramChange { program: 33.into() }),
+                (2, MidiMessage::NoteOn { key: 61.into(), vel: 118.into() }),
+            ]
+        );
+    }
 
+    #[test]
+    fn test_midi_quantize() {
+        let hit = |start: f64, end: f64| test_match("/loops/break.wav", 90.0, start, end, 4.0);
+        // At 120 BPM a sixteenth is 0.125 s
+        let matches = vec![hit(0.52, 0.75), hit(0.63, 0.66)];
         let temp = NamedTempFile::new().unwrap();
-        export_matches_to_csv(&matches, temp.path()).unwrap();
+        let bounds = |quantize| {
+            let config = MidiExportConfig { quantize, ..MidiExportConfig::default() };
+            export_matches_to_midi(&matches, &[], temp.path(), &config).unwrap();
+            read_midi_regions(temp.path()).unwrap().iter().map(|r| (r.start_time, r.end_time)).collect::<Vec<_>>()
+        };
+
+        let straight = bounds(Some(MidiQuantize { note_value: 16, swing: 0.0 }));
+        assert_eq!(straight, vec![(0.5, 0.75), (0.625, 0.75)]);
+        // Swung by a third of a step, the off-beat sixteenth moves to 2/3 s
+        let swung = bounds(Some(MidiQuantize { note_value: 16, swing: 1.0 / 3.0 }));
+        assert_eq!(swung[0], (0.5, 0.75));
+        assert!((swung[1].0 - 2.0 / 3.0).abs() < 1e-3, "{:?}", swung);
+        // Off the grid without quantizing
+        let raw = bounds(None);
+        assert!((raw[0].0 - 0.52).abs() < 1e-3 && (raw[1].1 - 0.66).abs() < 1e-3, "{:?}", raw);
+    }
+
+    #[test]
+    fn test_m3u_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let sound = |path: &Path, start: f64, end: f64| MatchResult {
+            sound_id: 1,
+            filepath: path.to_string_lossy().to_string(),
+            filename: path.file_name().unwrap().to_string_lossy().to_string(),
+            score: 100.0,
+            match_start: start,
+            match_end: end,
+            file_duration: 4.0,
+            time_resolution: 0.0,
+        };
+        let matches = vec![
+            sound(&root.join("kits").join("kick.wav"), 0.0, 4.0),
+            sound(&root.join("loops").join("break.wav"), 1.0, 2.5),
+        ];
 
-        let mut content = String::new();
-        File::open(temp.path()).unwrap().read_to_string(&mut content).unwrap();
-        assert!(content.contains("sound.wav"));
-        assert!(content.contains("85.5"));
+        let playlist = root.join("playlists").join("set.m3u8");
+        std::fs::create_dir(root.join("playlists")).unwrap();
+        export_matches_to_m3u(&matches, &playlist, true).unwrap();
+        let content = std::fs::read_to_string(&playlist).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        let sep = std::path::MAIN_SEPARATOR;
+        assert_eq!(lines[0], "#EXTM3U");
+        assert_eq!(lines[1], "#EXTINF:4,kick.wav");
+        assert_eq!(lines[2], format!("..{sep}kits{sep}kick.wav"));
+        // Segments carry start/stop options
+        assert_eq!(&lines[3..6], ["#EXTINF:2,break.wav", "#EXTVLCOPT:start-time=1.000", "#EXTVLCOPT:stop-time=2.500"]);
+
+        export_matches_to_m3u(&matches, &playlist, false).unwrap();
+        let content = std::fs::read_to_string(&playlist).unwrap();
+        assert!(content.lines().any(|l| l == matches[0].filepath));
     }
 }
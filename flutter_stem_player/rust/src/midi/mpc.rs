@@ -0,0 +1,134 @@
+//! Akai MPC drum program export
+//!
+//! An .xpm is the XML program format of the MPC software and standalone
+//! MPCs. Each match goes on a pad of bank A, trimmed to its segment with
+//! the layer's slice start and end, so a whole recording can stand in for
+//! a folder of chopped hits. The MPC finds samples by name in the
+//! program's folder; the relative path is written too, for the versions
+//! that read it.
+
+use super::sfz::{sample_path, segment_frames, DEFAULT_SAMPLE_RATE};
+use super::xml_escape;
+use crate::{MatchResult, Result};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Pads in a bank
+const PADS: usize = 16;
+/// Notes of pads A01-A16 in the MPC's classic layout
+const CLASSIC_PAD_NOTES: [u8; PADS] = [37, 36, 42, 82, 40, 38, 46, 44, 48, 47, 45, 43, 49, 55, 51, 53];
+/// Note of pad A01 in the chromatic layout, rising a semitone per pad
+const CHROMATIC_FIRST_NOTE: u8 = 36;
+
+/// Export match results (up to 16) as an MPC drum program named `name`,
+/// one per pad in result order, returning how many were placed
+///
+/// Pads play the classic MPC note layout, or consecutive notes from C1
+/// with `chromatic`. `sample_rates` lines up with `matches` (0 or missing
+/// when unknown) and turns segment bounds into slice points.
+pub fn export_matches_to_xpm<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    sample_rates: &[u32],
+    output_path: P,
+    name: &str,
+    chromatic: bool,
+) -> Result<usize> {
+    let output_path = std::path::absolute(output_path.as_ref())?;
+    let program_dir = output_path.parent().unwrap_or(Path::new(""));
+
+    let mut instruments = String::new();
+    let mut pad_notes = String::new();
+    let pads = matches.len().min(PADS);
+    for (i, m) in matches.iter().take(PADS).enumerate() {
+        let sample_rate = sample_rates.get(i).copied().unwrap_or(0);
+        let (slice_start, slice_end) = match segment_frames(m, sample_rate) {
+            Some((start, end)) => (start, end + 1),
+            None => {
+                let sample_rate = if sample_rate > 0 { sample_rate } else { DEFAULT_SAMPLE_RATE };
+                (0, (m.file_duration * sample_rate as f64).round() as u64)
+            }
+        };
+        let sample_name = Path::new(&m.filepath).file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+        let _ = write!(
+            instruments,
+            r#"      <Instrument number="{number}">
+        <Layers>
+          <Layer number="1">
+            <Active>True</Active>
+            <Volume>1.000000</Volume>
+            <Pan>0.500000</Pan>
+            <SampleName>{sample_name}</SampleName>
+            <SampleFile>{sample_file}</SampleFile>
+            <SliceIndex>128</SliceIndex>
+            <SliceStart>{slice_start}</SliceStart>
+            <SliceEnd>{slice_end}</SliceEnd>
+            <SliceLoop>0</SliceLoop>
+          </Layer>
+        </Layers>
+      </Instrument>
+"#,
+            number = i + 1,
+            sample_name = xml_escape(&sample_name),
+            sample_file = xml_escape(&sample_path(&m.filepath, program_dir)),
+        );
+        let note = if chromatic { CHROMATIC_FIRST_NOTE + i as u8 } else { CLASSIC_PAD_NOTES[i] };
+        let _ = writeln!(pad_notes, r#"      <PadNote number="{}"><Note>{note}</Note></PadNote>"#, i + 1);
+    }
+
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<MPCVObject>
+  <Version>
+    <File_Version>2.1</File_Version>
+    <Application>MPC-V</Application>
+    <Application_Version>2.10.0.0</Application_Version>
+  </Version>
+  <Program type="Drum">
+    <ProgramName>{name}</ProgramName>
+    <Instruments>
+{instruments}    </Instruments>
+    <PadNoteMap>
+{pad_notes}    </PadNoteMap>
+  </Program>
+</MPCVObject>
+"#,
+        name = xml_escape(name),
+    );
+    std::fs::write(&output_path, xml)?;
+    Ok(pads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_xpm_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let hit = |filename: &str, start: f64, end: f64| {
+            test_match(&dir.path().join("breaks").join(filename).to_string_lossy(), 90.0, start, end, 1.5)
+        };
+        let mut matches = vec![hit("amen.wav", 0.5, 0.75), hit("snare & clap.wav", 0.0, 0.0)];
+        let path = dir.path().join("Found Kit.xpm");
+
+        assert_eq!(export_matches_to_xpm(&matches, &[44100, 48000], &path, "Found <kit>", false).unwrap(), 2);
+        let xml = std::fs::read_to_string(&path).unwrap();
+        assert!(xml.contains("<ProgramName>Found &lt;kit&gt;</ProgramName>"));
+        assert!(xml.contains("<SampleName>amen</SampleName>"));
+        assert!(xml.contains("<SampleFile>breaks/amen.wav</SampleFile>"));
+        assert!(xml.contains("<SliceStart>22050</SliceStart>") && xml.contains("<SliceEnd>33075</SliceEnd>"));
+        // A whole-sound match plays its whole file
+        assert!(xml.contains("<SampleName>snare &amp; clap</SampleName>"));
+        assert!(xml.contains("<SliceStart>0</SliceStart>") && xml.contains("<SliceEnd>72000</SliceEnd>"));
+        assert!(xml.contains(r#"<PadNote number="1"><Note>37</Note></PadNote>"#));
+        assert!(xml.contains(r#"<PadNote number="2"><Note>36</Note></PadNote>"#));
+
+        // Only 16 pads, and the chromatic layout
+        matches = (0..20).map(|i| hit(&format!("hit{i}.wav"), 0.0, 0.0)).collect();
+        assert_eq!(export_matches_to_xpm(&matches, &[], &path, "Chromatic", true).unwrap(), 16);
+        let xml = std::fs::read_to_string(&path).unwrap();
+        assert!(xml.contains(r#"<PadNote number="16"><Note>51</Note></PadNote>"#));
+        assert!(!xml.contains("hit16"));
+    }
+}
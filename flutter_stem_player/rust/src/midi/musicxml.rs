@@ -0,0 +1,272 @@
+//! MusicXML export of transcribed notes
+//!
+//! Notation software (MuseScore, Sibelius, Dorico, Finale) opens MusicXML
+//! rather than MIDI without guessing at the rhythm, so a found phrase is
+//! written out already notated: notes snapped to a sixteenth grid in 4/4,
+//! rests filling the gaps, and notes that cross a barline (or have no
+//! single note value) split into tied notes. Pitches are spelled with
+//! sharps or flats to suit the key signature.
+
+use super::xml_escape;
+use super::TranscribedNote;
+use crate::Result;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Grid steps per quarter note (sixteenths)
+const DIVISIONS: u64 = 4;
+/// Steps per 4/4 measure
+const MEASURE_STEPS: u64 = 4 * DIVISIONS;
+/// Note values written without ties, longest first: length in steps, type
+/// and whether it's dotted
+const NOTE_VALUES: [(u64, &str, bool); 8] = [
+    (16, "whole", false),
+    (12, "half", true),
+    (8, "half", false),
+    (6, "quarter", true),
+    (4, "quarter", false),
+    (3, "eighth", true),
+    (2, "eighth", false),
+    (1, "16th", false),
+];
+/// Velocity written as 100% dynamics (forte)
+const FORTE_VELOCITY: f64 = 90.0;
+
+/// A note or rest: start and end in grid steps, then note and velocity
+/// (None for a rest)
+type Event = (u64, u64, Option<(u8, u8)>);
+
+/// Key signature (sharps positive, flats negative) of a key name such as
+/// "A minor" or "Bb major", or None when it can't be read
+fn key_fifths(key: &str) -> Option<i8> {
+    let mut words = key.split_whitespace();
+    let tonic = words.next()?;
+    let minor = words.next().is_some_and(|mode| mode.to_lowercase().starts_with("min"));
+    let mut chars = tonic.chars();
+    let mut pitch_class: i32 = match chars.next()?.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let mut flat = false;
+    for c in chars {
+        match c {
+            '#' | '♯' => pitch_class += 1,
+            'b' | '♭' => {
+                pitch_class -= 1;
+                flat = true;
+            }
+            _ => return None,
+        }
+    }
+    // A minor key shares the signature of the major a minor third up
+    if minor {
+        pitch_class += 3;
+    }
+    let fifths = (pitch_class * 7).rem_euclid(12) as i8;
+    Some(if fifths > 6 || (fifths == 6 && flat) { fifths - 12 } else { fifths })
+}
+
+/// Step, alteration and octave of a MIDI note, spelled with flats or sharps
+fn spell(note: u8, flats: bool) -> (char, i8, i32) {
+    const SHARPS: [(char, i8); 12] = [
+        ('C', 0), ('C', 1), ('D', 0), ('D', 1), ('E', 0), ('F', 0),
+        ('F', 1), ('G', 0), ('G', 1), ('A', 0), ('A', 1), ('B', 0),
+    ];
+    const FLATS: [(char, i8); 12] = [
+        ('C', 0), ('D', -1), ('D', 0), ('E', -1), ('E', 0), ('F', 0),
+        ('G', -1), ('G', 0), ('A', -1), ('A', 0), ('B', -1), ('B', 0),
+    ];
+    let (step, alter) = if flats { FLATS } else { SHARPS }[note as usize % 12];
+    (step, alter, note as i32 / 12 - 1)
+}
+
+/// Notes as (start, end, note, velocity) in grid steps, monophonic: a note
+/// is cut short where the next starts, and of notes starting together
+/// only the first is kept
+fn quantize(notes: &[TranscribedNote], step_seconds: f64) -> Vec<(u64, u64, u8, u8)> {
+    let mut sorted: Vec<&TranscribedNote> = notes.iter().collect();
+    sorted.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+    let mut grid: Vec<(u64, u64, u8, u8)> = Vec::with_capacity(sorted.len());
+    for n in sorted {
+        let start = (n.start_time.max(0.0) / step_seconds).round() as u64;
+        let end = ((n.end_time.max(0.0) / step_seconds).round() as u64).max(start + 1);
+        if let Some(last) = grid.last_mut() {
+            if start <= last.0 {
+                continue;
+            }
+            last.1 = last.1.min(start);
+        }
+        grid.push((start, end, n.note, n.velocity));
+    }
+    grid
+}
+
+/// Export transcribed notes as a single-part MusicXML score titled `title`
+/// at `tempo_bpm` in `key` (e.g. "A minor"; C major when empty or
+/// unreadable)
+pub fn export_notes_to_musicxml<P: AsRef<Path>>(
+    notes: &[TranscribedNote],
+    title: &str,
+    key: &str,
+    output_path: P,
+    tempo_bpm: u32,
+) -> Result<()> {
+    let tempo_bpm = tempo_bpm.max(1);
+    let step_seconds = 60.0 / tempo_bpm as f64 / DIVISIONS as f64;
+    let fifths = key_fifths(key).unwrap_or(0);
+    let grid = quantize(notes, step_seconds);
+
+    // Low melodies read better in the bass clef
+    let mut pitches: Vec<u8> = grid.iter().map(|n| n.2).collect();
+    pitches.sort_unstable();
+    let (clef, clef_line) = match pitches.get(pitches.len() / 2) {
+        Some(&median) if median < 60 => ('F', 4),
+        _ => ('G', 2),
+    };
+
+    // Notes and the rests between them, padded out to a whole measure
+    let mut events: Vec<Event> = Vec::new();
+    let mut cursor = 0;
+    for &(start, end, note, velocity) in &grid {
+        if start > cursor {
+            events.push((cursor, start, None));
+        }
+        events.push((start, end, Some((note, velocity))));
+        cursor = end;
+    }
+    let total = cursor.div_ceil(MEASURE_STEPS).max(1) * MEASURE_STEPS;
+    if total > cursor {
+        events.push((cursor, total, None));
+    }
+
+    let mut measures = String::new();
+    let _ = write!(
+        measures,
+        r#"    <measure number="1">
+      <attributes>
+        <divisions>{DIVISIONS}</divisions>
+        <key><fifths>{fifths}</fifths></key>
+        <time><beats>4</beats><beat-type>4</beat-type></time>
+        <clef><sign>{clef}</sign><line>{clef_line}</line></clef>
+      </attributes>
+      <direction placement="above">
+        <direction-type><metronome><beat-unit>quarter</beat-unit><per-minute>{tempo_bpm}</per-minute></metronome></direction-type>
+        <sound tempo="{tempo_bpm}"/>
+      </direction>
+"#,
+    );
+    for (start, end, note) in events {
+        let mut position = start;
+        while position < end {
+            if position > 0 && position % MEASURE_STEPS == 0 {
+                let _ = write!(measures, "    </measure>\n    <measure number=\"{}\">\n", position / MEASURE_STEPS + 1);
+            }
+            // The longest value that fits both the note and the measure
+            let bar_end = (position / MEASURE_STEPS + 1) * MEASURE_STEPS;
+            let remaining = end.min(bar_end) - position;
+            let &(length, kind, dotted) = NOTE_VALUES.iter().find(|v| v.0 <= remaining).unwrap_or(&NOTE_VALUES[7]);
+            let (tie_stop, tie_start) = (position > start, position + length < end);
+            let dot = if dotted { "<dot/>" } else { "" };
+            match note {
+                None => {
+                    let _ = writeln!(measures, "      <note><rest/><duration>{length}</duration><type>{kind}</type>{dot}</note>");
+                }
+                Some((note, velocity)) => {
+                    let (step, alter, octave) = spell(note, fifths < 0);
+                    let alter = if alter != 0 { format!("<alter>{alter}</alter>") } else { String::new() };
+                    let dynamics = (velocity as f64 / FORTE_VELOCITY * 100.0).round();
+                    let mut ties = String::new();
+                    let mut tied = String::new();
+                    for (on, kind) in [(tie_stop, "stop"), (tie_start, "start")] {
+                        if on {
+                            let _ = write!(ties, "<tie type=\"{kind}\"/>");
+                            let _ = write!(tied, "<tied type=\"{kind}\"/>");
+                        }
+                    }
+                    let notations = if tied.is_empty() { tied } else { format!("<notations>{tied}</notations>") };
+                    let _ = writeln!(
+                        measures,
+                        "      <note dynamics=\"{dynamics}\"><pitch><step>{step}</step>{alter}<octave>{octave}</octave></pitch>\
+                         <duration>{length}</duration>{ties}<type>{kind}</type>{dot}{notations}</note>"
+                    );
+                }
+            }
+            position += length;
+        }
+    }
+    measures.push_str("    </measure>\n");
+
+    let title = xml_escape(title);
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE score-partwise PUBLIC "-//Recordare//DTD MusicXML 4.0 Partwise//EN" "http://www.musicxml.org/dtds/partwise.dtd">
+<score-partwise version="4.0">
+  <work><work-title>{title}</work-title></work>
+  <identification>
+    <encoding><software>Audio Palette {version}</software></encoding>
+  </identification>
+  <part-list>
+    <score-part id="P1"><part-name>{title}</part-name></score-part>
+  </part-list>
+  <part id="P1">
+{measures}  </part>
+</score-partwise>
+"#,
+        version = env!("CARGO_PKG_VERSION"),
+    );
+    std::fs::write(output_path, xml)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_fifths() {
+        assert_eq!(key_fifths("C major"), Some(0));
+        assert_eq!(key_fifths("A minor"), Some(0));
+        assert_eq!(key_fifths("Bb major"), Some(-2));
+        assert_eq!(key_fifths("F# minor"), Some(3));
+        assert_eq!(key_fifths("Gb major"), Some(-6));
+        assert_eq!(key_fifths("F# major"), Some(6));
+        assert_eq!(key_fifths(""), None);
+        assert_eq!(key_fifths("unknown"), None);
+    }
+
+    #[test]
+    fn test_musicxml_export() {
+        let note = |start_time: f64, end_time: f64, note: u8| TranscribedNote { start_time, end_time, note, velocity: 90 };
+        // At 120 BPM a sixteenth is 0.125 s: a quarter-note C, a quarter
+        // rest, then a B flat from beat 3 over the barline into bar 2
+        let notes = vec![note(0.02, 0.49, 72), note(1.0, 2.5, 70)];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("phrase.musicxml");
+        export_notes_to_musicxml(&notes, "Vocal <take 2>", "Bb major", &path, 120).unwrap();
+
+        let xml = std::fs::read_to_string(&path).unwrap();
+        assert!(xml.contains("<work-title>Vocal &lt;take 2&gt;</work-title>"));
+        assert!(xml.contains("<fifths>-2</fifths>") && xml.contains("<sign>G</sign>"));
+        assert!(xml.contains("<per-minute>120</per-minute>"));
+        let notes: Vec<&str> = xml.lines().filter(|l| l.contains("<note")).map(str::trim).collect();
+        assert_eq!(
+            notes,
+            [
+                "<note dynamics=\"100\"><pitch><step>C</step><octave>5</octave></pitch><duration>4</duration><type>quarter</type></note>",
+                "<note><rest/><duration>4</duration><type>quarter</type></note>",
+                "<note dynamics=\"100\"><pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch><duration>8</duration>\
+                 <tie type=\"start\"/><type>half</type><notations><tied type=\"start\"/></notations></note>",
+                "<note dynamics=\"100\"><pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch><duration>4</duration>\
+                 <tie type=\"stop\"/><type>quarter</type><notations><tied type=\"stop\"/></notations></note>",
+                "<note><rest/><duration>12</duration><type>half</type><dot/></note>",
+            ]
+        );
+        assert!(xml.contains("<measure number=\"2\">") && !xml.contains("<measure number=\"3\">"));
+    }
+}
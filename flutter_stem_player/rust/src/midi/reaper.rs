@@ -0,0 +1,80 @@
+//! REAPER region/marker CSV
+//!
+//! The Region/Marker Manager imports a CSV of `#,Name,Start,End,Length,Color`
+//! rows, so each match lands on the timeline as a named region at its
+//! place in the source file. Regions are colored from red through yellow
+//! to green by score, which makes the strong matches easy to spot when
+//! scrubbing through a long recording.
+
+use super::csv::csv_field;
+use crate::{MatchResult, Result};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Seconds as REAPER's minutes:seconds time
+fn reaper_time(seconds: f64) -> String {
+    let seconds = seconds.max(0.0);
+    let minutes = (seconds / 60.0).floor();
+    format!("{}:{:06.3}", minutes as u64, seconds - minutes * 60.0)
+}
+
+/// Hex RGB for a score out of 100: red at 0, yellow at 50, green at 100
+fn score_color(score: f64) -> String {
+    let t = (score / 100.0).clamp(0.0, 1.0);
+    let (red, green) = if t < 0.5 { (1.0, 2.0 * t) } else { (2.0 * (1.0 - t), 1.0) };
+    format!("{:02X}{:02X}00", (red * 255.0).round() as u8, (green * 255.0).round() as u8)
+}
+
+/// Export match results as a REAPER region CSV, one region per match
+/// named after its file and score
+///
+/// Matches without a range (whole-sound matches) span the whole file.
+pub fn export_matches_to_reaper_regions<P: AsRef<Path>>(matches: &[MatchResult], output_path: P) -> Result<()> {
+    let mut file = File::create(output_path)?;
+    writeln!(file, "#,Name,Start,End,Length,Color")?;
+    for (i, m) in matches.iter().enumerate() {
+        let (start, end) =
+            if m.match_end > m.match_start { (m.match_start, m.match_end) } else { (0.0, m.file_duration) };
+        let name = format!("{} ({:.1}%)", m.filename, m.score);
+        writeln!(
+            file,
+            "R{},{},{},{},{},{}",
+            i + 1,
+            csv_field(&name, ','),
+            reaper_time(start),
+            reaper_time(end),
+            reaper_time(end - start),
+            score_color(m.score)
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_reaper_regions() {
+        let matches = vec![
+            test_match("/takes/take 1.wav", 100.0, 61.5, 63.25, 90.0),
+            test_match("/takes/hits, vol 2.wav", 25.0, 0.0, 0.0, 90.0),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("regions.csv");
+        export_matches_to_reaper_regions(&matches, &path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "#,Name,Start,End,Length,Color",
+                "R1,take 1.wav (100.0%),1:01.500,1:03.250,0:01.750,00FF00",
+                "R2,\"hits, vol 2.wav (25.0%)\",0:00.000,1:30.000,1:30.000,FF8000",
+            ]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! SFZ instrument export
+//!
+//! Turns a set of matches (or a collection of one-shots) into a playable
+//! sampler instrument: each match gets its own keys, from `lowest_note`
+//! up, and plays its segment of the source file in place through the
+//! `offset` and `end` opcodes, so nothing is copied or trimmed. Sample
+//! paths are relative to the .sfz file where possible, as sfizz, Sforzando
+//! and the DAW samplers expect.
+
+use super::relative_path;
+use crate::{MatchResult, Result};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Assumed when a source's sample rate is unknown
+pub(super) const DEFAULT_SAMPLE_RATE: u32 = 44100;
+/// Release in seconds, so segments cut mid-sound don't click on note-off
+const RELEASE_SECONDS: f64 = 0.05;
+
+/// Lowest and highest key of the `index`th sample, or None once past the
+/// top of the keyboard
+pub(super) fn key_range(index: usize, lowest_note: u8, keys_per_sample: u8) -> Option<(u32, u32)> {
+    let keys_per_sample = keys_per_sample.max(1) as u32;
+    let lokey = lowest_note as u32 + index as u32 * keys_per_sample;
+    (lokey <= 127).then(|| (lokey, (lokey + keys_per_sample - 1).min(127)))
+}
+
+/// `filepath` relative to `dir` where possible, with forward slashes as
+/// samplers expect on every platform
+pub(super) fn sample_path(filepath: &str, dir: &Path) -> String {
+    let target = Path::new(filepath);
+    let path = relative_path(target, dir).unwrap_or_else(|| target.to_path_buf());
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// First and last frame of a segment match at `sample_rate` (0 when
+/// unknown), or None for a whole-sound match
+pub(super) fn segment_frames(m: &MatchResult, sample_rate: u32) -> Option<(u64, u64)> {
+    if m.match_end <= m.match_start {
+        return None;
+    }
+    let sample_rate = if sample_rate > 0 { sample_rate } else { DEFAULT_SAMPLE_RATE } as f64;
+    let start = (m.match_start * sample_rate).round() as u64;
+    let end = ((m.match_end * sample_rate).round() as u64).max(start + 1);
+    Some((start, end - 1))
+}
+
+/// Export match results as an SFZ instrument, returning how many fit on
+/// the keyboard
+///
+/// Each match spans `keys_per_sample` keys from `lowest_note` up, in
+/// result order, played at its original pitch on its lowest key. With one
+/// key per sample the regions are one-shots (note-off doesn't stop them),
+/// like a drum kit; wider ranges play chromatically. `sample_rates` lines
+/// up with `matches` (0 or missing when unknown) and turns segment bounds
+/// into sample offsets.
+pub fn export_matches_to_sfz<P: AsRef<Path>>(
+    matches: &[MatchResult],
+    sample_rates: &[u32],
+    output_path: P,
+    lowest_note: u8,
+    keys_per_sample: u8,
+) -> Result<usize> {
+    let output_path = std::path::absolute(output_path.as_ref())?;
+    let instrument_dir = output_path.parent().unwrap_or(Path::new(""));
+
+    let mut file = File::create(&output_path)?;
+    writeln!(file, "// Audio Palette instrument")?;
+    writeln!(file, "<control>")?;
+    writeln!(file, "<global>")?;
+    if keys_per_sample <= 1 {
+        writeln!(file, "loop_mode=one_shot")?;
+    } else {
+        writeln!(file, "loop_mode=no_loop ampeg_release={RELEASE_SECONDS}")?;
+    }
+
+    let mut mapped = 0;
+    for (i, m) in matches.iter().enumerate() {
+        let Some((lokey, hikey)) = key_range(i, lowest_note, keys_per_sample) else {
+            break;
+        };
+        let sample = sample_path(&m.filepath, instrument_dir);
+
+        writeln!(file)?;
+        writeln!(file, "// {} ({:.1}%)", m.filename, m.score)?;
+        write!(file, "<region> lokey={lokey} hikey={hikey} pitch_keycenter={lokey}")?;
+        if let Some((offset, end)) = segment_frames(m, sample_rates.get(i).copied().unwrap_or(0)) {
+            write!(file, " offset={offset} end={end}")?;
+        }
+        // Last on the line, so the path may contain spaces
+        writeln!(file, " sample={sample}")?;
+        mapped += 1;
+    }
+    Ok(mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+
+    #[test]
+    fn test_sfz_export() {
+        let hit = |filepath: &str, start: f64, end: f64| test_match(filepath, 88.0, start, end, 4.0);
+        let dir = tempfile::tempdir().unwrap();
+        let samples = dir.path().join("samples");
+        let matches = vec![
+            hit(&samples.join("glass hit.wav").to_string_lossy(), 0.5, 1.0),
+            hit(&samples.join("bell.wav").to_string_lossy(), 0.0, 0.0),
+        ];
+        let path = dir.path().join("found.sfz");
+
+        assert_eq!(export_matches_to_sfz(&matches, &[48000], &path, 36, 1).unwrap(), 2);
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("loop_mode=one_shot"));
+        assert!(content.contains("// glass hit.wav (88.0%)"));
+        assert!(content
+            .contains("<region> lokey=36 hikey=36 pitch_keycenter=36 offset=24000 end=47999 sample=samples/glass hit.wav"));
+        // A whole-sound match plays the whole file
+        assert!(content.contains("<region> lokey=37 hikey=37 pitch_keycenter=37 sample=samples/bell.wav"));
+
+        // An octave per sample, until the keyboard runs out
+        assert_eq!(export_matches_to_sfz(&matches, &[], &path, 120, 12).unwrap(), 1);
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("loop_mode=no_loop ampeg_release=0.05"));
+        assert!(content.contains("<region> lokey=120 hikey=127 pitch_keycenter=120 offset=22050 end=44099"));
+        assert!(!content.contains("bell.wav"));
+    }
+}
@@ -0,0 +1,131 @@
+//! Tempo tracks for export: a fixed BPM, or a tempo map that follows
+//! detected beats
+//!
+//! Following the beats puts a tempo change at each one, sized so every
+//! detected beat lands exactly on a MIDI beat. Imported into a DAW next
+//! to the source audio, the exported events then line up with it and the
+//! DAW's grid follows the performance, drift and tempo changes included.
+
+use midly::{MetaMessage, Track, TrackEvent, TrackEventKind};
+
+/// Seconds to ticks for an export, and the tempo events that say so
+pub(super) struct TempoMap {
+    ticks_per_beat: u16,
+    /// (seconds, tick, ticks per second from there), by time
+    segments: Vec<(f64, f64, f64)>,
+}
+
+impl TempoMap {
+    /// Constant `tempo_bpm`
+    pub fn fixed(tempo_bpm: u32, ticks_per_beat: u16) -> Self {
+        let ticks_per_second = ticks_per_beat as f64 * tempo_bpm.max(1) as f64 / 60.0;
+        TempoMap { ticks_per_beat, segments: vec![(0.0, 0.0, ticks_per_second)] }
+    }
+
+    /// Following `beats` (seconds, ascending), or at `tempo_bpm` when
+    /// there are fewer than two
+    ///
+    /// Audio before the first beat gets a whole number of beats at the
+    /// first beat's tempo, so beat one of the audio is on a MIDI beat;
+    /// after the last beat its tempo carries on.
+    pub fn new(beats: &[f64], tempo_bpm: u32, ticks_per_beat: u16) -> Self {
+        let mut beats: Vec<f64> = beats.iter().copied().filter(|&t| t >= 0.0).collect();
+        beats.dedup_by(|b, a| *b - *a <= 0.0);
+        if beats.len() < 2 {
+            return Self::fixed(tempo_bpm, ticks_per_beat);
+        }
+        let tpb = ticks_per_beat as f64;
+        let first_period = beats[1] - beats[0];
+        let lead_in = (beats[0] / first_period).round();
+        let mut segments = Vec::with_capacity(beats.len() + 1);
+        if lead_in >= 1.0 {
+            segments.push((0.0, 0.0, lead_in * tpb / beats[0]));
+        } else {
+            // Under half a beat in: count the first beat from the start
+            beats[0] = 0.0;
+        }
+        for (i, pair) in beats.windows(2).enumerate() {
+            segments.push((pair[0], (lead_in + i as f64) * tpb, tpb / (pair[1] - pair[0])));
+        }
+        let &(_, last_tick, last_rate) = segments.last().unwrap();
+        segments.push((beats[beats.len() - 1], last_tick + tpb, last_rate));
+        TempoMap { ticks_per_beat, segments }
+    }
+
+    pub fn ticks_per_beat(&self) -> u16 {
+        self.ticks_per_beat
+    }
+
+    /// Tick (fractional) at `seconds`
+    pub fn ticks(&self, seconds: f64) -> f64 {
+        let seconds = seconds.max(0.0);
+        let i = self.segments.partition_point(|&(start, _, _)| start <= seconds).max(1) - 1;
+        let (start, tick, rate) = self.segments[i];
+        tick + (seconds - start) * rate
+    }
+
+    /// Tempo track: a tempo event wherever the tempo changes
+    pub fn track(&self) -> Track<'static> {
+        let mut track = Track::new();
+        let mut now = 0u64;
+        let mut last_us = None;
+        for &(_, tick, rate) in &self.segments {
+            // Microseconds per beat, within what the 24-bit field holds
+            let us = (1e6 * self.ticks_per_beat as f64 / rate).round().clamp(1.0, 16_777_215.0) as u32;
+            if last_us == Some(us) {
+                continue;
+            }
+            let tick = tick.round() as u64;
+            track.push(TrackEvent {
+                delta: ((tick - now) as u32).into(),
+                kind: TrackEventKind::Meta(MetaMessage::Tempo(us.into())),
+            });
+            now = tick;
+            last_us = Some(us);
+        }
+        track.push(TrackEvent { delta: 0.into(), kind: TrackEventKind::Meta(MetaMessage::EndOfTrack) });
+        track
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::{export_notes_to_midi, read_midi_regions, TranscribedNote};
+
+    #[test]
+    fn test_tempo_map_follows_beats() {
+        // 0.3 s in, four beats at 120 BPM, then slowing to 100 BPM
+        let beats = [0.3, 0.8, 1.3, 1.8, 2.3, 2.9, 3.5];
+        let tempo = TempoMap::new(&beats, 120, 480);
+        // One beat of lead-in, so the first detected beat is MIDI beat two
+        for (i, &beat) in beats.iter().enumerate() {
+            assert!((tempo.ticks(beat) - 480.0 * (i + 1) as f64).abs() < 1e-6, "beat {i}");
+        }
+        assert!((tempo.ticks(4.1) - 480.0 * 8.0).abs() < 1e-6);
+        assert_eq!(TempoMap::new(&[1.0], 120, 480).ticks(1.0), 960.0);
+
+        // Written out, the tempo changes put notes back at the beats
+        let notes: Vec<TranscribedNote> = beats
+            .iter()
+            .map(|&t| TranscribedNote { start_time: t, end_time: t + 0.1, note: 60, velocity: 100 })
+            .collect();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("beats.mid");
+        export_notes_to_midi(&notes, "beats", &path, 120, &beats, 480, 0).unwrap();
+        let regions = read_midi_regions(&path).unwrap();
+        for (region, &beat) in regions.iter().zip(&beats) {
+            assert!((region.start_time - beat).abs() < 1e-3, "{:?}", region);
+        }
+        let smf_bytes = std::fs::read(&path).unwrap();
+        let smf = midly::Smf::parse(&smf_bytes).unwrap();
+        let tempos: Vec<u32> = smf.tracks[0]
+            .iter()
+            .filter_map(|e| match e.kind {
+                TrackEventKind::Meta(MetaMessage::Tempo(us)) => Some(us.as_int()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tempos, vec![300_000, 500_000, 600_000]);
+    }
+}
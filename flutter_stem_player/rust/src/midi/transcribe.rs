@@ -0,0 +1,237 @@
+//! Melody transcription: a pitch curve as MIDI notes
+//!
+//! A found vocal or bass phrase is most useful as notes that any
+//! instrument can play. The YIN f0 curve is cut into notes where it moves
+//! to another semitone, stops being voiced, or an onset re-attacks the
+//! same pitch; each note takes the median semitone of its frames and a
+//! velocity from its loudness. Export then snaps the notes to a grid.
+
+use super::{meta_event, TempoMap};
+use crate::fingerprint::AnalysisTrack;
+use crate::{AudioPaletteError, Result};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Timing, TrackEvent, TrackEventKind};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Shorter notes are dropped as glitches of the pitch tracker
+const MIN_NOTE_SECONDS: f64 = 0.06;
+/// How far (in semitones) the f0 may drift from a note's pitch before a
+/// new note starts; above 0.5 so vibrato doesn't split notes
+const PITCH_TOLERANCE: f64 = 0.8;
+/// Loudness mapped onto velocities 1-127, in dBFS
+const VELOCITY_FLOOR_DB: f64 = -48.0;
+
+/// A transcribed note
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscribedNote {
+    /// Bounds in seconds from the start of the sound
+    pub start_time: f64,
+    pub end_time: f64,
+    pub note: u8,
+    pub velocity: u8,
+}
+
+/// Fractional MIDI note number of a frequency
+pub(super) fn midi_pitch(f0: f64) -> f64 {
+    69.0 + 12.0 * (f0 / 440.0).log2()
+}
+
+/// Velocity from the RMS level of `samples`
+pub(super) fn velocity(samples: &[f32]) -> u8 {
+    if samples.is_empty() {
+        return 1;
+    }
+    let rms = (samples.iter().map(|&s| (s as f64).powi(2)).sum::<f64>() / samples.len() as f64).sqrt();
+    let db = 20.0 * rms.max(1e-9).log10();
+    (1.0 + 126.0 * (1.0 - db / VELOCITY_FLOOR_DB)).clamp(1.0, 127.0).round() as u8
+}
+
+/// Notes of a monophonic melody from its pitch curve, `onsets` (seconds)
+/// and mono audio (for velocities)
+pub fn transcribe_melody(
+    pitch: &AnalysisTrack,
+    onsets: &[f64],
+    samples: &[f32],
+    sample_rate: u32,
+) -> Vec<TranscribedNote> {
+    let hop = pitch.hop_seconds;
+    if hop <= 0.0 {
+        return Vec::new();
+    }
+    // (first frame, frames) of each run of one pitch
+    let mut runs: Vec<(usize, Vec<f64>)> = Vec::new();
+    let mut current: Option<(usize, Vec<f64>)> = None;
+    let mut onsets = onsets.iter().peekable();
+    for (frame, &f0) in pitch.values.iter().enumerate() {
+        let time = frame as f64 * hop;
+        let mut reattack = false;
+        while onsets.next_if(|&&onset| onset <= time).is_some() {
+            reattack = true;
+        }
+        let value = (f0 > 0.0).then(|| midi_pitch(f0));
+        let continues = match (&current, value) {
+            (Some((_, frames)), Some(value)) => {
+                let center = frames.iter().sum::<f64>() / frames.len() as f64;
+                (value - center.round()).abs() <= PITCH_TOLERANCE && !reattack
+            }
+            _ => false,
+        };
+        if continues {
+            current.as_mut().unwrap().1.push(value.unwrap());
+        } else {
+            runs.extend(current.take());
+            current = value.map(|value| (frame, vec![value]));
+        }
+    }
+    runs.extend(current);
+
+    runs.into_iter()
+        .filter(|(_, frames)| frames.len() as f64 * hop >= MIN_NOTE_SECONDS)
+        .map(|(first, mut frames)| {
+            frames.sort_by(f64::total_cmp);
+            let note = frames[frames.len() / 2].round().clamp(0.0, 127.0) as u8;
+            let (start_time, end_time) = (first as f64 * hop, (first + frames.len()) as f64 * hop);
+            let sample = |t: f64| ((t * sample_rate as f64) as usize).min(samples.len());
+            let velocity = velocity(&samples[sample(start_time)..sample(end_time)]);
+            TranscribedNote { start_time, end_time, note, velocity }
+        })
+        .collect()
+}
+
+/// Notes for one track of `write_note_tracks`
+pub(super) struct NoteTrack<'a> {
+    pub name: &'a str,
+    /// 0-15 (9 is General MIDI percussion)
+    pub channel: u8,
+    pub notes: &'a [TranscribedNote],
+}
+
+/// Write notes as a MIDI file at `tempo_bpm` (or following `beats`, when
+/// there are two or more), on one track named `track_name`, snapped to
+/// `quantize_division` steps per beat (0 leaves the timing as transcribed)
+pub fn export_notes_to_midi<P: AsRef<Path>>(
+    notes: &[TranscribedNote],
+    track_name: &str,
+    output_path: P,
+    tempo_bpm: u32,
+    beats: &[f64],
+    ticks_per_beat: u16,
+    quantize_division: u16,
+) -> Result<()> {
+    if notes.is_empty() {
+        return Err(AudioPaletteError::MidiError("No notes to export".to_string()));
+    }
+    let track = NoteTrack { name: track_name, channel: 0, notes };
+    let tempo = TempoMap::new(beats, tempo_bpm, ticks_per_beat);
+    write_note_tracks(&[track], output_path, &tempo, quantize_division)
+}
+
+/// Write `tempo` and then `tracks`, notes snapped to `quantize_division`
+/// steps per beat (0 leaves their timing alone)
+///
+/// Snapping keeps every note at least one step long and trims a note that
+/// would overlap the next of the same key on its track.
+pub(super) fn write_note_tracks<P: AsRef<Path>>(
+    tracks: &[NoteTrack],
+    output_path: P,
+    tempo: &TempoMap,
+    quantize_division: u16,
+) -> Result<()> {
+    let ticks_per_beat = tempo.ticks_per_beat();
+    let step = match quantize_division {
+        0 => 1,
+        division => (ticks_per_beat as u64 / division as u64).max(1),
+    };
+    let snap = |seconds: f64| ((tempo.ticks(seconds) / step as f64).round() as u64) * step;
+
+    let mut smf_tracks = vec![tempo.track()];
+    for track in tracks {
+        let mut spans: Vec<(u64, u64, &TranscribedNote)> = track
+            .notes
+            .iter()
+            .map(|n| (snap(n.start_time), snap(n.end_time).max(snap(n.start_time) + step), n))
+            .collect();
+        spans.sort_by_key(|&(start, _, n)| (n.note, start));
+        for i in 1..spans.len() {
+            if spans[i].2.note == spans[i - 1].2.note {
+                spans[i - 1].1 = spans[i - 1].1.min(spans[i].0);
+            }
+        }
+        // Note offs go before note ons at the same tick, so a key struck
+        // again isn't released straight away
+        let mut events: Vec<(u64, bool, &TranscribedNote)> = spans
+            .into_iter()
+            .filter(|&(start, end, _)| end > start)
+            .flat_map(|(start, end, n)| [(start, true, n), (end, false, n)])
+            .collect();
+        events.sort_by_key(|&(tick, on, _)| (tick, on));
+
+        let mut smf_track = vec![meta_event(MetaMessage::TrackName(track.name.as_bytes()))];
+        let mut now = 0u64;
+        for (tick, on, n) in events {
+            let key = n.note.min(127).into();
+            let message = if on {
+                MidiMessage::NoteOn { key, vel: n.velocity.clamp(1, 127).into() }
+            } else {
+                MidiMessage::NoteOff { key, vel: 0.into() }
+            };
+            let kind = TrackEventKind::Midi { channel: track.channel.min(15).into(), message };
+            smf_track.push(TrackEvent { delta: ((tick - now) as u32).into(), kind });
+            now = tick;
+        }
+        smf_track.push(meta_event(MetaMessage::EndOfTrack));
+        smf_tracks.push(smf_track);
+    }
+
+    let smf = Smf {
+        header: Header::new(Format::Parallel, Timing::Metrical(ticks_per_beat.into())),
+        tracks: smf_tracks,
+    };
+    smf.save(output_path).map_err(|e| AudioPaletteError::MidiError(format!("Failed to write MIDI: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::{detect_onsets, pitch_curve, AnalysisKind};
+    use crate::midi::read_midi_regions;
+
+    #[test]
+    fn test_transcribe_melody() {
+        // A3, C4 and E4, 0.4 s each, getting louder, then the E again
+        let sr = 22050;
+        let phrase = [(220.0, 0.1), (261.63, 0.2), (329.63, 0.4), (329.63, 0.4)];
+        let samples: Vec<f32> = phrase
+            .iter()
+            .flat_map(|&(freq, amp)| {
+                (0..(0.4 * sr as f64) as usize).map(move |i| {
+                    let t = i as f64 / sr as f64;
+                    // Short fades so each note starts with an attack
+                    let envelope = (t / 0.01).min(1.0) * ((0.4 - t) / 0.01).min(1.0);
+                    (amp * envelope * (std::f64::consts::TAU * freq * t).sin()) as f32
+                })
+            })
+            .collect();
+        let pitch = AnalysisTrack { kind: AnalysisKind::Pitch, hop_seconds: 0.01, values: pitch_curve(&samples, sr) };
+        let onsets = detect_onsets(&samples, sr);
+
+        let notes = transcribe_melody(&pitch, &onsets, &samples, sr);
+        assert_eq!(notes.iter().map(|n| n.note).collect::<Vec<_>>(), vec![57, 60, 64, 64]);
+        assert!(notes[0].velocity < notes[1].velocity && notes[1].velocity < notes[2].velocity);
+        for (i, note) in notes.iter().enumerate() {
+            assert!((note.start_time - 0.4 * i as f64).abs() < 0.06, "{:?}", note);
+        }
+
+        // At 150 BPM a sixteenth is 0.1 s, so each note spans four of them
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("melody.mid");
+        export_notes_to_midi(&notes, "melody", &path, 150, &[], 480, 4).unwrap();
+        let regions = read_midi_regions(&path).unwrap();
+        assert_eq!(regions.iter().map(|r| r.note).collect::<Vec<_>>(), vec![57, 60, 64, 64]);
+        for (i, region) in regions.iter().enumerate() {
+            assert!((region.start_time - 0.4 * i as f64).abs() < 1e-9, "{:?}", region);
+            assert_eq!(region.track_name, "melody");
+        }
+        assert!(export_notes_to_midi(&[], "melody", &path, 150, &[], 480, 4).is_err());
+    }
+}
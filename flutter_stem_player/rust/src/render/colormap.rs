@@ -0,0 +1,47 @@
+//! Colormaps for spectrogram rendering
+
+use serde::{Deserialize, Serialize};
+
+/// Available spectrogram colormaps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Colormap {
+    Grayscale,
+    Viridis,
+    Magma,
+    Inferno,
+}
+
+const VIRIDIS: [[u8; 3]; 5] = [[68, 1, 84], [59, 82, 139], [33, 145, 140], [94, 201, 98], [253, 231, 37]];
+const MAGMA: [[u8; 3]; 5] = [[0, 0, 4], [81, 18, 124], [183, 55, 121], [252, 137, 97], [252, 253, 191]];
+const INFERNO: [[u8; 3]; 5] = [[0, 0, 4], [87, 16, 110], [188, 55, 84], [249, 142, 9], [252, 255, 164]];
+
+impl Colormap {
+    /// Map a normalized value (0-1) to RGB
+    pub fn rgb(&self, value: f64) -> [u8; 3] {
+        let v = if value.is_finite() { value.clamp(0.0, 1.0) } else { 0.0 };
+        match self {
+            Colormap::Grayscale => {
+                let g = (v * 255.0).round() as u8;
+                [g, g, g]
+            }
+            Colormap::Viridis => interpolate(&VIRIDIS, v),
+            Colormap::Magma => interpolate(&MAGMA, v),
+            Colormap::Inferno => interpolate(&INFERNO, v),
+        }
+    }
+}
+
+/// Piecewise-linear interpolation between evenly spaced color stops
+fn interpolate(stops: &[[u8; 3]], v: f64) -> [u8; 3] {
+    let scaled = v * (stops.len() - 1) as f64;
+    let i = (scaled.floor() as usize).min(stops.len() - 2);
+    let t = scaled - i as f64;
+
+    let mut rgb = [0u8; 3];
+    for (c, out) in rgb.iter_mut().enumerate() {
+        let a = stops[i][c] as f64;
+        let b = stops[i + 1][c] as f64;
+        *out = (a + (b - a) * t).round() as u8;
+    }
+    rgb
+}
@@ -0,0 +1,194 @@
+//! Image rendering (spectrogram PNGs, waveform peaks, thumbnails) for the Flutter app and reports
+
+pub mod colormap;
+mod report;
+pub mod thumbnail;
+pub mod waveform;
+
+use crate::{AudioPaletteError, Result};
+use crate::audio::AudioData;
+use rayon::prelude::*;
+use rustfft::{FftPlanner, num_complex::Complex};
+
+pub use colormap::Colormap;
+pub use report::{export_match_report, ReportEntry};
+pub use thumbnail::{
+    render_thumbnails, render_waveform_png, Thumbnail, ThumbnailKind, MAX_ARTWORK_BYTES, THUMBNAIL_HEIGHT,
+    THUMBNAIL_WIDTH,
+};
+pub use waveform::{WaveformPeaks, DEFAULT_WAVEFORM_BUCKETS};
+
+/// Spectrogram rendering configuration
+#[derive(Debug, Clone)]
+pub struct SpectrogramConfig {
+    pub width: u32,
+    pub height: u32,
+    pub n_fft: usize,
+    pub colormap: Colormap,
+    /// Level mapped to the bottom of the colormap (dBFS)
+    pub min_db: f64,
+    /// Level mapped to the top of the colormap (dBFS)
+    pub max_db: f64,
+    /// Use a logarithmic frequency axis instead of linear
+    pub log_frequency: bool,
+}
+
+impl Default for SpectrogramConfig {
+    fn default() -> Self {
+        SpectrogramConfig {
+            width: 512,
+            height: 256,
+            n_fft: 2048,
+            colormap: Colormap::Magma,
+            min_db: -90.0,
+            max_db: 0.0,
+            log_frequency: true,
+        }
+    }
+}
+
+/// Lowest frequency shown on a logarithmic axis
+const LOG_MIN_FREQ: f64 = 20.0;
+
+/// Render a spectrogram of audio samples as PNG bytes
+pub fn render_spectrogram(samples: &[f32], sample_rate: u32, config: &SpectrogramConfig) -> Result<Vec<u8>> {
+    let pixels = spectrogram_pixels(samples, sample_rate, config)?;
+    encode_png(&pixels, config.width, config.height)
+}
+
+/// Render a spectrogram of a file (or a time range of it) as PNG bytes
+pub fn render_file_spectrogram(
+    filepath: &str,
+    start: Option<f64>,
+    end: Option<f64>,
+    config: &SpectrogramConfig,
+) -> Result<Vec<u8>> {
+    let audio = AudioData::load(filepath)?;
+    let sr = audio.sample_rate as f64;
+    let start_sample = (start.unwrap_or(0.0).max(0.0) * sr) as usize;
+    let end_sample = end.map(|e| (e * sr) as usize).unwrap_or(audio.samples.len());
+    let segment = audio.get_range(start_sample, end_sample);
+    render_spectrogram(&segment, audio.sample_rate, config)
+}
+
+/// Compute RGB pixel rows (top row = highest frequency)
+fn spectrogram_pixels(samples: &[f32], sample_rate: u32, config: &SpectrogramConfig) -> Result<Vec<u8>> {
+    if config.width == 0 || config.height == 0 {
+        return Err(AudioPaletteError::RenderError("Image size must be non-zero".to_string()));
+    }
+    if samples.is_empty() {
+        return Err(AudioPaletteError::RenderError("Empty audio".to_string()));
+    }
+    if config.max_db <= config.min_db {
+        return Err(AudioPaletteError::RenderError("max_db must exceed min_db".to_string()));
+    }
+
+    let n_fft = config.n_fft.max(16);
+    let width = config.width as usize;
+    let height = config.height as usize;
+    let n_bins = n_fft / 2 + 1;
+    let nyquist = sample_rate as f64 / 2.0;
+
+    let mut planner = FftPlanner::<f64>::new();
+    let fft = planner.plan_fft_forward(n_fft);
+    let window: Vec<f64> = (0..n_fft)
+        .map(|i| 0.5 * (1.0 - (2.0 * std::f64::consts::PI * i as f64 / (n_fft - 1) as f64).cos()))
+        .collect();
+    // Full-scale sine peaks at n_fft / 4 with a Hann window
+    let reference = n_fft as f64 / 4.0;
+
+    // Frequency bin range covered by each pixel row (row 0 = bottom)
+    let row_bins: Vec<(usize, usize)> = (0..height)
+        .map(|row| {
+            let edge = |r: f64| -> f64 {
+                let t = r / height as f64;
+                if config.log_frequency {
+                    LOG_MIN_FREQ * (nyquist / LOG_MIN_FREQ).powf(t)
+                } else {
+                    t * nyquist
+                }
+            };
+            let lo = (edge(row as f64) / nyquist * (n_bins - 1) as f64).floor() as usize;
+            let hi = (edge(row as f64 + 1.0) / nyquist * (n_bins - 1) as f64).ceil() as usize;
+            (lo.min(n_bins - 1), hi.clamp(lo + 1, n_bins))
+        })
+        .collect();
+
+    let last_start = samples.len().saturating_sub(n_fft);
+    let columns: Vec<Vec<[u8; 3]>> = (0..width)
+        .into_par_iter()
+        .map(|x| {
+            let start = if width > 1 { x * last_start / (width - 1) } else { 0 };
+            let mut buffer: Vec<Complex<f64>> = (0..n_fft)
+                .map(|i| {
+                    let s = samples.get(start + i).copied().unwrap_or(0.0) as f64;
+                    Complex::new(s * window[i], 0.0)
+                })
+                .collect();
+            fft.process(&mut buffer);
+
+            row_bins
+                .iter()
+                .map(|&(lo, hi)| {
+                    let mag = buffer[lo..hi].iter().map(|c| c.norm()).fold(0.0_f64, f64::max);
+                    let db = 20.0 * (mag / reference).max(1e-12).log10();
+                    config.colormap.rgb((db - config.min_db) / (config.max_db - config.min_db))
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        let row = height - 1 - y;
+        for column in &columns {
+            pixels.extend_from_slice(&column[row]);
+        }
+    }
+    Ok(pixels)
+}
+
+/// Encode packed RGB pixels as PNG
+pub(crate) fn encode_png(pixels: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
+    encode(pixels, width, height, png::ColorType::Rgb)
+}
+
+/// Encode packed RGBA pixels as PNG
+pub(crate) fn encode_png_rgba(pixels: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
+    encode(pixels, width, height, png::ColorType::Rgba)
+}
+
+fn encode(pixels: &[u8], width: u32, height: u32, color: png::ColorType) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(color);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| AudioPaletteError::RenderError(e.to_string()))?;
+        writer
+            .write_image_data(pixels)
+            .map_err(|e| AudioPaletteError::RenderError(e.to_string()))?;
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_spectrogram_png() {
+        let samples: Vec<f32> = (0..22050).map(|i| (i as f32 * 0.1).sin() * 0.5).collect();
+        let config = SpectrogramConfig { width: 64, height: 32, ..SpectrogramConfig::default() };
+
+        let png_bytes = render_spectrogram(&samples, 44100, &config).unwrap();
+        assert_eq!(&png_bytes[1..4], b"PNG");
+
+        let decoder = png::Decoder::new(png_bytes.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!(reader.info().width, 64);
+        assert_eq!(reader.info().height, 32);
+    }
+}
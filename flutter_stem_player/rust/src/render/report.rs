@@ -0,0 +1,243 @@
+//! Match reports for sharing results
+//!
+//! A report is a single HTML file with everything inlined (styles, and the
+//! waveform and spectrogram of each match as data URIs), so it can be
+//! mailed or dropped in a shared folder and opened anywhere, by people
+//! who don't run the app. Its print styles keep each match on one page,
+//! so printing to PDF from a browser gives a clean PDF version.
+
+use super::{render_spectrogram, render_waveform_png, SpectrogramConfig, WaveformPeaks};
+use crate::audio::AudioData;
+use crate::{MatchResult, Result, SoundRecord};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rayon::prelude::*;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Size of the images drawn for each match
+const IMAGE_WIDTH: u32 = 480;
+const WAVEFORM_HEIGHT: u32 = 80;
+const SPECTROGRAM_HEIGHT: u32 = 120;
+
+const STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2em auto; max-width: 56em; color: #222; }
+h1 { margin-bottom: 0.2em; }
+.summary { color: #666; margin-top: 0; }
+.match { border-top: 1px solid #ddd; padding: 1em 0; }
+.match h2 { font-size: 1.1em; margin: 0; }
+.score { float: right; color: #2a7; }
+.path { color: #666; font-size: 0.85em; word-break: break-all; margin: 0.2em 0 0.8em; }
+.images img { display: block; width: 100%; max-width: 480px; margin-bottom: 4px; background: #f4f4f4; }
+.missing { color: #a44; font-style: italic; }
+dl { display: grid; grid-template-columns: 8em 1fr; gap: 0.2em 1em; margin: 0.8em 0 0; }
+dt { color: #666; }
+dd { margin: 0; }
+@media print { body { margin: 0; } .match { break-inside: avoid; } }
+";
+
+/// A match to report, with what's known of its sound
+#[derive(Debug, Clone)]
+pub struct ReportEntry {
+    pub result: MatchResult,
+    /// None when the sound is no longer in the palette
+    pub sound: Option<SoundRecord>,
+    pub tags: Vec<String>,
+}
+
+/// `text` escaped for HTML
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Seconds as M:SS.mmm
+fn clock(seconds: f64) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!("{}:{:02}.{:03}", millis / 60_000, millis / 1000 % 60, millis % 1000)
+}
+
+/// Waveform and spectrogram PNGs of a match's range, or None when its
+/// file can't be decoded
+fn match_images(m: &MatchResult) -> Option<(Vec<u8>, Vec<u8>)> {
+    let audio = AudioData::load(&m.filepath).ok()?;
+    let sr = audio.sample_rate as f64;
+    let samples = if m.match_end > m.match_start {
+        audio.get_range((m.match_start * sr) as usize, (m.match_end * sr) as usize)
+    } else {
+        audio.samples
+    };
+    if samples.is_empty() {
+        return None;
+    }
+    let peaks = WaveformPeaks::compute(&samples, IMAGE_WIDTH as usize);
+    let config = SpectrogramConfig { width: IMAGE_WIDTH, height: SPECTROGRAM_HEIGHT, ..SpectrogramConfig::default() };
+    Some((
+        render_waveform_png(&peaks, IMAGE_WIDTH, WAVEFORM_HEIGHT).ok()?,
+        render_spectrogram(&samples, audio.sample_rate, &config).ok()?,
+    ))
+}
+
+/// Export a self-contained HTML report of matches titled `title`, with a
+/// waveform and spectrogram of each match when `images` (which decodes
+/// every file)
+pub fn export_match_report<P: AsRef<Path>>(
+    entries: &[ReportEntry],
+    title: &str,
+    images: bool,
+    output_path: P,
+) -> Result<()> {
+    let rendered: Vec<Option<(Vec<u8>, Vec<u8>)>> = if images {
+        entries.par_iter().map(|e| match_images(&e.result)).collect()
+    } else {
+        Vec::new()
+    };
+
+    let title = html_escape(title);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n\
+         <p class=\"summary\">{} match{} &middot; Audio Palette {}</p>\n",
+        entries.len(),
+        if entries.len() == 1 { "" } else { "es" },
+        env!("CARGO_PKG_VERSION"),
+    );
+    for (i, entry) in entries.iter().enumerate() {
+        let m = &entry.result;
+        let _ = write!(
+            html,
+            "<section class=\"match\">\n<h2>{}. {} <span class=\"score\">{:.1}%</span></h2>\n<p class=\"path\">{}</p>\n",
+            i + 1,
+            html_escape(&m.filename),
+            m.score,
+            html_escape(&m.filepath),
+        );
+        if images {
+            match rendered.get(i).and_then(Option::as_ref) {
+                Some((waveform, spectrogram)) => {
+                    let _ = write!(
+                        html,
+                        "<div class=\"images\">\n<img alt=\"Waveform\" src=\"data:image/png;base64,{}\">\n\
+                         <img alt=\"Spectrogram\" src=\"data:image/png;base64,{}\">\n</div>\n",
+                        BASE64.encode(waveform),
+                        BASE64.encode(spectrogram),
+                    );
+                }
+                None => html.push_str("<p class=\"missing\">Audio unavailable</p>\n"),
+            }
+        }
+
+        let mut details: Vec<(&str, String)> = Vec::new();
+        if m.match_end > m.match_start {
+            let range = format!("{} &ndash; {}", clock(m.match_start), clock(m.match_end));
+            details.push(("Match", format!("{range} ({:.3} s)", m.match_end - m.match_start)));
+        } else {
+            details.push(("Match", "Whole sound".to_string()));
+        }
+        details.push(("Duration", clock(m.file_duration)));
+        if let Some(sound) = &entry.sound {
+            let format = format!("{}, {} Hz, {} ch", sound.format.to_uppercase(), sound.sample_rate, sound.channels);
+            details.push(("Format", html_escape(&format)));
+            for (label, text) in [("Title", &sound.title), ("Artist", &sound.artist), ("Source", &sound.source)] {
+                if !text.is_empty() {
+                    details.push((label, html_escape(text)));
+                }
+            }
+            if sound.bpm > 0.0 {
+                details.push(("Tempo", format!("{:.0} BPM", sound.bpm)));
+            }
+            if !sound.musical_key.is_empty() {
+                details.push(("Key", html_escape(&sound.musical_key)));
+            }
+            if sound.rating > 0 {
+                let rating = sound.rating.min(5) as usize;
+                details.push(("Rating", format!("{}{}", "&#9733;".repeat(rating), "&#9734;".repeat(5 - rating))));
+            }
+        }
+        if !entry.tags.is_empty() {
+            details.push(("Tags", html_escape(&entry.tags.join(", "))));
+        }
+        if let Some(sound) = entry.sound.as_ref().filter(|s| !s.notes.is_empty()) {
+            details.push(("Notes", html_escape(&sound.notes)));
+        }
+        html.push_str("<dl>\n");
+        for (label, value) in details {
+            let _ = writeln!(html, "<dt>{label}</dt><dd>{value}</dd>");
+        }
+        html.push_str("</dl>\n</section>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+
+    std::fs::write(output_path, html)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::test_match;
+    use crate::audio::write_test_wav;
+
+    #[test]
+    fn test_match_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let samples: Vec<f32> =
+            (0..22050).map(|i| (std::f32::consts::TAU * 440.0 * i as f32 / 22050.0).sin() * 0.366).collect();
+        let tone = write_test_wav(dir.path(), "tone & noise.wav", &samples, 22050);
+
+        let hit =
+            |filepath: &Path, start: f64, end: f64| test_match(&filepath.to_string_lossy(), 93.25, start, end, 1.0);
+        let sound = SoundRecord {
+            id: 1,
+            filepath: tone.to_string_lossy().to_string(),
+            filename: "tone & noise.wav".to_string(),
+            duration: 1.0,
+            sample_rate: 22050,
+            channels: 1,
+            format: "wav".to_string(),
+            date_added: String::new(),
+            title: "Test <tone>".to_string(),
+            artist: String::new(),
+            notes: String::new(),
+            source: String::new(),
+            rating: 4,
+            bpm: 120.0,
+            musical_key: "A major".to_string(),
+        };
+        let entries = vec![
+            ReportEntry { result: hit(&tone, 0.25, 0.75), sound: Some(sound), tags: vec!["synth".to_string()] },
+            ReportEntry { result: hit(&dir.path().join("gone.wav"), 0.0, 0.0), sound: None, tags: Vec::new() },
+        ];
+        let path = dir.path().join("report.html");
+        export_match_report(&entries, "Results for \"kick\"", true, &path).unwrap();
+
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(html.contains("<h1>Results for &quot;kick&quot;</h1>"));
+        assert!(html.contains("2 matches"));
+        assert!(html.contains("1. tone &amp; noise.wav <span class=\"score\">93.2%</span>"));
+        // Images of the first match; the second's file is gone
+        assert_eq!(html.matches("src=\"data:image/png;base64,").count(), 2);
+        assert_eq!(html.matches("Audio unavailable").count(), 1);
+        assert!(html.contains("<dt>Match</dt><dd>0:00.250 &ndash; 0:00.750 (0.500 s)</dd>"));
+        assert!(html.contains("<dt>Format</dt><dd>WAV, 22050 Hz, 1 ch</dd>"));
+        assert!(html.contains("<dt>Title</dt><dd>Test &lt;tone&gt;</dd>"));
+        assert!(html.contains("<dt>Tempo</dt><dd>120 BPM</dd>") && html.contains("<dt>Key</dt><dd>A major</dd>"));
+        assert!(html.contains("<dt>Rating</dt><dd>&#9733;&#9733;&#9733;&#9733;&#9734;</dd>"));
+        assert!(html.contains("<dt>Tags</dt><dd>synth</dd>"));
+        assert!(html.contains("<dt>Match</dt><dd>Whole sound</dd>"));
+
+        // Without images nothing is decoded
+        export_match_report(&entries, "Results", false, &path).unwrap();
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(!html.contains("data:image") && !html.contains("Audio unavailable"));
+    }
+}
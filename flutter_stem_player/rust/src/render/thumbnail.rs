@@ -0,0 +1,113 @@
+//! Small preview images for grid views
+//!
+//! Waveform and spectrogram thumbnails are rendered at index time and
+//! cached in the database so a grid of sounds can be drawn without
+//! decoding any audio. Cover art embedded in the file (ID3, FLAC and
+//! Vorbis pictures) is kept as-is.
+
+use super::{encode_png_rgba, render_spectrogram, SpectrogramConfig, WaveformPeaks};
+use crate::audio::AudioData;
+use crate::{AudioPaletteError, Result};
+use serde::{Deserialize, Serialize};
+
+pub const THUMBNAIL_WIDTH: u32 = 160;
+pub const THUMBNAIL_HEIGHT: u32 = 64;
+
+/// Embedded artwork larger than this is not cached
+pub const MAX_ARTWORK_BYTES: usize = 512 * 1024;
+
+/// Waveform colour on a transparent background
+const WAVEFORM_RGBA: [u8; 4] = [224, 224, 224, 255];
+
+/// Kind of thumbnail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ThumbnailKind {
+    Waveform,
+    Spectrogram,
+    /// Cover art embedded in the file, if any
+    Artwork,
+}
+
+impl ThumbnailKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ThumbnailKind::Waveform => "waveform",
+            ThumbnailKind::Spectrogram => "spectrogram",
+            ThumbnailKind::Artwork => "artwork",
+        }
+    }
+}
+
+/// An encoded image
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Thumbnail {
+    pub kind: ThumbnailKind,
+    /// e.g. "image/png", or the artwork's own type
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Draw waveform peaks as a PNG, one column per pixel
+pub fn render_waveform_png(peaks: &WaveformPeaks, width: u32, height: u32) -> Result<Vec<u8>> {
+    if width == 0 || height == 0 {
+        return Err(AudioPaletteError::RenderError("Image size must be non-zero".to_string()));
+    }
+    let (w, h) = (width as usize, height as usize);
+    let mut pixels = vec![0u8; w * h * 4];
+    let row = |value: f32| (((1.0 - value) / 2.0) * (h as f32 - 1.0)).round() as usize;
+    for x in 0..w {
+        // Columns span one or more buckets
+        let start = x * peaks.buckets() / w;
+        let end = ((x + 1) * peaks.buckets() / w).max(start + 1).min(peaks.buckets());
+        let lo = peaks.min[start..end].iter().copied().fold(f32::MAX, f32::min);
+        let hi = peaks.max[start..end].iter().copied().fold(f32::MIN, f32::max);
+        for y in row(hi)..=row(lo).min(h - 1) {
+            pixels[(y * w + x) * 4..][..4].copy_from_slice(&WAVEFORM_RGBA);
+        }
+    }
+    encode_png_rgba(&pixels, width, height)
+}
+
+/// Waveform and spectrogram thumbnails of decoded audio
+pub fn render_thumbnails(audio: &AudioData, peaks: &WaveformPeaks) -> Result<Vec<Thumbnail>> {
+    let config = SpectrogramConfig {
+        width: THUMBNAIL_WIDTH,
+        height: THUMBNAIL_HEIGHT,
+        n_fft: 1024,
+        ..SpectrogramConfig::default()
+    };
+    let mut thumbnails = vec![Thumbnail {
+        kind: ThumbnailKind::Waveform,
+        mime_type: "image/png".to_string(),
+        data: render_waveform_png(peaks, THUMBNAIL_WIDTH, THUMBNAIL_HEIGHT)?,
+    }];
+    if !audio.samples.is_empty() {
+        thumbnails.push(Thumbnail {
+            kind: ThumbnailKind::Spectrogram,
+            mime_type: "image/png".to_string(),
+            data: render_spectrogram(&audio.samples, audio.sample_rate, &config)?,
+        });
+    }
+    Ok(thumbnails)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_waveform_png() {
+        // Silence then a full-scale burst
+        let samples: Vec<f32> = (0..2000).map(|i| if i < 1000 { 0.0 } else { (i as f32 * 0.3).sin() }).collect();
+        let png_bytes = render_waveform_png(&WaveformPeaks::compute(&samples, 512), 40, 21).unwrap();
+
+        let mut reader = png::Decoder::new(png_bytes.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (40, 21));
+        let alpha = |x: usize, y: usize| pixels[(y * 40 + x) * 4 + 3];
+        // Silent columns only mark the centre line; loud ones span the height
+        assert_eq!((alpha(5, 0), alpha(5, 10), alpha(5, 20)), (0, 255, 0));
+        assert_eq!((alpha(30, 0), alpha(30, 10), alpha(30, 20)), (255, 255, 255));
+    }
+}
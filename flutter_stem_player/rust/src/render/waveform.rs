@@ -0,0 +1,102 @@
+//! Waveform overviews (min/max peaks per column) for the sound browser
+//!
+//! Peaks are cheap to draw at any width up to their bucket count and small
+//! enough to cache in the database: they're stored as 16-bit pairs, so a
+//! 512-bucket overview is about 2 KB.
+
+use crate::{AudioPaletteError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Bucket count computed at index time
+pub const DEFAULT_WAVEFORM_BUCKETS: usize = 512;
+
+/// Minimum and maximum sample value for each of `min.len()` equal slices
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveformPeaks {
+    pub min: Vec<f32>,
+    pub max: Vec<f32>,
+}
+
+impl WaveformPeaks {
+    /// Compute peaks from mono samples
+    ///
+    /// Audio shorter than `buckets` samples yields one bucket per sample.
+    pub fn compute(samples: &[f32], buckets: usize) -> Self {
+        let buckets = buckets.min(samples.len()).max(1);
+        let mut peaks = WaveformPeaks {
+            min: Vec::with_capacity(buckets),
+            max: Vec::with_capacity(buckets),
+        };
+        if samples.is_empty() {
+            peaks.min.push(0.0);
+            peaks.max.push(0.0);
+            return peaks;
+        }
+
+        for i in 0..buckets {
+            let start = i * samples.len() / buckets;
+            let end = ((i + 1) * samples.len() / buckets).max(start + 1);
+            let (lo, hi) = samples[start..end]
+                .iter()
+                .fold((f32::MAX, f32::MIN), |(lo, hi), &s| (lo.min(s), hi.max(s)));
+            peaks.min.push(lo.clamp(-1.0, 1.0));
+            peaks.max.push(hi.clamp(-1.0, 1.0));
+        }
+        peaks
+    }
+
+    pub fn buckets(&self) -> usize {
+        self.min.len()
+    }
+
+    /// Encode as interleaved little-endian i16 (min, max) pairs
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.buckets() * 4);
+        for (&lo, &hi) in self.min.iter().zip(&self.max) {
+            out.extend_from_slice(&quantize(lo).to_le_bytes());
+            out.extend_from_slice(&quantize(hi).to_le_bytes());
+        }
+        out
+    }
+
+    /// Decode a blob produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() || !bytes.len().is_multiple_of(4) {
+            return Err(AudioPaletteError::RenderError("Malformed waveform blob".to_string()));
+        }
+        let values: Vec<f32> = bytes
+            .chunks_exact(2)
+            .map(|c| i16::from_le_bytes([c[0], c[1]]) as f32 / i16::MAX as f32)
+            .collect();
+        Ok(WaveformPeaks {
+            min: values.iter().step_by(2).copied().collect(),
+            max: values.iter().skip(1).step_by(2).copied().collect(),
+        })
+    }
+}
+
+fn quantize(value: f32) -> i16 {
+    (value.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peaks_round_trip() {
+        let samples: Vec<f32> = (0..1000).map(|i| ((i as f32) * 0.05).sin() * (i as f32 / 1000.0)).collect();
+        let peaks = WaveformPeaks::compute(&samples, 10);
+        assert_eq!(peaks.buckets(), 10);
+        assert!(peaks.min.iter().zip(&peaks.max).all(|(lo, hi)| lo <= hi));
+        // The envelope grows towards the end
+        assert!(peaks.max[9] > peaks.max[0]);
+
+        let decoded = WaveformPeaks::from_bytes(&peaks.to_bytes()).unwrap();
+        assert_eq!(decoded.buckets(), 10);
+        assert!(decoded.max.iter().zip(&peaks.max).all(|(a, b)| (a - b).abs() < 1e-4));
+        assert!(WaveformPeaks::from_bytes(&[1, 2, 3]).is_err());
+
+        assert_eq!(WaveformPeaks::compute(&[0.5, -0.5], 512).buckets(), 2);
+    }
+}
@@ -0,0 +1,130 @@
+//! Sample-accurate alignment via normalized cross-correlation
+
+use rustfft::{FftPlanner, num_complex::Complex};
+
+/// Normalized cross-correlation of `query` against every offset of `target`
+///
+/// Returns one coefficient in [-1, 1] per lag `0..=target.len() - query.len()`,
+/// computed with FFTs so long windows stay cheap. Empty if the query is
+/// longer than the target.
+pub fn normalized_cross_correlation(query: &[f32], target: &[f32]) -> Vec<f64> {
+    let n = query.len();
+    if n == 0 || target.len() < n {
+        return Vec::new();
+    }
+    let n_lags = target.len() - n + 1;
+
+    let fft_len = (target.len() + n).next_power_of_two();
+    let mut planner = FftPlanner::<f64>::new();
+    let fft = planner.plan_fft_forward(fft_len);
+    let ifft = planner.plan_fft_inverse(fft_len);
+
+    let mut q: Vec<Complex<f64>> = query.iter().map(|&x| Complex::new(x as f64, 0.0)).collect();
+    q.resize(fft_len, Complex::new(0.0, 0.0));
+    let mut t: Vec<Complex<f64>> = target.iter().map(|&x| Complex::new(x as f64, 0.0)).collect();
+    t.resize(fft_len, Complex::new(0.0, 0.0));
+
+    fft.process(&mut q);
+    fft.process(&mut t);
+
+    // Cross-correlation = IFFT(T * conj(Q))
+    let mut xcorr: Vec<Complex<f64>> = t.iter().zip(q.iter()).map(|(a, b)| a * b.conj()).collect();
+    ifft.process(&mut xcorr);
+
+    // Normalize by query energy and sliding target energy
+    let query_energy: f64 = query.iter().map(|&x| (x as f64).powi(2)).sum();
+    let mut prefix = Vec::with_capacity(target.len() + 1);
+    prefix.push(0.0);
+    for &x in target {
+        prefix.push(prefix.last().copied().unwrap_or(0.0) + (x as f64).powi(2));
+    }
+
+    (0..n_lags)
+        .map(|lag| {
+            let target_energy = prefix[lag + n] - prefix[lag];
+            let denom = (query_energy * target_energy).sqrt();
+            if denom <= 1e-12 {
+                0.0
+            } else {
+                (xcorr[lag].re / fft_len as f64 / denom).clamp(-1.0, 1.0)
+            }
+        })
+        .collect()
+}
+
+/// Find the offset in `target` within `[search_start, search_end]` where
+/// `query` correlates best
+///
+/// Returns `(offset, correlation)` with the offset in target samples.
+pub fn refine_offset(
+    query: &[f32],
+    target: &[f32],
+    search_start: usize,
+    search_end: usize,
+) -> Option<(usize, f64)> {
+    let last_offset = target.len().checked_sub(query.len())?;
+    let start = search_start.min(last_offset);
+    let end = search_end.min(last_offset);
+    if end < start {
+        return None;
+    }
+
+    let region = &target[start..end + query.len()];
+    normalized_cross_correlation(query, region)
+        .into_iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(lag, corr)| (start + lag, corr))
+}
+
+/// Target samples correlated per FFT block by `locate` (at least four
+/// query lengths), so long files don't need one huge transform
+const LOCATE_BLOCK: usize = 1 << 16;
+
+/// Find the offset anywhere in `target` where `query` correlates best
+///
+/// Works through `target` in overlapping blocks; equivalent to
+/// `refine_offset` over the whole target. Returns `(offset, correlation)`.
+pub fn locate(query: &[f32], target: &[f32]) -> Option<(usize, f64)> {
+    let n = query.len();
+    if n == 0 || target.len() < n {
+        return None;
+    }
+    let block = LOCATE_BLOCK.max(n * 4);
+    let step = block - n + 1;
+    (0..=target.len() - n)
+        .step_by(step)
+        .filter_map(|start| {
+            let region = &target[start..(start + block).min(target.len())];
+            normalized_cross_correlation(query, region)
+                .into_iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(lag, corr)| (start + lag, corr))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refine_offset_sample_accurate() {
+        let target: Vec<f32> = (0..4000)
+            .map(|i| ((i as f32 * 0.031).sin() + (i as f32 * 0.173).cos()) * (1.0 + (i % 7) as f32 * 0.1))
+            .collect();
+        let query = target[1237..1737].to_vec();
+
+        let (offset, corr) = refine_offset(&query, &target, 1100, 1400).unwrap();
+        assert_eq!(offset, 1237);
+        assert!(corr > 0.999);
+
+        // Across block boundaries of a long target
+        let long: Vec<f32> = (0..200_000).map(|i| ((i as f32 * 0.7).sin() * 1e4).fract()).collect();
+        let straddling = long[65_300..65_800].to_vec();
+        let (offset, corr) = locate(&straddling, &long).unwrap();
+        assert_eq!(offset, 65_300);
+        assert!(corr > 0.999);
+    }
+}
@@ -0,0 +1,188 @@
+//! Batch similarity search
+//!
+//! Matching a whole folder against the palette one `find_similar` call at
+//! a time decodes a file once per query taken from it and looks up the
+//! same popular sounds over and over. A batch decodes each distinct file
+//! once, fingerprints and ranks the queries in parallel, and looks up each
+//! matched sound once however many queries it matches.
+
+use super::SearchEngine;
+use crate::audio::AudioData;
+use crate::database::{PaletteDatabase, VectorMatch};
+use crate::fingerprint::AudioFingerprint;
+use crate::{MatchResult, Result};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// One query of a batch: a file, or a time range of it
+#[derive(Debug, Clone)]
+pub struct BatchQuery {
+    pub path: String,
+    /// Range in seconds (the whole file if either end is None)
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+}
+
+/// Results of one batch query
+#[derive(Debug, Clone)]
+pub struct BatchMatches {
+    pub query: BatchQuery,
+    /// Best first
+    pub matches: Vec<MatchResult>,
+    /// Why the query couldn't be fingerprinted (its matches are then empty)
+    pub error: Option<String>,
+}
+
+impl SearchEngine {
+    /// Find similar sounds for many queries in one pass; results line up
+    /// with `queries`
+    ///
+    /// A query whose file can't be decoded reports the error in its
+    /// `BatchMatches` instead of failing the batch.
+    pub fn find_similar_batch(
+        &self,
+        queries: &[BatchQuery],
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<BatchMatches>> {
+        let fingerprints = self.fingerprint_batch(queries)?;
+        let shortlists = self.gpu_shortlists(&fingerprints, db, max_results);
+
+        let scored: Vec<Option<Vec<VectorMatch>>> = fingerprints
+            .par_iter()
+            .enumerate()
+            .map(|(i, fp)| {
+                self.check_cancelled()?;
+                let among = shortlists.as_ref().map(|lists| lists[i].as_slice());
+                fp.as_ref().ok().map(|fp| self.nearest(fp, db, among, threshold, max_results)).transpose()
+            })
+            .collect::<Result<_>>()?;
+
+        let matched: HashSet<i64> = scored.iter().flatten().flatten().map(|m| m.sound_id).collect();
+        let mut sounds = HashMap::new();
+        for sound_id in matched {
+            self.check_cancelled()?;
+            if let Ok(Some(sound)) = db.get_sound(sound_id) {
+                sounds.insert(sound_id, sound);
+            }
+        }
+
+        Ok(queries
+            .iter()
+            .zip(fingerprints)
+            .zip(scored)
+            .map(|((query, fp), scored)| {
+                let matches = scored
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|VectorMatch { sound_id, score, .. }| {
+                        let sound = sounds.get(&sound_id)?;
+                        Some(MatchResult {
+                            sound_id,
+                            filepath: sound.filepath.clone(),
+                            filename: sound.filename.clone(),
+                            score,
+                            match_start: 0.0,
+                            match_end: sound.duration,
+                            file_duration: sound.duration,
+                            time_resolution: 0.0,
+                        })
+                    })
+                    .collect();
+                BatchMatches { query: query.clone(), matches, error: fp.err() }
+            })
+            .collect())
+    }
+
+    /// GPU shortlists lining up with `fingerprints` (empty for failed
+    /// ones), or None to score every query on the CPU
+    fn gpu_shortlists(
+        &self,
+        fingerprints: &[std::result::Result<AudioFingerprint, String>],
+        db: &PaletteDatabase,
+        max_results: usize,
+    ) -> Option<Vec<Vec<i64>>> {
+        // Masked searches compare part of each vector; the GPU has whole ones
+        let gpu = self.config.gpu.as_ref().filter(|_| self.config.feature_mask.is_empty())?;
+        if let Err(e) = gpu.sync(db) {
+            log::warn!("GPU scorer couldn't catch up with the library, using the CPU: {}", e);
+            return None;
+        }
+        let queries: Vec<&AudioFingerprint> = fingerprints.iter().flatten().collect();
+        let mut shortlists = match gpu.shortlists(&queries, max_results) {
+            Ok(shortlists) => shortlists.into_iter(),
+            Err(e) => {
+                log::warn!("GPU scoring failed, using the CPU: {}", e);
+                return None;
+            }
+        };
+        Some(fingerprints.iter().map(|fp| if fp.is_ok() { shortlists.next().unwrap_or_default() } else { Vec::new() }).collect())
+    }
+
+    /// Fingerprint every query (or say why it can't be), decoding each
+    /// distinct file once
+    fn fingerprint_batch(&self, queries: &[BatchQuery]) -> Result<Vec<std::result::Result<AudioFingerprint, String>>> {
+        let paths: HashSet<&str> = queries.iter().map(|q| q.path.as_str()).collect();
+        let decoded: HashMap<&str, Result<AudioData>> = paths
+            .into_par_iter()
+            .map(|path| {
+                self.check_cancelled()?;
+                Ok((path, AudioData::load(path)))
+            })
+            .collect::<Result<_>>()?;
+
+        queries
+            .par_iter()
+            .map(|query| {
+                self.check_cancelled()?;
+                let audio = decoded[query.path.as_str()].as_ref().map_err(|e| e.to_string());
+                Ok(audio.and_then(|audio| {
+                    let fingerprint = match (query.start_time, query.end_time) {
+                        (Some(start), Some(end)) => {
+                            let rate = audio.sample_rate as f64;
+                            let segment =
+                                audio.get_range((start.max(0.0) * rate) as usize, (end.max(0.0) * rate) as usize);
+                            self.fingerprinter.extract_from_samples(&segment, audio.sample_rate)
+                        }
+                        _ => self.fingerprinter.extract(audio),
+                    };
+                    fingerprint.map_err(|e| e.to_string())
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::write_test_wav;
+
+    #[test]
+    fn test_find_similar_batch() {
+        let tone = |freq: f32, i: usize| (2.0 * std::f32::consts::PI * freq * i as f32 / 22050.0).sin() * 0.5;
+        let dir = tempfile::tempdir().unwrap();
+        let samples: Vec<f32> = (0..44100).map(|i| tone(if i < 22050 { 220.0 } else { 3000.0 }, i)).collect();
+        let take = write_test_wav(dir.path(), "take.wav", &samples, 22050);
+
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut ids = Vec::new();
+        for (name, freq) in [("low", 220.0), ("high", 3000.0)] {
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, 1.0, 22050, 1, "wav").unwrap();
+            let samples: Vec<f32> = (0..22050).map(|i| tone(freq, i)).collect();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&samples, 22050).unwrap()).unwrap();
+            ids.push(id);
+        }
+
+        let take = take.to_str().unwrap().to_string();
+        let range = |start, end| BatchQuery { path: take.clone(), start_time: Some(start), end_time: Some(end) };
+        let missing = BatchQuery { path: "/nowhere/gone.wav".to_string(), start_time: None, end_time: None };
+        let results = engine.find_similar_batch(&[range(0.0, 1.0), range(1.0, 2.0), missing], &db, 0.0, 1).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].matches[0].sound_id, ids[0]);
+        assert_eq!(results[1].matches[0].sound_id, ids[1]);
+        assert!(results[2].matches.is_empty() && results[2].error.is_some());
+    }
+}
@@ -0,0 +1,83 @@
+//! Best-so-far results for streaming searches
+//!
+//! Searches offer each match here as soon as it is scored. Whenever one
+//! makes the top `max_results`, the observer is handed the new top list,
+//! so the UI can show partial results while the rest of the candidates
+//! are still being scored.
+
+use crate::MatchResult;
+use std::cmp::Ordering;
+use std::sync::Mutex;
+
+pub(crate) struct BestResults<F> {
+    threshold: f64,
+    max_results: usize,
+    /// Best first by `MatchResult::rank_cmp`, whatever order the matches
+    /// were offered in
+    best: Mutex<Vec<MatchResult>>,
+    on_update: F,
+}
+
+impl<F: Fn(&[MatchResult]) + Sync> BestResults<F> {
+    pub(crate) fn new(threshold: f64, max_results: usize, on_update: F) -> Self {
+        BestResults { threshold, max_results, best: Mutex::new(Vec::new()), on_update }
+    }
+
+    /// Keep a scored match if it reaches the threshold and the top list
+    /// (a NaN score never does)
+    pub(crate) fn offer(&self, candidate: MatchResult) {
+        if candidate.score.is_nan() || candidate.score < self.threshold {
+            return;
+        }
+        let mut best = self.best.lock().unwrap_or_else(|e| e.into_inner());
+        let position = best.partition_point(|m| m.rank_cmp(&candidate) != Ordering::Greater);
+        if position >= self.max_results {
+            return;
+        }
+        best.insert(position, candidate);
+        best.truncate(self.max_results);
+        // Still holding the lock, so updates arrive in order
+        (self.on_update)(&best);
+    }
+
+    pub(crate) fn into_results(self) -> Vec<MatchResult> {
+        self.best.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(sound_id: i64, score: f64) -> MatchResult {
+        MatchResult {
+            sound_id,
+            filepath: format!("/kit/{sound_id}.wav"),
+            filename: format!("{sound_id}.wav"),
+            score,
+            match_start: 0.0,
+            match_end: 1.0,
+            file_duration: 1.0,
+            time_resolution: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_best_results() {
+        let updates = Mutex::new(Vec::new());
+        let best = BestResults::new(50.0, 2, |top: &[MatchResult]| {
+            updates.lock().unwrap().push(top.iter().map(|m| m.sound_id).collect::<Vec<_>>())
+        });
+        best.offer(scored(1, 60.0));
+        best.offer(scored(2, 40.0)); // below the threshold
+        best.offer(scored(3, 90.0));
+        best.offer(scored(4, 55.0)); // doesn't make the top two
+        best.offer(scored(5, 60.0)); // ties go by sound id, not arrival
+        best.offer(scored(0, 60.0));
+        best.offer(scored(6, f64::NAN));
+
+        let ids: Vec<i64> = best.into_results().iter().map(|m| m.sound_id).collect();
+        assert_eq!(ids, vec![3, 0]);
+        assert_eq!(updates.into_inner().unwrap(), vec![vec![1], vec![3, 1], vec![3, 0]]);
+    }
+}
@@ -0,0 +1,88 @@
+//! Memory budget for parallel decoding
+//!
+//! Segment search decodes candidate files on every core at once. Twenty
+//! long stems decoded side by side can hold gigabytes, more than a phone
+//! has. Each decode first reserves its decoded size from a shared budget
+//! and waits while the budget is spent, so short files still run in
+//! parallel while long ones take turns.
+
+use std::sync::{Condvar, Mutex};
+
+/// Default most bytes of decoded audio held at once by one search
+pub const DEFAULT_MEMORY_BUDGET: usize = 256 << 20;
+
+/// Counting semaphore over bytes
+pub(super) struct MemoryBudget {
+    limit: usize,
+    used: Mutex<usize>,
+    freed: Condvar,
+}
+
+/// Bytes reserved from a budget, given back on drop
+pub(super) struct BudgetPermit<'a> {
+    budget: &'a MemoryBudget,
+    bytes: usize,
+}
+
+impl MemoryBudget {
+    /// A budget of `limit` bytes (0 is unlimited)
+    pub(super) fn new(limit: usize) -> Self {
+        MemoryBudget { limit: if limit == 0 { usize::MAX } else { limit }, used: Mutex::new(0), freed: Condvar::new() }
+    }
+
+    /// Reserve `bytes`, waiting until that much is free
+    ///
+    /// A request larger than the whole budget waits until nothing else is
+    /// held and then runs alone rather than never.
+    pub(super) fn acquire(&self, bytes: usize) -> BudgetPermit<'_> {
+        let bytes = bytes.min(self.limit);
+        let mut used = self.used.lock().unwrap_or_else(|e| e.into_inner());
+        while self.limit - *used < bytes {
+            used = self.freed.wait(used).unwrap_or_else(|e| e.into_inner());
+        }
+        *used += bytes;
+        BudgetPermit { budget: self, bytes }
+    }
+}
+
+impl Drop for BudgetPermit<'_> {
+    fn drop(&mut self) {
+        *self.budget.used.lock().unwrap_or_else(|e| e.into_inner()) -= self.bytes;
+        self.budget.freed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn used(budget: &MemoryBudget) -> usize {
+        *budget.used.lock().unwrap()
+    }
+
+    #[test]
+    fn test_memory_budget() {
+        let budget = MemoryBudget::new(100);
+        let held = budget.acquire(60);
+        assert_eq!(used(&budget), 60);
+        drop(held);
+        assert_eq!(used(&budget), 0);
+
+        // Never more than the limit at once, and oversized requests still run
+        let peak = AtomicUsize::new(0);
+        std::thread::scope(|scope| {
+            for bytes in [40, 70, 30, 500, 50, 20] {
+                let (budget, peak) = (&budget, &peak);
+                scope.spawn(move || {
+                    let _permit = budget.acquire(bytes);
+                    peak.fetch_max(used(budget), Ordering::SeqCst);
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                });
+            }
+        });
+        assert!(peak.load(Ordering::SeqCst) <= 100);
+        assert_eq!(used(&budget), 0);
+        assert_eq!(MemoryBudget::new(0).acquire(usize::MAX).bytes, usize::MAX);
+    }
+}
@@ -0,0 +1,189 @@
+//! Cache of recent queries and their results
+//!
+//! Repeating a search, or asking for the next page of one, would otherwise
+//! decode the query file and scan the library again. Query fingerprints
+//! are kept by path and file stamp; ranked results by a hash of the query
+//! and its parameters, for the library revision they were computed at. A
+//! revision change (any sound or fingerprint added, removed or replaced)
+//! drops every cached result.
+
+use super::SearchEngine;
+use crate::database::PaletteDatabase;
+use crate::fingerprint::AudioFingerprint;
+use crate::{FileStamp, MatchResult, Result};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+/// Recent queries kept, for fingerprints and for results each
+pub const QUERY_CACHE_CAPACITY: usize = 32;
+
+struct CachedResults {
+    key: u64,
+    /// Results asked for when computed; fewer results than this means
+    /// there are no more
+    max_results: usize,
+    results: Vec<MatchResult>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Library revision the cached results belong to
+    revision: Option<i64>,
+    /// Most recently used last
+    fingerprints: VecDeque<(String, FileStamp, AudioFingerprint)>,
+    results: VecDeque<CachedResults>,
+}
+
+/// Recently used query fingerprints and ranked results
+pub struct QueryCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new(QUERY_CACHE_CAPACITY)
+    }
+}
+
+impl QueryCache {
+    pub fn new(capacity: usize) -> Self {
+        QueryCache { capacity: capacity.max(1), state: Mutex::new(CacheState::default()) }
+    }
+
+    /// Hash identifying a query: its kind, what it is (e.g. a path and
+    /// stamp, or a fingerprint's bytes) and its parameters
+    pub fn key(kind: &str, query: &[u8], parameters: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (kind, query, parameters).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// `key` for a query file, identified by path and file stamp so an
+    /// edited file is a new query
+    pub fn file_key(kind: &str, path: &str, parameters: &str) -> Result<u64> {
+        let stamp = crate::audio::file_stamp(path)?;
+        Ok(Self::key(kind, format!("{path}\n{}\n{}", stamp.size, stamp.mtime).as_bytes(), parameters))
+    }
+
+    /// Fingerprint of a query file, computed by `engine` unless the same
+    /// file (by path and stamp) was fingerprinted recently
+    pub fn fingerprint(&self, engine: &SearchEngine, path: &str) -> Result<AudioFingerprint> {
+        let stamp = crate::audio::file_stamp(path)?;
+        {
+            let mut state = self.lock();
+            if let Some(i) = state.fingerprints.iter().position(|(p, s, _)| p == path && *s == stamp) {
+                let entry = state.fingerprints.remove(i).unwrap();
+                let fingerprint = entry.2.clone();
+                state.fingerprints.push_back(entry);
+                return Ok(fingerprint);
+            }
+        }
+
+        let fingerprint = engine.fingerprint_file(path)?;
+        let mut state = self.lock();
+        state.fingerprints.retain(|(p, _, _)| p != path);
+        state.fingerprints.push_back((path.to_string(), stamp, fingerprint.clone()));
+        if state.fingerprints.len() > self.capacity {
+            state.fingerprints.pop_front();
+        }
+        Ok(fingerprint)
+    }
+
+    /// The top `max_results` for query `key`, from the cache when a search
+    /// at the current library revision already ranked at least as many
+    /// (or found fewer than it asked for); `search` runs otherwise
+    pub fn results(
+        &self,
+        db: &PaletteDatabase,
+        key: u64,
+        max_results: usize,
+        search: impl FnOnce() -> Result<Vec<MatchResult>>,
+    ) -> Result<Vec<MatchResult>> {
+        let revision = db.library_revision()?;
+        {
+            let mut state = self.lock();
+            if state.revision != Some(revision) {
+                state.revision = Some(revision);
+                state.results.clear();
+            }
+            if let Some(i) = state.results.iter().position(|c| c.key == key) {
+                let cached = &state.results[i];
+                if cached.max_results >= max_results || cached.results.len() < cached.max_results {
+                    let entry = state.results.remove(i).unwrap();
+                    let results = entry.results.iter().take(max_results).cloned().collect();
+                    state.results.push_back(entry);
+                    return Ok(results);
+                }
+            }
+        }
+
+        let results = search()?;
+        let mut state = self.lock();
+        // A search racing a library change keeps its results to itself
+        if state.revision == Some(revision) {
+            state.results.retain(|c| c.key != key);
+            state.results.push_back(CachedResults { key, max_results, results: results.clone() });
+            if state.results.len() > self.capacity {
+                state.results.pop_front();
+            }
+        }
+        Ok(results)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn scored(sound_id: i64) -> MatchResult {
+        MatchResult {
+            sound_id,
+            filepath: format!("/kit/{sound_id}.wav"),
+            filename: format!("{sound_id}.wav"),
+            score: 100.0 - sound_id as f64,
+            match_start: 0.0,
+            match_end: 1.0,
+            file_duration: 1.0,
+            time_resolution: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_query_cache() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let cache = QueryCache::default();
+        let searches = Cell::new(0);
+        let search = |n: usize| {
+            searches.set(searches.get() + 1);
+            Ok((1..=n as i64).map(scored).collect())
+        };
+        let key = QueryCache::key("similar", b"/q.wav", "{}");
+
+        assert_eq!(cache.results(&db, key, 10, || search(10)).unwrap().len(), 10);
+        // Repeats and earlier pages come from the cache; a longer page searches again
+        assert_eq!(cache.results(&db, key, 10, || search(10)).unwrap().len(), 10);
+        assert_eq!(cache.results(&db, key, 5, || search(5)).unwrap().len(), 5);
+        assert_eq!(searches.get(), 1);
+        assert_eq!(cache.results(&db, key, 20, || search(20)).unwrap().len(), 20);
+        assert_eq!(searches.get(), 2);
+        // Other parameters are another query
+        let other = QueryCache::key("similar", b"/q.wav", "{\"threshold\":50}");
+        cache.results(&db, other, 10, || search(10)).unwrap();
+        assert_eq!(searches.get(), 3);
+
+        // Adding a sound invalidates everything
+        db.add_sound("/kit/new.wav", "new.wav", 1.0, 44100, 1, "wav").unwrap();
+        cache.results(&db, key, 10, || search(10)).unwrap();
+        assert_eq!(searches.get(), 4);
+        cache.results(&db, key, 10, || search(10)).unwrap();
+        assert_eq!(searches.get(), 4);
+    }
+}
@@ -0,0 +1,142 @@
+//! Score calibration against the library
+//!
+//! Raw similarity scores aren't comparable between queries: a bright hat
+//! scores 70 against most of a drum library, a sub drone 30. Calibrated,
+//! a result's score is the percentage of the library the query is less
+//! similar to, so 80 means "more like the query than 80% of the library"
+//! whatever the query. The distribution comes from scoring the query
+//! against a fixed sample of stored fingerprints.
+
+use super::SearchEngine;
+use crate::database::PaletteDatabase;
+use crate::fingerprint::AudioFingerprint;
+use crate::{MatchResult, Result};
+
+/// Stored fingerprints the query is scored against to calibrate
+pub const CALIBRATION_SAMPLE: usize = 1_000;
+
+/// One query's raw scores over a library sample, mapping raw scores to
+/// percentiles and back
+#[derive(Debug, Clone)]
+pub struct ScoreCalibration {
+    /// Ascending
+    scores: Vec<f64>,
+}
+
+impl ScoreCalibration {
+    /// None with fewer than two scores to interpolate between
+    pub fn from_scores(mut scores: Vec<f64>) -> Option<Self> {
+        if scores.len() < 2 {
+            return None;
+        }
+        scores.sort_by(f64::total_cmp);
+        Some(ScoreCalibration { scores })
+    }
+
+    /// Percentile (0-100) of a raw score, interpolated between samples
+    pub fn percentile(&self, raw: f64) -> f64 {
+        let last = self.scores.len() - 1;
+        // Index of the highest sample at or below `raw`
+        let below = self.scores.partition_point(|&s| s <= raw);
+        if below == 0 {
+            return 0.0;
+        }
+        if below > last {
+            return 100.0;
+        }
+        let (low, high) = (self.scores[below - 1], self.scores[below]);
+        let position = (below - 1) as f64 + (raw - low) / (high - low);
+        100.0 * position / last as f64
+    }
+
+    /// Raw score at a percentile, the inverse of `percentile`
+    pub fn raw_score(&self, percentile: f64) -> f64 {
+        let position = percentile.clamp(0.0, 100.0) / 100.0 * (self.scores.len() - 1) as f64;
+        let below = (position.floor() as usize).min(self.scores.len() - 2);
+        let (low, high) = (self.scores[below], self.scores[below + 1]);
+        low + (position - below as f64) * (high - low)
+    }
+
+    /// `results` with their scores replaced by percentiles
+    pub fn calibrate(&self, mut results: Vec<MatchResult>) -> Vec<MatchResult> {
+        for m in &mut results {
+            m.score = self.percentile(m.score);
+        }
+        results
+    }
+}
+
+impl SearchEngine {
+    /// The query's calibration, or None when calibration is off or the
+    /// library is too small to calibrate against
+    pub fn score_calibration(&self, query_fp: &AudioFingerprint, db: &PaletteDatabase) -> Result<Option<ScoreCalibration>> {
+        if !self.config.calibrate_scores {
+            return Ok(None);
+        }
+        let sample = db.sample_fingerprint_ids(CALIBRATION_SAMPLE)?;
+        let scored = self.nearest(query_fp, db, Some(&sample), f64::NEG_INFINITY, sample.len())?;
+        Ok(ScoreCalibration::from_scores(scored.into_iter().map(|m| m.score).collect()))
+    }
+
+    /// Run `search` with a raw threshold and updates, calibrating both its
+    /// updates and its results when calibration is on
+    pub(super) fn calibrated(
+        &self,
+        query_fp: &AudioFingerprint,
+        db: &PaletteDatabase,
+        threshold: f64,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+        search: impl FnOnce(f64, &(dyn Fn(&[MatchResult]) + Sync)) -> Result<Vec<MatchResult>>,
+    ) -> Result<Vec<MatchResult>> {
+        let Some(calibration) = self.score_calibration(query_fp, db)? else {
+            return search(threshold, &on_update);
+        };
+        let results = search(calibration.raw_score(threshold), &|best| on_update(&calibration.calibrate(best.to_vec())))?;
+        Ok(calibration.calibrate(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::SearchConfig;
+
+    #[test]
+    fn test_score_calibration() {
+        let calibration = ScoreCalibration::from_scores(vec![40.0, 10.0, 30.0, 20.0, 50.0]).unwrap();
+        assert_eq!(calibration.percentile(5.0), 0.0);
+        assert_eq!(calibration.percentile(30.0), 50.0);
+        assert_eq!(calibration.percentile(35.0), 62.5);
+        assert_eq!(calibration.percentile(90.0), 100.0);
+        for percentile in [0.0, 12.5, 50.0, 80.0, 100.0] {
+            assert!((calibration.percentile(calibration.raw_score(percentile)) - percentile).abs() < 1e-9);
+        }
+        assert!(ScoreCalibration::from_scores(vec![50.0]).is_none());
+
+        // Ten tones: the nearest one outranks the other nine, and a
+        // calibrated threshold keeps the top fifth of the library
+        let engine = SearchEngine::with_config(SearchConfig { calibrate_scores: true, ..SearchConfig::default() });
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let tone = |freq: f32| -> Vec<f32> {
+            (0..22050).map(|i| (freq * i as f32 / 22050.0 * std::f32::consts::TAU).sin() * 0.5).collect()
+        };
+        for k in 0..10 {
+            let freq = 200.0 * 1.4f32.powi(k);
+            let id = db.add_sound(&format!("/lib/{k}.wav"), &format!("{k}.wav"), 1.0, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&tone(freq), 22050).unwrap()).unwrap();
+        }
+        let query = engine.fingerprint_samples(&tone(210.0), 22050).unwrap();
+
+        let all = engine.find_similar(&query, &db, 0.0, 10).unwrap();
+        assert_eq!(all.len(), 10);
+        assert_eq!(all[0].filename, "0.wav");
+        assert_eq!(all[0].score, 100.0);
+        assert_eq!(all[9].score, 0.0);
+        let top = engine.find_similar(&query, &db, 80.0, 10).unwrap();
+        assert_eq!(top.len(), 2);
+        assert!(top.iter().all(|m| m.score >= 80.0));
+
+        let raw = SearchEngine::new().find_similar(&query, &db, 0.0, 10).unwrap();
+        assert!(raw[9].score > 0.0);
+    }
+}
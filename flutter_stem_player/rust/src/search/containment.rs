@@ -0,0 +1,143 @@
+//! Exact-containment detection
+//!
+//! The statistical fingerprint says two sounds are alike, not that one is
+//! the other: a re-recorded snare can score higher than the very sample
+//! buried in a full mix. For clearance and forensics the question is
+//! whether the query's audio literally occurs inside a file, so every
+//! candidate long enough to hold it is decoded and searched for the query
+//! waveform by normalized cross-correlation, which ignores level changes.
+
+use super::align::locate;
+use super::{SearchEngine, SearchQuery};
+use crate::database::{PaletteDatabase, SoundQuery};
+use crate::{descending_score, AudioPaletteError, Result};
+use crate::audio::AudioData;
+use rayon::prelude::*;
+
+/// Correlation from which a file counts as containing the query; lossy
+/// re-encodes of the same recording stay well above it
+pub const DEFAULT_MIN_CORRELATION: f64 = 0.9;
+
+/// Where a file contains the query audio
+#[derive(Debug, Clone)]
+pub struct ContainmentMatch {
+    pub sound_id: i64,
+    pub filepath: String,
+    pub filename: String,
+    /// First sample of the occurrence, at the file's own sample rate
+    pub offset_samples: usize,
+    pub sample_rate: u32,
+    /// Occurrence in seconds
+    pub match_start: f64,
+    pub match_end: f64,
+    /// Normalized cross-correlation at the offset (1 is an exact copy)
+    pub correlation: f64,
+    /// Level of the occurrence relative to the query, in dB
+    pub gain_db: f64,
+}
+
+impl SearchEngine {
+    /// Find files in which the query's audio occurs, best correlated first
+    ///
+    /// Needs the query audio (`query_from_file`/`query_from_samples`). The
+    /// query is resampled to each candidate's rate, so offsets are exact
+    /// in the candidate's own samples. Every candidate is decoded.
+    pub fn find_containing(
+        &self,
+        query: &SearchQuery,
+        db: &PaletteDatabase,
+        min_correlation: f64,
+        max_results: usize,
+    ) -> Result<Vec<ContainmentMatch>> {
+        let Some(query_audio) = query.audio.as_ref().filter(|a| !a.samples.is_empty()) else {
+            return Err(AudioPaletteError::FingerprintError("Containment search needs the query audio".to_string()));
+        };
+        let filter = SoundQuery { min_duration: Some(query_audio.duration * 0.99), ..SoundQuery::default() };
+        let candidates = db.query_sounds(&filter)?.sounds;
+
+        let mut matches: Vec<ContainmentMatch> = candidates
+            .into_par_iter()
+            .filter_map(|sound| {
+                if self.cancel.is_cancelled() {
+                    return None;
+                }
+                let audio = AudioData::load(&sound.filepath).ok()?;
+                let resampled;
+                let query_samples = if query_audio.sample_rate == audio.sample_rate {
+                    &query_audio.samples
+                } else {
+                    resampled = query_audio.resample(audio.sample_rate);
+                    &resampled.samples
+                };
+                let (offset, correlation) = locate(query_samples, &audio.samples)?;
+                if correlation < min_correlation {
+                    return None;
+                }
+
+                // Least-squares scale of the query onto the occurrence
+                let occurrence = &audio.samples[offset..offset + query_samples.len()];
+                let dot: f64 = query_samples.iter().zip(occurrence).map(|(&q, &t)| q as f64 * t as f64).sum();
+                let energy: f64 = query_samples.iter().map(|&q| (q as f64).powi(2)).sum();
+                let rate = audio.sample_rate as f64;
+                Some(ContainmentMatch {
+                    sound_id: sound.id,
+                    filepath: sound.filepath,
+                    filename: sound.filename,
+                    offset_samples: offset,
+                    sample_rate: audio.sample_rate,
+                    match_start: offset as f64 / rate,
+                    match_end: (offset + query_samples.len()) as f64 / rate,
+                    correlation,
+                    gain_db: 20.0 * (dot / energy).abs().max(1e-10).log10(),
+                })
+            })
+            .collect();
+
+        self.check_cancelled()?;
+        matches.sort_by(|a, b| descending_score(a.correlation, b.correlation).then(a.sound_id.cmp(&b.sound_id)));
+        matches.truncate(max_results);
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::write_test_wav;
+
+    #[test]
+    fn test_find_containing() {
+        let rate = 22050;
+        let texture = |seed: f32, len: usize| -> Vec<f32> {
+            (0..len).map(|i| 0.4 * (((i as f32 * 12.9898 + seed * 78.233).sin() * 43758.545).fract().abs() - 0.5)).collect()
+        };
+        let sample = texture(1.0, rate / 2);
+        // A mix with the sample 6 dB down at 0.75s, and an unrelated take
+        let mut mix = texture(7.0, rate * 2);
+        let at = rate * 3 / 4;
+        for (i, &s) in sample.iter().enumerate() {
+            mix[at + i] = mix[at + i] * 0.01 + s * 0.5;
+        }
+        let other = texture(13.0, rate * 2);
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut ids = Vec::new();
+        for (name, samples) in [("mix", &mix), ("other", &other)] {
+            let path = write_test_wav(dir.path(), &format!("{name}.wav"), samples, rate as u32);
+            ids.push(db.add_sound(path.to_str().unwrap(), name, 2.0, rate as u32, 1, "wav").unwrap());
+        }
+
+        let engine = SearchEngine::new();
+        let query = engine.query_from_samples(&sample, rate as u32).unwrap();
+        let found = engine.find_containing(&query, &db, DEFAULT_MIN_CORRELATION, 10).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].sound_id, found[0].offset_samples), (ids[0], at));
+        assert!(found[0].correlation > 0.99);
+        assert!((found[0].gain_db + 6.02).abs() < 0.1, "{}", found[0].gain_db);
+
+        // A fingerprint-only query has no waveform to look for
+        let fingerprint_only = SearchQuery::from(query.fingerprint.clone());
+        assert!(engine.find_containing(&fingerprint_only, &db, DEFAULT_MIN_CORRELATION, 10).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! Diversity-aware re-ranking
+//!
+//! Ranked by score alone, the top 20 hits for a snare can be 20 takes of
+//! the same snare. Maximal marginal relevance picks results one at a time,
+//! each by its score minus how similar it is to the closest result already
+//! picked, so near-copies of a pick sink below genuinely different sounds.
+
+use crate::fingerprint::AudioFingerprint;
+use crate::MatchResult;
+
+/// Pick up to `max_results` of `candidates` (best first) by maximal
+/// marginal relevance
+///
+/// `diversity` (0-1) trades score for difference: each pick maximizes
+/// `(1 - diversity) * score - diversity * max similarity to earlier picks`.
+/// Candidates without a fingerprint count as unlike everything. Scores
+/// are returned unchanged; only the order and the cut change.
+pub(super) fn maximal_marginal_relevance(
+    candidates: Vec<MatchResult>,
+    fingerprints: &[Option<AudioFingerprint>],
+    diversity: f64,
+    max_results: usize,
+) -> Vec<MatchResult> {
+    let diversity = diversity.clamp(0.0, 1.0);
+    let mut remaining: Vec<(MatchResult, Option<&AudioFingerprint>)> =
+        candidates.into_iter().zip(fingerprints.iter().map(Option::as_ref)).collect();
+    // Highest similarity of each remaining candidate to any pick so far
+    let mut redundancy = vec![0.0; remaining.len()];
+    let mut picked: Vec<MatchResult> = Vec::new();
+
+    while picked.len() < max_results && !remaining.is_empty() {
+        let marginal = |i: usize| (1.0 - diversity) * remaining[i].0.score - diversity * redundancy[i];
+        // Earliest (best-scored) candidate on ties
+        let best = (0..remaining.len()).reduce(|best, i| if marginal(i) > marginal(best) { i } else { best }).unwrap();
+        let (choice, fp) = remaining.remove(best);
+        redundancy.remove(best);
+        if let Some(fp) = fp {
+            for ((_, other), r) in remaining.iter().zip(redundancy.iter_mut()) {
+                if let Some(other) = other {
+                    *r = f64::max(*r, fp.similarity(other));
+                }
+            }
+        }
+        picked.push(choice);
+    }
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::test_util::fingerprint;
+
+    fn scored(sound_id: i64, score: f64) -> MatchResult {
+        MatchResult {
+            sound_id,
+            filepath: format!("/kit/{sound_id}.wav"),
+            filename: format!("{sound_id}.wav"),
+            score,
+            match_start: 0.0,
+            match_end: 1.0,
+            file_duration: 1.0,
+            time_resolution: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_maximal_marginal_relevance() {
+        // Two takes of one snare, then a different one
+        let candidates = vec![scored(1, 95.0), scored(2, 94.0), scored(3, 90.0)];
+        let fingerprints = vec![Some(fingerprint(1.0)), Some(fingerprint(1.0)), Some(fingerprint(2.5))];
+        let ids = |results: &[MatchResult]| results.iter().map(|m| m.sound_id).collect::<Vec<_>>();
+
+        let plain = maximal_marginal_relevance(candidates.clone(), &fingerprints, 0.0, 2);
+        assert_eq!(ids(&plain), vec![1, 2]);
+        let diverse = maximal_marginal_relevance(candidates.clone(), &fingerprints, 0.5, 3);
+        assert_eq!(ids(&diverse), vec![1, 3, 2]);
+        assert_eq!(diverse[1].score, 90.0);
+    }
+}
@@ -0,0 +1,164 @@
+//! Dynamic time warping over frame-level feature sequences
+
+/// Distance scale used to map an average DTW path cost to a 0-100 similarity
+const DTW_DISTANCE_SCALE: f64 = 25.0;
+
+/// Euclidean distance between two feature frames
+fn frame_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Compute the DTW alignment cost between two frame sequences
+///
+/// `band` is a Sakoe-Chiba constraint as a fraction of the longer sequence
+/// (e.g. 0.1 allows 10% warping); `None` runs unconstrained DTW.
+/// The cost is normalized by path length so sequences of different
+/// lengths are comparable. Returns `f64::INFINITY` if either sequence is empty.
+pub fn dtw_distance(a: &[Vec<f64>], b: &[Vec<f64>], band: Option<f64>) -> f64 {
+    let n = a.len();
+    let m = b.len();
+    if n == 0 || m == 0 {
+        return f64::INFINITY;
+    }
+
+    // Band must be at least as wide as the length difference to reach (n, m)
+    let window = match band {
+        Some(fraction) => ((fraction * n.max(m) as f64).ceil() as usize).max(n.abs_diff(m)),
+        None => n.max(m),
+    };
+
+    // Two-row cost matrix: prev[j] = D(i-1, j), curr[j] = D(i, j)
+    let mut prev = vec![f64::INFINITY; m + 1];
+    let mut curr = vec![f64::INFINITY; m + 1];
+    prev[0] = 0.0;
+
+    for i in 1..=n {
+        curr.fill(f64::INFINITY);
+        // Map row i onto the diagonal of the (n, m) grid before applying the band
+        let center = i * m / n;
+        let j_start = center.saturating_sub(window).max(1);
+        let j_end = (center + window).min(m);
+
+        for j in j_start..=j_end {
+            let cost = frame_distance(&a[i - 1], &b[j - 1]);
+            let best = prev[j].min(curr[j - 1]).min(prev[j - 1]);
+            curr[j] = cost + best;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m] / (n + m) as f64
+}
+
+/// DTW similarity between two frame sequences (0-100)
+pub fn dtw_similarity(a: &[Vec<f64>], b: &[Vec<f64>], band: Option<f64>) -> f64 {
+    distance_to_similarity(dtw_distance(a, b, band))
+}
+
+/// Map a normalized DTW cost to a 0-100 similarity
+fn distance_to_similarity(distance: f64) -> f64 {
+    if !distance.is_finite() {
+        return 0.0;
+    }
+    100.0 * (-distance / DTW_DISTANCE_SCALE).exp()
+}
+
+/// Where a query sequence best occurs inside a longer one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DtwAlignment {
+    /// First and last target frames on the warping path
+    pub start: usize,
+    pub end: usize,
+    /// Path cost normalized by query plus matched length, as in `dtw_distance`
+    pub distance: f64,
+}
+
+impl DtwAlignment {
+    /// Similarity of the query to the matched stretch (0-100)
+    pub fn similarity(&self) -> f64 {
+        distance_to_similarity(self.distance)
+    }
+}
+
+/// Align `query` to its best-matching stretch of `target` (subsequence DTW)
+///
+/// The path may start and end on any target frame, so the match boundaries
+/// follow the warping: a phrase played 20% slower matches a stretch 20%
+/// longer. Each cell extends the predecessor path with the lowest cost
+/// normalized by query plus target length covered, rather than the lowest
+/// total, which would favour cutting the match short. Stretches more than
+/// `max_stretch` times longer or shorter than the query are rejected.
+/// Returns None if nothing qualifies.
+pub fn dtw_subsequence(query: &[Vec<f64>], target: &[Vec<f64>], max_stretch: f64) -> Option<DtwAlignment> {
+    let n = query.len();
+    let m = target.len();
+    if n == 0 || m == 0 {
+        return None;
+    }
+
+    // Two rows of (accumulated cost, first target frame of the path)
+    let mut prev = vec![(f64::INFINITY, 0); m];
+    let mut curr = vec![(f64::INFINITY, 0); m];
+    for (i, frame) in query.iter().enumerate() {
+        for j in 0..m {
+            let cost = frame_distance(frame, &target[j]);
+            curr[j] = if i == 0 {
+                // Free start: a path may begin on any target frame
+                (cost, j)
+            } else {
+                let normalized = |(total, start): (f64, usize)| (total + cost) / (i + 1 + j - start + 1) as f64;
+                let mut best = prev[j];
+                if j > 0 {
+                    for step in [curr[j - 1], prev[j - 1]] {
+                        if normalized(step) < normalized(best) {
+                            best = step;
+                        }
+                    }
+                }
+                (cost + best.0, best.1)
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let max_stretch = max_stretch.max(1.0);
+    (0..m)
+        .filter_map(|end| {
+            let (cost, start) = prev[end];
+            let len = (end - start + 1) as f64;
+            let fits = len * max_stretch >= n as f64 && len <= n as f64 * max_stretch;
+            (cost.is_finite() && fits).then(|| DtwAlignment { start, end, distance: cost / (n as f64 + len) })
+        })
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dtw_time_stretch() {
+        let a: Vec<Vec<f64>> = (0..20).map(|i| vec![(i as f64 * 0.3).sin()]).collect();
+        // Same contour played at half speed
+        let b: Vec<Vec<f64>> = (0..40).map(|i| vec![(i as f64 * 0.15).sin()]).collect();
+        let c: Vec<Vec<f64>> = (0..20).map(|_| vec![5.0]).collect();
+
+        assert!(dtw_distance(&a, &a, Some(0.1)) < 1e-9);
+        assert!(dtw_similarity(&a, &b, None) > dtw_similarity(&a, &c, None));
+        assert!(dtw_distance(&a, &b, Some(0.1)).is_finite());
+
+        // The slow phrase found inside silence spans its own, longer length
+        let mut padded: Vec<Vec<f64>> = (0..15).map(|_| vec![5.0]).collect();
+        padded.extend(b.iter().cloned());
+        padded.extend((0..15).map(|_| vec![5.0]));
+        let aligned = dtw_subsequence(&a, &padded, 2.5).unwrap();
+        assert!(aligned.start.abs_diff(15) <= 1 && aligned.end.abs_diff(54) <= 1, "{aligned:?}");
+        assert!(aligned.similarity() > 90.0);
+        // Too slow to accept when stretching is capped
+        assert!(dtw_subsequence(&a, &padded, 1.5).is_none_or(|a| a.end - a.start < 30));
+    }
+}
@@ -0,0 +1,142 @@
+//! Queries defined by several examples
+//!
+//! One example alone is often ambiguous: is it the pitch, the attack or
+//! the room that matters? With several "like this" examples and a few
+//! "not like this" ones, the query becomes the centroid of the positives
+//! moved away from the centroid of the negatives (Rocchio's relevance
+//! feedback), so what the positives share and the negatives lack carries
+//! the ranking. The feature vector is linear in the fingerprint fields, so
+//! combining fingerprints field by field combines their vectors.
+
+use super::SearchEngine;
+use crate::database::PaletteDatabase;
+use crate::fingerprint::AudioFingerprint;
+use crate::{AudioPaletteError, MatchResult, Result, StereoImage};
+
+/// How far the query moves away from the negatives' centroid, relative to
+/// the distance between the two centroids
+pub const DEFAULT_NEGATIVE_WEIGHT: f64 = 0.5;
+
+/// Search by "like these" and "not like these" examples
+#[derive(Debug, Clone)]
+pub struct ExampleQuery {
+    pub positives: Vec<AudioFingerprint>,
+    pub negatives: Vec<AudioFingerprint>,
+    pub negative_weight: f64,
+}
+
+/// Sum of `weight * fingerprint` over `parts`, field by field
+fn weighted_sum(parts: &[(&AudioFingerprint, f64)]) -> AudioFingerprint {
+    let scalar = |get: fn(&AudioFingerprint) -> f64| parts.iter().map(|(fp, w)| w * get(fp)).sum::<f64>();
+    let vector = |get: fn(&AudioFingerprint) -> &Vec<f64>| -> Vec<f64> {
+        let len = parts.iter().map(|(fp, _)| get(fp).len()).min().unwrap_or(0);
+        (0..len).map(|i| parts.iter().map(|(fp, w)| w * get(fp)[i]).sum()).collect()
+    };
+    // Stereo only counts when every example measured it
+    let stereo = parts
+        .iter()
+        .map(|(fp, w)| fp.stereo.map(|s| (s, *w)))
+        .collect::<Option<Vec<_>>>()
+        .map(|images| StereoImage {
+            width: images.iter().map(|(s, w)| w * s.width).sum(),
+            correlation: images.iter().map(|(s, w)| w * s.correlation).sum(),
+        });
+
+    AudioFingerprint {
+        duration: scalar(|fp| fp.duration),
+        sample_rate: parts.first().map_or(0, |(fp, _)| fp.sample_rate),
+        mfcc_mean: vector(|fp| &fp.mfcc_mean),
+        mfcc_std: vector(|fp| &fp.mfcc_std),
+        spectral_centroid: scalar(|fp| fp.spectral_centroid),
+        spectral_bandwidth: scalar(|fp| fp.spectral_bandwidth),
+        spectral_rolloff: scalar(|fp| fp.spectral_rolloff),
+        rms_mean: scalar(|fp| fp.rms_mean),
+        rms_std: scalar(|fp| fp.rms_std),
+        zero_crossing_rate: scalar(|fp| fp.zero_crossing_rate),
+        chroma_mean: vector(|fp| &fp.chroma_mean),
+        noise: None,
+        stereo,
+    }
+}
+
+impl ExampleQuery {
+    pub fn new(positives: Vec<AudioFingerprint>, negatives: Vec<AudioFingerprint>) -> Self {
+        ExampleQuery { positives, negatives, negative_weight: DEFAULT_NEGATIVE_WEIGHT }
+    }
+
+    /// The single fingerprint ranked against: positive centroid plus
+    /// `negative_weight` times (positive centroid - negative centroid)
+    pub fn combined(&self) -> Result<AudioFingerprint> {
+        if self.positives.is_empty() {
+            return Err(AudioPaletteError::FingerprintError("No positive examples".to_string()));
+        }
+        let toward = 1.0 + if self.negatives.is_empty() { 0.0 } else { self.negative_weight };
+        let mut parts: Vec<(&AudioFingerprint, f64)> =
+            self.positives.iter().map(|fp| (fp, toward / self.positives.len() as f64)).collect();
+        parts.extend(self.negatives.iter().map(|fp| (fp, -self.negative_weight / self.negatives.len() as f64)));
+
+        let mut combined = weighted_sum(&parts);
+        // Durations stay those of the positives (segment search sizes its window by them)
+        combined.duration = self.positives.iter().map(|fp| fp.duration).sum::<f64>() / self.positives.len() as f64;
+        Ok(combined)
+    }
+}
+
+impl SearchEngine {
+    /// Find sounds like the positive examples and unlike the negative ones
+    ///
+    /// Scores are similarities to the combined query, not to any one example.
+    pub fn find_similar_to_examples(
+        &self,
+        query: &ExampleQuery,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        self.find_similar(&query.combined()?, db, threshold, max_results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_query() {
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        // A tone with a little or a lot of a high partial
+        let tone = |freq: f32, bright: f32| -> Vec<f32> {
+            (0..22050)
+                .map(|i| {
+                    let t = i as f32 / 22050.0;
+                    0.4 * (freq * t * std::f32::consts::TAU).sin() + bright * (4000.0 * t * std::f32::consts::TAU).sin()
+                })
+                .collect()
+        };
+        let fingerprint = |samples: Vec<f32>| engine.fingerprint_samples(&samples, 22050).unwrap();
+        let mut ids = Vec::new();
+        for (name, freq, bright) in [("dull", 300.0, 0.02), ("bright", 300.0, 0.3)] {
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, 1.0, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &fingerprint(tone(freq, bright))).unwrap();
+            ids.push(id);
+        }
+
+        // Positives alone are just their centroid
+        let positives = vec![fingerprint(tone(280.0, 0.1)), fingerprint(tone(320.0, 0.1))];
+        let plain = ExampleQuery::new(positives.clone(), Vec::new());
+        let centroid = plain.combined().unwrap();
+        let mean_centroid = (positives[0].spectral_centroid + positives[1].spectral_centroid) / 2.0;
+        assert!((centroid.spectral_centroid - mean_centroid).abs() < 1e-9);
+
+        // A dull negative pushes the ranking towards the bright sound, and vice versa
+        let away_from_dull = ExampleQuery::new(positives.clone(), vec![fingerprint(tone(300.0, 0.0))]);
+        let results = engine.find_similar_to_examples(&away_from_dull, &db, 0.0, 2).unwrap();
+        assert_eq!(results[0].sound_id, ids[1]);
+        let away_from_bright = ExampleQuery::new(positives, vec![fingerprint(tone(300.0, 0.5))]);
+        let results = engine.find_similar_to_examples(&away_from_bright, &db, 0.0, 2).unwrap();
+        assert_eq!(results[0].sound_id, ids[0]);
+
+        assert!(ExampleQuery::new(Vec::new(), Vec::new()).combined().is_err());
+    }
+}
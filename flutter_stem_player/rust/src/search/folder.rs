@@ -0,0 +1,160 @@
+//! Ad-hoc search in a folder outside the library
+//!
+//! Triaging a new sample pack shouldn't mean indexing it first. The
+//! folder is scanned like an index run, every file is fingerprinted in
+//! parallel and scored against the query, and nothing is written to the
+//! database. Fingerprints are kept in a scratch cache by path and file
+//! stamp, so trying a second query against the same pack only decodes
+//! what changed.
+
+use super::SearchEngine;
+use crate::fingerprint::AudioFingerprint;
+use crate::indexer::{scan_directory, IndexOptions};
+use crate::{descending_score, FileStamp, Result};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Files whose fingerprints the cache holds before it starts over
+pub const FOLDER_CACHE_CAPACITY: usize = 20_000;
+
+/// A file of the searched folder matching the query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FolderMatch {
+    pub filepath: String,
+    pub filename: String,
+    /// Similarity (0-100)
+    pub score: f64,
+    /// Seconds
+    pub duration: f64,
+}
+
+/// Fingerprints of files outside the library, by path and file stamp
+/// (None for files that couldn't be decoded)
+#[derive(Default)]
+pub struct FolderCache {
+    entries: Mutex<HashMap<String, (FileStamp, Option<AudioFingerprint>)>>,
+}
+
+impl FolderCache {
+    /// Fingerprint of `path`, computed by `engine` unless cached for the
+    /// file as it is now; None if it can't be read or decoded
+    pub fn fingerprint(&self, engine: &SearchEngine, path: &str) -> Option<AudioFingerprint> {
+        let stamp = crate::audio::file_stamp(path).ok()?;
+        if let Some((cached, fingerprint)) = self.lock().get(path) {
+            if *cached == stamp {
+                return fingerprint.clone();
+            }
+        }
+
+        let fingerprint = engine.fingerprint_file(path).ok();
+        let mut entries = self.lock();
+        if entries.len() >= FOLDER_CACHE_CAPACITY {
+            entries.clear();
+        }
+        entries.insert(path.to_string(), (stamp, fingerprint.clone()));
+        fingerprint
+    }
+
+    /// Forget every cached fingerprint
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, (FileStamp, Option<AudioFingerprint>)>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl SearchEngine {
+    /// Files below `directory` (as `options` filters them) most similar
+    /// to the query, best first, without indexing them
+    ///
+    /// Files that can't be decoded are left out.
+    pub fn find_similar_in_directory(
+        &self,
+        query_fp: &AudioFingerprint,
+        directory: &Path,
+        options: &IndexOptions,
+        cache: &FolderCache,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<FolderMatch>> {
+        let files = scan_directory(directory, options)?;
+        let mut matches: Vec<FolderMatch> = files
+            .par_iter()
+            .filter_map(|path| {
+                if self.cancel.is_cancelled() {
+                    return None;
+                }
+                let fp = cache.fingerprint(self, path)?;
+                let score = query_fp.similarity_masked(&fp, &self.config.feature_mask);
+                (score >= threshold).then(|| FolderMatch {
+                    filepath: path.clone(),
+                    filename: Path::new(path)
+                        .file_name()
+                        .map_or_else(|| path.clone(), |name| name.to_string_lossy().to_string()),
+                    score,
+                    duration: fp.duration,
+                })
+            })
+            .collect();
+        self.check_cancelled()?;
+
+        matches.sort_by(|a, b| descending_score(a.score, b.score).then_with(|| a.filepath.cmp(&b.filepath)));
+        matches.truncate(max_results);
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::write_test_wav;
+
+    fn write_tone(dir: &Path, name: &str, freq: f32) -> std::path::PathBuf {
+        let samples: Vec<f32> =
+            (0..22050).map(|i| (std::f32::consts::TAU * freq * i as f32 / 22050.0).sin() * 0.366).collect();
+        write_test_wav(dir, name, &samples, 22050)
+    }
+
+    #[test]
+    fn test_find_similar_in_directory() {
+        let pack = tempfile::tempdir().unwrap();
+        std::fs::create_dir(pack.path().join("bass")).unwrap();
+        write_tone(pack.path(), "bass/sub.wav", 60.0);
+        write_tone(pack.path(), "lead.wav", 880.0);
+        write_tone(pack.path(), "pluck.wav", 2500.0);
+        std::fs::write(pack.path().join("broken.wav"), b"not audio").unwrap();
+
+        let engine = SearchEngine::new();
+        let cache = FolderCache::default();
+        let samples: Vec<f32> =
+            (0..22050).map(|i| (std::f32::consts::TAU * 900.0 * i as f32 / 22050.0).sin() * 0.4).collect();
+        let query = engine.fingerprint_samples(&samples, 22050).unwrap();
+
+        let results = engine
+            .find_similar_in_directory(&query, pack.path(), &IndexOptions::default(), &cache, 0.0, 2)
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].filename, "lead.wav");
+        let first = results[0].score;
+        assert!((results[0].duration - 1.0).abs() < 0.01);
+        // Every file was looked at once, the broken one included
+        assert_eq!(cache.lock().len(), 4);
+
+        // Rewritten files are fingerprinted again; the rest come from the cache
+        let lead = write_tone(pack.path(), "lead.wav", 3000.0);
+        let later = std::time::SystemTime::now() + std::time::Duration::from_secs(5);
+        std::fs::File::options().write(true).open(&lead).unwrap().set_modified(later).unwrap();
+        let results = engine
+            .find_similar_in_directory(&query, pack.path(), &IndexOptions::default(), &cache, 0.0, 3)
+            .unwrap();
+        // Compared by its own score: high pure tones all score about the
+        // same against the query, so its rank says little
+        let lead = results.iter().find(|r| r.filename == "lead.wav").unwrap();
+        assert!(lead.score < first - 5.0, "{} vs {}", lead.score, first);
+    }
+}
@@ -0,0 +1,150 @@
+//! wgpu compute backend for `GpuScorer`
+
+use crate::{AudioPaletteError, Result};
+use wgpu::util::DeviceExt;
+
+/// Dot product of every query with every matrix row (both unit length)
+const SHADER: &str = r#"
+struct Params {
+    rows: u32,
+    dims: u32,
+    queries: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0) var<storage, read> matrix: array<f32>;
+@group(0) @binding(1) var<storage, read> queries: array<f32>;
+@group(0) @binding(2) var<storage, read_write> scores: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = id.x;
+    let query = id.y;
+    if (row >= params.rows || query >= params.queries) {
+        return;
+    }
+    var dot = 0.0;
+    for (var i = 0u; i < params.dims; i++) {
+        dot += matrix[row * params.dims + i] * queries[query * params.dims + i];
+    }
+    scores[query * params.rows + row] = dot;
+}
+"#;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+fn gpu_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::GpuError(e.to_string())
+}
+
+/// A device holding the uploaded matrix and the scoring pipeline
+pub(super) struct GpuDevice {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    matrix: wgpu::Buffer,
+    rows: usize,
+    dims: usize,
+}
+
+impl GpuDevice {
+    /// Upload `matrix` (rows of `dims` values) to the first adapter found
+    pub(super) fn new(matrix: &[f32], dims: usize) -> Result<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))
+        .ok_or_else(|| gpu_error("No GPU adapter available"))?;
+        let limits = adapter.limits();
+        let bytes = std::mem::size_of_val(matrix) as u64;
+        if bytes > limits.max_storage_buffer_binding_size as u64 || bytes > limits.max_buffer_size {
+            return Err(gpu_error("Library too large for one GPU buffer"));
+        }
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor { label: Some("audio_palette"), required_limits: limits, ..Default::default() },
+            None,
+        ))
+        .map_err(gpu_error)?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("similarities"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("similarities"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        let matrix_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("fingerprint matrix"),
+            contents: bytemuck::cast_slice(matrix),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        Ok(GpuDevice { device, queue, pipeline, matrix: matrix_buffer, rows: matrix.len() / dims, dims })
+    }
+
+    /// Similarity of each query (rows of `dims` values) to every matrix
+    /// row, query by query
+    pub(super) fn similarities(&self, queries: &[f32]) -> Result<Vec<f32>> {
+        let count = queries.len() / self.dims;
+        let size = (count * self.rows * std::mem::size_of::<f32>()) as u64;
+        let query_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("queries"),
+            contents: bytemuck::cast_slice(queries),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        let params = [self.rows as u32, self.dims as u32, count as u32, 0];
+        let params_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("params"),
+            contents: bytemuck::cast_slice(&params),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let scores = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("scores"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: self.matrix.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: query_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: scores.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 3, resource: params_buffer.as_entire_binding() },
+            ],
+        });
+
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((self.rows as u32).div_ceil(WORKGROUP_SIZE), count as u32, 1);
+        }
+        encoder.copy_buffer_to_buffer(&scores, 0, &readback, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        readback.slice(..).map_async(wgpu::MapMode::Read, move |mapped| {
+            let _ = sender.send(mapped);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver.recv().map_err(gpu_error)?.map_err(gpu_error)?;
+        let similarities = bytemuck::cast_slice(&readback.slice(..).get_mapped_range()).to_vec();
+        readback.unmap();
+        Ok(similarities)
+    }
+}
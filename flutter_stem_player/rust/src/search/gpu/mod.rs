@@ -0,0 +1,260 @@
+//! GPU similarity scoring
+//!
+//! A batch search over a very large library compares every query with
+//! every stored vector. With the `gpu` cargo feature, the library's
+//! unit-length vectors are uploaded once as one matrix and a compute
+//! shader scores a whole batch of queries against it per dispatch. The
+//! GPU only shortlists candidates by cosine similarity of the mono
+//! vectors; the shortlist is then scored like any other search (stereo
+//! features, noise penalty, exclusions), so scores match the CPU path.
+//!
+//! Fingerprints written after the upload don't wait for the next one:
+//! `sync` reads just those, and they are scored on the CPU next to the
+//! matrix (replacing their stale rows) until there are enough of them to
+//! be worth re-uploading.
+//!
+//! Without the feature, or without a usable adapter, uploading fails and
+//! searches stay on the CPU.
+
+#[cfg(feature = "gpu")]
+mod device;
+
+use crate::database::PaletteDatabase;
+use crate::fingerprint::AudioFingerprint;
+use crate::{descending_score, AudioPaletteError, Result};
+use std::sync::RwLock;
+
+#[cfg(feature = "gpu")]
+use device::GpuDevice;
+
+/// Candidates shortlisted per result asked for, at least `GPU_MIN_CANDIDATES`
+const GPU_OVERSAMPLING: usize = 4;
+const GPU_MIN_CANDIDATES: usize = 100;
+/// Most scores computed and read back per dispatch (64 MB of f32)
+const MAX_SCORES_PER_DISPATCH: usize = 16 << 20;
+/// Fingerprints scored on the CPU, relative to the rows uploaded, beyond
+/// which a re-upload pays off
+const MAX_PENDING_FRACTION: f64 = 0.1;
+
+/// Placeholder used when the crate is built without the `gpu` feature
+#[cfg(not(feature = "gpu"))]
+struct GpuDevice;
+
+#[cfg(not(feature = "gpu"))]
+impl GpuDevice {
+    fn new(_matrix: &[f32], _dims: usize) -> Result<Self> {
+        Err(AudioPaletteError::GpuError("Built without GPU support (enable the `gpu` feature)".to_string()))
+    }
+
+    fn similarities(&self, _queries: &[f32]) -> Result<Vec<f32>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Unit vectors of fingerprints written since the upload
+#[derive(Debug, Default)]
+struct Pending {
+    /// Library revision they were read at
+    revision: i64,
+    /// Ascending
+    sound_ids: Vec<i64>,
+    vectors: Vec<Vec<f32>>,
+    /// Matrix rows of the uploaded sounds among them, no longer current
+    superseded: Vec<usize>,
+}
+
+/// The library's fingerprint matrix, resident on the GPU
+pub struct GpuScorer {
+    /// Library revision the matrix was uploaded at
+    revision: i64,
+    /// Sound of each matrix row, ascending
+    sound_ids: Vec<i64>,
+    dims: usize,
+    device: GpuDevice,
+    pending: RwLock<Pending>,
+}
+
+impl std::fmt::Debug for GpuScorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GpuScorer")
+            .field("revision", &self.revision)
+            .field("rows", &self.sound_ids.len())
+            .field("dims", &self.dims)
+            .field("pending", &self.pending_len())
+            .finish()
+    }
+}
+
+/// Ids of the `count` highest `scores`, best first (ties by row order,
+/// NaN last)
+fn top_candidates(scores: &[f32], sound_ids: &[i64], count: usize) -> Vec<i64> {
+    let mut rows: Vec<usize> = (0..scores.len()).collect();
+    let order = |a: &usize, b: &usize| descending_score(scores[*a] as f64, scores[*b] as f64).then(a.cmp(b));
+    if rows.len() > count {
+        rows.select_nth_unstable_by(count, order);
+        rows.truncate(count);
+    }
+    rows.sort_unstable_by(order);
+    rows.into_iter().map(|row| sound_ids[row]).collect()
+}
+
+/// `top_candidates` of one query's matrix row `scores`, with the pending
+/// fingerprints scored against `query` (unit length) in place of their
+/// uploaded rows
+fn top_candidates_with_pending(
+    mut scores: Vec<f32>,
+    sound_ids: &[i64],
+    pending: &Pending,
+    query: &[f32],
+    count: usize,
+) -> Vec<i64> {
+    if pending.sound_ids.is_empty() {
+        return top_candidates(&scores, sound_ids, count);
+    }
+    for &row in &pending.superseded {
+        scores[row] = f32::NEG_INFINITY;
+    }
+    scores.extend(pending.vectors.iter().map(|v| v.iter().zip(query).map(|(a, b)| a * b).sum::<f32>()));
+    let ids: Vec<i64> = sound_ids.iter().chain(&pending.sound_ids).copied().collect();
+    top_candidates(&scores, &ids, count)
+}
+
+impl GpuScorer {
+    /// Upload every stored fingerprint vector
+    ///
+    /// Fails without a usable GPU (or the `gpu` feature); callers then
+    /// score on the CPU.
+    pub fn upload(db: &PaletteDatabase) -> Result<Self> {
+        let revision = db.library_revision()?;
+        let (sound_ids, vectors) = db.unit_vectors()?;
+        let dims = vectors.first().map_or(0, Vec::len);
+        if dims == 0 {
+            return Err(AudioPaletteError::GpuError("No fingerprints to upload".to_string()));
+        }
+        let device = GpuDevice::new(&vectors.concat(), dims)?;
+        let pending = RwLock::new(Pending { revision, ..Pending::default() });
+        Ok(GpuScorer { revision, sound_ids, dims, device, pending })
+    }
+
+    /// Sounds uploaded
+    pub fn len(&self) -> usize {
+        self.sound_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sound_ids.is_empty()
+    }
+
+    /// Fingerprints written since the upload, scored on the CPU
+    pub fn pending_len(&self) -> usize {
+        self.pending.read().unwrap().sound_ids.len()
+    }
+
+    /// Whether every change to the library is accounted for, by the
+    /// upload or the last `sync`
+    pub fn is_current(&self, db: &PaletteDatabase) -> Result<bool> {
+        Ok(db.library_revision()? == self.pending.read().unwrap().revision)
+    }
+
+    /// Catch up on fingerprints written since the upload, so sounds added
+    /// while searching are shortlisted by the next search
+    ///
+    /// Safe to call while other threads search with this scorer; they see
+    /// the fingerprints pending before or after the call, never a mix.
+    pub fn sync(&self, db: &PaletteDatabase) -> Result<()> {
+        let revision = db.library_revision()?;
+        if self.pending.read().unwrap().revision == revision {
+            return Ok(());
+        }
+        let (ids, vectors) = db.unit_vectors_since(self.revision)?;
+        let (sound_ids, vectors): (Vec<i64>, Vec<Vec<f32>>) =
+            ids.into_iter().zip(vectors).filter(|(_, v)| v.len() == self.dims).unzip();
+        let superseded = sound_ids.iter().filter_map(|id| self.sound_ids.binary_search(id).ok()).collect();
+        *self.pending.write().unwrap() = Pending { revision, sound_ids, vectors, superseded };
+        Ok(())
+    }
+
+    /// Whether enough has changed since the upload that uploading again
+    /// beats scoring the changes on the CPU every search
+    pub fn needs_upload(&self) -> bool {
+        self.pending_len() as f64 > self.len() as f64 * MAX_PENDING_FRACTION
+    }
+
+    /// For each query, the sounds worth scoring exactly for `limit`
+    /// results: several times `limit` by cosine similarity, best first
+    pub fn shortlists(&self, queries: &[&AudioFingerprint], limit: usize) -> Result<Vec<Vec<i64>>> {
+        let mut flattened = Vec::with_capacity(queries.len() * self.dims);
+        for query in queries {
+            let vector = query.feature_vector(false);
+            if vector.len() != self.dims {
+                return Err(AudioPaletteError::GpuError("Query vector doesn't match the uploaded layout".to_string()));
+            }
+            let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt().max(1e-12);
+            flattened.extend(vector.iter().map(|x| (x / norm) as f32));
+        }
+
+        let count = limit.saturating_mul(GPU_OVERSAMPLING).max(GPU_MIN_CANDIDATES);
+        let per_dispatch = (MAX_SCORES_PER_DISPATCH / self.len().max(1)).max(1);
+        let pending = self.pending.read().unwrap();
+        let mut shortlists = Vec::with_capacity(queries.len());
+        for batch in flattened.chunks(per_dispatch * self.dims) {
+            let scores = self.device.similarities(batch)?;
+            shortlists.extend(scores.chunks(self.len()).zip(batch.chunks(self.dims)).map(|(row, query)| {
+                top_candidates_with_pending(row.to_vec(), &self.sound_ids, &pending, query, count)
+            }));
+        }
+        Ok(shortlists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_scorer() {
+        let scores = [0.2, 0.9, -0.5, 0.9, 0.4];
+        assert_eq!(top_candidates(&scores, &[10, 11, 12, 13, 14], 3), vec![11, 13, 14]);
+        assert_eq!(top_candidates(&scores, &[10, 11, 12, 13, 14], 10).len(), 5);
+
+        // Sound 13 was re-fingerprinted away from the query and 15 added
+        let pending = Pending {
+            revision: 1,
+            sound_ids: vec![13, 15],
+            vectors: vec![vec![0.0, 1.0], vec![0.6, 0.8]],
+            superseded: vec![3],
+        };
+        let ranked = top_candidates_with_pending(scores.to_vec(), &[10, 11, 12, 13, 14], &pending, &[1.0, 0.0], 3);
+        assert_eq!(ranked, vec![11, 15, 14]);
+
+        // Whatever the machine, scoring either runs on a GPU and agrees
+        // with the CPU ranking, or fails so callers fall back
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        assert!(GpuScorer::upload(&db).is_err());
+        let engine = crate::search::SearchEngine::new();
+        let add = |name: &str, freq: f32| {
+            let samples: Vec<f32> =
+                (0..22050).map(|i| (std::f32::consts::TAU * freq * i as f32 / 22050.0).sin() * 0.5).collect();
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, 1.0, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&samples, 22050).unwrap()).unwrap();
+            id
+        };
+        for (name, freq) in [("low", 220.0), ("mid", 880.0), ("high", 3000.0)] {
+            add(name, freq);
+        }
+        if let Ok(scorer) = GpuScorer::upload(&db) {
+            assert_eq!(scorer.len(), 3);
+            let query = db.get_fingerprint(2).unwrap().unwrap();
+            let shortlist = scorer.shortlists(&[&query], 1).unwrap();
+            assert_eq!(shortlist[0][0], db.nearest_fingerprints(&query, 0.0, 1, 0.0, 0.0).unwrap()[0].sound_id);
+
+            // A sound added afterwards is shortlisted without re-uploading
+            let added = add("higher", 5000.0);
+            assert!(!scorer.is_current(&db).unwrap());
+            scorer.sync(&db).unwrap();
+            assert!(scorer.is_current(&db).unwrap() && scorer.pending_len() == 1 && scorer.needs_upload());
+            let query = db.get_fingerprint(added).unwrap().unwrap();
+            assert_eq!(scorer.shortlists(&[&query], 1).unwrap()[0][0], added);
+        }
+    }
+}
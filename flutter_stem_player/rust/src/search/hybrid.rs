@@ -0,0 +1,101 @@
+//! Hybrid text and audio ranking
+//!
+//! "Vinyl kick, sounding like this one" is one intent, but text search and
+//! similarity search each rank by half of it. Here every sound matching
+//! the words and every sound near the query fingerprint is scored on both,
+//! and the two scores are blended into one ranking.
+
+use super::SearchEngine;
+use crate::database::{PaletteDatabase, VectorMatch};
+use crate::fingerprint::AudioFingerprint;
+use crate::{descending_score, MatchResult, Result};
+use std::collections::HashMap;
+
+/// Share of the blended score carried by text relevance
+pub const DEFAULT_TEXT_WEIGHT: f64 = 0.3;
+
+/// Nearest fingerprints gathered per result asked for, on top of the text hits
+const AUDIO_POOL_FACTOR: usize = 4;
+
+impl SearchEngine {
+    /// Rank sounds by `text_weight` (0-1) times text relevance to `text`
+    /// plus the rest times similarity to `query_fp`
+    ///
+    /// Text relevance is 0-100 relative to the best text hit; sounds not
+    /// matching every word score 0 on it. With no words in `text` this is
+    /// a plain similarity search.
+    pub fn find_similar_with_text(
+        &self,
+        query_fp: &AudioFingerprint,
+        text: &str,
+        db: &PaletteDatabase,
+        text_weight: f64,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        let Some(relevance) = db.text_relevance(text)? else {
+            return self.find_similar(query_fp, db, threshold, max_results);
+        };
+        let text_weight = text_weight.clamp(0.0, 1.0);
+
+        let text_hits: Vec<i64> = relevance.keys().copied().collect();
+        let mut similarity: HashMap<i64, VectorMatch> = HashMap::new();
+        let nearby = self.nearest(query_fp, db, None, 0.0, max_results.saturating_mul(AUDIO_POOL_FACTOR))?;
+        let matching = self.nearest(query_fp, db, Some(&text_hits), 0.0, usize::MAX)?;
+        for m in nearby.into_iter().chain(matching) {
+            similarity.insert(m.sound_id, m);
+        }
+
+        let mut ids: Vec<i64> = similarity.keys().chain(text_hits.iter()).copied().collect();
+        ids.sort_unstable();
+        ids.dedup();
+        let mut scored: Vec<VectorMatch> = ids
+            .into_iter()
+            .map(|sound_id| {
+                let (audio, snr_penalty) = similarity.get(&sound_id).map_or((0.0, 0.0), |m| (m.score, m.snr_penalty));
+                let text = relevance.get(&sound_id).copied().unwrap_or(0.0);
+                VectorMatch { sound_id, score: (1.0 - text_weight) * audio + text_weight * text, snr_penalty }
+            })
+            .collect();
+        scored.sort_by(|a, b| descending_score(a.score, b.score).then(a.sound_id.cmp(&b.sound_id)));
+        self.whole_sound_results(scored, db, threshold, max_results, |_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_similar_with_text() {
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let tone = |freq: f32| -> Vec<f32> {
+            (0..22050).map(|i| (std::f32::consts::TAU * freq * i as f32 / 22050.0).sin() * 0.5).collect()
+        };
+        let mut ids = Vec::new();
+        for (name, freq) in [("clean_kick.wav", 60.0), ("vinyl_kick.wav", 70.0), ("vinyl_hat.wav", 6000.0)] {
+            let id = db.add_sound(&format!("/lib/{name}"), name, 1.0, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&tone(freq), 22050).unwrap()).unwrap();
+            ids.push(id);
+        }
+        let query = engine.fingerprint_samples(&tone(60.0), 22050).unwrap();
+
+        // Sound alone prefers the clean kick, words alone don't tell the vinyl ones apart
+        let audio_only = engine.find_similar_with_text(&query, "vinyl", &db, 0.0, 0.0, 3).unwrap();
+        assert_eq!(audio_only[0].sound_id, ids[0]);
+        let text_only = engine.find_similar_with_text(&query, "vinyl", &db, 1.0, 0.0, 3).unwrap();
+        assert_eq!(text_only[0].score, 100.0);
+        assert_eq!(text_only[1].score, 100.0);
+
+        // Blended, the kick that is both vinyl and kick-like wins
+        let blended = engine.find_similar_with_text(&query, "vinyl", &db, 0.5, 0.0, 3).unwrap();
+        assert_eq!(blended[0].sound_id, ids[1]);
+        let audio = audio_only.iter().find(|m| m.sound_id == ids[1]).unwrap().score;
+        assert!((blended[0].score - (0.5 * audio + 50.0)).abs() < 1e-9);
+
+        // No words: a plain similarity search
+        let plain = engine.find_similar_with_text(&query, " ", &db, 0.5, 0.0, 3).unwrap();
+        assert_eq!(plain[0].sound_id, ids[0]);
+    }
+}
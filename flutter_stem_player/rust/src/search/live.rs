@@ -0,0 +1,122 @@
+//! Live queries from a microphone
+//!
+//! "Point the phone at a sound and find it in my library": audio arrives
+//! from the recorder in small chunks, the last few seconds are kept as a
+//! rolling window, and each time another hop of audio has arrived the
+//! window is fingerprinted and searched again, so the matches follow what
+//! is playing now. Silent windows aren't searched, so the matches stay on
+//! the last thing heard.
+
+use super::SearchEngine;
+use crate::database::PaletteDatabase;
+use crate::{MatchResult, Result};
+use std::collections::VecDeque;
+
+/// Seconds of the latest audio fingerprinted per search
+pub const DEFAULT_LIVE_WINDOW_SECONDS: f64 = 3.0;
+/// Seconds of new audio between searches
+pub const LIVE_HOP_SECONDS: f64 = 1.0;
+/// Window RMS below which the input counts as silence
+const SILENCE_RMS: f64 = 1e-3;
+
+/// Rolling window over live mono audio
+#[derive(Debug, Clone)]
+pub struct LiveQuery {
+    sample_rate: u32,
+    window: VecDeque<f32>,
+    capacity: usize,
+    hop: usize,
+    /// Samples pushed since the window was last handed out
+    pending: usize,
+}
+
+impl LiveQuery {
+    pub fn new(sample_rate: u32, window_seconds: f64) -> Self {
+        let seconds = |s: f64| ((s * sample_rate as f64) as usize).max(1);
+        let capacity = seconds(window_seconds);
+        LiveQuery {
+            sample_rate,
+            window: VecDeque::with_capacity(capacity),
+            capacity,
+            hop: seconds(LIVE_HOP_SECONDS).min(capacity),
+            pending: 0,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Add recorded samples; returns the window to search once a hop of
+    /// new audio has arrived since the last one, unless it is silent
+    pub fn push(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        self.window.extend(samples);
+        let excess = self.window.len().saturating_sub(self.capacity);
+        self.window.drain(..excess);
+        self.pending += samples.len();
+        if self.pending < self.hop {
+            return None;
+        }
+        self.pending = 0;
+
+        let rms = (self.window.iter().map(|&x| x as f64 * x as f64).sum::<f64>() / self.window.len() as f64).sqrt();
+        (rms >= SILENCE_RMS).then(|| self.window.iter().copied().collect())
+    }
+}
+
+impl SearchEngine {
+    /// Sounds most like a live window, scored as whole sounds (cheap
+    /// enough to repeat every hop)
+    pub fn find_similar_live(
+        &self,
+        window: &[f32],
+        sample_rate: u32,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        let query_fp = self.fingerprint_samples(window, sample_rate)?;
+        self.find_similar(&query_fp, db, threshold, max_results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_live_query() {
+        let tone = |freq: f32, range: std::ops::Range<usize>| -> Vec<f32> {
+            range.map(|i| (std::f32::consts::TAU * freq * i as f32 / 22050.0).sin() * 0.4).collect()
+        };
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut ids = Vec::new();
+        for (name, freq) in [("low", 220.0), ("high", 3000.0)] {
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, 1.0, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&tone(freq, 0..22050), 22050).unwrap()).unwrap();
+            ids.push(id);
+        }
+
+        // 100 ms chunks: silence, then the low tone, then the high one
+        let mut live = LiveQuery::new(22050, 2.0);
+        let mut searched = Vec::new();
+        for chunk in 0..60 {
+            let range = chunk * 2205..(chunk + 1) * 2205;
+            let samples = match chunk {
+                0..10 => vec![0.0; 2205],
+                10..35 => tone(220.0, range),
+                _ => tone(3000.0, range),
+            };
+            if let Some(window) = live.push(&samples) {
+                assert!(window.len() <= 44100);
+                let matches = engine.find_similar_live(&window, live.sample_rate(), &db, 0.0, 1).unwrap();
+                searched.push((chunk, matches[0].sound_id));
+            }
+        }
+        // One search per second of audio, none over the silence
+        assert_eq!(searched.iter().map(|(chunk, _)| *chunk).collect::<Vec<_>>(), vec![19, 29, 39, 49, 59]);
+        assert_eq!(searched[1].1, ids[0]);
+        assert_eq!(searched[4].1, ids[1]);
+    }
+}
@@ -0,0 +1,172 @@
+//! Loop sources of long queries
+//!
+//! A long phrase built from a two-bar loop scores poorly against that
+//! loop as a whole: segment search compares a query longer than a file
+//! with the whole file only. Here the query is cut into consecutive parts
+//! as long as each shorter candidate and every part is compared with the
+//! candidate, so a loop repeated through the phrase matches part after
+//! part. A summary fingerprint averages over its span, so a part holding
+//! one full cycle of the loop matches whatever phase it starts at.
+
+use super::{SearchEngine, SearchQuery};
+use crate::database::{PaletteDatabase, SoundQuery};
+use crate::{descending_score, AudioPaletteError, Result};
+use rayon::prelude::*;
+
+/// Longest candidate, relative to the query, that counts as a loop of it
+pub const MAX_LOOP_FRACTION: f64 = 0.75;
+/// Shortest candidate considered, in seconds
+const MIN_LOOP_SECONDS: f64 = 0.25;
+/// Shorter sounds most like the whole query that are checked part by part
+const LOOP_CANDIDATES: usize = 50;
+
+/// A sound that loops through part of the query
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopMatch {
+    pub sound_id: i64,
+    pub filepath: String,
+    pub filename: String,
+    /// Mean similarity (0-100) of the query parts it matches
+    pub score: f64,
+    /// Share of the query (0-1) covered by matching parts
+    pub coverage: f64,
+    /// Seconds of the loop
+    pub loop_duration: f64,
+    /// Times the loop fits into the query
+    pub repetitions: f64,
+}
+
+/// Covered length of the union of sorted, possibly overlapping spans
+fn covered(spans: &[(f64, f64)]) -> f64 {
+    let mut total = 0.0;
+    let mut reach = f64::NEG_INFINITY;
+    for &(start, end) in spans {
+        total += (end - start.max(reach)).max(0.0);
+        reach = reach.max(end);
+    }
+    total
+}
+
+impl SearchEngine {
+    /// Find shorter sounds that loop through the query, ranked by
+    /// `score * coverage`
+    ///
+    /// Needs the query audio (`query_from_file`/`query_from_samples`).
+    /// A part matches when it scores at least `threshold`; sounds covering
+    /// less than `min_coverage` (0-1) of the query are left out. Only
+    /// stored fingerprints are compared, so candidates aren't decoded.
+    pub fn find_loop_sources(
+        &self,
+        query: &SearchQuery,
+        db: &PaletteDatabase,
+        threshold: f64,
+        min_coverage: f64,
+        max_results: usize,
+    ) -> Result<Vec<LoopMatch>> {
+        let Some(audio) = query.audio.as_ref().filter(|a| !a.samples.is_empty()) else {
+            return Err(AudioPaletteError::FingerprintError("Loop search needs the query audio".to_string()));
+        };
+        let filter = SoundQuery {
+            min_duration: Some(MIN_LOOP_SECONDS),
+            max_duration: Some(audio.duration * MAX_LOOP_FRACTION),
+            ..SoundQuery::default()
+        };
+        let shorter = db.query_sound_ids(&filter)?;
+        let candidates = self.nearest(&query.fingerprint, db, Some(&shorter), 0.0, LOOP_CANDIDATES)?;
+
+        let rate = audio.sample_rate as f64;
+        let mut matches: Vec<LoopMatch> = candidates
+            .into_par_iter()
+            .filter_map(|candidate| {
+                if self.cancel.is_cancelled() {
+                    return None;
+                }
+                let sound = db.get_sound(candidate.sound_id).ok()??;
+                let loop_fp = db.get_fingerprint(candidate.sound_id).ok()??;
+                let part = ((loop_fp.duration * rate) as usize).max(1);
+                if part >= audio.samples.len() {
+                    return None;
+                }
+
+                // Consecutive parts, the last one ending with the query
+                let count = audio.samples.len().div_ceil(part);
+                let mut spans = Vec::new();
+                let mut total = 0.0;
+                for i in 0..count {
+                    let start = (i * part).min(audio.samples.len() - part);
+                    let samples = &audio.samples[start..start + part];
+                    let Ok(fp) = self.fingerprinter.extract_from_samples(samples, audio.sample_rate) else {
+                        continue;
+                    };
+                    let similarity = fp.similarity_masked(&loop_fp, &self.config.feature_mask);
+                    let score = (similarity - candidate.snr_penalty).max(0.0);
+                    if score >= threshold {
+                        spans.push((start as f64 / rate, (start + part) as f64 / rate));
+                        total += score;
+                    }
+                }
+                let coverage = (covered(&spans) / audio.duration).min(1.0);
+                if spans.is_empty() || coverage < min_coverage {
+                    return None;
+                }
+                Some(LoopMatch {
+                    sound_id: sound.id,
+                    filepath: sound.filepath,
+                    filename: sound.filename,
+                    score: total / spans.len() as f64,
+                    coverage,
+                    loop_duration: loop_fp.duration,
+                    repetitions: audio.duration / loop_fp.duration,
+                })
+            })
+            .collect();
+
+        self.check_cancelled()?;
+        matches.sort_by(|a, b| {
+            descending_score(a.score * a.coverage, b.score * b.coverage).then(a.sound_id.cmp(&b.sound_id))
+        });
+        matches.truncate(max_results);
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_loop_sources() {
+        let rate = 22050;
+        let tone = |freq: f32, i: usize| (std::f32::consts::TAU * freq * i as f32 / rate as f32).sin() * 0.4;
+        // A one-second loop: half a second low, half a second high
+        let looped = |i: usize| if i % rate < rate / 2 { tone(300.0, i) } else { tone(1200.0, i) };
+
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut ids = Vec::new();
+        let sounds = [
+            ("loop", (0..rate).map(looped).collect::<Vec<f32>>()),
+            ("low", (0..rate).map(|i| tone(300.0, i)).collect()),
+            ("hiss", (0..rate).map(|i| tone(5000.0, i)).collect()),
+        ];
+        for (name, samples) in &sounds {
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, 1.0, rate as u32, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(samples, rate as u32).unwrap()).unwrap();
+            ids.push(id);
+        }
+
+        // Three cycles of the loop starting mid-cycle, then a second of something else
+        let mut phrase: Vec<f32> = (rate * 3 / 10..rate * 33 / 10).map(looped).collect();
+        phrase.extend((0..rate).map(|i| tone(3000.0, i) * 0.5 + tone(90.0, i) * 0.5));
+        let query = engine.query_from_samples(&phrase, rate as u32).unwrap();
+
+        let found = engine.find_loop_sources(&query, &db, 95.0, 0.5, 3).unwrap();
+        assert_eq!(found[0].sound_id, ids[0], "{found:?}");
+        assert!((found[0].coverage - 0.75).abs() < 0.01, "{found:?}");
+        assert!((found[0].repetitions - 4.0).abs() < 0.01);
+        assert!(found.iter().all(|m| m.sound_id != ids[2]));
+
+        let fingerprint_only = SearchQuery::from(query.fingerprint.clone());
+        assert!(engine.find_loop_sources(&fingerprint_only, &db, 95.0, 0.5, 3).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! Query by humming
+//!
+//! A hummed or sung melody shares nothing with the original recording but
+//! its pitch contour, and even that in another key and tempo. Both the
+//! query's f0 curve and each sound's stored pitch curve are reduced to
+//! voiced semitone contours centred on their median (so octave and key
+//! drop out up to a few semitones, which are searched), and the query
+//! contour is located inside each sound's by subsequence DTW, so the
+//! melody can be anywhere in the file and sung faster or slower.
+
+use super::best::BestResults;
+use super::dtw::dtw_subsequence;
+use super::SearchEngine;
+use crate::database::PaletteDatabase;
+use crate::fingerprint::{pitch_curve, AnalysisKind};
+use crate::{AudioPaletteError, MatchResult, Result};
+use rayon::prelude::*;
+
+/// Contours keep one value per this many seconds
+const CONTOUR_HOP_SECONDS: f64 = 0.05;
+/// Queries need at least this many voiced contour steps (half a second)
+const MIN_QUERY_STEPS: usize = 10;
+/// Semitones either way the centred query is shifted to find the key
+const MAX_TRANSPOSITION: i32 = 6;
+/// A sung melody can be up to this much slower or faster than the original
+const MAX_TEMPO_STRETCH: f64 = 2.0;
+/// Normalized DTW cost (in semitones) at which the score falls to 1/e of 100
+const MELODY_DISTANCE_SCALE: f64 = 1.0;
+
+/// Voiced pitch in semitones relative to the median, with the time in
+/// seconds each step was taken at
+struct Contour {
+    times: Vec<f64>,
+    semitones: Vec<f64>,
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    values[values.len() / 2]
+}
+
+impl Contour {
+    /// Reduce an f0 curve (Hz every `hop_seconds`, 0 unvoiced) to steps of
+    /// `CONTOUR_HOP_SECONDS`; steps that are mostly unvoiced are dropped
+    fn from_pitch(pitch: &[f64], hop_seconds: f64) -> Self {
+        let step = ((CONTOUR_HOP_SECONDS / hop_seconds.max(1e-6)).round() as usize).max(1);
+        let mut contour = Contour { times: Vec::new(), semitones: Vec::new() };
+        for (k, chunk) in pitch.chunks(step).enumerate() {
+            let mut voiced: Vec<f64> = chunk.iter().copied().filter(|&f| f > 0.0).collect();
+            if voiced.len() * 2 > chunk.len() {
+                contour.times.push((k * step) as f64 * hop_seconds);
+                contour.semitones.push(69.0 + 12.0 * (median(&mut voiced) / 440.0).log2());
+            }
+        }
+        if !contour.semitones.is_empty() {
+            let centre = median(&mut contour.semitones.clone());
+            contour.semitones.iter_mut().for_each(|s| *s -= centre);
+        }
+        contour
+    }
+
+    fn frames(&self, shift: f64) -> Vec<Vec<f64>> {
+        self.semitones.iter().map(|&s| vec![s + shift]).collect()
+    }
+}
+
+impl SearchEngine {
+    /// Find sounds containing the melody hummed or sung in `samples`
+    ///
+    /// Matches against stored pitch curves, so only indexed sounds with a
+    /// current pitch analysis are searched. Results span the matched
+    /// stretch of each sound; scores are 0-100.
+    pub fn find_melody(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        let query = Contour::from_pitch(&pitch_curve(samples, sample_rate), 0.01);
+        if query.semitones.len() < MIN_QUERY_STEPS {
+            return Err(AudioPaletteError::FingerprintError("No melody found in the query".to_string()));
+        }
+        let shifted: Vec<Vec<Vec<f64>>> =
+            (-MAX_TRANSPOSITION..=MAX_TRANSPOSITION).map(|shift| query.frames(shift as f64)).collect();
+
+        let results = BestResults::new(threshold, max_results, |_: &[MatchResult]| {});
+        db.get_analyses(AnalysisKind::Pitch)?.into_par_iter().for_each(|(sound_id, track)| {
+            if self.cancel.is_cancelled() {
+                return;
+            }
+            let contour = Contour::from_pitch(&track.values, track.hop_seconds);
+            let target = contour.frames(0.0);
+            let Some(aligned) = shifted
+                .iter()
+                .filter_map(|frames| dtw_subsequence(frames, &target, MAX_TEMPO_STRETCH))
+                .min_by(|a, b| a.distance.total_cmp(&b.distance))
+            else {
+                return;
+            };
+            let Ok(Some(sound)) = db.get_sound(sound_id) else {
+                return;
+            };
+            results.offer(MatchResult {
+                sound_id,
+                filepath: sound.filepath,
+                filename: sound.filename,
+                score: 100.0 * (-aligned.distance / MELODY_DISTANCE_SCALE).exp(),
+                match_start: contour.times[aligned.start],
+                match_end: (contour.times[aligned.end] + CONTOUR_HOP_SECONDS).min(sound.duration),
+                file_duration: sound.duration,
+                time_resolution: CONTOUR_HOP_SECONDS,
+            });
+        });
+
+        self.check_cancelled()?;
+        Ok(results.into_results())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::AnalysisTrack;
+
+    /// Sine notes (MIDI numbers) of `seconds` each
+    fn melody(notes: &[f64], seconds: f64) -> Vec<f32> {
+        let rate = 22050.0;
+        let per_note = (seconds * rate) as usize;
+        notes
+            .iter()
+            .flat_map(|&note| {
+                let freq = 440.0 * 2f64.powf((note - 69.0) / 12.0);
+                (0..per_note).map(move |i| (0.5 * (std::f64::consts::TAU * freq * i as f64 / rate).sin()) as f32)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_melody() {
+        let tune = [60.0, 62.0, 64.0, 60.0, 67.0, 65.0, 64.0];
+        let other = [72.0, 71.0, 69.0, 71.0, 72.0, 74.0, 76.0];
+        // The tune a minor third up and slower, after a second of something else; then only something else
+        let mut song = melody(&[57.0, 57.0, 57.0, 57.0], 0.25);
+        song.extend(melody(&tune.map(|n| n + 3.0), 0.3));
+        song.extend(melody(&[57.0, 57.0], 0.25));
+        let unrelated = melody(&[other, other].concat(), 0.25);
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let mut ids = Vec::new();
+        for (name, samples) in [("song", &song), ("unrelated", &unrelated)] {
+            let duration = samples.len() as f64 / 22050.0;
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, duration, 22050, 1, "wav").unwrap();
+            let pitch = AnalysisTrack { kind: AnalysisKind::Pitch, hop_seconds: 0.01, values: pitch_curve(samples, 22050) };
+            db.store_analyses_batch(&[(id, vec![pitch])]).unwrap();
+            ids.push(id);
+        }
+
+        let engine = SearchEngine::new();
+        let hummed = melody(&tune.map(|n| n - 12.0), 0.25);
+        let found = engine.find_melody(&hummed, 22050, &db, 0.0, 2).unwrap();
+        assert_eq!(found[0].sound_id, ids[0]);
+        assert!(found[0].score > found[1].score + 10.0, "{found:?}");
+        assert!((found[0].match_start - 1.0).abs() < 0.15, "{found:?}");
+        assert!((found[0].match_end - 3.1).abs() < 0.15, "{found:?}");
+
+        assert!(engine.find_melody(&vec![0.0; 22050], 22050, &db, 0.0, 2).is_err());
+    }
+}
@@ -1,14 +1,166 @@
 //! Similarity search with segment matching
 
-use crate::{MatchResult, Result, SoundRecord};
+mod align;
+pub mod batch;
+mod best;
+mod budget;
+mod cache;
+mod calibration;
+pub mod containment;
+mod diversity;
+mod dtw;
+mod examples;
+pub mod folder;
+mod gpu;
+mod hybrid;
+mod live;
+pub mod loops;
+mod melody;
+pub mod presets;
+mod selection;
+
+use crate::{AudioPaletteError, CancellationToken, MatchResult, Result, SoundRecord};
 use crate::audio::AudioData;
-use crate::database::PaletteDatabase;
-use crate::fingerprint::{AudioFingerprint, Fingerprinter};
+use crate::database::{PaletteDatabase, Region, SmartQuery, SoundQuery, VectorMatch};
+use crate::fingerprint::{AudioFingerprint, FeatureMask, Fingerprinter, SegmentMatch, NOISY_SNR_DB};
+use diversity::maximal_marginal_relevance;
+use best::BestResults;
+use budget::MemoryBudget;
 use rayon::prelude::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+pub use align::{locate, normalized_cross_correlation, refine_offset};
+pub use batch::{BatchMatches, BatchQuery};
+pub use budget::DEFAULT_MEMORY_BUDGET;
+pub use cache::{QueryCache, QUERY_CACHE_CAPACITY};
+pub use calibration::{ScoreCalibration, CALIBRATION_SAMPLE};
+pub use containment::{ContainmentMatch, DEFAULT_MIN_CORRELATION};
+pub use dtw::{dtw_distance, dtw_similarity, dtw_subsequence, DtwAlignment};
+pub use examples::{ExampleQuery, DEFAULT_NEGATIVE_WEIGHT};
+pub use folder::{FolderCache, FolderMatch, FOLDER_CACHE_CAPACITY};
+pub use gpu::GpuScorer;
+pub use hybrid::DEFAULT_TEXT_WEIGHT;
+pub use live::{LiveQuery, DEFAULT_LIVE_WINDOW_SECONDS, LIVE_HOP_SECONDS};
+pub use loops::{LoopMatch, MAX_LOOP_FRACTION};
+pub use presets::SearchPreset;
+
+/// Sliding windows overlap by three quarters, a hop of a quarter window
+pub const DEFAULT_SEGMENT_OVERLAP: f64 = 0.75;
+const MAX_SEGMENT_OVERLAP: f64 = 0.95;
+/// Sliding windows per file before the hop widens
+pub const DEFAULT_MAX_SEGMENT_WINDOWS: usize = 50;
+
+/// Candidates gathered per result asked for when re-ranking for diversity
+const DIVERSITY_POOL_FACTOR: usize = 4;
+/// Similarity to an excluded reference from which sounds are left out
+pub const DEFAULT_EXCLUSION_THRESHOLD: f64 = 85.0;
+
+/// Search engine configuration
+#[derive(Debug, Clone)]
+pub struct SearchConfig {
+    /// Rerank segment matches by DTW over frame-level MFCCs
+    pub dtw_rerank: bool,
+    /// Weight of the DTW score when blended with the fingerprint score (0-1)
+    pub dtw_weight: f64,
+    /// Sakoe-Chiba band as a fraction of sequence length
+    pub dtw_band: f64,
+    /// Align segment matches to the query by subsequence DTW, so a phrase
+    /// played slower or faster still lines up; match boundaries follow the
+    /// warping and the score blends in the alignment by `dtw_weight`
+    pub dtw_align: bool,
+    /// Longest (and, inverted, shortest) aligned match relative to the query
+    pub dtw_max_stretch: f64,
+    /// Refine segment offsets to sample accuracy by cross-correlating with the query audio
+    pub refine_offsets: bool,
+    /// Report every non-overlapping segment above the threshold in each
+    /// file, not just the best one
+    pub all_segments: bool,
+    /// Re-rank results by maximal marginal relevance, trading score for
+    /// difference from results already picked (0 disables, 1 is maximal)
+    pub diversity: f64,
+    /// Feature groups left out of every comparison (e.g. everything but
+    /// timbre); masked searches score every stored fingerprint exactly
+    pub feature_mask: FeatureMask,
+    /// Score points subtracted per dB a candidate's SNR falls below `min_snr_db` (0 disables)
+    pub snr_penalty: f64,
+    /// SNR below which candidates are penalized
+    pub min_snr_db: f64,
+    /// Report scores (and take thresholds) as the percentage of the
+    /// library the query is less similar to, instead of raw similarity
+    pub calibrate_scores: bool,
+    /// Sounds scoring at least `exclusion_threshold` against any of these
+    /// are left out ("not like these"), e.g. a family already rejected;
+    /// unlike an `ExampleQuery`'s negatives they don't move the ranking
+    pub excluded: Vec<AudioFingerprint>,
+    pub exclusion_threshold: f64,
+    /// Score exactly only the sounds whose LSH signatures are nearest the
+    /// query's: far less work on large libraries, at the cost of rarely
+    /// missing a match (ignored with a feature mask)
+    pub lsh_prefilter: bool,
+    /// Most bytes of decoded audio a segment search holds at once; files
+    /// wait their turn to decode when it's spent (0 is unlimited)
+    pub memory_budget: usize,
+    /// Library matrix on the GPU for shortlisting batch candidates, caught
+    /// up with fingerprints added since its upload before each batch;
+    /// batches score on the CPU without it, or when it fails
+    pub gpu: Option<Arc<GpuScorer>>,
+    /// Sliding-window length in seconds for files without stored segment
+    /// fingerprints (0 uses the query's length)
+    pub segment_window: f64,
+    /// Overlap of consecutive sliding windows (0 to 0.95): more is finer
+    /// and slower
+    pub segment_overlap: f64,
+    /// Most sliding windows per file; longer files get a wider hop, so a
+    /// coarser time resolution (0 is no cap)
+    pub max_segment_windows: usize,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        SearchConfig {
+            dtw_rerank: false,
+            dtw_weight: 0.5,
+            dtw_band: 0.1,
+            dtw_align: false,
+            dtw_max_stretch: 1.5,
+            refine_offsets: true,
+            all_segments: false,
+            diversity: 0.0,
+            feature_mask: FeatureMask::default(),
+            snr_penalty: 0.0,
+            min_snr_db: NOISY_SNR_DB,
+            calibrate_scores: false,
+            excluded: Vec::new(),
+            exclusion_threshold: DEFAULT_EXCLUSION_THRESHOLD,
+            lsh_prefilter: false,
+            memory_budget: DEFAULT_MEMORY_BUDGET,
+            gpu: None,
+            segment_window: 0.0,
+            segment_overlap: DEFAULT_SEGMENT_OVERLAP,
+            max_segment_windows: DEFAULT_MAX_SEGMENT_WINDOWS,
+        }
+    }
+}
+
+/// Search query: the summary fingerprint plus, when available, the decoded
+/// query audio used by refinement steps (DTW reranking, offset alignment)
+pub struct SearchQuery {
+    pub fingerprint: AudioFingerprint,
+    pub(crate) audio: Option<AudioData>,
+}
+
+impl From<AudioFingerprint> for SearchQuery {
+    fn from(fingerprint: AudioFingerprint) -> Self {
+        SearchQuery { fingerprint, audio: None }
+    }
+}
 
 /// Similarity search engine
 pub struct SearchEngine {
     fingerprinter: Fingerprinter,
+    config: SearchConfig,
+    cancel: CancellationToken,
 }
 
 impl Default for SearchEngine {
@@ -17,13 +169,39 @@ impl Default for SearchEngine {
     }
 }
 
+/// Bytes a sound takes decoded (mono f32)
+fn decoded_bytes(sound: &SoundRecord) -> usize {
+    (sound.duration * sound.sample_rate as f64) as usize * std::mem::size_of::<f32>()
+}
+
 impl SearchEngine {
     pub fn new() -> Self {
+        Self::with_config(SearchConfig::default())
+    }
+
+    pub fn with_config(config: SearchConfig) -> Self {
         SearchEngine {
             fingerprinter: Fingerprinter::default(),
+            config,
+            cancel: CancellationToken::new(),
         }
     }
 
+    /// Stop searches with `Cancelled` once `token` is cancelled, checked
+    /// between candidates
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// `Cancelled` if the token has been triggered
+    fn check_cancelled(&self) -> Result<()> {
+        if self.cancel.is_cancelled() {
+            return Err(AudioPaletteError::Cancelled);
+        }
+        Ok(())
+    }
+
     /// Find similar sounds in database
     pub fn find_similar(
         &self,
@@ -32,29 +210,103 @@ impl SearchEngine {
         threshold: f64,
         max_results: usize,
     ) -> Result<Vec<MatchResult>> {
-        let fingerprints = db.get_all_fingerprints()?;
-
-        // Step 1: Parallel fingerprint comparison (no database access)
-        let mut scored: Vec<_> = fingerprints
-            .par_iter()
-            .filter_map(|(sound_id, fp)| {
-                let score = query_fp.similarity(fp);
-                if score >= threshold {
-                    Some((*sound_id, score))
-                } else {
-                    None
-                }
-            })
-            .collect();
+        self.find_similar_streaming(query_fp, db, threshold, max_results, |_| {})
+    }
 
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-        scored.truncate(max_results);
+    /// `find_similar`, handing the best results so far to `on_update`
+    /// each time they change
+    pub fn find_similar_streaming(
+        &self,
+        query_fp: &AudioFingerprint,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+    ) -> Result<Vec<MatchResult>> {
+        self.calibrated(query_fp, db, threshold, on_update, |threshold, on_update| {
+            // Step 1: Nearest-neighbour ranking inside SQLite
+            let scored = self.nearest(query_fp, db, None, threshold, self.pool_size(max_results))?;
 
-        // Step 2: Sequential database lookups for matching sounds
-        let mut results = Vec::new();
-        for (sound_id, score) in scored {
+            // Step 2: Sequential database lookups for matching sounds
+            self.whole_sound_results(scored, db, threshold, max_results, on_update)
+        })
+    }
+
+    /// Find sounds similar to one already in the library ("more like
+    /// this"), using its stored fingerprint; the sound itself is left out
+    ///
+    /// A sound whose stored fingerprint is outdated (or missing) is
+    /// fingerprinted from its file instead.
+    pub fn find_similar_to_sound(
+        &self,
+        sound_id: i64,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        let query_fp = match db.get_fingerprint(sound_id)? {
+            Some(fp) => fp,
+            None => {
+                let sound = db.get_sound(sound_id)?.ok_or_else(|| {
+                    AudioPaletteError::FingerprintError(format!("Sound {} has no stored fingerprint", sound_id))
+                })?;
+                self.fingerprint_file(&sound.filepath)?
+            }
+        };
+        let mut matches = self.find_similar(&query_fp, db, threshold, max_results.saturating_add(1))?;
+        matches.retain(|m| m.sound_id != sound_id);
+        matches.truncate(max_results);
+        Ok(matches)
+    }
+
+    /// Find similar sounds among those matching `filter` (its sort and
+    /// paging are ignored), e.g. "like this kick, but only one-shots under
+    /// a second"
+    ///
+    /// The filter runs in SQL first, so only sounds passing it are scored.
+    pub fn find_similar_matching(
+        &self,
+        query_fp: &AudioFingerprint,
+        db: &PaletteDatabase,
+        filter: &SoundQuery,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        self.find_similar_matching_streaming(query_fp, db, filter, threshold, max_results, |_| {})
+    }
+
+    /// `find_similar_matching`, handing the best results so far to
+    /// `on_update` each time they change
+    pub fn find_similar_matching_streaming(
+        &self,
+        query_fp: &AudioFingerprint,
+        db: &PaletteDatabase,
+        filter: &SoundQuery,
+        threshold: f64,
+        max_results: usize,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+    ) -> Result<Vec<MatchResult>> {
+        let allowed = db.query_sound_ids(filter)?;
+        self.calibrated(query_fp, db, threshold, on_update, |threshold, on_update| {
+            let scored = self.nearest(query_fp, db, Some(&allowed), threshold, self.pool_size(max_results))?;
+            self.whole_sound_results(scored, db, threshold, max_results, on_update)
+        })
+    }
+
+    /// Whole-sound matches for ranked fingerprints
+    fn whole_sound_results(
+        &self,
+        scored: Vec<VectorMatch>,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+    ) -> Result<Vec<MatchResult>> {
+        let results = BestResults::new(threshold, self.pool_size(max_results), self.pool_updates(&on_update));
+        for VectorMatch { sound_id, score, .. } in scored {
+            self.check_cancelled()?;
             if let Ok(Some(sound)) = db.get_sound(sound_id) {
-                results.push(MatchResult {
+                results.offer(MatchResult {
                     sound_id,
                     filepath: sound.filepath.clone(),
                     filename: sound.filename.clone(),
@@ -62,77 +314,272 @@ impl SearchEngine {
                     match_start: 0.0,
                     match_end: sound.duration,
                     file_duration: sound.duration,
+                    time_resolution: 0.0,
                 });
             }
         }
 
-        Ok(results)
+        Ok(self.diversify(results.into_results(), db, max_results, &on_update))
+    }
+
+    /// Candidates to gather for `max_results` results: more when
+    /// diversity re-ranking will choose among them
+    fn pool_size(&self, max_results: usize) -> usize {
+        if self.config.diversity > 0.0 {
+            max_results.saturating_mul(DIVERSITY_POOL_FACTOR)
+        } else {
+            max_results
+        }
+    }
+
+    /// Best-so-far updates while gathering candidates; with diversity
+    /// re-ranking the pool isn't the result yet, so only the final
+    /// re-ranked list is sent
+    fn pool_updates<'a>(
+        &self,
+        on_update: &'a (impl Fn(&[MatchResult]) + Sync),
+    ) -> impl Fn(&[MatchResult]) + Sync + 'a {
+        let diversify = self.config.diversity > 0.0;
+        move |best| {
+            if !diversify {
+                on_update(best)
+            }
+        }
+    }
+
+    /// Re-rank a candidate pool for diversity (unchanged when disabled)
+    fn diversify(
+        &self,
+        pool: Vec<MatchResult>,
+        db: &PaletteDatabase,
+        max_results: usize,
+        on_update: &impl Fn(&[MatchResult]),
+    ) -> Vec<MatchResult> {
+        if self.config.diversity <= 0.0 {
+            return pool;
+        }
+        let fingerprints: Vec<Option<AudioFingerprint>> =
+            pool.iter().map(|m| db.get_fingerprint(m.sound_id).ok().flatten()).collect();
+        let picked = maximal_marginal_relevance(pool, &fingerprints, self.config.diversity, max_results);
+        on_update(&picked);
+        picked
     }
 
     /// Find similar sounds with segment matching
     /// Returns exact time ranges where matches occur
     pub fn find_similar_with_segments(
         &self,
-        query_fp: &AudioFingerprint,
+        query: &SearchQuery,
         db: &PaletteDatabase,
         threshold: f64,
         max_results: usize,
     ) -> Result<Vec<MatchResult>> {
-        // First pass: quick whole-file matching (parallel, no db access)
-        let fingerprints = db.get_all_fingerprints()?;
-
-        let mut scored: Vec<_> = fingerprints
-            .par_iter()
-            .filter_map(|(sound_id, fp)| {
-                let score = query_fp.similarity(fp);
-                // Lower threshold for initial filtering
-                if score >= threshold * 0.8 {
-                    Some((*sound_id, score))
-                } else {
-                    None
-                }
-            })
-            .collect();
+        self.find_similar_with_segments_streaming(query, db, threshold, max_results, |_| {})
+    }
 
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-        scored.truncate(20); // Top 20 for segment matching
+    /// `find_similar_with_segments`, handing the best results so far to
+    /// `on_update` as each candidate is scored
+    pub fn find_similar_with_segments_streaming(
+        &self,
+        query: &SearchQuery,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+    ) -> Result<Vec<MatchResult>> {
+        self.calibrated(&query.fingerprint, db, threshold, on_update, |threshold, on_update| {
+            self.segment_results(query, db, threshold, max_results, on_update)
+        })
+    }
 
-        // Get sound records sequentially
+    /// Segment matches by raw score for `find_similar_with_segments_streaming`
+    fn segment_results(
+        &self,
+        query: &SearchQuery,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+    ) -> Result<Vec<MatchResult>> {
+        let query_fp = &query.fingerprint;
+
+        // First pass: quick whole-file matching, with a lower threshold for
+        // initial filtering and the top 20 kept for segment matching
+        let scored = self.nearest(query_fp, db, None, threshold * 0.8, 20)?;
+
+        // Get sound records sequentially (keeping each candidate's noise penalty)
         let mut candidates: Vec<(SoundRecord, f64)> = Vec::new();
-        for (sound_id, score) in scored {
+        for VectorMatch { sound_id, snr_penalty: penalty, .. } in scored {
             if let Ok(Some(sound)) = db.get_sound(sound_id) {
-                candidates.push((sound, score));
+                candidates.push((sound, penalty));
             }
         }
 
-        // Second pass: segment matching (parallel, file I/O only)
-        let results: Vec<MatchResult> = candidates
-            .into_par_iter()
-            .filter_map(|(sound, _)| {
-                self.find_best_segment(query_fp, &sound.filepath, &sound).ok()
-            })
-            .filter(|m| m.score >= threshold)
-            .collect();
+        let query_frames = self.query_frames(query);
 
-        let mut sorted: Vec<_> = results;
-        sorted.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-        sorted.truncate(max_results);
+        // Second pass: segment matching against stored segment fingerprints
+        // (parallel; files are only decoded when stored ones are missing or
+        // refinement needs the audio, and only as many at once as the
+        // memory budget allows)
+        let budget = MemoryBudget::new(self.config.memory_budget);
+        let results = BestResults::new(threshold, self.pool_size(max_results), self.pool_updates(&on_update));
+        candidates.into_par_iter().for_each(|(sound, penalty)| {
+            if self.cancel.is_cancelled() {
+                return;
+            }
+            if let Ok(matches) = self.find_segments(query, query_frames.as_deref(), db, &sound, threshold, &budget) {
+                for mut m in matches {
+                    m.score = (m.score - penalty).max(0.0);
+                    results.offer(m);
+                }
+            }
+        });
 
-        Ok(sorted)
+        self.check_cancelled()?;
+        Ok(self.diversify(results.into_results(), db, max_results, &on_update))
     }
 
-    /// Find the best matching segment in a file
-    fn find_best_segment(
+    /// Find the user-marked regions most similar to the query
+    ///
+    /// Only sounds with regions are decoded (once each); every region is
+    /// fingerprinted from its audio and scored as a whole.
+    pub fn find_similar_in_regions(
         &self,
-        query_fp: &AudioFingerprint,
-        filepath: &str,
-        sound: &SoundRecord,
-    ) -> Result<MatchResult> {
-        let audio = AudioData::load(filepath)?;
+        query: &SearchQuery,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        self.find_similar_in_regions_streaming(query, db, threshold, max_results, |_| {})
+    }
+
+    /// `find_similar_in_regions`, handing the best results so far to
+    /// `on_update` as each region is scored
+    pub fn find_similar_in_regions_streaming(
+        &self,
+        query: &SearchQuery,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+        on_update: impl Fn(&[MatchResult]) + Sync,
+    ) -> Result<Vec<MatchResult>> {
+        // Regions come ordered by sound; group them so each file loads once
+        let mut grouped: Vec<(SoundRecord, Vec<Region>)> = Vec::new();
+        for region in db.get_all_regions()? {
+            match grouped.last_mut() {
+                Some((sound, regions)) if sound.id == region.sound_id => regions.push(region),
+                _ => {
+                    if let Ok(Some(sound)) = db.get_sound(region.sound_id) {
+                        grouped.push((sound, vec![region]));
+                    }
+                }
+            }
+        }
+
+        let query_frames = self.query_frames(query);
+        let query_frames = query_frames.as_deref();
+        let results = BestResults::new(threshold, max_results, on_update);
+        grouped.into_par_iter().for_each(|(sound, regions)| {
+            if self.cancel.is_cancelled() {
+                return;
+            }
+            let Ok(audio) = AudioData::load(&sound.filepath) else {
+                return;
+            };
+            let rate = audio.sample_rate as f64;
+            for region in regions {
+                let segment = audio.get_range((region.start_time * rate) as usize, (region.end_time * rate) as usize);
+                let Ok(fp) = self.fingerprinter.extract_from_samples(&segment, audio.sample_rate) else {
+                    continue;
+                };
+                let score = query.fingerprint.similarity_masked(&fp, &self.config.feature_mask);
+                results.offer(MatchResult {
+                    sound_id: sound.id,
+                    filepath: sound.filepath.clone(),
+                    filename: sound.filename.clone(),
+                    score: self.rerank_score(score, query_frames, &segment, audio.sample_rate),
+                    match_start: region.start_time,
+                    match_end: region.end_time,
+                    file_duration: audio.duration,
+                    time_resolution: 0.0,
+                });
+            }
+        });
+
+        self.check_cancelled()?;
+        Ok(results.into_results())
+    }
+
+    /// Evaluate a smart collection's query against the palette as it is now
+    ///
+    /// Without a reference, results are whole sounds in the filter's sort
+    /// order with a score of 100; with one, they are ranked by similarity
+    /// to `reference` like `find_similar`.
+    pub fn evaluate_smart_query(
+        &self,
+        query: &SmartQuery,
+        reference: Option<&AudioFingerprint>,
+        db: &PaletteDatabase,
+    ) -> Result<Vec<MatchResult>> {
+        let mut filter = query.filter.clone();
+        let (offset, limit) = (filter.offset.max(0) as usize, filter.limit);
+        filter.offset = 0;
+        filter.limit = None;
+        let mut sounds = db.query_sounds(&filter)?.sounds;
+
+        if !query.tags.is_empty() {
+            let tagged: HashSet<i64> = db
+                .find_by_tags(&query.tags, false, query.min_tag_confidence)?
+                .into_iter()
+                .map(|s| s.id)
+                .collect();
+            sounds.retain(|s| tagged.contains(&s.id));
+        }
+
+        let results = match reference {
+            None => sounds
+                .into_iter()
+                .map(|sound| MatchResult {
+                    sound_id: sound.id,
+                    filepath: sound.filepath,
+                    filename: sound.filename,
+                    score: 100.0,
+                    match_start: 0.0,
+                    match_end: sound.duration,
+                    file_duration: sound.duration,
+                    time_resolution: 0.0,
+                })
+                .collect(),
+            Some(fp) => {
+                let allowed: Vec<i64> = sounds.iter().map(|s| s.id).collect();
+                let scored = self.nearest(fp, db, Some(&allowed), query.threshold, usize::MAX)?;
+                self.whole_sound_results(scored, db, query.threshold, usize::MAX, |_| {})?
+            }
+        };
+        let limit = limit.map_or(usize::MAX, |l| l.max(0) as usize);
+        Ok(results.into_iter().skip(offset).take(limit).collect())
+    }
 
-        let query_duration = query_fp.duration;
-        if query_duration <= 0.0 {
-            return Ok(MatchResult {
+    /// Find the best matching segment in a file, or with `all_segments`
+    /// every non-overlapping one scoring at least `threshold`
+    ///
+    /// Matches against the sound's stored segment fingerprints when there
+    /// are some for its current contents, decoding the file only if offset
+    /// refinement or DTW reranking needs its audio; otherwise fingerprints
+    /// a sliding window over the decoded file.
+    fn find_segments(
+        &self,
+        query: &SearchQuery,
+        query_frames: Option<&[Vec<f64>]>,
+        db: &PaletteDatabase,
+        sound: &SoundRecord,
+        threshold: f64,
+        budget: &MemoryBudget,
+    ) -> Result<Vec<MatchResult>> {
+        let query_fp = &query.fingerprint;
+        let needs_audio = query_frames.is_some() || (self.config.refine_offsets && query.audio.is_some());
+        if query_fp.duration <= 0.0 {
+            return Ok(vec![MatchResult {
                 sound_id: sound.id,
                 filepath: sound.filepath.clone(),
                 filename: sound.filename.clone(),
@@ -140,64 +587,376 @@ impl SearchEngine {
                 match_start: 0.0,
                 match_end: sound.duration,
                 file_duration: sound.duration,
-            });
+                time_resolution: 0.0,
+            }]);
+        }
+
+        if let Some(segments) = db.get_segment_fingerprints(sound.id)? {
+            let spans = segments.spans(query_fp, &self.config.feature_mask);
+            if !spans.is_empty() {
+                let found = self.select_spans(spans, threshold);
+                if !needs_audio {
+                    return Ok(found
+                        .into_iter()
+                        .map(|span| MatchResult {
+                            sound_id: sound.id,
+                            filepath: sound.filepath.clone(),
+                            filename: sound.filename.clone(),
+                            score: span.score,
+                            match_start: span.start,
+                            match_end: span.end.min(sound.duration),
+                            file_duration: sound.duration,
+                            time_resolution: segments.hop_seconds,
+                        })
+                        .collect());
+                }
+                if found.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                let _permit = budget.acquire(decoded_bytes(sound));
+                let audio = AudioData::load(&sound.filepath)?;
+                let rate = audio.sample_rate as f64;
+                return Ok(found
+                    .into_iter()
+                    .map(|span| {
+                        let pos = ((span.start * rate) as usize).min(audio.samples.len());
+                        let window = ((span.end - span.start) * rate) as usize;
+                        // Spans covering the whole sound have no offset to refine
+                        let hop = if window >= audio.samples.len() { 0 } else { (segments.hop_seconds * rate) as usize };
+                        self.refine_segment(query, query_frames, sound, &audio, span.score, pos, window, hop)
+                    })
+                    .collect());
+            }
         }
 
-        // If query is longer than file, compare whole file
-        if query_duration >= audio.duration {
+        // Without refinement only the current window is needed, so the
+        // file is scanned as it decodes instead of loaded whole
+        let window_seconds = self.window_seconds(query_fp);
+        if !needs_audio && window_seconds < sound.duration {
+            return self.streamed_segments(query_fp, sound, threshold);
+        }
+
+        let _permit = budget.acquire(decoded_bytes(sound));
+        let audio = AudioData::load(&sound.filepath)?;
+
+        // If the window is longer than the file, compare the whole file
+        if window_seconds >= audio.duration {
             let fp = self.fingerprinter.extract(&audio)?;
-            let score = query_fp.similarity(&fp);
-            return Ok(MatchResult {
-                sound_id: sound.id,
-                filepath: sound.filepath.clone(),
-                filename: sound.filename.clone(),
-                score,
-                match_start: 0.0,
-                match_end: audio.duration,
-                file_duration: audio.duration,
-            });
+            let score = query_fp.similarity_masked(&fp, &self.config.feature_mask);
+            return Ok(vec![self.refine_segment(query, query_frames, sound, &audio, score, 0, audio.samples.len(), 0)]);
         }
 
         // Sliding window search
-        let window_samples = (query_duration * audio.sample_rate as f64) as usize;
-        let hop_samples = window_samples / 4; // 75% overlap
-        let max_windows = 50;
+        let window_samples = (window_seconds * audio.sample_rate as f64) as usize;
+        let actual_hop = self.window_hop(window_samples, audio.samples.len());
 
-        let actual_hop = if audio.samples.len() / hop_samples > max_windows {
-            (audio.samples.len() - window_samples) / max_windows
-        } else {
-            hop_samples
-        };
-
-        let mut best_score = 0.0;
-        let mut best_start = 0.0;
-        let mut best_end = query_duration;
+        let rate = audio.sample_rate as f64;
+        let mut spans = Vec::new();
 
         let mut pos = 0;
-        while pos + window_samples <= audio.samples.len() {
+        while pos + window_samples <= audio.samples.len() && !self.cancel.is_cancelled() {
             let segment = &audio.samples[pos..pos + window_samples];
 
             if let Ok(segment_fp) = self.fingerprinter.extract_from_samples(segment, audio.sample_rate) {
-                let score = query_fp.similarity(&segment_fp);
-                if score > best_score {
-                    best_score = score;
-                    best_start = pos as f64 / audio.sample_rate as f64;
-                    best_end = (pos + window_samples) as f64 / audio.sample_rate as f64;
-                }
+                let start = pos as f64 / rate;
+                let end = (pos + window_samples) as f64 / rate;
+                spans.push(SegmentMatch { score: query_fp.similarity_masked(&segment_fp, &self.config.feature_mask), start, end });
             }
 
             pos += actual_hop;
         }
 
-        Ok(MatchResult {
+        // Nothing fingerprinted still reports the start of the file
+        if spans.is_empty() {
+            spans.push(SegmentMatch { score: 0.0, start: 0.0, end: window_samples as f64 / rate });
+        }
+        Ok(self
+            .select_spans(spans, threshold)
+            .into_iter()
+            .map(|span| {
+                let pos = (span.start * rate).round() as usize;
+                self.refine_segment(query, query_frames, sound, &audio, span.score, pos, window_samples, actual_hop)
+            })
+            .collect())
+    }
+
+    /// Sliding-window segment matches of a file scanned as it decodes,
+    /// holding no more than a window and a packet of audio
+    fn streamed_segments(&self, query_fp: &AudioFingerprint, sound: &SoundRecord, threshold: f64) -> Result<Vec<MatchResult>> {
+        let mask = &self.config.feature_mask;
+        // Window and hop are fixed by the first packet's sample rate
+        let mut geometry: Option<(usize, usize, f64)> = None;
+        let mut buffer: Vec<f32> = Vec::new();
+        // Samples already dropped from the front of the buffer
+        let mut dropped = 0;
+        let mut next = 0;
+        let mut spans = Vec::new();
+
+        AudioData::stream(&sound.filepath, |samples, sample_rate| {
+            let rate = sample_rate as f64;
+            let (window, hop, rate) = *geometry.get_or_insert_with(|| {
+                let window = (self.window_seconds(query_fp) * rate) as usize;
+                (window, self.window_hop(window, (sound.duration * rate) as usize), rate)
+            });
+            buffer.extend_from_slice(samples);
+            while next + window <= dropped + buffer.len() {
+                let segment = &buffer[next - dropped..next - dropped + window];
+                if let Ok(segment_fp) = self.fingerprinter.extract_from_samples(segment, sample_rate) {
+                    let (start, end) = (next as f64 / rate, (next + window) as f64 / rate);
+                    spans.push(SegmentMatch { score: query_fp.similarity_masked(&segment_fp, mask), start, end });
+                }
+                next += hop;
+            }
+            // Nothing before the next window is needed again
+            let done = (next - dropped).min(buffer.len());
+            buffer.drain(..done);
+            dropped += done;
+            !self.cancel.is_cancelled()
+        })?;
+
+        // Nothing fingerprinted still reports the start of the file
+        if spans.is_empty() {
+            let end = self.window_seconds(query_fp).min(sound.duration);
+            spans.push(SegmentMatch { score: 0.0, start: 0.0, end });
+        }
+        let resolution = geometry.map_or(0.0, |(_, hop, rate)| hop as f64 / rate);
+        Ok(self
+            .select_spans(spans, threshold)
+            .into_iter()
+            .map(|span| MatchResult {
+                sound_id: sound.id,
+                filepath: sound.filepath.clone(),
+                filename: sound.filename.clone(),
+                score: span.score,
+                match_start: span.start,
+                match_end: span.end.min(sound.duration),
+                file_duration: sound.duration,
+                time_resolution: resolution,
+            })
+            .collect())
+    }
+
+    /// Length of the sliding window compared with the query
+    fn window_seconds(&self, query_fp: &AudioFingerprint) -> f64 {
+        if self.config.segment_window > 0.0 { self.config.segment_window } else { query_fp.duration }
+    }
+
+    /// Hop between sliding windows for the configured overlap, widened so
+    /// a long file is covered in at most `max_segment_windows` windows
+    fn window_hop(&self, window_samples: usize, total_samples: usize) -> usize {
+        let overlap = self.config.segment_overlap.clamp(0.0, MAX_SEGMENT_OVERLAP);
+        let hop_samples = ((window_samples as f64 * (1.0 - overlap)) as usize).max(1);
+        let max_windows = self.config.max_segment_windows;
+        if max_windows > 0 && total_samples / hop_samples > max_windows {
+            (total_samples.saturating_sub(window_samples) / max_windows).max(1)
+        } else {
+            hop_samples
+        }
+    }
+
+    /// Seconds between sliding windows over a file of `file_duration`
+    /// for this query: the time resolution of a segment search before
+    /// offset refinement
+    pub fn segment_resolution(&self, query_fp: &AudioFingerprint, file_duration: f64) -> f64 {
+        // Any rate gives the same answer up to rounding; use the query's
+        let rate = f64::from(query_fp.sample_rate.max(1));
+        let window = (self.window_seconds(query_fp) * rate) as usize;
+        self.window_hop(window, (file_duration * rate) as usize) as f64 / rate
+    }
+
+    /// The best span, or with `all_segments` every non-overlapping one
+    /// scoring at least `threshold`
+    fn select_spans(&self, spans: Vec<SegmentMatch>, threshold: f64) -> Vec<SegmentMatch> {
+        if self.config.all_segments {
+            SegmentMatch::non_overlapping(spans, threshold)
+        } else {
+            SegmentMatch::best(spans).into_iter().collect()
+        }
+    }
+
+    /// Refine a hop-granular match at `pos` to sample accuracy within one
+    /// hop either side (0 to keep it), then apply DTW reranking; with
+    /// `dtw_align`, warp-align the query around it instead
+    #[allow(clippy::too_many_arguments)]
+    fn refine_segment(
+        &self,
+        query: &SearchQuery,
+        query_frames: Option<&[Vec<f64>]>,
+        sound: &SoundRecord,
+        audio: &AudioData,
+        score: f64,
+        pos: usize,
+        window_samples: usize,
+        hop: usize,
+    ) -> MatchResult {
+        let rate = audio.sample_rate as f64;
+        if let Some(frames) = query_frames.filter(|_| self.config.dtw_align) {
+            if let Some((start, end, dtw_score)) = self.align_segment(frames, audio, pos, window_samples, hop) {
+                let weight = self.config.dtw_weight.clamp(0.0, 1.0);
+                return MatchResult {
+                    sound_id: sound.id,
+                    filepath: sound.filepath.clone(),
+                    filename: sound.filename.clone(),
+                    score: (1.0 - weight) * score + weight * dtw_score,
+                    match_start: start as f64 / rate,
+                    match_end: end as f64 / rate,
+                    file_duration: audio.duration,
+                    time_resolution: self.fingerprinter.mfcc_frame_hop() as f64 / rate,
+                };
+            }
+        }
+
+        let mut best_pos = pos;
+        let mut best_end = ((pos + window_samples).min(audio.samples.len())) as f64 / rate;
+        let mut resolution = hop as f64 / rate;
+
+        if self.config.refine_offsets && score > 0.0 && hop > 0 {
+            if let Some(query_audio) = query.audio.as_ref().filter(|q| q.sample_rate == audio.sample_rate) {
+                let search_start = pos.saturating_sub(hop);
+                let search_end = pos + hop;
+                if let Some((offset, _)) = refine_offset(&query_audio.samples, &audio.samples, search_start, search_end) {
+                    let matched_len = query_audio.samples.len().min(audio.samples.len() - offset);
+                    best_pos = offset;
+                    best_end = (offset + matched_len) as f64 / rate;
+                    resolution = 1.0 / rate;
+                }
+            }
+        }
+
+        let best_segment = &audio.samples[best_pos..(best_pos + window_samples).min(audio.samples.len())];
+        MatchResult {
             sound_id: sound.id,
             filepath: sound.filepath.clone(),
             filename: sound.filename.clone(),
-            score: best_score,
-            match_start: best_start,
+            score: self.rerank_score(score, query_frames, best_segment, audio.sample_rate),
+            match_start: best_pos as f64 / rate,
             match_end: best_end,
             file_duration: audio.duration,
-        })
+            time_resolution: resolution,
+        }
+    }
+
+    /// Subsequence-DTW alignment of the query around a match at `pos`:
+    /// (start sample, end sample, DTW similarity)
+    ///
+    /// Searches from a hop before `pos` to as far as the longest allowed
+    /// stretch of the window reaches.
+    fn align_segment(
+        &self,
+        query_frames: &[Vec<f64>],
+        audio: &AudioData,
+        pos: usize,
+        window_samples: usize,
+        hop: usize,
+    ) -> Option<(usize, usize, f64)> {
+        let stretch = self.config.dtw_max_stretch.max(1.0);
+        let region_start = pos.saturating_sub(hop).min(audio.samples.len());
+        let region_end = (pos + (window_samples as f64 * stretch) as usize + hop).min(audio.samples.len());
+        let target = self
+            .fingerprinter
+            .extract_mfcc_frames(&audio.samples[region_start..region_end], audio.sample_rate)
+            .ok()?;
+        let aligned = dtw_subsequence(query_frames, &target, stretch)?;
+
+        let frame_hop = self.fingerprinter.mfcc_frame_hop();
+        let start = region_start + aligned.start * frame_hop;
+        let end = (region_start + aligned.end * frame_hop + self.fingerprinter.mfcc_frame_length()).min(region_end);
+        Some((start, end, aligned.similarity()))
+    }
+
+    /// Query frames for DTW reranking or alignment (computed once, shared
+    /// across candidates)
+    fn query_frames(&self, query: &SearchQuery) -> Option<Vec<Vec<f64>>> {
+        match (&query.audio, self.config.dtw_rerank || self.config.dtw_align) {
+            (Some(audio), true) => self.fingerprinter.extract_mfcc_frames(&audio.samples, audio.sample_rate).ok(),
+            _ => None,
+        }
+    }
+
+    /// Stored fingerprints similar to the query (only those of `among`
+    /// if given), penalized for noisy takes, leaving out excluded sounds
+    fn nearest(
+        &self,
+        query_fp: &AudioFingerprint,
+        db: &PaletteDatabase,
+        among: Option<&[i64]>,
+        threshold: f64,
+        limit: usize,
+    ) -> Result<Vec<VectorMatch>> {
+        let excluded = self.excluded_sounds(db)?;
+        let (penalty, min_snr_db) = (self.config.snr_penalty, self.config.min_snr_db);
+        let mask = &self.config.feature_mask;
+        // Ask for as many more as could be left out
+        let wanted = limit.saturating_add(excluded.len());
+        let shortlist;
+        let mut matches = if !mask.is_empty() {
+            db.nearest_fingerprints_masked(query_fp, mask, among, threshold, wanted, penalty, min_snr_db)?
+        } else if self.config.lsh_prefilter {
+            shortlist = db.lsh_candidates(query_fp, among, wanted)?;
+            db.nearest_fingerprints_among(query_fp, &shortlist, threshold, wanted, penalty, min_snr_db)?
+        } else {
+            match among {
+                Some(ids) => db.nearest_fingerprints_among(query_fp, ids, threshold, wanted, penalty, min_snr_db)?,
+                None => db.nearest_fingerprints(query_fp, threshold, wanted, penalty, min_snr_db)?,
+            }
+        };
+        matches.retain(|m| !excluded.contains(&m.sound_id));
+        matches.truncate(limit);
+        Ok(matches)
+    }
+
+    /// Sounds at least `exclusion_threshold` similar to an excluded
+    /// reference (every one is scored exactly, without noise penalties)
+    fn excluded_sounds(&self, db: &PaletteDatabase) -> Result<HashSet<i64>> {
+        let (mask, threshold) = (&self.config.feature_mask, self.config.exclusion_threshold);
+        let mut excluded = HashSet::new();
+        for reference in &self.config.excluded {
+            let matches = if mask.is_empty() {
+                db.nearest_fingerprints(reference, threshold, usize::MAX, 0.0, 0.0)?
+            } else {
+                db.nearest_fingerprints_masked(reference, mask, None, threshold, usize::MAX, 0.0, 0.0)?
+            };
+            excluded.extend(matches.into_iter().map(|m| m.sound_id));
+        }
+        Ok(excluded)
+    }
+
+    /// Blend a fingerprint score with DTW similarity over frame-level MFCCs
+    /// (returns the score unchanged when DTW reranking is disabled)
+    fn rerank_score(
+        &self,
+        score: f64,
+        query_frames: Option<&[Vec<f64>]>,
+        segment: &[f32],
+        sample_rate: u32,
+    ) -> f64 {
+        let Some(query_frames) = query_frames.filter(|_| self.config.dtw_rerank) else {
+            return score;
+        };
+
+        match self.fingerprinter.extract_mfcc_frames(segment, sample_rate) {
+            Ok(segment_frames) => {
+                let dtw_score = dtw_similarity(query_frames, &segment_frames, Some(self.config.dtw_band));
+                let weight = self.config.dtw_weight.clamp(0.0, 1.0);
+                (1.0 - weight) * score + weight * dtw_score
+            }
+            Err(_) => score,
+        }
+    }
+
+    /// Build a search query from a file, keeping decoded audio for refinement
+    pub fn query_from_file(&self, filepath: &str) -> Result<SearchQuery> {
+        let audio = AudioData::load(filepath)?;
+        let fingerprint = self.fingerprinter.extract(&audio)?;
+        Ok(SearchQuery { fingerprint, audio: Some(audio) })
+    }
+
+    /// Build a search query from samples, keeping them for refinement
+    pub fn query_from_samples(&self, samples: &[f32], sample_rate: u32) -> Result<SearchQuery> {
+        let audio = AudioData::from_samples(samples.to_vec(), sample_rate);
+        let fingerprint = self.fingerprinter.extract(&audio)?;
+        Ok(SearchQuery { fingerprint, audio: Some(audio) })
     }
 
     /// Fingerprint audio from file
@@ -214,11 +973,179 @@ impl SearchEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::audio::write_test_wav;
 
     #[test]
     fn test_search_engine() {
         let engine = SearchEngine::new();
-        // Basic instantiation test
-        assert!(true);
+        let db = PaletteDatabase::open_in_memory().unwrap();
+
+        // Searching an empty palette yields no matches
+        let samples: Vec<f32> = (0..44100).map(|i| (i as f32 * 0.05).sin()).collect();
+        let query_fp = engine.fingerprint_samples(&samples, 44100).unwrap();
+        let results = engine.find_similar(&query_fp, &db, 0.0, 10).unwrap();
+        assert!(results.is_empty());
+
+        // A cancelled search fails instead of returning partial results
+        let cancel = CancellationToken::new();
+        let engine = SearchEngine::new().with_cancellation(cancel.clone());
+        cancel.cancel();
+        let query = SearchQuery::from(query_fp);
+        assert!(matches!(
+            engine.find_similar_with_segments(&query, &db, 0.0, 10),
+            Err(AudioPaletteError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_find_similar_in_regions() {
+        let dir = tempfile::tempdir().unwrap();
+        let tone = |freq: f32, i: usize| (2.0 * std::f32::consts::PI * freq * i as f32 / 22050.0).sin();
+        let samples: Vec<f32> = (0..44100).map(|i| tone(if i < 22050 { 220.0 } else { 3000.0 }, i) * 0.366).collect();
+        let path = write_test_wav(dir.path(), "take.wav", &samples, 22050);
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let id = db.add_sound(path.to_str().unwrap(), "take.wav", 2.0, 22050, 1, "wav").unwrap();
+        db.add_region(id, 0.0, 1.0, "low", "").unwrap();
+        db.add_region(id, 1.0, 2.0, "high", "").unwrap();
+
+        let engine = SearchEngine::new();
+        let samples: Vec<f32> = (0..22050).map(|i| tone(3000.0, i) * 0.4).collect();
+        let query = engine.query_from_samples(&samples, 22050).unwrap();
+        let results = engine.find_similar_in_regions(&query, &db, 0.0, 10).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!((results[0].match_start, results[0].match_end), (1.0, 2.0));
+        assert!(results[0].score > results[1].score);
+    }
+
+    #[test]
+    fn test_dtw_aligned_segments() {
+        // A rising glide; the take plays it 25% slower between stretches of hiss
+        let rate = 22050;
+        let phrase = |stretch: f64| -> Vec<f32> {
+            let length = 0.9 * stretch;
+            (0..(length * rate as f64) as usize)
+                .map(|i| {
+                    let t = i as f64 / rate as f64;
+                    let phase = 200.0 * t + 1800.0 * t * t / (2.0 * length);
+                    (0.5 * (std::f64::consts::TAU * phase).sin()) as f32
+                })
+                .collect()
+        };
+        let hiss = |len: usize| (0..len).map(|i| 0.01 * ((((i as f32) * 12.9898).sin() * 43758.545).fract() - 0.5));
+        let take: Vec<f32> = hiss(rate).chain(phrase(1.25)).chain(hiss(rate * 3 / 2)).collect();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_test_wav(dir.path(), "take.wav", &take, rate as u32);
+
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let duration = take.len() as f64 / rate as f64;
+        let id = db.add_sound(path.to_str().unwrap(), "take.wav", duration, rate as u32, 1, "wav").unwrap();
+        let plain = SearchEngine::new();
+        db.store_fingerprint(id, &plain.fingerprint_samples(&take, rate as u32).unwrap()).unwrap();
+
+        let config = SearchConfig { dtw_align: true, refine_offsets: false, ..SearchConfig::default() };
+        let engine = SearchEngine::with_config(config);
+        let query = engine.query_from_samples(&phrase(1.0), rate as u32).unwrap();
+        let found = &engine.find_similar_with_segments(&query, &db, 0.0, 1).unwrap()[0];
+        // The match spans the slowed phrase (1.0s to 2.125s), not the query's 0.9s
+        assert!((found.match_start - 1.0).abs() < 0.1, "{found:?}");
+        assert!((found.match_end - 2.125).abs() < 0.15, "{found:?}");
+    }
+
+    #[test]
+    fn test_evaluate_smart_query() {
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let tone = |freq: f32| -> Vec<f32> {
+            (0..22050).map(|i| (2.0 * std::f32::consts::PI * freq * i as f32 / 22050.0).sin() * 0.5).collect()
+        };
+        let mut ids = Vec::new();
+        for (name, duration, freq) in [("snare_a", 0.4, 200.0), ("snare_b", 0.8, 2500.0), ("pad", 0.5, 200.0)] {
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, duration, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&tone(freq), 22050).unwrap()).unwrap();
+            ids.push(id);
+        }
+        db.tag_sound(ids[0], "snare", 1.0, "user").unwrap();
+        db.tag_sound(ids[1], "snare", 1.0, "user").unwrap();
+
+        // "All snares under 1s", then only those resembling a 200 Hz reference
+        let mut query = SmartQuery { tags: vec!["snare".to_string()], ..SmartQuery::default() };
+        query.filter.max_duration = Some(1.0);
+        let found = engine.evaluate_smart_query(&query, None, &db).unwrap();
+        assert_eq!(found.iter().map(|m| m.sound_id).collect::<Vec<_>>(), vec![ids[0], ids[1]]);
+
+        let reference = engine.fingerprint_samples(&tone(200.0), 22050).unwrap();
+        let all = engine.evaluate_smart_query(&query, Some(&reference), &db).unwrap();
+        assert_eq!(all[0].sound_id, ids[0]);
+        query.threshold = (all[0].score + all[1].score) / 2.0;
+        let similar = engine.evaluate_smart_query(&query, Some(&reference), &db).unwrap();
+        assert_eq!(similar.iter().map(|m| m.sound_id).collect::<Vec<_>>(), vec![ids[0]]);
+
+        // More like the first snare: the pad shares its tone, and the snare itself is left out
+        let more = engine.find_similar_to_sound(ids[0], &db, 0.0, 1).unwrap();
+        assert_eq!(more.iter().map(|m| m.sound_id).collect::<Vec<_>>(), vec![ids[2]]);
+
+        // Filters straight on a similarity search: the pad is closest but not a snare
+        let short_snares = SoundQuery { max_duration: Some(0.5), ..SoundQuery::new().tag("snare") };
+        let found = engine.find_similar_matching(&reference, &db, &short_snares, 0.0, 10).unwrap();
+        assert_eq!(found.iter().map(|m| m.sound_id).collect::<Vec<_>>(), vec![ids[0]]);
+
+        // New sounds are picked up on the next evaluation
+        let late = db.add_sound("/lib/snare_c.wav", "snare_c", 0.2, 22050, 1, "wav").unwrap();
+        db.tag_sound(late, "snare", 1.0, "user").unwrap();
+        assert_eq!(engine.evaluate_smart_query(&query, None, &db).unwrap().len(), 3);
+        // ...but have no fingerprint to search with yet, nor a file to compute one from
+        assert!(engine.find_similar_to_sound(late, &db, 0.0, 1).is_err());
+    }
+
+    #[test]
+    fn test_excluded_sounds() {
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        let tone = |freq: f32| -> Vec<f32> {
+            (0..22050).map(|i| (std::f32::consts::TAU * freq * i as f32 / 22050.0).sin() * 0.5).collect()
+        };
+        let engine = SearchEngine::new();
+        let mut ids = Vec::new();
+        for (name, freq) in [("low_a", 200.0), ("low_b", 210.0), ("mid", 800.0), ("high", 4000.0)] {
+            let id = db.add_sound(&format!("/lib/{name}.wav"), name, 1.0, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_samples(&tone(freq), 22050).unwrap()).unwrap();
+            ids.push(id);
+        }
+        let query = engine.fingerprint_samples(&tone(205.0), 22050).unwrap();
+        let found = |engine: &SearchEngine| -> Vec<i64> {
+            engine.find_similar(&query, &db, 0.0, 2).unwrap().iter().map(|m| m.sound_id).collect()
+        };
+        assert_eq!(found(&engine), vec![ids[0], ids[1]]);
+
+        // Not like the low tones: both drop out and the next best fill in
+        let reference = db.get_fingerprint(ids[0]).unwrap().unwrap();
+        let low_b = db.get_fingerprint(ids[1]).unwrap().unwrap();
+        let exclusion_threshold = reference.similarity(&low_b) - 0.5;
+        let not_low = SearchEngine::with_config(SearchConfig {
+            excluded: vec![reference],
+            exclusion_threshold,
+            ..SearchConfig::default()
+        });
+        let results = found(&not_low);
+        assert_eq!(results.len(), 2);
+        assert!(!results.contains(&ids[0]) && !results.contains(&ids[1]));
+    }
+
+    #[test]
+    fn test_segment_resolution() {
+        let samples: Vec<f32> = (0..22050).map(|i| (i as f32 * 0.05).sin() * 0.5).collect();
+        let query = SearchEngine::new().fingerprint_samples(&samples, 22050).unwrap();
+        let resolution = |config: SearchConfig, file_duration: f64| {
+            SearchEngine::with_config(config).segment_resolution(&query, file_duration)
+        };
+
+        // A quarter of the one-second window, until 50 windows no longer cover the file
+        assert!((resolution(SearchConfig::default(), 10.0) - 0.25).abs() < 1e-3);
+        assert!((resolution(SearchConfig::default(), 60.0) - 59.0 / 50.0).abs() < 1e-3);
+        let uncapped = SearchConfig { max_segment_windows: 0, ..SearchConfig::default() };
+        assert!((resolution(uncapped, 60.0) - 0.25).abs() < 1e-3);
+        let half = SearchConfig { segment_overlap: 0.5, segment_window: 2.0, ..SearchConfig::default() };
+        assert!((resolution(half, 10.0) - 1.0).abs() < 1e-3);
     }
 }
@@ -0,0 +1,124 @@
+//! Search presets for common kinds of material
+//!
+//! The right settings depend on what's being searched: a kick's pitch is
+//! noise but a bassline's is the point, a texture has no onsets to align
+//! and a line of dialogue is rarely read at the same pace twice. A preset
+//! bundles the feature groups compared, the threshold and the segment
+//! settings suited to one kind of material.
+
+use super::{SearchConfig, DEFAULT_MAX_SEGMENT_WINDOWS};
+use crate::fingerprint::{FeatureGroup, FeatureMask};
+use serde::{Deserialize, Serialize};
+
+/// Kind of material a search is tuned for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SearchPreset {
+    /// Single drum hits: short, pitch-agnostic, matched at sample accuracy
+    DrumOneShots,
+    /// Bars of a pitched part: harmony counts and tempo may drift
+    MelodicLoops,
+    /// Beds, room tones and textures: broad spectral character over seconds
+    Ambience,
+    /// Speech: voice timbre, tolerant of pace and background noise
+    Dialogue,
+}
+
+impl SearchPreset {
+    pub const ALL: [SearchPreset; 4] =
+        [SearchPreset::DrumOneShots, SearchPreset::MelodicLoops, SearchPreset::Ambience, SearchPreset::Dialogue];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchPreset::DrumOneShots => "drum_one_shots",
+            SearchPreset::MelodicLoops => "melodic_loops",
+            SearchPreset::Ambience => "ambience",
+            SearchPreset::Dialogue => "dialogue",
+        }
+    }
+
+    /// Minimum score (0-100) worth showing for this material
+    pub fn threshold(&self) -> f64 {
+        match self {
+            // Hits of one drum are close; anything less is another drum
+            SearchPreset::DrumOneShots => 75.0,
+            SearchPreset::MelodicLoops => 65.0,
+            SearchPreset::Dialogue => 60.0,
+            // Textures vary a lot over their length
+            SearchPreset::Ambience => 55.0,
+        }
+    }
+
+    /// Search settings for this material, the rest left at their defaults
+    pub fn config(&self) -> SearchConfig {
+        match self {
+            SearchPreset::DrumOneShots => SearchConfig {
+                // Tuning and panning vary between kits of the same drum
+                feature_mask: FeatureMask::ignoring(&[FeatureGroup::Harmony, FeatureGroup::Stereo]),
+                refine_offsets: true,
+                ..SearchConfig::default()
+            },
+            SearchPreset::MelodicLoops => SearchConfig {
+                dtw_align: true,
+                segment_overlap: 0.875,
+                max_segment_windows: 2 * DEFAULT_MAX_SEGMENT_WINDOWS,
+                ..SearchConfig::default()
+            },
+            SearchPreset::Ambience => SearchConfig {
+                feature_mask: FeatureMask::only(&[FeatureGroup::Timbre, FeatureGroup::Spectrum, FeatureGroup::Stereo]),
+                // Long, coarse windows: there's no attack to line up
+                segment_window: 4.0,
+                segment_overlap: 0.5,
+                refine_offsets: false,
+                diversity: 0.3,
+                ..SearchConfig::default()
+            },
+            SearchPreset::Dialogue => SearchConfig {
+                feature_mask: FeatureMask::only(&[FeatureGroup::Timbre, FeatureGroup::Spectrum, FeatureGroup::Energy]),
+                dtw_align: true,
+                // Location recordings: prefer the clean takes
+                snr_penalty: 1.0,
+                ..SearchConfig::default()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::AudioFingerprint;
+
+    #[test]
+    fn test_search_presets() {
+        let names: std::collections::HashSet<&str> = SearchPreset::ALL.iter().map(|p| p.name()).collect();
+        assert_eq!(names.len(), SearchPreset::ALL.len());
+        for preset in SearchPreset::ALL {
+            let config = preset.config();
+            assert!((0.0..=100.0).contains(&preset.threshold()));
+            assert!((0.0..1.0).contains(&config.segment_overlap));
+        }
+
+        // The same hit tuned up a fifth is still that drum, not that loop
+        let hit = |chroma: Vec<f64>| AudioFingerprint {
+            duration: 0.3,
+            sample_rate: 44100,
+            mfcc_mean: (0..13).map(|i| (i as f64 + 1.0).sin() * 10.0).collect(),
+            mfcc_std: vec![1.0; 13],
+            spectral_centroid: 2000.0,
+            spectral_bandwidth: 1500.0,
+            spectral_rolloff: 5000.0,
+            rms_mean: 0.2,
+            rms_std: 0.05,
+            zero_crossing_rate: 0.1,
+            chroma_mean: chroma,
+            noise: None,
+            stereo: None,
+        };
+        let low = hit((0..12).map(|i| if i == 0 { 1.0 } else { 0.0 }).collect());
+        let high = hit((0..12).map(|i| if i == 7 { 1.0 } else { 0.0 }).collect());
+        let drums = SearchPreset::DrumOneShots.config().feature_mask;
+        let loops = SearchPreset::MelodicLoops.config().feature_mask;
+        assert!((low.similarity_masked(&high, &drums) - 100.0).abs() < 1e-6);
+        assert!(low.similarity_masked(&high, &loops) < low.similarity_masked(&high, &drums));
+    }
+}
@@ -0,0 +1,86 @@
+//! Queries from a stored sound's time selection
+//!
+//! Searching by a selection used to mean copying its samples out to Dart
+//! and back over the bridge. Given the sound and the range instead, only
+//! that range is decoded, here, and the selection itself is left out of
+//! the results.
+
+use super::{SearchEngine, SearchQuery};
+use crate::audio::AudioData;
+use crate::database::PaletteDatabase;
+use crate::{AudioPaletteError, MatchResult, Result};
+
+impl SearchEngine {
+    /// Build a search query from `start`..`end` seconds of a stored sound
+    pub fn query_from_region(&self, sound_id: i64, start: f64, end: f64, db: &PaletteDatabase) -> Result<SearchQuery> {
+        let sound = db
+            .get_sound(sound_id)?
+            .ok_or_else(|| AudioPaletteError::RegionError(format!("no sound {}", sound_id)))?;
+        if !(start >= 0.0 && end > start && start < sound.duration) {
+            return Err(AudioPaletteError::RegionError(format!(
+                "invalid selection {}..{} of sound {} ({}s long)",
+                start, end, sound_id, sound.duration
+            )));
+        }
+        let audio = AudioData::load_range(&sound.filepath, start, end)?;
+        let fingerprint = self.fingerprinter.extract(&audio)?;
+        Ok(SearchQuery { fingerprint, audio: Some(audio) })
+    }
+
+    /// Find segments similar to `start`..`end` seconds of a stored sound
+    ///
+    /// Matches overlapping the selection in its own sound are left out;
+    /// other parts of the same sound still count.
+    pub fn find_similar_from_region(
+        &self,
+        sound_id: i64,
+        start: f64,
+        end: f64,
+        db: &PaletteDatabase,
+        threshold: f64,
+        max_results: usize,
+    ) -> Result<Vec<MatchResult>> {
+        let query = self.query_from_region(sound_id, start, end, db)?;
+        let mut matches = self.find_similar_with_segments(&query, db, threshold, max_results.saturating_add(1))?;
+        matches.retain(|m| m.sound_id != sound_id || m.match_end <= start || m.match_start >= end);
+        matches.truncate(max_results);
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::write_test_wav;
+
+    #[test]
+    fn test_find_similar_from_region() {
+        let tone = |freq: f32, i: usize| (std::f32::consts::TAU * freq * i as f32 / 22050.0).sin() * 0.5;
+        let dir = tempfile::tempdir().unwrap();
+        let engine = SearchEngine::new();
+        let db = PaletteDatabase::open_in_memory().unwrap();
+        // A take going from low to high, and a second of each tone alone
+        let add = |name: &str, freqs: &[f32]| {
+            let samples: Vec<f32> = (0..22050 * freqs.len()).map(|i| tone(freqs[i / 22050], i)).collect();
+            let path = write_test_wav(dir.path(), &format!("{name}.wav"), &samples, 22050);
+            let path = path.to_str().unwrap();
+            let id = db.add_sound(path, name, freqs.len() as f64, 22050, 1, "wav").unwrap();
+            db.store_fingerprint(id, &engine.fingerprint_file(path).unwrap()).unwrap();
+            id
+        };
+        let take = add("take", &[220.0, 3000.0]);
+        let ids = [add("low", &[220.0]), add("high", &[3000.0])];
+
+        // Only the selected second is decoded, and the selection itself
+        // isn't a result
+        let query = engine.query_from_region(take, 1.0, 2.0, &db).unwrap();
+        assert_eq!(query.audio.as_ref().unwrap().samples.len(), 22050);
+        let results = engine.find_similar_from_region(take, 1.0, 2.0, &db, 0.0, 1).unwrap();
+        assert_eq!(results[0].sound_id, ids[1]);
+        let results = engine.find_similar_from_region(take, 0.0, 1.0, &db, 0.0, 1).unwrap();
+        assert_eq!(results[0].sound_id, ids[0]);
+
+        assert!(engine.query_from_region(take, 1.5, 1.0, &db).is_err());
+        assert!(engine.query_from_region(take + 100, 0.0, 1.0, &db).is_err());
+    }
+}
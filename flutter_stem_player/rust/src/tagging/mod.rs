@@ -0,0 +1,82 @@
+//! Genre/mood auto-tagging with an optional ONNX model
+//!
+//! The model receives log-mel patches shaped `[1, patch_frames, n_mels]`
+//! (hop = `n_fft / 4`) and returns one score per label. Scores are averaged
+//! over all patches of a file. Labels come from a text file, one per line,
+//! and are stored as tag names verbatim (e.g. `genre:techno`, `mood:dark`).
+//!
+//! Inference requires the `onnx` cargo feature; without it, loading a model
+//! returns an error and indexing proceeds without model tags.
+
+#[cfg(feature = "onnx")]
+mod onnx;
+
+use crate::{AudioPaletteError, Result};
+use std::path::Path;
+
+#[cfg(feature = "onnx")]
+pub use onnx::ModelTagger;
+
+/// Tagging model configuration (must match how the model was trained)
+#[derive(Debug, Clone)]
+pub struct TaggerConfig {
+    /// Sample rate the model expects
+    pub sample_rate: u32,
+    pub n_fft: usize,
+    pub n_mels: usize,
+    /// Frames per model input patch
+    pub patch_frames: usize,
+    /// Apply a sigmoid to raw model outputs (for models that emit logits)
+    pub apply_sigmoid: bool,
+    /// Minimum confidence for a tag to be kept
+    pub threshold: f64,
+    /// Maximum tags kept per sound
+    pub max_tags: usize,
+}
+
+impl Default for TaggerConfig {
+    fn default() -> Self {
+        TaggerConfig {
+            sample_rate: 16000,
+            n_fft: 512,
+            n_mels: 96,
+            patch_frames: 187,
+            apply_sigmoid: false,
+            threshold: 0.3,
+            max_tags: 5,
+        }
+    }
+}
+
+/// Read model labels (one per line, blank lines ignored)
+pub fn load_labels<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    let text = std::fs::read_to_string(path)?;
+    let labels: Vec<String> = text
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    if labels.is_empty() {
+        return Err(AudioPaletteError::TaggingError("Label file is empty".to_string()));
+    }
+    Ok(labels)
+}
+
+/// Placeholder used when the crate is built without the `onnx` feature
+#[cfg(not(feature = "onnx"))]
+pub struct ModelTagger;
+
+#[cfg(not(feature = "onnx"))]
+impl ModelTagger {
+    pub fn load(_model_path: &str, _labels: Vec<String>, _config: TaggerConfig) -> Result<Self> {
+        Err(AudioPaletteError::TaggingError(
+            "Built without ONNX support (enable the `onnx` feature)".to_string(),
+        ))
+    }
+
+    /// Predicted (label, confidence) pairs, best first
+    pub fn tag(&self, _samples: &[f32], _sample_rate: u32) -> Result<Vec<(String, f64)>> {
+        Ok(Vec::new())
+    }
+}
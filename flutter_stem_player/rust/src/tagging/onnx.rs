@@ -0,0 +1,90 @@
+//! ONNX model inference via tract
+
+use super::TaggerConfig;
+use crate::{descending_score, AudioPaletteError, Result};
+use crate::audio::AudioData;
+use crate::fingerprint::MfccExtractor;
+use tract_onnx::prelude::*;
+
+/// Genre/mood tagger backed by an ONNX model
+pub struct ModelTagger {
+    model: TypedRunnableModel<TypedModel>,
+    labels: Vec<String>,
+    config: TaggerConfig,
+    mel: MfccExtractor,
+}
+
+fn tagging_error(e: impl std::fmt::Display) -> AudioPaletteError {
+    AudioPaletteError::TaggingError(e.to_string())
+}
+
+impl ModelTagger {
+    /// Load and optimize a model for the configured input shape
+    pub fn load(model_path: &str, labels: Vec<String>, config: TaggerConfig) -> Result<Self> {
+        let model = tract_onnx::onnx()
+            .model_for_path(model_path)
+            .and_then(|m| m.with_input_fact(0, f32::fact([1, config.patch_frames, config.n_mels]).into()))
+            .and_then(|m| m.into_optimized())
+            .and_then(|m| m.into_runnable())
+            .map_err(tagging_error)?;
+
+        let mel = MfccExtractor::with_mels(0, config.n_fft, config.n_mels);
+        Ok(ModelTagger { model, labels, config, mel })
+    }
+
+    /// Predicted (label, confidence) pairs, best first
+    pub fn tag(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<(String, f64)>> {
+        let audio = AudioData::from_samples(samples.to_vec(), sample_rate).resample(self.config.sample_rate);
+        let frames = self.mel.log_mel_frames(&audio.samples, audio.sample_rate)?;
+
+        let n_mels = self.config.n_mels;
+        let patch_frames = self.config.patch_frames.max(1);
+        let mut scores = vec![0.0_f64; self.labels.len()];
+        let mut n_patches = 0;
+
+        // Non-overlapping patches; a clip shorter than one patch is zero-padded
+        for patch in frames.chunks(patch_frames) {
+            if patch.len() < patch_frames && n_patches > 0 {
+                break;
+            }
+
+            let mut data = vec![0.0_f32; patch_frames * n_mels];
+            for (f, frame) in patch.iter().enumerate() {
+                for (m, &v) in frame.iter().enumerate().take(n_mels) {
+                    data[f * n_mels + m] = v as f32;
+                }
+            }
+
+            let input: Tensor = tract_ndarray::Array3::from_shape_vec((1, patch_frames, n_mels), data)
+                .map_err(tagging_error)?
+                .into();
+            let outputs = self.model.run(tvec!(input.into())).map_err(tagging_error)?;
+            let output = outputs[0].to_array_view::<f32>().map_err(tagging_error)?;
+
+            for (score, &v) in scores.iter_mut().zip(output.iter()) {
+                *score += v as f64;
+            }
+            n_patches += 1;
+        }
+
+        if n_patches == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut predictions: Vec<(String, f64)> = self
+            .labels
+            .iter()
+            .zip(scores.iter())
+            .map(|(label, &total)| {
+                let mean = total / n_patches as f64;
+                let confidence = if self.config.apply_sigmoid { 1.0 / (1.0 + (-mean).exp()) } else { mean };
+                (label.clone(), confidence)
+            })
+            .filter(|(_, confidence)| *confidence >= self.config.threshold)
+            .collect();
+
+        predictions.sort_by(|a, b| descending_score(a.1, b.1).then_with(|| a.0.cmp(&b.0)));
+        predictions.truncate(self.config.max_tags);
+        Ok(predictions)
+    }
+}